@@ -1,6 +1,21 @@
 package dev.dioxus.main
 
+import android.app.Notification
+import android.app.NotificationChannel
+import android.app.NotificationManager
+import android.app.PendingIntent
+import android.app.Service
+import android.appwidget.AppWidgetManager
+import android.content.Context
+import android.content.Intent
+import android.content.pm.ServiceInfo
+import android.content.pm.ShortcutInfo
+import android.content.pm.ShortcutManager
+import android.graphics.drawable.Icon
+import android.net.Uri
+import android.os.Build
 import android.os.Bundle
+import android.os.IBinder
 import android.view.WindowManager
 import android.webkit.WebView
 
@@ -46,4 +61,167 @@ class MainActivity : WryActivity() {
         rustWebView?.onPause()
         super.onPause()
     }
+
+    /// Keep the latest intent, e.g. of a home-screen shortcut tapped while
+    /// the app was running, for `takeDeepLink`.
+    override fun onNewIntent(intent: Intent) {
+        super.onNewIntent(intent)
+        setIntent(intent)
+    }
+
+    /// The `logworkout://` link the app was opened on, called from Rust
+    /// (`services::launcher`), which polls it.  Returns it once, `null`
+    /// otherwise.
+    fun takeDeepLink(): String? {
+        val link = intent?.data?.takeIf { it.scheme == DEEP_LINK_SCHEME } ?: return null
+        intent = Intent(intent).setData(null)
+        return link.toString()
+    }
+
+    /// Home-screen shortcuts and widget summary, called from Rust
+    /// (`services::launcher`) whenever the sessions change.
+    ///
+    /// Publishes the "Start session" shortcut, and the "Last session" one
+    /// when `lastLink` is set, each opening the app on its deep link.  The
+    /// summary JSON is stored for app widgets, which are asked to redraw.
+    fun updateLauncher(
+        summaryJson: String,
+        startLabel: String,
+        startLink: String,
+        lastLabel: String?,
+        lastLink: String?,
+    ) {
+        getSharedPreferences(WIDGET_PREFERENCES, Context.MODE_PRIVATE)
+            .edit()
+            .putString(WIDGET_SUMMARY_KEY, summaryJson)
+            .apply()
+        val widgets = AppWidgetManager.getInstance(this)
+        for (provider in widgets.getInstalledProvidersForPackage(packageName, null)) {
+            sendBroadcast(
+                Intent(AppWidgetManager.ACTION_APPWIDGET_UPDATE)
+                    .setComponent(provider.provider)
+                    .putExtra(
+                        AppWidgetManager.EXTRA_APPWIDGET_IDS,
+                        widgets.getAppWidgetIds(provider.provider),
+                    ),
+            )
+        }
+        val shortcuts = mutableListOf(buildShortcut(START_SHORTCUT_ID, startLabel, startLink))
+        if (lastLabel != null && lastLink != null) {
+            shortcuts.add(buildShortcut(LAST_SHORTCUT_ID, lastLabel, lastLink))
+        }
+        getSystemService(ShortcutManager::class.java)?.dynamicShortcuts = shortcuts
+    }
+
+    private fun buildShortcut(id: String, label: String, link: String): ShortcutInfo =
+        ShortcutInfo.Builder(this, id)
+            .setShortLabel(label)
+            .setIcon(Icon.createWithResource(this, applicationInfo.icon))
+            .setIntent(
+                Intent(Intent.ACTION_VIEW, Uri.parse(link), this, MainActivity::class.java)
+                    .addFlags(Intent.FLAG_ACTIVITY_SINGLE_TOP),
+            )
+            .build()
+
+    /// Ongoing timer notification, called from Rust (`services::timer_service`)
+    /// whenever what is being timed changes.  `whenMillis` is the chronometer
+    /// origin (count up) or target (count down).
+    ///
+    /// Runs `WorkoutTimerService` in the foreground so Android keeps the app
+    /// (and its rest notifications) alive with the screen off.  Falls back to
+    /// a plain ongoing notification if the service cannot be resolved.
+    fun showTimerNotification(title: String, whenMillis: Long, countDown: Boolean) {
+        val intent = Intent(this, WorkoutTimerService::class.java)
+            .putExtra(WorkoutTimerService.EXTRA_TITLE, title)
+            .putExtra(WorkoutTimerService.EXTRA_WHEN, whenMillis)
+            .putExtra(WorkoutTimerService.EXTRA_COUNT_DOWN, countDown)
+        if (packageManager.resolveService(intent, 0) != null) {
+            startForegroundService(intent)
+        } else {
+            getSystemService(NotificationManager::class.java).notify(
+                TIMER_NOTIFICATION_ID,
+                buildTimerNotification(this, title, whenMillis, countDown),
+            )
+        }
+    }
+
+    /// Remove the timer notification and stop the foreground service.
+    fun cancelTimerNotification() {
+        stopService(Intent(this, WorkoutTimerService::class.java))
+        getSystemService(NotificationManager::class.java).cancel(TIMER_NOTIFICATION_ID)
+    }
+}
+
+private const val DEEP_LINK_SCHEME = "logworkout"
+private const val START_SHORTCUT_ID = "start_session"
+private const val LAST_SHORTCUT_ID = "last_session"
+/// Shared preferences holding the summary shown by app widgets, as JSON
+/// (`services::launcher::WidgetSummary`) under `WIDGET_SUMMARY_KEY`.
+private const val WIDGET_PREFERENCES = "logout_widget"
+private const val WIDGET_SUMMARY_KEY = "summary"
+private const val TIMER_CHANNEL_ID = "logout_timer"
+private const val TIMER_NOTIFICATION_ID = 4201
+
+/// Silent, ongoing notification whose chronometer is ticked by the OS.
+private fun buildTimerNotification(
+    context: Context,
+    title: String,
+    whenMillis: Long,
+    countDown: Boolean,
+): Notification {
+    val manager = context.getSystemService(NotificationManager::class.java)
+    if (manager.getNotificationChannel(TIMER_CHANNEL_ID) == null) {
+        manager.createNotificationChannel(
+            NotificationChannel(TIMER_CHANNEL_ID, "Workout timer", NotificationManager.IMPORTANCE_LOW),
+        )
+    }
+    val open = PendingIntent.getActivity(
+        context,
+        0,
+        Intent(context, MainActivity::class.java).addFlags(Intent.FLAG_ACTIVITY_SINGLE_TOP),
+        PendingIntent.FLAG_IMMUTABLE or PendingIntent.FLAG_UPDATE_CURRENT,
+    )
+    return Notification.Builder(context, TIMER_CHANNEL_ID)
+        .setSmallIcon(context.applicationInfo.icon)
+        .setContentTitle(title)
+        .setWhen(whenMillis)
+        .setShowWhen(true)
+        .setUsesChronometer(true)
+        .setChronometerCountDown(countDown)
+        .setCategory(Notification.CATEGORY_STOPWATCH)
+        .setOngoing(true)
+        .setOnlyAlertOnce(true)
+        .setContentIntent(open)
+        .build()
+}
+
+/// Foreground service holding the timer notification while a session is
+/// active, declared in the bundled `android/AndroidManifest.xml`.
+class WorkoutTimerService : Service() {
+    companion object {
+        const val EXTRA_TITLE = "title"
+        const val EXTRA_WHEN = "when"
+        const val EXTRA_COUNT_DOWN = "count_down"
+    }
+
+    override fun onBind(intent: Intent?): IBinder? = null
+
+    override fun onStartCommand(intent: Intent?, flags: Int, startId: Int): Int {
+        val notification = buildTimerNotification(
+            this,
+            intent?.getStringExtra(EXTRA_TITLE) ?: "LogOut",
+            intent?.getLongExtra(EXTRA_WHEN, System.currentTimeMillis()) ?: System.currentTimeMillis(),
+            intent?.getBooleanExtra(EXTRA_COUNT_DOWN, false) ?: false,
+        )
+        if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.UPSIDE_DOWN_CAKE) {
+            startForeground(
+                TIMER_NOTIFICATION_ID,
+                notification,
+                ServiceInfo.FOREGROUND_SERVICE_TYPE_SPECIAL_USE,
+            )
+        } else {
+            startForeground(TIMER_NOTIFICATION_ID, notification)
+        }
+        return START_STICKY
+    }
 }
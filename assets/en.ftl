@@ -3,16 +3,72 @@
 ## App-wide
 app-title = 💪 LogOut
 app-subtitle = Turn off your computer, Log your workOut
+nav-aria = Main navigation
+nav-exercises = Exercises
+nav-sessions = Sessions
+nav-analytics = Analytics
+nav-more = More
 
 ## Home page
 no-sessions = No past sessions yet
 start-first-workout = Tap + to start your first workout
 start-new-workout = Start New Workout
 session-repeat-title = Start a new session based on this one
+templates-title = 📋 Templates
+templates-desc = Sessions saved as templates, with their exercises and typical loads
+template-start-title = Start a session from this template
+template-delete = Delete template
+template-delete-confirm = Delete the template “{ $name }”?
+template-save-title = Save as template
+template-name-placeholder = Template name
+template-saved = 📋 Template “{ $name }” saved
+calendar-title = 📅 Training calendar
+calendar-open-title = Training calendar
+calendar-previous-month = Previous month
+calendar-next-month = Next month
+calendar-weekdays = Mo Tu We Th Fr Sa Su
+calendar-day-heading = { $month } { $day }
+calendar-day-done = ✅ Workout done
+calendar-day-missed = ⚠️ Planned workout missed
+calendar-start-btn = ▶️ Start
+calendar-unassign-aria = Remove from the calendar
+calendar-assign-aria = Plan this workout on this day
+calendar-no-choices = Save a session as a template, or import a training plan, to plan workouts.
 session-repeat-weekday-title = Repeat same-weekday session
 session-delete-title = Delete session
+share-image-btn-title = Share a picture of this session
+share-image-title = Workout of { $date }
+share-image-duration = duration
+share-image-volume = volume
+share-image-sets = sets
+share-image-failed = ⚠️ Sharing failed: { $error }
+share-link-btn-title = Share a link to this session
+share-link-aria = Link to this session
+share-link-too-long = This session is too long for a QR code, send the link instead.
+share-link-imported = 📥 Shared session imported
+share-link-invalid = ⚠️ Invalid shared session: { $error }
+share-link-import-section = 📥 Import a Shared Session
+share-link-import-desc = Paste a session link or code received from a friend to add a copy of that workout to your history.
+share-link-import-placeholder = Session link or code
+share-link-import-aria = Import the shared session
 session-resume-last-title = Resume last session
 session-show-more = +{ $count } more
+session-stats-exercises = 🏋️ { $count } exercises
+history-search-placeholder = 🔍 Exercise, muscle or note…
+recovery-title = 🔋 Muscle freshness
+recovery-desc = Estimated from the sets logged in the last { $days } days: each set tires its muscles, which recover over time. Below { $ready } % a muscle is still recovering.
+recovery-tired-title = Works a muscle that is still recovering
+weekly-sets-title = 🎯 Hard sets this week
+weekly-sets-desc = Working sets logged since Monday (warm-ups excluded) for each primary muscle of the exercise, against the weekly target of that muscle.
+history-search-from-aria = From date
+history-search-to-aria = To date
+history-search-clear = Clear search
+history-search-no-match = No session matches this search.
+session-select-title = Select sessions
+session-select-cancel = Cancel selection
+session-archive-selected-title = Archive selected sessions (hidden here, still counted in analytics)
+session-delete-selected-title = Delete selected sessions
+session-unarchive-title = Show this session in the history again
 hold-to-delete-hint = Hold for 3s to delete
 session-delete-confirm = Delete this session?
 session-delete-confirm-btn = 🗑️ Delete
@@ -20,6 +76,14 @@ cancel-btn = ❌ Cancel
 
 ## Exercises page
 browse-exercises = Browse { $count } exercises
+exercises-db-fetched = Database updated: { $when }
+exercises-db-never-fetched = Database never downloaded
+exercises-db-refresh-title = Refresh the exercise database
+exercises-db-refreshing = Downloading the exercise database…
+exercises-db-downloading = { $received } kB received
+exercises-db-retrying = (retry { $attempt } of { $max })
+exercises-db-failed = Download failed: { $error }
+exercises-db-retry = Retry
 search-placeholder = Search exercises names or attributes
 add-exercise = Add Custom Exercise
 filter-add = Activate filter
@@ -28,17 +92,61 @@ filter-remove = Remove filter
 ## Exercise card
 exercise-edit = Edit
 exercise-clone = Clone then edit
+exercise-hide = Hide this exercise from lists and search
+exercise-hidden-toast = 🙈 Exercise hidden, show it again from the More page
+exercise-offline-keep = Keep the images of this exercise offline
+exercise-offline-forget = Stop keeping the images of this exercise offline
+exercise-offline-toast = 📥 Images kept offline, they are being downloaded
+exercise-tag-add = + Custom tag…
+exercise-tag-remove = Remove this tag
+avoid-flag-title = Works a movement you are avoiding (injury mode)
+exercise-video-title = Technique video
+exercise-video-placeholder = https://youtube.com/watch?v=…
+exercise-rest-aria = Set the rest after a set of this exercise
+exercise-video-add = Add my own technique video link
+exercise-video-edit = Change my technique video link
+exercise-video-remove = Remove my video link
+exercise-alias-placeholder = My name for it (e.g. bench)
+exercise-alias-add = Add a search alias
+goal-weight-label = Goal weight (kg)
+goal-reps-label = Goal repetitions
+goal-reps = { $reps } reps
+goal-deadline-label = Reach it by (optional)
+goal-by = { $target } by { $deadline }
+goal-add = Set this goal
+goal-remove = Remove this goal
+goal-achieved = 🎉 Goal reached: { $exercise } { $target }!
+plateau-notice = No progress over the last { $sessions } sessions: try a deload week (about 10 % lighter) or a variation of this exercise.
+plateau-toast = 📉 { $exercise }: no progress over the last { $sessions } sessions, consider a deload or a variation
+exercise-alias-remove = Remove this alias
 
 ## Active session – notes
 session-notes-placeholder = Session notes (Markdown supported)…
 session-notes-unfold = 📝 Unfold Session Notes
+session-notes-add-title = Add notes to this session
+session-notes-edit-title = Edit session notes
+session-notes-save = 💾 Save Notes
 
 ## Active session – search
 session-search-placeholder = Search for an exercise...
 session-add-exercise-title = Add Custom Exercise
+session-pick-title = Select exercises to plan
+session-pick-cancel = Cancel selection
+session-pick-add-title = Add the selected exercises to the pending ones
+session-pick-added = ⏳ { $count } exercises added to the pending ones
+session-browse-title = Browse the exercises by muscle
+session-browse-aria = Exercises by muscle
+search-match-alias = Alias:
+search-match-other-language = Also called:
+search-match-muscle = Muscle:
+search-match-instructions = Found in the instructions
 session-filter-remove = Remove filter
 session-filter-add = Add filter
+session-search-results-aria = Matching exercises
+session-search-results-count = { $count } matching exercises
 pending-more = More pre-added ({ $count })
+pending-sets-left = { $count } sets left
+pending-target-reps = 🎯 { $count } reps
 
 ## Active session – header
 session-title = ⏱️ Active Session
@@ -57,7 +165,11 @@ completed-exercises-title = Completed Exercises
 
 ## Exercise input form
 exercise-complete-title = Complete Exercise
+exercise-same-as-last-btn = 🔁 Same as last
+exercise-same-as-last-title = Complete the set right away with the weight, reps and distance of the last one
 time-placeholder = mm:ss
+target-duration-title = Target duration of the hold, counted down by the timer
+target-duration-placeholder = Target (mm:ss)
 weight-placeholder = kg
 distance-placeholder = km
 reps-placeholder = reps
@@ -65,25 +177,52 @@ reps-placeholder = reps
 ## Completed exercise log
 log-replay-title = Do another set
 log-edit-title = Edit this exercise
+log-notes-placeholder = Notes about this set (Markdown supported)…
+assisted-toggle-title = Toggle between added load (➕) and assistance (➖)
+side-toggle-title = Side worked (for unilateral movements)
+side-both = ↔️ Both sides
+side-left = ⬅️ Left
+side-right = ➡️ Right
+set-kind-toggle-title = Kind of set (warm-ups count towards neither volume nor records)
+set-kind-normal = 💪 Working set
+set-kind-warm-up = 🔥 Warm-up
+set-kind-dropset = 📉 Dropset
+set-kind-amrap = 🔁 AMRAP
+set-kind-failure = 💥 To failure
 log-delete-title = Delete this exercise
+log-deleted = 🗑️ Exercise deleted
+log-start-label = Start time
+log-end-label = End time
+log-end-before-start = The end time cannot be before the start time
+log-start-taken = Another exercise of the session started at that time
+log-export-gpx-title = Export as GPX (Garmin, Strava…)
+log-export-tcx-title = Export as TCX (Garmin Connect)
 
 ## Add / Edit exercise pages
 add-exercise-page-title = Add Exercise
 edit-exercise-page-title = Edit Exercise
+exercise-delete-title = Delete exercise
 exercise-not-found = Exercise not found
 exercise-save = Save Exercise
 exercise-save-changes = Save Changes
+exercise-export-upstream-btn = 📤 Export as exercise-db JSON
+exercise-export-upstream-title = Export in the free-exercise-db format to contribute this exercise upstream (add its pictures at the listed paths)
 cancel-title = Cancel
 
 ## Exercise form fields
 form-name-label = Exercise Name *
 form-category-label = Category *
 form-force-label = Force Type
+form-level-label = Level
+form-mechanic-label = Mechanic
 form-equipment-label = Equipment
 form-muscles-primary-label = Primary Muscles
 form-muscles-secondary-label = Secondary Muscles
 form-instructions-label = Instructions
 form-images-label = Images
+form-videos-label = Technique videos
+form-rest-label = Rest after a set (s)
+form-rest-placeholder = Default rest duration
 form-name-placeholder = Pushups
 form-muscle-select-default = Select muscle...
 form-instruction-placeholder = Add an instruction step...
@@ -93,15 +232,29 @@ form-image-upload-title = Upload a local image file
 form-local-image-placeholder = /path/to/local/image.jpg
 form-local-image-title = Path to a local image file (will be copied into app storage)
 form-save-aria = Save
+form-draft-restored = 📝 Unsaved changes restored
+form-draft-discard-btn = Discard
+form-error-empty-name = Give the exercise a name
+form-error-duplicate-name = A custom exercise already has this name
+form-error-no-primary-muscle = Add at least one primary muscle
 
 ## More page
 more-title = ⚙️ More
 more-export-section = 📤 Export
 more-export-exercises-btn = 💾 { $count } Custom Exercises
 more-export-sessions-btn = 💾 { $count } Sessions
+more-export-calendar-btn = 📅 Calendar (.ics)
+ics-event-summary = 🏋️ Workout
 more-import-section = 📥 Import
 more-import-exercises-btn = 📂 Custom Exercises
 more-import-sessions-btn = 📂 Sessions
+backup-passphrase-section = 🔐 Backup Encryption
+backup-passphrase-desc = With a passphrase, exported sessions and custom exercises are encrypted so they can be safely stored in a cloud drive. Importing an encrypted backup needs the same passphrase. It cannot be recovered if forgotten.
+backup-passphrase-placeholder = Passphrase (optional)
+backup-open-failed = ⚠️ Cannot open the backup: { $error }
+more-trash-btn = Open the trash
+more-achievements-btn = Open the achievements
+more-storage-btn = Open the storage usage
 more-about-section = LogOut
 more-about-desc-a = A simple, efficient and cross-platform workout logging application with
 more-about-exercises-link = 800+ exercises
@@ -111,6 +264,155 @@ more-db-url-desc = Override the exercise database source. Save to trigger a down
 more-db-url-save-aria = Save
 more-db-exercises-count = 📦 { $count } exercises
 more-db-images-count = 🖼️ { $count } images
+more-db-refresh-btn = 🔄 Refresh now
+more-db-url-reset-btn = Reset to default
+exercise-sources-section = ➕ Additional exercise sources
+exercise-sources-desc = Merge exercises from other databases using the same format. Their IDs are prefixed with the source shown next to it to avoid clashes.
+exercise-sources-add-aria = Add source
+exercise-sources-remove-aria = Remove source
+language-pack-section = 🌐 Exercise language pack
+language-pack-desc = Translate exercise names and instructions with files named exercises.<language>.json (entries with an id, a name and instructions) under this address. The app language picks the translation.
+language-pack-save-aria = Save language pack address
+language-pack-remove-aria = Remove language pack
+merge-exercises-section = 🔀 Merge duplicate exercises
+merge-exercises-desc = Move the whole history of a custom exercise to another one (e.g. its database version), then send the duplicate to the trash.
+merge-exercises-from-aria = Duplicate exercise
+merge-exercises-from-placeholder = Duplicate custom exercise…
+merge-exercises-into-aria = Exercise to keep
+merge-exercises-into-placeholder = Exercise to keep…
+merge-exercises-btn = Merge
+merge-exercises-confirm = Move every set of “{ $from }” to “{ $into }” and delete “{ $from }”?
+merge-exercises-done = 🔀 Merged, { $count } sessions updated
+merge-exercises-failed = Merge failed: { $error }
+sample-data-section = 🧪 Sample data
+sample-data-desc = Load { $weeks } weeks of sample workouts to explore the analytics before having a history of your own, then purge them in one click.
+sample-data-load-btn = Load sample data
+sample-data-loaded = 🧪 { $count } sample sessions loaded
+sample-data-purge-btn = Purge sample data
+sample-data-purge-confirm = Delete every sample session? Your own sessions are kept.
+sample-data-purged = 🧹 { $count } sample sessions deleted
+sample-data-purge-failed = Purge failed: { $error }
+hidden-exercises-section = 🙈 Hidden exercises
+hidden-exercises-desc = These exercises are left out of the exercise list and session search.
+hidden-exercises-show-btn = Show
+taxonomy-section = 🏷 Custom categories, equipment and muscles
+taxonomy-desc = Add what the built-in lists lack (climbing, sled…), then tag exercises with it from their card. Tags can be used as search filters and are counted in analytics.
+taxonomy-kind-aria = Kind
+taxonomy-kind-category = Category
+taxonomy-kind-equipment = Equipment
+taxonomy-kind-muscle = Muscle
+taxonomy-placeholder = Name
+taxonomy-add-aria = Add
+taxonomy-remove = Delete this { $kind } from every exercise
+taxonomy-invalid = ⚠️ Empty, already defined or built-in name
+more-language-section = 🌐 Language
+more-language-system = System default
+display-section = 🔠 Display
+display-desc = Make text and buttons bigger, and the session buttons easier to hit with sweaty fingers.
+display-scale-aria = Text size
+display-scale-normal = Normal text
+display-scale-large = Large text
+display-scale-extra-large = Extra-large text
+display-large-targets-enable-btn = Enlarge session buttons
+display-large-targets-disable-btn = Normal session buttons
+display-one-handed-title = Moves the session controls and the complete button to the bottom of the screen, within thumb reach, and enlarges them
+display-one-handed-enable-btn = One-handed session layout
+display-one-handed-disable-btn = Usual session layout
+more-keep-awake-section = ☀️ Keep Screen Awake
+more-keep-awake-desc = Prevent the screen from turning off while a session is in progress. The lock is released when the session ends.
+more-keep-awake-disable-btn = 🌙 Let the screen sleep
+more-keep-awake-enable-btn = ☀️ Keep the screen awake
+more-flow-section = 🔁 Flow Mode
+more-flow-desc = Once the rest after an exercise is over, start the next pending exercise automatically, with the values of its last set. Handy for circuits.
+more-flow-enable-btn = 🔁 Chain exercises
+more-flow-disable-btn = ✋ Start exercises by hand
+more-quiet-section = 📳 Quiet Mode
+more-quiet-desc = Rest and duration alerts only vibrate, without any sound: for the gym or quiet hours. Desktop notifications are silent.
+more-quiet-enable-btn = 📳 Vibrate only
+more-quiet-disable-btn = 🔔 Play alert sounds
+more-media-controls-section = 🎵 Rest Timer in Media Controls
+more-media-controls-desc = Show the rest countdown on the lock screen and in the browser's media controls, whose play and pause buttons extend or skip the rest. Browsers only show them while audio plays, so a silent clip plays during rests, which may pause your music.
+more-media-controls-enable-btn = 🎵 Show the rest in media controls
+more-media-controls-disable-btn = 🔇 Leave the media controls alone
+tour-section = 💡 Session Tour
+tour-desc = Tips explaining the session view, shown the first times it opens.
+tour-restart-btn = 💡 Show the tour again
+tour-restarted = 💡 The tour will show in the session view
+tour-timer = Tap the timers to set the rest duration between sets.
+tour-pending = Exercises queued for the session wait here: tap 🔁 to start one.
+tour-complete-cancel = 💾 logs the set and starts the rest timer, ❌ cancels the exercise without logging it.
+tour-got-it = Got it
+tour-skip = Skip the tour
+rest-skip-section = ⏭️ No Rest After
+rest-skip-desc = No rest timer is started after completing an exercise of the selected categories, e.g. after a long run or a stretch.
+body-weight-section = ⚖️ Body weight
+body-weight-desc = Used for bodyweight exercises: added load and assistance are counted on top of it in analytics and one-rep max estimates.
+body-weight-save-aria = Save body weight
+equipment-profiles-section = 🏠 Equipment Profiles
+equipment-profiles-desc = Define the equipment available at each of your training places. Selecting a profile next to the exercise search only shows exercises doable with its equipment; body-only exercises are always shown.
+equipment-profile-name-placeholder = Profile name (e.g. Home)
+equipment-profile-add = Add profile
+equipment-profile-delete = Delete profile
+equipment-profile-select-title = Equipment profile
+equipment-profile-all = 🏋️ All equipment
+locations-section = 📍 Locations
+locations-desc = Save the gyms and places you train at, then tag each session with one from the session page. Analytics can be limited to a location, and inputs are prefilled from your last sets at the same place.
+location-name-placeholder = Location name (e.g. Downtown Gym)
+location-add = Add location
+location-remove = Remove location
+location-select-title = Session location
+location-none = 📍 No location
+weekly-sets-section = 🎯 Weekly set targets
+weekly-sets-target = { $muscle }: { $sets } sets per week
+weekly-sets-muscle-label = Muscle
+weekly-sets-sets-label = Hard sets per week
+weekly-sets-placeholder = Sets
+weekly-sets-add = Set the weekly target
+weekly-sets-remove = Remove this target
+avoid-section = 🩹 Injury Mode
+avoid-desc = List the movements to avoid while an injury heals, e.g. shoulders with push exercises. During the chosen dates, exercises working that muscle with that movement are marked with ⚠️ in search, or hidden.
+avoid-any-muscle = Any muscle
+avoid-any-force = Any movement
+avoid-muscle-label = Muscle to avoid
+avoid-force-label = Movement to avoid
+avoid-from-label = From
+avoid-until-label = Until
+avoid-add = Add movement to avoid
+avoid-remove = Remove
+avoid-hide-btn = 🙈 Hide flagged exercises
+avoid-warn-btn = ⚠️ Only warn about flagged exercises
+lock-section = 🔒 App Lock
+lock-desc = Require a passcode at start-up. Session notes, GPS tracks and heart-rate data are then stored encrypted with a key derived from it. A forgotten passcode cannot be recovered.
+lock-title = 🔒 LogOut is locked
+lock-passcode-placeholder = Passcode
+lock-confirm-placeholder = Confirm passcode
+lock-unlock-aria = Unlock
+lock-enable-btn = 🔒 Enable lock
+lock-disable-btn = 🔓 Disable lock
+lock-enabled = 🔒 App lock enabled
+lock-disabled = 🔓 App lock disabled
+lock-mismatch = ⚠️ Passcodes do not match
+lock-too-short = ⚠️ Passcode must have at least 4 characters
+lock-wrong-passcode = ❌ Wrong passcode
+sync-section = 🔄 Encrypted Sync
+sync-desc = Sync sessions and custom exercises with your own server. Data is encrypted on this device with your passphrase; the server never sees it. Use the same URL and passphrase on every device.
+sync-passphrase-placeholder = Passphrase
+sync-now-aria = Sync now
+sync-done = Synced
+sync-failed = ❌ Sync failed
+transfer-section = 📲 Transfer to New Device
+transfer-desc = Move all sessions and custom exercises directly to another device on the same network. No account or cloud involved.
+transfer-send-btn = 📤 Send from this device
+transfer-receive-btn = 📥 Receive on this device
+transfer-show-code = Scan this code with the other device:
+transfer-scan-hint = Scan or paste the code shown on the other device.
+transfer-paste-placeholder = Pairing code
+transfer-scan-aria = Scan QR code
+transfer-submit-aria = Confirm
+transfer-sent = session(s) sent
+transfer-received = session(s) received
+transfer-failed = ❌ Transfer failed
+transfer-timeout = ⏱️ The other device did not connect or stopped responding
 more-oss-section = Open Source & Licences
 more-oss-desc-a = This project is open-source under the GPL-3.0, and uses other open-source projects. See its
 more-oss-repo-link = code repository
@@ -121,6 +423,12 @@ more-built-with-rust = Systems programming language
 more-built-with-dioxus = Rust framework for cross-platform apps
 more-built-with-freeexdb = Exercise data and images, by yuhonas
 more-built-with-others = And many others …
+credits-section = 📜 Versions & data sources
+credits-license = License: { $license }
+credits-schema = Storage schema version { $version }
+credits-db-source = Exercise database:
+credits-last-fetch = Last fetched: { $time }
+credits-never = never
 more-privacy-section = 🔒 Privacy Policy
 more-privacy-desc = LogOut does not collect, share or transmit any personal data. All workout data is stored exclusively on your device. The exercise database is downloaded from a configurable URL of your choice. No analytics, no accounts, no tracking.
 more-replace-confirm = Replace custom exercise { $name }?
@@ -128,18 +436,162 @@ more-replace-btn = 💾 Replace
 more-sessions-refused = session(s) refused: ID already exists
 more-exercises-refused = exercise(s) refused: built-in ID conflict
 
+## Trash page
+trash-title = 🗑️ Trash
+trash-description = Deleted sessions and custom exercises can be restored for { $days } days.
+trash-empty = The trash is empty.
+trash-days-left = Deleted for good in { $count } days
+trash-restore-btn = ♻️ Restore
+trash-restored = ♻️ Restored
+trash-delete-title = Delete for good
+
+## Achievements page
+achievements-title = 🏆 Achievements
+achievements-desc = Badges earned by training regularly, kept even when the sessions behind them are deleted.
+achievement-sessions = { $count } session(s) completed
+achievement-weekly-volume = { $kg } kg lifted in a week
+achievement-streak = { $weeks }-week streak
+achievement-unlocked = 🏆 Badge unlocked: { $badge }
+achievement-unlocked-at = Unlocked on { $date }
+
+## Confirmation dialog
+confirm-cancel = Cancel
+confirm-discard-btn = 🗑️ Discard
+confirm-cancel-session = Cancel this session? Nothing has been logged yet.
+confirm-discard-exercise = Discard the exercise in progress?
+
+## Error screen
+error-title = 💥 Something went wrong
+error-desc = LogOut hit an unexpected error. Your saved data is safe; try again, reload the app, or export diagnostics to attach to a bug report.
+error-retry-btn = 🔄 Try again
+error-reload-btn = ♻️ Reload
+error-export-btn = 📤 Export diagnostics
+
+## Storage usage page
+storage-title = 💽 Storage
+storage-desc = Space used on this device by each part of the app data.
+storage-sessions = 📅 Sessions
+storage-custom-exercises = 📋 Custom exercises
+storage-exercises = 📦 Exercise database
+storage-trash = 🗑️ Trash
+storage-images = 🖼️ Images
+storage-other = 🧩 Other (indexes, caches, app files)
+storage-total = Total: { $used }
+storage-total-quota = Total: { $used } of { $quota } available
+storage-persistent = 🔒 Storage is persistent: the browser will not delete it
+storage-not-persistent = ⚠️ Storage is not persistent: the browser may delete it when space runs low. Installing the app or exporting your sessions regularly helps.
+storage-persist-btn = 🔒 Ask to keep the storage
+storage-clear-images-btn = 🧹 Clear the image cache
+storage-images-cleared = 🧹 Image cache cleared, images will be downloaded again
+storage-prefetch-desc = Images load as they scroll into view. Those of the exercises you performed are kept offline; prefetching downloads them ahead of time (on Android and desktop, every image of the exercise database).
+storage-prefetch-aria = Image prefetching
+storage-prefetch-off = 🚫 Never prefetch images
+storage-prefetch-wifi-only = 📶 Prefetch images on Wi-Fi only
+storage-prefetch-always = 🌐 Always prefetch images
+storage-compact-btn = 🗜️ Compact the database
+storage-compacted = 🗜️ Database compacted
+storage-failed = ⚠️ Storage operation failed: { $error }
+
+## Cloud backup
+cloud-backup-section = ☁️ Cloud Backup
+cloud-backup-desc = Link a cloud account to upload your sessions and custom exercises to its app folder after each finished session. The uploaded files are encrypted.
+cloud-backup-link-btn = 🔗 Link { $provider }
+cloud-backup-linked = ☁️ Linked to { $provider }
+cloud-backup-last-upload = last upload on { $date }
+cloud-backup-upload-btn = ⬆️ Upload now
+cloud-backup-unlink-btn = Unlink
+cloud-backup-uploaded = ☁️ Backup uploaded
+cloud-backup-failed = ⚠️ Cloud backup failed: { $error }
+cloud-backup-passphrase-desc = Uploads are encrypted with this passphrase. Import them with the same passphrase to restore them. It cannot be recovered if forgotten.
+cloud-backup-passphrase-placeholder = Backup passphrase
+cloud-backup-passphrase-required = ⚠️ Cloud backup skipped: set a backup passphrase first
+training-plan-section = Training plan
+training-plan-desc = Import a plan written by your coach (JSON file) to start its days in one tap and export a weekly adherence report.
+training-plan-import-btn = Import a plan
+training-plan-imported = Plan "{ $name }" imported
+training-plan-invalid = Invalid plan: { $error }
+training-plan-author = By { $author }
+training-plan-per-week = { $count } sessions per week
+training-plan-start-btn = Start
+training-plan-report-btn = Export adherence report
+training-plan-report-failed = Could not build the report: { $error }
+training-plan-export-btn = Export plan
+training-plan-remove-btn = Remove plan
+
+## Webhook
+webhook-section = 🪝 Webhook
+webhook-desc = Send a JSON summary of each finished session to this URL, so your home automation (Home Assistant, Node-RED, an MQTT bridge…) can react when you are done working out.
+webhook-save-aria = Save the webhook URL
+webhook-saved = 🪝 Webhook saved
+webhook-test-btn = 📤 Send a test
+webhook-test-sent = 🪝 Test sent
+webhook-failed = ⚠️ Webhook failed: { $error }
+
+## Local API
+local-api-section = 🔌 Local API
+local-api-desc = Serve your sessions and analytics as JSON on this computer only, for your own dashboards and spreadsheets. The API is read-only unless writes are allowed.
+local-api-port-aria = Port
+local-api-start-btn = ▶️ Start
+local-api-stop-btn = ⏹️ Stop
+local-api-running = 🔌 Listening on { $url }
+local-api-writes = Allow adding sessions through the API
+local-api-failed = ⚠️ Local API failed: { $error }
+global-hotkey-section = Global shortcut
+global-hotkey-desc = Bring LogOut to the front from any application, starting a session if none is running, e.g. when stepping away from the computer to train.
+global-hotkey-enabled = Enable the global shortcut
+global-hotkey-aria = Global shortcut, e.g. CmdOrCtrl+Shift+L
+global-hotkey-save-btn = Save
+global-hotkey-invalid = ⚠️ Invalid shortcut: { $accelerator }
+
 ## Toast messages (static prefixes; technical error detail appended at runtime)
 toast-export-failed = ⚠️ Export failed
 toast-export-sessions-failed = ⚠️ Failed to export sessions
 toast-sessions-invalid = ⚠️ Invalid sessions JSON
 toast-exercises-invalid = ⚠️ Invalid exercises JSON
 toast-screen-locked = 🔒 Screen locked – only active session edits allowed
+toast-session-save-delayed = ⚠️ Session save delayed, will retry
+toast-session-save-failed = ⚠️ Failed to save session
+toast-session-delete-delayed = ⚠️ Session deletion delayed, will retry
+toast-session-delete-failed = ⚠️ Failed to delete session
+toast-exercise-save-delayed = ⚠️ Exercise save delayed, will retry
+toast-exercise-save-failed = ⚠️ Failed to save exercise
+toast-exercise-delete-delayed = ⚠️ Exercise deletion delayed, will retry
+toast-exercise-delete-failed = ⚠️ Failed to delete exercise
+toast-save-delayed = ⚠️ Saving delayed, will retry
+toast-save-failed = ⚠️ Failed to save
+active-conflict-title = ⚠️ Several sessions in progress
+active-conflict-desc = { $count } sessions are in progress at once, only one is shown. Merge them into the earliest one, or finish the ones you no longer need.
+active-conflict-logs = { $count } exercises
+active-conflict-finish-btn = 🏁 Finish
+active-conflict-merge-btn = 🔗 Merge into one session
+toast-session-already-active = ⚠️ A session is already in progress, finish it first
 db-empty-toast = 📥 Exercise database empty — tap to download
+db-download-failed-toast = ❌ Exercise database download failed — tap to retry
+update-available-toast = 🆕 Update available — tap to reload
+install-banner = 📲 Install LogOut for one-tap access, even offline
+install-banner-dismiss-aria = Do not show again
+persistence-banner = ⚠️ The browser may delete your workout history when space runs low — tap to keep it
+persistence-banner-dismiss-aria = Hide until next launch
 
 ## Relative date labels
 date-today = Today
 date-yesterday = Yesterday
 date-days-ago = { $count } days ago
+date-group-this-week = This week
+date-group-last-week = Last week
+date-group-month = { $month } { $year }
+month-january = January
+month-february = February
+month-march = March
+month-april = April
+month-may = May
+month-june = June
+month-july = July
+month-august = August
+month-september = September
+month-october = October
+month-november = November
+month-december = December
 
 ## Congratulations & notification toasts
 congratulations = 🎉 Great workout! Session complete!
@@ -149,12 +601,43 @@ notif-duration-title = Duration reached
 notif-duration-body = All Time High duration reached!
 notif-rest-title = Rest over
 notif-rest-body = Time to start your next set!
+media-rest-title = 🛋️ Rest
+media-rest-next = { $exercise } next
+timer-session-title = Workout in progress
+tray-complete = Complete exercise
+tray-finish = Finish session
+launcher-start-session = Start session
+launcher-last-session = Last session
 
 ## Analytics page
 analytics-title = 📊 Analytics
 analytics-subtitle = Track your progress over time
 analytics-pairs-label = Metric–Exercise Pairs (⩽ 8)
+analytics-location-title = Only sessions at this location
+analytics-location-all = 📍 All locations
 analytics-empty = Select exercises to view analytics
+analytics-asymmetry-title = ↔️ Left / Right Balance
+analytics-asymmetry-row = left { $left }, right { $right } ({ $gap } % left)
+analytics-one-rep-max-title = 🏋️ Estimated one-rep max
+analytics-one-rep-max-desc = Epley estimate from the best set, body weight included for bodyweight exercises
+analytics-tags-title = 🏷 Custom tags, last { $days } days
+analytics-tags-sets = { $count } sets
+analytics-strain-title = 🔥 Training load
+analytics-strain-desc = Strain score per session: heart-rate zone minutes (TRIMP) when a heart rate was recorded, else one point per 100 kg lifted
+analytics-strain-ratio = Acute:chronic load ratio (last 7 days vs the weekly average of the last 28): { $ratio }
+analytics-rest-title = ⏱️ Rest between exercises
+analytics-rest-desc = Average actual rest per session (bars) against the rest planned for its exercises (line), breaks over 30 minutes left out
+analytics-rest-overrun = Average rest overrun: { $early } s early in a session, { $late } s late
+analytics-compare-btn = ⚖️ Compare two periods
+analytics-compare-back = 📈 Back to the charts
+analytics-compare-current = Period A
+analytics-compare-previous = Period B
+analytics-compare-from = From
+analytics-compare-to = To
+analytics-compare-invalid = Pick two valid date ranges to compare
+analytics-compare-sessions = Sessions
+analytics-compare-frequency = Sessions per week
+analytics-compare-volume = Volume
 analytics-metric-weight = Weight (kg)
 analytics-metric-reps = Repetitions
 analytics-metric-distance = Distance
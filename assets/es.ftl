@@ -3,16 +3,72 @@
 ## Para toda la aplicación
 app-title = 💪 LogOut
 app-subtitle = Apaga tu ordenador, registra tu entrenamiento
+nav-aria = Navegación principal
+nav-exercises = Ejercicios
+nav-sessions = Sesiones
+nav-analytics = Estadísticas
+nav-more = Más
 
 ## Página de inicio
 no-sessions = Sin sesiones pasadas
 start-first-workout = Pulsa + para empezar tu primer entrenamiento
 start-new-workout = Nuevo entrenamiento
 session-repeat-title = Iniciar nueva sesión basada en esta
+templates-title = 📋 Plantillas
+templates-desc = Sesiones guardadas como plantillas, con sus ejercicios y cargas habituales
+template-start-title = Empezar una sesión con esta plantilla
+template-delete = Eliminar la plantilla
+template-delete-confirm = ¿Eliminar la plantilla «{ $name }»?
+template-save-title = Guardar como plantilla
+template-name-placeholder = Nombre de la plantilla
+template-saved = 📋 Plantilla «{ $name }» guardada
+calendar-title = 📅 Calendario de entrenamiento
+calendar-open-title = Calendario de entrenamiento
+calendar-previous-month = Mes anterior
+calendar-next-month = Mes siguiente
+calendar-weekdays = Lu Ma Mi Ju Vi Sá Do
+calendar-day-heading = { $day } de { $month }
+calendar-day-done = ✅ Entrenamiento hecho
+calendar-day-missed = ⚠️ Entrenamiento previsto perdido
+calendar-start-btn = ▶️ Empezar
+calendar-unassign-aria = Quitar del calendario
+calendar-assign-aria = Planificar este entrenamiento ese día
+calendar-no-choices = Guarda una sesión como plantilla, o importa un plan de entrenamiento, para planificar entrenamientos.
 session-repeat-weekday-title = Repetir la sesión del mismo día de la semana
 session-delete-title = Eliminar sesión
+share-image-btn-title = Compartir una imagen de esta sesión
+share-image-title = Entrenamiento del { $date }
+share-image-duration = duración
+share-image-volume = volumen
+share-image-sets = series
+share-image-failed = ⚠️ Error al compartir: { $error }
+share-link-btn-title = Compartir un enlace a esta sesión
+share-link-aria = Enlace a esta sesión
+share-link-too-long = Esta sesión es demasiado larga para un código QR, envía el enlace en su lugar.
+share-link-imported = 📥 Sesión compartida importada
+share-link-invalid = ⚠️ Sesión compartida no válida: { $error }
+share-link-import-section = 📥 Importar una sesión compartida
+share-link-import-desc = Pega el enlace o el código de una sesión recibida de un amigo para añadir una copia de ese entrenamiento a tu historial.
+share-link-import-placeholder = Enlace o código de sesión
+share-link-import-aria = Importar la sesión compartida
 session-resume-last-title = Reanudar la última sesión
 session-show-more = +{ $count } más
+session-stats-exercises = 🏋️ { $count } ejercicios
+history-search-placeholder = 🔍 Ejercicio, músculo o nota…
+recovery-title = 🔋 Frescura muscular
+recovery-desc = Estimada a partir de las series de los últimos { $days } días: cada serie cansa sus músculos, que se recuperan con el tiempo. Por debajo del { $ready } % un músculo aún se está recuperando.
+recovery-tired-title = Trabaja un músculo que aún se está recuperando
+weekly-sets-title = 🎯 Series efectivas esta semana
+weekly-sets-desc = Series de trabajo registradas desde el lunes (calentamientos excluidos) para cada músculo principal del ejercicio, frente al objetivo semanal de ese músculo.
+history-search-from-aria = Fecha de inicio
+history-search-to-aria = Fecha de fin
+history-search-clear = Borrar la búsqueda
+history-search-no-match = Ninguna sesión coincide con esta búsqueda.
+session-select-title = Seleccionar sesiones
+session-select-cancel = Cancelar selección
+session-archive-selected-title = Archivar las sesiones seleccionadas (ocultas aquí, siguen contando en las estadísticas)
+session-delete-selected-title = Eliminar las sesiones seleccionadas
+session-unarchive-title = Mostrar de nuevo esta sesión en el historial
 hold-to-delete-hint = Mantener 3s para eliminar
 session-delete-confirm = ¿Eliminar esta sesión?
 session-delete-confirm-btn = 🗑️ Eliminar
@@ -20,6 +76,14 @@ cancel-btn = ❌ Cancelar
 
 ## Página de ejercicios
 browse-exercises = { $count } ejercicios disponibles
+exercises-db-fetched = Base actualizada: { $when }
+exercises-db-never-fetched = Base nunca descargada
+exercises-db-refresh-title = Actualizar la base de ejercicios
+exercises-db-refreshing = Descargando la base de ejercicios…
+exercises-db-downloading = { $received } kB recibidos
+exercises-db-retrying = (reintento { $attempt } de { $max })
+exercises-db-failed = Error de descarga: { $error }
+exercises-db-retry = Reintentar
 search-placeholder = Buscar ejercicios por nombre o atributos
 add-exercise = Añadir ejercicio personalizado
 filter-add = Activar filtro
@@ -28,13 +92,61 @@ filter-remove = Eliminar filtro
 ## Tarjeta de ejercicio
 exercise-edit = Editar
 exercise-clone = Duplicar y editar
+exercise-hide = Ocultar este ejercicio de las listas y la búsqueda
+exercise-hidden-toast = 🙈 Ejercicio oculto, vuelve a mostrarlo desde la página Más
+exercise-offline-keep = Guardar las imágenes de este ejercicio sin conexión
+exercise-offline-forget = Dejar de guardar las imágenes de este ejercicio sin conexión
+exercise-offline-toast = 📥 Imágenes guardadas sin conexión, se están descargando
+exercise-tag-add = + Etiqueta personalizada…
+exercise-tag-remove = Quitar esta etiqueta
+avoid-flag-title = Trabaja un movimiento que estás evitando (modo lesión)
+exercise-video-title = Vídeo de técnica
+exercise-video-placeholder = https://youtube.com/watch?v=…
+exercise-rest-aria = Definir el descanso tras una serie de este ejercicio
+exercise-video-add = Añadir mi enlace de vídeo de técnica
+exercise-video-edit = Cambiar mi enlace de vídeo de técnica
+exercise-video-remove = Quitar mi enlace de vídeo
+exercise-alias-placeholder = Mi nombre para este ejercicio (p. ej. press banca)
+exercise-alias-add = Añadir un alias de búsqueda
+goal-weight-label = Peso objetivo (kg)
+goal-reps-label = Repeticiones objetivo
+goal-reps = { $reps } repeticiones
+goal-deadline-label = Alcanzarlo antes del (opcional)
+goal-by = { $target } antes del { $deadline }
+goal-add = Fijar este objetivo
+goal-remove = Eliminar este objetivo
+goal-achieved = 🎉 ¡Objetivo alcanzado: { $exercise } { $target }!
+plateau-notice = Sin progreso en las últimas { $sessions } sesiones: prueba una semana de descarga (un 10 % más ligera) o una variante de este ejercicio.
+plateau-toast = 📉 { $exercise }: sin progreso en las últimas { $sessions } sesiones, considera una descarga o una variante
+exercise-alias-remove = Quitar este alias
+
+## Sesión activa – notas
+session-notes-placeholder = Notas de la sesión (Markdown compatible)…
+session-notes-unfold = 📝 Desplegar notas de la sesión
+session-notes-add-title = Añadir notas a esta sesión
+session-notes-edit-title = Editar las notas de la sesión
+session-notes-save = 💾 Guardar notas
 
 ## Sesión activa – búsqueda
 session-search-placeholder = Buscar un ejercicio...
 session-add-exercise-title = Añadir ejercicio personalizado
+session-pick-title = Seleccionar ejercicios para planificar
+session-pick-cancel = Cancelar la selección
+session-pick-add-title = Añadir los ejercicios seleccionados a los pendientes
+session-pick-added = ⏳ { $count } ejercicios añadidos a los pendientes
+session-browse-title = Explorar los ejercicios por músculo
+session-browse-aria = Ejercicios por músculo
+search-match-alias = Alias:
+search-match-other-language = También llamado:
+search-match-muscle = Músculo:
+search-match-instructions = Encontrado en las instrucciones
 session-filter-remove = Eliminar filtro
 session-filter-add = Añadir filtro
+session-search-results-aria = Ejercicios coincidentes
+session-search-results-count = { $count } ejercicios coincidentes
 pending-more = Más preañadidos ({ $count })
+pending-sets-left = { $count } series restantes
+pending-target-reps = 🎯 { $count } repeticiones
 
 ## Sesión activa – encabezado
 session-title = ⏱️ Sesión activa
@@ -53,7 +165,11 @@ completed-exercises-title = Ejercicios completados
 
 ## Formulario de ejercicio
 exercise-complete-title = Completar ejercicio
+exercise-same-as-last-btn = 🔁 Igual que la última
+exercise-same-as-last-title = Completa la serie al instante con el peso, las repeticiones y la distancia de la última
 time-placeholder = mm:ss
+target-duration-title = Duración objetivo del mantenimiento, en cuenta atrás en el cronómetro
+target-duration-placeholder = Objetivo (mm:ss)
 weight-placeholder = kg
 distance-placeholder = km
 reps-placeholder = rep.
@@ -61,25 +177,52 @@ reps-placeholder = rep.
 ## Registro de ejercicio completado
 log-replay-title = Hacer otra serie
 log-edit-title = Editar este ejercicio
+log-notes-placeholder = Notas sobre esta serie (admite Markdown)…
+assisted-toggle-title = Alternar entre carga añadida (➕) y asistencia (➖)
+side-toggle-title = Lado trabajado (movimientos unilaterales)
+side-both = ↔️ Ambos lados
+side-left = ⬅️ Izquierda
+side-right = ➡️ Derecha
+set-kind-toggle-title = Tipo de serie (los calentamientos no cuentan ni para el volumen ni para los récords)
+set-kind-normal = 💪 Serie efectiva
+set-kind-warm-up = 🔥 Calentamiento
+set-kind-dropset = 📉 Serie descendente
+set-kind-amrap = 🔁 Máximo de repeticiones
+set-kind-failure = 💥 Hasta el fallo
 log-delete-title = Eliminar este ejercicio
+log-deleted = 🗑️ Ejercicio eliminado
+log-start-label = Hora de inicio
+log-end-label = Hora de fin
+log-end-before-start = La hora de fin no puede ser anterior a la hora de inicio
+log-start-taken = Otro ejercicio de la sesión empezó a esa hora
+log-export-gpx-title = Exportar como GPX (Garmin, Strava…)
+log-export-tcx-title = Exportar como TCX (Garmin Connect)
 
 ## Páginas de añadir / editar ejercicio
 add-exercise-page-title = Añadir ejercicio
 edit-exercise-page-title = Editar ejercicio
+exercise-delete-title = Eliminar ejercicio
 exercise-not-found = Ejercicio no encontrado
 exercise-save = Guardar ejercicio
 exercise-save-changes = Guardar cambios
+exercise-export-upstream-btn = 📤 Exportar como JSON de exercise-db
+exercise-export-upstream-title = Exportar en el formato de free-exercise-db para contribuir este ejercicio (añade sus imágenes en las rutas indicadas)
 cancel-title = Cancelar
 
 ## Campos del formulario de ejercicio
 form-name-label = Nombre del ejercicio *
 form-category-label = Categoría *
 form-force-label = Tipo de fuerza
+form-level-label = Nivel
+form-mechanic-label = Mecánica
 form-equipment-label = Equipamiento
 form-muscles-primary-label = Músculos principales
 form-muscles-secondary-label = Músculos secundarios
 form-instructions-label = Instrucciones
 form-images-label = Imágenes
+form-videos-label = Vídeos de técnica
+form-rest-label = Descanso tras una serie (s)
+form-rest-placeholder = Duración de descanso por defecto
 form-name-placeholder = Flexiones
 form-muscle-select-default = Seleccionar músculo...
 form-instruction-placeholder = Añadir un paso de instrucción...
@@ -89,15 +232,29 @@ form-image-upload-title = Subir una imagen local
 form-local-image-placeholder = /ruta/a/imagen.jpg
 form-local-image-title = Ruta a un archivo de imagen local (se copiará al almacenamiento de la aplicación)
 form-save-aria = Guardar
+form-draft-restored = 📝 Cambios sin guardar restaurados
+form-draft-discard-btn = Descartar
+form-error-empty-name = Dale un nombre al ejercicio
+form-error-duplicate-name = Ya hay un ejercicio personalizado con este nombre
+form-error-no-primary-muscle = Añade al menos un músculo principal
 
 ## Página Más
 more-title = ⚙️ Más
 more-export-section = 📤 Exportar
 more-export-exercises-btn = 💾 { $count } Ejercicios personalizados
 more-export-sessions-btn = 💾 { $count } Sesiones
+more-export-calendar-btn = 📅 Calendario (.ics)
+ics-event-summary = 🏋️ Entrenamiento
 more-import-section = 📥 Importar
 more-import-exercises-btn = 📂 Ejercicios personalizados
 more-import-sessions-btn = 📂 Sesiones
+backup-passphrase-section = 🔐 Cifrado de copias de seguridad
+backup-passphrase-desc = Con una frase de contraseña, las sesiones y ejercicios personalizados exportados se cifran y pueden guardarse de forma segura en la nube. Importar una copia cifrada requiere la misma frase, que no se puede recuperar si se olvida.
+backup-passphrase-placeholder = Frase de contraseña (opcional)
+backup-open-failed = ⚠️ No se puede abrir la copia de seguridad: { $error }
+more-trash-btn = Abrir la papelera
+more-achievements-btn = Ver los logros
+more-storage-btn = Ver el espacio de almacenamiento
 more-about-section = LogOut
 more-about-desc-a = Una aplicación simple, eficiente y multiplataforma para registrar entrenamientos con
 more-about-exercises-link = 800+ ejercicios
@@ -107,6 +264,155 @@ more-db-url-desc = Cambia la fuente de datos de ejercicios. Guarda para descarga
 more-db-url-save-aria = Guardar
 more-db-exercises-count = 📦 { $count } ejercicios
 more-db-images-count = 🖼️ { $count } imágenes
+more-db-refresh-btn = 🔄 Actualizar ahora
+more-db-url-reset-btn = Restablecer por defecto
+exercise-sources-section = ➕ Fuentes de ejercicios adicionales
+exercise-sources-desc = Combina ejercicios de otras bases con el mismo formato. Sus identificadores llevan como prefijo la fuente indicada al lado para evitar conflictos.
+exercise-sources-add-aria = Añadir fuente
+exercise-sources-remove-aria = Quitar fuente
+language-pack-section = 🌐 Paquete de idioma de los ejercicios
+language-pack-desc = Traduce los nombres e instrucciones de los ejercicios con archivos llamados exercises.<idioma>.json (entradas con un id, un nombre e instrucciones) en esta dirección. El idioma de la aplicación elige la traducción.
+language-pack-save-aria = Guardar la dirección del paquete de idioma
+language-pack-remove-aria = Quitar el paquete de idioma
+merge-exercises-section = 🔀 Fusionar ejercicios duplicados
+merge-exercises-desc = Traslada todo el historial de un ejercicio personalizado a otro (por ejemplo su versión de la base) y envía el duplicado a la papelera.
+merge-exercises-from-aria = Ejercicio duplicado
+merge-exercises-from-placeholder = Ejercicio personalizado duplicado…
+merge-exercises-into-aria = Ejercicio a conservar
+merge-exercises-into-placeholder = Ejercicio a conservar…
+merge-exercises-btn = Fusionar
+merge-exercises-confirm = ¿Trasladar todas las series de «{ $from }» a «{ $into }» y eliminar «{ $from }»?
+merge-exercises-done = 🔀 Fusionado, { $count } sesiones actualizadas
+merge-exercises-failed = Error al fusionar: { $error }
+sample-data-section = 🧪 Datos de ejemplo
+sample-data-desc = Carga { $weeks } semanas de entrenamientos de ejemplo para explorar los análisis antes de tener tu propio historial, y bórralos después con un clic.
+sample-data-load-btn = Cargar datos de ejemplo
+sample-data-loaded = 🧪 { $count } sesiones de ejemplo cargadas
+sample-data-purge-btn = Borrar datos de ejemplo
+sample-data-purge-confirm = ¿Borrar todas las sesiones de ejemplo? Tus propias sesiones se conservan.
+sample-data-purged = 🧹 { $count } sesiones de ejemplo borradas
+sample-data-purge-failed = Error al borrar: { $error }
+hidden-exercises-section = 🙈 Ejercicios ocultos
+hidden-exercises-desc = Estos ejercicios no aparecen en la lista de ejercicios ni en la búsqueda de la sesión.
+hidden-exercises-show-btn = Mostrar
+taxonomy-section = 🏷 Categorías, equipamiento y músculos personalizados
+taxonomy-desc = Añade lo que falta en las listas integradas (escalada, trineo…) y etiqueta los ejercicios desde su tarjeta. Las etiquetas sirven como filtros de búsqueda y se cuentan en las estadísticas.
+taxonomy-kind-aria = Tipo
+taxonomy-kind-category = Categoría
+taxonomy-kind-equipment = Equipamiento
+taxonomy-kind-muscle = Músculo
+taxonomy-placeholder = Nombre
+taxonomy-add-aria = Añadir
+taxonomy-remove = Eliminar este valor ({ $kind }) de todos los ejercicios
+taxonomy-invalid = ⚠️ Nombre vacío, ya definido o integrado
+more-language-section = 🌐 Idioma
+more-language-system = Idioma del sistema
+display-section = 🔠 Pantalla
+display-desc = Agranda el texto y los botones, y haz que los botones de la sesión sean más fáciles de pulsar con los dedos sudados.
+display-scale-aria = Tamaño del texto
+display-scale-normal = Texto normal
+display-scale-large = Texto grande
+display-scale-extra-large = Texto muy grande
+display-large-targets-enable-btn = Agrandar los botones de la sesión
+display-large-targets-disable-btn = Botones de sesión normales
+display-one-handed-title = Lleva los controles de la sesión y el botón de completar a la parte inferior de la pantalla, al alcance del pulgar, y los agranda
+display-one-handed-enable-btn = Sesión con una mano
+display-one-handed-disable-btn = Disposición de sesión habitual
+more-keep-awake-section = ☀️ Mantener la pantalla encendida
+more-keep-awake-desc = Evita que la pantalla se apague durante una sesión. El bloqueo se libera al terminar la sesión.
+more-keep-awake-disable-btn = 🌙 Dejar que la pantalla se apague
+more-keep-awake-enable-btn = ☀️ Mantener la pantalla encendida
+more-flow-section = 🔁 Modo encadenado
+more-flow-desc = Al terminar el descanso tras un ejercicio, inicia automáticamente el siguiente ejercicio pendiente, con los valores de su última serie. Práctico para circuitos.
+more-flow-enable-btn = 🔁 Encadenar ejercicios
+more-flow-disable-btn = ✋ Iniciar los ejercicios a mano
+more-quiet-section = 📳 Modo silencioso
+more-quiet-desc = Las alertas de descanso y de duración solo vibran, sin ningún sonido: para el gimnasio o las horas de silencio. Las notificaciones en el ordenador son silenciosas.
+more-quiet-enable-btn = 📳 Solo vibrar
+more-quiet-disable-btn = 🔔 Reproducir sonidos de alerta
+more-media-controls-section = 🎵 Descanso en los controles multimedia
+more-media-controls-desc = Mostrar la cuenta atrás del descanso en la pantalla de bloqueo y en los controles multimedia del navegador, cuyos botones de reproducir y pausar alargan u omiten el descanso. Los navegadores solo los muestran mientras suena audio, así que se reproduce un sonido silencioso durante los descansos, lo que puede pausar tu música.
+more-media-controls-enable-btn = 🎵 Mostrar el descanso en los controles multimedia
+more-media-controls-disable-btn = 🔇 No usar los controles multimedia
+tour-section = 💡 Recorrido de la sesión
+tour-desc = Consejos que explican la vista de sesión, mostrados las primeras veces que se abre.
+tour-restart-btn = 💡 Ver el recorrido de nuevo
+tour-restarted = 💡 El recorrido se mostrará en la vista de sesión
+tour-timer = Toca los cronómetros para ajustar la duración del descanso entre series.
+tour-pending = Los ejercicios previstos para la sesión esperan aquí: toca 🔁 para empezar uno.
+tour-complete-cancel = 💾 registra la serie e inicia el descanso, ❌ cancela el ejercicio sin registrarlo.
+tour-got-it = Entendido
+tour-skip = Saltar el recorrido
+rest-skip-section = ⏭️ Sin descanso después
+rest-skip-desc = No se inicia ningún temporizador de descanso tras un ejercicio de las categorías elegidas, por ejemplo tras una carrera larga o unos estiramientos.
+body-weight-section = ⚖️ Peso corporal
+body-weight-desc = Se usa en los ejercicios con peso corporal: la carga añadida y la asistencia se suman o restan en los análisis y las estimaciones de 1RM.
+body-weight-save-aria = Guardar peso corporal
+equipment-profiles-section = 🏠 Perfiles de equipamiento
+equipment-profiles-desc = Define el equipamiento disponible en cada uno de tus lugares de entrenamiento. Al elegir un perfil junto a la búsqueda de ejercicios solo se muestran los ejercicios realizables con su equipamiento; los ejercicios con el peso corporal siempre se muestran.
+equipment-profile-name-placeholder = Nombre del perfil (p. ej. Casa)
+equipment-profile-add = Añadir perfil
+equipment-profile-delete = Eliminar perfil
+equipment-profile-select-title = Perfil de equipamiento
+equipment-profile-all = 🏋️ Todo el equipamiento
+locations-section = 📍 Lugares
+locations-desc = Guarda los gimnasios y lugares donde entrenas y asigna uno a cada sesión desde la página de sesión. Las estadísticas pueden limitarse a un lugar y los campos se rellenan con tus últimas series en el mismo sitio.
+location-name-placeholder = Nombre del lugar (p. ej. Gimnasio del centro)
+location-add = Añadir lugar
+location-remove = Quitar lugar
+location-select-title = Lugar de la sesión
+location-none = 📍 Sin lugar
+weekly-sets-section = 🎯 Objetivos semanales de series
+weekly-sets-target = { $muscle }: { $sets } series por semana
+weekly-sets-muscle-label = Músculo
+weekly-sets-sets-label = Series efectivas por semana
+weekly-sets-placeholder = Series
+weekly-sets-add = Fijar el objetivo semanal
+weekly-sets-remove = Eliminar este objetivo
+avoid-section = 🩹 Modo lesión
+avoid-desc = Indica los movimientos a evitar mientras se cura una lesión, p. ej. hombros en empuje. Durante las fechas elegidas, los ejercicios que trabajan ese músculo con ese movimiento se marcan con ⚠️ en la búsqueda, o se ocultan.
+avoid-any-muscle = Cualquier músculo
+avoid-any-force = Cualquier movimiento
+avoid-muscle-label = Músculo a evitar
+avoid-force-label = Movimiento a evitar
+avoid-from-label = Desde
+avoid-until-label = Hasta
+avoid-add = Añadir movimiento a evitar
+avoid-remove = Quitar
+avoid-hide-btn = 🙈 Ocultar los ejercicios marcados
+avoid-warn-btn = ⚠️ Solo avisar de los ejercicios marcados
+lock-section = 🔒 Bloqueo
+lock-desc = Pedir un código al iniciar. Las notas de sesión, rutas GPS y datos de frecuencia cardíaca se guardan cifrados con una clave derivada del código. Un código olvidado no se puede recuperar.
+lock-title = 🔒 LogOut está bloqueado
+lock-passcode-placeholder = Código
+lock-confirm-placeholder = Confirmar código
+lock-unlock-aria = Desbloquear
+lock-enable-btn = 🔒 Activar bloqueo
+lock-disable-btn = 🔓 Desactivar bloqueo
+lock-enabled = 🔒 Bloqueo activado
+lock-disabled = 🔓 Bloqueo desactivado
+lock-mismatch = ⚠️ Los códigos no coinciden
+lock-too-short = ⚠️ El código debe tener al menos 4 caracteres
+lock-wrong-passcode = ❌ Código incorrecto
+sync-section = 🔄 Sincronización cifrada
+sync-desc = Sincroniza sesiones y ejercicios personalizados con tu propio servidor. Los datos se cifran en este dispositivo con tu frase secreta; el servidor nunca los ve. Usa la misma URL y frase en cada dispositivo.
+sync-passphrase-placeholder = Frase secreta
+sync-now-aria = Sincronizar ahora
+sync-done = Sincronizado
+sync-failed = ❌ Error de sincronización
+transfer-section = 📲 Transferir a un nuevo dispositivo
+transfer-desc = Mueve todas las sesiones y ejercicios personalizados directamente a otro dispositivo de la misma red. Sin cuenta ni nube.
+transfer-send-btn = 📤 Enviar desde este dispositivo
+transfer-receive-btn = 📥 Recibir en este dispositivo
+transfer-show-code = Escanea este código con el otro dispositivo:
+transfer-scan-hint = Escanea o pega el código mostrado en el otro dispositivo.
+transfer-paste-placeholder = Código de emparejamiento
+transfer-scan-aria = Escanear código QR
+transfer-submit-aria = Confirmar
+transfer-sent = sesión(es) enviada(s)
+transfer-received = sesión(es) recibida(s)
+transfer-failed = ❌ Error de transferencia
+transfer-timeout = ⏱️ El otro dispositivo no se conectó o dejó de responder
 more-oss-section = Código abierto y licencias
 more-oss-desc-a = Este proyecto es de código abierto bajo la licencia GPL-3.0 y utiliza otros proyectos de código abierto. Consulta su
 more-oss-repo-link = repositorio de código
@@ -117,6 +423,12 @@ more-built-with-rust = Lenguaje de programación de sistemas
 more-built-with-dioxus = Framework Rust para aplicaciones multiplataforma
 more-built-with-freeexdb = Datos e imágenes de ejercicios, por yuhonas
 more-built-with-others = Y muchos más …
+credits-section = 📜 Versiones y fuentes de datos
+credits-license = Licencia: { $license }
+credits-schema = Versión del esquema de almacenamiento: { $version }
+credits-db-source = Base de ejercicios:
+credits-last-fetch = Última descarga: { $time }
+credits-never = nunca
 more-privacy-section = 🔒 Política de privacidad
 more-privacy-desc = LogOut no recopila, comparte ni transmite ningún dato personal. Todos los datos de entrenamiento se almacenan exclusivamente en tu dispositivo. La base de datos de ejercicios se descarga desde una URL configurable de tu elección. Sin analíticas, sin cuentas, sin rastreo.
 more-replace-confirm = ¿Reemplazar el ejercicio personalizado { $name }?
@@ -124,18 +436,162 @@ more-replace-btn = 💾 Reemplazar
 more-sessions-refused = sesión/sesiones rechazada(s): ID ya existe
 more-exercises-refused = ejercicio(s) rechazado(s): conflicto con ID integrado
 
+## Página Papelera
+trash-title = 🗑️ Papelera
+trash-description = Las sesiones y ejercicios personalizados eliminados se pueden restaurar durante { $days } días.
+trash-empty = La papelera está vacía.
+trash-days-left = Se eliminará definitivamente en { $count } días
+trash-restore-btn = ♻️ Restaurar
+trash-restored = ♻️ Restaurado
+trash-delete-title = Eliminar definitivamente
+
+## Diálogo de confirmación
+confirm-cancel = Cancelar
+confirm-discard-btn = 🗑️ Descartar
+confirm-cancel-session = ¿Cancelar esta sesión? Aún no se ha registrado nada.
+confirm-discard-exercise = ¿Descartar el ejercicio en curso?
+
+## Pantalla de error
+error-title = 💥 Algo salió mal
+error-desc = LogOut encontró un error inesperado. Tus datos guardados están a salvo; vuelve a intentarlo, recarga la aplicación o exporta un diagnóstico para adjuntarlo a un informe de errores.
+error-retry-btn = 🔄 Reintentar
+error-reload-btn = ♻️ Recargar
+error-export-btn = 📤 Exportar diagnóstico
+
+## Página de almacenamiento
+storage-title = 💽 Almacenamiento
+storage-desc = Espacio ocupado en este dispositivo por cada parte de los datos de la aplicación.
+storage-sessions = 📅 Sesiones
+storage-custom-exercises = 📋 Ejercicios personalizados
+storage-exercises = 📦 Base de ejercicios
+storage-trash = 🗑️ Papelera
+storage-images = 🖼️ Imágenes
+storage-other = 🧩 Otros (índices, cachés, archivos de la aplicación)
+storage-total = Total: { $used }
+storage-total-quota = Total: { $used } de { $quota } disponibles
+storage-persistent = 🔒 Almacenamiento persistente: el navegador no lo borrará
+storage-not-persistent = ⚠️ Almacenamiento no persistente: el navegador puede borrarlo cuando falte espacio. Instalar la aplicación o exportar tus sesiones con regularidad ayuda.
+storage-persist-btn = 🔒 Pedir conservar el almacenamiento
+storage-clear-images-btn = 🧹 Vaciar la caché de imágenes
+storage-images-cleared = 🧹 Caché de imágenes vaciada, se descargarán de nuevo
+storage-prefetch-desc = Las imágenes se cargan a medida que aparecen. Las de los ejercicios realizados se guardan sin conexión; la precarga las descarga por adelantado (en Android y escritorio, todas las imágenes de la base de ejercicios).
+storage-prefetch-aria = Precarga de imágenes
+storage-prefetch-off = 🚫 Nunca precargar las imágenes
+storage-prefetch-wifi-only = 📶 Precargar las imágenes solo con Wi-Fi
+storage-prefetch-always = 🌐 Precargar siempre las imágenes
+storage-compact-btn = 🗜️ Compactar la base de datos
+storage-compacted = 🗜️ Base de datos compactada
+storage-failed = ⚠️ Error de la operación de almacenamiento: { $error }
+
+## Copia de seguridad en la nube
+cloud-backup-section = ☁️ Copia en la nube
+cloud-backup-desc = Vincula una cuenta en la nube para subir tus sesiones y ejercicios personalizados a la carpeta de la aplicación tras cada sesión terminada. Los archivos subidos están cifrados.
+cloud-backup-link-btn = 🔗 Vincular { $provider }
+cloud-backup-linked = ☁️ Vinculado a { $provider }
+cloud-backup-last-upload = última subida el { $date }
+cloud-backup-upload-btn = ⬆️ Subir ahora
+cloud-backup-unlink-btn = Desvincular
+cloud-backup-uploaded = ☁️ Copia subida
+cloud-backup-failed = ⚠️ Error de la copia en la nube: { $error }
+cloud-backup-passphrase-desc = Las copias subidas se cifran con esta frase de contraseña. Impórtalas con la misma frase para restaurarlas. No se puede recuperar si se olvida.
+cloud-backup-passphrase-placeholder = Frase de contraseña de la copia
+cloud-backup-passphrase-required = ⚠️ Copia en la nube omitida: define primero una frase de contraseña
+training-plan-section = Plan de entrenamiento
+training-plan-desc = Importa un plan escrito por tu entrenador (archivo JSON) para empezar sus días con un toque y exportar un informe semanal de cumplimiento.
+training-plan-import-btn = Importar un plan
+training-plan-imported = Plan «{ $name }» importado
+training-plan-invalid = Plan no válido: { $error }
+training-plan-author = Por { $author }
+training-plan-per-week = { $count } sesiones por semana
+training-plan-start-btn = Empezar
+training-plan-report-btn = Exportar informe de cumplimiento
+training-plan-report-failed = No se pudo crear el informe: { $error }
+training-plan-export-btn = Exportar plan
+training-plan-remove-btn = Quitar plan
+
+## Webhook
+webhook-section = 🪝 Webhook
+webhook-desc = Envía un resumen JSON de cada sesión terminada a esta URL, para que tu domótica (Home Assistant, Node-RED, un puente MQTT…) reaccione cuando termines de entrenar.
+webhook-save-aria = Guardar la URL del webhook
+webhook-saved = 🪝 Webhook guardado
+webhook-test-btn = 📤 Enviar una prueba
+webhook-test-sent = 🪝 Prueba enviada
+webhook-failed = ⚠️ Error del webhook: { $error }
+
+## API local
+local-api-section = 🔌 API local
+local-api-desc = Sirve tus sesiones y estadísticas en JSON solo en este ordenador, para tus propios paneles y hojas de cálculo. La API es de solo lectura salvo que se permita la escritura.
+local-api-port-aria = Puerto
+local-api-start-btn = ▶️ Iniciar
+local-api-stop-btn = ⏹️ Detener
+local-api-running = 🔌 Escuchando en { $url }
+local-api-writes = Permitir añadir sesiones mediante la API
+local-api-failed = ⚠️ Error de la API local: { $error }
+global-hotkey-section = Atajo global
+global-hotkey-desc = Trae LogOut al frente desde cualquier aplicación, iniciando una sesión si no hay ninguna en curso, por ejemplo al dejar el ordenador para entrenar.
+global-hotkey-enabled = Activar el atajo global
+global-hotkey-aria = Atajo global, p. ej. CmdOrCtrl+Shift+L
+global-hotkey-save-btn = Guardar
+global-hotkey-invalid = ⚠️ Atajo no válido: { $accelerator }
+
+## Página Logros
+achievements-title = 🏆 Logros
+achievements-desc = Insignias ganadas entrenando con regularidad, conservadas aunque se eliminen las sesiones correspondientes.
+achievement-sessions = { $count } sesión(es) completada(s)
+achievement-weekly-volume = { $kg } kg levantados en una semana
+achievement-streak = Racha de { $weeks } semanas
+achievement-unlocked = 🏆 Insignia desbloqueada: { $badge }
+achievement-unlocked-at = Desbloqueada el { $date }
+
 ## Mensajes toast (prefijo estático; el detalle técnico se añade en tiempo de ejecución)
 toast-export-failed = ⚠️ Error al exportar
 toast-export-sessions-failed = ⚠️ Error al exportar sesiones
 toast-sessions-invalid = ⚠️ JSON de sesiones no válido
 toast-exercises-invalid = ⚠️ JSON de ejercicios no válido
 toast-screen-locked = 🔒 Pantalla bloqueada — solo se permiten ediciones de la sesión activa
+toast-session-save-delayed = ⚠️ Guardado de la sesión retrasado, se reintentará
+toast-session-save-failed = ⚠️ No se pudo guardar la sesión
+toast-session-delete-delayed = ⚠️ Eliminación de la sesión retrasada, se reintentará
+toast-session-delete-failed = ⚠️ No se pudo eliminar la sesión
+toast-exercise-save-delayed = ⚠️ Guardado del ejercicio retrasado, se reintentará
+toast-exercise-save-failed = ⚠️ No se pudo guardar el ejercicio
+toast-exercise-delete-delayed = ⚠️ Eliminación del ejercicio retrasada, se reintentará
+toast-exercise-delete-failed = ⚠️ No se pudo eliminar el ejercicio
+toast-save-delayed = ⚠️ Guardado retrasado, se reintentará
+toast-save-failed = ⚠️ No se pudo guardar
+active-conflict-title = ⚠️ Varias sesiones en curso
+active-conflict-desc = { $count } sesiones están en curso a la vez y solo se muestra una. Fusiónalas en la más antigua o termina las que ya no necesites.
+active-conflict-logs = { $count } ejercicios
+active-conflict-finish-btn = 🏁 Terminar
+active-conflict-merge-btn = 🔗 Fusionar en una sesión
+toast-session-already-active = ⚠️ Ya hay una sesión en curso, termínala primero
 db-empty-toast = 📥 Base de datos de ejercicios vacía — toca para descargar
+db-download-failed-toast = ❌ Error al descargar la base de datos de ejercicios — toca para reintentar
+update-available-toast = 🆕 Actualización disponible — toca para recargar
+install-banner = 📲 Instala LogOut para acceder con un toque, incluso sin conexión
+install-banner-dismiss-aria = No volver a mostrar
+persistence-banner = ⚠️ El navegador puede borrar tu historial cuando falte espacio — toca para conservarlo
+persistence-banner-dismiss-aria = Ocultar hasta el próximo inicio
 
 ## Etiquetas de fecha relativa
 date-today = Hoy
 date-yesterday = Ayer
 date-days-ago = Hace { $count } día{ $count ->
+date-group-this-week = Esta semana
+date-group-last-week = La semana pasada
+date-group-month = { $month } de { $year }
+month-january = Enero
+month-february = Febrero
+month-march = Marzo
+month-april = Abril
+month-may = Mayo
+month-june = Junio
+month-july = Julio
+month-august = Agosto
+month-september = Septiembre
+month-october = Octubre
+month-november = Noviembre
+month-december = Diciembre
     [one] {""}
    *[other] s
 }
@@ -148,12 +604,43 @@ notif-duration-title = Duración alcanzada
 notif-duration-body = ¡Duración récord personal del ejercicio alcanzada!
 notif-rest-title = Descanso terminado
 notif-rest-body = ¡Es hora de tu próxima serie!
+media-rest-title = 🛋️ Descanso
+media-rest-next = Siguiente: { $exercise }
+timer-session-title = Entrenamiento en curso
+tray-complete = Completar ejercicio
+tray-finish = Finalizar sesión
+launcher-start-session = Nueva sesión
+launcher-last-session = Última sesión
 
 ## Página de estadísticas
 analytics-title = 📊 Estadísticas
 analytics-subtitle = Sigue tu progreso a lo largo del tiempo
 analytics-pairs-label = Pares métrica–ejercicio (⩽ 8)
+analytics-location-title = Solo sesiones en este lugar
+analytics-location-all = 📍 Todos los lugares
 analytics-empty = Selecciona ejercicios para ver las estadísticas
+analytics-asymmetry-title = ↔️ Equilibrio izquierda / derecha
+analytics-asymmetry-row = izquierda { $left }, derecha { $right } ({ $gap } % a la izquierda)
+analytics-one-rep-max-title = 🏋️ 1RM estimado
+analytics-one-rep-max-desc = Estimación de Epley a partir de la mejor serie, peso corporal incluido en los ejercicios con peso corporal
+analytics-tags-title = 🏷 Etiquetas personalizadas, últimos { $days } días
+analytics-tags-sets = { $count } series
+analytics-strain-title = 🔥 Carga de entrenamiento
+analytics-strain-desc = Puntuación de esfuerzo por sesión: minutos por zona cardíaca (TRIMP) si se registró la frecuencia cardíaca, si no un punto por cada 100 kg levantados
+analytics-strain-ratio = Ratio de carga aguda:crónica (últimos 7 días frente a la media semanal de los últimos 28): { $ratio }
+analytics-rest-title = ⏱️ Descanso entre ejercicios
+analytics-rest-desc = Descanso real medio por sesión (barras) frente al descanso previsto para sus ejercicios (línea), sin las pausas de más de 30 minutos
+analytics-rest-overrun = Exceso medio de descanso: { $early } s al inicio de la sesión, { $late } s al final
+analytics-compare-btn = ⚖️ Comparar dos periodos
+analytics-compare-back = 📈 Volver a los gráficos
+analytics-compare-current = Periodo A
+analytics-compare-previous = Periodo B
+analytics-compare-from = Desde
+analytics-compare-to = Hasta
+analytics-compare-invalid = Elige dos rangos de fechas válidos para comparar
+analytics-compare-sessions = Sesiones
+analytics-compare-frequency = Sesiones por semana
+analytics-compare-volume = Volumen
 analytics-metric-weight = Peso (kg)
 analytics-metric-reps = Repeticiones
 analytics-metric-distance = Distancia
@@ -3,16 +3,72 @@
 ## Toute l'appli
 app-title = 💪 LogOut
 app-subtitle = Éteins ton ordinateur, consigne tes entraînements
+nav-aria = Navigation principale
+nav-exercises = Exercices
+nav-sessions = Séances
+nav-analytics = Statistiques
+nav-more = Plus
 
 ## Page d'accueil
 no-sessions = Aucune séance passée
 start-first-workout = Appuie sur + pour démarrer ta première séance
 start-new-workout = Nouvelle séance
 session-repeat-title = Démarrer une nouvelle séance basée sur celle-ci
+templates-title = 📋 Modèles
+templates-desc = Séances enregistrées comme modèles, avec leurs exercices et charges habituelles
+template-start-title = Commencer une séance à partir de ce modèle
+template-delete = Supprimer le modèle
+template-delete-confirm = Supprimer le modèle « { $name } » ?
+template-save-title = Enregistrer comme modèle
+template-name-placeholder = Nom du modèle
+template-saved = 📋 Modèle « { $name } » enregistré
+calendar-title = 📅 Calendrier d’entraînement
+calendar-open-title = Calendrier d’entraînement
+calendar-previous-month = Mois précédent
+calendar-next-month = Mois suivant
+calendar-weekdays = Lu Ma Me Je Ve Sa Di
+calendar-day-heading = { $day } { $month }
+calendar-day-done = ✅ Entraînement fait
+calendar-day-missed = ⚠️ Entraînement prévu manqué
+calendar-start-btn = ▶️ Commencer
+calendar-unassign-aria = Retirer du calendrier
+calendar-assign-aria = Prévoir cet entraînement ce jour-là
+calendar-no-choices = Enregistrez une séance comme modèle, ou importez un programme, pour prévoir des entraînements.
 session-repeat-weekday-title = Répéter la séance du même jour de la semaine
 session-delete-title = Supprimer la séance
+share-image-btn-title = Partager une image de cette séance
+share-image-title = Séance du { $date }
+share-image-duration = durée
+share-image-volume = volume
+share-image-sets = séries
+share-image-failed = ⚠️ Échec du partage : { $error }
+share-link-btn-title = Partager un lien vers cette séance
+share-link-aria = Lien vers cette séance
+share-link-too-long = Cette séance est trop longue pour un code QR, envoyez plutôt le lien.
+share-link-imported = 📥 Séance partagée importée
+share-link-invalid = ⚠️ Séance partagée invalide : { $error }
+share-link-import-section = 📥 Importer une séance partagée
+share-link-import-desc = Collez le lien ou le code d’une séance reçue d’un ami pour ajouter une copie de cet entraînement à votre historique.
+share-link-import-placeholder = Lien ou code de séance
+share-link-import-aria = Importer la séance partagée
 session-resume-last-title = Reprendre la dernière séance
 session-show-more = +{ $count } autres
+session-stats-exercises = 🏋️ { $count } exercices
+history-search-placeholder = 🔍 Exercice, muscle ou note…
+recovery-title = 🔋 Fraîcheur musculaire
+recovery-desc = Estimée d'après les séries des { $days } derniers jours : chaque série fatigue ses muscles, qui récupèrent avec le temps. Sous { $ready } %, un muscle est encore en récupération.
+recovery-tired-title = Sollicite un muscle encore en récupération
+weekly-sets-title = 🎯 Séries effectives cette semaine
+weekly-sets-desc = Séries de travail enregistrées depuis lundi (échauffements exclus) pour chaque muscle principal de l’exercice, comparées à l’objectif hebdomadaire de ce muscle.
+history-search-from-aria = Date de début
+history-search-to-aria = Date de fin
+history-search-clear = Effacer la recherche
+history-search-no-match = Aucune séance ne correspond à cette recherche.
+session-select-title = Sélectionner des séances
+session-select-cancel = Annuler la sélection
+session-archive-selected-title = Archiver les séances sélectionnées (masquées ici, toujours comptées dans les statistiques)
+session-delete-selected-title = Supprimer les séances sélectionnées
+session-unarchive-title = Afficher à nouveau cette séance dans l'historique
 hold-to-delete-hint = Maintenir 3s pour supprimer
 session-delete-confirm = Supprimer cette séance ?
 session-delete-confirm-btn = 🗑️ Supprimer
@@ -20,6 +76,14 @@ cancel-btn = ❌ Annuler
 
 ## Page des exercices
 browse-exercises = { $count } exercices disponibles
+exercises-db-fetched = Base mise à jour : { $when }
+exercises-db-never-fetched = Base jamais téléchargée
+exercises-db-refresh-title = Actualiser la base d’exercices
+exercises-db-refreshing = Téléchargement de la base d’exercices…
+exercises-db-downloading = { $received } ko reçus
+exercises-db-retrying = (nouvel essai { $attempt } sur { $max })
+exercises-db-failed = Échec du téléchargement : { $error }
+exercises-db-retry = Réessayer
 search-placeholder = Rechercher des exercices par noms ou attributs
 add-exercise = Ajouter un exercice personnalisé
 filter-add = Activer le filtre
@@ -28,17 +92,61 @@ filter-remove = Supprimer le filtre
 ## Fiche exercice
 exercise-edit = Modifier
 exercise-clone = Dupliquer puis modifier
+exercise-hide = Masquer cet exercice des listes et de la recherche
+exercise-hidden-toast = 🙈 Exercice masqué, réaffichez-le depuis la page Plus
+exercise-offline-keep = Garder les images de cet exercice hors ligne
+exercise-offline-forget = Ne plus garder les images de cet exercice hors ligne
+exercise-offline-toast = 📥 Images gardées hors ligne, elles sont en cours de téléchargement
+exercise-tag-add = + Étiquette personnalisée…
+exercise-tag-remove = Retirer cette étiquette
+avoid-flag-title = Sollicite un mouvement que vous évitez (mode blessure)
+exercise-video-title = Vidéo de technique
+exercise-video-placeholder = https://youtube.com/watch?v=…
+exercise-rest-aria = Définir le repos après une série de cet exercice
+exercise-video-add = Ajouter mon lien de vidéo de technique
+exercise-video-edit = Changer mon lien de vidéo de technique
+exercise-video-remove = Retirer mon lien de vidéo
+exercise-alias-placeholder = Mon nom pour cet exercice (ex. dév couché)
+exercise-alias-add = Ajouter un alias de recherche
+goal-weight-label = Poids visé (kg)
+goal-reps-label = Répétitions visées
+goal-reps = { $reps } répétitions
+goal-deadline-label = À atteindre avant le (facultatif)
+goal-by = { $target } d'ici le { $deadline }
+goal-add = Fixer cet objectif
+goal-remove = Supprimer cet objectif
+goal-achieved = 🎉 Objectif atteint : { $exercise } { $target } !
+plateau-notice = Aucun progrès sur les { $sessions } dernières séances : essayez une semaine allégée (environ 10 % plus léger) ou une variante de cet exercice.
+plateau-toast = 📉 { $exercise } : aucun progrès sur les { $sessions } dernières séances, envisagez une décharge ou une variante
+exercise-alias-remove = Retirer cet alias
 
 ## Séance active – notes
 session-notes-placeholder = Notes de séance (Markdown supporté)…
 session-notes-unfold = 📝 Déplier les notes de séance
+session-notes-add-title = Ajouter des notes à cette séance
+session-notes-edit-title = Modifier les notes de séance
+session-notes-save = 💾 Enregistrer les notes
 
 ## Séance active – recherche
 session-search-placeholder = Rechercher un exercice...
 session-add-exercise-title = Ajouter un exercice personnalisé
+session-pick-title = Sélectionner des exercices à planifier
+session-pick-cancel = Annuler la sélection
+session-pick-add-title = Ajouter les exercices sélectionnés à ceux en attente
+session-pick-added = ⏳ { $count } exercices ajoutés à ceux en attente
+session-browse-title = Parcourir les exercices par muscle
+session-browse-aria = Exercices par muscle
+search-match-alias = Alias :
+search-match-other-language = Aussi appelé :
+search-match-muscle = Muscle :
+search-match-instructions = Trouvé dans les instructions
 session-filter-remove = Supprimer le filtre
 session-filter-add = Ajouter le filtre
+session-search-results-aria = Exercices correspondants
+session-search-results-count = { $count } exercices correspondants
 pending-more = Plus en attente ({ $count })
+pending-sets-left = { $count } séries restantes
+pending-target-reps = 🎯 { $count } répétitions
 
 ## Séance active – en-tête
 session-title = ⏱️ Séance active
@@ -57,7 +165,11 @@ completed-exercises-title = Exercices complétés
 
 ## Formulaire d'exercice
 exercise-complete-title = Valider l'exercice
+exercise-same-as-last-btn = 🔁 Comme la dernière
+exercise-same-as-last-title = Valide la série tout de suite avec le poids, les répétitions et la distance de la dernière
 time-placeholder = mm:ss
+target-duration-title = Durée visée du maintien, décomptée par le chronomètre
+target-duration-placeholder = Objectif (mm:ss)
 weight-placeholder = kg
 distance-placeholder = km
 reps-placeholder = rép.
@@ -65,25 +177,52 @@ reps-placeholder = rép.
 ## Journal d'exercice complété
 log-replay-title = Faire une autre série
 log-edit-title = Modifier cet exercice
+log-notes-placeholder = Notes sur cette série (Markdown pris en charge)…
+assisted-toggle-title = Basculer entre charge ajoutée (➕) et assistance (➖)
+side-toggle-title = Côté travaillé (mouvements unilatéraux)
+side-both = ↔️ Deux côtés
+side-left = ⬅️ Gauche
+side-right = ➡️ Droite
+set-kind-toggle-title = Type de série (les échauffements ne comptent ni dans le volume ni dans les records)
+set-kind-normal = 💪 Série de travail
+set-kind-warm-up = 🔥 Échauffement
+set-kind-dropset = 📉 Série dégressive
+set-kind-amrap = 🔁 Max de répétitions
+set-kind-failure = 💥 Jusqu'à l'échec
 log-delete-title = Supprimer cet exercice
+log-deleted = 🗑️ Exercice supprimé
+log-start-label = Heure de début
+log-end-label = Heure de fin
+log-end-before-start = L'heure de fin ne peut pas précéder l'heure de début
+log-start-taken = Un autre exercice de la séance a commencé à cette heure-là
+log-export-gpx-title = Exporter en GPX (Garmin, Strava…)
+log-export-tcx-title = Exporter en TCX (Garmin Connect)
 
 ## Pages ajout / modification d'exercice
 add-exercise-page-title = Ajouter un exercice
 edit-exercise-page-title = Modifier l'exercice
+exercise-delete-title = Supprimer l'exercice
 exercise-not-found = Exercice introuvable
 exercise-save = Enregistrer l'exercice
 exercise-save-changes = Enregistrer les modifications
+exercise-export-upstream-btn = 📤 Exporter en JSON exercise-db
+exercise-export-upstream-title = Exporter au format free-exercise-db pour proposer cet exercice à la base (ajoutez ses images aux chemins indiqués)
 cancel-title = Annuler
 
 ## Champs du formulaire d'exercice
 form-name-label = Nom de l'exercice *
 form-category-label = Catégorie *
 form-force-label = Type de force
+form-level-label = Niveau
+form-mechanic-label = Mécanique
 form-equipment-label = Équipement
 form-muscles-primary-label = Muscles principaux
 form-muscles-secondary-label = Muscles secondaires
 form-instructions-label = Instructions
 form-images-label = Images
+form-videos-label = Vidéos de technique
+form-rest-label = Repos après une série (s)
+form-rest-placeholder = Durée de repos par défaut
 form-name-placeholder = Pompes
 form-muscle-select-default = Sélectionner un muscle...
 form-instruction-placeholder = Ajouter une étape d'instruction...
@@ -93,15 +232,29 @@ form-image-upload-title = Téléverser une image locale
 form-local-image-placeholder = /chemin/vers/image.jpg
 form-local-image-title = Chemin vers un fichier image local (sera copié dans le stockage de l'application)
 form-save-aria = Enregistrer
+form-draft-restored = 📝 Modifications non enregistrées restaurées
+form-draft-discard-btn = Abandonner
+form-error-empty-name = Donnez un nom à l’exercice
+form-error-duplicate-name = Un exercice personnalisé porte déjà ce nom
+form-error-no-primary-muscle = Ajoutez au moins un muscle principal
 
 ## Page Plus
 more-title = ⚙️ Plus
 more-export-section = 📤 Exporter
 more-export-exercises-btn = 💾 { $count } Exercices personnalisés
 more-export-sessions-btn = 💾 { $count } Séances
+more-export-calendar-btn = 📅 Calendrier (.ics)
+ics-event-summary = 🏋️ Séance
 more-import-section = 📥 Importer
 more-import-exercises-btn = 📂 Exercices personnalisés
 more-import-sessions-btn = 📂 Séances
+backup-passphrase-section = 🔐 Chiffrement des sauvegardes
+backup-passphrase-desc = Avec une phrase secrète, les séances et exercices personnalisés exportés sont chiffrés et peuvent être stockés sans risque dans un cloud. Importer une sauvegarde chiffrée demande la même phrase secrète, impossible à récupérer en cas d’oubli.
+backup-passphrase-placeholder = Phrase secrète (facultative)
+backup-open-failed = ⚠️ Impossible d’ouvrir la sauvegarde : { $error }
+more-trash-btn = Ouvrir la corbeille
+more-achievements-btn = Voir les succès
+more-storage-btn = Voir l’espace de stockage
 more-about-section = LogOut
 more-about-desc-a = Une application simple, efficace et multiplateforme de suivi d'entraînement avec
 more-about-exercises-link = 800+ exercices
@@ -111,6 +264,155 @@ more-db-url-desc = Remplace la source de données d'exercices. Enregistre pour t
 more-db-url-save-aria = Enregistrer
 more-db-exercises-count = 📦 { $count } exercices
 more-db-images-count = 🖼️ { $count } images
+more-db-refresh-btn = 🔄 Actualiser maintenant
+more-db-url-reset-btn = Rétablir par défaut
+exercise-sources-section = ➕ Sources d'exercices supplémentaires
+exercise-sources-desc = Fusionnez les exercices d'autres bases au même format. Leurs identifiants sont préfixés par la source indiquée à côté pour éviter les conflits.
+exercise-sources-add-aria = Ajouter la source
+exercise-sources-remove-aria = Retirer la source
+language-pack-section = 🌐 Pack de langue des exercices
+language-pack-desc = Traduisez les noms et instructions des exercices avec des fichiers nommés exercises.<langue>.json (entrées avec un id, un nom et des instructions) à cette adresse. La langue de l’application choisit la traduction.
+language-pack-save-aria = Enregistrer l’adresse du pack de langue
+language-pack-remove-aria = Retirer le pack de langue
+merge-exercises-section = 🔀 Fusionner des exercices en double
+merge-exercises-desc = Transférez tout l'historique d'un exercice personnalisé vers un autre (par exemple sa version de la base), puis placez le doublon dans la corbeille.
+merge-exercises-from-aria = Exercice en double
+merge-exercises-from-placeholder = Exercice personnalisé en double…
+merge-exercises-into-aria = Exercice à conserver
+merge-exercises-into-placeholder = Exercice à conserver…
+merge-exercises-btn = Fusionner
+merge-exercises-confirm = Transférer toutes les séries de « { $from } » vers « { $into } » et supprimer « { $from } » ?
+merge-exercises-done = 🔀 Fusion faite, { $count } séances mises à jour
+merge-exercises-failed = Échec de la fusion : { $error }
+sample-data-section = 🧪 Données d’exemple
+sample-data-desc = Chargez { $weeks } semaines de séances d’exemple pour explorer les analyses avant d’avoir votre propre historique, puis supprimez-les en un clic.
+sample-data-load-btn = Charger les données d’exemple
+sample-data-loaded = 🧪 { $count } séances d’exemple chargées
+sample-data-purge-btn = Supprimer les données d’exemple
+sample-data-purge-confirm = Supprimer toutes les séances d’exemple ? Vos propres séances sont conservées.
+sample-data-purged = 🧹 { $count } séances d’exemple supprimées
+sample-data-purge-failed = Échec de la suppression : { $error }
+hidden-exercises-section = 🙈 Exercices masqués
+hidden-exercises-desc = Ces exercices n'apparaissent ni dans la liste des exercices ni dans la recherche en séance.
+hidden-exercises-show-btn = Afficher
+taxonomy-section = 🏷 Catégories, équipements et muscles personnalisés
+taxonomy-desc = Ajoutez ce qui manque aux listes intégrées (escalade, traîneau…), puis étiquetez les exercices depuis leur carte. Les étiquettes servent de filtres de recherche et sont comptées dans les statistiques.
+taxonomy-kind-aria = Type
+taxonomy-kind-category = Catégorie
+taxonomy-kind-equipment = Équipement
+taxonomy-kind-muscle = Muscle
+taxonomy-placeholder = Nom
+taxonomy-add-aria = Ajouter
+taxonomy-remove = Supprimer cette valeur ({ $kind }) de tous les exercices
+taxonomy-invalid = ⚠️ Nom vide, déjà défini ou intégré
+more-language-section = 🌐 Langue
+more-language-system = Langue du système
+display-section = 🔠 Affichage
+display-desc = Agrandissez le texte et les boutons, et rendez les boutons de séance plus faciles à toucher avec des doigts moites.
+display-scale-aria = Taille du texte
+display-scale-normal = Texte normal
+display-scale-large = Grand texte
+display-scale-extra-large = Très grand texte
+display-large-targets-enable-btn = Agrandir les boutons de séance
+display-large-targets-disable-btn = Boutons de séance normaux
+display-one-handed-title = Place les contrôles de séance et le bouton de validation en bas de l’écran, à portée de pouce, et les agrandit
+display-one-handed-enable-btn = Séance à une main
+display-one-handed-disable-btn = Disposition de séance habituelle
+more-keep-awake-section = ☀️ Garder l'écran allumé
+more-keep-awake-desc = Empêche l'écran de s'éteindre pendant une séance. Le verrou est relâché à la fin de la séance.
+more-keep-awake-disable-btn = 🌙 Laisser l'écran s'éteindre
+more-keep-awake-enable-btn = ☀️ Garder l'écran allumé
+more-flow-section = 🔁 Mode enchaînement
+more-flow-desc = Une fois le repos après un exercice terminé, lance automatiquement l'exercice en attente suivant, avec les valeurs de sa dernière série. Pratique pour les circuits.
+more-flow-enable-btn = 🔁 Enchaîner les exercices
+more-flow-disable-btn = ✋ Lancer les exercices à la main
+more-quiet-section = 📳 Mode silencieux
+more-quiet-desc = Les alertes de repos et de durée vibrent seulement, sans aucun son : pour la salle ou les heures calmes. Les notifications sur ordinateur sont silencieuses.
+more-quiet-enable-btn = 📳 Vibrer seulement
+more-quiet-disable-btn = 🔔 Jouer les sons d'alerte
+more-media-controls-section = 🎵 Repos dans les contrôles multimédias
+more-media-controls-desc = Afficher le décompte du repos sur l'écran de verrouillage et dans les contrôles multimédias du navigateur, dont les boutons lecture et pause prolongent ou passent le repos. Les navigateurs ne les affichent que pendant la lecture d'un son : un son silencieux est donc joué pendant les repos, ce qui peut mettre votre musique en pause.
+more-media-controls-enable-btn = 🎵 Afficher le repos dans les contrôles multimédias
+more-media-controls-disable-btn = 🔇 Laisser les contrôles multimédias tranquilles
+tour-section = 💡 Visite de la séance
+tour-desc = Astuces expliquant la vue de séance, affichées ses premières ouvertures.
+tour-restart-btn = 💡 Revoir la visite
+tour-restarted = 💡 La visite s’affichera dans la vue de séance
+tour-timer = Touchez les chronomètres pour régler la durée de repos entre les séries.
+tour-pending = Les exercices prévus pour la séance attendent ici : touchez 🔁 pour en commencer un.
+tour-complete-cancel = 💾 enregistre la série et lance le repos, ❌ annule l’exercice sans l’enregistrer.
+tour-got-it = Compris
+tour-skip = Passer la visite
+rest-skip-section = ⏭️ Pas de repos après
+rest-skip-desc = Aucun minuteur de repos n'est lancé après un exercice des catégories choisies, par exemple après une longue course ou des étirements.
+body-weight-section = ⚖️ Poids de corps
+body-weight-desc = Utilisé pour les exercices au poids du corps : la charge ajoutée et l'assistance s'y ajoutent ou s'y retranchent dans les analyses et les estimations de 1RM.
+body-weight-save-aria = Enregistrer le poids de corps
+equipment-profiles-section = 🏠 Profils d'équipement
+equipment-profiles-desc = Définissez l'équipement disponible dans chacun de vos lieux d'entraînement. Choisir un profil à côté de la recherche d'exercices n'affiche que les exercices faisables avec son équipement ; les exercices au poids du corps sont toujours affichés.
+equipment-profile-name-placeholder = Nom du profil (ex. Maison)
+equipment-profile-add = Ajouter un profil
+equipment-profile-delete = Supprimer le profil
+equipment-profile-select-title = Profil d'équipement
+equipment-profile-all = 🏋️ Tout l'équipement
+locations-section = 📍 Lieux
+locations-desc = Enregistrez les salles et lieux où vous vous entraînez, puis associez-en un à chaque séance depuis la page de séance. Les statistiques peuvent être limitées à un lieu, et les champs sont préremplis avec vos dernières séries au même endroit.
+location-name-placeholder = Nom du lieu (ex. Salle du centre)
+location-add = Ajouter un lieu
+location-remove = Retirer le lieu
+location-select-title = Lieu de la séance
+location-none = 📍 Aucun lieu
+weekly-sets-section = 🎯 Objectifs hebdomadaires de séries
+weekly-sets-target = { $muscle } : { $sets } séries par semaine
+weekly-sets-muscle-label = Muscle
+weekly-sets-sets-label = Séries effectives par semaine
+weekly-sets-placeholder = Séries
+weekly-sets-add = Définir l’objectif hebdomadaire
+weekly-sets-remove = Supprimer cet objectif
+avoid-section = 🩹 Mode blessure
+avoid-desc = Listez les mouvements à éviter le temps qu'une blessure guérisse, par ex. les épaules en poussée. Pendant les dates choisies, les exercices sollicitant ce muscle avec ce mouvement sont marqués ⚠️ dans la recherche, ou masqués.
+avoid-any-muscle = Tout muscle
+avoid-any-force = Tout mouvement
+avoid-muscle-label = Muscle à éviter
+avoid-force-label = Mouvement à éviter
+avoid-from-label = Du
+avoid-until-label = Au
+avoid-add = Ajouter un mouvement à éviter
+avoid-remove = Retirer
+avoid-hide-btn = 🙈 Masquer les exercices signalés
+avoid-warn-btn = ⚠️ Seulement signaler les exercices
+lock-section = 🔒 Verrouillage
+lock-desc = Demander un code au démarrage. Les notes de séance, traces GPS et données cardiaques sont alors chiffrées avec une clé dérivée de ce code. Un code oublié ne peut pas être récupéré.
+lock-title = 🔒 LogOut est verrouillé
+lock-passcode-placeholder = Code
+lock-confirm-placeholder = Confirmer le code
+lock-unlock-aria = Déverrouiller
+lock-enable-btn = 🔒 Activer le verrouillage
+lock-disable-btn = 🔓 Désactiver le verrouillage
+lock-enabled = 🔒 Verrouillage activé
+lock-disabled = 🔓 Verrouillage désactivé
+lock-mismatch = ⚠️ Les codes ne correspondent pas
+lock-too-short = ⚠️ Le code doit comporter au moins 4 caractères
+lock-wrong-passcode = ❌ Code incorrect
+sync-section = 🔄 Synchronisation chiffrée
+sync-desc = Synchronisez séances et exercices personnalisés avec votre propre serveur. Les données sont chiffrées sur cet appareil avec votre phrase secrète ; le serveur ne les voit jamais. Utilisez la même URL et la même phrase sur chaque appareil.
+sync-passphrase-placeholder = Phrase secrète
+sync-now-aria = Synchroniser
+sync-done = Synchronisé
+sync-failed = ❌ Échec de la synchronisation
+transfer-section = 📲 Transfert vers un nouvel appareil
+transfer-desc = Transférez toutes les séances et exercices personnalisés directement vers un autre appareil du même réseau. Aucun compte ni cloud.
+transfer-send-btn = 📤 Envoyer depuis cet appareil
+transfer-receive-btn = 📥 Recevoir sur cet appareil
+transfer-show-code = Scannez ce code avec l’autre appareil :
+transfer-scan-hint = Scannez ou collez le code affiché sur l’autre appareil.
+transfer-paste-placeholder = Code d’appairage
+transfer-scan-aria = Scanner le QR code
+transfer-submit-aria = Valider
+transfer-sent = séance(s) envoyée(s)
+transfer-received = séance(s) reçue(s)
+transfer-failed = ❌ Échec du transfert
+transfer-timeout = ⏱️ L’autre appareil ne s’est pas connecté ou ne répond plus
 more-oss-section = Open Source & Licences
 more-oss-desc-a = Ce projet est open-source sous licence GPL-3.0 et utilise d'autres projets open-source. Voir son
 more-oss-repo-link = dépôt de code
@@ -121,6 +423,12 @@ more-built-with-rust = Langage de programmation système
 more-built-with-dioxus = Framework Rust pour applications multiplateformes
 more-built-with-freeexdb = Données et images d'exercices, par yuhonas
 more-built-with-others = Et bien d'autres …
+credits-section = 📜 Versions et sources de données
+credits-license = Licence : { $license }
+credits-schema = Version du schéma de stockage : { $version }
+credits-db-source = Base d’exercices :
+credits-last-fetch = Dernière récupération : { $time }
+credits-never = jamais
 more-privacy-section = 🔒 Politique de confidentialité
 more-privacy-desc = LogOut ne collecte, ne partage ni ne transmet aucune donnée personnelle. Toutes les données d'entraînement sont stockées exclusivement sur votre appareil. La base de données d'exercices est téléchargée depuis une URL configurable de votre choix. Pas d'analyse, pas de compte, pas de suivi.
 more-replace-confirm = Remplacer l'exercice personnalisé { $name } ?
@@ -128,18 +436,162 @@ more-replace-btn = 💾 Remplacer
 more-sessions-refused = séance(s) refusée(s) : ID déjà existant
 more-exercises-refused = exercice(s) refusé(s) : conflit avec un ID intégré
 
+## Page Corbeille
+trash-title = 🗑️ Corbeille
+trash-description = Les séances et exercices personnalisés supprimés peuvent être restaurés pendant { $days } jours.
+trash-empty = La corbeille est vide.
+trash-days-left = Supprimé définitivement dans { $count } jours
+trash-restore-btn = ♻️ Restaurer
+trash-restored = ♻️ Restauré
+trash-delete-title = Supprimer définitivement
+
+## Boîte de confirmation
+confirm-cancel = Annuler
+confirm-discard-btn = 🗑️ Abandonner
+confirm-cancel-session = Annuler cette séance ? Rien n’a encore été enregistré.
+confirm-discard-exercise = Abandonner l’exercice en cours ?
+
+## Écran d’erreur
+error-title = 💥 Une erreur est survenue
+error-desc = LogOut a rencontré une erreur inattendue. Vos données enregistrées ne risquent rien ; réessayez, rechargez l’application ou exportez un diagnostic à joindre à un rapport de bug.
+error-retry-btn = 🔄 Réessayer
+error-reload-btn = ♻️ Recharger
+error-export-btn = 📤 Exporter le diagnostic
+
+## Page du stockage
+storage-title = 💽 Stockage
+storage-desc = Espace occupé sur cet appareil par chaque partie des données de l’application.
+storage-sessions = 📅 Séances
+storage-custom-exercises = 📋 Exercices personnalisés
+storage-exercises = 📦 Base d’exercices
+storage-trash = 🗑️ Corbeille
+storage-images = 🖼️ Images
+storage-other = 🧩 Autre (index, caches, fichiers de l’application)
+storage-total = Total : { $used }
+storage-total-quota = Total : { $used } sur { $quota } disponibles
+storage-persistent = 🔒 Stockage persistant : le navigateur ne le supprimera pas
+storage-not-persistent = ⚠️ Stockage non persistant : le navigateur peut le supprimer quand l’espace manque. Installer l’application ou exporter régulièrement vos séances aide.
+storage-persist-btn = 🔒 Demander à conserver le stockage
+storage-clear-images-btn = 🧹 Vider le cache des images
+storage-images-cleared = 🧹 Cache des images vidé, elles seront téléchargées à nouveau
+storage-prefetch-desc = Les images se chargent à mesure qu’elles s’affichent. Celles des exercices réalisés sont gardées hors ligne ; le préchargement les télécharge à l’avance (sur Android et ordinateur, toutes les images de la base d’exercices).
+storage-prefetch-aria = Préchargement des images
+storage-prefetch-off = 🚫 Ne jamais précharger les images
+storage-prefetch-wifi-only = 📶 Précharger les images en Wi-Fi seulement
+storage-prefetch-always = 🌐 Toujours précharger les images
+storage-compact-btn = 🗜️ Compacter la base de données
+storage-compacted = 🗜️ Base de données compactée
+storage-failed = ⚠️ Échec de l’opération de stockage : { $error }
+
+## Sauvegarde dans le cloud
+cloud-backup-section = ☁️ Sauvegarde dans le cloud
+cloud-backup-desc = Liez un compte cloud pour y envoyer vos séances et exercices personnalisés, dans le dossier de l’application, après chaque séance terminée. Les fichiers envoyés sont chiffrés.
+cloud-backup-link-btn = 🔗 Lier { $provider }
+cloud-backup-linked = ☁️ Lié à { $provider }
+cloud-backup-last-upload = dernier envoi le { $date }
+cloud-backup-upload-btn = ⬆️ Envoyer maintenant
+cloud-backup-unlink-btn = Délier
+cloud-backup-uploaded = ☁️ Sauvegarde envoyée
+cloud-backup-failed = ⚠️ Échec de la sauvegarde dans le cloud : { $error }
+cloud-backup-passphrase-desc = Les envois sont chiffrés avec cette phrase secrète. Importez-les avec la même phrase secrète pour les restaurer. Elle est impossible à récupérer en cas d’oubli.
+cloud-backup-passphrase-placeholder = Phrase secrète de sauvegarde
+cloud-backup-passphrase-required = ⚠️ Sauvegarde dans le cloud ignorée : définissez d’abord une phrase secrète
+training-plan-section = Programme d'entraînement
+training-plan-desc = Importez un programme écrit par votre coach (fichier JSON) pour lancer ses séances en un geste et exporter un rapport d'assiduité hebdomadaire.
+training-plan-import-btn = Importer un programme
+training-plan-imported = Programme « { $name } » importé
+training-plan-invalid = Programme invalide : { $error }
+training-plan-author = Par { $author }
+training-plan-per-week = { $count } séances par semaine
+training-plan-start-btn = Commencer
+training-plan-report-btn = Exporter le rapport d'assiduité
+training-plan-report-failed = Impossible de créer le rapport : { $error }
+training-plan-export-btn = Exporter le programme
+training-plan-remove-btn = Retirer le programme
+
+## Webhook
+webhook-section = 🪝 Webhook
+webhook-desc = Envoyez un résumé JSON de chaque séance terminée à cette URL, pour que votre domotique (Home Assistant, Node-RED, une passerelle MQTT…) réagisse à la fin de l’entraînement.
+webhook-save-aria = Enregistrer l’URL du webhook
+webhook-saved = 🪝 Webhook enregistré
+webhook-test-btn = 📤 Envoyer un test
+webhook-test-sent = 🪝 Test envoyé
+webhook-failed = ⚠️ Échec du webhook : { $error }
+
+## API locale
+local-api-section = 🔌 API locale
+local-api-desc = Servez vos séances et statistiques en JSON sur cet ordinateur uniquement, pour vos propres tableaux de bord et tableurs. L’API est en lecture seule sauf si l’écriture est autorisée.
+local-api-port-aria = Port
+local-api-start-btn = ▶️ Démarrer
+local-api-stop-btn = ⏹️ Arrêter
+local-api-running = 🔌 À l’écoute sur { $url }
+local-api-writes = Autoriser l’ajout de séances via l’API
+local-api-failed = ⚠️ Échec de l’API locale : { $error }
+global-hotkey-section = Raccourci global
+global-hotkey-desc = Affichez LogOut au premier plan depuis n’importe quelle application, en démarrant une séance si aucune n’est en cours, par exemple pour quitter l’ordinateur et aller vous entraîner.
+global-hotkey-enabled = Activer le raccourci global
+global-hotkey-aria = Raccourci global, par ex. CmdOrCtrl+Shift+L
+global-hotkey-save-btn = Enregistrer
+global-hotkey-invalid = ⚠️ Raccourci invalide : { $accelerator }
+
+## Page des succès
+achievements-title = 🏆 Succès
+achievements-desc = Badges gagnés en s’entraînant régulièrement, conservés même si les séances correspondantes sont supprimées.
+achievement-sessions = { $count } séance(s) terminée(s)
+achievement-weekly-volume = { $kg } kg soulevés en une semaine
+achievement-streak = { $weeks } semaines d’affilée
+achievement-unlocked = 🏆 Badge débloqué : { $badge }
+achievement-unlocked-at = Débloqué le { $date }
+
 ## Messages toast (préfixe statique ; le détail technique est ajouté à l'exécution)
 toast-export-failed = ⚠️ Échec de l'export
 toast-export-sessions-failed = ⚠️ Échec de l'export des séances
 toast-sessions-invalid = ⚠️ JSON de séances invalide
 toast-exercises-invalid = ⚠️ JSON d'exercices invalide
 toast-screen-locked = 🔒 Écran verrouillé — seules les modifications de la séance active sont autorisées
+toast-session-save-delayed = ⚠️ Enregistrement de la séance retardé, nouvel essai plus tard
+toast-session-save-failed = ⚠️ Échec de l'enregistrement de la séance
+toast-session-delete-delayed = ⚠️ Suppression de la séance retardée, nouvel essai plus tard
+toast-session-delete-failed = ⚠️ Échec de la suppression de la séance
+toast-exercise-save-delayed = ⚠️ Enregistrement de l'exercice retardé, nouvel essai plus tard
+toast-exercise-save-failed = ⚠️ Échec de l'enregistrement de l'exercice
+toast-exercise-delete-delayed = ⚠️ Suppression de l'exercice retardée, nouvel essai plus tard
+toast-exercise-delete-failed = ⚠️ Échec de la suppression de l'exercice
+toast-save-delayed = ⚠️ Enregistrement retardé, nouvel essai plus tard
+toast-save-failed = ⚠️ Échec de l'enregistrement
+active-conflict-title = ⚠️ Plusieurs séances en cours
+active-conflict-desc = { $count } séances sont en cours en même temps, une seule est affichée. Fusionnez-les dans la plus ancienne ou terminez celles dont vous n'avez plus besoin.
+active-conflict-logs = { $count } exercices
+active-conflict-finish-btn = 🏁 Terminer
+active-conflict-merge-btn = 🔗 Fusionner en une séance
+toast-session-already-active = ⚠️ Une séance est déjà en cours, terminez-la d'abord
 db-empty-toast = 📥 Base de données d'exercices vide — appuyez pour télécharger
+db-download-failed-toast = ❌ Échec du téléchargement de la base d'exercices — appuyez pour réessayer
+update-available-toast = 🆕 Mise à jour disponible — touchez pour recharger
+install-banner = 📲 Installez LogOut pour y accéder en un geste, même hors ligne
+install-banner-dismiss-aria = Ne plus afficher
+persistence-banner = ⚠️ Le navigateur peut supprimer votre historique quand l’espace manque — appuyez pour le conserver
+persistence-banner-dismiss-aria = Masquer jusqu’au prochain lancement
 
 ## Étiquettes de date relative
 date-today = Aujourd'hui
 date-yesterday = Hier
 date-days-ago = Il y a { $count } jour{ $count ->
+date-group-this-week = Cette semaine
+date-group-last-week = La semaine dernière
+date-group-month = { $month } { $year }
+month-january = Janvier
+month-february = Février
+month-march = Mars
+month-april = Avril
+month-may = Mai
+month-june = Juin
+month-july = Juillet
+month-august = Août
+month-september = Septembre
+month-october = Octobre
+month-november = Novembre
+month-december = Décembre
     [one] {""}
    *[other] s
 }
@@ -152,12 +604,43 @@ notif-duration-title = Durée atteinte
 notif-duration-body = Durée record personnel de l'exercice atteinte !
 notif-rest-title = Repos terminé
 notif-rest-body = C'est l'heure de ta prochaine série !
+media-rest-title = 🛋️ Repos
+media-rest-next = Ensuite : { $exercise }
+timer-session-title = Séance en cours
+tray-complete = Terminer l’exercice
+tray-finish = Terminer la séance
+launcher-start-session = Démarrer une séance
+launcher-last-session = Dernière séance
 
 ## Page Statistiques
 analytics-title = 📊 Statistiques
 analytics-subtitle = Suis ta progression dans le temps
 analytics-pairs-label = Paires métrique–exercice (⩽ 8)
+analytics-location-title = Seulement les séances dans ce lieu
+analytics-location-all = 📍 Tous les lieux
 analytics-empty = Sélectionnez des exercices pour voir les statistiques
+analytics-asymmetry-title = ↔️ Équilibre gauche / droite
+analytics-asymmetry-row = gauche { $left }, droite { $right } ({ $gap } % à gauche)
+analytics-one-rep-max-title = 🏋️ 1RM estimé
+analytics-one-rep-max-desc = Estimation d'Epley à partir de la meilleure série, poids de corps inclus pour les exercices au poids du corps
+analytics-tags-title = 🏷 Étiquettes personnalisées, { $days } derniers jours
+analytics-tags-sets = { $count } séries
+analytics-strain-title = 🔥 Charge d’entraînement
+analytics-strain-desc = Score d’effort par séance : minutes par zone cardiaque (TRIMP) si la fréquence cardiaque a été enregistrée, sinon un point par 100 kg soulevés
+analytics-strain-ratio = Ratio de charge aiguë:chronique (7 derniers jours face à la moyenne hebdomadaire des 28 derniers) : { $ratio }
+analytics-rest-title = ⏱️ Repos entre exercices
+analytics-rest-desc = Repos réel moyen par séance (barres) face au repos prévu pour ses exercices (ligne), pauses de plus de 30 minutes exclues
+analytics-rest-overrun = Dépassement moyen du repos : { $early } s en début de séance, { $late } s en fin
+analytics-compare-btn = ⚖️ Comparer deux périodes
+analytics-compare-back = 📈 Retour aux graphiques
+analytics-compare-current = Période A
+analytics-compare-previous = Période B
+analytics-compare-from = Du
+analytics-compare-to = Au
+analytics-compare-invalid = Choisissez deux plages de dates valides à comparer
+analytics-compare-sessions = Séances
+analytics-compare-frequency = Séances par semaine
+analytics-compare-volume = Volume
 analytics-metric-weight = Poids (kg)
 analytics-metric-reps = Répétitions
 analytics-metric-distance = Distance
@@ -0,0 +1,316 @@
+//! **`logout-cli`** – headless companion of `LogOut`
+//!
+//! Works on the `SQLite` database of the native (desktop) app, through
+//! [`logout_core`]: print training statistics, export the history as JSON or
+//! CSV, and import backup files, for scripting and data recovery.  It also
+//! runs the minimal sync server the app can be pointed at (see
+//! [`logout_core::sync_server`]).
+//!
+//! The database is looked up in the app data directory, or in the directory
+//! given by `--data-dir` (or the `LOGOUT_DATA_DIR` variable).  Encrypted
+//! backups take their passphrase from `--passphrase` or, to keep it out of
+//! the shell history, the `LOGOUT_BACKUP_PASSPHRASE` variable.
+mod stats;
+use logout_core::backup::{self, BackupError};
+use logout_core::models::{Exercise, WorkoutSession};
+use logout_core::storage::sqlite::{self, StorageError};
+use logout_core::sync_server::{self, RecordStore};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::process::ExitCode;
+/// Environment variable overriding the app data directory (see
+/// [`sqlite::data_dir`]).
+const DATA_DIR_ENV: &str = "LOGOUT_DATA_DIR";
+/// Environment variable holding the backup passphrase.
+const PASSPHRASE_ENV: &str = "LOGOUT_BACKUP_PASSPHRASE";
+/// Address the sync server listens on without `--listen`.
+const DEFAULT_SYNC_ADDR: &str = "127.0.0.1:7879";
+/// File of the sync server records in the data directory, without `--store`.
+const SYNC_STORE_FILENAME: &str = "sync-records.json";
+const USAGE: &str = "\
+Usage: logout-cli [--data-dir DIR] [--passphrase PASS] <command>
+
+Commands:
+  stats                                 Print training statistics
+  export sessions [--csv] [-o FILE]     Export the workout history
+  export exercises [-o FILE]            Export the custom exercises
+  import FILE                           Import a sessions or exercises backup
+  sync-serve [--listen ADDR] [--store FILE]
+                                        Serve encrypted sync records to the app
+  help                                  Show this message
+
+Exports go to the standard output unless -o is given, and are encrypted
+when a passphrase is set.  The sync server listens on 127.0.0.1:7879 and
+keeps the records in sync-records.json of the data directory by default.";
+/// Errors reported by the CLI before exiting with a failure status.
+#[derive(Debug, thiserror::Error)]
+enum CliError {
+    /// Invalid command line.
+    #[error("{0}\n\n{USAGE}")]
+    Usage(String),
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+    #[error(transparent)]
+    Backup(#[from] BackupError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The imported file holds neither sessions nor exercises.
+    #[error("not a LogOut sessions or exercises export: {0}")]
+    Format(serde_json::Error),
+}
+/// Data exported by the `export` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dataset {
+    Sessions,
+    Exercises,
+}
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Stats,
+    Export {
+        dataset: Dataset,
+        csv: bool,
+        output: Option<PathBuf>,
+    },
+    Import(PathBuf),
+    SyncServe {
+        listen: String,
+        store: Option<PathBuf>,
+    },
+    Help,
+}
+/// Parsed command line.
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    data_dir: Option<PathBuf>,
+    passphrase: Option<String>,
+    command: Command,
+}
+/// Parse the command line `args`, program name excluded.
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, CliError> {
+    let mut data_dir = None;
+    let mut passphrase = None;
+    let mut output = None;
+    let mut listen = None;
+    let mut store = None;
+    let mut csv = false;
+    let mut words = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| CliError::Usage(format!("{name} needs a value")))
+        };
+        match arg.as_str() {
+            "--data-dir" => data_dir = Some(PathBuf::from(value(&arg)?)),
+            "--passphrase" => passphrase = Some(value(&arg)?),
+            "-o" | "--output" => output = Some(PathBuf::from(value(&arg)?)),
+            "--listen" => listen = Some(value(&arg)?),
+            "--store" => store = Some(PathBuf::from(value(&arg)?)),
+            "--csv" => csv = true,
+            "-h" | "--help" => words.push("help".to_owned()),
+            flag if flag.starts_with('-') => {
+                return Err(CliError::Usage(format!("unknown option {flag}")))
+            }
+            _ => words.push(arg),
+        }
+    }
+    let command = match words.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["stats"] => Command::Stats,
+        ["export", "sessions"] => Command::Export {
+            dataset: Dataset::Sessions,
+            csv,
+            output,
+        },
+        ["export", "exercises"] if !csv => Command::Export {
+            dataset: Dataset::Exercises,
+            csv,
+            output,
+        },
+        ["export", "exercises"] => {
+            return Err(CliError::Usage(
+                "only sessions can be exported as CSV".into(),
+            ))
+        }
+        ["import", file] => Command::Import(PathBuf::from(file)),
+        ["sync-serve"] => Command::SyncServe {
+            listen: listen.unwrap_or_else(|| DEFAULT_SYNC_ADDR.to_owned()),
+            store,
+        },
+        [] | ["help", ..] => Command::Help,
+        _ => {
+            return Err(CliError::Usage(format!(
+                "unknown command {}",
+                words.join(" ")
+            )))
+        }
+    };
+    Ok(Args {
+        data_dir,
+        passphrase,
+        command,
+    })
+}
+/// Export `dataset` as JSON or CSV, sealed with `passphrase` if not empty.
+fn export(dataset: Dataset, csv: bool, passphrase: &str) -> Result<String, CliError> {
+    let content = match dataset {
+        Dataset::Sessions => {
+            let sessions: Vec<WorkoutSession> = sqlite::get_all(sqlite::STORE_SESSIONS)?;
+            if csv {
+                logout_core::csv_export::sessions_to_csv(&sessions)
+            } else {
+                serde_json::to_string_pretty(&sessions).map_err(StorageError::from)?
+            }
+        }
+        Dataset::Exercises => {
+            let exercises: Vec<Exercise> = sqlite::get_all(sqlite::STORE_CUSTOM_EXERCISES)?;
+            serde_json::to_string_pretty(&exercises).map_err(StorageError::from)?
+        }
+    };
+    if passphrase.is_empty() {
+        Ok(content)
+    } else {
+        Ok(backup::seal_backup(&content, passphrase)?)
+    }
+}
+/// Import the sessions or custom exercises of the backup `file`, skipping
+/// those already stored (as the app does).  Returns a summary line.
+fn import(file: &PathBuf, passphrase: &str) -> Result<String, CliError> {
+    let json = backup::open_backup(&std::fs::read_to_string(file)?, passphrase)?;
+    if let Ok(sessions) = serde_json::from_str::<Vec<WorkoutSession>>(&json) {
+        let existing: HashSet<String> = sqlite::get_all::<WorkoutSession>(sqlite::STORE_SESSIONS)?
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        let (mut added, mut skipped) = (0, 0);
+        for session in sessions {
+            if existing.contains(&session.id) {
+                skipped += 1;
+            } else {
+                sqlite::put_item(sqlite::STORE_SESSIONS, &session.id, &session)?;
+                added += 1;
+            }
+        }
+        return Ok(format!(
+            "Imported {added} session(s), skipped {skipped} already present"
+        ));
+    }
+    let exercises: Vec<Exercise> = serde_json::from_str(&json).map_err(CliError::Format)?;
+    let mut existing = HashSet::new();
+    for store in [sqlite::STORE_EXERCISES, sqlite::STORE_CUSTOM_EXERCISES] {
+        existing.extend(
+            sqlite::get_all::<Exercise>(store)?
+                .into_iter()
+                .map(|e| e.id),
+        );
+    }
+    let (mut added, mut skipped) = (0, 0);
+    for exercise in exercises {
+        if existing.contains(&exercise.id) {
+            skipped += 1;
+        } else {
+            sqlite::put_item(sqlite::STORE_CUSTOM_EXERCISES, &exercise.id, &exercise)?;
+            added += 1;
+        }
+    }
+    Ok(format!(
+        "Imported {added} exercise(s), skipped {skipped} already present"
+    ))
+}
+fn run(args: Args) -> Result<(), CliError> {
+    if let Some(dir) = &args.data_dir {
+        // Read once by the storage layer, before any database access.
+        std::env::set_var(DATA_DIR_ENV, dir);
+    }
+    let passphrase = args
+        .passphrase
+        .or_else(|| std::env::var(PASSPHRASE_ENV).ok())
+        .unwrap_or_default();
+    match args.command {
+        Command::Stats => {
+            let sessions: Vec<WorkoutSession> = sqlite::get_all(sqlite::STORE_SESSIONS)?;
+            print!(
+                "{}",
+                stats::Stats::of(&sessions, logout_core::datetime::today())
+            );
+        }
+        Command::Export {
+            dataset,
+            csv,
+            output,
+        } => {
+            let content = export(dataset, csv, &passphrase)?;
+            match output {
+                Some(path) => std::fs::write(path, content)?,
+                None => print!("{content}"),
+            }
+        }
+        Command::Import(file) => println!("{}", import(&file, &passphrase)?),
+        Command::SyncServe { listen, store } => {
+            let store = store.unwrap_or_else(|| sqlite::data_dir().join(SYNC_STORE_FILENAME));
+            if let Some(dir) = store.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let mut store = RecordStore::open(store)?;
+            let listener = std::net::TcpListener::bind(&listen)?;
+            println!(
+                "Serving {} sync record(s) from {} on http://{}/records",
+                store.len(),
+                store.path().display(),
+                listener.local_addr()?
+            );
+            sync_server::serve(&listener, &mut store);
+        }
+        Command::Help => println!("{USAGE}"),
+    }
+    Ok(())
+}
+fn main() -> ExitCode {
+    match parse_args(std::env::args().skip(1)).and_then(run) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("logout-cli: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn parse(line: &str) -> Result<Args, CliError> {
+        parse_args(line.split_whitespace().map(String::from))
+    }
+    #[test]
+    fn parses_commands_and_options_in_any_order() {
+        assert_eq!(
+            parse("--data-dir /tmp/lo export sessions --csv -o out.csv").unwrap(),
+            Args {
+                data_dir: Some("/tmp/lo".into()),
+                passphrase: None,
+                command: Command::Export {
+                    dataset: Dataset::Sessions,
+                    csv: true,
+                    output: Some("out.csv".into()),
+                },
+            }
+        );
+        assert_eq!(
+            parse("import backup.json --passphrase pw").unwrap().command,
+            Command::Import("backup.json".into())
+        );
+        assert_eq!(parse("").unwrap().command, Command::Help);
+        assert!(matches!(
+            parse("export exercises --csv"),
+            Err(CliError::Usage(_))
+        ));
+        assert_eq!(
+            parse("sync-serve --store records.json").unwrap().command,
+            Command::SyncServe {
+                listen: DEFAULT_SYNC_ADDR.into(),
+                store: Some("records.json".into()),
+            }
+        );
+        assert!(matches!(parse("stats --verbose"), Err(CliError::Usage(_))));
+        assert!(matches!(parse("--passphrase"), Err(CliError::Usage(_))));
+    }
+}
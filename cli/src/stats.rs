@@ -0,0 +1,155 @@
+/// Training statistics printed by the `stats` command.
+use logout_core::models::analytics::PeriodSummary;
+use logout_core::models::{format_time, WorkoutSession, HG_PER_KG};
+use std::collections::HashMap;
+use std::fmt;
+use time::{Date, Duration};
+/// Length of the recent period summarised, in days (today included).
+const RECENT_DAYS: i64 = 30;
+/// Number of most performed exercises listed.
+const TOP_EXERCISES: usize = 10;
+/// Totals over the whole history, plus the recent period.
+#[derive(Debug, Default, PartialEq)]
+pub struct Stats {
+    pub completed: usize,
+    pub active: usize,
+    pub first: Option<Date>,
+    pub last: Option<Date>,
+    /// Net training time in seconds, pauses excluded.
+    pub training_s: u64,
+    pub sets: usize,
+    pub warm_ups: usize,
+    pub volume_kg: f64,
+    /// The last [`RECENT_DAYS`] days.
+    pub recent: PeriodSummary,
+    /// Most performed exercises by name, with their number of sets.
+    pub top: Vec<(String, usize)>,
+}
+impl Stats {
+    /// Statistics of the completed `sessions`, the recent period ending on
+    /// the local day `today`.
+    #[must_use]
+    pub fn of(sessions: &[WorkoutSession], today: Date) -> Self {
+        let mut stats = Self {
+            active: sessions
+                .iter()
+                .filter(|s| s.is_active() && !s.is_cancelled())
+                .count(),
+            recent: PeriodSummary::of(sessions, today - Duration::days(RECENT_DAYS - 1), today),
+            ..Self::default()
+        };
+        let mut volume_hg = 0u64;
+        let mut sets_per_exercise: HashMap<&str, usize> = HashMap::new();
+        for session in sessions.iter().filter(|s| !s.is_active()) {
+            stats.completed += 1;
+            let day = logout_core::datetime::to_local(session.start_time).date();
+            stats.first = Some(stats.first.map_or(day, |d| d.min(day)));
+            stats.last = Some(stats.last.map_or(day, |d| d.max(day)));
+            stats.training_s += session.duration_seconds();
+            for log in session.exercise_logs.iter().filter(|l| l.is_complete()) {
+                if log.set_kind.counts_for_stats() {
+                    stats.sets += 1;
+                    *sets_per_exercise.entry(&log.exercise_name).or_default() += 1;
+                } else {
+                    stats.warm_ups += 1;
+                }
+                volume_hg += log.volume_hg();
+            }
+        }
+        #[allow(clippy::cast_precision_loss)]
+        {
+            stats.volume_kg = volume_hg as f64 / HG_PER_KG;
+        }
+        let mut top: Vec<(String, usize)> = sets_per_exercise
+            .into_iter()
+            .map(|(name, sets)| (name.to_owned(), sets))
+            .collect();
+        top.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top.truncate(TOP_EXERCISES);
+        stats.top = top;
+        stats
+    }
+}
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Sessions:       {} completed ({} active)",
+            self.completed, self.active
+        )?;
+        if let (Some(first), Some(last)) = (self.first, self.last) {
+            writeln!(f, "Period:         {first} to {last}")?;
+        }
+        writeln!(f, "Training time:  {}", format_time(self.training_s))?;
+        writeln!(
+            f,
+            "Sets:           {} ({} warm-up)",
+            self.sets, self.warm_ups
+        )?;
+        writeln!(f, "Volume:         {:.1} kg", self.volume_kg)?;
+        writeln!(
+            f,
+            "Last {RECENT_DAYS} days:   {} sessions, {:.1}/week, {:.1} kg",
+            self.recent.sessions, self.recent.sessions_per_week, self.recent.volume_kg
+        )?;
+        if !self.top.is_empty() {
+            writeln!(f, "Top exercises:")?;
+            for (name, sets) in &self.top {
+                writeln!(f, "  {sets:>5}  {name}")?;
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use logout_core::models::{ExerciseLog, SetKind, Weight};
+    fn set(name: &str, kind: SetKind) -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: name.to_lowercase(),
+            exercise_name: name.into(),
+            start_time: 1_700_000_000,
+            end_time: Some(1_700_000_060),
+            weight_hg: Weight(1_000),
+            reps: Some(5),
+            set_kind: kind,
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn stats_count_completed_sets_and_rank_exercises() {
+        let done = WorkoutSession {
+            id: "s1".into(),
+            start_time: 1_700_000_000,
+            end_time: Some(1_700_001_800),
+            exercise_logs: vec![
+                set("Squat", SetKind::WarmUp),
+                set("Squat", SetKind::Normal),
+                set("Squat", SetKind::Normal),
+                set("Bench", SetKind::Normal),
+            ],
+            ..WorkoutSession::default()
+        };
+        let ongoing = WorkoutSession {
+            id: "s2".into(),
+            start_time: 1_700_100_000,
+            exercise_logs: vec![set("Deadlift", SetKind::Normal)],
+            ..WorkoutSession::default()
+        };
+        let today = logout_core::datetime::to_local(1_700_000_000).date();
+        let stats = Stats::of(&[done, ongoing], today);
+        assert_eq!((stats.completed, stats.active), (1, 1));
+        assert_eq!((stats.sets, stats.warm_ups), (3, 1));
+        assert_eq!(stats.training_s, 1_800);
+        assert!((stats.volume_kg - 1_500.0).abs() < f64::EPSILON);
+        assert_eq!(
+            stats.top,
+            vec![("Squat".to_owned(), 2), ("Bench".to_owned(), 1)]
+        );
+        assert_eq!(stats.first, Some(today));
+        assert!(stats
+            .to_string()
+            .contains("Sessions:       1 completed (1 active)"));
+    }
+}
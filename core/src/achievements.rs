@@ -0,0 +1,209 @@
+/// Milestone badges ("100 sessions", "10,000 kg in a week", "12-week
+/// streak"…) for the Achievements page.
+///
+/// Badges are earned from the records of the completed sessions.  The app
+/// remembers the unlocked ones, so a badge stays unlocked even once the
+/// sessions behind it are deleted.
+use crate::models::{Exercise, WorkoutSession};
+use crate::session_stats;
+use std::collections::{BTreeMap, BTreeSet};
+use time::{Date, Duration};
+/// Hectograms per kilogram, to report the volume in whole kilograms.
+const HECTOGRAMS_PER_KG: u64 = 10;
+/// What a badge rewards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Milestone {
+    /// Number of completed sessions.
+    Sessions(u32),
+    /// Volume (kg) lifted within one Monday-to-Sunday week.
+    WeeklyVolumeKg(u32),
+    /// Consecutive weeks with at least one session.
+    WeekStreak(u32),
+}
+/// A badge and the milestone unlocking it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Achievement {
+    /// Stable identifier, the key in the persisted unlocked map.
+    pub id: &'static str,
+    pub milestone: Milestone,
+}
+/// Every badge, in display order.
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "sessions-1",
+        milestone: Milestone::Sessions(1),
+    },
+    Achievement {
+        id: "sessions-10",
+        milestone: Milestone::Sessions(10),
+    },
+    Achievement {
+        id: "sessions-50",
+        milestone: Milestone::Sessions(50),
+    },
+    Achievement {
+        id: "sessions-100",
+        milestone: Milestone::Sessions(100),
+    },
+    Achievement {
+        id: "sessions-250",
+        milestone: Milestone::Sessions(250),
+    },
+    Achievement {
+        id: "weekly-volume-1000",
+        milestone: Milestone::WeeklyVolumeKg(1_000),
+    },
+    Achievement {
+        id: "weekly-volume-5000",
+        milestone: Milestone::WeeklyVolumeKg(5_000),
+    },
+    Achievement {
+        id: "weekly-volume-10000",
+        milestone: Milestone::WeeklyVolumeKg(10_000),
+    },
+    Achievement {
+        id: "streak-4",
+        milestone: Milestone::WeekStreak(4),
+    },
+    Achievement {
+        id: "streak-12",
+        milestone: Milestone::WeekStreak(12),
+    },
+    Achievement {
+        id: "streak-26",
+        milestone: Milestone::WeekStreak(26),
+    },
+    Achievement {
+        id: "streak-52",
+        milestone: Milestone::WeekStreak(52),
+    },
+];
+/// Unlocked badge IDs and when they were unlocked.
+pub type Unlocked = BTreeMap<String, u64>;
+/// Figures of the training history that badges are measured against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Records {
+    pub sessions: u32,
+    pub best_weekly_volume_kg: u32,
+    pub longest_week_streak: u32,
+}
+impl Records {
+    /// Measure the completed sessions among `sessions`.
+    #[must_use]
+    pub fn measure(sessions: &[WorkoutSession]) -> Self {
+        let completed: Vec<&WorkoutSession> = sessions
+            .iter()
+            .filter(|s| !s.is_active() && !s.exercise_logs.is_empty())
+            .collect();
+        let mut weekly_volume_hg: BTreeMap<Date, u64> = BTreeMap::new();
+        for session in &completed {
+            let stats = session_stats::compute::<Exercise>(session, &[], &[]);
+            *weekly_volume_hg
+                .entry(week_of(session.start_time))
+                .or_default() += stats.volume_hg;
+        }
+        let weeks: BTreeSet<Date> = weekly_volume_hg.keys().copied().collect();
+        let mut longest = 0u32;
+        let mut current = 0u32;
+        let mut previous: Option<Date> = None;
+        for &week in &weeks {
+            current = match previous {
+                Some(p) if week - p == Duration::weeks(1) => current + 1,
+                _ => 1,
+            };
+            longest = longest.max(current);
+            previous = Some(week);
+        }
+        let best_volume_hg = weekly_volume_hg.values().copied().max().unwrap_or(0);
+        Self {
+            sessions: u32::try_from(completed.len()).unwrap_or(u32::MAX),
+            best_weekly_volume_kg: u32::try_from(best_volume_hg / HECTOGRAMS_PER_KG)
+                .unwrap_or(u32::MAX),
+            longest_week_streak: longest,
+        }
+    }
+    /// Current value of the figure `milestone` is measured on.
+    #[must_use]
+    pub fn value(&self, milestone: Milestone) -> u32 {
+        match milestone {
+            Milestone::Sessions(_) => self.sessions,
+            Milestone::WeeklyVolumeKg(_) => self.best_weekly_volume_kg,
+            Milestone::WeekStreak(_) => self.longest_week_streak,
+        }
+    }
+}
+impl Milestone {
+    /// Value to reach.
+    #[must_use]
+    pub fn target(self) -> u32 {
+        match self {
+            Self::Sessions(n) | Self::WeeklyVolumeKg(n) | Self::WeekStreak(n) => n,
+        }
+    }
+}
+/// Local Monday of the week `timestamp` falls in.
+fn week_of(timestamp: u64) -> Date {
+    crate::datetime::monday_of(crate::datetime::to_local(timestamp).date())
+}
+/// Badges of `ACHIEVEMENTS` earned by `records` but missing from `unlocked`.
+#[must_use]
+pub fn newly_earned(records: &Records, unlocked: &Unlocked) -> Vec<Achievement> {
+    ACHIEVEMENTS
+        .iter()
+        .filter(|a| !unlocked.contains_key(a.id))
+        .filter(|a| records.value(a.milestone) >= a.milestone.target())
+        .copied()
+        .collect()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datetime::SECONDS_IN_DAY;
+    use crate::models::{ExerciseLog, Weight};
+    /// A Wednesday noon (UTC), far from any week boundary in most timezones.
+    const WEDNESDAY: u64 = 1_760_529_600;
+    fn session(day: u64, weight_hg: u16, reps: u32) -> WorkoutSession {
+        let start = WEDNESDAY + day * SECONDS_IN_DAY;
+        let mut s = WorkoutSession::new();
+        s.start_time = start;
+        s.end_time = Some(start + 3_600);
+        s.exercise_logs = vec![ExerciseLog {
+            exercise_id: "squat".into(),
+            exercise_name: "Squat".into(),
+            start_time: start,
+            end_time: Some(start + 60),
+            weight_hg: Weight(weight_hg),
+            reps: Some(reps),
+            ..Default::default()
+        }];
+        s
+    }
+    #[test]
+    fn records_count_sessions_volume_and_streaks() {
+        let sessions = vec![
+            session(0, 1_000, 10),
+            session(1, 1_000, 10),
+            session(7, 500, 10),
+            session(14, 500, 10),
+            session(35, 500, 10),
+        ];
+        let records = Records::measure(&sessions);
+        assert_eq!(records.sessions, 5);
+        assert_eq!(records.best_weekly_volume_kg, 2_000);
+        assert_eq!(records.longest_week_streak, 3);
+    }
+    #[test]
+    fn only_missing_badges_are_newly_earned() {
+        let records = Records {
+            sessions: 12,
+            best_weekly_volume_kg: 0,
+            longest_week_streak: 4,
+        };
+        let unlocked: Unlocked = [("sessions-1".to_owned(), 0)].into();
+        let ids: Vec<&str> = newly_earned(&records, &unlocked)
+            .iter()
+            .map(|a| a.id)
+            .collect();
+        assert_eq!(ids, ["sessions-10", "streak-4"]);
+    }
+}
@@ -0,0 +1,326 @@
+/// GPX / TCX exporters for cardio activities.
+///
+/// Converts a cardio [`ExerciseLog`] (with its optional GPS track and
+/// heart-rate samples) into XML documents understood by Garmin Connect,
+/// Strava and most other fitness platforms.  The functions are pure so they
+/// can be unit-tested without a virtual DOM.
+use crate::models::{Category, ExerciseLog, HeartRateSample};
+use std::fmt::Write as _;
+/// Creator attribute written into exported files.
+const CREATOR: &str = "LogOut";
+/// Supported activity file formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityFormat {
+    /// GPS Exchange Format 1.1.
+    Gpx,
+    /// Garmin Training Center XML v2.
+    Tcx,
+}
+impl ActivityFormat {
+    /// File extension (without the dot) for this format.
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Gpx => "gpx",
+            Self::Tcx => "tcx",
+        }
+    }
+    /// MIME type used when triggering a download.
+    #[must_use]
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Gpx => "application/gpx+xml",
+            Self::Tcx => "application/vnd.garmin.tcx+xml",
+        }
+    }
+}
+/// Returns `true` when `log` can be exported as an activity file.
+///
+/// Only completed cardio logs qualify.
+#[must_use]
+pub fn is_exportable(log: &ExerciseLog) -> bool {
+    log.category == Category::Cardio && log.is_complete()
+}
+/// Render `log` in the requested `format`.
+#[must_use]
+pub fn export_log(log: &ExerciseLog, format: ActivityFormat) -> String {
+    match format {
+        ActivityFormat::Gpx => to_gpx(log),
+        ActivityFormat::Tcx => to_tcx(log),
+    }
+}
+/// Suggested download filename, e.g. `Running_20240301T073000Z.gpx`.
+#[must_use]
+pub fn export_filename(log: &ExerciseLog, format: ActivityFormat) -> String {
+    let stamp: String = iso8601_utc(log.start_time)
+        .chars()
+        .filter(|c| !matches!(c, '-' | ':'))
+        .collect();
+    format!("{}_{stamp}.{}", log.exercise_id, format.extension())
+}
+/// Render `log` as a GPX 1.1 document.
+///
+/// Heart-rate samples are attached to the nearest preceding track point using
+/// the Garmin `TrackPointExtension` schema.
+#[must_use]
+pub fn to_gpx(log: &ExerciseLog) -> String {
+    let mut out = String::new();
+    out.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    out.push('\n');
+    let _ = writeln!(
+        out,
+        r#"<gpx version="1.1" creator="{CREATOR}" xmlns="http://www.topografix.com/GPX/1/1" xmlns:gpxtpx="http://www.garmin.com/xmlschemas/TrackPointExtension/v1">"#
+    );
+    let _ = writeln!(
+        out,
+        "  <metadata><time>{}</time></metadata>",
+        iso8601_utc(log.start_time)
+    );
+    out.push_str("  <trk>\n");
+    let _ = writeln!(out, "    <name>{}</name>", xml_escape(&log.exercise_name));
+    out.push_str("    <trkseg>\n");
+    for point in &log.gps_track {
+        let _ = write!(
+            out,
+            r#"      <trkpt lat="{:.7}" lon="{:.7}">"#,
+            point.lat, point.lon
+        );
+        if let Some(ele) = point.ele {
+            let _ = write!(out, "<ele>{ele:.1}</ele>");
+        }
+        let _ = write!(out, "<time>{}</time>", iso8601_utc(point.time));
+        if let Some(bpm) = hr_at(&log.hr_samples, point.time) {
+            let _ = write!(
+                out,
+                "<extensions><gpxtpx:TrackPointExtension><gpxtpx:hr>{bpm}</gpxtpx:hr></gpxtpx:TrackPointExtension></extensions>"
+            );
+        }
+        out.push_str("</trkpt>\n");
+    }
+    out.push_str("    </trkseg>\n  </trk>\n</gpx>\n");
+    out
+}
+/// Render `log` as a TCX v2 document containing a single lap.
+///
+/// Track points are merged from the GPS track and the heart-rate samples so
+/// that indoor activities without GPS still carry their heart-rate data.
+#[must_use]
+pub fn to_tcx(log: &ExerciseLog) -> String {
+    let start = iso8601_utc(log.start_time);
+    let duration = log.duration_seconds().unwrap_or(0);
+    let mut out = String::new();
+    out.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    out.push('\n');
+    out.push_str(
+        r#"<TrainingCenterDatabase xmlns="http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2">"#,
+    );
+    out.push('\n');
+    out.push_str("  <Activities>\n");
+    let _ = writeln!(
+        out,
+        r#"    <Activity Sport="{}">"#,
+        tcx_sport(&log.exercise_id)
+    );
+    let _ = writeln!(out, "      <Id>{start}</Id>");
+    let _ = writeln!(out, r#"      <Lap StartTime="{start}">"#);
+    let _ = writeln!(
+        out,
+        "        <TotalTimeSeconds>{duration}</TotalTimeSeconds>"
+    );
+    let _ = writeln!(
+        out,
+        "        <DistanceMeters>{}</DistanceMeters>",
+        log.distance_m.map_or(0, |d| d.0)
+    );
+    if let Some(avg) = average_bpm(&log.hr_samples) {
+        let _ = writeln!(
+            out,
+            "        <AverageHeartRateBpm><Value>{avg}</Value></AverageHeartRateBpm>"
+        );
+    }
+    if let Some(max) = log.hr_samples.iter().map(|s| s.bpm).max() {
+        let _ = writeln!(
+            out,
+            "        <MaximumHeartRateBpm><Value>{max}</Value></MaximumHeartRateBpm>"
+        );
+    }
+    out.push_str("        <Intensity>Active</Intensity>\n");
+    out.push_str("        <TriggerMethod>Manual</TriggerMethod>\n");
+    out.push_str("        <Track>\n");
+    let mut times: Vec<u64> = log
+        .gps_track
+        .iter()
+        .map(|p| p.time)
+        .chain(log.hr_samples.iter().map(|s| s.time))
+        .collect();
+    times.sort_unstable();
+    times.dedup();
+    for time in times {
+        let _ = write!(
+            out,
+            "          <Trackpoint><Time>{}</Time>",
+            iso8601_utc(time)
+        );
+        if let Some(point) = log.gps_track.iter().find(|p| p.time == time) {
+            let _ = write!(
+                out,
+                "<Position><LatitudeDegrees>{:.7}</LatitudeDegrees><LongitudeDegrees>{:.7}</LongitudeDegrees></Position>",
+                point.lat, point.lon
+            );
+            if let Some(ele) = point.ele {
+                let _ = write!(out, "<AltitudeMeters>{ele:.1}</AltitudeMeters>");
+            }
+        }
+        if let Some(bpm) = hr_at(&log.hr_samples, time) {
+            let _ = write!(out, "<HeartRateBpm><Value>{bpm}</Value></HeartRateBpm>");
+        }
+        out.push_str("</Trackpoint>\n");
+    }
+    out.push_str("        </Track>\n");
+    out.push_str("      </Lap>\n");
+    out.push_str("    </Activity>\n");
+    out.push_str("  </Activities>\n");
+    out.push_str("</TrainingCenterDatabase>\n");
+    out
+}
+/// Heart rate of the most recent sample taken at or before `time`.
+fn hr_at(samples: &[HeartRateSample], time: u64) -> Option<u16> {
+    samples
+        .iter()
+        .filter(|s| s.time <= time)
+        .max_by_key(|s| s.time)
+        .map(|s| s.bpm)
+}
+/// Arithmetic mean of the heart-rate samples, rounded down.
+fn average_bpm(samples: &[HeartRateSample]) -> Option<u16> {
+    if samples.is_empty() {
+        return None;
+    }
+    let sum: u64 = samples.iter().map(|s| u64::from(s.bpm)).sum();
+    u16::try_from(sum / samples.len() as u64).ok()
+}
+/// TCX only knows `Running`, `Biking` and `Other`; guess from the exercise ID.
+fn tcx_sport(exercise_id: &str) -> &'static str {
+    let id = exercise_id.to_lowercase();
+    if id.contains("run") || id.contains("jog") {
+        "Running"
+    } else if id.contains("bik") || id.contains("cycl") {
+        "Biking"
+    } else {
+        "Other"
+    }
+}
+/// Format a Unix timestamp as an ISO 8601 UTC string (`YYYY-MM-DDTHH:MM:SSZ`).
+fn iso8601_utc(timestamp: u64) -> String {
+    let dt = time::OffsetDateTime::from_unix_timestamp(timestamp.cast_signed())
+        .unwrap_or(time::OffsetDateTime::UNIX_EPOCH);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        dt.year(),
+        u8::from(dt.month()),
+        dt.day(),
+        dt.hour(),
+        dt.minute(),
+        dt.second()
+    )
+}
+/// Escape the five XML special characters.
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Distance, TrackPoint};
+    fn run_log() -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: "Running".into(),
+            exercise_name: "Running & Jogging".into(),
+            category: Category::Cardio,
+            start_time: 1_700_000_000,
+            end_time: Some(1_700_000_600),
+            distance_m: Some(Distance(2000)),
+            gps_track: vec![
+                TrackPoint {
+                    time: 1_700_000_000,
+                    lat: 48.858_37,
+                    lon: 2.294_481,
+                    ele: Some(35.0),
+                },
+                TrackPoint {
+                    time: 1_700_000_300,
+                    lat: 48.86,
+                    lon: 2.3,
+                    ele: None,
+                },
+            ],
+            hr_samples: vec![
+                HeartRateSample {
+                    time: 1_700_000_000,
+                    bpm: 120,
+                },
+                HeartRateSample {
+                    time: 1_700_000_150,
+                    bpm: 150,
+                },
+            ],
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn iso8601_utc_formats_epoch_offsets() {
+        assert_eq!(iso8601_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(iso8601_utc(1_700_000_000), "2023-11-14T22:13:20Z");
+    }
+    #[test]
+    fn gpx_contains_track_points_and_hr() {
+        let gpx = to_gpx(&run_log());
+        assert!(gpx.contains(r#"<trkpt lat="48.8583700" lon="2.2944810">"#));
+        assert!(gpx.contains("<ele>35.0</ele>"));
+        assert!(gpx.contains("<gpxtpx:hr>120</gpxtpx:hr>"));
+        // Second point picks up the most recent preceding HR sample (150).
+        assert!(gpx.contains("<gpxtpx:hr>150</gpxtpx:hr>"));
+        assert!(gpx.contains("<name>Running &amp; Jogging</name>"));
+    }
+    #[test]
+    fn tcx_contains_lap_summary() {
+        let tcx = to_tcx(&run_log());
+        assert!(tcx.contains(r#"<Activity Sport="Running">"#));
+        assert!(tcx.contains("<TotalTimeSeconds>600</TotalTimeSeconds>"));
+        assert!(tcx.contains("<DistanceMeters>2000</DistanceMeters>"));
+        assert!(tcx.contains("<AverageHeartRateBpm><Value>135</Value></AverageHeartRateBpm>"));
+        assert!(tcx.contains("<MaximumHeartRateBpm><Value>150</Value></MaximumHeartRateBpm>"));
+        // GPS points at t=0 and t=300 plus an HR-only point at t=150.
+        assert_eq!(tcx.matches("<Trackpoint>").count(), 3);
+    }
+    #[test]
+    fn tcx_without_track_has_empty_track() {
+        let mut log = run_log();
+        log.gps_track.clear();
+        log.hr_samples.clear();
+        let tcx = to_tcx(&log);
+        assert!(!tcx.contains("<Trackpoint>"));
+        assert!(!tcx.contains("HeartRateBpm"));
+    }
+    #[test]
+    fn export_filename_uses_compact_timestamp() {
+        assert_eq!(
+            export_filename(&run_log(), ActivityFormat::Gpx),
+            "Running_20231114T221320Z.gpx"
+        );
+    }
+    #[test]
+    fn only_completed_cardio_is_exportable() {
+        let mut log = run_log();
+        assert!(is_exportable(&log));
+        log.end_time = None;
+        assert!(!is_exportable(&log));
+        log.end_time = Some(1_700_000_600);
+        log.category = Category::Strength;
+        assert!(!is_exportable(&log));
+    }
+}
@@ -0,0 +1,173 @@
+/// Passphrase-encrypted backup files.
+///
+/// An encrypted backup is a small JSON envelope holding the random PBKDF2
+/// salt and the XChaCha20-Poly1305 sealed export (see [`crypto`]), both in
+/// base64, so it stays a text file that can safely sit in a cloud drive.
+/// The export is gzip-compressed before being sealed, as ciphertext does not
+/// compress.  [`open_backup`] accepts plain and encrypted exports alike.
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+/// Value of the `format` field identifying an encrypted backup.
+const ENCRYPTED_FORMAT: &str = "logout-encrypted-backup";
+/// Version of the envelope layout, bumped on incompatible changes.
+const ENVELOPE_VERSION: u32 = 2;
+/// First envelope version whose sealed export is gzip-compressed.
+const COMPRESSED_ENVELOPE_VERSION: u32 = 2;
+/// Length in bytes of the random salt of every backup.
+const SALT_LEN: usize = 16;
+/// Errors raised when sealing or opening a backup.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum BackupError {
+    /// The backup is encrypted and no passphrase was given.
+    #[error("this backup is encrypted, enter its passphrase")]
+    PassphraseRequired,
+    /// Encryption failure, wrong passphrase or tampered data.
+    #[error(transparent)]
+    Crypto(#[from] crypto::CryptoError),
+    /// The envelope is malformed or from a newer version.
+    #[error("invalid encrypted backup: {0}")]
+    Format(String),
+}
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    format: String,
+    version: u32,
+    salt: String,
+    data: String,
+}
+/// Parse `content` as an encrypted backup envelope, `None` for anything
+/// else (such as a plain JSON export).
+fn envelope(content: &str) -> Option<Envelope> {
+    serde_json::from_str::<Envelope>(content)
+        .ok()
+        .filter(|e| e.format == ENCRYPTED_FORMAT)
+}
+/// Encrypt the exported `json` with `passphrase`.
+///
+/// # Errors
+///
+/// Returns [`BackupError::Crypto`] if the random source is unavailable.
+pub fn seal_backup(json: &str, passphrase: &str) -> Result<String, BackupError> {
+    seal_backup_with(json, passphrase, crypto::derive_key)
+}
+fn seal_backup_with(
+    json: &str,
+    passphrase: &str,
+    derive: impl Fn(&str, &[u8]) -> crypto::SecretKey,
+) -> Result<String, BackupError> {
+    let salt = crypto::random_bytes(SALT_LEN)?;
+    let compressed = crate::compress::gzip(json.as_bytes());
+    let data = crypto::encrypt(&derive(passphrase, &salt), &compressed)?;
+    let envelope = Envelope {
+        format: ENCRYPTED_FORMAT.into(),
+        version: ENVELOPE_VERSION,
+        salt: crypto::to_base64(&salt),
+        data: crypto::to_base64(&data),
+    };
+    serde_json::to_string(&envelope).map_err(|e| BackupError::Format(e.to_string()))
+}
+/// File name and content of the `name` export of `json`, sealed when a
+/// backup `passphrase` is set.
+///
+/// # Errors
+///
+/// Returns [`BackupError::Crypto`] if the random source is unavailable.
+pub fn backup_file(
+    name: &str,
+    json: String,
+    passphrase: &str,
+) -> Result<(String, String), BackupError> {
+    if passphrase.is_empty() {
+        Ok((format!("{name}.json"), json))
+    } else {
+        Ok((
+            format!("{name}.encrypted.json"),
+            seal_backup(&json, passphrase)?,
+        ))
+    }
+}
+/// The exported JSON of a backup file: `content` itself when plain, else
+/// decrypted with `passphrase`.
+///
+/// # Errors
+///
+/// Fails when an encrypted backup gets an empty or wrong passphrase, or is
+/// malformed.
+pub fn open_backup(content: &str, passphrase: &str) -> Result<String, BackupError> {
+    open_backup_with(content, passphrase, crypto::derive_key)
+}
+fn open_backup_with(
+    content: &str,
+    passphrase: &str,
+    derive: impl Fn(&str, &[u8]) -> crypto::SecretKey,
+) -> Result<String, BackupError> {
+    let Some(envelope) = envelope(content) else {
+        return Ok(content.to_string());
+    };
+    if envelope.version > ENVELOPE_VERSION {
+        return Err(BackupError::Format(format!(
+            "version {} is not supported",
+            envelope.version
+        )));
+    }
+    if passphrase.is_empty() {
+        return Err(BackupError::PassphraseRequired);
+    }
+    let invalid = || BackupError::Format("invalid base64".into());
+    let salt = crypto::from_base64(&envelope.salt).ok_or_else(invalid)?;
+    let data = crypto::from_base64(&envelope.data).ok_or_else(invalid)?;
+    let json = crypto::decrypt(&derive(passphrase, &salt), &data)?;
+    if envelope.version < COMPRESSED_ENVELOPE_VERSION {
+        return String::from_utf8(json).map_err(|e| BackupError::Format(e.to_string()));
+    }
+    crate::compress::gunzip_str(&json).map_err(|e| BackupError::Format(e.to_string()))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    /// Low iteration count so tests stay fast.
+    const TEST_ITERATIONS: u32 = 1_000;
+    fn fast_key(passphrase: &str, salt: &[u8]) -> crypto::SecretKey {
+        crypto::derive_key_with_iterations(passphrase, salt, TEST_ITERATIONS)
+    }
+    #[test]
+    fn encrypted_backup_round_trips_with_its_passphrase_only() {
+        let sealed = seal_backup_with(r#"[{"id":"s1"}]"#, "hunter2", fast_key).unwrap();
+        assert!(envelope(&sealed).is_some());
+        assert!(!sealed.contains("s1"));
+        assert_eq!(
+            open_backup_with(&sealed, "hunter2", fast_key).unwrap(),
+            r#"[{"id":"s1"}]"#
+        );
+        assert_eq!(
+            open_backup_with(&sealed, "wrong", fast_key),
+            Err(BackupError::Crypto(crypto::CryptoError::Decrypt))
+        );
+        assert_eq!(
+            open_backup_with(&sealed, "", fast_key),
+            Err(BackupError::PassphraseRequired)
+        );
+    }
+    #[test]
+    fn uncompressed_backups_of_the_first_version_still_open() {
+        let salt = crypto::random_bytes(SALT_LEN).unwrap();
+        let data = crypto::encrypt(&fast_key("hunter2", &salt), br#"[{"id":"s1"}]"#).unwrap();
+        let sealed = serde_json::to_string(&Envelope {
+            format: ENCRYPTED_FORMAT.into(),
+            version: 1,
+            salt: crypto::to_base64(&salt),
+            data: crypto::to_base64(&data),
+        })
+        .unwrap();
+        assert_eq!(
+            open_backup_with(&sealed, "hunter2", fast_key).unwrap(),
+            r#"[{"id":"s1"}]"#
+        );
+    }
+    #[test]
+    fn plain_exports_pass_through() {
+        let plain = r#"[{"id":"s1","format":"x"}]"#;
+        assert!(envelope(plain).is_none());
+        assert_eq!(open_backup(plain, "ignored").unwrap(), plain);
+    }
+}
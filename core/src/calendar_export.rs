@@ -0,0 +1,182 @@
+/// iCalendar (RFC 5545) export of the workout history.
+///
+/// Each completed session becomes a `VEVENT` spanning its start and end time,
+/// with the performed exercises listed in the description and the session
+/// notes appended.  The output can be imported into (or served as a
+/// subscription to) any calendar application.
+use crate::models::WorkoutSession;
+use std::fmt::Write as _;
+/// Product identifier written into the `VCALENDAR` header.
+const PRODID: &str = "-//LogOut//Workout History//EN";
+/// Maximum octets per content line before folding (RFC 5545 §3.1).
+const MAX_LINE_OCTETS: usize = 75;
+/// Render completed `sessions` as an iCalendar document.
+///
+/// Active sessions are skipped.  `summary` is used as the event title (e.g. a
+/// localised "Workout").
+#[must_use]
+pub fn sessions_to_ics(sessions: &[WorkoutSession], summary: &str) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{PRODID}"));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, &format!("X-WR-CALNAME:{}", escape_text(summary)));
+    let stamp = ics_utc(crate::models::get_current_timestamp());
+    for session in sessions.iter().filter(|s| !s.is_active()) {
+        let Some(end) = session.end_time else {
+            continue;
+        };
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("UID:{}@logout", session.id));
+        push_line(&mut out, &format!("DTSTAMP:{stamp}"));
+        push_line(
+            &mut out,
+            &format!("DTSTART:{}", ics_utc(session.start_time)),
+        );
+        push_line(&mut out, &format!("DTEND:{}", ics_utc(end)));
+        push_line(&mut out, &format!("SUMMARY:{}", escape_text(summary)));
+        let description = describe(session);
+        if !description.is_empty() {
+            push_line(
+                &mut out,
+                &format!("DESCRIPTION:{}", escape_text(&description)),
+            );
+        }
+        push_line(&mut out, "END:VEVENT");
+    }
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+/// Event description: one line per distinct exercise, then the notes.
+fn describe(session: &WorkoutSession) -> String {
+    let mut seen = std::collections::HashSet::new();
+    let mut lines: Vec<String> = Vec::new();
+    for log in &session.exercise_logs {
+        if seen.insert(log.exercise_id.as_str()) {
+            let sets = session
+                .exercise_logs
+                .iter()
+                .filter(|l| l.exercise_id == log.exercise_id)
+                .count();
+            lines.push(format!("{} ×{sets}", log.exercise_name));
+        }
+    }
+    if !session.notes.trim().is_empty() {
+        lines.push(String::new());
+        lines.push(session.notes.trim().to_owned());
+    }
+    lines.join("\n")
+}
+/// Append a content line, folded at [`MAX_LINE_OCTETS`] and CRLF-terminated.
+fn push_line(out: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if octets + len > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            // The leading space of a continuation line counts as one octet.
+            octets = 1;
+        }
+        out.push(c);
+        octets += len;
+    }
+    out.push_str("\r\n");
+}
+/// Escape `TEXT` values: backslash, semicolon, comma and newlines.
+fn escape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+/// Format a Unix timestamp as an iCalendar UTC date-time (`YYYYMMDDTHHMMSSZ`).
+fn ics_utc(timestamp: u64) -> String {
+    let dt = time::OffsetDateTime::from_unix_timestamp(timestamp.cast_signed())
+        .unwrap_or(time::OffsetDateTime::UNIX_EPOCH);
+    let mut s = String::with_capacity(16);
+    let _ = write!(
+        s,
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        dt.year(),
+        u8::from(dt.month()),
+        dt.day(),
+        dt.hour(),
+        dt.minute(),
+        dt.second()
+    );
+    s
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ExerciseLog;
+    fn log(id: &str, name: &str) -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: id.into(),
+            exercise_name: name.into(),
+            start_time: 1_700_000_100,
+            end_time: Some(1_700_000_200),
+            reps: Some(10),
+            ..Default::default()
+        }
+    }
+    fn completed() -> WorkoutSession {
+        let mut s = WorkoutSession::new();
+        s.id = "session_1700000000".into();
+        s.start_time = 1_700_000_000;
+        s.end_time = Some(1_700_003_600);
+        s.exercise_logs = vec![
+            log("squat", "Squat"),
+            log("squat", "Squat"),
+            log("bench", "Bench, flat"),
+        ];
+        s.notes = "Felt strong; new PR".into();
+        s
+    }
+    #[test]
+    fn ics_utc_format() {
+        assert_eq!(ics_utc(0), "19700101T000000Z");
+        assert_eq!(ics_utc(1_700_000_000), "20231114T221320Z");
+    }
+    #[test]
+    fn completed_session_becomes_event() {
+        let ics = sessions_to_ics(&[completed()], "Workout");
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("UID:session_1700000000@logout\r\n"));
+        assert!(ics.contains("DTSTART:20231114T221320Z\r\n"));
+        assert!(ics.contains("DTEND:20231114T231320Z\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 1);
+    }
+    #[test]
+    fn description_is_escaped() {
+        let ics = sessions_to_ics(&[completed()], "Workout");
+        let unfolded = ics.replace("\r\n ", "");
+        assert!(unfolded.contains("Squat ×2\\nBench\\, flat ×1\\n\\nFelt strong\\; new PR"));
+    }
+    #[test]
+    fn active_sessions_are_skipped() {
+        let mut s = completed();
+        s.end_time = None;
+        let ics = sessions_to_ics(&[s], "Workout");
+        assert!(!ics.contains("BEGIN:VEVENT"));
+    }
+    #[test]
+    fn long_lines_are_folded() {
+        let mut out = String::new();
+        push_line(&mut out, &"x".repeat(100));
+        let lines: Vec<&str> = out.split("\r\n").collect();
+        assert_eq!(lines[0].len(), MAX_LINE_OCTETS);
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(lines[0].len() + lines[1].len() - 1, 100);
+    }
+}
@@ -0,0 +1,48 @@
+/// Gzip compression of stored sessions and of encrypted backups.
+///
+/// JSON compresses several times over, which matters for the history that
+/// piles up in storage and in backup files.  `flate2` uses the pure Rust
+/// `miniz_oxide` backend, so the same code runs on WASM and native targets.
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::{Read, Write};
+/// Compress `data` with gzip.
+#[must_use]
+pub fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    // Writing to a `Vec` cannot fail.
+    let _ = encoder.write_all(data);
+    encoder.finish().unwrap_or_default()
+}
+/// Decompress gzip `data`.
+///
+/// # Errors
+///
+/// Returns an [`std::io::Error`] when `data` is not valid gzip.
+pub fn gunzip(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    GzDecoder::new(data).read_to_end(&mut out)?;
+    Ok(out)
+}
+/// Decompress gzip `data` holding UTF-8 text.
+///
+/// # Errors
+///
+/// Returns an [`std::io::Error`] when `data` is not valid gzip or UTF-8.
+pub fn gunzip_str(data: &[u8]) -> std::io::Result<String> {
+    String::from_utf8(gunzip(data)?)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn gzip_round_trips_and_shrinks_repetitive_json() {
+        let json = r#"{"exercise_id":"squat","reps":5}"#.repeat(100);
+        let packed = gzip(json.as_bytes());
+        assert!(packed.len() * 10 < json.len());
+        assert_eq!(gunzip_str(&packed).unwrap(), json);
+        assert!(gunzip(json.as_bytes()).is_err());
+    }
+}
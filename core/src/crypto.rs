@@ -0,0 +1,149 @@
+/// Symmetric encryption primitives shared by sync, backups and the app lock.
+///
+/// Keys are derived from a user passphrase with PBKDF2-HMAC-SHA256, and data
+/// is sealed with XChaCha20-Poly1305 (random 24-byte nonce prepended to the
+/// ciphertext).  All crates used here are pure Rust so the same code runs on
+/// WASM and native targets.
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+/// PBKDF2 iteration count (OWASP 2023 recommendation for HMAC-SHA256).
+pub const PBKDF2_ITERATIONS: u32 = 600_000;
+/// Length in bytes of derived keys.
+pub const KEY_LEN: usize = 32;
+/// Length in bytes of the XChaCha20 nonce prepended to every ciphertext.
+const NONCE_LEN: usize = 24;
+/// A derived 256-bit encryption key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretKey([u8; KEY_LEN]);
+impl SecretKey {
+    /// Wrap raw key bytes (e.g. a random transfer key).
+    #[must_use]
+    pub fn from_bytes(bytes: [u8; KEY_LEN]) -> Self {
+        Self(bytes)
+    }
+    /// Raw key bytes, for embedding in a pairing token.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8; KEY_LEN] {
+        &self.0
+    }
+}
+impl std::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretKey(…)")
+    }
+}
+/// Errors raised by [`encrypt`] / [`decrypt`].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CryptoError {
+    /// The operating system / browser random source failed.
+    #[error("random number generator unavailable")]
+    Rng,
+    /// Encryption failed (should not happen with valid inputs).
+    #[error("encryption failed")]
+    Encrypt,
+    /// Wrong key, truncated or tampered ciphertext.
+    #[error("decryption failed: wrong passphrase or corrupted data")]
+    Decrypt,
+}
+/// Derive a key from `passphrase` and `salt` using PBKDF2-HMAC-SHA256.
+#[must_use]
+pub fn derive_key(passphrase: &str, salt: &[u8]) -> SecretKey {
+    derive_key_with_iterations(passphrase, salt, PBKDF2_ITERATIONS)
+}
+/// Like [`derive_key`] with an explicit iteration count (used by tests).
+#[must_use]
+pub fn derive_key_with_iterations(passphrase: &str, salt: &[u8], iterations: u32) -> SecretKey {
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2::pbkdf2_hmac::<sha2::Sha256>(passphrase.as_bytes(), salt, iterations, &mut key);
+    SecretKey(key)
+}
+/// Fill a new buffer of `len` bytes from the platform CSPRNG.
+///
+/// # Errors
+///
+/// Returns [`CryptoError::Rng`] if the random source is unavailable.
+pub fn random_bytes(len: usize) -> Result<Vec<u8>, CryptoError> {
+    let mut buf = vec![0u8; len];
+    getrandom::getrandom(&mut buf).map_err(|_| CryptoError::Rng)?;
+    Ok(buf)
+}
+/// Encrypt `plaintext`, returning `nonce || ciphertext || tag`.
+///
+/// # Errors
+///
+/// Returns [`CryptoError::Rng`] if no nonce can be generated.
+pub fn encrypt(key: &SecretKey, plaintext: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    let cipher = XChaCha20Poly1305::new((&key.0).into());
+    let nonce_bytes = random_bytes(NONCE_LEN)?;
+    let nonce = XNonce::from_slice(&nonce_bytes);
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext)
+        .map_err(|_| CryptoError::Encrypt)?;
+    let mut out = nonce_bytes;
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+/// Decrypt data produced by [`encrypt`].
+///
+/// # Errors
+///
+/// Returns [`CryptoError::Decrypt`] on a wrong key or corrupted input.
+pub fn decrypt(key: &SecretKey, data: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    if data.len() < NONCE_LEN {
+        return Err(CryptoError::Decrypt);
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    XChaCha20Poly1305::new((&key.0).into())
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| CryptoError::Decrypt)
+}
+/// Encode bytes as standard base64 (for embedding in JSON / config values).
+#[must_use]
+pub fn to_base64(data: &[u8]) -> String {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD.encode(data)
+}
+/// Decode standard base64, returning `None` on malformed input.
+#[must_use]
+pub fn from_base64(s: &str) -> Option<Vec<u8>> {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD.decode(s).ok()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    /// Low iteration count so tests stay fast.
+    const TEST_ITERATIONS: u32 = 1_000;
+    #[test]
+    fn round_trip() {
+        let key = derive_key_with_iterations("correct horse", b"salt", TEST_ITERATIONS);
+        let sealed = encrypt(&key, b"squat 100kg x5").unwrap();
+        assert_eq!(decrypt(&key, &sealed).unwrap(), b"squat 100kg x5");
+    }
+    #[test]
+    fn nonces_are_random() {
+        let key = derive_key_with_iterations("pw", b"salt", TEST_ITERATIONS);
+        assert_ne!(encrypt(&key, b"x").unwrap(), encrypt(&key, b"x").unwrap());
+    }
+    #[test]
+    fn wrong_key_fails() {
+        let key = derive_key_with_iterations("pw", b"salt", TEST_ITERATIONS);
+        let other = derive_key_with_iterations("pw2", b"salt", TEST_ITERATIONS);
+        let sealed = encrypt(&key, b"x").unwrap();
+        assert_eq!(decrypt(&other, &sealed), Err(CryptoError::Decrypt));
+        assert_eq!(decrypt(&key, &sealed[..4]), Err(CryptoError::Decrypt));
+    }
+    #[test]
+    fn derivation_depends_on_salt() {
+        assert_ne!(
+            derive_key_with_iterations("pw", b"a", TEST_ITERATIONS),
+            derive_key_with_iterations("pw", b"b", TEST_ITERATIONS)
+        );
+    }
+    #[test]
+    fn base64_round_trip() {
+        let data = [0u8, 1, 2, 250, 255];
+        assert_eq!(from_base64(&to_base64(&data)).unwrap(), data);
+        assert!(from_base64("not base64!").is_none());
+    }
+}
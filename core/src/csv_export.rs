@@ -0,0 +1,124 @@
+/// CSV export of the workout history, one row per logged set.
+///
+/// Meant for spreadsheets and scripts: weights are in kilograms, distances in
+/// metres, durations in seconds and times are Unix timestamps, with the local
+/// day of the session in the first column.
+use crate::models::{WorkoutSession, HG_PER_KG};
+use std::fmt::Write as _;
+/// Column names of the header row.
+const HEADER: [&str; 15] = [
+    "date",
+    "session_id",
+    "exercise_id",
+    "exercise_name",
+    "category",
+    "set_kind",
+    "side",
+    "start_time",
+    "end_time",
+    "duration_s",
+    "weight_kg",
+    "assisted",
+    "reps",
+    "distance_m",
+    "notes",
+];
+/// Render every set of `sessions` as CSV (RFC 4180, CRLF line endings).
+#[must_use]
+pub fn sessions_to_csv(sessions: &[WorkoutSession]) -> String {
+    let mut out = String::new();
+    push_row(&mut out, HEADER.map(String::from));
+    for session in sessions {
+        let date = crate::datetime::to_local(session.start_time)
+            .date()
+            .to_string();
+        for log in &session.exercise_logs {
+            let optional = |v: Option<String>| v.unwrap_or_default();
+            push_row(
+                &mut out,
+                [
+                    date.clone(),
+                    session.id.clone(),
+                    log.exercise_id.clone(),
+                    log.exercise_name.clone(),
+                    log.category.as_ref().to_owned(),
+                    log.set_kind.as_ref().to_owned(),
+                    optional(log.side.map(|s| s.as_ref().to_owned())),
+                    log.start_time.to_string(),
+                    optional(log.end_time.map(|t| t.to_string())),
+                    optional(log.duration_seconds().map(|d| d.to_string())),
+                    if log.weight_hg.0 == 0 {
+                        String::new()
+                    } else {
+                        (f64::from(log.weight_hg.0) / HG_PER_KG).to_string()
+                    },
+                    log.assisted.to_string(),
+                    optional(log.reps.map(|r| r.to_string())),
+                    optional(log.distance_m.map(|d| d.0.to_string())),
+                    log.notes.clone(),
+                ],
+            );
+        }
+    }
+    out
+}
+/// Append one CRLF-terminated row, quoting the fields that need it.
+fn push_row<const N: usize>(out: &mut String, fields: [String; N]) {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            let _ = write!(out, "\"{}\"", field.replace('"', "\"\""));
+        } else {
+            out.push_str(field);
+        }
+    }
+    out.push_str("\r\n");
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ExerciseLog, SetKind, Side, Weight};
+    fn log(notes: &str) -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: "bench".into(),
+            exercise_name: "Bench Press".into(),
+            start_time: 1_700_000_000,
+            end_time: Some(1_700_000_045),
+            weight_hg: Weight(775),
+            reps: Some(8),
+            notes: notes.into(),
+            side: Some(Side::Left),
+            set_kind: SetKind::WarmUp,
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn each_set_becomes_a_row_in_metric_units() {
+        let session = WorkoutSession {
+            id: "s1".into(),
+            start_time: 1_700_000_000,
+            end_time: Some(1_700_003_600),
+            exercise_logs: vec![log("")],
+            ..WorkoutSession::default()
+        };
+        let csv = sessions_to_csv(&[session]);
+        let mut rows = csv.split("\r\n");
+        assert_eq!(rows.next(), Some(HEADER.join(",").as_str()));
+        let row = rows.next().unwrap();
+        assert!(row.ends_with(
+            ",s1,bench,Bench Press,strength,warm_up,left,1700000000,1700000045,45,77.5,false,8,,"
+        ));
+        assert_eq!(rows.next(), Some(""));
+    }
+    #[test]
+    fn fields_with_separators_are_quoted() {
+        let mut out = String::new();
+        push_row(
+            &mut out,
+            ["a,b".to_owned(), "say \"hi\"".to_owned(), "x".to_owned()],
+        );
+        assert_eq!(out, "\"a,b\",\"say \"\"hi\"\"\",x\r\n");
+    }
+}
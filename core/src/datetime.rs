@@ -0,0 +1,296 @@
+//! Date helpers working in the device's local timezone.
+//!
+//! Sessions store UTC Unix timestamps; every calendar computation (which day
+//! a session happened on, how many days ago, which week or month it belongs
+//! to) must first convert to local time, using the UTC offset in effect *at
+//! that timestamp* so dates across a daylight-saving change stay correct.
+use time::{Date, Duration, Month, OffsetDateTime, UtcOffset};
+/// Seconds in a minute.
+pub const SECONDS_IN_MINUTE: u64 = 60;
+/// Seconds in an hour.
+pub const SECONDS_IN_HOUR: u64 = 3600;
+/// Seconds in a day.
+pub const SECONDS_IN_DAY: u64 = 86400;
+/// Local UTC offset in effect at `timestamp` (Unix seconds).
+///
+/// Falls back to UTC when the offset cannot be determined (e.g. native
+/// multi-threaded processes where `time` refuses to query it).
+#[must_use]
+pub fn local_offset_at(timestamp: u64) -> UtcOffset {
+    #[cfg(target_arch = "wasm32")]
+    {
+        #[allow(clippy::cast_precision_loss)]
+        let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(timestamp as f64 * 1000.0));
+        #[allow(clippy::cast_possible_truncation)]
+        let offset_secs = -(date.get_timezone_offset() as i32) * 60;
+        UtcOffset::from_whole_seconds(offset_secs).unwrap_or(UtcOffset::UTC)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        OffsetDateTime::from_unix_timestamp(timestamp.cast_signed())
+            .ok()
+            .and_then(|utc| UtcOffset::local_offset_at(utc).ok())
+            .unwrap_or(UtcOffset::UTC)
+    }
+}
+/// `timestamp` (Unix seconds) as a local date-time.
+#[must_use]
+pub fn to_local(timestamp: u64) -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp(timestamp.cast_signed())
+        .unwrap_or(OffsetDateTime::UNIX_EPOCH)
+        .to_offset(local_offset_at(timestamp))
+}
+/// Today's local date.
+#[must_use]
+pub fn today() -> Date {
+    to_local(crate::models::get_current_timestamp()).date()
+}
+/// Number of calendar days between the local day of `timestamp` and today
+/// (0 = today, 1 = yesterday, …).  Use together with `t!()` in a component to
+/// produce a localised relative date string.
+#[must_use]
+pub fn session_days_ago(timestamp: u64) -> i64 {
+    (today() - to_local(timestamp).date()).whole_days()
+}
+/// Format a session timestamp as a human-readable relative date string.
+///
+/// Returns English strings; for localised output use [`session_days_ago`] with
+/// `t!()` in a component.
+#[must_use]
+pub fn format_session_date(timestamp: u64) -> String {
+    match session_days_ago(timestamp) {
+        0 => "Today".to_string(),
+        1 => "Yesterday".to_string(),
+        n => format!("{n} days ago"),
+    }
+}
+/// Returns `true` when `timestamp` falls on the same weekday as today in the
+/// local timezone (e.g. both are Monday), regardless of the calendar week.
+/// Used to suggest repeating a session performed on the same day of the week.
+#[must_use]
+pub fn is_same_weekday_as_today(timestamp: u64) -> bool {
+    to_local(timestamp).weekday() == today().weekday()
+}
+/// Returns a short, locale-sensitive date string for `timestamp_secs` suitable
+/// for display on a compact button (e.g. "03/28" for English or "28/03" for
+/// French / Spanish).
+///
+/// The format is `MM/DD` when `lang` starts with `"en"`, and `DD/MM` for all
+/// other language tags, matching common European conventions.
+#[must_use]
+pub fn format_short_date(timestamp_secs: u64, lang: &str) -> String {
+    let dt = to_local(timestamp_secs);
+    let day = dt.day();
+    let month = dt.month() as u8;
+    if lang.starts_with("en") {
+        format!("{month:02}/{day:02}")
+    } else {
+        format!("{day:02}/{month:02}")
+    }
+}
+/// Parse a `YYYY-MM-DD` date, as produced by `<input type="date">`.
+#[must_use]
+pub fn parse_iso_date(input: &str) -> Option<Date> {
+    let mut parts = input.trim().splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = Month::try_from(parts.next()?.parse::<u8>().ok()?).ok()?;
+    let day = parts.next()?.parse().ok()?;
+    Date::from_calendar_date(year, month, day).ok()
+}
+/// Local time of day of `timestamp` as `HH:MM:SS`, the value of an
+/// `<input type="time" step="1">`.
+#[must_use]
+pub fn format_clock_time(timestamp: u64) -> String {
+    let dt = to_local(timestamp);
+    format!("{:02}:{:02}:{:02}", dt.hour(), dt.minute(), dt.second())
+}
+/// Timestamp of the local day of `timestamp` at the time of day `input`
+/// (`HH:MM` or `HH:MM:SS`, see [`format_clock_time`]).
+#[must_use]
+pub fn with_clock_time(timestamp: u64, input: &str) -> Option<u64> {
+    let mut parts = input.trim().splitn(3, ':');
+    let hour = parts.next()?.parse().ok()?;
+    let minute = parts.next()?.parse().ok()?;
+    let second = parts.next().map_or(Some(0), |s| s.parse().ok())?;
+    let dt = to_local(timestamp).replace_time(time::Time::from_hms(hour, minute, second).ok()?);
+    u64::try_from(dt.unix_timestamp()).ok()
+}
+/// Monday of the week `date` falls in.
+#[must_use]
+pub fn monday_of(date: Date) -> Date {
+    date - Duration::days(i64::from(date.weekday().number_days_from_monday()))
+}
+/// Header under which a past session is listed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateGroup {
+    /// Since Monday of the current week.
+    ThisWeek,
+    /// The previous Monday-to-Sunday week.
+    LastWeek,
+    /// Any earlier date, grouped by calendar month.
+    Month { year: i32, month: Month },
+}
+/// Group of a session on local `date`, relative to local `today`.
+#[must_use]
+pub fn date_group(date: Date, today: Date) -> DateGroup {
+    let this_monday = monday_of(today);
+    if date >= this_monday {
+        DateGroup::ThisWeek
+    } else if date >= this_monday - Duration::weeks(1) {
+        DateGroup::LastWeek
+    } else {
+        DateGroup::Month {
+            year: date.year(),
+            month: date.month(),
+        }
+    }
+}
+/// Group of a session started at `timestamp` (see [`date_group`]).
+#[must_use]
+pub fn session_group(timestamp: u64) -> DateGroup {
+    date_group(to_local(timestamp).date(), today())
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::from_calendar_date(year, Month::try_from(month).unwrap(), day).unwrap()
+    }
+    fn today_midnight_local_secs() -> u64 {
+        let now = to_local(crate::models::get_current_timestamp());
+        let midnight = now.replace_time(time::Time::MIDNIGHT);
+        midnight.unix_timestamp().max(0).cast_unsigned()
+    }
+    #[test]
+    fn format_session_date_today() {
+        let ts = today_midnight_local_secs() + SECONDS_IN_HOUR;
+        assert_eq!(format_session_date(ts), "Today");
+    }
+    #[test]
+    fn format_session_date_yesterday() {
+        let ts = today_midnight_local_secs() - 1;
+        assert_eq!(format_session_date(ts), "Yesterday");
+    }
+    #[test]
+    fn format_session_date_days_ago() {
+        let ts = today_midnight_local_secs() - SECONDS_IN_DAY * 3;
+        assert_eq!(format_session_date(ts), "3 days ago");
+    }
+    #[test]
+    fn format_session_date_beginning_of_today() {
+        let ts = today_midnight_local_secs();
+        assert_eq!(format_session_date(ts), "Today");
+    }
+    #[test]
+    fn format_session_date_end_of_yesterday() {
+        let ts = today_midnight_local_secs() - 1;
+        assert_eq!(format_session_date(ts), "Yesterday");
+    }
+    #[test]
+    fn format_session_date_two_days_ago() {
+        let ts = today_midnight_local_secs() - SECONDS_IN_DAY * 2;
+        assert_eq!(format_session_date(ts), "2 days ago");
+    }
+    #[test]
+    fn days_since_uses_local_midnight_boundary() {
+        let midnight = today_midnight_local_secs();
+        assert_eq!(
+            session_days_ago(midnight),
+            0,
+            "local midnight should be day 0"
+        );
+    }
+    #[test]
+    fn session_days_ago_today_is_zero() {
+        let midnight = today_midnight_local_secs();
+        assert_eq!(session_days_ago(midnight + SECONDS_IN_HOUR), 0);
+    }
+    #[test]
+    fn session_days_ago_yesterday_is_one() {
+        let midnight = today_midnight_local_secs();
+        assert_eq!(session_days_ago(midnight - 1), 1);
+    }
+    #[test]
+    fn session_days_ago_seven_days() {
+        let midnight = today_midnight_local_secs();
+        assert_eq!(session_days_ago(midnight - SECONDS_IN_DAY * 7), 7,);
+    }
+    #[test]
+    fn is_same_weekday_as_today_for_today() {
+        let midnight = today_midnight_local_secs();
+        // A timestamp from earlier today must share today's weekday.
+        assert!(is_same_weekday_as_today(midnight + SECONDS_IN_HOUR));
+    }
+    #[test]
+    fn is_same_weekday_as_today_for_yesterday() {
+        let midnight = today_midnight_local_secs();
+        // Yesterday has a different weekday (unless two days differ by 7, but
+        // yesterday is exactly 1 day ago so different weekday).
+        assert!(!is_same_weekday_as_today(midnight - 1));
+    }
+    #[test]
+    fn is_same_weekday_as_today_for_same_weekday_last_week() {
+        let midnight = today_midnight_local_secs();
+        // Exactly 7 days ago is the same weekday.
+        assert!(is_same_weekday_as_today(
+            midnight - SECONDS_IN_DAY * 7 + SECONDS_IN_HOUR
+        ));
+    }
+    #[test]
+    fn format_short_date_en() {
+        let midnight = today_midnight_local_secs();
+        let s = format_short_date(midnight + SECONDS_IN_HOUR, "en");
+        // Format should be MM/DD with two digits each.
+        assert_eq!(s.len(), 5, "en short date should be 5 chars: {s}");
+        assert_eq!(&s[2..3], "/");
+    }
+    #[test]
+    fn format_short_date_fr() {
+        let midnight = today_midnight_local_secs();
+        let s = format_short_date(midnight + SECONDS_IN_HOUR, "fr");
+        // Format should be DD/MM with two digits each.
+        assert_eq!(s.len(), 5, "fr short date should be 5 chars: {s}");
+        assert_eq!(&s[2..3], "/");
+    }
+    #[test]
+    fn clock_time_round_trips_within_the_day() {
+        let ts = 1_700_000_000;
+        assert_eq!(with_clock_time(ts, &format_clock_time(ts)), Some(ts));
+        let minute_earlier = format_clock_time(ts - 60);
+        assert_eq!(
+            with_clock_time(ts, &minute_earlier[..5]),
+            Some(ts - 60 - ts % 60)
+        );
+        assert_eq!(with_clock_time(ts, "25:00"), None);
+        assert_eq!(with_clock_time(ts, "noon"), None);
+    }
+    #[test]
+    fn parse_iso_date_accepts_date_input_values() {
+        assert_eq!(parse_iso_date("2025-03-09"), Some(date(2025, 3, 9)));
+        assert_eq!(parse_iso_date(""), None);
+        assert_eq!(parse_iso_date("2025-02-30"), None);
+        assert_eq!(parse_iso_date("09/03/2025"), None);
+    }
+    #[test]
+    fn groups_by_week_then_month() {
+        // Wednesday 2025-03-19.
+        let today = date(2025, 3, 19);
+        assert_eq!(date_group(date(2025, 3, 17), today), DateGroup::ThisWeek);
+        assert_eq!(date_group(date(2025, 3, 16), today), DateGroup::LastWeek);
+        assert_eq!(date_group(date(2025, 3, 10), today), DateGroup::LastWeek);
+        assert_eq!(
+            date_group(date(2025, 3, 9), today),
+            DateGroup::Month {
+                year: 2025,
+                month: Month::March
+            }
+        );
+        assert_eq!(
+            date_group(date(2024, 12, 31), today),
+            DateGroup::Month {
+                year: 2024,
+                month: Month::December
+            }
+        );
+    }
+}
@@ -0,0 +1,163 @@
+/// Filtering of past sessions on the home page ("last time I deadlifted").
+///
+/// A session matches when its location, its notes, the notes of one of its
+/// sets, or the name or muscles of one of its exercises contain the text query, and when
+/// it started within the optional local-date range.  Matching is case-insensitive and works on
+/// both English and localised names.
+use crate::models::{resolve_exercise, Exercise, Muscle, WorkoutSession};
+use time::Date;
+/// Criteria selecting past sessions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryFilter {
+    /// Exercise name, muscle name, location or notes fragment (empty: any).
+    pub query: String,
+    /// First local day included.
+    pub from: Option<Date>,
+    /// Last local day included.
+    pub to: Option<Date>,
+}
+impl HistoryFilter {
+    /// Whether the filter lets every session through.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.query.trim().is_empty() && self.from.is_none() && self.to.is_none()
+    }
+    /// Whether `session` satisfies the filter.
+    ///
+    /// `lang` selects the localised exercise names; `muscle_label` returns the
+    /// localised name of a muscle (English names always match too).
+    #[must_use]
+    pub fn matches<E: AsRef<Exercise>>(
+        &self,
+        session: &WorkoutSession,
+        db: &[E],
+        custom: &[E],
+        lang: &str,
+        muscle_label: impl Fn(Muscle) -> String,
+    ) -> bool {
+        let day = crate::datetime::to_local(session.start_time).date();
+        if self.from.is_some_and(|from| day < from) || self.to.is_some_and(|to| day > to) {
+            return false;
+        }
+        let query = self.query.trim().to_lowercase();
+        if query.is_empty() {
+            return true;
+        }
+        let hit = |text: &str| text.to_lowercase().contains(&query);
+        if hit(&session.notes) || session.location.as_deref().is_some_and(hit) {
+            return true;
+        }
+        session.exercise_logs.iter().any(|log| {
+            if hit(&log.exercise_name) || hit(&log.notes) {
+                return true;
+            }
+            let Some(exercise) = resolve_exercise(db, custom, &log.exercise_id) else {
+                return false;
+            };
+            let exercise = exercise.as_ref();
+            hit(exercise.name_for_lang(lang))
+                || exercise
+                    .primary_muscles
+                    .iter()
+                    .chain(&exercise.secondary_muscles)
+                    .any(|&m| hit(m.as_ref()) || hit(&muscle_label(m)))
+        })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, ExerciseLog, Weight};
+    fn exercise(id: &str, name: &str, muscles: Vec<Muscle>) -> Exercise {
+        Exercise {
+            id: id.into(),
+            name: name.into(),
+            name_lower: name.to_lowercase(),
+            force: None,
+            level: None,
+            mechanic: None,
+            equipment: None,
+            primary_muscles: muscles,
+            secondary_muscles: vec![],
+            instructions: vec![],
+            category: Category::Strength,
+            images: vec![],
+            videos: vec![],
+            i18n: None,
+            rest_seconds: None,
+        }
+    }
+    fn session_with(id: &str, name: &str, start_time: u64) -> WorkoutSession {
+        let mut s = WorkoutSession::new();
+        s.start_time = start_time;
+        s.end_time = Some(start_time + 60);
+        s.exercise_logs.push(ExerciseLog {
+            exercise_id: id.into(),
+            exercise_name: name.into(),
+            start_time,
+            end_time: Some(start_time + 30),
+            weight_hg: Weight(1000),
+            reps: Some(5),
+            ..Default::default()
+        });
+        s
+    }
+    fn matches(filter: &HistoryFilter, session: &WorkoutSession) -> bool {
+        let db = vec![exercise(
+            "deadlift",
+            "Barbell Deadlift",
+            vec![Muscle::LowerBack],
+        )];
+        filter.matches(session, &db, &[], "en", |m| match m {
+            Muscle::LowerBack => "lombaires".into(),
+            other => other.to_string(),
+        })
+    }
+    #[test]
+    fn empty_filter_matches_everything() {
+        let filter = HistoryFilter::default();
+        assert!(filter.is_empty());
+        assert!(matches(&filter, &session_with("x", "Anything", 0)));
+    }
+    #[test]
+    fn query_matches_exercise_and_muscle_names() {
+        let session = session_with("deadlift", "Barbell Deadlift", 1_700_000_000);
+        let by = |q: &str| HistoryFilter {
+            query: q.into(),
+            ..HistoryFilter::default()
+        };
+        assert!(matches(&by("deadl"), &session));
+        assert!(matches(&by("LOWER BACK"), &session));
+        assert!(matches(&by("lombaires"), &session));
+        assert!(!matches(&by("squat"), &session));
+    }
+    #[test]
+    fn query_matches_session_and_set_notes() {
+        let mut session = session_with("deadlift", "Barbell Deadlift", 1_700_000_000);
+        session.notes = "Felt **strong**".into();
+        session.exercise_logs[0].notes = "Lower back tight".into();
+        session.location = Some("Downtown Gym".into());
+        let by = |q: &str| HistoryFilter {
+            query: q.into(),
+            ..HistoryFilter::default()
+        };
+        assert!(matches(&by("strong"), &session));
+        assert!(matches(&by("tight"), &session));
+        assert!(matches(&by("downtown"), &session));
+        assert!(!matches(&by("knee"), &session));
+    }
+    #[test]
+    fn date_range_is_inclusive() {
+        let ts = 1_700_000_000;
+        let session = session_with("deadlift", "Barbell Deadlift", ts);
+        let day = crate::datetime::to_local(ts).date();
+        let range = |from, to| HistoryFilter {
+            from,
+            to,
+            ..HistoryFilter::default()
+        };
+        assert!(matches(&range(Some(day), Some(day)), &session));
+        assert!(!matches(&range(day.next_day(), None), &session));
+        assert!(!matches(&range(None, day.previous_day()), &session));
+    }
+}
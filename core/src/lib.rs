@@ -0,0 +1,27 @@
+//! **`logout-core`** – the UI-free data core of `LogOut`
+//!
+//! Data models, the `IndexedDB` and `SQLite` storage backends, analytics
+//! computations and import/export formats, shared by the Dioxus app and its
+//! companions.  Nothing here depends on Dioxus, so it can be unit-tested and
+//! reused on its own.
+pub mod achievements;
+pub mod activity_export;
+pub mod backup;
+pub mod calendar_export;
+pub mod compress;
+pub mod crypto;
+pub mod csv_export;
+/// Local-timezone date helpers (relative dates, session list grouping).
+pub mod datetime;
+pub mod history_filter;
+pub mod models;
+pub mod query;
+pub mod rest_stats;
+pub mod session_stats;
+pub mod share_link;
+pub mod storage;
+pub mod strain;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod sync_server;
+pub mod upstream_export;
+pub mod webhook;
@@ -0,0 +1,311 @@
+use crate::models::{ExerciseLog, Side, WorkoutSession, HG_PER_KG, M_PER_KM};
+use std::collections::BTreeMap;
+use time::Date;
+/// Minimum average duration (in minutes) below which values are displayed in seconds.
+const DURATION_MINS_SECS_THRESHOLD: f64 = 3.0;
+/// Minimum average duration (in minutes) below which values are displayed in minutes rather than hours.
+const DURATION_HOURS_MINS_THRESHOLD: f64 = 180.0;
+
+#[derive(Clone, Copy, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum Metric {
+    Weight,
+    Reps,
+    Distance,
+    Duration,
+}
+
+impl Metric {
+    /// Returns the index of this metric in the `available_by_metric` array.
+    pub fn to_index(self) -> usize {
+        match self {
+            Metric::Weight => 0,
+            Metric::Reps => 1,
+            Metric::Distance => 2,
+            Metric::Duration => 3,
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn extract_value(self, log: &ExerciseLog) -> Option<f64> {
+        match self {
+            Metric::Weight => log.load_kg(),
+            Metric::Reps => log.reps.map(f64::from),
+            Metric::Distance => log.distance_m.map(|d| f64::from(d.0) / M_PER_KM),
+            Metric::Duration => log.duration_seconds().map(|d| d as f64 / 60.0),
+        }
+    }
+}
+
+/// Determine the most adapted display unit for a metric based on the actual
+/// data values, so the Y-axis stays in a readable range.
+/// Returns `(short_unit, scale_factor)` where `scale_factor` is applied to
+/// the raw values to produce display values.
+pub fn adapt_metric_unit(metric: Metric, values: &[f64]) -> (&'static str, f64) {
+    let avg = if values.is_empty() {
+        0.0
+    } else {
+        #[allow(clippy::cast_precision_loss)]
+        {
+            values.iter().sum::<f64>() / (values.len() as f64)
+        }
+    };
+    match metric {
+        Metric::Weight => ("kg", 1.0),
+        Metric::Reps => ("reps", 1.0),
+        Metric::Distance => {
+            if avg < 1.0 {
+                ("m", M_PER_KM)
+            } else {
+                ("km", 1.0)
+            }
+        }
+        Metric::Duration => {
+            if avg < DURATION_MINS_SECS_THRESHOLD {
+                ("s", 60.0)
+            } else if avg < DURATION_HOURS_MINS_THRESHOLD {
+                ("min", 1.0)
+            } else {
+                ("h", 1.0 / 60.0)
+            }
+        }
+    }
+}
+
+/// Best value of `metric` on each side among the unilateral sets in `logs`,
+/// as `(left, right)`, when both sides were logged.
+pub fn side_bests<'a>(
+    metric: Metric,
+    logs: impl IntoIterator<Item = &'a ExerciseLog>,
+) -> Option<(f64, f64)> {
+    let (mut left, mut right) = (None::<f64>, None::<f64>);
+    for log in logs {
+        let (Some(side), Some(value)) = (log.side, metric.extract_value(log)) else {
+            continue;
+        };
+        let best = match side {
+            Side::Left => &mut left,
+            Side::Right => &mut right,
+        };
+        *best = Some(best.map_or(value, |b| b.max(value)));
+    }
+    left.zip(right)
+}
+
+/// Gap between the sides in percent of the stronger one; positive when the
+/// left side is stronger.
+pub fn asymmetry_percent(left: f64, right: f64) -> f64 {
+    let stronger = left.max(right);
+    if stronger <= 0.0 {
+        0.0
+    } else {
+        100.0 * (left - right) / stronger
+    }
+}
+
+/// Highest estimated one-repetition maximum (kg) among `logs`, body weight
+/// included for bodyweight sets.
+pub fn best_one_rep_max<'a>(logs: impl IntoIterator<Item = &'a ExerciseLog>) -> Option<f64> {
+    logs.into_iter()
+        .filter_map(ExerciseLog::estimated_one_rep_max_kg)
+        .reduce(f64::max)
+}
+
+/// Sessions in a row without progress after which an exercise is considered
+/// to have plateaued.
+pub const PLATEAU_SESSIONS: usize = 4;
+
+/// Performance of a working set, compared across sessions: its estimated
+/// one-repetition maximum, else its load, repetitions, distance or duration.
+fn set_performance(log: &ExerciseLog) -> Option<f64> {
+    if !log.set_kind.counts_for_stats() {
+        return None;
+    }
+    log.estimated_one_rep_max_kg()
+        .or_else(|| Metric::Weight.extract_value(log))
+        .or_else(|| Metric::Reps.extract_value(log))
+        .or_else(|| Metric::Distance.extract_value(log))
+        .or_else(|| Metric::Duration.extract_value(log))
+}
+
+/// Number of training days (one per local day) since the best performance
+/// among `logs` was last beaten; `None` without any measurable set.
+pub fn sessions_without_progress<'a>(
+    logs: impl IntoIterator<Item = &'a ExerciseLog>,
+) -> Option<usize> {
+    let mut daily_bests: BTreeMap<Date, f64> = BTreeMap::new();
+    for log in logs {
+        if let Some(performance) = set_performance(log) {
+            let day = crate::datetime::to_local(log.start_time).date();
+            let best = daily_bests.entry(day).or_insert(performance);
+            *best = best.max(performance);
+        }
+    }
+    let mut record = f64::NEG_INFINITY;
+    let mut since_record = None;
+    for best in daily_bests.into_values() {
+        if best > record {
+            record = best;
+            since_record = Some(0);
+        } else {
+            since_record = since_record.map(|n| n + 1);
+        }
+    }
+    since_record
+}
+
+/// Training done over a range of local days, for the period comparison.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]
+pub struct PeriodSummary {
+    /// Completed sessions started within the range.
+    pub sessions: usize,
+    /// Sessions per week, over the length of the range.
+    pub sessions_per_week: f64,
+    /// Total volume lifted in kg, warm-up sets excluded.
+    pub volume_kg: f64,
+    /// Heaviest load (kg) lifted per exercise ID.
+    pub bests: BTreeMap<String, f64>,
+}
+
+impl PeriodSummary {
+    /// Summarise the completed `sessions` started between the local days
+    /// `from` and `to`, both included.
+    pub fn of(sessions: &[WorkoutSession], from: Date, to: Date) -> Self {
+        let mut summary = Self::default();
+        let mut volume_hg = 0u64;
+        let in_range = sessions.iter().filter(|s| {
+            let day = crate::datetime::to_local(s.start_time).date();
+            !s.is_active() && from <= day && day <= to
+        });
+        for session in in_range {
+            summary.sessions += 1;
+            for log in &session.exercise_logs {
+                volume_hg += log.volume_hg();
+                if !log.set_kind.counts_for_stats() {
+                    continue;
+                }
+                if let Some(load) = Metric::Weight.extract_value(log) {
+                    let best = summary.bests.entry(log.exercise_id.clone()).or_insert(load);
+                    *best = best.max(load);
+                }
+            }
+        }
+        #[allow(clippy::cast_precision_loss)]
+        {
+            summary.volume_kg = volume_hg as f64 / HG_PER_KG;
+            let days = ((to - from).whole_days() + 1).max(1);
+            summary.sessions_per_week = summary.sessions as f64 * 7.0 / days as f64;
+        }
+        summary
+    }
+}
+
+/// Relative change from `before` to `after` in percent, `None` when there
+/// is nothing to compare against.
+pub fn delta_percent(before: f64, after: f64) -> Option<f64> {
+    (before > 0.0).then(|| 100.0 * (after - before) / before)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SetKind, Weight};
+    use time::Month;
+
+    fn set(side: Option<Side>, weight_hg: u16) -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: "curl".into(),
+            exercise_name: "Curl".into(),
+            end_time: Some(30),
+            weight_hg: Weight(weight_hg),
+            reps: Some(10),
+            side,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn side_bests_need_both_sides() {
+        let logs = [
+            set(Some(Side::Left), 120),
+            set(Some(Side::Left), 140),
+            set(Some(Side::Right), 100),
+            set(None, 200),
+        ];
+        assert_eq!(side_bests(Metric::Weight, &logs), Some((14.0, 10.0)));
+        assert_eq!(side_bests(Metric::Weight, &logs[..2]), None);
+        let (left, right) = side_bests(Metric::Weight, &logs).unwrap();
+        assert!((asymmetry_percent(left, right) - 100.0 * 4.0 / 14.0).abs() < 1e-9);
+        assert!(asymmetry_percent(right, left) < 0.0);
+    }
+
+    #[test]
+    fn weight_accounts_for_body_weight() {
+        let mut dip = set(None, 100);
+        assert_eq!(Metric::Weight.extract_value(&dip), Some(10.0));
+        dip.body_weight_hg = Some(Weight(700));
+        assert_eq!(Metric::Weight.extract_value(&dip), Some(80.0));
+        dip.assisted = true;
+        assert_eq!(Metric::Weight.extract_value(&dip), Some(60.0));
+        dip.weight_hg = Weight(0);
+        assert_eq!(Metric::Weight.extract_value(&dip), Some(70.0));
+    }
+
+    #[test]
+    fn best_one_rep_max_uses_epley() {
+        let mut light = set(None, 600);
+        light.reps = Some(15);
+        let heavy = set(None, 900);
+        let one_rm = best_one_rep_max([&light, &heavy]).unwrap();
+        assert!((one_rm - 90.0 * (1.0 + 10.0 / 30.0)).abs() < 1e-9);
+        light.reps = None;
+        assert_eq!(best_one_rep_max([&light]), None);
+    }
+
+    #[test]
+    fn period_summary_counts_sessions_volume_and_bests() {
+        let day = |d: u8| Date::from_calendar_date(2025, Month::October, d).unwrap();
+        let session = |d: u8, weight_hg: u16| {
+            let mut s = WorkoutSession::new();
+            // Noon UTC, the same local day in most timezones.
+            s.start_time = u64::try_from(day(d).midnight().assume_utc().unix_timestamp()).unwrap()
+                + 12 * 3_600;
+            s.end_time = Some(s.start_time + 3_600);
+            let mut warm_up = set(None, 200);
+            warm_up.set_kind = SetKind::WarmUp;
+            s.exercise_logs = vec![set(None, weight_hg), warm_up];
+            s
+        };
+        let sessions = [session(1, 500), session(8, 600), session(20, 900)];
+        let summary = PeriodSummary::of(&sessions, day(1), day(14));
+        assert_eq!(summary.sessions, 2);
+        assert!((summary.sessions_per_week - 1.0).abs() < 1e-9);
+        assert!((summary.volume_kg - 1_100.0).abs() < 1e-9);
+        assert_eq!(summary.bests.get("curl"), Some(&60.0));
+    }
+
+    #[test]
+    fn delta_percent_needs_a_baseline() {
+        assert_eq!(delta_percent(50.0, 75.0), Some(50.0));
+        assert_eq!(delta_percent(80.0, 60.0), Some(-25.0));
+        assert_eq!(delta_percent(0.0, 10.0), None);
+    }
+
+    #[test]
+    fn plateau_counts_sessions_since_last_record() {
+        let on_day = |day: u64, weight_hg: u16| {
+            let mut log = set(None, weight_hg);
+            log.start_time = day * crate::datetime::SECONDS_IN_DAY + 43_200;
+            log
+        };
+        let logs = [
+            on_day(0, 500),
+            on_day(1, 600),
+            on_day(2, 550),
+            on_day(3, 600),
+            on_day(3, 580),
+        ];
+        assert_eq!(sessions_without_progress(&logs), Some(2));
+        assert_eq!(sessions_without_progress(&logs[..2]), Some(0));
+        assert_eq!(sessions_without_progress(&[]), None);
+    }
+}
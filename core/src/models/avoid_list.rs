@@ -0,0 +1,120 @@
+use super::enums::{Force, Muscle};
+use super::exercise::Exercise;
+use serde::{Deserialize, Serialize};
+use time::Date;
+/// Movement to avoid for a while, e.g. pushing with the shoulders while they
+/// heal.  A rule with both a muscle and a force only flags exercises matching
+/// both; a rule with neither flags nothing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AvoidRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muscle: Option<Muscle>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub force: Option<Force>,
+    /// First local day (`YYYY-MM-DD`) the rule applies, open-ended if absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Last local day (`YYYY-MM-DD`) the rule applies, open-ended if absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+}
+impl AvoidRule {
+    /// Whether the rule applies on local day `today`.
+    #[must_use]
+    pub fn is_active_on(&self, today: Date) -> bool {
+        let day = |d: &Option<String>| d.as_deref().and_then(crate::datetime::parse_iso_date);
+        day(&self.from).is_none_or(|from| from <= today)
+            && day(&self.until).is_none_or(|until| today <= until)
+    }
+    /// Whether `exercise` involves the avoided muscle (primary or secondary)
+    /// and force.
+    #[must_use]
+    pub fn flags(&self, exercise: &Exercise) -> bool {
+        if self.muscle.is_none() && self.force.is_none() {
+            return false;
+        }
+        self.muscle.is_none_or(|m| {
+            exercise.primary_muscles.contains(&m) || exercise.secondary_muscles.contains(&m)
+        }) && self.force.is_none_or(|f| exercise.force == Some(f))
+    }
+}
+/// Injury mode: the movements to avoid and how flagged exercises are shown.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AvoidList {
+    #[serde(default)]
+    pub rules: Vec<AvoidRule>,
+    /// Hide flagged exercises from search instead of only warning about them.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hide: bool,
+}
+impl AvoidList {
+    /// Whether a rule active on local day `today` flags `exercise`.
+    #[must_use]
+    pub fn flags(&self, exercise: &Exercise, today: Date) -> bool {
+        self.rules
+            .iter()
+            .any(|r| r.is_active_on(today) && r.flags(exercise))
+    }
+    /// Whether any rule applies on local day `today`.
+    #[must_use]
+    pub fn is_active_on(&self, today: Date) -> bool {
+        self.rules.iter().any(|r| r.is_active_on(today))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Category;
+    use time::Month;
+    fn press() -> Exercise {
+        Exercise {
+            id: "press".into(),
+            name: "Overhead Press".into(),
+            name_lower: "overhead press".into(),
+            force: Some(Force::Push),
+            level: None,
+            mechanic: None,
+            equipment: None,
+            primary_muscles: vec![Muscle::Shoulders],
+            secondary_muscles: vec![Muscle::Triceps],
+            instructions: vec![],
+            category: Category::Strength,
+            images: vec![],
+            videos: vec![],
+            i18n: None,
+            rest_seconds: None,
+        }
+    }
+    fn day(d: u8) -> Date {
+        Date::from_calendar_date(2026, Month::October, d).unwrap()
+    }
+    #[test]
+    fn rule_needs_both_muscle_and_force() {
+        let rule = |muscle, force| AvoidRule {
+            muscle,
+            force,
+            ..AvoidRule::default()
+        };
+        assert!(rule(Some(Muscle::Shoulders), Some(Force::Push)).flags(&press()));
+        assert!(rule(Some(Muscle::Triceps), None).flags(&press()));
+        assert!(!rule(Some(Muscle::Shoulders), Some(Force::Pull)).flags(&press()));
+        assert!(!rule(Some(Muscle::Quadriceps), None).flags(&press()));
+        assert!(!rule(None, None).flags(&press()));
+    }
+    #[test]
+    fn rule_applies_within_its_dates() {
+        let list = AvoidList {
+            rules: vec![AvoidRule {
+                muscle: Some(Muscle::Shoulders),
+                force: None,
+                from: Some("2026-10-05".into()),
+                until: Some("2026-10-20".into()),
+            }],
+            hide: false,
+        };
+        assert!(!list.flags(&press(), day(4)));
+        assert!(list.flags(&press(), day(5)));
+        assert!(list.flags(&press(), day(20)));
+        assert!(!list.flags(&press(), day(21)));
+    }
+}
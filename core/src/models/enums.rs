@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
     Debug,
     Clone,
     Copy,
+    Default,
     PartialEq,
     Eq,
     Hash,
@@ -25,6 +26,7 @@ pub enum Category {
     #[serde(rename = "powerlifting")]
     Powerlifting,
     #[serde(rename = "strength")]
+    #[default]
     Strength,
     #[serde(rename = "stretching")]
     Stretching,
@@ -60,6 +62,69 @@ impl Force {
         matches!(self, Self::Pull | Self::Push)
     }
 }
+/// Side of the body worked by a set of a unilateral movement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum::AsRefStr)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Side {
+    Left,
+    Right,
+}
+impl Side {
+    /// The opposite side, usually trained next.
+    #[must_use]
+    pub fn other(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+/// Purpose of a logged set, shown as a badge on the completed set.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, strum::AsRefStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SetKind {
+    /// Regular working set.
+    #[default]
+    Normal,
+    /// Light preparatory set, left out of volume and personal records.
+    WarmUp,
+    /// Set continued at a reduced weight right after the previous one.
+    Dropset,
+    /// As many repetitions as possible.
+    Amrap,
+    /// Set taken to muscular failure.
+    Failure,
+}
+impl SetKind {
+    /// Every kind, in the order the set-kind toggle cycles through them.
+    pub const ALL: [Self; 5] = [
+        Self::Normal,
+        Self::WarmUp,
+        Self::Dropset,
+        Self::Amrap,
+        Self::Failure,
+    ];
+    /// Whether this is a plain working set (serde default).
+    #[must_use]
+    pub fn is_normal(&self) -> bool {
+        *self == Self::Normal
+    }
+    /// Whether sets of this kind count towards volume and personal records.
+    #[must_use]
+    pub fn counts_for_stats(self) -> bool {
+        self != Self::WarmUp
+    }
+    /// The kind following this one in the set-kind toggle.
+    #[must_use]
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&k| k == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
 /// The difficulty level of an exercise.
 #[derive(
     Debug,
@@ -157,6 +222,8 @@ pub enum Equipment {
     Copy,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     Hash,
     Serialize,
     Deserialize,
@@ -229,6 +296,17 @@ mod tests {
         assert_eq!(back, Muscle::LowerBack);
     }
     #[test]
+    fn set_kind_round_trip_and_cycle() {
+        // The native bests query filters warm-ups on this exact value.
+        let json = serde_json::to_string(&SetKind::WarmUp).unwrap();
+        assert_eq!(json, "\"warm_up\"");
+        let back: SetKind = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, SetKind::WarmUp);
+        assert_eq!(SetKind::Failure.next(), SetKind::Normal);
+        assert!(!SetKind::WarmUp.counts_for_stats());
+        assert!(SetKind::Dropset.counts_for_stats());
+    }
+    #[test]
     fn force_has_reps() {
         assert!(Force::Push.has_reps());
         assert!(Force::Pull.has_reps());
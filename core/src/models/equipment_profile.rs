@@ -0,0 +1,89 @@
+use super::enums::Equipment;
+use super::exercise::Exercise;
+use serde::{Deserialize, Serialize};
+/// A named set of available equipment, e.g. "Home: dumbbells, bands".
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EquipmentProfile {
+    pub name: String,
+    #[serde(default)]
+    pub equipment: Vec<Equipment>,
+}
+impl EquipmentProfile {
+    /// Whether `exercise` can be done with this equipment.  Exercises needing
+    /// none (body only), or not telling which, are always doable.
+    #[must_use]
+    pub fn allows(&self, exercise: &Exercise) -> bool {
+        match exercise.equipment {
+            None | Some(Equipment::BodyOnly) => true,
+            Some(equipment) => self.equipment.contains(&equipment),
+        }
+    }
+}
+/// The saved equipment profiles and the one filtering exercise search.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EquipmentProfiles {
+    #[serde(default)]
+    pub profiles: Vec<EquipmentProfile>,
+    /// Name of the active profile; `None` lets every exercise through.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<String>,
+}
+impl EquipmentProfiles {
+    /// The active profile, if it still exists.
+    #[must_use]
+    pub fn active_profile(&self) -> Option<&EquipmentProfile> {
+        let active = self.active.as_deref()?;
+        self.profiles.iter().find(|p| p.name == active)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Category;
+    fn exercise(equipment: Option<Equipment>) -> Exercise {
+        Exercise {
+            id: "x".into(),
+            name: "X".into(),
+            name_lower: "x".into(),
+            force: None,
+            level: None,
+            mechanic: None,
+            equipment,
+            primary_muscles: vec![],
+            secondary_muscles: vec![],
+            instructions: vec![],
+            category: Category::Strength,
+            images: vec![],
+            videos: vec![],
+            i18n: None,
+            rest_seconds: None,
+        }
+    }
+    #[test]
+    fn profile_allows_its_equipment_and_none() {
+        let home = EquipmentProfile {
+            name: "Home".into(),
+            equipment: vec![Equipment::Dumbbell, Equipment::Bands],
+        };
+        assert!(home.allows(&exercise(Some(Equipment::Dumbbell))));
+        assert!(home.allows(&exercise(Some(Equipment::BodyOnly))));
+        assert!(home.allows(&exercise(None)));
+        assert!(!home.allows(&exercise(Some(Equipment::Machine))));
+    }
+    #[test]
+    fn active_profile_must_exist() {
+        let mut profiles = EquipmentProfiles {
+            profiles: vec![EquipmentProfile {
+                name: "Home".into(),
+                equipment: vec![],
+            }],
+            active: Some("Gym".into()),
+        };
+        assert!(profiles.active_profile().is_none());
+        profiles.active = Some("Home".into());
+        assert_eq!(
+            profiles.active_profile().map(|p| p.name.as_str()),
+            Some("Home")
+        );
+    }
+}
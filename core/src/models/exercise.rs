@@ -94,9 +94,16 @@ pub struct Exercise {
     #[serde(default)]
     /// Relative or absolute image paths / URLs.
     pub images: Vec<String>,
+    /// Technique video URLs (YouTube, Vimeo or any other page).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub videos: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     /// Per-language translations of [`name`] and [`instructions`] (schema2 `i18n` field).
     pub i18n: Option<HashMap<String, ExerciseI18n>>,
+    /// Preferred rest after a set of this exercise, in seconds, instead of
+    /// the default rest duration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rest_seconds: Option<u64>,
 }
 impl Exercise {
     /// Populate `name_lower` from `name`.
@@ -142,64 +149,11 @@ impl Exercise {
         }
         &self.instructions
     }
-    /// Get the URL for a specific image by index.
-    ///
-    /// Images with a known URL scheme (`http://`, `https://`, `blob:`, `data:`,
-    /// `file://`) or an absolute filesystem path (starting with `/`) are returned
-    /// unchanged.
-    ///
-    /// Images with the `local:` prefix are user-uploaded files copied into the
-    /// app's `data_dir/images/` folder on native platforms.  The prefix is
-    /// stripped and the filename is resolved to the full path.
-    ///
-    /// Images with the `idb:` prefix are stored as binary blobs in `IndexedDB` on
-    /// the web platform.  This method returns `None` for them; use
-    /// `storage::idb_images::get_image_blob_url` to obtain a `blob:` URL
-    /// asynchronously when the image is actually rendered.
-    ///
-    /// Relative paths from the exercise database (e.g. `Squat/0.jpg`) are
-    /// prefixed with the configured `EXERCISES_IMAGE_BASE_URL`.
-    /// Resolves a raw image key from `images` by index to a displayable URL.
-    /// Returns `None` for `idb:` keys (which require async loading on web) or
-    /// if the index is out of bounds.
-    ///
-    /// Recognised formats:
-    /// - Absolute URL schemes (`http://`, `https://`, `blob:`, `data:`, `file://`)
-    /// - Absolute filesystem paths (starting with `/`)
-    /// - `local:filename` on native → resolved to `data_dir()/images/filename` as a `file://` URL
-    /// - `idb:key` → `None` (caller must use `idb_images::get_image_blob_url` asynchronously)
-    /// - Relative DB path (e.g. `Squat/0.jpg`) → prefixed with `EXERCISES_IMAGE_BASE_URL`
-    pub fn get_image_url(&self, index: usize) -> Option<String> {
-        let key = self.images.get(index)?;
-        if key.starts_with("idb:") {
-            return None;
-        }
-        if key.starts_with("http://")
-            || key.starts_with("https://")
-            || key.starts_with("blob:")
-            || key.starts_with("data:")
-            || key.starts_with("file://")
-            || key.starts_with('/')
-        {
-            return Some(key.to_owned());
-        }
-        // Resolve `local:` and cached-DB images to native filesystem URLs.
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            if let Some(filename) = key.strip_prefix("local:") {
-                return Some(local_image_url(filename));
-            }
-            if let Some(url) = cached_db_image_url(key) {
-                return Some(url);
-            }
-        }
-        let base_url = crate::utils::get_exercise_images_base_url();
-        Some(format!("{base_url}{EXERCISES_IMAGE_SUB_PATH}{key}"))
-    }
-    /// Get the first image URL if available
-    #[cfg(test)]
-    pub fn get_first_image_url(&self) -> Option<String> {
-        self.get_image_url(0)
+    /// Whether the exercise moves the athlete's own body, so a set may add
+    /// load (dip belt) or assistance (band, machine) to the body weight.
+    #[must_use]
+    pub fn is_bodyweight(&self) -> bool {
+        self.equipment == Some(Equipment::BodyOnly)
     }
     /// Returns the CSS class and icon for the exercise type tag.
     ///
@@ -212,238 +166,37 @@ impl Exercise {
         exercise_type_tag(self.category, self.force)
     }
 }
-/// Builds a displayable URL for a `local:` user-uploaded image.
-///
-/// On mobile, returns an `imgcache://` URL served by the custom protocol handler.
-/// On other native targets, returns a `file://` URL.
-#[cfg(not(target_arch = "wasm32"))]
-fn local_image_url(filename: &str) -> String {
-    #[cfg(feature = "mobile-platform")]
-    {
-        use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
-        const COMPONENT: &percent_encoding::AsciiSet = &NON_ALPHANUMERIC
-            .remove(b'-')
-            .remove(b'_')
-            .remove(b'.')
-            .remove(b'~');
-        let encoded = utf8_percent_encode(filename, COMPONENT).to_string();
-        return format!("imgcache://localhost/{encoded}");
-    }
-    #[cfg(not(feature = "mobile-platform"))]
-    {
-        use percent_encoding::{utf8_percent_encode, CONTROLS};
-        const PATH_SEG: &percent_encoding::AsciiSet = &CONTROLS
-            .add(b' ')
-            .add(b'"')
-            .add(b'#')
-            .add(b'%')
-            .add(b'<')
-            .add(b'>')
-            .add(b'?')
-            .add(b'[')
-            .add(b'\\')
-            .add(b']')
-            .add(b'^')
-            .add(b'`')
-            .add(b'{')
-            .add(b'|')
-            .add(b'}');
-        let path = crate::services::storage::native_storage::data_dir()
-            .join("images")
-            .join(filename);
-        let encoded = path.components().fold(String::new(), |mut acc, c| {
-            use std::path::Component;
-            match c {
-                Component::Prefix(p) => acc.push_str(&p.as_os_str().to_string_lossy()),
-                Component::RootDir => acc.push('/'),
-                Component::Normal(seg) => {
-                    if !acc.is_empty() && !acc.ends_with('/') {
-                        acc.push('/');
-                    }
-                    acc.push_str(
-                        &utf8_percent_encode(&seg.to_string_lossy(), PATH_SEG).to_string(),
-                    );
-                }
-                _ => {}
-            }
-            acc
-        });
-        format!("file://{encoded}")
-    }
-}
-
-/// Returns a displayable URL for a cached DB image key if the file exists
-/// in `data_dir()/images/`, or `None` if it has not been downloaded yet.
-///
-/// On mobile, returns an `imgcache://` URL.  On other native targets, returns
-/// a `file://` URL.
-#[cfg(not(target_arch = "wasm32"))]
-fn cached_db_image_url(key: &str) -> Option<String> {
-    let cached = crate::services::storage::native_storage::images_dir().join(key);
-    if !cached.exists() {
-        return None;
-    }
-    #[cfg(feature = "mobile-platform")]
-    {
-        use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
-        const COMPONENT: &percent_encoding::AsciiSet = &NON_ALPHANUMERIC
-            .remove(b'-')
-            .remove(b'_')
-            .remove(b'.')
-            .remove(b'~');
-        // Preserve `/` as path separator; percent-encode only within each segment.
-        let encoded = key
-            .split('/')
-            .map(|seg| utf8_percent_encode(seg, COMPONENT).to_string())
-            .collect::<Vec<_>>()
-            .join("/");
-        return Some(format!("imgcache://localhost/{encoded}"));
-    }
-    #[cfg(not(feature = "mobile-platform"))]
-    {
-        use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
-        const COMPONENT: &percent_encoding::AsciiSet = &NON_ALPHANUMERIC
-            .remove(b'-')
-            .remove(b'_')
-            .remove(b'.')
-            .remove(b'~');
-        // Percent-encode each path component, preserving slashes.
-        let encoded = cached.components().fold(String::new(), |mut acc, c| {
-            use std::path::Component;
-            match c {
-                Component::Prefix(p) => acc.push_str(&p.as_os_str().to_string_lossy()),
-                Component::RootDir => acc.push('/'),
-                Component::Normal(seg) => {
-                    if !acc.is_empty() && !acc.ends_with('/') {
-                        acc.push('/');
-                    }
-                    acc.push_str(
-                        &utf8_percent_encode(&seg.to_string_lossy(), COMPONENT).to_string(),
-                    );
-                }
-                _ => {}
-            }
-            acc
-        });
-        Some(format!("file://{encoded}"))
-    }
-}
-
 impl AsRef<Exercise> for Exercise {
     fn as_ref(&self) -> &Exercise {
         self
     }
 }
+/// Looks up an exercise by ID in a slice.
+///
+/// Works with any element type that dereferences to [`Exercise`] (e.g. plain
+/// `Exercise` in tests, `Arc<Exercise>` in production signals).
+pub fn get_exercise_by_id<'a, E>(exercises: &'a [E], id: &str) -> Option<&'a E>
+where
+    E: AsRef<Exercise>,
+{
+    exercises.iter().find(|e| e.as_ref().id == id)
+}
+/// Resolves an exercise by ID: checks the main DB slice first, then falls back
+/// to the custom-exercises slice.  Centralises the lookup logic used across
+/// multiple components.
+///
+/// Works with any element type that dereferences to [`Exercise`] (e.g. plain
+/// `Exercise` in tests, `Arc<Exercise>` in production signals).
+pub fn resolve_exercise<'a, E>(db: &'a [E], custom: &'a [E], id: &str) -> Option<&'a E>
+where
+    E: AsRef<Exercise>,
+{
+    get_exercise_by_id(db, id).or_else(|| get_exercise_by_id(custom, id))
+}
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
-    fn exercise_get_first_image_url_some() {
-        #[cfg(not(target_arch = "wasm32"))]
-        let _g = crate::services::storage::native_storage::test_lock();
-        let ex = Exercise {
-            id: "ex1".into(),
-            name: "Squat".into(),
-            name_lower: String::new(),
-            force: None,
-            level: Some(Level::Beginner),
-            mechanic: None,
-            equipment: None,
-            primary_muscles: vec![],
-            secondary_muscles: vec![],
-            instructions: vec![],
-            category: Category::Strength,
-            images: vec!["Squat/0.jpg".into()],
-            i18n: None,
-        };
-        assert_eq!(
-            ex.get_first_image_url(),
-            Some(
-                "https://raw.githubusercontent.com/gfauredev/free-exercise-db/main/exercises/Squat/0.jpg"
-                    .into(),
-            ),
-        );
-    }
-    #[test]
-    fn exercise_get_first_image_url_none() {
-        let ex = Exercise {
-            id: "ex1".into(),
-            name: "Squat".into(),
-            name_lower: String::new(),
-            force: None,
-            level: Some(Level::Beginner),
-            mechanic: None,
-            equipment: None,
-            primary_muscles: vec![],
-            secondary_muscles: vec![],
-            instructions: vec![],
-            category: Category::Strength,
-            images: vec![],
-            i18n: None,
-        };
-        assert_eq!(ex.get_first_image_url(), None);
-    }
-    fn make_exercise_with_image(image: &str) -> Exercise {
-        Exercise {
-            id: "ex1".into(),
-            name: "Test".into(),
-            name_lower: String::new(),
-            force: None,
-            level: None,
-            mechanic: None,
-            equipment: None,
-            primary_muscles: vec![],
-            secondary_muscles: vec![],
-            instructions: vec![],
-            category: Category::Strength,
-            images: vec![image.into()],
-            i18n: None,
-        }
-    }
-    #[test]
-    fn get_image_url_passes_through_blob_url() {
-        let ex = make_exercise_with_image("blob:https://example.com/abc-123");
-        assert_eq!(
-            ex.get_first_image_url(),
-            Some("blob:https://example.com/abc-123".into()),
-        );
-    }
-    #[test]
-    fn get_image_url_passes_through_data_url() {
-        let ex = make_exercise_with_image("data:image/jpeg;base64,/9j/4AAQ");
-        assert_eq!(
-            ex.get_first_image_url(),
-            Some("data:image/jpeg;base64,/9j/4AAQ".into()),
-        );
-    }
-    #[test]
-    fn get_image_url_passes_through_file_url() {
-        let ex = make_exercise_with_image("file:///home/user/images/my.jpg");
-        assert_eq!(
-            ex.get_first_image_url(),
-            Some("file:///home/user/images/my.jpg".into()),
-        );
-    }
-    #[test]
-    fn get_image_url_passes_through_absolute_path() {
-        let ex = make_exercise_with_image("/data/user/0/dev.log_out/images/my.jpg");
-        assert_eq!(
-            ex.get_first_image_url(),
-            Some("/data/user/0/dev.log_out/images/my.jpg".into()),
-        );
-    }
-    #[test]
-    fn get_image_url_prefixes_relative_exercise_db_path() {
-        #[cfg(not(target_arch = "wasm32"))]
-        let _g = crate::services::storage::native_storage::test_lock();
-        let ex = make_exercise_with_image("Squat/0.jpg");
-        let url = ex.get_first_image_url().unwrap();
-        assert!(
-            url.contains("exercises/Squat/0.jpg"),
-            "relative path must be prefixed; got: {url}",
-        );
-    }
-    #[test]
     fn user_exercise_serialization_with_all_fields() {
         let exercise = Exercise {
             id: "custom_123".into(),
@@ -458,7 +211,9 @@ mod tests {
             secondary_muscles: vec![Muscle::Triceps, Muscle::Shoulders],
             instructions: vec!["Step 1".into(), "Step 2".into()],
             images: vec!["https://example.com/img.jpg".into()],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         };
         let json = serde_json::to_string(&exercise).unwrap();
         let deserialized: Exercise = serde_json::from_str(&json).unwrap();
@@ -483,7 +238,9 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }
         .with_lowercase();
         assert_eq!(exercise.name_lower, "bench press");
@@ -530,7 +287,9 @@ mod tests {
             instructions: vec!["Grab the bar.".into()],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: Some(map),
+            rest_seconds: None,
         }
     }
     #[test]
@@ -564,7 +323,9 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         };
         assert_eq!(ex.name_for_lang("fr"), "Bench Press");
     }
@@ -610,7 +371,9 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         };
         let json = serde_json::to_string(&ex).unwrap();
         assert!(
@@ -679,63 +442,6 @@ mod tests {
         );
     }
     #[test]
-    fn exercise_get_image_url_by_index() {
-        #[cfg(not(target_arch = "wasm32"))]
-        let _g = crate::services::storage::native_storage::test_lock();
-        let ex = Exercise {
-            id: "ex1".into(),
-            name: "Squat".into(),
-            name_lower: String::new(),
-            force: None,
-            level: Some(Level::Beginner),
-            mechanic: None,
-            equipment: None,
-            primary_muscles: vec![],
-            secondary_muscles: vec![],
-            instructions: vec![],
-            category: Category::Strength,
-            images: vec!["Squat/0.jpg".into(), "Squat/1.jpg".into()],
-            i18n: None,
-        };
-        assert_eq!(
-            ex.get_image_url(0),
-            Some(
-                "https://raw.githubusercontent.com/gfauredev/free-exercise-db/main/exercises/Squat/0.jpg"
-                    .into(),
-            ),
-        );
-        assert_eq!(
-            ex.get_image_url(1),
-            Some(
-                "https://raw.githubusercontent.com/gfauredev/free-exercise-db/main/exercises/Squat/1.jpg"
-                    .into(),
-            ),
-        );
-        assert_eq!(ex.get_image_url(2), None);
-    }
-    #[test]
-    fn exercise_get_image_url_full_url_passthrough() {
-        let ex = Exercise {
-            id: "ex1".into(),
-            name: "Custom".into(),
-            name_lower: String::new(),
-            force: None,
-            level: None,
-            mechanic: None,
-            equipment: None,
-            primary_muscles: vec![],
-            secondary_muscles: vec![],
-            instructions: vec![],
-            category: Category::Strength,
-            images: vec!["https://example.com/image.jpg".into()],
-            i18n: None,
-        };
-        assert_eq!(
-            ex.get_image_url(0),
-            Some("https://example.com/image.jpg".into())
-        );
-    }
-    #[test]
     fn exercise_level_none_when_missing_from_json() {
         let json = r#"{"id":"ex1","name":"Test","category":"strength","primaryMuscles":[]}"#;
         let ex: Exercise = serde_json::from_str(json).unwrap();
@@ -811,35 +517,15 @@ mod tests {
             secondary_muscles: vec![],
             instructions: vec![],
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         };
         let json = serde_json::to_string(&ex).unwrap();
         let back: Exercise = serde_json::from_str(&json).unwrap();
         assert_eq!(back, ex);
     }
     #[test]
-    fn exercise_get_image_url_http_passthrough() {
-        let ex = Exercise {
-            id: "ex1".into(),
-            name: "Custom".into(),
-            name_lower: String::new(),
-            force: None,
-            level: None,
-            mechanic: None,
-            equipment: None,
-            primary_muscles: vec![],
-            secondary_muscles: vec![],
-            instructions: vec![],
-            category: Category::Strength,
-            images: vec!["http://example.com/image.jpg".into()],
-            i18n: None,
-        };
-        assert_eq!(
-            ex.get_image_url(0),
-            Some("http://example.com/image.jpg".into())
-        );
-    }
-    #[test]
     fn exercise_type_tag_cardio() {
         let ex = Exercise {
             id: "run1".into(),
@@ -854,7 +540,9 @@ mod tests {
             secondary_muscles: vec![],
             instructions: vec![],
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         };
         assert_eq!(ex.type_tag(), ("tag-cardio", "🏃"));
     }
@@ -873,7 +561,9 @@ mod tests {
             secondary_muscles: vec![],
             instructions: vec![],
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         };
         assert_eq!(ex.type_tag(), ("tag-strength", "💪"));
     }
@@ -892,7 +582,9 @@ mod tests {
             secondary_muscles: vec![],
             instructions: vec![],
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         };
         assert_eq!(ex.type_tag(), ("tag-static", "⏱️"));
     }
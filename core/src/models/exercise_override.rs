@@ -0,0 +1,145 @@
+use super::exercise::Exercise;
+use super::taxonomy::CustomTag;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+/// Personal settings of an exercise, kept on this device only.
+///
+/// They apply to database exercises too, which cannot be edited, and take
+/// precedence over the matching fields of custom exercises.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExerciseOverride {
+    /// Personal technique video URL, shown instead of the exercise's own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video: Option<String>,
+    /// Personal names the exercise is also found by when searching.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// Left out of the exercise lists and search results.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hidden: bool,
+    /// Custom categories, equipment and muscles of the exercise, from the
+    /// user's [`super::CustomTaxonomy`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<CustomTag>,
+    /// Images downloaded and kept for offline use, e.g. in a gym without
+    /// signal.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub offline: bool,
+    /// Personal rest after a set, in seconds, see [`Exercise::rest_with`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rest_seconds: Option<u64>,
+}
+impl ExerciseOverride {
+    /// Whether the override changes nothing and can be forgotten.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.video.is_none()
+            && self.aliases.is_empty()
+            && !self.hidden
+            && self.tags.is_empty()
+            && !self.offline
+            && self.rest_seconds.is_none()
+    }
+}
+/// Personal overrides keyed by exercise ID.
+pub type ExerciseOverrides = HashMap<String, ExerciseOverride>;
+/// IDs of the exercises whose images are kept offline in `overrides`.
+pub fn kept_offline(overrides: &ExerciseOverrides) -> impl Iterator<Item = &str> {
+    overrides
+        .iter()
+        .filter(|(_, o)| o.offline)
+        .map(|(id, _)| id.as_str())
+}
+/// Whether the exercise `id` is hidden in `overrides`.
+#[must_use]
+pub fn is_hidden(overrides: &ExerciseOverrides, id: &str) -> bool {
+    overrides.get(id).is_some_and(|o| o.hidden)
+}
+impl Exercise {
+    /// Technique video URLs to show: the personal one of `personal` when set,
+    /// otherwise the exercise's own.
+    #[must_use]
+    pub fn videos_with<'a>(&'a self, personal: Option<&'a ExerciseOverride>) -> Vec<&'a str> {
+        match personal.and_then(|p| p.video.as_deref()) {
+            Some(url) => vec![url],
+            None => self.videos.iter().map(String::as_str).collect(),
+        }
+    }
+    /// Preferred rest after a set, in seconds: the personal one of `personal`
+    /// when set, otherwise the exercise's own, if any.
+    #[must_use]
+    pub fn rest_with(&self, personal: Option<&ExerciseOverride>) -> Option<u64> {
+        personal.and_then(|p| p.rest_seconds).or(self.rest_seconds)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Category;
+    #[test]
+    fn personal_video_replaces_the_exercise_ones() {
+        let exercise = Exercise {
+            id: "squat".into(),
+            name: "Squat".into(),
+            name_lower: "squat".into(),
+            force: None,
+            level: None,
+            mechanic: None,
+            equipment: None,
+            primary_muscles: vec![],
+            secondary_muscles: vec![],
+            instructions: vec![],
+            category: Category::Strength,
+            images: vec![],
+            videos: vec!["https://a.example".into(), "https://b.example".into()],
+            i18n: None,
+            rest_seconds: Some(120),
+        };
+        assert_eq!(exercise.videos_with(None).len(), 2);
+        let personal = ExerciseOverride {
+            video: Some("https://mine.example".into()),
+            ..ExerciseOverride::default()
+        };
+        assert!(!personal.is_empty());
+        assert_eq!(
+            exercise.videos_with(Some(&personal)),
+            ["https://mine.example"]
+        );
+        assert_eq!(
+            exercise
+                .videos_with(Some(&ExerciseOverride::default()))
+                .len(),
+            2
+        );
+        assert_eq!(exercise.rest_with(Some(&personal)), Some(120));
+        let rest = ExerciseOverride {
+            rest_seconds: Some(45),
+            ..ExerciseOverride::default()
+        };
+        assert!(!rest.is_empty());
+        assert_eq!(exercise.rest_with(Some(&rest)), Some(45));
+    }
+    #[test]
+    fn hidden_exercises_are_remembered() {
+        let mut overrides = ExerciseOverrides::new();
+        assert!(!is_hidden(&overrides, "leg_press"));
+        let hidden = ExerciseOverride {
+            hidden: true,
+            ..ExerciseOverride::default()
+        };
+        assert!(!hidden.is_empty());
+        overrides.insert("leg_press".into(), hidden);
+        assert!(is_hidden(&overrides, "leg_press"));
+        let json = serde_json::to_string(&overrides).unwrap();
+        assert_eq!(json, r#"{"leg_press":{"hidden":true}}"#);
+        overrides.insert(
+            "squat".into(),
+            ExerciseOverride {
+                offline: true,
+                ..ExerciseOverride::default()
+            },
+        );
+        assert!(!overrides["squat"].is_empty());
+        assert_eq!(kept_offline(&overrides).collect::<Vec<_>>(), ["squat"]);
+    }
+}
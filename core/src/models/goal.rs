@@ -0,0 +1,128 @@
+use super::log::{ExerciseLog, EPLEY_REPS_DIVISOR};
+use super::units::{Weight, HG_PER_KG};
+use serde::{Deserialize, Serialize};
+use time::Date;
+/// Target to reach on an exercise, e.g. "squat 100 kg × 5 by June".  A goal
+/// with a weight and repetitions is met by a single set with both; a goal
+/// with neither is never met.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExerciseGoal {
+    /// Unix timestamp (seconds) when the goal was set, also its identifier.
+    pub created_at: u64,
+    pub exercise_id: String,
+    /// Load to move in one set, body weight included for bodyweight sets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight_hg: Option<Weight>,
+    /// Repetitions to perform in one set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reps: Option<u32>,
+    /// Local day (`YYYY-MM-DD`) the goal should be reached by, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<String>,
+    /// Unix timestamp (seconds) of the set that reached the goal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub achieved_at: Option<u64>,
+}
+impl ExerciseGoal {
+    /// Whether the completed working set `log` reaches the goal.
+    #[must_use]
+    pub fn is_met_by(&self, log: &ExerciseLog) -> bool {
+        if log.exercise_id != self.exercise_id
+            || !log.is_complete()
+            || !log.set_kind.counts_for_stats()
+            || (self.weight_hg.is_none() && self.reps.is_none())
+        {
+            return false;
+        }
+        let load_ok = self
+            .weight_hg
+            .is_none_or(|w| log.load_kg().unwrap_or(0.0) >= f64::from(w.0) / HG_PER_KG);
+        let reps_ok = self.reps.is_none_or(|r| log.reps.unwrap_or(0) >= r);
+        load_ok && reps_ok
+    }
+    /// Progress of the best set among `logs` towards the goal, from 0 to 1.
+    /// Sets with both a load and repetitions are compared through their
+    /// estimated one-repetition maximum.
+    #[must_use]
+    pub fn progress<'a>(&self, logs: impl IntoIterator<Item = &'a ExerciseLog>) -> f64 {
+        if self.achieved_at.is_some() {
+            return 1.0;
+        }
+        logs.into_iter()
+            .filter(|l| l.exercise_id == self.exercise_id && l.set_kind.counts_for_stats())
+            .map(|l| self.set_progress(l))
+            .fold(0.0, f64::max)
+    }
+    fn set_progress(&self, log: &ExerciseLog) -> f64 {
+        if self.is_met_by(log) {
+            return 1.0;
+        }
+        let kg = |w: Weight| f64::from(w.0) / HG_PER_KG;
+        let ratio = match (self.weight_hg, self.reps) {
+            (Some(w), Some(r)) => {
+                let target = kg(w) * (1.0 + f64::from(r) / EPLEY_REPS_DIVISOR);
+                log.estimated_one_rep_max_kg().unwrap_or(0.0) / target
+            }
+            (Some(w), None) => log.load_kg().unwrap_or(0.0) / kg(w),
+            (None, Some(r)) => f64::from(log.reps.unwrap_or(0)) / f64::from(r),
+            (None, None) => 0.0,
+        };
+        // Only a set meeting the goal completes it, e.g. not a heavier set
+        // with too few repetitions.
+        ratio.clamp(0.0, 1.0 - f64::EPSILON)
+    }
+    /// Whether the deadline passed before the goal was reached.
+    #[must_use]
+    pub fn is_overdue(&self, today: Date) -> bool {
+        self.achieved_at.is_none()
+            && self
+                .deadline
+                .as_deref()
+                .and_then(crate::datetime::parse_iso_date)
+                .is_some_and(|deadline| deadline < today)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SetKind;
+    fn set(weight_hg: u16, reps: u32) -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: "squat".into(),
+            exercise_name: "Squat".into(),
+            end_time: Some(60),
+            weight_hg: Weight(weight_hg),
+            reps: Some(reps),
+            ..Default::default()
+        }
+    }
+    fn goal(weight_hg: Option<u16>, reps: Option<u32>) -> ExerciseGoal {
+        ExerciseGoal {
+            exercise_id: "squat".into(),
+            weight_hg: weight_hg.map(Weight),
+            reps,
+            ..ExerciseGoal::default()
+        }
+    }
+    #[test]
+    fn goal_needs_weight_and_reps_in_one_set() {
+        let target = goal(Some(1_000), Some(5));
+        assert!(target.is_met_by(&set(1_000, 5)));
+        assert!(!target.is_met_by(&set(1_100, 3)));
+        assert!(!target.is_met_by(&set(900, 8)));
+        let mut warm_up = set(1_000, 5);
+        warm_up.set_kind = SetKind::WarmUp;
+        assert!(!target.is_met_by(&warm_up));
+        assert!(!goal(None, None).is_met_by(&set(1_000, 5)));
+    }
+    #[test]
+    fn progress_follows_best_set() {
+        let logs = [set(500, 10), set(800, 5)];
+        assert!((goal(Some(1_000), None).progress(&logs) - 0.8).abs() < 1e-9);
+        assert!((goal(None, Some(20)).progress(&logs) - 0.5).abs() < 1e-9);
+        let heavy_single = [set(1_200, 1)];
+        let progress = goal(Some(1_000), Some(5)).progress(&heavy_single);
+        assert!(progress < 1.0);
+        assert!((goal(Some(1_000), Some(5)).progress(&[set(1_000, 5)]) - 1.0).abs() < 1e-9);
+    }
+}
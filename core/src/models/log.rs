@@ -1,9 +1,14 @@
-use super::enums::{Category, Force};
+use super::enums::{Category, Force, SetKind, Side};
 use super::exercise_type_tag;
-use super::units::{Distance, Weight};
+use super::units::{Distance, Weight, HG_PER_KG};
 use serde::{Deserialize, Serialize};
+/// Repetitions divisor of the Epley one-repetition maximum estimate.
+pub(crate) const EPLEY_REPS_DIVISOR: f64 = 30.0;
 /// A single completed (or in-progress) exercise within a [`WorkoutSession`].
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// The default is an empty, unfinished strength set, mostly useful as the
+/// base of a struct update.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct ExerciseLog {
     /// Identifier of the exercise performed.
     pub exercise_id: String,
@@ -26,6 +31,54 @@ pub struct ExerciseLog {
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Force type of the exercise (push / pull / static).
     pub force: Option<Force>,
+    /// Recorded GPS track for cardio activities (empty when none was recorded).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gps_track: Vec<TrackPoint>,
+    /// Heart-rate samples recorded during the exercise (empty when none).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hr_samples: Vec<HeartRateSample>,
+    /// Free-form notes about this set (Markdown supported).
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub notes: String,
+    /// Side worked by this set of a unilateral movement; `None` for both.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<Side>,
+    /// Whether `weight_hg` is assistance (band, machine) lightening a
+    /// bodyweight movement rather than load added to it.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub assisted: bool,
+    /// Body weight of the athlete when a bodyweight set was logged, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_weight_hg: Option<Weight>,
+    /// Purpose of the set (warm-up, dropset…); warm-ups are left out of
+    /// volume and personal records.
+    #[serde(default, skip_serializing_if = "SetKind::is_normal")]
+    pub set_kind: SetKind,
+    /// Rest (seconds) planned after this set when it was logged, `Some(0)`
+    /// when none was timed, `None` for sets logged before it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub planned_rest_s: Option<u64>,
+}
+/// A single GPS fix of a recorded activity track.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TrackPoint {
+    /// Unix timestamp (seconds) of the fix.
+    pub time: u64,
+    /// Latitude in decimal degrees (WGS 84).
+    pub lat: f64,
+    /// Longitude in decimal degrees (WGS 84).
+    pub lon: f64,
+    /// Elevation above sea level in meters, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ele: Option<f64>,
+}
+/// A single heart-rate measurement.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HeartRateSample {
+    /// Unix timestamp (seconds) of the measurement.
+    pub time: u64,
+    /// Heart rate in beats per minute.
+    pub bpm: u16,
 }
 impl ExerciseLog {
     /// Calculate duration in seconds
@@ -43,6 +96,46 @@ impl ExerciseLog {
     pub fn type_tag(&self) -> (&'static str, &'static str) {
         exercise_type_tag(self.category, self.force)
     }
+    /// Weight adjustment in kg: positive for added load, negative for
+    /// assistance.
+    #[must_use]
+    pub fn signed_weight_kg(&self) -> f64 {
+        let kg = f64::from(self.weight_hg.0) / HG_PER_KG;
+        if self.assisted {
+            -kg
+        } else {
+            kg
+        }
+    }
+    /// Total load moved in kg: body weight plus the signed adjustment for
+    /// bodyweight sets, the external weight otherwise.  `None` without any
+    /// load, or when assistance exceeds the body weight.
+    #[must_use]
+    pub fn load_kg(&self) -> Option<f64> {
+        let body = self
+            .body_weight_hg
+            .map_or(0.0, |w| f64::from(w.0) / HG_PER_KG);
+        let load = body + self.signed_weight_kg();
+        (load > 0.0).then_some(load)
+    }
+    /// Estimated one-repetition maximum in kg (Epley formula), for sets with
+    /// both a load and repetitions.
+    #[must_use]
+    pub fn estimated_one_rep_max_kg(&self) -> Option<f64> {
+        let reps = self.reps.filter(|&r| r > 0)?;
+        let load = self.load_kg()?;
+        Some(load * (1.0 + f64::from(reps) / EPLEY_REPS_DIVISOR))
+    }
+    /// Volume of the set (weight × repetitions) in hectogram-repetitions,
+    /// zero for sets not counted in statistics such as warm-ups.
+    #[must_use]
+    pub fn volume_hg(&self) -> u64 {
+        if self.set_kind.counts_for_stats() {
+            u64::from(self.weight_hg.0) * u64::from(self.reps.unwrap_or(0))
+        } else {
+            0
+        }
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -52,13 +145,9 @@ mod tests {
         let mut log = ExerciseLog {
             exercise_id: "ex1".into(),
             exercise_name: "Push-up".into(),
-            category: Category::Strength,
             start_time: 1000,
-            end_time: None,
-            weight_hg: Weight(0),
-            reps: None,
-            distance_m: None,
             force: Some(Force::Push),
+            ..Default::default()
         };
         assert!(!log.is_complete());
         log.end_time = Some(1060);
@@ -69,13 +158,10 @@ mod tests {
         let log = ExerciseLog {
             exercise_id: "ex1".into(),
             exercise_name: "Push-up".into(),
-            category: Category::Strength,
             start_time: 1000,
             end_time: Some(1060),
-            weight_hg: Weight(0),
-            reps: None,
-            distance_m: None,
             force: Some(Force::Push),
+            ..Default::default()
         };
         assert_eq!(log.duration_seconds(), Some(60));
     }
@@ -84,13 +170,9 @@ mod tests {
         let log = ExerciseLog {
             exercise_id: "ex1".into(),
             exercise_name: "Push-up".into(),
-            category: Category::Strength,
             start_time: 1000,
-            end_time: None,
-            weight_hg: Weight(0),
-            reps: None,
-            distance_m: None,
             force: Some(Force::Push),
+            ..Default::default()
         };
         assert_eq!(log.duration_seconds(), None);
     }
@@ -99,13 +181,9 @@ mod tests {
         let log = ExerciseLog {
             exercise_id: "ex1".into(),
             exercise_name: "Bench".into(),
-            category: Category::Strength,
             start_time: 2000,
             end_time: Some(1000),
-            weight_hg: Weight(0),
-            reps: None,
-            distance_m: None,
-            force: None,
+            ..Default::default()
         };
         assert_eq!(log.duration_seconds(), Some(0));
     }
@@ -114,13 +192,13 @@ mod tests {
         let log = ExerciseLog {
             exercise_id: "ex1".into(),
             exercise_name: "Squat".into(),
-            category: Category::Strength,
             start_time: 1000,
             end_time: Some(1120),
             weight_hg: Weight(1000),
             reps: Some(5),
             distance_m: Some(Distance(50)),
             force: Some(Force::Push),
+            ..Default::default()
         };
         let json = serde_json::to_string(&log).unwrap();
         let back: ExerciseLog = serde_json::from_str(&json).unwrap();
@@ -134,10 +212,8 @@ mod tests {
             category: Category::Cardio,
             start_time: 1000,
             end_time: Some(2000),
-            weight_hg: Weight(0),
-            reps: None,
             distance_m: Some(Distance(500)),
-            force: None,
+            ..Default::default()
         };
         let json = serde_json::to_string(&log).unwrap();
         assert!(!json.contains("force"));
@@ -154,13 +230,10 @@ mod tests {
         let log = ExerciseLog {
             exercise_id: "bench1".into(),
             exercise_name: "Bench Press".into(),
-            category: Category::Strength,
             force: Some(Force::Push),
             start_time: 1000,
             end_time: Some(1060),
-            weight_hg: Weight(0),
-            reps: None,
-            distance_m: None,
+            ..Default::default()
         };
         assert_eq!(log.type_tag(), ("tag-strength", "💪"));
     }
@@ -4,16 +4,36 @@
 //! supporting types (Enums, Units). All types are serialisable to JSON for
 //! persistence in `IndexedDB` or `SQLite`.
 pub mod analytics;
+pub mod avoid_list;
 pub mod enums;
+pub mod equipment_profile;
 pub mod exercise;
+pub mod exercise_override;
+pub mod goal;
 pub mod log;
+pub mod plan;
+pub mod schedule;
 pub mod session;
+pub mod taxonomy;
+pub mod template;
+pub mod trash;
 pub mod units;
+pub mod video;
+pub use avoid_list::*;
 pub use enums::*;
+pub use equipment_profile::*;
 pub use exercise::*;
+pub use exercise_override::*;
+pub use goal::*;
 pub use log::*;
+pub use plan::*;
+pub use schedule::*;
 pub use session::*;
+pub use taxonomy::*;
+pub use template::*;
+pub use trash::*;
 pub use units::*;
+pub use video::*;
 /// Returns the current Unix timestamp in seconds.
 /// Cross-platform: uses `js_sys` on Web and `SystemTime` on Native.
 #[must_use]
@@ -0,0 +1,310 @@
+use super::session::WorkoutSession;
+use super::units::HG_PER_KG;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use time::{Date, Duration};
+/// Version of the plan file format, bumped on incompatible changes.
+pub const PLAN_FORMAT_VERSION: u32 = 1;
+/// Largest number of sessions a plan may ask for in a week.
+pub const MAX_SESSIONS_PER_WEEK: u8 = 14;
+/// Largest target accepted for sets or repetitions, to catch typos.
+pub const MAX_PLANNED_COUNT: u32 = 1_000;
+/// Errors found while importing a plan file.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum PlanError {
+    /// Not a plan file.
+    #[error("invalid plan file: {0}")]
+    Format(String),
+    /// Written for a newer version of the app.
+    #[error("plan format {0} is not supported")]
+    Version(u32),
+    #[error("the plan has no name")]
+    NoName,
+    #[error("the plan has no day")]
+    NoDays,
+    /// A day lists no exercise.
+    #[error("day \"{0}\" has no exercise")]
+    EmptyDay(String),
+    /// An exercise is in neither the database nor the custom exercises.
+    #[error("unknown exercise \"{0}\"")]
+    UnknownExercise(String),
+    /// A target is zero, negative or implausibly large.
+    #[error("invalid target for \"{0}\"")]
+    InvalidTarget(String),
+}
+/// Exercise of a plan day, with its optional targets.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PlannedExercise {
+    pub exercise_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sets: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reps: Option<u32>,
+    /// Load per set, in kg.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight_kg: Option<f64>,
+}
+impl PlannedExercise {
+    /// Whether any target is set (otherwise it is a bare exercise ID).
+    #[must_use]
+    pub fn has_targets(&self) -> bool {
+        self.sets.is_some() || self.reps.is_some() || self.weight_kg.is_some()
+    }
+}
+impl From<String> for PlannedExercise {
+    fn from(exercise_id: String) -> Self {
+        Self {
+            exercise_id,
+            ..Self::default()
+        }
+    }
+}
+/// One workout of a plan, e.g. "Push" or "Day A".
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PlanDay {
+    pub name: String,
+    pub exercises: Vec<PlannedExercise>,
+}
+/// Training program authored by a coach (or anywhere else) and imported as
+/// a JSON file.  Its days are meant to be rotated, `sessions_per_week`
+/// times a week.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrainingPlan {
+    /// File format version, [`PLAN_FORMAT_VERSION`].
+    pub format: u32,
+    pub name: String,
+    /// Coach or source of the plan.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub author: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub notes: String,
+    pub sessions_per_week: u8,
+    pub days: Vec<PlanDay>,
+    /// Local day (`YYYY-MM-DD`) the plan was started on, set on import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_on: Option<String>,
+}
+/// Sessions done during one week of a plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekAdherence {
+    /// Monday of the week.
+    pub week_start: Date,
+    pub planned: u8,
+    /// Completed sessions with at least one exercise of the plan.
+    pub done: usize,
+    /// Sets of plan exercises logged, warm-ups excluded.
+    pub sets: usize,
+}
+impl WeekAdherence {
+    /// Share of the planned sessions done, capped at 100.
+    #[must_use]
+    pub fn percent(&self) -> u32 {
+        if self.planned == 0 {
+            return 100;
+        }
+        let done = u32::try_from(self.done).unwrap_or(u32::MAX);
+        (done.saturating_mul(100) / u32::from(self.planned)).min(100)
+    }
+}
+impl TrainingPlan {
+    /// Parse and validate a plan file, every exercise having to be one of
+    /// `known_ids`.
+    ///
+    /// # Errors
+    /// The first problem found, see [`PlanError`].
+    pub fn from_json(json: &str, known_ids: &HashSet<&str>) -> Result<Self, PlanError> {
+        let version = serde_json::from_str::<serde_json::Value>(json)
+            .map_err(|e| PlanError::Format(e.to_string()))?
+            .get("format")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| PlanError::Format("missing format version".into()))?;
+        let version = u32::try_from(version).unwrap_or(u32::MAX);
+        if version > PLAN_FORMAT_VERSION {
+            return Err(PlanError::Version(version));
+        }
+        let plan: Self =
+            serde_json::from_str(json).map_err(|e| PlanError::Format(e.to_string()))?;
+        plan.validate(known_ids)?;
+        Ok(plan)
+    }
+    /// Check the plan is usable, every exercise being one of `known_ids`.
+    ///
+    /// # Errors
+    /// The first problem found, see [`PlanError`].
+    pub fn validate(&self, known_ids: &HashSet<&str>) -> Result<(), PlanError> {
+        if self.name.trim().is_empty() {
+            return Err(PlanError::NoName);
+        }
+        if self.days.is_empty() {
+            return Err(PlanError::NoDays);
+        }
+        if self.sessions_per_week == 0 || self.sessions_per_week > MAX_SESSIONS_PER_WEEK {
+            return Err(PlanError::InvalidTarget(self.name.clone()));
+        }
+        for day in &self.days {
+            if day.exercises.is_empty() {
+                return Err(PlanError::EmptyDay(day.name.clone()));
+            }
+            for planned in &day.exercises {
+                let id = &planned.exercise_id;
+                if !known_ids.contains(id.as_str()) {
+                    return Err(PlanError::UnknownExercise(id.clone()));
+                }
+                let count_ok =
+                    |n: Option<u32>| n.is_none_or(|n| (1..=MAX_PLANNED_COUNT).contains(&n));
+                let weight_ok = planned.weight_kg.is_none_or(|w| {
+                    w.is_finite() && w >= 0.0 && w * HG_PER_KG <= f64::from(u16::MAX)
+                });
+                if !count_ok(planned.sets) || !count_ok(planned.reps) || !weight_ok {
+                    return Err(PlanError::InvalidTarget(id.clone()));
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Exercise IDs of every day of the plan.
+    fn exercise_ids(&self) -> HashSet<&str> {
+        self.days
+            .iter()
+            .flat_map(|d| &d.exercises)
+            .map(|e| e.exercise_id.as_str())
+            .collect()
+    }
+    /// Weekly adherence from the week of `started` to the week of `today`,
+    /// oldest first.
+    #[must_use]
+    pub fn adherence(
+        &self,
+        started: Date,
+        sessions: &[WorkoutSession],
+        today: Date,
+    ) -> Vec<WeekAdherence> {
+        let ids = self.exercise_ids();
+        let mut weeks = Vec::new();
+        let mut week_start = crate::datetime::monday_of(started);
+        while week_start <= today {
+            weeks.push(WeekAdherence {
+                week_start,
+                planned: self.sessions_per_week,
+                done: 0,
+                sets: 0,
+            });
+            week_start += Duration::weeks(1);
+        }
+        for session in sessions.iter().filter(|s| !s.is_active()) {
+            let day = crate::datetime::to_local(session.start_time).date();
+            if day < started || day > today {
+                continue;
+            }
+            let sets = session
+                .exercise_logs
+                .iter()
+                .filter(|l| {
+                    l.is_complete()
+                        && l.set_kind.counts_for_stats()
+                        && ids.contains(l.exercise_id.as_str())
+                })
+                .count();
+            let week = crate::datetime::monday_of(day);
+            if let Some(entry) = weeks.iter_mut().find(|w| w.week_start == week) {
+                if sets > 0 {
+                    entry.done += 1;
+                    entry.sets += sets;
+                }
+            }
+        }
+        weeks
+    }
+}
+/// Weekly adherence `weeks` as CSV, for the coach.
+#[must_use]
+pub fn adherence_csv(weeks: &[WeekAdherence]) -> String {
+    let mut csv = String::from("week_start,planned_sessions,done_sessions,percent,sets\r\n");
+    for week in weeks {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\r\n",
+            week.week_start,
+            week.planned,
+            week.done,
+            week.percent(),
+            week.sets
+        ));
+    }
+    csv
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ExerciseLog, Weight};
+    use time::Month;
+    const PLAN: &str = r#"{
+        "format": 1,
+        "name": "Beginner 3x",
+        "author": "Coach C",
+        "sessions_per_week": 3,
+        "days": [
+            {"name": "A", "exercises": [{"exercise_id": "squat", "sets": 3, "reps": 5, "weight_kg": 60}]},
+            {"name": "B", "exercises": [{"exercise_id": "deadlift", "sets": 1, "reps": 5}]}
+        ]
+    }"#;
+    #[test]
+    fn import_validates_the_plan() {
+        let known: HashSet<&str> = ["squat", "deadlift"].into();
+        let plan = TrainingPlan::from_json(PLAN, &known).unwrap();
+        assert_eq!(plan.days[0].exercises[0].weight_kg, Some(60.0));
+        assert_eq!(
+            TrainingPlan::from_json(PLAN, &["squat"].into()),
+            Err(PlanError::UnknownExercise("deadlift".into()))
+        );
+        assert_eq!(
+            TrainingPlan::from_json(&PLAN.replace("\"reps\": 5,", "\"reps\": 0,"), &known),
+            Err(PlanError::InvalidTarget("squat".into()))
+        );
+        assert_eq!(
+            TrainingPlan::from_json(&PLAN.replace("\"format\": 1", "\"format\": 7"), &known),
+            Err(PlanError::Version(7))
+        );
+        assert!(matches!(
+            TrainingPlan::from_json("[]", &known),
+            Err(PlanError::Format(_))
+        ));
+    }
+    #[test]
+    fn adherence_counts_plan_sessions_per_week() {
+        let plan = TrainingPlan::from_json(PLAN, &["squat", "deadlift"].into()).unwrap();
+        let day = |d: u8| Date::from_calendar_date(2025, Month::October, d).unwrap();
+        let at = |d: u8| {
+            u64::try_from(day(d).midnight().assume_utc().unix_timestamp()).unwrap() + 12 * 3_600
+        };
+        let session = |d: u8, id: &str| WorkoutSession {
+            id: format!("{id}{d}"),
+            start_time: at(d),
+            end_time: Some(at(d) + 3_600),
+            exercise_logs: vec![ExerciseLog {
+                exercise_id: id.into(),
+                exercise_name: id.into(),
+                start_time: at(d),
+                end_time: Some(at(d) + 60),
+                weight_hg: Weight(600),
+                reps: Some(5),
+                ..Default::default()
+            }],
+            ..WorkoutSession::default()
+        };
+        // Monday 6 and 13 October 2025.
+        let sessions = [
+            session(7, "squat"),
+            session(9, "deadlift"),
+            session(10, "curl"),
+            session(14, "squat"),
+        ];
+        let weeks = plan.adherence(day(8), &sessions, day(15));
+        assert_eq!(weeks.len(), 2);
+        assert_eq!((weeks[0].week_start, weeks[0].done), (day(6), 1));
+        assert_eq!(
+            (weeks[1].done, weeks[1].sets, weeks[1].percent()),
+            (1, 1, 33)
+        );
+        assert!(adherence_csv(&weeks).contains("\r\n2025-10-13,3,1,33,1\r\n"));
+    }
+}
@@ -0,0 +1,110 @@
+use super::plan::PlannedExercise;
+use serde::{Deserialize, Serialize};
+use time::{Date, Duration, Month};
+/// A workout assigned to a day of the training calendar, from a template or
+/// a day of the training plan.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ScheduledWorkout {
+    /// Local day (`YYYY-MM-DD`) it is planned on.
+    pub date: String,
+    pub name: String,
+    pub exercises: Vec<PlannedExercise>,
+}
+impl ScheduledWorkout {
+    /// The day it is planned on, `None` when unreadable.
+    #[must_use]
+    pub fn day(&self) -> Option<Date> {
+        crate::datetime::parse_iso_date(&self.date)
+    }
+}
+/// How a day of the training calendar is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayStatus {
+    /// Nothing planned nor done.
+    Rest,
+    /// A workout is planned, today or later.
+    Planned,
+    /// A workout was done.
+    Done,
+    /// A workout was planned on a past day without any done.
+    Missed,
+}
+impl DayStatus {
+    /// Status of `day` with respect to `today`, given whether a workout is
+    /// `planned` on it and whether one was `done`.
+    #[must_use]
+    pub fn of(day: Date, today: Date, planned: bool, done: bool) -> Self {
+        match (planned, done) {
+            (_, true) => Self::Done,
+            (true, false) if day < today => Self::Missed,
+            (true, false) => Self::Planned,
+            (false, false) => Self::Rest,
+        }
+    }
+}
+/// First day of the month `delta` months after the one of `date`.
+#[must_use]
+pub fn shift_month(date: Date, delta: i32) -> Date {
+    let index = date.year() * 12 + i32::from(u8::from(date.month())) - 1 + delta;
+    let month = u8::try_from(index.rem_euclid(12) + 1).unwrap_or(1);
+    let month = Month::try_from(month).unwrap_or(Month::January);
+    Date::from_calendar_date(index.div_euclid(12), month, 1).unwrap_or(date)
+}
+/// Weeks of the month of `date`, Monday first, the days of the neighbouring
+/// months being `None`.
+#[must_use]
+pub fn month_weeks(date: Date) -> Vec<[Option<Date>; 7]> {
+    let first = shift_month(date, 0);
+    let next = shift_month(date, 1);
+    let mut weeks = Vec::new();
+    let mut monday = crate::datetime::monday_of(first);
+    while monday < next {
+        let mut week = [None; 7];
+        for (offset, slot) in (0i64..).zip(week.iter_mut()) {
+            let day = monday + Duration::days(offset);
+            if day >= first && day < next {
+                *slot = Some(day);
+            }
+        }
+        weeks.push(week);
+        monday += Duration::weeks(1);
+    }
+    weeks
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+    #[test]
+    fn month_weeks_start_on_monday() {
+        // October 2025 starts on a Wednesday and ends on a Friday.
+        let weeks = month_weeks(date(2025, Month::October, 14));
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][..2], [None, None]);
+        assert_eq!(weeks[0][2], Some(date(2025, Month::October, 1)));
+        assert_eq!(weeks[4][4], Some(date(2025, Month::October, 31)));
+        assert_eq!(weeks[4][5], None);
+        assert_eq!(
+            shift_month(date(2025, Month::December, 31), 1),
+            date(2026, Month::January, 1)
+        );
+        assert_eq!(
+            shift_month(date(2025, Month::January, 5), -1),
+            date(2024, Month::December, 1)
+        );
+    }
+    #[test]
+    fn day_status_tells_planned_done_and_missed_days() {
+        let today = date(2025, Month::October, 14);
+        let yesterday = date(2025, Month::October, 13);
+        assert_eq!(DayStatus::of(today, today, true, false), DayStatus::Planned);
+        assert_eq!(
+            DayStatus::of(yesterday, today, true, false),
+            DayStatus::Missed
+        );
+        assert_eq!(DayStatus::of(yesterday, today, true, true), DayStatus::Done);
+        assert_eq!(DayStatus::of(today, today, false, false), DayStatus::Rest);
+    }
+}
@@ -0,0 +1,190 @@
+use super::enums::{Category, Equipment, Muscle};
+use super::exercise_override::ExerciseOverrides;
+use super::session::WorkoutSession;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use time::Date;
+/// Longest label accepted for a custom tag.
+pub const MAX_TAG_LABEL_LEN: usize = 40;
+/// Built-in taxonomy a custom tag extends.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum TaxonomyKind {
+    Category,
+    Equipment,
+    Muscle,
+}
+impl TaxonomyKind {
+    /// Icon shown before labels of this kind, as on the search filter chips.
+    #[must_use]
+    pub fn icon(self) -> &'static str {
+        match self {
+            Self::Category => "🏷",
+            Self::Equipment => "🔧",
+            Self::Muscle => "💪",
+        }
+    }
+    /// Whether `label` names a built-in value of this kind.
+    fn is_built_in(self, label: &str) -> bool {
+        let label = label.to_lowercase();
+        match self {
+            Self::Category => Category::iter().any(|c| c.as_ref() == label),
+            Self::Equipment => Equipment::iter().any(|e| e.as_ref() == label),
+            Self::Muscle => Muscle::iter().any(|m| m.as_ref() == label),
+        }
+    }
+}
+/// User-defined category, equipment or muscle, e.g. "climbing" or "sled".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct CustomTag {
+    pub kind: TaxonomyKind,
+    pub label: String,
+}
+impl CustomTag {
+    /// Label with the icon of its kind, e.g. "🔧 sled".
+    #[must_use]
+    pub fn display(&self) -> String {
+        format!("{} {}", self.kind.icon(), self.label)
+    }
+}
+/// Custom categories, equipment and muscles extending the built-in ones.
+/// They are assigned to exercises through their overrides.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomTaxonomy {
+    #[serde(default)]
+    pub tags: Vec<CustomTag>,
+}
+impl CustomTaxonomy {
+    /// Tags of `kind`, in the order added.
+    pub fn of_kind(&self, kind: TaxonomyKind) -> impl Iterator<Item = &CustomTag> {
+        self.tags.iter().filter(move |t| t.kind == kind)
+    }
+    /// Add the tag `label` of `kind`.  Returns `false`, changing nothing, when
+    /// the label is empty, too long, built in or already defined (case
+    /// insensitively).
+    pub fn add(&mut self, kind: TaxonomyKind, label: &str) -> bool {
+        let label = label.trim();
+        let taken = self
+            .of_kind(kind)
+            .any(|t| t.label.to_lowercase() == label.to_lowercase());
+        if label.is_empty()
+            || label.chars().count() > MAX_TAG_LABEL_LEN
+            || taken
+            || kind.is_built_in(label)
+        {
+            return false;
+        }
+        self.tags.push(CustomTag {
+            kind,
+            label: label.to_owned(),
+        });
+        true
+    }
+}
+/// Completed sets (warm-ups excluded) of the sessions started between `from`
+/// and `to` (included), per custom tag of their exercise in `overrides`,
+/// most trained first.
+#[must_use]
+pub fn tag_set_counts(
+    sessions: &[WorkoutSession],
+    overrides: &ExerciseOverrides,
+    from: Date,
+    to: Date,
+) -> Vec<(CustomTag, usize)> {
+    let mut counts: Vec<(CustomTag, usize)> = Vec::new();
+    for session in sessions {
+        let day = crate::datetime::to_local(session.start_time).date();
+        if day < from || day > to {
+            continue;
+        }
+        let sets = session
+            .exercise_logs
+            .iter()
+            .filter(|l| l.is_complete() && l.set_kind.counts_for_stats());
+        for log in sets {
+            let Some(over) = overrides.get(&log.exercise_id) else {
+                continue;
+            };
+            for tag in &over.tags {
+                match counts.iter_mut().find(|(t, _)| t == tag) {
+                    Some((_, n)) => *n += 1,
+                    None => counts.push((tag.clone(), 1)),
+                }
+            }
+        }
+    }
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ExerciseLog, ExerciseOverride, SetKind};
+    #[test]
+    fn add_rejects_duplicates_and_built_in_values() {
+        let mut taxonomy = CustomTaxonomy::default();
+        assert!(taxonomy.add(TaxonomyKind::Category, " Climbing "));
+        assert!(!taxonomy.add(TaxonomyKind::Category, "climbing"));
+        assert!(taxonomy.add(TaxonomyKind::Equipment, "climbing"));
+        assert!(!taxonomy.add(TaxonomyKind::Category, "Cardio"));
+        assert!(!taxonomy.add(TaxonomyKind::Muscle, "  "));
+        assert_eq!(taxonomy.tags[0].display(), "🏷 Climbing");
+        assert_eq!(taxonomy.of_kind(TaxonomyKind::Muscle).count(), 0);
+    }
+    #[test]
+    fn counts_sets_per_tag_in_the_period() {
+        let sled = CustomTag {
+            kind: TaxonomyKind::Equipment,
+            label: "sled".into(),
+        };
+        let mut overrides = ExerciseOverrides::new();
+        overrides.insert(
+            "push".into(),
+            ExerciseOverride {
+                tags: vec![sled.clone()],
+                ..ExerciseOverride::default()
+            },
+        );
+        let log = |id: &str, set_kind| ExerciseLog {
+            exercise_id: id.into(),
+            exercise_name: id.into(),
+            category: Category::Strongman,
+            start_time: 1_700_000_000,
+            end_time: Some(1_700_000_060),
+            set_kind,
+            ..Default::default()
+        };
+        let session = WorkoutSession {
+            start_time: 1_700_000_000,
+            end_time: Some(1_700_003_600),
+            exercise_logs: vec![
+                log("push", SetKind::Normal),
+                log("push", SetKind::WarmUp),
+                log("push", SetKind::Normal),
+                log("squat", SetKind::Normal),
+            ],
+            ..WorkoutSession::default()
+        };
+        let day = crate::datetime::to_local(session.start_time).date();
+        let sessions = [session];
+        assert_eq!(
+            tag_set_counts(&sessions, &overrides, day, day),
+            vec![(sled, 2)]
+        );
+        assert!(tag_set_counts(&sessions, &overrides, day.next_day().unwrap(), day).is_empty());
+    }
+}
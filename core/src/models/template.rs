@@ -0,0 +1,109 @@
+use super::log::ExerciseLog;
+use super::plan::PlannedExercise;
+use super::session::WorkoutSession;
+use super::units::HG_PER_KG;
+use serde::{Deserialize, Serialize};
+/// Reusable session saved from a completed one: its exercises in order, with
+/// the sets, reps and load typically done, started again in one tap.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SessionTemplate {
+    pub name: String,
+    pub exercises: Vec<PlannedExercise>,
+}
+/// Middle value of `values`, the upper one for an even count.
+fn median<T: Ord + Copy>(mut values: Vec<T>) -> Option<T> {
+    values.sort_unstable();
+    values.get(values.len() / 2).copied()
+}
+/// Targets of `exercise_id` from its `logs`: the working sets done, and their
+/// median reps and load.
+fn planned(exercise_id: &str, logs: &[&ExerciseLog]) -> PlannedExercise {
+    let working: Vec<&ExerciseLog> = logs
+        .iter()
+        .copied()
+        .filter(|l| l.is_complete() && l.set_kind.counts_for_stats())
+        .collect();
+    let weight_hg = median(
+        working
+            .iter()
+            .filter(|l| !l.assisted && l.weight_hg.0 > 0)
+            .map(|l| l.weight_hg.0)
+            .collect(),
+    );
+    PlannedExercise {
+        exercise_id: exercise_id.to_owned(),
+        sets: u32::try_from(working.len()).ok().filter(|&n| n > 0),
+        reps: median(working.iter().filter_map(|l| l.reps).collect()),
+        weight_kg: weight_hg.map(|hg| f64::from(hg) / HG_PER_KG),
+    }
+}
+impl SessionTemplate {
+    /// Template named `name` of the exercises of `session`, in the order
+    /// first done.
+    #[must_use]
+    pub fn from_session(name: String, session: &WorkoutSession) -> Self {
+        let mut logs: Vec<&ExerciseLog> = session.exercise_logs.iter().collect();
+        logs.sort_by_key(|l| l.start_time);
+        let mut order: Vec<&str> = Vec::new();
+        for log in &logs {
+            if !order.contains(&log.exercise_id.as_str()) {
+                order.push(&log.exercise_id);
+            }
+        }
+        let exercises = order
+            .into_iter()
+            .map(|id| {
+                let of_exercise: Vec<&ExerciseLog> = logs
+                    .iter()
+                    .copied()
+                    .filter(|l| l.exercise_id == id)
+                    .collect();
+                planned(id, &of_exercise)
+            })
+            .collect();
+        Self { name, exercises }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SetKind, Weight};
+    fn log(id: &str, start_time: u64, weight_hg: u16, reps: u32, set_kind: SetKind) -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: id.into(),
+            exercise_name: id.into(),
+            start_time,
+            end_time: Some(start_time + 30),
+            weight_hg: Weight(weight_hg),
+            reps: Some(reps),
+            set_kind,
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn template_keeps_the_order_and_typical_loads() {
+        let mut session = WorkoutSession::new();
+        session.exercise_logs = vec![
+            log("bench", 300, 600, 8, SetKind::Normal),
+            log("squat", 0, 400, 10, SetKind::WarmUp),
+            log("squat", 100, 800, 5, SetKind::Normal),
+            log("squat", 200, 850, 5, SetKind::Normal),
+            log("squat", 250, 800, 4, SetKind::Normal),
+            log("pullups", 400, 0, 6, SetKind::Normal),
+        ];
+        let template = SessionTemplate::from_session("Legs".into(), &session);
+        assert_eq!(template.name, "Legs");
+        let ids: Vec<&str> = template
+            .exercises
+            .iter()
+            .map(|e| e.exercise_id.as_str())
+            .collect();
+        assert_eq!(ids, ["squat", "bench", "pullups"]);
+        let squat = &template.exercises[0];
+        assert_eq!(
+            (squat.sets, squat.reps, squat.weight_kg),
+            (Some(3), Some(5), Some(80.0))
+        );
+        assert_eq!(template.exercises[2].weight_kg, None);
+    }
+}
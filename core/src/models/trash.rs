@@ -0,0 +1,115 @@
+use super::exercise::Exercise;
+use super::session::WorkoutSession;
+use crate::datetime::SECONDS_IN_DAY;
+use serde::{Deserialize, Serialize};
+/// Number of days deleted data stays restorable.
+pub const TRASH_RETENTION_DAYS: u64 = 30;
+/// A deleted item, as it was in its original store.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashedItem {
+    Session(WorkoutSession),
+    Exercise(Exercise),
+}
+impl TrashedItem {
+    /// ID of the item in its original store.
+    #[must_use]
+    pub fn original_id(&self) -> &str {
+        match self {
+            Self::Session(s) => &s.id,
+            Self::Exercise(e) => &e.id,
+        }
+    }
+    /// Whether the item is worth keeping in the trash: sessions without any
+    /// log (e.g. cancelled right after starting) are simply deleted.
+    #[must_use]
+    pub fn is_worth_keeping(&self) -> bool {
+        match self {
+            Self::Session(s) => !s.exercise_logs.is_empty(),
+            Self::Exercise(_) => true,
+        }
+    }
+}
+/// An entry of the trash store.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrashEntry {
+    /// Store key, unique across item kinds (see [`TrashEntry::key`]).
+    pub id: String,
+    /// Unix timestamp (seconds) of the deletion.
+    pub deleted_at: u64,
+    pub item: TrashedItem,
+}
+impl TrashEntry {
+    /// Wrap `item`, deleted at `deleted_at`.
+    #[must_use]
+    pub fn new(item: TrashedItem, deleted_at: u64) -> Self {
+        Self {
+            id: Self::key(&item),
+            deleted_at,
+            item,
+        }
+    }
+    /// Trash store key of `item`: its kind and original ID, so a session and
+    /// an exercise sharing an ID never collide.
+    #[must_use]
+    pub fn key(item: &TrashedItem) -> String {
+        let kind = match item {
+            TrashedItem::Session(_) => "session",
+            TrashedItem::Exercise(_) => "exercise",
+        };
+        format!("{kind}/{}", item.original_id())
+    }
+    /// Unix timestamp after which the entry is purged.
+    #[must_use]
+    pub fn expires_at(&self) -> u64 {
+        self.deleted_at + TRASH_RETENTION_DAYS * SECONDS_IN_DAY
+    }
+    /// Whole days left before the entry is purged, as of `now`.
+    #[must_use]
+    pub fn days_left(&self, now: u64) -> u64 {
+        self.expires_at()
+            .saturating_sub(now)
+            .div_ceil(SECONDS_IN_DAY)
+    }
+}
+/// Oldest deletion timestamp still kept in the trash, as of `now`.
+#[must_use]
+pub fn trash_cutoff(now: u64) -> u64 {
+    now.saturating_sub(TRASH_RETENTION_DAYS * SECONDS_IN_DAY)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ExerciseLog, Weight};
+    #[test]
+    fn key_includes_the_item_kind() {
+        let mut session = WorkoutSession::new();
+        session.id = "abc".into();
+        let entry = TrashEntry::new(TrashedItem::Session(session), 0);
+        assert_eq!(entry.id, "session/abc");
+        assert_eq!(entry.item.original_id(), "abc");
+    }
+    #[test]
+    fn retention_counts_whole_days() {
+        let mut session = WorkoutSession::new();
+        session.exercise_logs.push(ExerciseLog {
+            exercise_id: "squat".into(),
+            exercise_name: "Squat".into(),
+            end_time: Some(30),
+            weight_hg: Weight(1000),
+            reps: Some(5),
+            ..Default::default()
+        });
+        let entry = TrashEntry::new(TrashedItem::Session(session), 1_000);
+        assert!(entry.item.is_worth_keeping());
+        assert_eq!(entry.days_left(1_000), TRASH_RETENTION_DAYS);
+        assert_eq!(entry.days_left(1_000 + SECONDS_IN_DAY + 1), 29);
+        assert_eq!(entry.days_left(entry.expires_at()), 0);
+        assert_eq!(trash_cutoff(entry.expires_at()), entry.deleted_at);
+    }
+    #[test]
+    fn empty_sessions_are_not_kept() {
+        let item = TrashedItem::Session(WorkoutSession::new());
+        assert!(!item.is_worth_keeping());
+    }
+}
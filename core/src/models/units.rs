@@ -4,10 +4,61 @@ use std::fmt;
 pub const HG_PER_KG: f64 = 10.0;
 /// Distance stored as meters: 1 km = 1000 m
 pub const M_PER_KM: f64 = 1000.0;
+/// Base language tags whose locale writes decimals with a comma ("82,5").
+const DECIMAL_COMMA_LANGUAGES: &[&str] = &[
+    "cs", "da", "de", "es", "fi", "fr", "it", "nb", "nl", "pl", "pt", "ru", "sv", "tr", "uk",
+];
+/// Whether numbers are written with a decimal comma in `lang` (BCP 47 tag).
+#[must_use]
+pub fn uses_decimal_comma(lang: &str) -> bool {
+    let base = lang.split(['-', '_']).next().unwrap_or_default();
+    DECIMAL_COMMA_LANGUAGES.contains(&base)
+}
+/// Replace the decimal point of a formatted number with the separator of `lang`.
+#[must_use]
+pub fn localize_decimal(formatted: &str, lang: &str) -> String {
+    if uses_decimal_comma(lang) {
+        formatted.replace('.', ",")
+    } else {
+        formatted.to_owned()
+    }
+}
+/// Parse a user-entered decimal number, accepting `.` or `,` as separator.
+#[must_use]
+pub fn parse_decimal(input: &str) -> Option<f64> {
+    input.trim().replacen(',', ".", 1).parse().ok()
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct Weight(pub u16);
 
+impl Weight {
+    /// Weight of `kg` kilograms, rounded to the hectogram; `None` unless
+    /// positive and representable.
+    #[must_use]
+    pub fn from_kg(kg: f64) -> Option<Self> {
+        if !kg.is_finite() || kg <= 0.0 {
+            return None;
+        }
+        let hg = (kg * HG_PER_KG).round();
+        if hg < 1.0 || hg > f64::from(u16::MAX) {
+            return None;
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Some(Self(hg as u16))
+    }
+    /// Text to pre-fill a kg input with, e.g. "82.5" or "82,5" depending on `lang`.
+    #[must_use]
+    pub fn input_value(self, lang: &str) -> String {
+        localize_decimal(&format!("{:.1}", f64::from(self.0) / HG_PER_KG), lang)
+    }
+    /// Display form (see [`fmt::Display`]) with the decimal separator of `lang`.
+    #[must_use]
+    pub fn localized(self, lang: &str) -> String {
+        localize_decimal(&self.to_string(), lang)
+    }
+}
+
 impl fmt::Display for Weight {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if f64::from(self.0) % HG_PER_KG < f64::EPSILON {
@@ -22,6 +73,19 @@ impl fmt::Display for Weight {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Distance(pub u32);
 
+impl Distance {
+    /// Text to pre-fill a km input with, e.g. "5.25" or "5,25" depending on `lang`.
+    #[must_use]
+    pub fn input_value(self, lang: &str) -> String {
+        localize_decimal(&format!("{:.2}", f64::from(self.0) / M_PER_KM), lang)
+    }
+    /// Display form (see [`fmt::Display`]) with the decimal separator of `lang`.
+    #[must_use]
+    pub fn localized(self, lang: &str) -> String {
+        localize_decimal(&self.to_string(), lang)
+    }
+}
+
 impl fmt::Display for Distance {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if f64::from(self.0) >= M_PER_KM {
@@ -36,18 +100,9 @@ impl fmt::Display for Distance {
     }
 }
 
-/// Parse a user-entered kg string into a Weight (hectograms).
+/// Parse a user-entered kg string ("82.5" or "82,5") into a Weight (hectograms).
 pub fn parse_weight_kg(input: &str) -> Option<Weight> {
-    let val: f64 = input.parse().ok()?;
-    if !val.is_finite() || val <= 0.0 {
-        return None;
-    }
-    let hg = (val * HG_PER_KG).round();
-    if hg < 1.0 || hg > f64::from(u16::MAX) {
-        return None;
-    }
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    Some(Weight(hg as u16))
+    Weight::from_kg(parse_decimal(input)?)
 }
 /// Parse a user-entered duration string (seconds, MM:SS, or HH:MM:SS) into seconds.
 #[must_use]
@@ -79,9 +134,9 @@ pub fn parse_duration_seconds(input: &str) -> Option<u64> {
         _ => None,
     }
 }
-/// Parse a user-entered km string into a Distance (meters).
+/// Parse a user-entered km string ("5.2" or "5,2") into a Distance (meters).
 pub fn parse_distance_km(input: &str) -> Option<Distance> {
-    let val: f64 = input.parse().ok()?;
+    let val = parse_decimal(input)?;
     if !val.is_finite() || val <= 0.0 {
         return None;
     }
@@ -226,4 +281,17 @@ mod tests {
         assert_eq!(parse_duration_seconds("abc"), None);
         assert_eq!(parse_duration_seconds("1:ab"), None);
     }
+    #[test]
+    fn parse_accepts_decimal_comma() {
+        assert_eq!(parse_weight_kg("82,5"), Some(Weight(825)));
+        assert_eq!(parse_distance_km(" 5,25 "), Some(Distance(5250)));
+        assert_eq!(parse_weight_kg("1,000,5"), None);
+    }
+    #[test]
+    fn values_follow_locale_separator() {
+        assert_eq!(Weight(825).input_value("fr-FR"), "82,5");
+        assert_eq!(Weight(825).input_value("en"), "82.5");
+        assert_eq!(Distance(5250).localized("es"), "5,25 km");
+        assert_eq!(Distance(5250).localized("en-US"), "5.25 km");
+    }
 }
@@ -0,0 +1,92 @@
+/// Whether `url` is a web page address, the only kind of video link stored
+/// or rendered (other schemes such as `javascript:` are rejected).
+#[must_use]
+pub fn is_web_url(url: &str) -> bool {
+    let url = url.trim();
+    url.len() > "https://".len() && (url.starts_with("https://") || url.starts_with("http://"))
+}
+/// Embeddable player URL of a technique video, when its host is known.
+///
+/// Recognises YouTube (`watch?v=`, `youtu.be/`, `shorts/`, `embed/`) and
+/// Vimeo links; any other URL returns `None` and is shown as a plain link.
+/// YouTube videos use the privacy-enhanced `youtube-nocookie.com` player.
+#[must_use]
+pub fn video_embed_url(url: &str) -> Option<String> {
+    let rest = url
+        .trim()
+        .strip_prefix("https://")
+        .or_else(|| url.trim().strip_prefix("http://"))?;
+    let rest = rest.strip_prefix("www.").unwrap_or(rest);
+    let rest = rest.strip_prefix("m.").unwrap_or(rest);
+    let (host, path) = rest.split_once('/')?;
+    let youtube_id = match host {
+        "youtu.be" => Some(path.to_owned()),
+        "youtube.com" | "youtube-nocookie.com" => path
+            .strip_prefix("watch?")
+            .and_then(|query| crate::query::get_query_param(query, "v"))
+            .or_else(|| {
+                path.strip_prefix("shorts/")
+                    .or_else(|| path.strip_prefix("embed/"))
+                    .map(str::to_owned)
+            }),
+        _ => None,
+    };
+    if let Some(id) = youtube_id {
+        let id = video_id(&id)?;
+        return Some(format!("https://www.youtube-nocookie.com/embed/{id}"));
+    }
+    if host == "vimeo.com" || host == "player.vimeo.com" {
+        let id = video_id(path.strip_prefix("video/").unwrap_or(path))?;
+        if id.bytes().all(|b| b.is_ascii_digit()) {
+            return Some(format!("https://player.vimeo.com/video/{id}"));
+        }
+    }
+    None
+}
+/// Leading ID of `path` (up to the first `?`, `&`, `#` or `/`), when it only
+/// contains URL-safe ID characters.
+fn video_id(path: &str) -> Option<&str> {
+    let id = path.split(['?', '&', '#', '/']).next()?;
+    let valid = !id.is_empty()
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+    valid.then_some(id)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EMBED: &str = "https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ";
+    #[test]
+    fn recognises_youtube_links() {
+        for url in [
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+            "https://m.youtube.com/watch?feature=share&v=dQw4w9WgXcQ",
+            "https://youtu.be/dQw4w9WgXcQ?t=42",
+            "https://youtube.com/shorts/dQw4w9WgXcQ",
+            "http://www.youtube.com/embed/dQw4w9WgXcQ",
+        ] {
+            assert_eq!(video_embed_url(url).as_deref(), Some(EMBED), "{url}");
+        }
+    }
+    #[test]
+    fn recognises_vimeo_links() {
+        assert_eq!(
+            video_embed_url("https://vimeo.com/76979871").as_deref(),
+            Some("https://player.vimeo.com/video/76979871")
+        );
+    }
+    #[test]
+    fn other_links_are_not_embedded() {
+        assert_eq!(video_embed_url("https://example.com/squat.mp4"), None);
+        assert_eq!(video_embed_url("https://vimeo.com/channels/staff"), None);
+        assert_eq!(video_embed_url("https://youtu.be/<script>"), None);
+        assert_eq!(video_embed_url("not a url"), None);
+    }
+    #[test]
+    fn only_web_urls_are_accepted() {
+        assert!(is_web_url("https://example.com/squat"));
+        assert!(!is_web_url("javascript:alert(1)"));
+        assert!(!is_web_url("https://"));
+    }
+}
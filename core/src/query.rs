@@ -0,0 +1,59 @@
+//! URL query string helpers.
+/// Look up a single parameter value from a URL query string.
+#[must_use]
+pub fn get_query_param(query: &str, name: &str) -> Option<String> {
+    query.split('&').find_map(|pair| {
+        let (k, v) = pair.split_once('=')?;
+        if k == name {
+            Some(percent_decode(v))
+        } else {
+            None
+        }
+    })
+}
+/// Percent-decodes a URL query-parameter value using the standardised
+/// `percent-encoding` crate.  `+` is treated as a space per the
+/// `application/x-www-form-urlencoded` convention.
+fn percent_decode(s: &str) -> String {
+    use std::borrow::Cow;
+    // Replace `+` with `%20` only when needed so we avoid an allocation in the common case.
+    let s = if s.contains('+') {
+        Cow::Owned(s.replace('+', "%20"))
+    } else {
+        Cow::Borrowed(s)
+    };
+    percent_encoding::percent_decode_str(&s)
+        .decode_utf8_lossy()
+        .into_owned()
+}
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn get_query_param_basic() {
+        assert_eq!(
+            super::get_query_param("foo=bar&baz=qux", "foo"),
+            Some("bar".to_string()),
+        );
+        assert_eq!(
+            super::get_query_param("foo=bar&baz=qux", "baz"),
+            Some("qux".to_string()),
+        );
+        assert_eq!(super::get_query_param("foo=bar&baz=qux", "missing"), None);
+    }
+    #[test]
+    fn percent_decode_handles_common_chars() {
+        assert_eq!(
+            super::percent_decode("hello%20world"),
+            "hello world".to_string()
+        );
+        assert_eq!(super::percent_decode("a+b"), "a b".to_string());
+        assert_eq!(
+            super::percent_decode("http%3A%2F%2Flocalhost%3A8080"),
+            "http://localhost:8080".to_string(),
+        );
+    }
+    #[test]
+    fn percent_decode_handles_multibyte_utf8() {
+        assert_eq!(super::percent_decode("%C3%A9"), "é".to_string());
+    }
+}
@@ -0,0 +1,217 @@
+/// Actual rests between exercises, against the planned rest duration.
+///
+/// A rest runs from the end of a logged exercise to the start of the next
+/// one, less the session pauses taken in between (a pause during the next
+/// exercise included, as resuming shifts its start).  Gaps still longer than
+/// [`MAX_REST_SECS`] are breaks (a chat, a phone call) and not counted;
+/// neither are rests after an exercise without planned rest.
+///
+/// The planned rest is the one recorded on the log
+/// ([`ExerciseLog::planned_rest_s`]); logs older than that record fall back
+/// to the rest the caller estimates for them.
+///
+/// Comparing the first and the second half of the rests of each session shows
+/// whether rests get longer as the workout goes on.
+use crate::models::{ExerciseLog, Pause, WorkoutSession};
+/// Longest gap (seconds) between two exercises still counted as a rest.
+pub const MAX_REST_SECS: u64 = 1_800;
+/// One rest, see [`rests`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rest {
+    /// Seconds actually rested.
+    pub actual: u64,
+    /// Seconds of rest planned after the exercise.
+    pub planned: u64,
+}
+impl Rest {
+    /// Seconds rested beyond the planned rest, negative when cut short.
+    #[must_use]
+    pub fn overrun(&self) -> i64 {
+        self.actual.cast_signed() - self.planned.cast_signed()
+    }
+}
+/// Average rest of a session, see [`rest_series`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SessionRests {
+    pub start_time: u64,
+    /// Average seconds actually rested.
+    pub actual_seconds: f64,
+    /// Average seconds of rest planned.
+    pub planned_seconds: f64,
+}
+/// Rest planned after `log`: the recorded one, else `fallback`'s; `None`
+/// when no rest follows it.
+fn planned_rest(log: &ExerciseLog, fallback: impl Fn(&ExerciseLog) -> Option<u64>) -> Option<u64> {
+    log.planned_rest_s
+        .or_else(|| fallback(log))
+        .filter(|&secs| secs > 0)
+}
+/// Rests of `session` in order, `fallback` giving the rest planned after a
+/// log that did not record it, `None` when no rest follows it.
+pub fn rests(
+    session: &WorkoutSession,
+    fallback: impl Fn(&ExerciseLog) -> Option<u64>,
+) -> Vec<Rest> {
+    let mut logs: Vec<&ExerciseLog> = session.exercise_logs.iter().collect();
+    logs.sort_by_key(|l| l.start_time);
+    logs.windows(2)
+        .filter_map(|pair| {
+            let end = pair[0].end_time?;
+            let next_end = pair[1].end_time.unwrap_or(pair[1].start_time);
+            let paused: u64 = session
+                .pauses
+                .iter()
+                .filter(|p| p.start >= end && p.start < next_end)
+                .map(Pause::duration)
+                .sum();
+            let actual = pair[1].start_time.checked_sub(end)?.saturating_sub(paused);
+            let planned = planned_rest(pair[0], &fallback)?;
+            (actual <= MAX_REST_SECS).then_some(Rest { actual, planned })
+        })
+        .collect()
+}
+/// Mean of `values`, `None` when empty.
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0u32), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| sum / f64::from(count))
+}
+/// Average rest of each completed session of `sessions` with rests, sorted
+/// by time.
+pub fn rest_series(
+    sessions: &[WorkoutSession],
+    fallback: impl Fn(&ExerciseLog) -> Option<u64>,
+) -> Vec<SessionRests> {
+    let mut series: Vec<SessionRests> = sessions
+        .iter()
+        .filter(|s| !s.is_active())
+        .filter_map(|s| {
+            let rests = rests(s, &fallback);
+            #[allow(clippy::cast_precision_loss)]
+            Some(SessionRests {
+                start_time: s.start_time,
+                actual_seconds: mean(rests.iter().map(|r| r.actual as f64))?,
+                planned_seconds: mean(rests.iter().map(|r| r.planned as f64))?,
+            })
+        })
+        .collect();
+    series.sort_by_key(|s| s.start_time);
+    series
+}
+/// Average overrun (seconds) of the rests in the first half of the sessions,
+/// and of those in the second half; `None` without sessions of two rests.
+pub fn overrun_by_half(
+    sessions: &[WorkoutSession],
+    fallback: impl Fn(&ExerciseLog) -> Option<u64>,
+) -> Option<(f64, f64)> {
+    let (mut early, mut late) = (Vec::new(), Vec::new());
+    for session in sessions.iter().filter(|s| !s.is_active()) {
+        let rests = rests(session, &fallback);
+        // With an odd count, the middle rest belongs to neither half.
+        let half = rests.len() / 2;
+        early.extend(rests[..half].iter().map(Rest::overrun));
+        late.extend(rests[rests.len() - half..].iter().map(Rest::overrun));
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let average = |overruns: &[i64]| mean(overruns.iter().map(|&o| o as f64));
+    Some((average(&early)?, average(&late)?))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn log(start_time: u64, end_time: u64) -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: "squat".into(),
+            exercise_name: "Squat".into(),
+            start_time,
+            end_time: Some(end_time),
+            reps: Some(5),
+            ..Default::default()
+        }
+    }
+    fn session(logs: Vec<ExerciseLog>) -> WorkoutSession {
+        let mut s = WorkoutSession::new();
+        s.start_time = logs.first().map_or(0, |l| l.start_time);
+        s.end_time = logs.last().and_then(|l| l.end_time);
+        s.exercise_logs = logs;
+        s
+    }
+    #[test]
+    fn rests_run_between_exercises_and_skip_breaks() {
+        // Rests of 60 s, then a 40 min break, then 90 s.
+        let s = session(vec![
+            log(0, 30),
+            log(90, 120),
+            log(2_520, 2_550),
+            log(2_640, 2_670),
+        ]);
+        let rests = rests(&s, |_| Some(60));
+        assert_eq!(
+            rests,
+            vec![
+                Rest {
+                    actual: 60,
+                    planned: 60
+                },
+                Rest {
+                    actual: 90,
+                    planned: 60
+                },
+            ]
+        );
+        assert_eq!(rests[1].overrun(), 30);
+        assert!(super::rests(&s, |_| None).is_empty());
+        let series = rest_series(&[s], |_| Some(60));
+        assert!((series[0].actual_seconds - 75.0).abs() < 1e-9);
+        assert!((series[0].planned_seconds - 60.0).abs() < 1e-9);
+    }
+    #[test]
+    fn overrun_by_half_compares_early_and_late_rests() {
+        // Rests of 60, 70, 100 and 120 s against 60 planned.
+        let s = session(vec![
+            log(0, 30),
+            log(90, 120),
+            log(190, 220),
+            log(320, 350),
+            log(470, 500),
+        ]);
+        assert_eq!(overrun_by_half(&[s], |_| Some(60)), Some((5.0, 50.0)));
+        assert_eq!(
+            overrun_by_half(&[session(vec![log(0, 30)])], |_| Some(60)),
+            None
+        );
+    }
+    #[test]
+    fn recorded_planned_rest_wins_over_the_fallback() {
+        let planned = |start_time, end_time, rest| ExerciseLog {
+            planned_rest_s: Some(rest),
+            ..log(start_time, end_time)
+        };
+        // 90 planned then recorded, 0 recorded as no rest, then unrecorded.
+        let s = session(vec![
+            planned(0, 30, 90),
+            planned(120, 150, 0),
+            log(200, 230),
+            log(300, 330),
+        ]);
+        let planned: Vec<u64> = rests(&s, |_| Some(60)).iter().map(|r| r.planned).collect();
+        assert_eq!(planned, [90, 60]);
+    }
+    #[test]
+    fn pauses_are_not_counted_as_rest() {
+        // A 60 s rest with a 20 min pause in it, then a 40 s rest followed by
+        // a set paused for 5 min, which resuming moves 5 min later.
+        let mut s = session(vec![log(0, 30), log(1_290, 1_320), log(1_660, 1_750)]);
+        s.pauses = vec![
+            Pause {
+                start: 60,
+                end: 1_260,
+            },
+            Pause {
+                start: 1_400,
+                end: 1_700,
+            },
+        ];
+        let actual: Vec<u64> = rests(&s, |_| Some(60)).iter().map(|r| r.actual).collect();
+        assert_eq!(actual, [60, 40]);
+    }
+}
@@ -0,0 +1,145 @@
+/// Summary statistics of a workout session.
+///
+/// Shared by the past-session cards on the home page and the summary shown
+/// when a session is finished, so both always agree.  Exercise metadata
+/// (muscles) is looked up in the built-in and custom databases; logs of
+/// exercises missing from both still count towards every other figure.
+use crate::models::{resolve_exercise, Exercise, Muscle, WorkoutSession, HG_PER_KG};
+/// Number of muscle groups listed in [`SessionStats::top_muscles`].
+pub const TOP_MUSCLES: usize = 3;
+/// Aggregated figures for one session.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SessionStats {
+    /// Net duration in seconds (pauses excluded).
+    pub duration: u64,
+    /// Number of distinct exercises performed.
+    pub exercise_count: usize,
+    /// Number of logged sets.
+    pub set_count: usize,
+    /// Total volume lifted: Σ weight × reps, in hectogram-reps (warm-up sets
+    /// excluded).
+    pub volume_hg: u64,
+    /// Most trained primary muscles (by number of sets), most trained first.
+    pub top_muscles: Vec<Muscle>,
+}
+impl SessionStats {
+    /// Total volume in kg.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn volume_kg(&self) -> f64 {
+        self.volume_hg as f64 / HG_PER_KG
+    }
+}
+/// Compute the [`SessionStats`] of `session`.
+#[must_use]
+pub fn compute<E: AsRef<Exercise>>(
+    session: &WorkoutSession,
+    db: &[E],
+    custom: &[E],
+) -> SessionStats {
+    let mut exercises = std::collections::HashSet::new();
+    let mut volume_hg = 0u64;
+    let mut muscle_sets: Vec<(Muscle, usize)> = Vec::new();
+    for log in &session.exercise_logs {
+        exercises.insert(log.exercise_id.as_str());
+        volume_hg += log.volume_hg();
+        let Some(exercise) = resolve_exercise(db, custom, &log.exercise_id) else {
+            continue;
+        };
+        for &muscle in &exercise.as_ref().primary_muscles {
+            match muscle_sets.iter_mut().find(|(m, _)| *m == muscle) {
+                Some((_, sets)) => *sets += 1,
+                None => muscle_sets.push((muscle, 1)),
+            }
+        }
+    }
+    // Stable sort: ties keep the order in which muscles were first trained.
+    muscle_sets.sort_by_key(|&(_, sets)| std::cmp::Reverse(sets));
+    SessionStats {
+        duration: session.duration_seconds(),
+        exercise_count: exercises.len(),
+        set_count: session.exercise_logs.len(),
+        volume_hg,
+        top_muscles: muscle_sets
+            .into_iter()
+            .take(TOP_MUSCLES)
+            .map(|(m, _)| m)
+            .collect(),
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, ExerciseLog, SetKind, Weight};
+    fn exercise(id: &str, muscles: Vec<Muscle>) -> Exercise {
+        Exercise {
+            id: id.into(),
+            name: id.into(),
+            name_lower: id.to_lowercase(),
+            force: None,
+            level: None,
+            mechanic: None,
+            equipment: None,
+            primary_muscles: muscles,
+            secondary_muscles: vec![],
+            instructions: vec![],
+            category: Category::Strength,
+            images: vec![],
+            videos: vec![],
+            i18n: None,
+            rest_seconds: None,
+        }
+    }
+    fn set(id: &str, weight_hg: u16, reps: Option<u32>) -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: id.into(),
+            exercise_name: id.into(),
+            end_time: Some(30),
+            weight_hg: Weight(weight_hg),
+            reps,
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn aggregates_counts_volume_and_muscles() {
+        let db = vec![
+            exercise("bench", vec![Muscle::Chest, Muscle::Triceps]),
+            exercise("curl", vec![Muscle::Biceps]),
+        ];
+        let mut session = WorkoutSession::new();
+        session.exercise_logs = vec![
+            set("bench", 800, Some(10)),
+            set("bench", 800, Some(8)),
+            set("curl", 120, Some(12)),
+            set("unknown", 100, None),
+        ];
+        let stats = compute(&session, &db, &[]);
+        assert_eq!(stats.exercise_count, 3);
+        assert_eq!(stats.set_count, 4);
+        assert_eq!(stats.volume_hg, 800 * 18 + 120 * 12);
+        assert!((stats.volume_kg() - 1584.0).abs() < f64::EPSILON);
+        assert_eq!(
+            stats.top_muscles,
+            vec![Muscle::Chest, Muscle::Triceps, Muscle::Biceps]
+        );
+    }
+    #[test]
+    fn warm_up_sets_add_no_volume() {
+        let mut warm_up = set("bench", 400, Some(10));
+        warm_up.set_kind = SetKind::WarmUp;
+        let mut dropset = set("bench", 600, Some(5));
+        dropset.set_kind = SetKind::Dropset;
+        let mut session = WorkoutSession::new();
+        session.exercise_logs = vec![warm_up, set("bench", 800, Some(8)), dropset];
+        let stats = compute::<Exercise>(&session, &[], &[]);
+        assert_eq!(stats.set_count, 3);
+        assert_eq!(stats.volume_hg, 800 * 8 + 600 * 5);
+    }
+    #[test]
+    fn empty_session_has_zero_stats() {
+        let stats = compute::<Exercise>(&WorkoutSession::new(), &[], &[]);
+        assert_eq!(stats.exercise_count, 0);
+        assert_eq!(stats.volume_hg, 0);
+        assert!(stats.top_muscles.is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+/// Compact, read-only encoding of one session, to send a workout in a link
+/// or QR code without any server.
+///
+/// Only what describes the workout is kept: the sets with their exercise,
+/// load, reps, distance and timing.  Notes, GPS tracks, heart-rate samples
+/// and the location stay private.  Keys are shortened and times stored
+/// relative to the session start, then the JSON is base64url-encoded so it
+/// can travel in a URL query unescaped.
+use crate::models::{Category, Distance, ExerciseLog, SetKind, Side, Weight, WorkoutSession};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use serde::{Deserialize, Serialize};
+/// Version of the encoding, bumped on incompatible changes.
+const FORMAT_VERSION: u8 = 1;
+/// Errors raised while decoding a shared session.
+#[derive(Debug, thiserror::Error)]
+pub enum ShareLinkError {
+    /// Not base64url.
+    #[error("not a shared session code")]
+    Encoding,
+    /// Malformed content.
+    #[error("invalid shared session: {0}")]
+    Format(#[from] serde_json::Error),
+    /// Encoded by a newer version of the app.
+    #[error("shared session format {0} is not supported")]
+    Version(u8),
+}
+fn is_zero<T: Default + PartialEq>(n: &T) -> bool {
+    *n == T::default()
+}
+#[derive(Debug, Serialize, Deserialize)]
+struct SharedSession {
+    v: u8,
+    /// Start time (Unix seconds).
+    t: u64,
+    /// Gross duration in seconds.
+    d: u64,
+    /// Time spent paused, in seconds.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    p: u64,
+    s: Vec<SharedSet>,
+}
+#[derive(Debug, Serialize, Deserialize)]
+struct SharedSet {
+    /// Exercise ID and name.
+    i: String,
+    n: String,
+    c: Category,
+    /// Start and end, in seconds after the session start.
+    t: u64,
+    e: u64,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    w: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    r: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    m: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    l: Option<Side>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    a: bool,
+    #[serde(default, skip_serializing_if = "SetKind::is_normal")]
+    k: SetKind,
+}
+/// Encode the completed sets of the finished `session`.
+#[must_use]
+pub fn encode_session(session: &WorkoutSession) -> String {
+    let start = session.start_time;
+    let shared = SharedSession {
+        v: FORMAT_VERSION,
+        t: start,
+        d: session.end_time.unwrap_or(start).saturating_sub(start),
+        p: session.total_paused_duration,
+        s: session
+            .exercise_logs
+            .iter()
+            .filter_map(|log| {
+                Some(SharedSet {
+                    i: log.exercise_id.clone(),
+                    n: log.exercise_name.clone(),
+                    c: log.category,
+                    t: log.start_time.saturating_sub(start),
+                    e: log.end_time?.saturating_sub(start),
+                    w: log.weight_hg.0,
+                    r: log.reps,
+                    m: log.distance_m.map(|d| d.0),
+                    l: log.side,
+                    a: log.assisted,
+                    k: log.set_kind,
+                })
+            })
+            .collect(),
+    };
+    let json = serde_json::to_vec(&shared).unwrap_or_default();
+    URL_SAFE_NO_PAD.encode(json)
+}
+/// Decode a code made by [`encode_session`] into a finished session with
+/// the ID `id`.
+///
+/// # Errors
+/// If `code` is not a valid shared session.
+pub fn decode_session(code: &str, id: String) -> Result<WorkoutSession, ShareLinkError> {
+    let json = URL_SAFE_NO_PAD
+        .decode(code.trim())
+        .map_err(|_| ShareLinkError::Encoding)?;
+    let shared: SharedSession = serde_json::from_slice(&json)?;
+    if shared.v > FORMAT_VERSION {
+        return Err(ShareLinkError::Version(shared.v));
+    }
+    let start = shared.t;
+    Ok(WorkoutSession {
+        id,
+        start_time: start,
+        end_time: Some(start.saturating_add(shared.d)),
+        total_paused_duration: shared.p,
+        exercise_logs: shared
+            .s
+            .into_iter()
+            .map(|set| ExerciseLog {
+                exercise_id: set.i,
+                exercise_name: set.n,
+                category: set.c,
+                start_time: start.saturating_add(set.t),
+                end_time: Some(start.saturating_add(set.e)),
+                weight_hg: Weight(set.w),
+                reps: set.r,
+                distance_m: set.m.map(Distance),
+                force: None,
+                gps_track: Vec::new(),
+                hr_samples: Vec::new(),
+                notes: String::new(),
+                side: set.l,
+                assisted: set.a,
+                body_weight_hg: None,
+                set_kind: set.k,
+                planned_rest_s: None,
+            })
+            .collect(),
+        ..WorkoutSession::default()
+    })
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn log(start_time: u64, end_time: Option<u64>) -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: "squat".into(),
+            exercise_name: "Squat".into(),
+            start_time,
+            end_time,
+            weight_hg: Weight(1_005),
+            reps: Some(5),
+            notes: "felt heavy".into(),
+            side: Some(Side::Left),
+            set_kind: SetKind::WarmUp,
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn round_trips_the_completed_sets_without_private_fields() {
+        let session = WorkoutSession {
+            id: "mine".into(),
+            start_time: 1_700_000_000,
+            end_time: Some(1_700_003_600),
+            total_paused_duration: 120,
+            exercise_logs: vec![
+                log(1_700_000_100, Some(1_700_000_160)),
+                log(1_700_000_300, None),
+            ],
+            notes: "private".into(),
+            location: Some("Home gym".into()),
+            ..WorkoutSession::default()
+        };
+        let code = encode_session(&session);
+        assert!(code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        let decoded = decode_session(&code, "theirs".into()).unwrap();
+        assert_eq!(decoded.id, "theirs");
+        assert_eq!(decoded.end_time, session.end_time);
+        assert_eq!(decoded.duration_seconds(), session.duration_seconds());
+        assert_eq!(
+            decoded.exercise_logs,
+            vec![ExerciseLog {
+                notes: String::new(),
+                ..session.exercise_logs[0].clone()
+            }]
+        );
+        assert!(decoded.notes.is_empty() && decoded.location.is_none());
+    }
+    #[test]
+    fn rejects_garbage_and_future_versions() {
+        assert!(matches!(
+            decode_session("not base64!", String::new()),
+            Err(ShareLinkError::Encoding)
+        ));
+        let future = URL_SAFE_NO_PAD.encode(r#"{"v":9,"t":0,"d":0,"s":[]}"#);
+        assert!(matches!(
+            decode_session(&future, String::new()),
+            Err(ShareLinkError::Version(9))
+        ));
+    }
+}
@@ -0,0 +1,525 @@
+//! `IndexedDB` storage backend for web builds, through the `rexie` crate.
+use super::PackedSession;
+use crate::models::{Exercise, WorkoutSession};
+use rexie::{Direction, Index, KeyRange, ObjectStore, Rexie, TransactionMode};
+use wasm_bindgen::JsValue;
+const DB_NAME: &str = "log_out_db";
+/// `IndexedDB` version of the database, raised with every schema change.
+pub const DB_VERSION: u32 = 5;
+pub const STORE_SESSIONS: &str = "sessions";
+pub const STORE_CUSTOM_EXERCISES: &str = "custom_exercises";
+pub const STORE_EXERCISES: &str = "exercises";
+/// Dedicated object store for binary image data (key: UUID string, value: `Uint8Array`).
+pub const STORE_IMAGES: &str = "images";
+/// Deleted sessions and custom exercises (see [`crate::models::TrashEntry`]).
+pub const STORE_TRASH: &str = "trash";
+/// Index of the sessions on `start_time`, to page through the history.
+const INDEX_START_TIME: &str = "start_time";
+/// Index of the sessions on `end_time`.  IndexedDB leaves out the records
+/// without a key, so it only holds the completed sessions.
+const INDEX_END_TIME: &str = "end_time";
+/// Multi-entry index of the sessions on [`EXERCISE_IDS_FIELD`].
+const INDEX_EXERCISE_IDS: &str = "exercise_ids";
+/// Field added to each stored session (see [`put_session`]), the ids of the
+/// exercises it logs, as IndexedDB cannot index the fields of nested objects.
+const EXERCISE_IDS_FIELD: &str = "exercise_ids";
+/// Field present on the stored [`PackedSession`]s only.
+const PACKED_FIELD: &str = "packed";
+/// Number of sessions read per cursor pass while paging the history.
+const SCAN_BATCH: u32 = 50;
+/// Structured error type for `IndexedDB` operations via the `rexie` crate.
+///
+/// Using a typed enum instead of `String` preserves the underlying cause so
+/// that callers can inspect or display it with full context.
+#[derive(Debug, thiserror::Error)]
+pub enum IdbError {
+    /// A lower-level `rexie` / IndexedDB error.
+    #[error("IndexedDB error: {0}")]
+    Rexie(#[from] rexie::Error),
+    /// A `serde-wasm-bindgen` serialisation or deserialisation error.
+    #[error("Serialization error: {0}")]
+    Serde(#[from] serde_wasm_bindgen::Error),
+    /// A corrupt [`PackedSession`].
+    #[error("Unpacking error: {0}")]
+    Unpack(#[from] std::io::Error),
+}
+/// Open (or create) the IndexedDB database via rexie.
+pub async fn open_db() -> Result<Rexie, rexie::Error> {
+    Rexie::builder(DB_NAME)
+        .version(DB_VERSION)
+        .add_object_store(
+            ObjectStore::new(STORE_SESSIONS)
+                .key_path("id")
+                .add_index(Index::new(INDEX_START_TIME, "start_time").unique(false))
+                .add_index(Index::new(INDEX_END_TIME, "end_time").unique(false))
+                .add_index(
+                    Index::new(INDEX_EXERCISE_IDS, EXERCISE_IDS_FIELD)
+                        .unique(false)
+                        .multi_entry(true),
+                ),
+        )
+        .add_object_store(ObjectStore::new(STORE_CUSTOM_EXERCISES).key_path("id"))
+        .add_object_store(ObjectStore::new(STORE_EXERCISES).key_path("id"))
+        .add_object_store(ObjectStore::new(STORE_IMAGES))
+        .add_object_store(ObjectStore::new(STORE_TRASH).key_path("id"))
+        .build()
+        .await
+}
+/// Put a single serialisable item into a store (upsert by key).
+pub async fn put_item<T: serde::Serialize>(store_name: &str, item: &T) -> Result<(), IdbError> {
+    let db = open_db().await?;
+    let tx = db.transaction(&[store_name], TransactionMode::ReadWrite)?;
+    let store = tx.store(store_name)?;
+    let js_val = serde_wasm_bindgen::to_value(item)?;
+    store.put(&js_val, None).await?;
+    tx.done().await?;
+    Ok(())
+}
+/// Serialise `session` along with its [`EXERCISE_IDS_FIELD`].
+fn session_value(session: &WorkoutSession) -> Result<JsValue, IdbError> {
+    Ok(with_exercise_ids(
+        serde_wasm_bindgen::to_value(session)?,
+        session,
+    ))
+}
+/// Add the [`EXERCISE_IDS_FIELD`] of `session` to its stored `value`.
+fn with_exercise_ids(value: JsValue, session: &WorkoutSession) -> JsValue {
+    let ids = js_sys::Array::new();
+    let mut seen = std::collections::HashSet::new();
+    for log in &session.exercise_logs {
+        if seen.insert(log.exercise_id.as_str()) {
+            ids.push(&JsValue::from_str(&log.exercise_id));
+        }
+    }
+    // Setting a property of a plain object cannot fail.
+    let _ = js_sys::Reflect::set(&value, &JsValue::from_str(EXERCISE_IDS_FIELD), &ids);
+    value
+}
+/// Deserialise a stored `value`, unpacking it first if it is a
+/// [`PackedSession`].
+fn from_value<T: serde::de::DeserializeOwned>(value: JsValue) -> Result<T, IdbError> {
+    if js_sys::Reflect::has(&value, &JsValue::from_str(PACKED_FIELD)).unwrap_or(false) {
+        let packed: PackedSession = serde_wasm_bindgen::from_value(value)?;
+        return Ok(serde_json::from_str(&packed.unpack()?).map_err(std::io::Error::from)?);
+    }
+    Ok(serde_wasm_bindgen::from_value(value)?)
+}
+/// Put a session into the sessions store (upsert by id), indexed by the
+/// exercises it logs.
+pub async fn put_session(session: &WorkoutSession) -> Result<(), IdbError> {
+    let value = session_value(session)?;
+    let db = open_db().await?;
+    let tx = db.transaction(&[STORE_SESSIONS], TransactionMode::ReadWrite)?;
+    tx.store(STORE_SESSIONS)?.put(&value, None).await?;
+    tx.done().await?;
+    Ok(())
+}
+/// Add the [`EXERCISE_IDS_FIELD`] to the sessions stored before it existed,
+/// so that [`get_sessions_with_exercises`] finds them.
+///
+/// Returns the number of updated sessions.
+pub async fn index_sessions() -> Result<usize, IdbError> {
+    let field = JsValue::from_str(EXERCISE_IDS_FIELD);
+    let mut values = Vec::new();
+    for value in get_all_values(STORE_SESSIONS).await? {
+        if js_sys::Reflect::has(&value, &field).unwrap_or(true) {
+            continue;
+        }
+        match from_value::<WorkoutSession>(value) {
+            Ok(session) => values.push(session_value(&session)?),
+            Err(e) => log::warn!("Skipping corrupt IndexedDB session: {e}"),
+        }
+    }
+    if values.is_empty() {
+        return Ok(0);
+    }
+    let db = open_db().await?;
+    let tx = db.transaction(&[STORE_SESSIONS], TransactionMode::ReadWrite)?;
+    let store = tx.store(STORE_SESSIONS)?;
+    let put_futs: Vec<_> = values.iter().map(|v| store.put(v, None)).collect();
+    futures_util::future::try_join_all(put_futs).await?;
+    tx.done().await?;
+    Ok(values.len())
+}
+/// Number of items to serialise per chunk before yielding.
+const PUT_ALL_CHUNK_SIZE: usize = 50;
+/// Serialise `items` in chunks of [`PUT_ALL_CHUNK_SIZE`], yielding to the
+/// browser's macro-task queue between each chunk via a zero-delay
+/// `setTimeout`.  This prevents large datasets (e.g. 800+ exercises) from
+/// synchronously blocking the UI thread during the serialisation phase.
+///
+/// Callers open their transaction only after all serialisation is complete
+/// so the yield points cannot cause it to auto-commit prematurely.
+async fn to_values_chunked<T: serde::Serialize>(items: &[T]) -> Result<Vec<JsValue>, IdbError> {
+    let mut js_values = Vec::with_capacity(items.len());
+    for chunk in items.chunks(PUT_ALL_CHUNK_SIZE) {
+        for item in chunk {
+            js_values.push(serde_wasm_bindgen::to_value(item)?);
+        }
+        gloo_timers::future::TimeoutFuture::new(0).await;
+    }
+    Ok(js_values)
+}
+/// Put many serialisable items into a store in a single transaction.
+/// More efficient than calling [`put_item`] in a loop because only one
+/// database connection and one transaction are opened.
+///
+/// Serialisation yields to the browser between chunks (see
+/// [`to_values_chunked`]).  All individual `put` requests are issued
+/// concurrently within the same transaction via
+/// [`futures_util::future::try_join_all`] so the browser can pipeline them
+/// instead of waiting for each one before issuing the next.
+pub async fn put_all<T: serde::Serialize>(store_name: &str, items: &[T]) -> Result<(), IdbError> {
+    let js_values = to_values_chunked(items).await?;
+    let db = open_db().await?;
+    let tx = db.transaction(&[store_name], TransactionMode::ReadWrite)?;
+    let store = tx.store(store_name)?;
+    let put_futs: Vec<_> = js_values
+        .iter()
+        .map(|js_val| store.put(js_val, None))
+        .collect();
+    futures_util::future::try_join_all(put_futs).await?;
+    tx.done().await?;
+    Ok(())
+}
+/// Replace all the items of a store with `items`, clearing it and putting
+/// them in a single transaction, so that an interrupted write leaves the
+/// previous items in place instead of a partial set.
+pub async fn replace_all<T: serde::Serialize>(
+    store_name: &str,
+    items: &[T],
+) -> Result<(), IdbError> {
+    let js_values = to_values_chunked(items).await?;
+    let db = open_db().await?;
+    let tx = db.transaction(&[store_name], TransactionMode::ReadWrite)?;
+    let store = tx.store(store_name)?;
+    store.clear().await?;
+    let put_futs: Vec<_> = js_values
+        .iter()
+        .map(|js_val| store.put(js_val, None))
+        .collect();
+    futures_util::future::try_join_all(put_futs).await?;
+    tx.done().await?;
+    Ok(())
+}
+/// Put `sessions` and custom `exercises` in a single transaction, e.g. the
+/// writes replayed from the `localStorage` journal.
+pub async fn put_sessions_and_exercises(
+    sessions: &[WorkoutSession],
+    exercises: &[Exercise],
+) -> Result<(), IdbError> {
+    let session_values = sessions
+        .iter()
+        .map(session_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    let exercise_values = to_values_chunked(exercises).await?;
+    let db = open_db().await?;
+    let tx = db.transaction(
+        &[STORE_SESSIONS, STORE_CUSTOM_EXERCISES],
+        TransactionMode::ReadWrite,
+    )?;
+    let session_store = tx.store(STORE_SESSIONS)?;
+    let exercise_store = tx.store(STORE_CUSTOM_EXERCISES)?;
+    let put_futs: Vec<_> = session_values
+        .iter()
+        .map(|v| session_store.put(v, None))
+        .chain(exercise_values.iter().map(|v| exercise_store.put(v, None)))
+        .collect();
+    futures_util::future::try_join_all(put_futs).await?;
+    tx.done().await?;
+    Ok(())
+}
+/// Delete an item from a store by its key.
+pub async fn delete_item(store_name: &str, key: &str) -> Result<(), IdbError> {
+    let db = open_db().await?;
+    let tx = db.transaction(&[store_name], TransactionMode::ReadWrite)?;
+    let store = tx.store(store_name)?;
+    store.delete(JsValue::from_str(key)).await?;
+    tx.done().await?;
+    Ok(())
+}
+/// Delete the items with `keys` from a store, skipping the trash, in a
+/// single transaction.
+pub async fn delete_items(store_name: &str, keys: &[String]) -> Result<(), IdbError> {
+    let db = open_db().await?;
+    let tx = db.transaction(&[store_name], TransactionMode::ReadWrite)?;
+    let store = tx.store(store_name)?;
+    let delete_futs: Vec<_> = keys
+        .iter()
+        .map(|key| store.delete(JsValue::from_str(key)))
+        .collect();
+    futures_util::future::try_join_all(delete_futs).await?;
+    tx.done().await?;
+    Ok(())
+}
+/// Remove all items from a store.
+pub async fn clear_all(store_name: &str) -> Result<(), IdbError> {
+    let db = open_db().await?;
+    let tx = db.transaction(&[store_name], TransactionMode::ReadWrite)?;
+    let store = tx.store(store_name)?;
+    store.clear().await?;
+    tx.done().await?;
+    Ok(())
+}
+/// Deserialise `js_values`, skipping (and logging) the corrupt ones.
+fn from_values<T: serde::de::DeserializeOwned>(js_values: Vec<JsValue>) -> Vec<T> {
+    let mut items = Vec::new();
+    for (i, js_val) in js_values.into_iter().enumerate() {
+        match from_value::<T>(js_val) {
+            Ok(item) => items.push(item),
+            Err(e) => {
+                log::warn!("Skipping corrupt IndexedDB entry at index {i}: {e}")
+            }
+        }
+    }
+    items
+}
+/// Load the raw values of all items from a store.
+async fn get_all_values(store_name: &str) -> Result<Vec<JsValue>, IdbError> {
+    let db = open_db().await?;
+    let tx = db.transaction(&[store_name], TransactionMode::ReadOnly)?;
+    let store = tx.store(store_name)?;
+    Ok(store.get_all(None, None).await?)
+}
+/// Load all items from a store.
+pub async fn get_all<T: serde::de::DeserializeOwned>(store_name: &str) -> Result<Vec<T>, IdbError> {
+    Ok(from_values(get_all_values(store_name).await?))
+}
+/// Number of items in a store, without reading them.
+pub async fn count(store_name: &str) -> Result<usize, IdbError> {
+    let db = open_db().await?;
+    let tx = db.transaction(&[store_name], TransactionMode::ReadOnly)?;
+    let count = tx.store(store_name)?.count(None).await?;
+    Ok(count as usize)
+}
+/// Load the active sessions, i.e. those missing from the `end_time` index,
+/// reading only the keys of the completed ones.
+pub async fn get_active_sessions() -> Result<Vec<WorkoutSession>, IdbError> {
+    let db = open_db().await?;
+    let tx = db.transaction(&[STORE_SESSIONS], TransactionMode::ReadOnly)?;
+    let store = tx.store(STORE_SESSIONS)?;
+    let completed: std::collections::HashSet<String> = store
+        .index(INDEX_END_TIME)?
+        .get_all_keys(None, None)
+        .await?
+        .iter()
+        .filter_map(JsValue::as_string)
+        .collect();
+    let mut values = Vec::new();
+    for key in store.get_all_keys(None, None).await? {
+        if key.as_string().is_some_and(|k| completed.contains(&k)) {
+            continue;
+        }
+        if let Some(value) = store.get(key).await? {
+            values.push(value);
+        }
+    }
+    Ok(from_values(values))
+}
+/// Load `limit` completed sessions, most recent first, after skipping the
+/// `offset` most recent ones, archived ones included only if
+/// `include_archived`.
+///
+/// Sessions are walked with a cursor on the `start_time` index and only the
+/// returned ones are deserialised, the others being told apart by the raw
+/// `end_time` and `archived` fields.
+pub async fn get_completed_sessions_page(
+    limit: usize,
+    offset: usize,
+    include_archived: bool,
+) -> Result<Vec<WorkoutSession>, IdbError> {
+    let end_time = JsValue::from_str("end_time");
+    let archived = JsValue::from_str("archived");
+    let db = open_db().await?;
+    let tx = db.transaction(&[STORE_SESSIONS], TransactionMode::ReadOnly)?;
+    let index = tx.store(STORE_SESSIONS)?.index(INDEX_START_TIME)?;
+    let (mut scanned, mut skipped) = (0, 0);
+    let mut values = Vec::new();
+    while values.len() < limit {
+        let batch = index
+            .scan(
+                None,
+                Some(SCAN_BATCH),
+                // A cursor cannot advance by zero.
+                (scanned > 0).then_some(scanned),
+                Some(Direction::Prev),
+            )
+            .await?;
+        scanned += batch.len() as u32;
+        let exhausted = batch.len() < SCAN_BATCH as usize;
+        for (_, value) in batch {
+            let completed = js_sys::Reflect::get(&value, &end_time)
+                .is_ok_and(|v| !v.is_undefined() && !v.is_null());
+            let hidden = !include_archived
+                && js_sys::Reflect::get(&value, &archived).is_ok_and(|v| v.is_truthy());
+            if !completed || hidden {
+                continue;
+            }
+            if skipped < offset {
+                skipped += 1;
+            } else if values.len() < limit {
+                values.push(value);
+            }
+        }
+        if exhausted {
+            break;
+        }
+    }
+    Ok(from_values(values))
+}
+/// Load the sessions logging any of `exercise_ids`, through the
+/// [`INDEX_EXERCISE_IDS`] index.
+pub async fn get_sessions_with_exercises(
+    exercise_ids: &[String],
+) -> Result<Vec<WorkoutSession>, IdbError> {
+    let db = open_db().await?;
+    let tx = db.transaction(&[STORE_SESSIONS], TransactionMode::ReadOnly)?;
+    let index = tx.store(STORE_SESSIONS)?.index(INDEX_EXERCISE_IDS)?;
+    let mut values = Vec::new();
+    for id in exercise_ids {
+        let range = KeyRange::only(&JsValue::from_str(id)).map_err(rexie::Error::from)?;
+        values.extend(index.get_all(Some(range), None).await?);
+    }
+    let mut seen = std::collections::HashSet::new();
+    Ok(from_values::<WorkoutSession>(values)
+        .into_iter()
+        .filter(|s| seen.insert(s.id.clone()))
+        .collect())
+}
+/// Pack the sessions completed before `cutoff` (Unix seconds) that are
+/// still stored plain (see [`PackedSession`]), in a single transaction.
+///
+/// Returns the number of packed sessions.
+pub async fn pack_sessions_ended_before(cutoff: u64) -> Result<usize, IdbError> {
+    let field = JsValue::from_str(PACKED_FIELD);
+    // Unix timestamps are far below 2^53, exactly representable as `f64`.
+    #[allow(clippy::cast_precision_loss)]
+    let cutoff = JsValue::from_f64(cutoff as f64);
+    let range = KeyRange::upper_bound(&cutoff, Some(true)).map_err(rexie::Error::from)?;
+    let db = open_db().await?;
+    let tx = db.transaction(&[STORE_SESSIONS], TransactionMode::ReadWrite)?;
+    let store = tx.store(STORE_SESSIONS)?;
+    let ended = store
+        .index(INDEX_END_TIME)?
+        .get_all(Some(range), None)
+        .await?;
+    let mut values = Vec::new();
+    for value in ended {
+        if js_sys::Reflect::has(&value, &field).unwrap_or(true) {
+            continue;
+        }
+        match serde_wasm_bindgen::from_value::<WorkoutSession>(value) {
+            Ok(session) => {
+                let packed = PackedSession::pack(&session).map_err(std::io::Error::from)?;
+                values.push(with_exercise_ids(
+                    serde_wasm_bindgen::to_value(&packed)?,
+                    &session,
+                ));
+            }
+            Err(e) => log::warn!("Not packing corrupt IndexedDB session: {e}"),
+        }
+    }
+    let put_futs: Vec<_> = values.iter().map(|v| store.put(v, None)).collect();
+    futures_util::future::try_join_all(put_futs).await?;
+    tx.done().await?;
+    Ok(values.len())
+}
+/// Approximate bytes used by the items of a store: the length of binary
+/// values (images) and of the JSON text of the others.
+pub async fn store_bytes(store_name: &str) -> Result<u64, IdbError> {
+    use wasm_bindgen::JsCast;
+    let db = open_db().await?;
+    let tx = db.transaction(&[store_name], TransactionMode::ReadOnly)?;
+    let store = tx.store(store_name)?;
+    let js_values = store.get_all(None, None).await?;
+    Ok(js_values
+        .iter()
+        .map(|v| match v.dyn_ref::<js_sys::Uint8Array>() {
+            Some(bytes) => u64::from(bytes.byte_length()),
+            None => js_sys::JSON::stringify(v)
+                .map(|json| u64::from(json.length()))
+                .unwrap_or(0),
+        })
+        .sum())
+}
+/// Move the item with `key` from `store_name` (sessions or custom
+/// exercises) to the trash, in a single transaction (no-op if absent).
+///
+/// Items not worth keeping (see
+/// [`crate::models::TrashedItem::is_worth_keeping`]) are only deleted.
+pub async fn move_to_trash(store_name: &str, key: &str, deleted_at: u64) -> Result<(), IdbError> {
+    use crate::models::{TrashEntry, TrashedItem};
+    let db = open_db().await?;
+    let tx = db.transaction(&[store_name, STORE_TRASH], TransactionMode::ReadWrite)?;
+    let store = tx.store(store_name)?;
+    let key = JsValue::from_str(key);
+    if let Some(value) = store.get(key.clone()).await? {
+        let item = if store_name == STORE_SESSIONS {
+            TrashedItem::Session(from_value(value)?)
+        } else {
+            TrashedItem::Exercise(serde_wasm_bindgen::from_value(value)?)
+        };
+        if item.is_worth_keeping() {
+            let entry = serde_wasm_bindgen::to_value(&TrashEntry::new(item, deleted_at))?;
+            tx.store(STORE_TRASH)?.put(&entry, None).await?;
+        }
+        store.delete(key).await?;
+    }
+    tx.done().await?;
+    Ok(())
+}
+/// Put the item of trash entry `entry_id` back into its original store
+/// and remove the entry, in a single transaction.
+///
+/// Returns the restored item, or `None` if the entry no longer exists.
+pub async fn restore_from_trash(
+    entry_id: &str,
+) -> Result<Option<crate::models::TrashedItem>, IdbError> {
+    use crate::models::{TrashEntry, TrashedItem};
+    let db = open_db().await?;
+    let tx = db.transaction(
+        &[STORE_SESSIONS, STORE_CUSTOM_EXERCISES, STORE_TRASH],
+        TransactionMode::ReadWrite,
+    )?;
+    let trash = tx.store(STORE_TRASH)?;
+    let key = JsValue::from_str(entry_id);
+    let Some(value) = trash.get(key.clone()).await? else {
+        return Ok(None);
+    };
+    let entry: TrashEntry = serde_wasm_bindgen::from_value(value)?;
+    match &entry.item {
+        TrashedItem::Session(s) => {
+            let value = session_value(s)?;
+            tx.store(STORE_SESSIONS)?.put(&value, None).await?;
+        }
+        TrashedItem::Exercise(e) => {
+            let value = serde_wasm_bindgen::to_value(e)?;
+            tx.store(STORE_CUSTOM_EXERCISES)?.put(&value, None).await?;
+        }
+    }
+    trash.delete(key).await?;
+    tx.done().await?;
+    Ok(Some(entry.item))
+}
+/// Delete the trash entries deleted before `cutoff` (Unix seconds).
+///
+/// Returns the number of purged entries.
+pub async fn purge_trash(cutoff: u64) -> Result<usize, IdbError> {
+    let expired: Vec<String> = get_all::<crate::models::TrashEntry>(STORE_TRASH)
+        .await?
+        .into_iter()
+        .filter(|e| e.deleted_at < cutoff)
+        .map(|e| e.id)
+        .collect();
+    if expired.is_empty() {
+        return Ok(0);
+    }
+    let db = open_db().await?;
+    let tx = db.transaction(&[STORE_TRASH], TransactionMode::ReadWrite)?;
+    let store = tx.store(STORE_TRASH)?;
+    for id in &expired {
+        store.delete(JsValue::from_str(id)).await?;
+    }
+    tx.done().await?;
+    Ok(expired.len())
+}
@@ -0,0 +1,124 @@
+//! Storage backends shared by the app and its companions.
+//!
+//! - **Web** (`wasm32`): [`idb`], `IndexedDB` via the `rexie` crate.
+//! - **Native** (Android / desktop): [`sqlite`], `SQLite` via `rusqlite`
+//!   stored in the OS app-data directory.
+//!
+//! Both expose the same stores (sessions, custom exercises, exercise
+//! database, trash) holding the JSON of the [`crate::models`] types, old
+//! sessions being compressed (see [`PackedSession`]).
+#[cfg(target_arch = "wasm32")]
+pub mod idb;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod sqlite;
+/// Aggregated per-exercise personal-record values, computed from the
+/// completed sessions of a storage backend.
+///
+/// Raw numeric types match the storage representation so callers can build
+/// their own personal-record values without an extra conversion step.
+pub struct BestsRow {
+    /// The exercise this row describes.
+    pub exercise_id: String,
+    /// Maximum `weight_hg` (hectograms) across all completed logs, assisted
+    /// sets excluded.
+    pub max_weight_hg: Option<u16>,
+    /// Maximum repetition count across all completed logs.
+    pub max_reps: Option<u32>,
+    /// Maximum `distance_m` (metres) across all completed logs.
+    pub max_distance_m: Option<u32>,
+    /// Maximum set duration (seconds) across all completed logs.
+    pub max_duration_s: Option<u64>,
+    /// `weight_hg` from the most-recently completed log (for input prefilling).
+    pub last_weight_hg: Option<u16>,
+    /// Repetition count from the most-recently completed log.
+    pub last_reps: Option<u32>,
+    /// `distance_m` from the most-recently completed log.
+    pub last_distance_m: Option<u32>,
+    /// Whether the most-recently completed log was an assisted set.
+    pub last_assisted: bool,
+    /// `end_time` of the most-recently completed log (used to merge entries).
+    pub last_log_end_time: Option<u64>,
+}
+/// Completed sessions are packed once they ended this long ago (30 days),
+/// history being rarely edited by then.
+pub const PACK_AFTER_SECS: u64 = 30 * 24 * 60 * 60;
+/// Start of the stored JSON of a [`PackedSession`], telling it apart from the
+/// JSON of a plain session without parsing it.
+pub const PACKED_PREFIX: &str = "{\"packed\":";
+/// Stored form of an old completed session: its JSON gzip-compressed in
+/// base64, next to the plain fields that the indexes and queries of both
+/// backends read.  Reads unpack it transparently, and writing the session
+/// again stores it plain until it gets old again.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct PackedSession {
+    /// Kept first so that the JSON starts with [`PACKED_PREFIX`].
+    pub packed: String,
+    /// The id of the session, the key of both stores.
+    pub id: String,
+    /// Read by the history order.
+    pub start_time: u64,
+    /// Read by the active and completed session queries.
+    pub end_time: Option<u64>,
+    /// Read by the history filter.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub archived: bool,
+}
+impl PackedSession {
+    /// Pack `session`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`serde_json::Error`] if the session cannot be serialised.
+    pub fn pack(session: &crate::models::WorkoutSession) -> Result<Self, serde_json::Error> {
+        let json = serde_json::to_string(session)?;
+        Ok(Self {
+            packed: crate::crypto::to_base64(&crate::compress::gzip(json.as_bytes())),
+            id: session.id.clone(),
+            start_time: session.start_time,
+            end_time: session.end_time,
+            archived: session.archived,
+        })
+    }
+    /// The JSON of the packed session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`std::io::Error`] when the packed data is corrupt.
+    pub fn unpack(&self) -> std::io::Result<String> {
+        let bytes = crate::crypto::from_base64(&self.packed).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid base64")
+        })?;
+        crate::compress::gunzip_str(&bytes)
+    }
+}
+/// The plain JSON of a stored item: `data` itself, unless it is a
+/// [`PackedSession`], which is then unpacked.
+///
+/// # Errors
+///
+/// Returns an [`std::io::Error`] when a packed session is corrupt.
+pub fn unpacked_json(data: String) -> std::io::Result<String> {
+    if !data.starts_with(PACKED_PREFIX) {
+        return Ok(data);
+    }
+    serde_json::from_str::<PackedSession>(&data)
+        .map_err(std::io::Error::from)?
+        .unpack()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::WorkoutSession;
+    #[test]
+    fn packed_sessions_unpack_to_their_json() {
+        let mut session = WorkoutSession::new();
+        session.end_time = Some(session.start_time + 3_600);
+        session.notes = "Leg day ".repeat(50);
+        let json = serde_json::to_string(&session).unwrap();
+        let packed = serde_json::to_string(&PackedSession::pack(&session).unwrap()).unwrap();
+        assert!(packed.starts_with(PACKED_PREFIX));
+        assert!(packed.len() < json.len());
+        assert_eq!(unpacked_json(packed).unwrap(), json);
+        assert_eq!(unpacked_json(json.clone()).unwrap(), json);
+    }
+}
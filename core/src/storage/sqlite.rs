@@ -0,0 +1,1184 @@
+//! `SQLite`-backed storage for Android and desktop builds.
+//!
+//! A single `log-out.db` `SQLite` database file is kept inside the app-
+//! specific data directory.  On Android this is `Context.getFilesDir()`
+//! (queried via `ndk-context` at runtime); on other platforms it is
+//! `dirs::data_local_dir()/log-out/`.
+//! Each "store" maps to a table with columns `id TEXT PRIMARY KEY, data TEXT`.
+//! A separate `config` table holds arbitrary key/value string pairs, and the
+//! `exercise_fts` FTS5 table indexes the exercises for full-text search (see
+//! [`search_exercise_ids`]).
+//!
+//! On first launch, the database is initialized with the current schema.
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::PathBuf;
+/// On Android, ask the OS for the app's internal files directory via JNI.
+///
+/// Uses `ndk_context::android_context()` (which Dioxus already sets up)
+/// to obtain the `JavaVM` and `Activity` pointers without requiring any
+/// custom `MainActivity.kt`.  Calls `Activity.getFilesDir()` and then
+/// `File.getAbsolutePath()` to get a `String` path back.
+///
+/// Returns `None` on any JNI error; `data_dir()` will then fall back to
+/// `dirs::data_local_dir()`.
+#[cfg(target_os = "android")]
+fn android_files_dir() -> Option<PathBuf> {
+    use jni::{objects::JObject, JavaVM};
+    let ctx = ndk_context::android_context();
+    if ctx.vm().is_null() {
+        log::error!("android_files_dir: JavaVM pointer is NULL! ndk-context not initialized?");
+        return None;
+    }
+    if ctx.context().is_null() {
+        log::error!("android_files_dir: Context pointer is NULL! ndk-context not initialized?");
+        return None;
+    }
+    // SAFETY: pointers are valid for the lifetime of the process and were
+    // set up by the Dioxus / Android runtime before Rust code runs.
+    let vm = match unsafe { JavaVM::from_raw(ctx.vm().cast()) } {
+        Ok(vm) => vm,
+        Err(e) => {
+            log::error!("android_files_dir: JavaVM::from_raw failed: {e:?}");
+            return None;
+        }
+    };
+    let mut env = match vm.attach_current_thread() {
+        Ok(env) => env,
+        Err(e) => {
+            log::error!("android_files_dir: attach_current_thread failed: {e:?}");
+            return None;
+        }
+    };
+    let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
+    let files_dir = match env
+        .call_method(&activity, "getFilesDir", "()Ljava/io/File;", &[])
+        .and_then(jni::objects::JValueGen::l)
+    {
+        Ok(obj) => obj,
+        Err(e) => {
+            log::error!("android_files_dir: getFilesDir() failed: {e:?}");
+            return None;
+        }
+    };
+    let path_jobj = match env
+        .call_method(&files_dir, "getAbsolutePath", "()Ljava/lang/String;", &[])
+        .and_then(jni::objects::JValueGen::l)
+    {
+        Ok(obj) => obj,
+        Err(e) => {
+            log::error!("android_files_dir: getAbsolutePath() failed: {e:?}");
+            return None;
+        }
+    };
+    let path_str: jni::objects::JString = path_jobj.into();
+    let result = match env.get_string(&path_str) {
+        Ok(s) => {
+            let p = PathBuf::from(String::from(s));
+            log::info!("android_files_dir: Success! Path: {}", p.display());
+            Some(p)
+        }
+        Err(e) => {
+            log::error!("android_files_dir: get_string failed: {e:?}");
+            None
+        }
+    };
+    result
+}
+/// Returns the app's external files directory via JNI (`getExternalFilesDir(null)`).
+///
+/// On Android, this resolves to a path like
+/// `/storage/emulated/0/Android/data/<package>/files/`.  The directory is
+/// readable by the user via a file manager without any special permissions.
+/// Returns `None` on any JNI error.
+///
+/// SAFETY: the `JavaVM` pointer is process-lifetime, set up by the Android /
+/// Dioxus runtime before Rust code runs.  `JavaVM::from_raw` wraps the raw
+/// pointer without taking ownership; the JVM is not destroyed when `vm` is
+/// dropped because `jni::JavaVM::drop` is a no-op for attached VMs.
+#[cfg(target_os = "android")]
+pub fn android_external_files_dir() -> Option<std::path::PathBuf> {
+    use jni::{objects::JObject, JavaVM};
+    let ctx = ndk_context::android_context();
+    if ctx.vm().is_null() || ctx.context().is_null() {
+        log::error!("android_external_files_dir: Context or VM is NULL!");
+        return None;
+    }
+    let vm = match unsafe { JavaVM::from_raw(ctx.vm().cast()) } {
+        Ok(vm) => vm,
+        Err(e) => {
+            log::error!("android_external_files_dir: JavaVM::from_raw: {e:?}");
+            return None;
+        }
+    };
+    let mut env = match vm.attach_current_thread() {
+        Ok(env) => env,
+        Err(e) => {
+            log::error!("android_external_files_dir: attach_current_thread: {e:?}");
+            return None;
+        }
+    };
+    let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
+    let null_obj = JObject::null();
+    let files_dir = match env
+        .call_method(
+            &activity,
+            "getExternalFilesDir",
+            "(Ljava/lang/String;)Ljava/io/File;",
+            &[jni::objects::JValue::Object(&null_obj)],
+        )
+        .and_then(jni::objects::JValueGen::l)
+    {
+        Ok(obj) => obj,
+        Err(e) => {
+            log::error!("android_external_files_dir: getExternalFilesDir: {e:?}");
+            return None;
+        }
+    };
+    if files_dir.is_null() {
+        log::warn!("android_external_files_dir: getExternalFilesDir returned null (external storage not mounted?)");
+        return None;
+    }
+    let path_jobj = match env
+        .call_method(&files_dir, "getAbsolutePath", "()Ljava/lang/String;", &[])
+        .and_then(jni::objects::JValueGen::l)
+    {
+        Ok(obj) => obj,
+        Err(e) => {
+            log::error!("android_external_files_dir: getAbsolutePath: {e:?}");
+            return None;
+        }
+    };
+    let path_str: jni::objects::JString = path_jobj.into();
+    // Bind to a local so the `JavaStr` temporary is dropped before `vm`
+    // and `path_str` go out of scope (avoids E0597).
+    let result = match env.get_string(&path_str) {
+        Ok(s) => {
+            let p = std::path::PathBuf::from(String::from(s));
+            log::info!("android_external_files_dir: {}", p.display());
+            Some(p)
+        }
+        Err(e) => {
+            log::error!("android_external_files_dir: get_string: {e:?}");
+            None
+        }
+    };
+    result
+}
+
+/// Saves a text file to the global Android Downloads folder using MediaStore.
+///
+/// On Android 10+ (API 29) this is the preferred way to write to public
+/// directories without requiring the broad `WRITE_EXTERNAL_STORAGE`
+/// permission.  The file is inserted into the `MediaStore.Downloads`
+/// collection.
+#[cfg(target_os = "android")]
+pub fn android_save_to_downloads(
+    filename: &str,
+    content: &str,
+    mime: &str,
+) -> Result<String, String> {
+    use jni::{objects::JObject, JavaVM};
+    let ctx = ndk_context::android_context();
+    if ctx.vm().is_null() || ctx.context().is_null() {
+        return Err("Android context not available".into());
+    }
+    let vm = unsafe { JavaVM::from_raw(ctx.vm().cast()) }
+        .map_err(|e| format!("JavaVM::from_raw: {e}"))?;
+    let mut env = vm
+        .attach_current_thread()
+        .map_err(|e| format!("attach_current_thread: {e}"))?;
+    let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
+
+    // ContentValues values = new ContentValues();
+    let values = env
+        .new_object("android/content/ContentValues", "()V", &[])
+        .map_err(|e| format!("new ContentValues: {e}"))?;
+
+    let jfilename = env
+        .new_string(filename)
+        .map_err(|e| format!("new_string filename: {e}"))?;
+    let jmime = env
+        .new_string(mime)
+        .map_err(|e| format!("new_string mime: {e}"))?;
+    let jrel_path = env
+        .new_string("Download/")
+        .map_err(|e| format!("new_string rel_path: {e}"))?;
+
+    let jdisplay_name_key = env
+        .new_string("_display_name")
+        .map_err(|e| format!("new_string _display_name: {e}"))?;
+    let jmime_type_key = env
+        .new_string("mime_type")
+        .map_err(|e| format!("new_string mime_type: {e}"))?;
+    let jrelative_path_key = env
+        .new_string("relative_path")
+        .map_err(|e| format!("new_string relative_path: {e}"))?;
+
+    // values.put("_display_name", filename);
+    env.call_method(
+        &values,
+        "put",
+        "(Ljava/lang/String;Ljava/lang/String;)V",
+        &[
+            jni::objects::JValue::from(&jdisplay_name_key),
+            jni::objects::JValue::from(&jfilename),
+        ],
+    )
+    .map_err(|e| format!("ContentValues.put name: {e}"))?;
+
+    // values.put("mime_type", "application/json");
+    env.call_method(
+        &values,
+        "put",
+        "(Ljava/lang/String;Ljava/lang/String;)V",
+        &[
+            jni::objects::JValue::from(&jmime_type_key),
+            jni::objects::JValue::from(&jmime),
+        ],
+    )
+    .map_err(|e| format!("ContentValues.put mime: {e}"))?;
+
+    // values.put("relative_path", "Download/");
+    env.call_method(
+        &values,
+        "put",
+        "(Ljava/lang/String;Ljava/lang/String;)V",
+        &[
+            jni::objects::JValue::from(&jrelative_path_key),
+            jni::objects::JValue::from(&jrel_path),
+        ],
+    )
+    .map_err(|e| format!("ContentValues.put path: {e}"))?;
+
+    // ContentResolver resolver = context.getContentResolver();
+    let resolver = env
+        .call_method(
+            &activity,
+            "getContentResolver",
+            "()Landroid/content/ContentResolver;",
+            &[],
+        )
+        .map_err(|e| format!("getContentResolver: {e}"))?
+        .l()
+        .map_err(|e| format!("ContentResolver obj: {e}"))?;
+
+    // Uri uri = MediaStore.Downloads.getContentUri("external");
+    let jexternal = env
+        .new_string("external")
+        .map_err(|e| format!("new_string external: {e}"))?;
+    let external_uri = env
+        .call_static_method(
+            "android/provider/MediaStore$Downloads",
+            "getContentUri",
+            "(Ljava/lang/String;)Landroid/net/Uri;",
+            &[jni::objects::JValue::from(&jexternal)],
+        )
+        .map_err(|e| format!("MediaStore.Downloads.getContentUri: {e}"))?
+        .l()
+        .map_err(|e| format!("Uri obj: {e}"))?;
+
+    // Uri fileUri = resolver.insert(external_uri, values);
+    let file_uri = env
+        .call_method(
+            &resolver,
+            "insert",
+            "(Landroid/net/Uri;Landroid/content/ContentValues;)Landroid/net/Uri;",
+            &[
+                jni::objects::JValue::from(&external_uri),
+                jni::objects::JValue::from(&values),
+            ],
+        )
+        .map_err(|e| format!("resolver.insert: {e}"))?
+        .l()
+        .map_err(|e| format!("fileUri obj: {e}"))?;
+
+    if file_uri.is_null() {
+        return Err("MediaStore insert returned null (duplicate filename?)".into());
+    }
+
+    // OutputStream os = resolver.openOutputStream(fileUri, "w");
+    let jwrite_mode = env
+        .new_string("w")
+        .map_err(|e| format!("new_string w: {e}"))?;
+    let os = env
+        .call_method(
+            &resolver,
+            "openOutputStream",
+            "(Landroid/net/Uri;Ljava/lang/String;)Ljava/io/OutputStream;",
+            &[
+                jni::objects::JValue::from(&file_uri),
+                jni::objects::JValue::from(&jwrite_mode),
+            ],
+        )
+        .map_err(|e| format!("openOutputStream: {e}"))?
+        .l()
+        .map_err(|e| format!("OutputStream obj: {e}"))?;
+
+    // os.write(content.getBytes());
+    let jcontent_bytes = env
+        .byte_array_from_slice(content.as_bytes())
+        .map_err(|e| format!("byte_array_from_slice: {e}"))?;
+    let content_bytes_obj = jni::objects::JObject::from(jcontent_bytes);
+    env.call_method(
+        &os,
+        "write",
+        "([B)V",
+        &[jni::objects::JValue::from(&content_bytes_obj)],
+    )
+    .map_err(|e| format!("OutputStream.write: {e}"))?;
+
+    // os.close();
+    env.call_method(&os, "close", "()V", &[])
+        .map_err(|e| format!("OutputStream.close: {e}"))?;
+
+    Ok(format!("Download/{filename}"))
+}
+pub const STORE_SESSIONS: &str = "sessions";
+pub const STORE_CUSTOM_EXERCISES: &str = "custom_exercises";
+pub const STORE_EXERCISES: &str = "exercises";
+/// Deleted sessions and custom exercises (see [`crate::models::TrashEntry`]).
+pub const STORE_TRASH: &str = "trash";
+/// Name of the application data sub-directory under the OS data dir.
+#[cfg(not(any(test, feature = "test-support")))]
+const APP_DATA_DIR_NAME: &str = "log-out";
+/// File name of the `SQLite` database within the application data directory.
+pub const DB_FILENAME: &str = "log-out.db";
+/// `SQLite` `user_version` value written on a successful schema migration.
+pub const SCHEMA_VERSION: u32 = 4;
+/// Schema version that added the trash.
+const TRASH_SCHEMA_VERSION: u32 = 3;
+/// Databases below this version are wiped and recreated from scratch;
+/// later versions are migrated in place.
+const BASE_SCHEMA_VERSION: u32 = 2;
+/// Structured error type for native (`SQLite`) storage operations.
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    /// Unknown store name — indicates a programming error.
+    #[error("Unknown store: {0}")]
+    UnknownStore(String),
+    /// Database-level error from `rusqlite`.
+    #[error("Database error: {0}")]
+    Database(#[from] rusqlite::Error),
+    /// JSON serialisation / deserialisation error.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    /// OS-level I/O error (directory creation, file access, etc.).
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+/// Returns a static SQL table name for a known store, or
+/// `Err(StorageError::UnknownStore)` for an unrecognised name.
+///
+/// Using this function for all table-name resolution ensures that no
+/// dynamic string can ever reach a SQL statement, eliminating table-name
+/// injection as a risk regardless of call order.
+///
+/// The return type is `&'static str`, which means the value is always one
+/// of a fixed set of compile-time string literals — never arbitrary
+/// user-controlled input.  Interpolating this value into a SQL string is
+/// therefore safe, equivalent in risk to writing the table name directly.
+fn store_table(store_name: &str) -> Result<&'static str, StorageError> {
+    match store_name {
+        STORE_SESSIONS => Ok("sessions"),
+        STORE_CUSTOM_EXERCISES => Ok("custom_exercises"),
+        STORE_EXERCISES => Ok("exercises"),
+        STORE_TRASH => Ok("trash"),
+        other => Err(StorageError::UnknownStore(other.to_string())),
+    }
+}
+/// Returns the application data directory, creating it if necessary.
+///
+/// The result is computed once and cached in a process-wide `OnceLock` so
+/// that all call sites — including the `imgcache://` custom-protocol handler
+/// running on a `WebView` thread — always see the same path regardless of
+/// whether the JNI call succeeds on every thread.
+pub fn data_dir() -> PathBuf {
+    // In test builds each nextest process gets its own isolated directory
+    // so concurrent test runs never share the same SQLite file.
+    #[cfg(any(test, feature = "test-support"))]
+    return test_data_dir();
+    #[cfg(not(any(test, feature = "test-support")))]
+    {
+        static DATA_DIR: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
+        DATA_DIR
+            .get_or_init(|| {
+                let dir = if let Ok(custom) = std::env::var("LOGOUT_DATA_DIR") {
+                    PathBuf::from(custom)
+                } else {
+                    #[cfg(target_os = "android")]
+                    if let Some(dir) = android_files_dir() {
+                        dir
+                    } else {
+                        dirs::data_local_dir()
+                            .unwrap_or_else(|| PathBuf::from("."))
+                            .join(APP_DATA_DIR_NAME)
+                    }
+                    #[cfg(not(target_os = "android"))]
+                    dirs::data_local_dir()
+                        .unwrap_or_else(|| PathBuf::from("."))
+                        .join(APP_DATA_DIR_NAME)
+                };
+                log::info!("Resolved data_dir: {}", dir.display());
+                dir
+            })
+            .clone()
+    }
+}
+/// Returns a per-process temporary directory for test isolation.
+///
+/// Each nextest invocation runs in its own process, so using the process
+/// ID as a unique suffix guarantees that concurrent tests never share the
+/// same `SQLite` database file.
+#[cfg(any(test, feature = "test-support"))]
+fn test_data_dir() -> PathBuf {
+    static DIR: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
+    DIR.get_or_init(|| {
+        let dir = std::env::temp_dir().join(format!("logout-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("failed to create per-process test data directory");
+        dir
+    })
+    .clone()
+}
+fn db_path() -> PathBuf {
+    data_dir().join(DB_FILENAME)
+}
+/// Returns the directory used for storing cached exercise images.
+///
+/// On Android, this prefers the external files directory so that the images
+/// are visible to the user and can be backed up or managed by the system
+/// gallery.  Falls back to the internal data directory if external storage
+/// is unavailable.
+pub fn images_dir() -> PathBuf {
+    static IMAGES_DIR: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
+    IMAGES_DIR
+        .get_or_init(|| {
+            let dir = {
+                #[cfg(target_os = "android")]
+                if let Some(dir) = android_external_files_dir() {
+                    dir.join("images")
+                } else {
+                    data_dir().join("images")
+                }
+                #[cfg(not(target_os = "android"))]
+                data_dir().join("images")
+            };
+            log::info!("Resolved images_dir: {}", dir.display());
+            dir
+        })
+        .clone()
+}
+/// Runs incremental schema migrations to bring the database up to the current version.
+///
+/// Any schema version below [`BASE_SCHEMA_VERSION`] (including a blank database)
+/// causes all tables to be dropped and recreated fresh.  Data preservation is not
+/// attempted — the app has no established user base yet.  Later changes only add
+/// tables, keeping existing data.
+///
+/// Separated from [`open_db`] so it can be called in tests after a manual schema
+/// reset without needing to re-create the long-lived connection.
+fn apply_migration_if_needed(conn: &Connection) -> Result<(), StorageError> {
+    let schema_version: u32 = conn.query_row("PRAGMA user_version", [], |r| r.get(0))?;
+    if schema_version < BASE_SCHEMA_VERSION {
+        // Fresh install or outdated schema: drop everything and start clean.
+        // BASE_SCHEMA_VERSION must match the `PRAGMA user_version` value at the end.
+        conn.execute_batch(
+            "DROP TABLE IF EXISTS sessions;
+             DROP TABLE IF EXISTS exercise_fts;
+             DROP TABLE IF EXISTS custom_exercises;
+             DROP TABLE IF EXISTS exercises;
+             DROP TABLE IF EXISTS config;
+             DROP TABLE IF EXISTS trash;
+             CREATE TABLE sessions (
+                 id          TEXT    PRIMARY KEY,
+                 data        TEXT    NOT NULL,
+                 start_time  INTEGER GENERATED ALWAYS AS (
+                                 CASE WHEN json_valid(data)
+                                      THEN CAST(json_extract(data, '$.start_time') AS INTEGER)
+                                      ELSE NULL END
+                             ) STORED,
+                 end_time    INTEGER GENERATED ALWAYS AS (
+                                 CASE WHEN json_valid(data)
+                                      THEN CAST(json_extract(data, '$.end_time') AS INTEGER)
+                                      ELSE NULL END
+                             ) STORED
+             );
+             CREATE INDEX IF NOT EXISTS idx_sessions_end_time   ON sessions(end_time)   WHERE end_time   IS NOT NULL;
+             CREATE INDEX IF NOT EXISTS idx_sessions_start_time ON sessions(start_time) WHERE start_time IS NOT NULL;
+             CREATE TABLE custom_exercises (id TEXT PRIMARY KEY, data TEXT NOT NULL);
+             CREATE TABLE exercises         (id TEXT PRIMARY KEY, data TEXT NOT NULL);
+             CREATE TABLE config            (key TEXT PRIMARY KEY, value TEXT NOT NULL);
+             PRAGMA user_version = 2;",
+        )?;
+    }
+    if schema_version < TRASH_SCHEMA_VERSION {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS trash (
+                 id          TEXT    PRIMARY KEY,
+                 data        TEXT    NOT NULL,
+                 deleted_at  INTEGER GENERATED ALWAYS AS (
+                                 CASE WHEN json_valid(data)
+                                      THEN CAST(json_extract(data, '$.deleted_at') AS INTEGER)
+                                      ELSE NULL END
+                             ) STORED
+             );
+             PRAGMA user_version = 3;",
+        )?;
+    }
+    if schema_version < SCHEMA_VERSION {
+        let mut ddl = String::from(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS exercise_fts USING fts5(
+                 id UNINDEXED, name, muscles, instructions,
+                 tokenize = 'unicode61 remove_diacritics 2'
+             );",
+        );
+        for (table, sign) in FTS_SOURCES {
+            ddl.push_str(&fts_triggers(table, sign));
+        }
+        ddl.push_str("PRAGMA user_version = 4;");
+        conn.execute_batch(&ddl)?;
+        rebuild_exercise_fts(conn)?;
+    }
+    Ok(())
+}
+/// Exercise tables indexed in `exercise_fts`, with the sign given to their
+/// rowids there so that both fit in its rowid space.
+const FTS_SOURCES: [(&str, &str); 2] = [("exercises", ""), ("custom_exercises", "-")];
+/// `SELECT` of the `exercise_fts` rows of the `row` exercises (`new` in a
+/// trigger, or an exercise table read `from`): their names (the localized
+/// ones too), muscles and instructions, when their JSON is valid.
+fn fts_row_select(row: &str, sign: &str, from: &str) -> String {
+    format!(
+        "SELECT {sign}{row}.rowid, {row}.id,
+                json_extract({row}.data, '$.name') || ' ' || COALESCE((
+                    SELECT group_concat(json_extract(value, '$.name'), ' ')
+                    FROM json_each({row}.data, '$.i18n')), ''),
+                COALESCE(json_extract({row}.data, '$.primaryMuscles'), '') || ' ' ||
+                    COALESCE(json_extract({row}.data, '$.secondaryMuscles'), ''),
+                COALESCE(json_extract({row}.data, '$.instructions'), '')
+         {from} WHERE json_valid({row}.data)"
+    )
+}
+/// Triggers keeping `exercise_fts` in step with `table`.  `INSERT OR
+/// REPLACE` does not fire delete triggers, hence the `BEFORE INSERT` one.
+fn fts_triggers(table: &str, sign: &str) -> String {
+    let insert_new = fts_row_select("new", sign, "");
+    format!(
+        "CREATE TRIGGER IF NOT EXISTS {table}_fts_replace BEFORE INSERT ON {table} BEGIN
+             DELETE FROM exercise_fts
+             WHERE rowid = (SELECT {sign}rowid FROM {table} WHERE id = new.id);
+         END;
+         CREATE TRIGGER IF NOT EXISTS {table}_fts_insert AFTER INSERT ON {table} BEGIN
+             INSERT INTO exercise_fts (rowid, id, name, muscles, instructions) {insert_new};
+         END;
+         CREATE TRIGGER IF NOT EXISTS {table}_fts_update AFTER UPDATE ON {table} BEGIN
+             DELETE FROM exercise_fts WHERE rowid = {sign}old.rowid;
+             INSERT INTO exercise_fts (rowid, id, name, muscles, instructions) {insert_new};
+         END;
+         CREATE TRIGGER IF NOT EXISTS {table}_fts_delete AFTER DELETE ON {table} BEGIN
+             DELETE FROM exercise_fts WHERE rowid = {sign}old.rowid;
+         END;"
+    )
+}
+/// Re-indexes every exercise in `exercise_fts`, e.g. once `VACUUM` may have
+/// renumbered the rowids it refers to.
+fn rebuild_exercise_fts(conn: &Connection) -> Result<(), StorageError> {
+    let mut sql = String::from("DELETE FROM exercise_fts;");
+    for (table, sign) in FTS_SOURCES {
+        let select = fts_row_select(table, sign, &format!("FROM {table}"));
+        sql.push_str(&format!(
+            "INSERT INTO exercise_fts (rowid, id, name, muscles, instructions) {select};"
+        ));
+    }
+    conn.execute_batch(&sql)?;
+    Ok(())
+}
+/// Registers the `unpack(data)` SQL function, the plain JSON of a stored row
+/// even if it is a [`super::PackedSession`] (see [`super::unpacked_json`]),
+/// through which every query reads the `data` column.
+fn register_unpack(conn: &Connection) -> rusqlite::Result<()> {
+    use rusqlite::functions::FunctionFlags;
+    conn.create_scalar_function(
+        "unpack",
+        1,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            super::unpacked_json(ctx.get::<String>(0)?)
+                .map_err(|e| rusqlite::Error::UserFunctionError(Box::new(e)))
+        },
+    )
+}
+/// Returns a mutex guard for the long-lived `SQLite` connection.
+///
+/// The connection is opened **once** via [`std::sync::OnceLock`] and reused for the
+/// lifetime of the process.  The schema migration is also applied exactly once,
+/// inside the `OnceLock` initialiser, so it never runs on subsequent calls.
+///
+/// If the data directory cannot be created or the database file cannot be opened on
+/// the first call, an error is returned and cached permanently — all subsequent calls
+/// will return the same error without retrying.
+fn open_db() -> Result<std::sync::MutexGuard<'static, Connection>, StorageError> {
+    static DB: std::sync::OnceLock<Result<std::sync::Mutex<Connection>, String>> =
+        std::sync::OnceLock::new();
+    let result = DB.get_or_init(|| {
+        (|| {
+            let dir = data_dir();
+            std::fs::create_dir_all(&dir).map_err(|e| {
+                format!(
+                    "open_db: failed to create data directory {}: {e}",
+                    dir.display()
+                )
+            })?;
+            let path = db_path();
+            let conn = Connection::open(&path).map_err(|e| {
+                format!(
+                    "open_db: failed to open SQLite database at {}: {e}",
+                    path.display()
+                )
+            })?;
+            register_unpack(&conn)
+                .map_err(|e| format!("open_db: failed to register unpack(): {e}"))?;
+            apply_migration_if_needed(&conn)
+                .map_err(|e| format!("open_db: failed to apply schema migration: {e}"))?;
+            Ok(std::sync::Mutex::new(conn))
+        })()
+    });
+    match result {
+        Ok(mutex) => Ok(mutex
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)),
+        Err(e) => Err(StorageError::Io(std::io::Error::other(e.clone()))),
+    }
+}
+/// Re-applies the schema migration using the shared long-lived connection.
+///
+/// Only available in tests.  Use this after manually dropping tables to simulate
+/// a fresh-database migration without needing a separate `Connection`.
+#[cfg(any(test, feature = "test-support"))]
+pub fn apply_migration_for_testing() -> Result<(), StorageError> {
+    let conn = open_db()?;
+    apply_migration_if_needed(&conn)
+}
+/// Reads all items from a store, deserialising each row's JSON `data` column.
+pub fn get_all<T: DeserializeOwned>(store_name: &str) -> Result<Vec<T>, StorageError> {
+    let table = store_table(store_name)?;
+    let conn = open_db()?;
+    let query = format!("SELECT unpack(data) FROM {table}");
+    let mut stmt = conn.prepare(&query)?;
+    let items = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .filter_map(Result::ok)
+        .filter_map(|data| {
+            serde_json::from_str::<T>(&data)
+                .inspect_err(|e| log::warn!("Skipping corrupt SQLite row: {e}"))
+                .ok()
+        })
+        .collect();
+    Ok(items)
+}
+/// Reads completed sessions ordered by `start_time` descending, with
+/// database-level `LIMIT` / `OFFSET` pagination to avoid loading the
+/// entire history into memory.
+///
+/// Uses the `end_time` and `start_time` generated columns (and their
+/// covering indices) so `SQLite` never needs to parse JSON for filtering or
+/// sorting.  The `archived` flag is only serialised when set, so it is
+/// read from the JSON itself, which avoids a schema migration.
+///
+/// `limit` and `offset` are clamped to `i64::MAX` before being passed to
+/// `SQLite`; in practice both will always be tiny (tens to hundreds).
+pub fn get_completed_sessions_paged(
+    limit: usize,
+    offset: usize,
+    include_archived: bool,
+) -> Result<Vec<crate::models::WorkoutSession>, StorageError> {
+    let conn = open_db()?;
+    let mut stmt = conn.prepare(
+        "SELECT unpack(data) FROM sessions \
+         WHERE end_time IS NOT NULL \
+           AND (?3 OR json_extract(data, '$.archived') IS NOT 1) \
+         ORDER BY start_time DESC \
+         LIMIT ?1 OFFSET ?2",
+    )?;
+    let limit_i64 = i64::try_from(limit).unwrap_or(i64::MAX);
+    let offset_i64 = i64::try_from(offset).unwrap_or(i64::MAX);
+    let items = stmt
+        .query_map(params![limit_i64, offset_i64, include_archived], |row| {
+            row.get::<_, String>(0)
+        })?
+        .filter_map(Result::ok)
+        .filter_map(|data| {
+            serde_json::from_str::<crate::models::WorkoutSession>(&data)
+                .inspect_err(|e| log::warn!("Skipping corrupt SQLite row: {e}"))
+                .ok()
+        })
+        .collect();
+    Ok(items)
+}
+/// Replaces the entire contents of a store with `items` in a single transaction.
+///
+/// JSON serialisation is performed **before** the `SQLite` mutex is acquired so
+/// that expensive serialisation work never blocks other threads waiting for the
+/// lock.
+///
+/// Uses a RAII `Transaction` guard so that the database is automatically
+/// rolled back if an error or panic occurs before `commit()`.
+pub fn store_all<T: Serialize>(store_name: &str, items: &[T]) -> Result<(), StorageError> {
+    let table = store_table(store_name)?;
+    // Serialise every item to (id, JSON) *before* acquiring the database mutex.
+    let rows: Vec<(String, String)> = items
+        .iter()
+        .map(|item| {
+            let val = serde_json::to_value(item)?;
+            let id = val
+                .get("id")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string();
+            let data = serde_json::to_string(item)?;
+            Ok((id, data))
+        })
+        .collect::<Result<_, serde_json::Error>>()?;
+    let mut conn = open_db()?;
+    let tx = conn.transaction()?;
+    let delete_sql = format!("DELETE FROM {table}");
+    tx.execute(&delete_sql, [])?;
+    let insert_sql = format!("INSERT OR REPLACE INTO {table} (id, data) VALUES (?1, ?2)");
+    for (id, data) in &rows {
+        tx.execute(&insert_sql, params![id, data])?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+/// Upserts one item (identified by `id`) into a store.
+///
+/// JSON serialisation is performed **before** the `SQLite` mutex is acquired so
+/// that serialisation work never blocks other threads waiting for the lock.
+pub fn put_item<T: Serialize>(store_name: &str, id: &str, item: &T) -> Result<(), StorageError> {
+    let table = store_table(store_name)?;
+    // Serialise outside the lock to keep the critical section minimal.
+    let data = serde_json::to_string(item)?;
+    let conn = open_db()?;
+    let insert_sql = format!("INSERT OR REPLACE INTO {table} (id, data) VALUES (?1, ?2)");
+    conn.execute(&insert_sql, params![id, data])?;
+    Ok(())
+}
+/// Deletes the item with `id` from a store (no-op if absent).
+pub fn delete_item(store_name: &str, id: &str) -> Result<(), StorageError> {
+    let table = store_table(store_name)?;
+    let conn = open_db()?;
+    let delete_sql = format!("DELETE FROM {table} WHERE id = ?1");
+    conn.execute(&delete_sql, params![id])?;
+    Ok(())
+}
+/// Deletes the items with `ids` from a store, skipping the trash, in a
+/// single transaction.
+///
+/// Returns the number of deleted items.
+pub fn delete_items(store_name: &str, ids: &[String]) -> Result<usize, StorageError> {
+    let table = store_table(store_name)?;
+    let mut conn = open_db()?;
+    let tx = conn.transaction()?;
+    let mut deleted = 0;
+    {
+        let mut stmt = tx.prepare(&format!("DELETE FROM {table} WHERE id = ?1"))?;
+        for id in ids {
+            deleted += stmt.execute(params![id])?;
+        }
+    }
+    tx.commit()?;
+    Ok(deleted)
+}
+/// Moves the item with `id` from `store_name` (sessions or custom
+/// exercises) to the trash, in a single transaction (no-op if absent).
+///
+/// Items not worth keeping (see
+/// [`crate::models::TrashedItem::is_worth_keeping`]) are only deleted.
+pub fn move_to_trash(store_name: &str, id: &str, deleted_at: u64) -> Result<(), StorageError> {
+    use crate::models::{TrashEntry, TrashedItem};
+    let table = store_table(store_name)?;
+    let mut conn = open_db()?;
+    let tx = conn.transaction()?;
+    let data: Option<String> = tx
+        .query_row(
+            &format!("SELECT unpack(data) FROM {table} WHERE id = ?1"),
+            params![id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if let Some(data) = data {
+        let item = match store_name {
+            STORE_SESSIONS => TrashedItem::Session(serde_json::from_str(&data)?),
+            STORE_CUSTOM_EXERCISES => TrashedItem::Exercise(serde_json::from_str(&data)?),
+            other => return Err(StorageError::UnknownStore(other.to_string())),
+        };
+        if item.is_worth_keeping() {
+            let entry = TrashEntry::new(item, deleted_at);
+            tx.execute(
+                "INSERT OR REPLACE INTO trash (id, data) VALUES (?1, ?2)",
+                params![entry.id, serde_json::to_string(&entry)?],
+            )?;
+        }
+        tx.execute(&format!("DELETE FROM {table} WHERE id = ?1"), params![id])?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+/// Puts the item of trash entry `entry_id` back into its original store
+/// and removes the entry, in a single transaction.
+///
+/// Returns the restored item, or `None` if the entry no longer exists.
+pub fn restore_from_trash(
+    entry_id: &str,
+) -> Result<Option<crate::models::TrashedItem>, StorageError> {
+    use crate::models::{TrashEntry, TrashedItem};
+    let mut conn = open_db()?;
+    let tx = conn.transaction()?;
+    let data: Option<String> = tx
+        .query_row(
+            "SELECT data FROM trash WHERE id = ?1",
+            params![entry_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(data) = data else {
+        return Ok(None);
+    };
+    let entry: TrashEntry = serde_json::from_str(&data)?;
+    let (table, item_data) = match &entry.item {
+        TrashedItem::Session(s) => (store_table(STORE_SESSIONS)?, serde_json::to_string(s)?),
+        TrashedItem::Exercise(e) => (
+            store_table(STORE_CUSTOM_EXERCISES)?,
+            serde_json::to_string(e)?,
+        ),
+    };
+    tx.execute(
+        &format!("INSERT OR REPLACE INTO {table} (id, data) VALUES (?1, ?2)"),
+        params![entry.item.original_id(), item_data],
+    )?;
+    tx.execute("DELETE FROM trash WHERE id = ?1", params![entry_id])?;
+    tx.commit()?;
+    Ok(Some(entry.item))
+}
+/// Deletes the trash entries deleted before `cutoff` (Unix seconds).
+///
+/// Returns the number of purged entries.
+pub fn purge_trash(cutoff: u64) -> Result<usize, StorageError> {
+    let conn = open_db()?;
+    let cutoff = i64::try_from(cutoff).unwrap_or(i64::MAX);
+    Ok(conn.execute("DELETE FROM trash WHERE deleted_at < ?1", params![cutoff])?)
+}
+/// Packs the sessions completed before `cutoff` (Unix seconds) that are
+/// still stored plain (see [`super::PackedSession`]), in a single
+/// transaction.
+///
+/// Returns the number of packed sessions.
+pub fn pack_sessions_ended_before(cutoff: u64) -> Result<usize, StorageError> {
+    let cutoff = i64::try_from(cutoff).unwrap_or(i64::MAX);
+    let mut conn = open_db()?;
+    let tx = conn.transaction()?;
+    let rows: Vec<(String, String)> = {
+        let mut stmt = tx.prepare(
+            "SELECT id, data FROM sessions \
+             WHERE end_time IS NOT NULL AND end_time < ?1 \
+               AND substr(data, 1, ?2) != ?3",
+        )?;
+        let prefix = super::PACKED_PREFIX;
+        let rows = stmt
+            .query_map(params![cutoff, prefix.len(), prefix], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })?
+            .collect::<Result<_, _>>()?;
+        rows
+    };
+    let mut packed = 0;
+    for (id, data) in rows {
+        let Ok(session) = serde_json::from_str::<crate::models::WorkoutSession>(&data) else {
+            log::warn!("Not packing corrupt session row {id}");
+            continue;
+        };
+        let data = serde_json::to_string(&super::PackedSession::pack(&session)?)?;
+        tx.execute(
+            "UPDATE sessions SET data = ?1 WHERE id = ?2",
+            params![data, id],
+        )?;
+        packed += 1;
+    }
+    tx.commit()?;
+    Ok(packed)
+}
+/// Returns the total number of rows in the `sessions` table.
+pub fn get_session_count() -> Result<usize, StorageError> {
+    let conn = open_db()?;
+    let count: usize = conn.query_row("SELECT COUNT(*) FROM sessions", [], |r| r.get(0))?;
+    Ok(count)
+}
+/// Returns the bytes of JSON data stored in a store's table.
+pub fn store_bytes(store_name: &str) -> Result<u64, StorageError> {
+    let table = store_table(store_name)?;
+    let conn = open_db()?;
+    let query = format!("SELECT COALESCE(SUM(LENGTH(data)), 0) FROM {table}");
+    let bytes: i64 = conn.query_row(&query, [], |r| r.get(0))?;
+    Ok(u64::try_from(bytes).unwrap_or(0))
+}
+/// Returns the size in bytes of the database file, including its
+/// write-ahead log when there is one.
+pub fn db_file_bytes() -> u64 {
+    let path = db_path();
+    let wal = path.with_extension("db-wal");
+    [path, wal]
+        .iter()
+        .filter_map(|p| std::fs::metadata(p).ok())
+        .map(|m| m.len())
+        .sum()
+}
+/// Rebuilds the database file, reclaiming the space of deleted rows.
+pub fn vacuum() -> Result<(), StorageError> {
+    let conn = open_db()?;
+    conn.execute_batch("VACUUM")?;
+    rebuild_exercise_fts(&conn)
+}
+/// Returns the string value for `key`, or `None` if absent.
+pub fn get_config_value(key: &str) -> Option<String> {
+    let conn = open_db().ok()?;
+    conn.query_row(
+        "SELECT value FROM config WHERE key = ?1",
+        params![key],
+        |row| row.get(0),
+    )
+    .ok()
+}
+/// Sets `key` to `value`.  Passing an empty `value` removes the key.
+pub fn set_config_value(key: &str, value: &str) -> Result<(), StorageError> {
+    let conn = open_db()?;
+    if value.is_empty() {
+        conn.execute("DELETE FROM config WHERE key = ?1", params![key])?;
+    } else {
+        conn.execute(
+            "INSERT OR REPLACE INTO config (key, value) VALUES (?1, ?2)",
+            params![key, value],
+        )?;
+    }
+    Ok(())
+}
+/// Removes `key` from the config (no-op if absent).
+pub fn remove_config_value(key: &str) -> Result<(), StorageError> {
+    set_config_value(key, "")
+}
+/// FTS5 query of the user's search `query`: each word a prefix and each
+/// double-quoted part a phrase, all required.  `None` when nothing is left
+/// to search for.
+fn fts_query(query: &str) -> Option<String> {
+    let quote = |text: &str| format!("\"{}\"", text.replace('"', "\"\""));
+    let mut terms = Vec::new();
+    for (i, part) in query.split('"').enumerate() {
+        // Odd parts are within quotes (an unclosed one runs to the end).
+        if i % 2 == 1 {
+            if part.chars().any(char::is_alphanumeric) {
+                terms.push(quote(part.trim()));
+            }
+            continue;
+        }
+        for word in part.split_whitespace() {
+            if word.chars().any(char::is_alphanumeric) {
+                terms.push(format!("{}*", quote(word)));
+            }
+        }
+    }
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+/// IDs of the exercises (from the database or custom) matching the search
+/// `query` (see [`fts_query`]) in their names, muscles or instructions, best
+/// first and at most `limit`.  Names weigh most, then muscles.
+pub fn search_exercise_ids(query: &str, limit: usize) -> Result<Vec<String>, StorageError> {
+    let Some(fts) = fts_query(query) else {
+        return Ok(Vec::new());
+    };
+    let conn = open_db()?;
+    let mut stmt = conn.prepare(
+        "SELECT id FROM exercise_fts WHERE exercise_fts MATCH ?1
+         ORDER BY bm25(exercise_fts, 0.0, 10.0, 3.0, 1.0) LIMIT ?2",
+    )?;
+    let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+    let ids = stmt
+        .query_map(params![fts, limit], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+    Ok(ids)
+}
+/// Load only the active (in-progress) sessions by filtering at the SQL level.
+///
+/// More memory-efficient than [`get_all`] because completed sessions, which
+/// can represent the bulk of history, are never deserialised into Rust.
+pub fn get_active_sessions() -> Result<Vec<crate::models::WorkoutSession>, StorageError> {
+    let conn = open_db()?;
+    let mut stmt = conn.prepare("SELECT unpack(data) FROM sessions WHERE end_time IS NULL")?;
+    let items = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .filter_map(Result::ok)
+        .filter_map(|data| {
+            serde_json::from_str::<crate::models::WorkoutSession>(&data)
+                .inspect_err(|e| log::warn!("Skipping corrupt active session row: {e}"))
+                .ok()
+        })
+        .collect();
+    Ok(items)
+}
+/// Compute per-exercise all-time bests using a single SQL aggregation query.
+///
+/// Uses `json_each` to iterate the `exercise_logs` array inside each
+/// completed session row, so **no session JSON is ever deserialised into a
+/// Rust struct**.  This is the most memory-efficient path available on
+/// native.
+///
+/// Only completed logs (those whose `end_time` field is non-null) other
+/// than warm-up sets contribute to the aggregation, matching the behaviour of
+/// the app's in-memory personal-record merge.
+pub fn compute_bests_rows() -> Result<Vec<super::BestsRow>, StorageError> {
+    bests_rows_query(None)
+}
+/// Same as [`compute_bests_rows`] but restricted to the given exercise IDs.
+///
+/// Passes the IDs as a JSON array parameter and uses a sub-select to avoid
+/// aggregating exercises the caller does not need.
+pub fn compute_bests_rows_for(
+    exercise_ids: &[String],
+) -> Result<Vec<super::BestsRow>, StorageError> {
+    bests_rows_query(Some(exercise_ids))
+}
+/// Shared implementation: if `ids` is `None` aggregates all exercises;
+/// if `Some`, adds a `json_each` IN-filter on the `exercise_id` column.
+fn bests_rows_query(ids: Option<&[String]>) -> Result<Vec<super::BestsRow>, StorageError> {
+    let conn = open_db()?;
+    let id_filter = if ids.is_some() {
+        "AND json_extract(log.value, '$.exercise_id') \
+         IN (SELECT value FROM json_each(?1))"
+    } else {
+        ""
+    };
+    // CTE-based query that computes both ATH (max) values and the values from
+    // the most-recently completed log per exercise in a single pass.
+    let sql = format!(
+        "WITH all_logs AS ( \
+             SELECT \
+                 json_extract(log.value, '$.exercise_id')                           AS exercise_id, \
+                 CAST(json_extract(log.value, '$.weight_hg')   AS INTEGER)          AS weight, \
+                 CAST(json_extract(log.value, '$.reps')        AS INTEGER)          AS reps, \
+                 CAST(json_extract(log.value, '$.distance_m')  AS INTEGER)          AS dist, \
+                 CAST(json_extract(log.value, '$.end_time')    AS INTEGER) \
+               - CAST(json_extract(log.value, '$.start_time')  AS INTEGER)          AS dur, \
+                 CAST(json_extract(log.value, '$.end_time')    AS INTEGER)          AS end_ts, \
+                 COALESCE(json_extract(log.value, '$.assisted'), 0)                 AS assisted \
+             FROM sessions \
+             CROSS JOIN json_each(json_extract(unpack(data), '$.exercise_logs')) AS log \
+             WHERE end_time IS NOT NULL \
+               AND json_extract(log.value, '$.end_time') IS NOT NULL \
+               AND COALESCE(json_extract(log.value, '$.set_kind'), 'normal') != 'warm_up' \
+               {id_filter} \
+         ), \
+         bests AS ( \
+             SELECT exercise_id, \
+                    MAX(CASE WHEN assisted = 0 THEN weight END) AS max_weight, \
+                    MAX(reps)   AS max_reps, \
+                    MAX(dist)   AS max_dist, \
+                    MAX(dur)    AS max_dur \
+             FROM all_logs \
+             GROUP BY exercise_id \
+         ), \
+         ranked AS ( \
+             SELECT exercise_id, weight, reps, dist, end_ts, assisted, \
+                    ROW_NUMBER() OVER ( \
+                        PARTITION BY exercise_id \
+                        ORDER BY end_ts DESC \
+                    ) AS rn \
+             FROM all_logs \
+         ), \
+         lasts AS ( \
+             SELECT exercise_id, \
+                    weight AS last_weight, \
+                    reps   AS last_reps, \
+                    dist   AS last_dist, \
+                    end_ts AS last_ts, \
+                    assisted AS last_assisted \
+             FROM ranked WHERE rn = 1 \
+         ) \
+         SELECT b.exercise_id, \
+                b.max_weight, b.max_reps, b.max_dist, b.max_dur, \
+                l.last_weight, l.last_reps, l.last_dist, l.last_ts, l.last_assisted \
+         FROM bests b \
+         LEFT JOIN lasts l ON b.exercise_id = l.exercise_id"
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let map_row = |row: &rusqlite::Row<'_>| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, Option<i64>>(1)?,
+            row.get::<_, Option<i64>>(2)?,
+            row.get::<_, Option<i64>>(3)?,
+            row.get::<_, Option<i64>>(4)?,
+            row.get::<_, Option<i64>>(5)?,
+            row.get::<_, Option<i64>>(6)?,
+            row.get::<_, Option<i64>>(7)?,
+            row.get::<_, Option<i64>>(8)?,
+            row.get::<_, Option<i64>>(9)?,
+        ))
+    };
+    let rows: Vec<super::BestsRow> = if let Some(ids) = ids {
+        let json = serde_json::to_string(ids).unwrap_or_else(|_| "[]".into());
+        stmt.query_map(rusqlite::params![json], map_row)?
+            .filter_map(Result::ok)
+            .map(bests_row_from_tuple)
+            .collect()
+    } else {
+        stmt.query_map([], map_row)?
+            .filter_map(Result::ok)
+            .map(bests_row_from_tuple)
+            .collect()
+    };
+    Ok(rows)
+}
+/// Raw SQL projection tuple returned by `bests_rows_query`.
+type BestsSqlTuple = (
+    String,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+);
+/// Convert the raw SQL tuple into a [`BestsRow`].
+fn bests_row_from_tuple(
+    (exercise_id, w, r, d, dur, lw, lr, ld, lts, la): BestsSqlTuple,
+) -> super::BestsRow {
+    super::BestsRow {
+        exercise_id,
+        max_weight_hg: w.and_then(|v| u16::try_from(v).ok()),
+        max_reps: r.and_then(|v| u32::try_from(v).ok()),
+        max_distance_m: d.and_then(|v| u32::try_from(v).ok()),
+        max_duration_s: dur.and_then(|v| u64::try_from(v).ok()),
+        last_weight_hg: lw.and_then(|v| u16::try_from(v).ok()),
+        last_reps: lr.and_then(|v| u32::try_from(v).ok()),
+        last_distance_m: ld.and_then(|v| u32::try_from(v).ok()),
+        last_assisted: la.is_some_and(|v| v != 0),
+        last_log_end_time: lts.and_then(|v| u64::try_from(v).ok()),
+    }
+}
+/// Global mutex that serialises all tests touching native storage within a
+/// single process.
+///
+/// With nextest each test runs in its own process, so cross-process
+/// isolation is handled by [`test_data_dir`]'s per-process directory.
+/// This mutex provides additional within-process serialisation for the
+/// (rare) case where multiple storage tests share a process.
+///
+/// Recovers from a poisoned mutex so a previous test failure does not
+/// cascade into every subsequent test that needs storage isolation.
+#[cfg(any(test, feature = "test-support"))]
+pub fn test_lock() -> std::sync::MutexGuard<'static, ()> {
+    static LOCK: std::sync::OnceLock<std::sync::Mutex<()>> = std::sync::OnceLock::new();
+    let m = LOCK.get_or_init(|| std::sync::Mutex::new(()));
+    m.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
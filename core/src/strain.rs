@@ -0,0 +1,149 @@
+/// Session strain score, charting the training load over time.
+///
+/// Sessions with heart-rate samples are scored with Edwards' TRIMP: every
+/// minute counts from 1 to 5 points depending on the heart-rate zone
+/// (50–60 % … 90–100 % of the maximum heart rate).  Other sessions are scored
+/// from their volume, one point per [`KG_PER_POINT`] kg lifted.
+///
+/// The acute:chronic ratio compares the load of the last 7 days with the
+/// weekly average of the last 28; above [`OVERREACHING_RATIO`] the load rises
+/// faster than the body adapts to it.
+use crate::models::WorkoutSession;
+use crate::session_stats;
+/// Volume (kg) scored as one strain point when no heart rate was recorded.
+pub const KG_PER_POINT: f64 = 100.0;
+/// Maximum heart rate assumed until a higher one has been recorded.
+const DEFAULT_MAX_HR_BPM: u16 = 190;
+/// Longest gap (seconds) between two samples counted as continuous effort.
+const MAX_SAMPLE_GAP_SECS: u64 = 60;
+/// Share of the maximum heart rate from which the first zone starts.
+const FIRST_ZONE_SHARE: f64 = 0.5;
+/// Width of a heart-rate zone, as a share of the maximum heart rate.
+const ZONE_SHARE: f64 = 0.1;
+/// Highest zone weight.
+const MAX_ZONE: f64 = 5.0;
+/// Days of the acute (recent) training load.
+const ACUTE_DAYS: u64 = 7;
+/// Days of the chronic (habitual) training load.
+const CHRONIC_DAYS: u64 = 28;
+/// Acute:chronic ratio above which the load is flagged as overreaching.
+pub const OVERREACHING_RATIO: f64 = 1.5;
+const SECS_PER_MINUTE: f64 = 60.0;
+/// Maximum heart rate the zones are relative to: the highest one recorded
+/// in `sessions`, at least [`DEFAULT_MAX_HR_BPM`].
+#[must_use]
+pub fn max_heart_rate(sessions: &[WorkoutSession]) -> u16 {
+    sessions
+        .iter()
+        .flat_map(|s| &s.exercise_logs)
+        .flat_map(|l| &l.hr_samples)
+        .map(|s| s.bpm)
+        .fold(DEFAULT_MAX_HR_BPM, u16::max)
+}
+/// Strain score of `session`, heart-rate zones being relative to `max_hr`.
+#[must_use]
+pub fn session_strain(session: &WorkoutSession, max_hr: u16) -> f64 {
+    let mut trimp = 0.0;
+    let mut has_samples = false;
+    for log in &session.exercise_logs {
+        for pair in log.hr_samples.windows(2) {
+            has_samples = true;
+            let gap = pair[1].time.saturating_sub(pair[0].time);
+            if gap > MAX_SAMPLE_GAP_SECS {
+                continue;
+            }
+            let share = f64::from(pair[0].bpm) / f64::from(max_hr.max(1));
+            if share < FIRST_ZONE_SHARE {
+                continue;
+            }
+            let zone = (((share - FIRST_ZONE_SHARE) / ZONE_SHARE).floor() + 1.0).min(MAX_ZONE);
+            #[allow(clippy::cast_precision_loss)]
+            let minutes = gap as f64 / SECS_PER_MINUTE;
+            trimp += minutes * zone;
+        }
+    }
+    if has_samples {
+        trimp
+    } else {
+        session_stats::compute::<crate::models::Exercise>(session, &[], &[]).volume_kg()
+            / KG_PER_POINT
+    }
+}
+/// Strain of each completed session of `sessions`, as `(start time, score)`
+/// pairs sorted by time.
+#[must_use]
+pub fn strain_series(sessions: &[WorkoutSession]) -> Vec<(u64, f64)> {
+    let max_hr = max_heart_rate(sessions);
+    let mut series: Vec<(u64, f64)> = sessions
+        .iter()
+        .filter(|s| !s.is_active() && !s.exercise_logs.is_empty())
+        .map(|s| (s.start_time, session_strain(s, max_hr)))
+        .collect();
+    series.sort_by_key(|&(time, _)| time);
+    series
+}
+/// Load of the last [`ACUTE_DAYS`] over the weekly average of the last
+/// [`CHRONIC_DAYS`] before `now`; `None` without any chronic load.
+#[must_use]
+pub fn acute_chronic_ratio(series: &[(u64, f64)], now: u64) -> Option<f64> {
+    let since = |days: u64| now.saturating_sub(days * crate::datetime::SECONDS_IN_DAY);
+    let load = |days: u64| -> f64 {
+        series
+            .iter()
+            .filter(|&&(time, _)| time > since(days) && time <= now)
+            .map(|&(_, score)| score)
+            .sum()
+    };
+    #[allow(clippy::cast_precision_loss)]
+    let chronic = load(CHRONIC_DAYS) * ACUTE_DAYS as f64 / CHRONIC_DAYS as f64;
+    (chronic > 0.0).then(|| load(ACUTE_DAYS) / chronic)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datetime::SECONDS_IN_DAY;
+    use crate::models::{ExerciseLog, HeartRateSample, Weight};
+    fn session(start: u64, weight_hg: u16, hr: &[u16]) -> WorkoutSession {
+        let mut s = WorkoutSession::new();
+        s.start_time = start;
+        s.end_time = Some(start + 3_600);
+        s.exercise_logs = vec![ExerciseLog {
+            exercise_id: "row".into(),
+            exercise_name: "Row".into(),
+            start_time: start,
+            end_time: Some(start + 600),
+            weight_hg: Weight(weight_hg),
+            reps: Some(10),
+            hr_samples: (0u64..)
+                .zip(hr)
+                .map(|(i, &bpm)| HeartRateSample {
+                    time: start + i * 60,
+                    bpm,
+                })
+                .collect(),
+            ..Default::default()
+        }];
+        s
+    }
+    #[test]
+    fn strain_uses_heart_rate_zones_else_volume() {
+        // One minute in zone 1 (50–60 %), then one in zone 5 (90–100 %).
+        let with_hr = session(0, 1_000, &[100, 190, 120]);
+        assert!((session_strain(&with_hr, 190) - 6.0).abs() < 1e-9);
+        let without_hr = session(0, 1_000, &[]);
+        assert!((session_strain(&without_hr, 190) - 1_000.0 / KG_PER_POINT).abs() < 1e-9);
+        assert_eq!(max_heart_rate(&[with_hr]), 190);
+    }
+    #[test]
+    fn acute_chronic_ratio_flags_load_spikes() {
+        let now = 100 * SECONDS_IN_DAY;
+        let steady: Vec<(u64, f64)> = (0..4)
+            .map(|w| (now - w * 7 * SECONDS_IN_DAY, 10.0))
+            .collect();
+        assert!((acute_chronic_ratio(&steady, now).unwrap() - 1.0).abs() < 1e-9);
+        let mut spike = steady.clone();
+        spike.push((now - SECONDS_IN_DAY, 30.0));
+        assert!(acute_chronic_ratio(&spike, now).unwrap() > OVERREACHING_RATIO);
+        assert_eq!(acute_chronic_ratio(&[], now), None);
+    }
+}
@@ -0,0 +1,330 @@
+//! Minimal self-hostable server for the app's end-to-end encrypted sync,
+//! run by `logout-cli sync-serve`.
+//!
+//! The app encrypts every record before sending it, so the server stores
+//! opaque JSON objects and only reads their `id`, `kind` and vector `clock`:
+//!
+//! ```json
+//! { "id": "…", "kind": "session", "clock": { "device": 3 }, "data": "base64…" }
+//! ```
+//!
+//! It answers two routes under any base path, the app calling
+//! `{endpoint}records`:
+//!
+//! - `GET  …/records` → `200` with the JSON array of every stored record
+//! - `PUT  …/records` ← JSON array of records to upsert → `204`, or `400`
+//!   if one lacks a string `id`, `kind` or `data` or an object `clock`
+//!
+//! An upserted record replaces the stored one of the same `kind` and `id`,
+//! unless the stored clock strictly dominates the new one: a device pushing
+//! a version older than one already synced does not roll it back.  Records
+//! are kept in a JSON file, rewritten after every `PUT`.
+//!
+//! Browsers may call it from the web app of another origin (CORS, any
+//! origin allowed).  There is no authentication and no TLS: the records are
+//! unreadable without the passphrase, but anyone reaching the server can
+//! list or overwrite them, so expose it only behind a reverse proxy that
+//! adds both.
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+/// Largest request body accepted, in bytes (a full history with GPS tracks).
+const MAX_BODY_BYTES: usize = 64 * 1024 * 1024;
+/// Time a client gets to send its request before being dropped.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// The stored records, by `kind:id`, backed by a JSON file.
+#[derive(Debug)]
+pub struct RecordStore {
+    path: PathBuf,
+    records: BTreeMap<String, Value>,
+}
+/// A parsed HTTP request.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+/// An HTTP response, with a JSON body unless empty.
+#[derive(Debug, PartialEq, Eq)]
+struct Response {
+    status: u16,
+    body: String,
+}
+impl Response {
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: serde_json::json!({ "error": message }).to_string(),
+        }
+    }
+    fn empty(status: u16) -> Self {
+        Self {
+            status,
+            body: String::new(),
+        }
+    }
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            204 => "No Content",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+}
+/// `kind:id` of a record, `None` if it is not a well-formed record.
+fn record_key(record: &Value) -> Option<String> {
+    let id = record.get("id")?.as_str()?;
+    let kind = record.get("kind")?.as_str()?;
+    record.get("data")?.as_str()?;
+    record.get("clock")?.as_object()?;
+    Some(format!("{kind}:{id}"))
+}
+/// Whether vector clock `a` strictly dominates `b`: no counter lower, one
+/// higher.
+fn dominates(a: &Map<String, Value>, b: &Map<String, Value>) -> bool {
+    let count = |clock: &Map<String, Value>, device: &str| {
+        clock.get(device).and_then(Value::as_u64).unwrap_or(0)
+    };
+    let devices = || a.keys().chain(b.keys());
+    devices().all(|d| count(a, d) >= count(b, d)) && devices().any(|d| count(a, d) > count(b, d))
+}
+impl RecordStore {
+    /// Open the store kept in `path`, empty if the file does not exist yet.
+    ///
+    /// # Errors
+    /// If the file cannot be read or does not hold an array of records.
+    pub fn open(path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let path = path.into();
+        let records: Vec<Value> = match std::fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        let records = records
+            .into_iter()
+            .filter_map(|record| Some((record_key(&record)?, record)))
+            .collect();
+        Ok(Self { path, records })
+    }
+    /// File the records are kept in.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    /// Number of stored records.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+    /// Whether no record is stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+    /// Upsert `records` (see the module documentation), then save the file.
+    /// Returns the number of records replaced or added.
+    fn upsert(&mut self, records: Vec<Value>) -> std::io::Result<usize> {
+        let mut changed = 0;
+        for record in records {
+            let Some(key) = record_key(&record) else {
+                continue;
+            };
+            let stale = self.records.get(&key).is_some_and(|stored| {
+                matches!(
+                    (stored.get("clock"), record.get("clock")),
+                    (Some(Value::Object(old)), Some(Value::Object(new))) if dominates(old, new)
+                )
+            });
+            if !stale {
+                self.records.insert(key, record);
+                changed += 1;
+            }
+        }
+        self.save()?;
+        Ok(changed)
+    }
+    /// Write the records to a temporary file renamed over the store, so an
+    /// interrupted write never truncates it.
+    fn save(&self) -> std::io::Result<()> {
+        let records: Vec<&Value> = self.records.values().collect();
+        let tmp = self.path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_vec(&records)?)?;
+        std::fs::rename(&tmp, &self.path)
+    }
+    /// Answer `request`.
+    fn respond(&mut self, request: &Request) -> Response {
+        if request.path.trim_end_matches('/').rsplit('/').next() != Some("records") {
+            return Response::error(404, "not found");
+        }
+        match request.method.as_str() {
+            "GET" => {
+                let records: Vec<&Value> = self.records.values().collect();
+                match serde_json::to_string(&records) {
+                    Ok(body) => Response { status: 200, body },
+                    Err(e) => Response::error(500, &e.to_string()),
+                }
+            }
+            "PUT" => {
+                let records: Vec<Value> = match serde_json::from_str(&request.body) {
+                    Ok(records) => records,
+                    Err(e) => return Response::error(400, &e.to_string()),
+                };
+                if let Some(bad) = records.iter().position(|r| record_key(r).is_none()) {
+                    return Response::error(400, &format!("record {bad} is malformed"));
+                }
+                match self.upsert(records) {
+                    Ok(changed) => {
+                        log::info!("Sync server stored {changed} record(s)");
+                        Response::empty(204)
+                    }
+                    Err(e) => Response::error(500, &e.to_string()),
+                }
+            }
+            // CORS preflight of the web app's `PUT`.
+            "OPTIONS" => Response::empty(204),
+            _ => Response::error(405, "method not allowed"),
+        }
+    }
+}
+/// Answer the connections of `listener` one at a time, forever.
+pub fn serve(listener: &TcpListener, store: &mut RecordStore) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => serve_connection(stream, store),
+            Err(e) => log::warn!("Sync server connection failed: {e}"),
+        }
+    }
+}
+/// Answer one connection.
+fn serve_connection(mut stream: TcpStream, store: &mut RecordStore) {
+    let _ = stream.set_read_timeout(Some(REQUEST_TIMEOUT));
+    let response = match read_request(&mut stream) {
+        Ok(Some(request)) => store.respond(&request),
+        Ok(None) => Response::error(413, "request too large"),
+        Err(_) => return,
+    };
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Access-Control-Allow-Methods: GET, PUT\r\n\
+         Access-Control-Allow-Headers: Content-Type\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    );
+}
+/// Read the request line, headers and body; `None` if the body is larger
+/// than [`MAX_BODY_BYTES`].
+fn read_request(stream: impl Read) -> std::io::Result<Option<Request>> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_owned();
+    let target = parts.next().unwrap_or_default();
+    let path = target.split_once('?').map_or(target, |(path, _)| path);
+    let mut request = Request {
+        method,
+        path: path.to_owned(),
+        ..Request::default()
+    };
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Ok(None);
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    request.body = String::from_utf8_lossy(&body).into_owned();
+    Ok(Some(request))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn record(id: &str, clock: Value, data: &str) -> Value {
+        serde_json::json!({ "id": id, "kind": "session", "clock": clock, "data": data })
+    }
+    fn store() -> RecordStore {
+        let path = std::env::temp_dir().join(format!(
+            "logout-sync-server-{}-{}.json",
+            std::process::id(),
+            crate::crypto::to_base64(&crate::crypto::random_bytes(6).unwrap()).replace('/', "_")
+        ));
+        RecordStore::open(path).unwrap()
+    }
+    fn request(method: &str, path: &str, body: &str) -> Request {
+        Request {
+            method: method.into(),
+            path: path.into(),
+            body: body.into(),
+        }
+    }
+    #[test]
+    fn stored_records_are_listed_and_survive_a_restart() {
+        let mut store = store();
+        let put = Value::Array(vec![record("s1", serde_json::json!({ "a": 1 }), "x")]);
+        let response = store.respond(&request("PUT", "/logout/records", &put.to_string()));
+        assert_eq!(response.status, 204);
+        let response = store.respond(&request("GET", "/logout/records", ""));
+        assert_eq!(response.status, 200);
+        assert_eq!(serde_json::from_str::<Value>(&response.body).unwrap(), put);
+        let reopened = RecordStore::open(store.path()).unwrap();
+        assert_eq!(reopened.len(), 1);
+        let _ = std::fs::remove_file(store.path());
+    }
+    #[test]
+    fn older_versions_do_not_replace_newer_ones() {
+        let mut store = store();
+        let newer = record("s1", serde_json::json!({ "a": 2, "b": 1 }), "new");
+        let older = record("s1", serde_json::json!({ "a": 1 }), "old");
+        let concurrent = record("s1", serde_json::json!({ "a": 3 }), "other");
+        assert_eq!(store.upsert(vec![newer.clone()]).unwrap(), 1);
+        assert_eq!(store.upsert(vec![older]).unwrap(), 0);
+        assert_eq!(store.records["session:s1"], newer);
+        assert_eq!(store.upsert(vec![concurrent.clone()]).unwrap(), 1);
+        assert_eq!(store.records["session:s1"], concurrent);
+        let _ = std::fs::remove_file(store.path());
+    }
+    #[test]
+    fn refuses_malformed_records_and_other_routes() {
+        let mut store = store();
+        let bad = r#"[{"id":"s1","kind":"session","data":"x"}]"#;
+        assert_eq!(store.respond(&request("PUT", "/records", bad)).status, 400);
+        assert_eq!(store.respond(&request("PUT", "/records", "{")).status, 400);
+        assert_eq!(store.respond(&request("GET", "/other", "")).status, 404);
+        assert_eq!(
+            store.respond(&request("DELETE", "/records", "")).status,
+            405
+        );
+        assert!(store.is_empty());
+    }
+    #[test]
+    fn reads_the_body_of_its_content_length() {
+        let raw = "PUT /records?x=1 HTTP/1.1\r\nContent-Length: 2\r\n\r\n[]trailing";
+        assert_eq!(
+            read_request(raw.as_bytes()).unwrap(),
+            Some(request("PUT", "/records", "[]"))
+        );
+    }
+}
@@ -0,0 +1,122 @@
+/// Export of a custom exercise in the format of the upstream
+/// [free-exercise-db](https://github.com/yuhonas/free-exercise-db), so it can
+/// be contributed there.
+///
+/// Upstream exercises are one JSON file each, `exercises/<id>.json`, with
+/// every field present (`null` when unknown) and their pictures stored next
+/// to it as `exercises/<id>/<n>.jpg`.  The ID is derived from the name as
+/// upstream does, and the local images are mapped to such paths; the picture
+/// files themselves are to be added by hand.
+use crate::models::{Category, Equipment, Exercise, Force, Level, Mechanic, Muscle};
+use serde::Serialize;
+/// Extension given to pictures whose key has none.
+const DEFAULT_IMAGE_EXTENSION: &str = "jpg";
+/// Exercise as written in an upstream `exercises/<id>.json` file.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpstreamExercise<'a> {
+    name: &'a str,
+    force: Option<Force>,
+    level: Option<Level>,
+    mechanic: Option<Mechanic>,
+    equipment: Option<Equipment>,
+    primary_muscles: &'a [Muscle],
+    secondary_muscles: &'a [Muscle],
+    instructions: &'a [String],
+    category: Category,
+    images: Vec<String>,
+    id: String,
+}
+/// Upstream ID of an exercise named `name`: its words joined by `_`, any
+/// character other than ASCII letters, digits and `-` acting as a separator.
+#[must_use]
+pub fn upstream_id(name: &str) -> String {
+    name.split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+/// Upstream path of the `index`-th image of the exercise `id`, whose local
+/// key is `key`.  Images of the upstream database (plain relative paths,
+/// e.g. of a cloned exercise) are kept as they are.
+fn image_path(id: &str, index: usize, key: &str) -> String {
+    let is_db_path = !key.contains(':') && !key.starts_with('/');
+    if is_db_path {
+        return key.to_owned();
+    }
+    let extension = key
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .filter(|ext| matches!(ext.as_str(), "jpg" | "jpeg" | "png" | "gif" | "webp"))
+        .unwrap_or_else(|| DEFAULT_IMAGE_EXTENSION.to_owned());
+    format!("{id}/{index}.{extension}")
+}
+/// `exercise` as the pretty-printed content of an upstream exercise file,
+/// with the file name to give it.
+#[must_use]
+pub fn export_exercise(exercise: &Exercise) -> (String, String) {
+    let id = upstream_id(&exercise.name);
+    let upstream = UpstreamExercise {
+        name: exercise.name.trim(),
+        force: exercise.force,
+        level: exercise.level,
+        mechanic: exercise.mechanic,
+        equipment: exercise.equipment,
+        primary_muscles: &exercise.primary_muscles,
+        secondary_muscles: &exercise.secondary_muscles,
+        instructions: &exercise.instructions,
+        category: exercise.category,
+        images: exercise
+            .images
+            .iter()
+            .enumerate()
+            .map(|(i, key)| image_path(&id, i, key))
+            .collect(),
+        id: id.clone(),
+    };
+    let json = serde_json::to_string_pretty(&upstream).unwrap_or_default();
+    (format!("{id}.json"), json)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn ids_follow_the_upstream_convention() {
+        assert_eq!(upstream_id("3/4 Sit-Up"), "3_4_Sit-Up");
+        assert_eq!(upstream_id("  Barbell  Curl (Wide) "), "Barbell_Curl_Wide");
+    }
+    #[test]
+    fn exports_every_field_with_upstream_image_paths() {
+        let exercise = Exercise {
+            id: "custom_1700000000".into(),
+            name: "Ring Dip".into(),
+            name_lower: String::new(),
+            force: Some(Force::Push),
+            level: None,
+            mechanic: None,
+            equipment: None,
+            primary_muscles: vec![Muscle::Triceps],
+            secondary_muscles: vec![],
+            instructions: vec!["Dip.".into()],
+            category: Category::Strength,
+            images: vec![
+                "local:ring.PNG".into(),
+                "https://example.com/dip".into(),
+                "Dips/0.jpg".into(),
+            ],
+            videos: vec!["https://example.com/video".into()],
+            i18n: None,
+            rest_seconds: None,
+        };
+        let (filename, json) = export_exercise(&exercise);
+        assert_eq!(filename, "Ring_Dip.json");
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["id"], "Ring_Dip");
+        assert_eq!(value["primaryMuscles"][0], "triceps");
+        assert!(value["mechanic"].is_null() && value.get("videos").is_none());
+        assert_eq!(
+            value["images"],
+            serde_json::json!(["Ring_Dip/0.png", "Ring_Dip/1.jpg", "Dips/0.jpg"])
+        );
+    }
+}
@@ -0,0 +1,98 @@
+/// Body of the webhook notified when a session finishes, for
+/// home-automation setups (Home Assistant, Node-RED, n8n…) to react to the
+/// end of a workout.  The app POSTs it as JSON to the configured URL.
+use crate::models::{WorkoutSession, HG_PER_KG};
+use serde::Serialize;
+/// Value of [`WebhookPayload::event`] for a finished session.
+pub const SESSION_FINISHED_EVENT: &str = "session_finished";
+/// JSON body POSTed to the webhook.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebhookPayload {
+    /// What happened, [`SESSION_FINISHED_EVENT`].
+    pub event: &'static str,
+    pub session_id: String,
+    /// Unix timestamps of the session start and end.
+    pub start_time: u64,
+    pub end_time: Option<u64>,
+    /// Net duration in seconds, pauses excluded.
+    pub duration_s: u64,
+    /// Completed sets, warm-ups included.
+    pub sets: usize,
+    /// Volume lifted in kg, warm-up sets excluded.
+    pub volume_kg: f64,
+    /// Names of the exercises performed, in order, without repetition.
+    pub exercises: Vec<String>,
+    pub location: Option<String>,
+}
+impl WebhookPayload {
+    /// Summary of the finished `session`.
+    #[must_use]
+    pub fn session_finished(session: &WorkoutSession) -> Self {
+        let completed: Vec<_> = session
+            .exercise_logs
+            .iter()
+            .filter(|l| l.is_complete())
+            .collect();
+        let mut exercises: Vec<String> = Vec::new();
+        for log in &completed {
+            if !exercises.contains(&log.exercise_name) {
+                exercises.push(log.exercise_name.clone());
+            }
+        }
+        let volume_hg: u64 = completed.iter().map(|l| l.volume_hg()).sum();
+        #[allow(clippy::cast_precision_loss)]
+        let volume_kg = volume_hg as f64 / HG_PER_KG;
+        Self {
+            event: SESSION_FINISHED_EVENT,
+            session_id: session.id.clone(),
+            start_time: session.start_time,
+            end_time: session.end_time,
+            duration_s: session.duration_seconds(),
+            sets: completed.len(),
+            volume_kg,
+            exercises,
+            location: session.location.clone(),
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ExerciseLog, SetKind, Weight};
+    fn log(name: &str, kind: SetKind, end_time: Option<u64>) -> ExerciseLog {
+        ExerciseLog {
+            exercise_id: name.to_lowercase(),
+            exercise_name: name.into(),
+            start_time: 1_700_000_000,
+            end_time,
+            weight_hg: Weight(600),
+            reps: Some(10),
+            set_kind: kind,
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn payload_summarises_the_completed_sets() {
+        let done = Some(1_700_000_060);
+        let session = WorkoutSession {
+            id: "s1".into(),
+            start_time: 1_700_000_000,
+            end_time: Some(1_700_001_200),
+            exercise_logs: vec![
+                log("Squat", SetKind::WarmUp, done),
+                log("Squat", SetKind::Normal, done),
+                log("Row", SetKind::Normal, done),
+                log("Curl", SetKind::Normal, None),
+            ],
+            ..WorkoutSession::default()
+        };
+        let payload = WebhookPayload::session_finished(&session);
+        assert_eq!(payload.event, SESSION_FINISHED_EVENT);
+        assert_eq!(payload.duration_s, 1_200);
+        assert_eq!(payload.sets, 3);
+        assert!((payload.volume_kg - 1_200.0).abs() < f64::EPSILON);
+        assert_eq!(payload.exercises, vec!["Squat", "Row"]);
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["event"], "session_finished");
+    }
+}
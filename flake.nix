@@ -129,6 +129,23 @@
             pkgs.darwin.apple_sdk.frameworks.Security
             pkgs.darwin.apple_sdk.frameworks.SystemConfiguration
           ];
+          # GTK / WebKitGTK stack of the desktop renderer, tray icon and global
+          # shortcut (desktop-platform feature)
+          desktopBuildInputs = pkgs.lib.optionals pkgs.stdenv.isLinux (
+            with pkgs;
+            [
+              atk
+              cairo
+              gdk-pixbuf
+              glib
+              gtk3
+              libayatana-appindicator
+              libsoup_3
+              pango
+              webkitgtk_4_1
+              xdotool
+            ]
+          );
           webTestInputs = with pkgs; [
             curl
             chromedriver
@@ -149,6 +166,13 @@
             buildInputs = commonBuildInputs;
             doCheck = false;
           };
+          cargoArtifactsDesktop = craneLib.buildDepsOnly {
+            src = filteredSrc;
+            cargoExtraArgs = "--no-default-features --features desktop-platform";
+            nativeBuildInputs = commonNativeBuildInputs;
+            buildInputs = commonBuildInputs ++ desktopBuildInputs;
+            doCheck = false;
+          };
           cargoArtifactsWeb = craneLib.buildDepsOnly {
             src = filteredSrc;
             cargoExtraArgs = "--target wasm32-unknown-unknown";
@@ -188,6 +212,7 @@
             filteredSrcServer
             cargoArtifactsHost
             cargoArtifactsServer
+            cargoArtifactsDesktop
             cargoArtifactsWeb
             androidComposition
             commonNativeBuildInputs
@@ -195,6 +220,7 @@
             androidNativeBuildInputs
             webTestInputs
             commonBuildInputs
+            desktopBuildInputs
             ;
         };
     in
@@ -519,6 +545,28 @@
             buildInputs = env.commonBuildInputs;
             cargoClippyExtraArgs = "--all-targets -- -D warnings -W clippy::all -W clippy::pedantic";
           };
+          # The desktop-only code (tray icon, global shortcut) is not part of
+          # the default web build linted above
+          lint-desktop = env.craneLib.cargoClippy {
+            cargoArtifacts = env.cargoArtifactsDesktop;
+            src = env.filteredSrc;
+            pname = "logout-desktop";
+            version = env.projectVersion;
+            nativeBuildInputs = env.commonNativeBuildInputs;
+            buildInputs = env.commonBuildInputs ++ env.desktopBuildInputs;
+            cargoExtraArgs = "--no-default-features --features desktop-platform";
+            cargoClippyExtraArgs = "--all-targets -- -D warnings";
+          };
+          # Unit tests of the desktop-only code, e.g. global shortcut parsing
+          test-desktop = env.craneLib.cargoTest {
+            cargoArtifacts = env.cargoArtifactsDesktop;
+            src = env.filteredSrc;
+            pname = "logout-desktop";
+            version = env.projectVersion;
+            nativeBuildInputs = env.commonNativeBuildInputs;
+            buildInputs = env.commonBuildInputs ++ env.desktopBuildInputs;
+            cargoExtraArgs = "--no-default-features --features desktop-platform";
+          };
           coverage = env.craneLib.buildPackage {
             cargoArtifacts = env.cargoArtifactsHost;
             src = env.filteredSrc;
@@ -530,7 +578,7 @@
             buildPhase = ''
               export HOME=$TMPDIR
               mkdir -p $out
-              cargo llvm-cov nextest --bin log-out \
+              cargo llvm-cov nextest --workspace \
                 --ignore-filename-regex "(src/components/|\.cargo/registry/|nix/store)" \
                 --html --output-dir $out 2>&1 | tee $out/nextest.log
               cargo llvm-cov report \
@@ -553,6 +601,14 @@
               name = "lint";
               path = self.checks.${system}.lint; # Clippy
             }
+            {
+              name = "lint-desktop";
+              path = self.checks.${system}.lint-desktop; # Clippy, desktop-platform feature
+            }
+            {
+              name = "test-desktop";
+              path = self.checks.${system}.test-desktop; # Tests, desktop-platform feature
+            }
           ];
         }
       );
@@ -0,0 +1,80 @@
+// Web worker scanning the exercise names for a search query, off the UI thread
+//
+// The page (src/services/background_search.rs) sends each list of exercises
+// it searches once, as the names of every exercise, then only the queries.
+// For a query the worker answers the indices of the exercises one of whose
+// names may match it: a superset of what the Rust search keeps, which the page
+// then scores and ranks among these few exercises.  Its normalisation
+// therefore only ever folds more than `normalize_for_search` does, so that no
+// match of the Rust search is lost.
+//
+// Messages from the page:
+// - { type: "index", key, drop, names: [[name, ...], ...] } keeps the list
+//   `key`, forgetting the list `drop` if any
+// - { type: "search", id, key, query } answers { id, hits: [index, ...] }
+
+// Normalised names of each exercise, and their lowercase forms, by list key
+const lists = new Map();
+
+// Lowercase, without hyphens, apostrophes, spaces and periods, nor diacritics
+function normalize(text) {
+	return text
+		.toLowerCase()
+		.replace(/[-'. ]/g, "")
+		.normalize("NFD")
+		.replace(/\p{M}/gu, "")
+		.replace(/æ/g, "ae")
+		.replace(/œ/g, "oe")
+		.replace(/ß/g, "ss")
+		.replace(/ø/g, "o")
+		.replace(/ł/g, "l")
+		.replace(/đ/g, "d")
+		.replace(/ħ/g, "h");
+}
+
+// Whether a name, lowercase and normalised, may match the query
+function mayMatch(lower, norm, query) {
+	const { queryLower, queryNorm, tokens } = query;
+	return (
+		lower.includes(queryLower) ||
+		(queryNorm !== "" && (norm.includes(queryNorm) || (norm !== "" && queryNorm.includes(norm)))) ||
+		(tokens.length > 0 && (norm.startsWith(tokens[0]) || tokens.every((t) => norm.includes(t))))
+	);
+}
+
+function search(names, text) {
+	const queryLower = text.toLowerCase();
+	const query = {
+		queryLower,
+		queryNorm: normalize(text),
+		tokens: queryLower
+			.split(/\s+/)
+			.map(normalize)
+			.filter((t) => /[\p{Alphabetic}\p{N}]/u.test(t)),
+	};
+	const hits = [];
+	names.forEach((forms, index) => {
+		if (forms.some(([lower, norm]) => mayMatch(lower, norm, query))) {
+			hits.push(index);
+		}
+	});
+	return hits;
+}
+
+self.addEventListener("message", (event) => {
+	const message = event.data;
+	if (message.type === "index") {
+		if (message.drop) {
+			lists.delete(message.drop);
+		}
+		lists.set(
+			message.key,
+			message.names.map((names) => names.map((name) => [name.toLowerCase(), normalize(name)])),
+		);
+	} else if (message.type === "search") {
+		const names = lists.get(message.key);
+		// An unknown list cannot rule anything out: every exercise may match
+		const hits = names ? search(names, message.query) : null;
+		self.postMessage({ id: message.id, hits });
+	}
+});
@@ -12,34 +12,129 @@
 // The registration of this Service Worker is handled in Rust (src/services/service_worker.rs)
 // following Dioxus best practices - only the worker script itself must be JavaScript.
 
-const CACHE_VERSION = "v3";
+// The app-shell cache is named after the version passed at registration
+// (`sw.js?v=<crate version>`), so every release gets a fresh cache and the
+// previous one is deleted on activation.
+const CACHE_VERSION =
+	new URL(self.location.href).searchParams.get("v") || "dev";
 const APP_CACHE_NAME = `logout-app-${CACHE_VERSION}`;
 const IMAGE_CACHE_NAME = "workout-images-v1";
-const IMAGE_BASE_URL =
-	"https://raw.githubusercontent.com/yuhonas/free-exercise-db/main/exercises/";
+// List of the images of performed exercises, the only ones the image cache
+// keeps (see the "offline-images" message below), stored in that cache so it
+// survives the worker being stopped
+const OFFLINE_IMAGES_URL = new URL("./offline-images.json", self.registration.scope).href;
+let offlineImages = null;
+
+// Promise of the set of image URLs to keep offline
+function offlineImageSet() {
+	if (!offlineImages) {
+		offlineImages = caches
+			.open(IMAGE_CACHE_NAME)
+			.then((cache) => cache.match(OFFLINE_IMAGES_URL))
+			.then((stored) => (stored ? stored.json() : []))
+			.then((urls) => new Set(urls))
+			.catch(() => new Set());
+	}
+	return offlineImages;
+}
+
+// Keep exactly the images in `urls`, downloading the missing ones of `download`
+async function syncOfflineImages(urls, download) {
+	const cache = await caches.open(IMAGE_CACHE_NAME);
+	await cache.put(OFFLINE_IMAGES_URL, new Response(JSON.stringify([...urls])));
+	const keys = await cache.keys();
+	await Promise.all(
+		keys
+			.filter((request) => request.url !== OFFLINE_IMAGES_URL && !urls.has(request.url))
+			.map((request) => cache.delete(request)),
+	);
+	const cached = new Set(keys.map((request) => request.url));
+	await precache(cache, download.filter((url) => !cached.has(url)));
+}
 
 // App shell assets to pre-cache for offline use
 const APP_SHELL_URLS = [
 	"./",
 	"./manifest.json",
+	"./favicon.ico",
+	"./icon-192.png",
+	"./icon-512.png",
 	"./wasm/log-out.js",
 	"./wasm/log-out_bg.wasm",
+	"./search-worker.js",
 ];
 
-// Install event - pre-cache the app shell
+// Same-origin URLs referenced by `src` / `href` attributes of the index page,
+// i.e. the hashed WASM bundle, its JS glue, preloads and global stylesheets.
+function referencedAssets(html) {
+	const urls = new Set();
+	for (const match of html.matchAll(/(?:src|href)="([^"]+)"/g)) {
+		const url = new URL(match[1], self.registration.scope);
+		if (url.origin === self.location.origin && !url.pathname.endsWith("/sw.js")) {
+			urls.add(url.href);
+		}
+	}
+	return [...urls];
+}
+
+// Add URLs one by one so a single missing asset does not abort the others
+function precache(cache, urls) {
+	return Promise.all(
+		urls.map((url) =>
+			cache.add(url).catch((err) => {
+				console.warn("Service Worker: Could not pre-cache", url, err);
+			}),
+		),
+	);
+}
+
+// Install event - pre-cache the app shell and everything the index references
 self.addEventListener("install", (event) => {
-	console.log("Service Worker: Installing...");
+	console.log("Service Worker: Installing", CACHE_VERSION);
 	event.waitUntil(
-		caches.open(APP_CACHE_NAME).then((cache) => {
-			return cache.addAll(APP_SHELL_URLS).catch((err) => {
-				console.warn(
-					"Service Worker: Could not pre-cache some shell assets:",
-					err,
-				);
-			});
+		caches.open(APP_CACHE_NAME).then(async (cache) => {
+			await precache(cache, APP_SHELL_URLS);
+			const index = await cache.match("./");
+			if (index) {
+				await precache(cache, referencedAssets(await index.clone().text()));
+			}
 		}),
 	);
-	self.skipWaiting();
+	// First install takes over immediately; updates wait for the user to
+	// accept the in-app "Update available" prompt (see "skip-waiting" below).
+	if (!self.registration.active) {
+		self.skipWaiting();
+	}
+});
+
+// Message event - pre-cache resources the page loaded at runtime, update the
+// images kept offline, or activate this waiting worker when the user accepted
+// the update
+self.addEventListener("message", (event) => {
+	if (event.data && event.data.type === "skip-waiting") {
+		self.skipWaiting();
+		return;
+	}
+	if (event.data && event.data.type === "offline-images" && Array.isArray(event.data.urls)) {
+		const urls = new Set(event.data.urls);
+		offlineImages = Promise.resolve(urls);
+		const download = Array.isArray(event.data.download) ? event.data.download : [];
+		event.waitUntil(syncOfflineImages(urls, download));
+		return;
+	}
+	if (event.data && event.data.type === "precache" && Array.isArray(event.data.urls)) {
+		event.waitUntil(
+			caches.open(APP_CACHE_NAME).then((cache) =>
+				cache.keys().then((keys) => {
+					const cached = new Set(keys.map((request) => request.url));
+					return precache(
+						cache,
+						event.data.urls.filter((url) => !cached.has(url)),
+					);
+				}),
+			),
+		);
+	}
 });
 
 // Activate event - clean up old caches
@@ -64,26 +159,27 @@ self.addEventListener("activate", (event) => {
 self.addEventListener("fetch", (event) => {
 	const url = event.request.url;
 
-	// Exercise images: cache-first (immutable CDN assets)
-	if (url.startsWith(IMAGE_BASE_URL)) {
+	// Exercise images: cache-first (immutable CDN assets), only those of
+	// performed exercises being added to the cache
+	if (event.request.destination === "image" && !url.startsWith(self.location.origin)) {
 		event.respondWith(
-			caches.open(IMAGE_CACHE_NAME).then((cache) => {
-				return cache.match(event.request).then((cachedResponse) => {
-					if (cachedResponse) {
-						return cachedResponse;
-					}
-					return fetch(event.request)
-						.then((response) => {
-							if (response && response.status === 200) {
-								cache.put(event.request, response.clone());
-							}
-							return response;
-						})
-						.catch((error) => {
-							console.error("Service Worker: Fetch failed for", url, error);
-							throw error;
-						});
+			caches.open(IMAGE_CACHE_NAME).then(async (cache) => {
+				const cachedResponse = await cache.match(url);
+				if (cachedResponse) {
+					return cachedResponse;
+				}
+				if (!(await offlineImageSet()).has(url)) {
+					return fetch(event.request);
+				}
+				// A CORS request, as opaque responses take far more quota
+				const response = await fetch(url, { mode: "cors" }).catch((error) => {
+					console.error("Service Worker: Fetch failed for", url, error);
+					throw error;
 				});
+				if (response && response.status === 200) {
+					cache.put(url, response.clone());
+				}
+				return response;
 			}),
 		);
 		return;
@@ -0,0 +1,99 @@
+use crate::components::{ActiveTab, BottomNav};
+use crate::datetime;
+use crate::services::achievements::{self, Achievement, Milestone, Records, ACHIEVEMENTS};
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+use std::collections::VecDeque;
+/// Icon and title of a badge, e.g. "🔥 12-week streak".
+pub(crate) fn achievement_label(achievement: &Achievement) -> String {
+    match achievement.milestone {
+        Milestone::Sessions(count) => format!("🏅 {}", t!("achievement-sessions", count: count)),
+        Milestone::WeeklyVolumeKg(kg) => {
+            format!("🏋️ {}", t!("achievement-weekly-volume", kg: kg))
+        }
+        Milestone::WeekStreak(weeks) => format!("🔥 {}", t!("achievement-streak", weeks: weeks)),
+    }
+}
+/// Congratulate the user on each of the newly unlocked `earned` badges.
+fn toast_unlocked(mut toast: Signal<VecDeque<String>>, earned: &[Achievement]) {
+    for achievement in earned {
+        toast.write().push_back(t!(
+            "achievement-unlocked",
+            badge: achievement_label(achievement)
+        ));
+    }
+}
+/// Check the badges against the stored sessions (and `just_finished`) in
+/// the background, toasting every newly unlocked one.
+pub(crate) fn check_achievements(just_finished: Option<crate::models::WorkoutSession>) {
+    let toast = consume_context::<ToastSignal>().0;
+    // Outlive the finished session's view, which unmounts right away.
+    dioxus::core::spawn_forever(async move {
+        let (_, earned) = achievements::check(just_finished).await;
+        toast_unlocked(toast, &earned);
+    });
+}
+/// Badges page: the unlocked badges with their date, then the locked ones
+/// with the progress towards them.
+#[component]
+pub fn Achievements() -> Element {
+    let lang = use_memo(move || i18n().language().to_string());
+    let mut records = use_signal(|| None::<Records>);
+    let mut unlocked = use_signal(achievements::load_unlocked);
+    let toast = use_context::<ToastSignal>().0;
+    use_hook(|| {
+        spawn(async move {
+            let (measured, earned) = achievements::check(None).await;
+            toast_unlocked(toast, &earned);
+            records.set(Some(measured));
+            unlocked.set(achievements::load_unlocked());
+        });
+    });
+    let rows: Vec<(&'static str, String, String, bool)> = ACHIEVEMENTS
+        .iter()
+        .map(|achievement| {
+            let label = achievement_label(achievement);
+            let target = achievement.milestone.target();
+            match unlocked.read().get(achievement.id) {
+                Some(&at) => {
+                    let date = datetime::format_short_date(at, &lang.read());
+                    (
+                        achievement.id,
+                        label,
+                        t!("achievement-unlocked-at", date: date),
+                        true,
+                    )
+                }
+                None => {
+                    let progress = records.read().map_or_else(String::new, |r| {
+                        format!("{} / {target}", r.value(achievement.milestone).min(target))
+                    });
+                    (achievement.id, label, progress, false)
+                }
+            }
+        })
+        .collect();
+    rsx! {
+        Stylesheet { href: asset!("/assets/more.scss") }
+        header {
+            h1 { {t!("achievements-title")} }
+        }
+        main { class: "more achievements",
+            p { {t!("achievements-desc")} }
+            for (id, label, detail, is_unlocked) in rows {
+                article { key: "{id}", class: if is_unlocked { "unlocked" } else { "locked" },
+                    h2 {
+                        if !is_unlocked {
+                            "🔒 "
+                        }
+                        "{label}"
+                    }
+                    p { "{detail}" }
+                }
+            }
+        }
+        BottomNav { active_tab: ActiveTab::More }
+    }
+}
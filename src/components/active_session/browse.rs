@@ -0,0 +1,142 @@
+use crate::components::exercise_card::translate_enum;
+use crate::models::{Exercise, Muscle};
+use crate::DbI18nSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+/// `exercises` grouped by primary muscle, in muscle order, each sorted by
+/// name in `lang`.  An exercise working several muscles is in each group.
+fn group_by_muscle(exercises: &[Arc<Exercise>], lang: &str) -> Vec<(Muscle, Vec<Arc<Exercise>>)> {
+    let mut groups: BTreeMap<Muscle, Vec<Arc<Exercise>>> = BTreeMap::new();
+    for ex in exercises {
+        for muscle in &ex.primary_muscles {
+            groups.entry(*muscle).or_default().push(Arc::clone(ex));
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(muscle, mut list)| {
+            list.sort_by_cached_key(|ex| ex.name_for_lang(lang).to_lowercase());
+            (muscle, list)
+        })
+        .collect()
+}
+
+/// Browse mode of the session exercise picker, for when the muscle is known
+/// but not the exercise name: `exercises` in collapsible sections by primary
+/// muscle.  Only the unfolded sections are rendered.  Fires `on_pick` with
+/// the ID of the exercise tapped; `picked` marks the ones already selected
+/// in multi-select mode.
+#[component]
+pub fn MuscleBrowser(
+    exercises: ReadSignal<Vec<Arc<Exercise>>>,
+    picked: Option<Vec<String>>,
+    on_pick: EventHandler<String>,
+) -> Element {
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    let lang = use_memo(move || i18n().language().to_string());
+    let groups = use_memo(move || group_by_muscle(&exercises.read(), &lang.read()));
+    let mut open = use_signal(BTreeSet::<Muscle>::new);
+    rsx! {
+        section { class: "browse", aria_label: t!("session-browse-aria"),
+            for (muscle, list) in groups() {
+                details { key: "{muscle}", open: open.read().contains(&muscle),
+                    summary {
+                        onclick: move |evt| {
+                            evt.prevent_default();
+                            let mut open = open.write();
+                            if !open.remove(&muscle) {
+                                open.insert(muscle);
+                            }
+                        },
+                        {translate_enum(&db_i18n.read(), &lang.read(), "muscles", muscle.as_ref()).to_owned()}
+                        " ({list.len()})"
+                    }
+                    if open.read().contains(&muscle) {
+                        ul { class: "results",
+                            for ex in list {
+                                li {
+                                    key: "{ex.id}",
+                                    role: "button",
+                                    tabindex: 0,
+                                    class: if picked.as_ref().is_some_and(|ids| ids.contains(&ex.id)) { "selected" },
+                                    onclick: {
+                                        let id = ex.id.clone();
+                                        move |_| on_pick.call(id.clone())
+                                    },
+                                    onkeydown: {
+                                        let id = ex.id.clone();
+                                        move |evt: KeyboardEvent| {
+                                            if evt.key() == Key::Enter || evt.key() == Key::Character(" ".into()) {
+                                                evt.prevent_default();
+                                                on_pick.call(id.clone());
+                                            }
+                                        }
+                                    },
+                                    if let Some(ids) = picked.as_ref() {
+                                        input {
+                                            r#type: "checkbox",
+                                            tabindex: -1,
+                                            checked: ids.contains(&ex.id),
+                                            aria_label: t!("session-pick-title"),
+                                        }
+                                    }
+                                    span { "{ex.name_for_lang(&lang.read())}" }
+                                    span { class: "category", "{ex.category}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Category;
+    fn exercise(id: &str, primary: Vec<Muscle>) -> Arc<Exercise> {
+        Arc::new(Exercise {
+            id: id.into(),
+            name: id.into(),
+            name_lower: id.into(),
+            force: None,
+            level: None,
+            mechanic: None,
+            equipment: None,
+            primary_muscles: primary,
+            secondary_muscles: vec![],
+            instructions: vec![],
+            category: Category::Strength,
+            images: vec![],
+            videos: vec![],
+            i18n: None,
+            rest_seconds: None,
+        })
+    }
+    #[test]
+    fn exercises_are_listed_under_each_primary_muscle() {
+        let exercises = vec![
+            exercise("squat", vec![Muscle::Quadriceps, Muscle::Glutes]),
+            exercise("lunge", vec![Muscle::Quadriceps]),
+            exercise("plank", vec![]),
+        ];
+        let groups = group_by_muscle(&exercises, "en");
+        let ids: Vec<(Muscle, Vec<&str>)> = groups
+            .iter()
+            .map(|(m, list)| (*m, list.iter().map(|e| e.id.as_str()).collect()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                (Muscle::Glutes, vec!["squat"]),
+                (Muscle::Quadriceps, vec!["lunge", "squat"]),
+            ]
+        );
+    }
+}
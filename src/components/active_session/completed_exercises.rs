@@ -63,10 +63,11 @@ pub fn CompletedExercisesSection(
             }
             {
                 rsx! {
-                    for (idx, log) in session.read().exercise_logs.iter().enumerate().rev() {
+                    // Keyed by start time, which identifies a log within its session, so
+                    // that deleting a log leaves the others' edit state in place.
+                    for log in session.read().exercise_logs.iter().rev() {
                         CompletedExerciseLog {
-                            key: "{idx}",
-                            idx,
+                            key: "{log.start_time}",
                             log: log.clone(),
                             session,
                             show_replay: no_exercise_active,
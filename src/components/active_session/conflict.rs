@@ -0,0 +1,76 @@
+use crate::datetime;
+use crate::models::{get_current_timestamp, WorkoutSession};
+use crate::services::storage;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+
+/// Warning shown above the session view when several sessions are active at
+/// once (e.g. after a sync), which the session view cannot tell apart: it
+/// offers to merge them all into the earliest one or to finish any of them.
+#[component]
+pub fn ActiveSessionsConflict() -> Element {
+    let sessions = storage::use_sessions();
+    let active = use_memo(move || {
+        let mut active: Vec<WorkoutSession> = sessions
+            .read()
+            .iter()
+            .filter(|s| s.is_active())
+            .cloned()
+            .collect();
+        active.sort_by_key(|s| s.start_time);
+        active
+    });
+    let lang = use_memo(move || i18n().language().to_string());
+    if active.read().len() < 2 {
+        return rsx! {};
+    }
+    let finish = move |id: String| {
+        let Some(mut s) = active.peek().iter().find(|s| s.id == id).cloned() else {
+            return;
+        };
+        if s.is_paused() {
+            s.resume();
+        }
+        s.end_time = Some(
+            s.exercise_logs
+                .iter()
+                .filter_map(|l| l.end_time)
+                .max()
+                .unwrap_or_else(get_current_timestamp),
+        );
+        storage::save_session(s);
+    };
+    rsx! {
+        article { class: "conflict", role: "alert",
+            h2 { {t!("active-conflict-title")} }
+            p { {t!("active-conflict-desc", count: active.read().len())} }
+            ul {
+                for session in active.read().iter() {
+                    li { key: "{session.id}",
+                        time {
+                            {datetime::format_short_date(session.start_time, &lang.read())}
+                            " "
+                            {datetime::format_clock_time(session.start_time)}
+                        }
+                        " · "
+                        {t!("active-conflict-logs", count: session.exercise_logs.len())}
+                        button {
+                            class: "label less",
+                            onclick: {
+                                let id = session.id.clone();
+                                move |_| finish(id.clone())
+                            },
+                            {t!("active-conflict-finish-btn")}
+                        }
+                    }
+                }
+            }
+            button {
+                class: "label save",
+                onclick: move |_| storage::merge_active_sessions(),
+                {t!("active-conflict-merge-btn")}
+            }
+        }
+    }
+}
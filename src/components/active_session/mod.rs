@@ -1,13 +1,24 @@
 use super::session_exercise_form::ExerciseFormPanel;
+use crate::components::achievements::check_achievements;
+use crate::components::confirm;
+use crate::components::exercise_card::{Highlighted, MatchSubtitle};
+use crate::components::goals::celebrate_goals_met;
+use crate::components::plateau::warn_if_plateaued;
+use crate::components::{EquipmentProfileSelect, SessionLocationSelect};
 use crate::models::{
-    get_current_timestamp, parse_distance_km, parse_weight_kg, Category, ExerciseLog, Force,
-    Weight, WorkoutSession, HG_PER_KG, M_PER_KM,
+    format_time, get_current_timestamp, is_hidden, parse_distance_km, parse_weight_kg, Category,
+    ExerciseLog, Force, SetKind, Side, Weight, WorkoutSession,
 };
 use crate::services::exercise_db::{
-    detect_filter_suggestions, exercise_matches_filters, SearchFilter,
+    detect_filter_suggestions, exercise_matches_filters, MatchField, SearchFilter,
 };
-use crate::services::{exercise_db, storage};
-use crate::{RestDurationSignal, Route};
+use crate::services::recovery;
+use crate::services::shortcuts::{self, Shortcut};
+use crate::services::timer_service::{self, TimerKind};
+use crate::services::tour::Hint;
+use crate::services::tray::{self, TrayAction};
+use crate::services::{background_search, drafts, exercise_db, storage};
+use crate::{FlowModeSignal, RestDurationSignal, Route};
 use dioxus::prelude::*;
 use dioxus_i18n::prelude::i18n;
 use dioxus_i18n::t;
@@ -19,15 +30,21 @@ use std::sync::{
     Arc,
 };
 
+mod browse;
 mod completed_exercises;
+mod conflict;
 mod header;
 mod pending_exercises;
 mod rest_input;
+mod tour;
 
+pub use browse::MuscleBrowser;
 pub use completed_exercises::CompletedExercisesSection;
+pub use conflict::ActiveSessionsConflict;
 pub use header::SessionHeader;
 pub use pending_exercises::PendingExercisesSection;
 pub use rest_input::RestDurationInput;
+pub use tour::TourTip;
 
 /// Maximum number of simultaneously active hard filters in the session search.
 const MAX_FILTERS: usize = 4;
@@ -40,18 +57,51 @@ const MAX_TEXT_SEARCH_RESULTS: usize = 10;
 /// Default rest time in seconds offered to the user in the rest input form.
 const DEFAULT_REST_SECONDS: u64 = 30;
 
-/// Prefill the weight / reps / distance inputs from the last recorded log for
-/// `exercise_id`, or clear them if no prior log exists.
+/// Input values of the exercise in progress, kept as a draft so a reload
+/// mid-exercise restores the exact form.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct InputsDraft {
+    /// Start time of the exercise the inputs belong to.
+    exercise_start: u64,
+    weight: String,
+    reps: String,
+    distance: String,
+    side: Option<Side>,
+    assisted: bool,
+    set_kind: SetKind,
+    target: String,
+}
+
+/// Draft key of the in-progress inputs of the session `session_id`.
+fn inputs_draft_key(session_id: &str) -> String {
+    format!("exercise-inputs:{session_id}")
+}
+
+/// Prefill the weight / reps / distance inputs (and whether the weight is
+/// assistance) from the last recorded log for `exercise_id`, or clear them if
+/// no prior log exists.  `location_log`, the
+/// last log of the exercise at the session's location, beats older sessions
+/// elsewhere.
 fn prefill_inputs_from_last_log(
     exercise_id: &str,
+    location_log: Option<&ExerciseLog>,
     mut weight_input: Signal<String>,
     mut reps_input: Signal<String>,
     mut distance_input: Signal<String>,
+    mut assisted_input: Signal<bool>,
+    lang: &str,
 ) {
     // Most-recent log from the active session (same session).
     let active_log = storage::get_last_exercise_log(exercise_id);
     // Most-recent log info from completed sessions (via cache).
-    let bests = storage::get_exercise_bests(exercise_id);
+    let mut bests = storage::get_exercise_bests(exercise_id);
+    if let Some(log) = location_log {
+        bests.last_weight_hg = (log.weight_hg.0 > 0).then_some(log.weight_hg);
+        bests.last_reps = log.reps;
+        bests.last_distance_m = log.distance_m;
+        bests.last_assisted = log.assisted;
+        bests.last_log_end_time = log.end_time;
+    }
 
     // Pick whichever source has the more recent end_time.
     let use_active = match (
@@ -65,34 +115,25 @@ fn prefill_inputs_from_last_log(
 
     if use_active {
         if let Some(last_log) = active_log {
-            if last_log.weight_hg.0 > 0 {
-                weight_input.set(format!(
-                    "{:.1}",
-                    f64::from(last_log.weight_hg.0) / HG_PER_KG
-                ));
-            } else {
-                weight_input.set(String::new());
-            }
-            if let Some(reps) = last_log.reps {
-                reps_input.set(reps.to_string());
-            } else {
-                reps_input.set(String::new());
-            }
-            if let Some(d) = last_log.distance_m {
-                distance_input.set(format!("{:.2}", f64::from(d.0) / M_PER_KM));
-            } else {
-                distance_input.set(String::new());
-            }
+            fill_inputs_from_log(
+                &last_log,
+                weight_input,
+                reps_input,
+                distance_input,
+                assisted_input,
+                lang,
+            );
         } else if bests.last_log_end_time.is_none() {
             // No log anywhere – clear inputs.
             weight_input.set(String::new());
             reps_input.set(String::new());
             distance_input.set(String::new());
+            assisted_input.set(false);
         }
     } else {
         // Use values from the most-recently completed cross-session log.
         if let Some(w) = bests.last_weight_hg {
-            weight_input.set(format!("{:.1}", f64::from(w.0) / HG_PER_KG));
+            weight_input.set(w.input_value(lang));
         } else {
             weight_input.set(String::new());
         }
@@ -102,15 +143,76 @@ fn prefill_inputs_from_last_log(
             reps_input.set(String::new());
         }
         if let Some(d) = bests.last_distance_m {
-            distance_input.set(format!("{:.2}", f64::from(d.0) / M_PER_KM));
+            distance_input.set(d.input_value(lang));
         } else {
             distance_input.set(String::new());
         }
+        assisted_input.set(bests.last_assisted);
     }
 }
 
+/// Set the weight / reps / distance inputs (and whether the weight is
+/// assistance) to the values of `log`.
+fn fill_inputs_from_log(
+    log: &ExerciseLog,
+    mut weight_input: Signal<String>,
+    mut reps_input: Signal<String>,
+    mut distance_input: Signal<String>,
+    mut assisted_input: Signal<bool>,
+    lang: &str,
+) {
+    if log.weight_hg.0 > 0 {
+        weight_input.set(log.weight_hg.input_value(lang));
+    } else {
+        weight_input.set(String::new());
+    }
+    if let Some(reps) = log.reps {
+        reps_input.set(reps.to_string());
+    } else {
+        reps_input.set(String::new());
+    }
+    if let Some(d) = log.distance_m {
+        distance_input.set(d.input_value(lang));
+    } else {
+        distance_input.set(String::new());
+    }
+    assisted_input.set(log.assisted);
+}
+
+/// Side to preselect when starting `exercise_id`: the other side than the
+/// last set of a unilateral movement, so left and right alternate.
+fn next_side(exercise_id: &str) -> Option<Side> {
+    storage::get_last_exercise_log(exercise_id)
+        .and_then(|log| log.side)
+        .map(Side::other)
+}
+
+/// Rest duration (seconds) after the exercise `last_exercise_id` returns, the
+/// last one logged in the session: the preferred rest of that exercise (see
+/// [`exercise_db::preferred_rest`]), otherwise the default rest duration.
+fn use_session_rest(last_exercise_id: impl Fn() -> Option<String> + 'static) -> Memo<u64> {
+    let rest_duration = use_context::<RestDurationSignal>().0;
+    let exercises = exercise_db::use_exercises();
+    let custom_exercises = storage::use_custom_exercises();
+    let overrides = storage::use_exercise_overrides();
+    use_memo(move || {
+        let default = *rest_duration.read();
+        let Some(id) = last_exercise_id() else {
+            return default;
+        };
+        exercise_db::preferred_rest(
+            &exercises.read(),
+            &custom_exercises.read(),
+            &overrides.read(),
+            &id,
+        )
+        .unwrap_or(default)
+    })
+}
+
 #[component]
 pub fn SessionView() -> Element {
+    crate::services::tour::use_tour_view();
     let sessions = storage::use_sessions();
     let session = use_memo(move || {
         sessions
@@ -129,10 +231,82 @@ pub fn SessionView() -> Element {
     let mut reps_input = use_signal(String::new);
     let mut distance_input = use_signal(String::new);
     let mut duration_bell_rung = use_signal(|| false);
+    let mut side_input = use_signal(|| None::<Side>);
+    let mut assisted_input = use_signal(|| false);
+    let mut set_kind_input = use_signal(SetKind::default);
+    let mut target_input = use_signal(String::new);
+    // Whether the rest after the last completed exercise was skipped, for
+    // flow mode to start the next one without waiting.
+    let mut rest_skipped = use_signal(|| false);
+    // Restore the inputs of the exercise in progress before a reload, then
+    // keep them as a draft while it goes on.
+    use_hook(move || {
+        let s = session.peek();
+        let Some(start) = s.current_exercise_start else {
+            return;
+        };
+        let Some(draft) = drafts::load::<InputsDraft>(&inputs_draft_key(&s.id)) else {
+            return;
+        };
+        if draft.exercise_start == start {
+            weight_input.set(draft.weight);
+            reps_input.set(draft.reps);
+            distance_input.set(draft.distance);
+            side_input.set(draft.side);
+            assisted_input.set(draft.assisted);
+            set_kind_input.set(draft.set_kind);
+            target_input.set(draft.target);
+        }
+    });
+    let session_id = use_memo(move || session.read().id.clone());
+    use_effect(move || {
+        let key = inputs_draft_key(&session_id.read());
+        match current_exercise_start() {
+            Some(exercise_start) => drafts::save(
+                &key,
+                &InputsDraft {
+                    exercise_start,
+                    weight: weight_input(),
+                    reps: reps_input(),
+                    distance: distance_input(),
+                    side: side_input(),
+                    assisted: assisted_input(),
+                    set_kind: set_kind_input(),
+                    target: target_input(),
+                },
+            ),
+            None => drafts::clear(&key),
+        }
+    });
     let custom_exercises = storage::use_custom_exercises();
-    let all_exercises = exercise_db::use_exercises();
-    let pending_ids = use_memo(move || session.read().pending_exercise_ids.clone());
+    let overrides = storage::use_exercise_overrides();
+    let taxonomy = storage::use_custom_taxonomy();
+    let equipment_profiles = storage::use_equipment_profiles();
+    let avoid_list = storage::use_avoid_list();
+    let freshness = recovery::use_muscle_freshness();
+    let all_exercises = exercise_db::use_hydrated_exercises();
+    let pending = use_memo(move || session.read().pending_exercises.clone());
+    let pending_ids = use_memo(move || {
+        pending
+            .read()
+            .iter()
+            .map(|p| p.exercise_id.clone())
+            .collect::<Vec<_>>()
+    });
     let lang_str = use_memo(move || i18n().language().to_string());
+    let session_location = use_memo(move || session.read().location.clone());
+    let location_logs = use_resource(move || async move {
+        match session_location() {
+            Some(location) => storage::load_last_logs_at_location(location).await,
+            None => std::collections::HashMap::new(),
+        }
+    });
+    let location_log = move |exercise_id: &str| {
+        location_logs
+            .read()
+            .as_ref()
+            .and_then(|logs| logs.get(exercise_id).cloned())
+    };
     let mut notes_input = use_signal(|| session.read().notes.clone());
     // Track the session ID so we can distinguish between:
     //   (a) the debounce saving the user's own input for the *same* session
@@ -205,7 +379,7 @@ pub fn SessionView() -> Element {
             return Vec::new();
         }
         let current = active_filters.read();
-        detect_filter_suggestions(&query)
+        detect_filter_suggestions(&query, &taxonomy.read())
             .into_iter()
             .filter(|s| !current.contains(s))
             .collect::<Vec<_>>()
@@ -215,22 +389,64 @@ pub fn SessionView() -> Element {
         let custom = custom_exercises.read();
         let all = all_exercises.read();
         let filters = active_filters.read();
-        if filters.is_empty() {
+        let profiles = equipment_profiles.read();
+        let profile = profiles.active_profile();
+        let avoid = avoid_list.read();
+        let today = crate::datetime::today();
+        let hide_avoided = avoid.hide && avoid.is_active_on(today);
+        let overrides = overrides.read();
+        let any_hidden = overrides.values().any(|o| o.hidden);
+        if filters.is_empty() && profile.is_none() && !hide_avoided && !any_hidden {
             return (custom.clone(), all.clone());
         }
-        let filtered_custom: Vec<_> = custom
-            .iter()
-            .filter(|e| exercise_matches_filters(e.as_ref(), &filters))
-            .cloned()
-            .collect();
-        let filtered_all: Vec<_> = all
-            .iter()
-            .filter(|e| exercise_matches_filters(e.as_ref(), &filters))
-            .cloned()
-            .collect();
+        let keep = |e: &&Arc<crate::models::Exercise>| {
+            exercise_matches_filters(e.as_ref(), &filters, &overrides)
+                && profile.is_none_or(|p| p.allows(e))
+                && !(hide_avoided && avoid.flags(e, today))
+                && !is_hidden(&overrides, &e.id)
+        };
+        let filtered_custom: Vec<_> = custom.iter().filter(keep).cloned().collect();
+        let filtered_all: Vec<_> = all.iter().filter(keep).cloned().collect();
         (filtered_custom, filtered_all)
     });
 
+    // Browse mode: the exercises allowed by the filters, grouped by muscle,
+    // instead of search results.
+    let mut browsing = use_signal(|| false);
+    let show_browser = use_memo(move || browsing() && debounced_query.read().is_empty());
+    let browse_pool = use_memo(move || {
+        let (custom_pool, all_pool) = filter_pool();
+        let mut seen_ids = std::collections::HashSet::new();
+        custom_pool
+            .into_iter()
+            .chain(all_pool)
+            .filter(|ex| seen_ids.insert(ex.id.clone()))
+            .collect::<Vec<_>>()
+    });
+
+    // Text matches of the custom then database exercises, searched off the
+    // UI thread (see `background_search`).
+    let text_matches = use_resource(move || {
+        let query = debounced_query();
+        let (custom_pool, all_pool) = filter_pool();
+        let lang = lang_str();
+        let overrides = overrides.read().clone();
+        async move {
+            if query.is_empty() {
+                return None;
+            }
+            let custom = background_search::search(
+                custom_pool,
+                query.clone(),
+                lang.clone(),
+                overrides.clone(),
+            )
+            .await;
+            let all = background_search::search(all_pool, query, lang, overrides).await;
+            Some((custom, all))
+        }
+    });
+
     let search_results = use_memo(move || {
         let query = debounced_query.read();
         let has_query = !query.is_empty();
@@ -238,63 +454,122 @@ pub fn SessionView() -> Element {
         if !has_query && !has_filters {
             return vec![];
         }
-        let (custom_pool, all_pool) = filter_pool();
-        let lang = lang_str.read();
-        let mut results: Vec<Arc<crate::models::Exercise>> = Vec::new();
+        let mut results: Vec<(Arc<crate::models::Exercise>, Option<MatchField>)> = Vec::new();
         let mut seen_ids = std::collections::HashSet::new();
         if has_query {
-            let custom_results = exercise_db::search_exercises(&custom_pool, &query, &lang);
-            for ex in custom_results {
+            let matches = text_matches.read();
+            let Some(Some((custom_matches, db_matches))) = matches.as_ref() else {
+                return vec![];
+            };
+            for (ex, field) in custom_matches {
                 if seen_ids.insert(ex.id.clone()) {
-                    results.push(Arc::clone(ex));
+                    results.push((Arc::clone(ex), Some(field.clone())));
                 }
             }
-            let db_results = exercise_db::search_exercises(&all_pool, &query, &lang);
-            for ex in db_results.into_iter().take(MAX_TEXT_SEARCH_RESULTS) {
+            for (ex, field) in db_matches.iter().take(MAX_TEXT_SEARCH_RESULTS) {
                 if seen_ids.insert(ex.id.clone()) {
-                    results.push(Arc::clone(ex));
+                    results.push((Arc::clone(ex), Some(field.clone())));
                 }
             }
         } else {
+            let (custom_pool, all_pool) = filter_pool();
             for ex in &custom_pool {
                 if seen_ids.insert(ex.id.clone()) {
-                    results.push(Arc::clone(ex));
+                    results.push((Arc::clone(ex), None));
                 }
             }
             for ex in all_pool.iter().take(MAX_FILTER_ONLY_RESULTS) {
                 if seen_ids.insert(ex.id.clone()) {
-                    results.push(Arc::clone(ex));
+                    results.push((Arc::clone(ex), None));
                 }
             }
         }
+        // Exercises for muscles still recovering go last, keeping relevance
+        // order otherwise.
+        let freshness = freshness.read();
+        results.sort_by_key(|(ex, _)| recovery::is_tired(ex, &freshness));
         results
     });
 
     let mut start_exercise = move |exercise_id: String| {
-        prefill_inputs_from_last_log(&exercise_id, weight_input, reps_input, distance_input);
+        prefill_inputs_from_last_log(
+            &exercise_id,
+            location_log(&exercise_id).as_ref(),
+            weight_input,
+            reps_input,
+            distance_input,
+            assisted_input,
+            &i18n().language().to_string(),
+        );
+        side_input.set(next_side(&exercise_id));
+        set_kind_input.set(SetKind::Normal);
+        target_input.set(
+            storage::get_exercise_bests(&exercise_id)
+                .duration
+                .map(format_time)
+                .unwrap_or_default(),
+        );
         let exercise_start = get_current_timestamp();
         search_query.set(String::new());
         debounced_query.set(String::new());
         active_filters.write().clear();
         duration_bell_rung.set(false);
+        warn_if_plateaued(exercise_id.clone());
         storage::begin_exercise_in_session(exercise_id, exercise_start);
     };
 
-    let complete_exercise = move |()| {
+    // Batch planning: `Some` with the search results ticked, in tap order, to
+    // add them all to the pending exercises at once.
+    let mut picked = use_signal(|| None::<Vec<String>>);
+    let mut pick_or_start = move |exercise_id: String| {
+        let picking = picked.peek().is_some();
+        if picking {
+            if let Some(ids) = picked.write().as_mut() {
+                if let Some(i) = ids.iter().position(|id| *id == exercise_id) {
+                    ids.remove(i);
+                } else {
+                    ids.push(exercise_id);
+                }
+            }
+        } else {
+            start_exercise(exercise_id);
+        }
+    };
+    let mut toast = use_context::<crate::ToastSignal>().0;
+    let add_picked = move |_| {
+        let Some(ids) = picked.write().take() else {
+            return;
+        };
+        let count = ids.len();
+        storage::add_pending_exercises_to_session(ids);
+        search_query.set(String::new());
+        debounced_query.set(String::new());
+        toast
+            .write()
+            .push_back(t!("session-pick-added", count: count).to_string());
+    };
+
+    // Rest planned after the exercise in progress, recorded on its log.
+    let planned_rest = use_session_rest(move || current_exercise_id.read().clone());
+    let mut complete_exercise = move |()| {
         let Some(exercise_id) = current_exercise_id() else {
             return;
         };
         let start_time = current_exercise_start().unwrap_or_else(get_current_timestamp);
-        let (exercise_name, category, force) = {
+        let (exercise_name, category, force, bodyweight) = {
             let all = all_exercises.read();
             let custom = custom_exercises.read();
             if let Some(ex) = exercise_db::resolve_exercise(&all, &custom, &exercise_id) {
-                (ex.name.clone(), ex.category, ex.force)
+                (ex.name.clone(), ex.category, ex.force, ex.is_bodyweight())
             } else {
                 return;
             }
         };
         let end_time = get_current_timestamp();
+        // No rest to time after e.g. a long run or a stretch, if so set.
+        let skip_rest = storage::use_rest_skip_categories()
+            .peek()
+            .contains(&category);
         let weight_hg = if category == Category::Stretching {
             Weight::default()
         } else {
@@ -320,56 +595,186 @@ pub fn SessionView() -> Element {
             reps,
             distance_m,
             force,
+            gps_track: Vec::new(),
+            hr_samples: Vec::new(),
+            notes: String::new(),
+            side: *side_input.read(),
+            assisted: bodyweight && weight_hg.0 > 0 && *assisted_input.read(),
+            body_weight_hg: bodyweight.then(storage::current_body_weight).flatten(),
+            set_kind: *set_kind_input.read(),
+            planned_rest_s: Some(if skip_rest { 0 } else { planned_rest() }),
         };
-        storage::append_exercise_log(log);
+        celebrate_goals_met(&log, &log.exercise_name);
+        storage::append_exercise_log(log, !skip_rest);
+        rest_skipped.set(skip_rest);
         weight_input.set(String::new());
         reps_input.set(String::new());
         distance_input.set(String::new());
         duration_bell_rung.set(false);
     };
 
+    // Whether the exercise in progress was logged before, to offer completing
+    // it again with the same values in one tap.
+    let has_last_log = use_memo(move || {
+        current_exercise_id().is_some_and(|id| storage::get_last_exercise_log(&id).is_some())
+    });
+    let complete_same_as_last = move |()| {
+        let Some(last_log) =
+            current_exercise_id().and_then(|id| storage::get_last_exercise_log(&id))
+        else {
+            return;
+        };
+        fill_inputs_from_log(
+            &last_log,
+            weight_input,
+            reps_input,
+            distance_input,
+            assisted_input,
+            &i18n().language().to_string(),
+        );
+        complete_exercise(());
+    };
+
     let cancel_exercise = move |()| {
-        weight_input.set(String::new());
-        reps_input.set(String::new());
-        distance_input.set(String::new());
-        storage::cancel_exercise_in_session();
+        spawn(async move {
+            if !confirm(t!("confirm-discard-exercise"), t!("confirm-discard-btn")).await {
+                return;
+            }
+            weight_input.set(String::new());
+            reps_input.set(String::new());
+            distance_input.set(String::new());
+            storage::cancel_exercise_in_session();
+        });
     };
 
+    let start_pending = use_callback(move |exercise_id: String| {
+        prefill_inputs_from_last_log(
+            &exercise_id,
+            location_log(&exercise_id).as_ref(),
+            weight_input,
+            reps_input,
+            distance_input,
+            assisted_input,
+            &i18n().language().to_string(),
+        );
+        // Targets planned for the exercise beat its last values.
+        if let Some(planned) = pending.peek().iter().find(|p| p.exercise_id == exercise_id) {
+            if let Some(reps) = planned.reps {
+                reps_input.set(reps.to_string());
+            }
+            if let Some(weight) = planned.weight_kg.and_then(Weight::from_kg) {
+                weight_input.set(weight.input_value(&i18n().language().to_string()));
+            }
+        }
+        side_input.set(next_side(&exercise_id));
+        set_kind_input.set(SetKind::Normal);
+        target_input.set(
+            storage::get_exercise_bests(&exercise_id)
+                .duration
+                .map(format_time)
+                .unwrap_or_default(),
+        );
+        let pending_start = get_current_timestamp();
+        search_query.set(String::new());
+        debounced_query.set(String::new());
+        active_filters.write().clear();
+        duration_bell_rung.set(false);
+        warn_if_plateaued(exercise_id.clone());
+        storage::start_pending_exercise_in_session(exercise_id, pending_start);
+    });
+    shortcuts::use_shortcut(move |shortcut| {
+        if shortcut == Shortcut::StartNext && current_exercise_id().is_none() {
+            if let Some(next) = pending_ids.peek().first().cloned() {
+                start_pending.call(next);
+            }
+        }
+    });
+    // Flow mode: start the next pending exercise once the rest is over.
+    let flow_mode = use_context::<FlowModeSignal>().0;
+    let rest_duration = use_session_rest(move || {
+        let session = session.read();
+        session.exercise_logs.last().map(|l| l.exercise_id.clone())
+    });
+    use_coroutine(move |_: UnboundedReceiver<()>| async move {
+        loop {
+            crate::utils::sleep_ms(1_000).await;
+            let skipped = *rest_skipped.peek();
+            if skipped {
+                rest_skipped.set(false);
+            }
+            if !*flow_mode.peek() {
+                continue;
+            }
+            let over = (skipped && session.peek().current_exercise_id.is_none())
+                || crate::services::flow::rest_is_over(
+                    &session.peek(),
+                    *rest_duration.peek(),
+                    get_current_timestamp(),
+                );
+            if over {
+                if let Some(next) = pending_ids.peek().first().cloned() {
+                    start_pending.call(next);
+                }
+            }
+        }
+    });
+
     rsx! {
         Stylesheet { href: asset!("/assets/session.scss") }
         main { class: "session",
+            ActiveSessionsConflict {}
             if current_exercise_id().is_none() && !pending_ids().is_empty() {
                 PendingExercisesSection {
-                    pending_ids: pending_ids(),
-                    on_start: move |exercise_id: String| {
-                        prefill_inputs_from_last_log(
-                            &exercise_id,
-                            weight_input,
-                            reps_input,
-                            distance_input,
-                        );
-                        let pending_start = get_current_timestamp();
-                        search_query.set(String::new());
-                        debounced_query.set(String::new());
-                        active_filters.write().clear();
-                        duration_bell_rung.set(false);
-                        storage::start_pending_exercise_in_session(exercise_id, pending_start);
-                    },
+                    pending: pending(),
+                    on_start: start_pending,
                 }
             }
             if current_exercise_id().is_none() {
                 div { class: "inputs",
                     input {
+                        id: shortcuts::SEARCH_INPUT_ID,
                         r#type: "text",
                         placeholder: t!("session-search-placeholder"),
+                        aria_label: t!("session-search-placeholder"),
                         value: "{search_query}",
                         oninput: move |evt| search_query.set(evt.value()),
                     }
-                    Link {
-                        class: "more",
-                        to: Route::AddExercise {},
-                        title: t!("session-add-exercise-title"),
-                        "+"
+                    EquipmentProfileSelect {}
+                    button {
+                        class: if browsing() { "less" } else { "edit" },
+                        onclick: move |_| browsing.toggle(),
+                        title: t!("session-browse-title"),
+                        aria_pressed: browsing(),
+                        "🗂️"
+                    }
+                    if let Some(ids) = picked.read().as_ref() {
+                        button {
+                            class: "less",
+                            onclick: move |_| picked.set(None),
+                            title: t!("session-pick-cancel"),
+                            "✕"
+                        }
+                        if !ids.is_empty() {
+                            button {
+                                class: "label save",
+                                onclick: add_picked,
+                                title: t!("session-pick-add-title"),
+                                "⏳ {ids.len()}"
+                            }
+                        }
+                    } else {
+                        button {
+                            class: "edit",
+                            onclick: move |_| picked.set(Some(Vec::new())),
+                            title: t!("session-pick-title"),
+                            "☑️"
+                        }
+                        Link {
+                            class: "more",
+                            to: Route::AddExercise {},
+                            title: t!("session-add-exercise-title"),
+                            "+"
+                        }
                     }
                 }
                 if !active_filters.read().is_empty() {
@@ -410,18 +815,67 @@ pub fn SessionView() -> Element {
                         }
                     }
                 }
-                if !search_results().is_empty() {
-                    ul { class: "results",
-                        for ex in search_results() {
+                p { class: "sr-only", role: "status", aria_live: "polite",
+                    if !debounced_query.read().is_empty() || !active_filters.read().is_empty() {
+                        {t!("session-search-results-count", count: search_results().len())}
+                    }
+                }
+                if !search_results().is_empty() && !show_browser() {
+                    ul { class: "results", aria_label: t!("session-search-results-aria"),
+                        for (ex, matched) in search_results() {
                             li {
                                 key: "{ex.id}",
-                                onclick: move |_| start_exercise(ex.id.clone()),
-                                span { "{ex.name_for_lang(&lang_str.read())}" }
+                                role: "button",
+                                tabindex: 0,
+                                class: if picked.read().as_ref().is_some_and(|ids| ids.contains(&ex.id)) { "selected" },
+                                onclick: {
+                                    let id = ex.id.clone();
+                                    move |_| pick_or_start(id.clone())
+                                },
+                                onkeydown: {
+                                    let id = ex.id.clone();
+                                    move |evt: KeyboardEvent| {
+                                        if evt.key() == Key::Enter || evt.key() == Key::Character(" ".into()) {
+                                            evt.prevent_default();
+                                            pick_or_start(id.clone());
+                                        }
+                                    }
+                                },
+                                if let Some(ids) = picked.read().as_ref() {
+                                    input {
+                                        r#type: "checkbox",
+                                        tabindex: -1,
+                                        checked: ids.contains(&ex.id),
+                                        aria_label: t!("session-pick-title"),
+                                    }
+                                }
+                                span {
+                                    Highlighted {
+                                        text: ex.name_for_lang(&lang_str.read()).to_owned(),
+                                        query: debounced_query(),
+                                    }
+                                    if let Some(field) = matched {
+                                        MatchSubtitle { field, query: debounced_query() }
+                                    }
+                                }
+                                if recovery::is_tired(&ex, &freshness.read()) {
+                                    span { class: "tired", title: t!("recovery-tired-title"), "🪫" }
+                                }
+                                if avoid_list.read().flags(&ex, crate::datetime::today()) {
+                                    span { class: "avoid", title: t!("avoid-flag-title"), "⚠️" }
+                                }
                                 span { class: "category", "{ex.category}" }
                             }
                         }
                     }
                 }
+                if show_browser() {
+                    MuscleBrowser {
+                        exercises: browse_pool(),
+                        picked: picked(),
+                        on_pick: move |id: String| pick_or_start(id),
+                    }
+                }
             } else if let Some(exercise_id) = current_exercise_id() {
                 ExerciseFormPanel {
                     exercise_id,
@@ -431,7 +885,12 @@ pub fn SessionView() -> Element {
                     current_exercise_start,
                     duration_bell_rung,
                     paused_at: session.read().paused_at,
+                    side_input,
+                    assisted_input,
+                    set_kind_input,
+                    target_input,
                     on_complete: complete_exercise,
+                    on_same_as_last: has_last_log().then_some(EventHandler::new(complete_same_as_last)),
                     on_cancel: cancel_exercise,
                 }
             }
@@ -442,6 +901,7 @@ pub fn SessionView() -> Element {
                     on_replay: move |exercise_id: String| start_exercise(exercise_id),
                 }
             }
+            SessionLocationSelect { current: session.read().location.clone() }
             textarea {
                 id: "session-notes-input",
                 placeholder: t!("session-notes-placeholder"),
@@ -481,11 +941,17 @@ pub fn GlobalSessionHeader() -> Element {
     let rest_duration = use_context::<RestDurationSignal>().0;
     let mut rest_input_value = use_signal(|| DEFAULT_REST_SECONDS.to_string());
     let mut congratulations = use_context::<crate::CongratulationsSignal>().0;
+    // The rest that applies, per the last exercise logged.
+    let session_rest = use_session_rest(move || {
+        let session = session.read();
+        let log = session.as_ref()?.exercise_logs.last()?;
+        Some(log.exercise_id.clone())
+    });
 
     // A memo that captures the (rest_start_time, rest_duration) pair so the
     // notification effect only re-fires when the rest period actually changes.
     let rest_key = use_memo(move || {
-        let rd = *rest_duration.read();
+        let rd = session_rest();
         session()
             .and_then(|s| s.rest_start_time)
             .map(|start| (start, rd))
@@ -495,7 +961,7 @@ pub fn GlobalSessionHeader() -> Element {
     // Stored as an Arc<AtomicU64> so it can be read/written from both the
     // Dioxus thread and spawned async tasks without Signal's !Send constraint.
     let rest_bell_count = use_hook(|| Arc::new(AtomicU64::new(0)));
-    // Clone used by the tick coroutine (the scheduling effect takes the original).
+    // Clone used by the clock check (the scheduling effect takes the original).
     let bc_tick = rest_bell_count.clone();
 
     // Cancel token for the current one-shot scheduled notification.
@@ -548,10 +1014,9 @@ pub fn GlobalSessionHeader() -> Element {
                 let bc = rest_bell_count.clone();
                 tokio::spawn(async move {
                     tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
-                    if !cancel.load(Ordering::Relaxed) {
-                        // Mark first interval done so the tick coroutine doesn't
-                        // fire a duplicate for interval 1.
-                        bc.store(1, Ordering::Relaxed);
+                    // Mark the first interval done, unless the clock check
+                    // already rang it, so it never rings twice.
+                    if !cancel.load(Ordering::Relaxed) && bc.fetch_max(1, Ordering::Relaxed) == 0 {
                         crate::services::notifications::send_notification(
                             &title,
                             &body,
@@ -578,56 +1043,173 @@ pub fn GlobalSessionHeader() -> Element {
             let bc = rest_bell_count.clone();
             wasm_bindgen_futures::spawn_local(async move {
                 gloo_timers::future::TimeoutFuture::new(delay_ms).await;
-                if !cancel.load(Ordering::Relaxed) {
-                    // Mark first interval done so the tick coroutine doesn't
-                    // fire a duplicate for interval 1.
-                    bc.store(1, Ordering::Relaxed);
+                // Mark the first interval done, unless the clock check already
+                // rang it (e.g. this timeout was throttled in the background),
+                // so it never rings twice.
+                if !cancel.load(Ordering::Relaxed) && bc.fetch_max(1, Ordering::Relaxed) == 0 {
                     crate::services::notifications::send_notification(&title, &body, "logout-rest");
                 }
             });
         }
     });
 
-    // Tick-based coroutine: fires a notification for every completed exceeded
-    // interval (2nd, 3rd, … ring) so the user keeps being reminded.
-    // Also handles the first notification on native (as a fallback).
-    use_coroutine(move |_: UnboundedReceiver<()>| {
-        // Clone inside the FnMut closure so each invocation gets its own Arc.
-        let bc = bc_tick.clone();
-        async move {
-            loop {
-                crate::utils::sleep_ms(1_000).await;
-                // Skip all checks while the session is paused.
-                if session_paused_at.peek().is_some() {
-                    continue;
+    // Clock-driven check: fires a notification for every completed exceeded
+    // interval (2nd, 3rd, … ring) so the user keeps being reminded.  Also
+    // handles the first notification as a fallback.  After the app was in
+    // the background, the intervals missed meanwhile ring once, as soon as
+    // the clock is set on resume.
+    let clock = crate::services::clock::use_clock();
+    use_effect(move || {
+        let now = clock();
+        // Skip all checks while the session is paused.
+        if session_paused_at.peek().is_some() {
+            return;
+        }
+        let Some((start, duration)) = *rest_key.peek() else {
+            return;
+        };
+        if duration == 0 {
+            return;
+        }
+        let intervals = now.saturating_sub(start) / duration;
+        if bc_tick.fetch_max(intervals, Ordering::Relaxed) < intervals {
+            crate::services::notifications::send_notification(
+                &rest_notif_title.peek(),
+                &rest_notif_body.peek(),
+                "logout-rest",
+            );
+        }
+    });
+
+    // Mirror the rest timer in the OS media controls (lock screen), and map
+    // their buttons to skipping / extending the rest.
+    let exercises_all = exercise_db::use_exercises();
+    let custom_exercises = storage::use_custom_exercises();
+    let media_title = use_memo(move || t!("media-rest-title").to_string());
+    let media_next = use_memo(move || {
+        let sess = session()?;
+        let name = match sess.pending_exercises.first() {
+            Some(p) => exercise_db::resolve_exercise(
+                &exercises_all.read(),
+                &custom_exercises.read(),
+                &p.exercise_id,
+            )
+            .map(|e| e.name.clone()),
+            None => sess.exercise_logs.last().map(|l| l.exercise_name.clone()),
+        }?;
+        Some(t!("media-rest-next", exercise: name).to_string())
+    });
+    use_coroutine(move |_: UnboundedReceiver<()>| async move {
+        let mut shown = false;
+        loop {
+            crate::utils::sleep_ms(1_000).await;
+            match *rest_key.peek() {
+                Some((start, duration))
+                    if session_paused_at.peek().is_none()
+                        && crate::services::media_session::media_controls_enabled() =>
+                {
+                    let elapsed = crate::models::get_current_timestamp().saturating_sub(start);
+                    let remaining = duration.cast_signed() - elapsed.cast_signed();
+                    crate::services::media_session::show_rest(
+                        &crate::services::media_session::rest_title(&media_title.peek(), remaining),
+                        &media_next.peek().clone().unwrap_or_default(),
+                    );
+                    shown = true;
                 }
-                let Some((start, duration)) = *rest_key.peek() else {
-                    continue;
-                };
-                if duration == 0 {
-                    continue;
+                _ if shown => {
+                    crate::services::media_session::clear();
+                    shown = false;
                 }
-                let now = crate::models::get_current_timestamp();
-                let elapsed = now.saturating_sub(start);
-                let intervals = elapsed / duration;
-                let prev = bc.load(Ordering::Relaxed);
-                if intervals > prev {
-                    bc.store(intervals, Ordering::Relaxed);
-                    crate::services::notifications::send_notification(
-                        &rest_notif_title.peek(),
-                        &rest_notif_body.peek(),
-                        "logout-rest",
-                    );
+                _ => {}
+            }
+        }
+    });
+    use_coroutine(move |_: UnboundedReceiver<()>| async move {
+        use crate::services::media_session::{self, RestAction};
+        // Leave the media keys to the user's own player unless opted in.
+        if !media_session::media_controls_enabled() {
+            return;
+        }
+        while let Some(action) = media_session::next_action().await {
+            let Some(mut s) = session.peek().clone() else {
+                continue;
+            };
+            let Some(rest_start) = s.rest_start_time else {
+                continue;
+            };
+            match action {
+                RestAction::Skip => s.rest_start_time = None,
+                RestAction::Extend => {
+                    s.rest_start_time = Some(rest_start + media_session::REST_EXTEND_SECONDS);
                 }
             }
+            storage::save_session(s);
         }
     });
-
+    // Keep an ongoing notification (Android foreground service) in sync so the
+    // timers survive the app being backgrounded.  The OS chronometer ticks on
+    // its own; it is only updated when what is being timed changes.
+    let timer_notice =
+        use_memo(move || session().and_then(|s| timer_service::notice_for(&s, session_rest())));
+    let timer_title = use_memo(move || {
+        let notice = timer_notice()?;
+        let sess = session()?;
+        Some(match notice.kind {
+            TimerKind::Rest => match media_next() {
+                Some(next) => format!("{} · {next}", media_title()),
+                None => media_title(),
+            },
+            TimerKind::Exercise => sess
+                .current_exercise_id
+                .as_deref()
+                .and_then(|id| {
+                    exercise_db::resolve_exercise(
+                        &exercises_all.read(),
+                        &custom_exercises.read(),
+                        id,
+                    )
+                    .map(|e| e.name.clone())
+                })
+                .unwrap_or_else(|| t!("timer-session-title").to_string()),
+            TimerKind::Session => t!("timer-session-title").to_string(),
+        })
+    });
+    use_effect(move || match (timer_notice(), timer_title()) {
+        (Some(notice), Some(title)) => timer_service::show(&title, notice),
+        _ => timer_service::hide(),
+    });
     use_effect(move || {
         if *show_rest.read() {
             rest_input_value.set(rest_duration.read().to_string());
         }
     });
+    let mut on_finish = move |()| {
+        let Some(s) = session() else { return };
+        if s.is_cancelled() {
+            spawn(async move {
+                if confirm(t!("confirm-cancel-session"), t!("confirm-discard-btn")).await {
+                    storage::delete_session(&s.id);
+                }
+            });
+        } else {
+            let mut s = s.clone();
+            if s.is_paused() {
+                s.resume();
+            }
+            s.end_time = Some(get_current_timestamp());
+            check_achievements(Some(s.clone()));
+            crate::components::cloud_backup::upload_after_session(s.clone());
+            crate::components::webhook::notify_after_session(&s);
+            storage::save_session(s);
+            congratulations.set(true);
+        }
+    };
+    // Desktop builds mirror the timer in a tray icon, whose menu acts on
+    // the session while the window is minimized.
+    tray::use_session_tray(timer_notice, timer_title, move |action| match action {
+        TrayAction::Complete => shortcuts::dispatch(Shortcut::Complete),
+        TrayAction::Finish => on_finish(()),
+    });
     let Some(sess) = session() else {
         return rsx! {};
     };
@@ -646,20 +1228,6 @@ pub fn GlobalSessionHeader() -> Element {
         }
         storage::save_session(s);
     };
-    let on_finish = move |()| {
-        let Some(s) = session() else { return };
-        if s.is_cancelled() {
-            storage::delete_session(&s.id);
-        } else {
-            let mut s = s.clone();
-            if s.is_paused() {
-                s.resume();
-            }
-            s.end_time = Some(get_current_timestamp());
-            storage::save_session(s);
-            congratulations.set(true);
-        }
-    };
     rsx! {
         SessionHeader {
             session_start_time,
@@ -668,7 +1236,7 @@ pub fn GlobalSessionHeader() -> Element {
             total_paused_duration,
             exercise_count,
             rest_start_time,
-            rest_duration: *rest_duration.read(),
+            rest_duration: session_rest(),
             on_click_timer: move |()| {
                 let current = *show_rest.peek();
                 show_rest.set(!current);
@@ -676,6 +1244,7 @@ pub fn GlobalSessionHeader() -> Element {
             on_pause,
             on_finish,
         }
+        TourTip { hint: Hint::Timer }
         if *show_rest.read() {
             RestDurationInput {
                 show_rest_input: show_rest,
@@ -1,39 +1,68 @@
-use crate::models::Category;
+use crate::models::{Category, PlannedExercise, Weight};
 use crate::services::{exercise_db, storage};
 use dioxus::prelude::*;
 use dioxus_i18n::prelude::i18n;
 use dioxus_i18n::t;
 
-/// List of exercises pre-added to the session that haven't been started yet.
+/// Planned sets left and targets of a pending exercise, e.g. "3 sets left"
+/// and "🎯 5 × 60 kg", as list items.
+fn planned_items(planned: &PlannedExercise, lang: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    if let Some(sets) = planned.sets {
+        items.push(t!("pending-sets-left", count: sets).to_string());
+    }
+    let weight = planned
+        .weight_kg
+        .and_then(Weight::from_kg)
+        .map(|w| w.localized(lang));
+    match (planned.reps, weight) {
+        (Some(reps), Some(weight)) => items.push(format!("🎯 {reps} × {weight}")),
+        (Some(reps), None) => items.push(t!("pending-target-reps", count: reps).to_string()),
+        (None, Some(weight)) => items.push(format!("🎯 {weight}")),
+        (None, None) => {}
+    }
+    items
+}
+
+/// List of exercises pre-added to the session that haven't been started yet,
+/// with their planned sets left and targets.
 /// The first (oldest) exercise is always visible and directly clickable.
 /// Any additional exercises are hidden inside a folded `<details>` dropdown.
 /// Fires `on_start` with the exercise ID when the user taps 🔁.
 #[component]
 pub fn PendingExercisesSection(
-    pending_ids: Vec<String>,
+    pending: Vec<PlannedExercise>,
     on_start: EventHandler<String>,
 ) -> Element {
     let all_exercises = exercise_db::use_exercises();
     let custom_exercises = storage::use_custom_exercises();
     let lang_str = use_memo(move || i18n().language().to_string());
-    let resolved: Vec<(String, String, Category)> = {
+    let resolved: Vec<(String, String, Category, Vec<String>)> = {
         let all = all_exercises.read();
         let custom = custom_exercises.read();
         let lang = lang_str.read();
-        pending_ids
+        pending
             .iter()
-            .map(|id| {
+            .map(|planned| {
+                let id = &planned.exercise_id;
+                let items = planned_items(planned, &lang);
                 if let Some(ex) = exercise_db::resolve_exercise(&all, &custom, id) {
-                    (id.clone(), ex.name_for_lang(&lang).to_owned(), ex.category)
+                    (
+                        id.clone(),
+                        ex.name_for_lang(&lang).to_owned(),
+                        ex.category,
+                        items,
+                    )
                 } else {
-                    (id.clone(), "Unknown".to_string(), Category::Strength)
+                    (id.clone(), "Unknown".to_string(), Category::Strength, items)
                 }
             })
             .collect()
     };
     rsx! {
         section { class: "exercises",
-            if let Some((first_id, first_name, first_cat)) = resolved.first() {
+            super::TourTip { hint: crate::services::tour::Hint::PendingQueue }
+            if let Some((first_id, first_name, first_cat, first_items)) = resolved.first() {
                 {
                     let id = first_id.clone();
                     let name = first_name.clone();
@@ -44,6 +73,9 @@ pub fn PendingExercisesSection(
                                 h4 { "{name}" }
                                 ul {
                                     li { "{cat}" }
+                                    for item in first_items.iter() {
+                                        li { "{item}" }
+                                    }
                                 }
                                 button { class: "edit", onclick: move |_| on_start.call(id.clone()), "🔁" }
                             }
@@ -54,7 +86,7 @@ pub fn PendingExercisesSection(
             if resolved.len() > 1 {
                 details {
                     summary { {t!("pending-more", count : (resolved.len() - 1).to_string())} }
-                    for (id, name, category) in resolved.iter().skip(1).cloned() {
+                    for (id, name, category, items) in resolved.iter().skip(1).cloned() {
                         {
                             let id2 = id.clone();
                             rsx! {
@@ -63,6 +95,9 @@ pub fn PendingExercisesSection(
                                         h4 { "{name}" }
                                         ul {
                                             li { "{category}" }
+                                            for item in items {
+                                                li { "{item}" }
+                                            }
                                         }
                                         button { class: "edit", onclick: move |_| on_start.call(id2.clone()), "🔁" }
                                     }
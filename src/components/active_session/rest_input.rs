@@ -10,7 +10,7 @@ pub fn RestDurationInput(
 ) -> Element {
     rsx! {
         form {
-            class: "inputs",
+            class: "inputs rest-duration",
             aria_label: t!("rest-duration-aria"),
             onsubmit: move |evt| {
                 evt.prevent_default();
@@ -0,0 +1,27 @@
+use crate::services::tour::{self, Hint};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+/// Tip of the session view tour explaining what is next to it, shown when
+/// `hint` is its current step (see [`tour`]).
+#[component]
+pub fn TourTip(hint: Hint) -> Element {
+    let shown = tour::use_hint(hint);
+    if !shown() {
+        return rsx! {};
+    }
+    let text = match hint {
+        Hint::Timer => t!("tour-timer"),
+        Hint::PendingQueue => t!("tour-pending"),
+        Hint::CompleteCancel => t!("tour-complete-cancel"),
+    };
+    rsx! {
+        aside { class: "tour", role: "note", aria_live: "polite",
+            p { "💡 {text}" }
+            div { class: "inputs",
+                button { class: "label yes", onclick: move |_| tour::dismiss(hint), {t!("tour-got-it")} }
+                button { class: "label", onclick: move |_| tour::skip_tour(), {t!("tour-skip")} }
+            }
+        }
+    }
+}
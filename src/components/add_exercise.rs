@@ -1,45 +1,12 @@
-use crate::components::exercise_form_fields::ExerciseFormFields;
-use crate::models::{get_current_timestamp, Category, Equipment, Exercise, Force, Muscle};
+use crate::components::ExerciseForm;
+use crate::models::{get_current_timestamp, Exercise};
 use crate::services::storage;
 use dioxus::prelude::*;
 use dioxus_i18n::t;
 #[component]
 pub fn AddExercise() -> Element {
-    let name_input = use_signal(String::new);
-    let category_input = use_signal(|| Category::Strength);
-    let force_input: Signal<Option<Force>> = use_signal(|| None);
-    let equipment_input: Signal<Option<Equipment>> = use_signal(|| None);
-    let muscle_input = use_signal(String::new);
-    let muscles_list = use_signal(Vec::<Muscle>::new);
-    let secondary_muscle_input = use_signal(String::new);
-    let secondary_muscles_list = use_signal(Vec::<Muscle>::new);
-    let instructions_input = use_signal(String::new);
-    let instructions_list = use_signal(Vec::<String>::new);
-    let image_url_input = use_signal(String::new);
-    let images_list = use_signal(Vec::<String>::new);
     let sessions = storage::use_sessions();
-    let save_exercise = move |()| {
-        let name = name_input.read().trim().to_string();
-        if name.is_empty() {
-            return;
-        }
-        let timestamp = get_current_timestamp();
-        let name_lower = name.to_lowercase();
-        let exercise = Exercise {
-            id: format!("custom_{timestamp}"),
-            name,
-            name_lower,
-            category: *category_input.read(),
-            force: *force_input.read(),
-            level: None,
-            mechanic: None,
-            equipment: *equipment_input.read(),
-            primary_muscles: muscles_list.read().clone(),
-            secondary_muscles: secondary_muscles_list.read().clone(),
-            instructions: instructions_list.read().clone(),
-            images: images_list.read().clone(),
-            i18n: None,
-        };
+    let save_exercise = move |exercise: Exercise| {
         let exercise_id = exercise.id.clone();
         storage::add_custom_exercise(exercise);
         let active = sessions.read().iter().find(|s| s.is_active()).cloned();
@@ -65,19 +32,8 @@ pub fn AddExercise() -> Element {
             }
         }
         main { class: "edit",
-            ExerciseFormFields {
-                name_input,
-                category_input,
-                force_input,
-                equipment_input,
-                muscle_input,
-                muscles_list,
-                secondary_muscle_input,
-                secondary_muscles_list,
-                instructions_input,
-                instructions_list,
-                image_url_input,
-                images_list,
+            ExerciseForm {
+                exercise: None,
                 save_label: t!("exercise-save"),
                 on_save: save_exercise,
             }
@@ -1,3 +1,4 @@
+use crate::components::charting::{linear_trend, padded_domain, LinearScale, YAxis, AXIS_COLOR};
 use crate::models::analytics::{adapt_metric_unit, Metric};
 use dioxus::prelude::*;
 
@@ -92,17 +93,12 @@ pub fn ChartView(data: SeriesData, colors: Vec<&'static str>) -> Element {
             max_x = max_x.max(*x);
         }
     }
-    let scale_x = move |x: f64| -> f64 {
-        if (max_x - min_x).abs() < f64::EPSILON {
-            left_pad + chart_width / 2.0
-        } else {
-            left_pad + (x - min_x) / (max_x - min_x) * chart_width
-        }
-    };
+    let x_scale = LinearScale::new((min_x, max_x), (left_pad, left_pad + chart_width));
+    let scale_x = move |x: f64| x_scale.apply(x);
 
     // ── Per-metric Y-axis data ────────────────────────────────────────────────
-    #[allow(clippy::cast_precision_loss)]
-    let axis_data: [Option<(&'static str, f64, f64, f64)>; 4] = std::array::from_fn(|i| {
+    // (unit, display scale factor, Y scale of the display values)
+    let axis_data: [Option<(&'static str, f64, LinearScale)>; 4] = std::array::from_fn(|i| {
         if !metric_has_data[i] {
             return None;
         }
@@ -113,40 +109,23 @@ pub fn ChartView(data: SeriesData, colors: Vec<&'static str>) -> Element {
             .flat_map(|(_, _, _, pts)| pts.iter().map(|(_, y)| *y))
             .collect();
         let (unit, scale) = adapt_metric_unit(metric, &raw_y);
-        let scaled: Vec<f64> = raw_y.iter().map(|y| y * scale).collect();
-        let s_min = scaled.iter().copied().fold(f64::INFINITY, f64::min);
-        let s_max = scaled.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-        let rng = if (s_max - s_min).abs() < f64::EPSILON {
-            1.0
+        let domain = padded_domain(raw_y.iter().map(|y| y * scale))?;
+        let range = if i < 2 {
+            (chart1_bottom, chart1_top)
         } else {
-            s_max - s_min
+            (chart2_bottom, chart2_top)
         };
-        let min_y = (s_min - rng * 0.1).max(0.0);
-        let max_y = s_max + rng * 0.1;
-        Some((unit, scale, min_y, max_y))
+        Some((unit, scale, LinearScale::new(domain, range)))
     });
 
     let y_svg = |y_display: f64, mi: usize| -> f64 {
-        let Some((_, _, min_y, max_y)) = axis_data[mi] else {
-            return 0.0;
-        };
-        let (ct, cb) = if mi < 2 {
-            (chart1_top, chart1_bottom)
-        } else {
-            (chart2_top, chart2_bottom)
-        };
-        let h = cb - ct;
-        if (max_y - min_y).abs() < f64::EPSILON {
-            ct + h / 2.0
-        } else {
-            ct + h - (y_display - min_y) / (max_y - min_y) * h
-        }
+        axis_data[mi].map_or(0.0, |(_, _, y_scale)| y_scale.apply(y_display))
     };
 
     let i18n = dioxus_i18n::prelude::i18n();
     let format_date = move |ts: f64| -> String {
         #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-        let days = crate::utils::session_days_ago(ts as u64);
+        let days = crate::datetime::session_days_ago(ts as u64);
         match days {
             0 => i18n.translate("date-today"),
             1 => i18n.translate("date-yesterday"),
@@ -167,7 +146,7 @@ pub fn ChartView(data: SeriesData, colors: Vec<&'static str>) -> Element {
                     return None;
                 }
                 let mi = metric.to_index();
-                let (unit, scale, _, _) = axis_data[mi]?;
+                let (unit, scale, _) = axis_data[mi]?;
                 let nearest = points.iter().min_by(|(t1, _), (t2, _)| {
                     (t1 - ts)
                         .abs()
@@ -208,7 +187,7 @@ pub fn ChartView(data: SeriesData, colors: Vec<&'static str>) -> Element {
                 y1: "{chart1_bottom}",
                 x2: "{left_pad + chart_width}",
                 y2: "{chart1_bottom}",
-                stroke: "#555",
+                stroke: AXIS_COLOR,
                 stroke_width: "1",
             }
             if has_chart2 {
@@ -217,75 +196,18 @@ pub fn ChartView(data: SeriesData, colors: Vec<&'static str>) -> Element {
                     y1: "{chart2_bottom}",
                     x2: "{left_pad + chart_width}",
                     y2: "{chart2_bottom}",
-                    stroke: "#555",
+                    stroke: AXIS_COLOR,
                     stroke_width: "1",
                 }
             }
             for i in 0..4_usize {
-                if let Some((unit, _, min_y, max_y)) = axis_data[i] {
-                    {
-                        let is_right = i % 2 == 1;
-                        let x_pos = if is_right { left_pad + chart_width } else { left_pad };
-                        let (ct, cb) = if i < 2 {
-                            (chart1_top, chart1_bottom)
-                        } else {
-                            (chart2_top, chart2_bottom)
-                        };
-                        let tick_x1 = if is_right { x_pos } else { x_pos - 4.0 };
-                        let tick_x2 = if is_right { x_pos + 4.0 } else { x_pos };
-                        let text_x = if is_right { x_pos + 7.0 } else { x_pos - 7.0 };
-                        let text_anchor: &str = if is_right { "start" } else { "end" };
-                        let unit_anchor: &str = if is_right { "start" } else { "middle" };
-                        rsx! {
-                            g { key: "axis_{i}",
-                                line {
-                                    x1: "{x_pos}",
-                                    y1: "{ct}",
-                                    x2: "{x_pos}",
-                                    y2: "{cb}",
-                                    stroke: "#555",
-                                    stroke_width: "1",
-                                    stroke_opacity: "0.7",
-                                }
-                                text {
-                                    x: "{x_pos}",
-                                    y: "{ct - 6.0}",
-                                    text_anchor: "{unit_anchor}",
-                                    font_size: "14",
-                                    font_weight: "bold",
-                                    fill: "#ccc",
-                                    "{unit}"
-                                }
-                                for tick in 0..5_usize {
-                                    {
-                                        #[allow(clippy::cast_precision_loss)]
-                                        let frac = tick as f64 / 4.0;
-                                        let y_val = min_y + (max_y - min_y) * frac;
-                                        let sy = y_svg(y_val, i);
-                                        rsx! {
-                                            g { key: "tick_{tick}",
-                                                line {
-                                                    x1: "{tick_x1}",
-                                                    y1: "{sy}",
-                                                    x2: "{tick_x2}",
-                                                    y2: "{sy}",
-                                                    stroke: "#555",
-                                                    stroke_width: "1",
-                                                }
-                                                text {
-                                                    x: "{text_x}",
-                                                    y: "{sy + 4.0}",
-                                                    text_anchor: "{text_anchor}",
-                                                    font_size: "13",
-                                                    fill: "#bbb",
-                                                    "{y_val:.1}"
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
+                if let Some((unit, _, y_scale)) = axis_data[i] {
+                    YAxis {
+                        key: "axis_{i}",
+                        x: if i % 2 == 1 { left_pad + chart_width } else { left_pad },
+                        scale: y_scale,
+                        unit,
+                        right: i % 2 == 1,
                     }
                 }
             }
@@ -315,26 +237,24 @@ pub fn ChartView(data: SeriesData, colors: Vec<&'static str>) -> Element {
             for (slot_idx, _, metric, points) in data.iter() {
                 {
                     let mi = metric.to_index();
-                    if let Some((_, scale, _, _)) = axis_data[mi] {
+                    if let Some((_, scale, _)) = axis_data[mi] {
                         let color = *colors.get(*slot_idx).unwrap_or(&"#ccc");
                         if points.len() >= 2 {
-                            #[allow(clippy::cast_precision_loss)]
-                            let n = points.len() as f64;
-                            let sum_x: f64 = points.iter().map(|(x, _)| x).sum();
-                            let sum_y: f64 = points.iter().map(|(_, y)| y * scale).sum();
-                            let sum_xx: f64 = points.iter().map(|(x, _)| x * x).sum();
-                            let sum_xy: f64 = points.iter().map(|(x, y)| x * y * scale).sum();
-                            let denom = n * sum_xx - sum_x * sum_x;
-                            let (trend_x1, trend_y1, trend_x2, trend_y2) = if denom.abs()
-                                > f64::EPSILON
+                            let scaled: Vec<(f64, f64)> = points
+                                .iter()
+                                .map(|&(x, y)| (x, y * scale))
+                                .collect();
+                            let (trend_x1, trend_y1, trend_x2, trend_y2) = if let Some(
+                                (slope, intercept),
+                            ) = linear_trend(&scaled)
                             {
-                                let slope = (n * sum_xy - sum_x * sum_y) / denom;
-                                let intercept = (sum_y - slope * sum_x) / n;
                                 let x1 = points.first().map_or(min_x, |(x, _)| *x);
                                 let x2 = points.last().map_or(max_x, |(x, _)| *x);
                                 (x1, slope * x1 + intercept, x2, slope * x2 + intercept)
                             } else {
-                                let mean_y = sum_y / n;
+                                #[allow(clippy::cast_precision_loss)]
+                                let mean_y = scaled.iter().map(|(_, y)| y).sum::<f64>()
+                                    / scaled.len() as f64;
                                 (min_x, mean_y, max_x, mean_y)
                             };
                             Some(rsx! {
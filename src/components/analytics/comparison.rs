@@ -0,0 +1,207 @@
+use crate::models::analytics::{delta_percent, PeriodSummary};
+use crate::models::WorkoutSession;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use time::{Date, Duration};
+
+/// Default periods: this month so far, then the whole previous month.
+fn default_periods(today: Date) -> [(Date, Date); 2] {
+    let this_month = today.replace_day(1).unwrap_or(today);
+    let last_month_end = this_month - Duration::days(1);
+    let last_month = last_month_end.replace_day(1).unwrap_or(last_month_end);
+    [(this_month, today), (last_month, last_month_end)]
+}
+
+/// Row of the comparison table: label, value in each period and the change
+/// from the second period to the first.
+#[derive(Clone, PartialEq)]
+struct ComparisonRow {
+    label: String,
+    current: String,
+    previous: String,
+    delta: Option<f64>,
+}
+
+impl ComparisonRow {
+    fn new(label: String, current: Option<f64>, previous: Option<f64>, unit: &str) -> Self {
+        let format = |v: Option<f64>| v.map_or_else(|| "–".to_owned(), |v| format!("{v:.1}{unit}"));
+        Self {
+            label,
+            current: format(current),
+            previous: format(previous),
+            delta: delta_percent(previous.unwrap_or(0.0), current.unwrap_or(0.0)),
+        }
+    }
+}
+
+/// Date range input pair of one period.
+#[component]
+fn PeriodInputs(title: String, from: Signal<String>, to: Signal<String>) -> Element {
+    rsx! {
+        fieldset {
+            legend { "{title}" }
+            input {
+                r#type: "date",
+                aria_label: t!("analytics-compare-from"),
+                value: "{from}",
+                oninput: move |evt| from.set(evt.value()),
+            }
+            "→"
+            input {
+                r#type: "date",
+                aria_label: t!("analytics-compare-to"),
+                value: "{to}",
+                oninput: move |evt| to.set(evt.value()),
+            }
+        }
+    }
+}
+
+/// Side-by-side comparison of two user-selected periods: frequency, volume
+/// and the best load of every exercise trained in either, with the change
+/// from the second period to the first.
+#[component]
+pub fn PeriodComparison(
+    sessions: Vec<WorkoutSession>,
+    available_by_metric: Memo<[Vec<(String, String)>; 4]>,
+) -> Element {
+    let [(current_from, current_to), (previous_from, previous_to)] =
+        default_periods(crate::datetime::today());
+    let current_from = use_signal(|| current_from.to_string());
+    let current_to = use_signal(|| current_to.to_string());
+    let previous_from = use_signal(|| previous_from.to_string());
+    let previous_to = use_signal(|| previous_to.to_string());
+    let summary = |from: Signal<String>, to: Signal<String>| {
+        let from = crate::datetime::parse_iso_date(&from.read())?;
+        let to = crate::datetime::parse_iso_date(&to.read())?;
+        (from <= to).then(|| PeriodSummary::of(&sessions, from, to))
+    };
+    let current = summary(current_from, current_to);
+    let previous = summary(previous_from, previous_to);
+
+    let rows: Vec<ComparisonRow> = match (&current, &previous) {
+        (Some(current), Some(previous)) => {
+            #[allow(clippy::cast_precision_loss)]
+            let mut rows = vec![
+                ComparisonRow {
+                    delta: delta_percent(previous.sessions as f64, current.sessions as f64),
+                    label: t!("analytics-compare-sessions"),
+                    current: current.sessions.to_string(),
+                    previous: previous.sessions.to_string(),
+                },
+                ComparisonRow::new(
+                    t!("analytics-compare-frequency"),
+                    Some(current.sessions_per_week),
+                    Some(previous.sessions_per_week),
+                    "",
+                ),
+                ComparisonRow::new(
+                    t!("analytics-compare-volume"),
+                    Some(current.volume_kg),
+                    Some(previous.volume_kg),
+                    " kg",
+                ),
+            ];
+            let names = &available_by_metric.read()[0];
+            let mut exercises: Vec<(&str, &str)> = current
+                .bests
+                .keys()
+                .chain(previous.bests.keys())
+                .map(|id| {
+                    let name = names
+                        .iter()
+                        .find(|(known, _)| known == id)
+                        .map_or(id.as_str(), |(_, name)| name.as_str());
+                    (id.as_str(), name)
+                })
+                .collect();
+            exercises.sort_by(|a, b| a.1.cmp(b.1));
+            exercises.dedup_by_key(|(id, _)| *id);
+            rows.extend(exercises.into_iter().map(|(id, name)| {
+                ComparisonRow::new(
+                    name.to_owned(),
+                    current.bests.get(id).copied(),
+                    previous.bests.get(id).copied(),
+                    " kg",
+                )
+            }));
+            rows
+        }
+        _ => Vec::new(),
+    };
+
+    rsx! {
+        section { class: "comparison",
+            div { class: "inputs",
+                PeriodInputs {
+                    title: t!("analytics-compare-current"),
+                    from: current_from,
+                    to: current_to,
+                }
+                PeriodInputs {
+                    title: t!("analytics-compare-previous"),
+                    from: previous_from,
+                    to: previous_to,
+                }
+            }
+            if rows.is_empty() {
+                p { {t!("analytics-compare-invalid")} }
+            } else {
+                table {
+                    thead {
+                        tr {
+                            th {}
+                            th { {t!("analytics-compare-current")} }
+                            th { {t!("analytics-compare-previous")} }
+                            th { "Δ" }
+                        }
+                    }
+                    tbody {
+                        for (i, row) in rows.into_iter().enumerate() {
+                            tr { key: "{i}",
+                                th { "{row.label}" }
+                                td { "{row.current}" }
+                                td { "{row.previous}" }
+                                match row.delta {
+                                    Some(delta) if delta > 0.0 => rsx! {
+                                        td { class: "up", "▲ {delta:+.0} %" }
+                                    },
+                                    Some(delta) if delta < 0.0 => rsx! {
+                                        td { class: "down", "▼ {delta:+.0} %" }
+                                    },
+                                    Some(_) => rsx! {
+                                        td { "= 0 %" }
+                                    },
+                                    None => rsx! {
+                                        td { "–" }
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn default_periods_are_this_and_last_month() {
+        let today = Date::from_calendar_date(2025, Month::March, 14).unwrap();
+        let [(from, to), (previous_from, previous_to)] = default_periods(today);
+        assert_eq!((from, to), (today.replace_day(1).unwrap(), today));
+        assert_eq!(
+            previous_from,
+            Date::from_calendar_date(2025, Month::February, 1).unwrap()
+        );
+        assert_eq!(
+            previous_to,
+            Date::from_calendar_date(2025, Month::February, 28).unwrap()
+        );
+    }
+}
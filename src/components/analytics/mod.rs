@@ -1,20 +1,31 @@
 use crate::components::{ActiveTab, BottomNav};
-use crate::models::analytics::Metric;
+use crate::models::analytics::{
+    adapt_metric_unit, asymmetry_percent, best_one_rep_max, side_bests, Metric,
+};
 use crate::services::{exercise_db, storage};
 use dioxus::prelude::*;
 use dioxus_i18n::prelude::i18n;
 use dioxus_i18n::t;
 
 mod chart;
+mod comparison;
+mod rest;
 mod selector;
+mod strain;
 
 pub use chart::{ChartView, SeriesData};
+pub use comparison::PeriodComparison;
+pub use rest::RestChart;
 pub use selector::MetricSelector;
+pub use strain::StrainChart;
 
 const COLORS: [&str; 8] = [
     "#3498db", "#e74c3c", "#2ecc71", "#9b59b6", "#e67e22", "#f1c40f", "#16a085", "#e91e63",
 ];
 
+/// Days back over which sets are counted per custom tag.
+const TAG_STATS_DAYS: i64 = 30;
+
 #[component]
 pub fn Analytics() -> Element {
     let selected_pairs: Signal<Vec<(Metric, Option<String>)>> =
@@ -22,13 +33,16 @@ pub fn Analytics() -> Element {
     let all_exercises = exercise_db::use_exercises();
     let custom_exercises = storage::use_custom_exercises();
     let lang_str = use_memo(move || i18n().language().to_string());
+    let mut selected_location = use_signal(|| None::<String>);
+    let mut comparing = use_signal(|| false);
+    let overrides = storage::use_exercise_overrides();
 
     let sessions_resource = use_resource(move || async move {
         let mut all: Vec<crate::models::WorkoutSession> = Vec::new();
         let mut offset = 0usize;
         let page_size = 500usize;
         loop {
-            match storage::load_completed_sessions_page(page_size, offset).await {
+            match storage::load_completed_sessions_page(page_size, offset, true).await {
                 Ok(page) => {
                     let fetched = page.len();
                     all.extend(page);
@@ -48,6 +62,20 @@ pub fn Analytics() -> Element {
 
     let sessions: Vec<crate::models::WorkoutSession> =
         sessions_resource.read().as_deref().unwrap_or(&[]).to_vec();
+    let locations: Vec<String> = sessions
+        .iter()
+        .filter_map(|s| s.location.clone())
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let sessions_at_location: Vec<crate::models::WorkoutSession> = {
+        let location = selected_location.read();
+        sessions
+            .iter()
+            .filter(|s| location.is_none() || s.location == *location)
+            .cloned()
+            .collect()
+    };
 
     let available_by_metric = use_memo(move || {
         let res = sessions_resource.read();
@@ -64,7 +92,7 @@ pub fn Analytics() -> Element {
                         || log.exercise_name.clone(),
                         |ex| ex.name_for_lang(&lang).to_owned(),
                     );
-                if log.weight_hg.0 > 0 {
+                if Metric::Weight.extract_value(log).is_some() {
                     maps[0].insert(log.exercise_id.clone(), name.clone());
                 }
                 if log.reps.is_some() {
@@ -91,9 +119,13 @@ pub fn Analytics() -> Element {
             .filter_map(|(i, (metric, opt_id))| opt_id.as_ref().map(|id| (i, *metric, id.clone())))
             .map(|(i, metric, exercise_id)| {
                 let mut points = Vec::new();
-                for session in &sessions {
+                let location = selected_location.read();
+                let at_location = |s: &&crate::models::WorkoutSession| {
+                    location.is_none() || s.location == *location
+                };
+                for session in sessions.iter().filter(at_location) {
                     for log in &session.exercise_logs {
-                        if log.exercise_id == exercise_id {
+                        if log.exercise_id == exercise_id && log.set_kind.counts_for_stats() {
                             if let Some(value) = metric.extract_value(log) {
                                 #[allow(clippy::cast_precision_loss)]
                                 points.push((log.start_time as f64, value));
@@ -113,28 +145,149 @@ pub fn Analytics() -> Element {
             .collect()
     };
 
+    // Left/right gap of the selected exercises logged per side.
+    let asymmetries: Vec<(String, String)> = chart_data
+        .iter()
+        .filter_map(|&(i, ref name, metric, _)| {
+            let exercise_id = selected_pairs.read().get(i)?.1.clone()?;
+            let location = selected_location.read();
+            let logs = sessions
+                .iter()
+                .filter(|s| location.is_none() || s.location == *location)
+                .flat_map(|s| &s.exercise_logs)
+                .filter(|l| l.exercise_id == exercise_id && l.set_kind.counts_for_stats());
+            let (left, right) = side_bests(metric, logs)?;
+            let (unit, scale) = adapt_metric_unit(metric, &[left, right]);
+            let left_text = format!("{:.1} {unit}", left * scale);
+            let right_text = format!("{:.1} {unit}", right * scale);
+            let gap = format!("{:+.0}", asymmetry_percent(left, right));
+            let text = t!("analytics-asymmetry-row", left: left_text, right: right_text, gap: gap);
+            Some((name.clone(), text))
+        })
+        .collect();
+
+    // Estimated one-repetition maximum of the selected weight series.
+    let one_rep_maxes: Vec<(String, String)> = chart_data
+        .iter()
+        .filter(|&&(_, _, metric, _)| metric == Metric::Weight)
+        .filter_map(|&(i, ref name, _, _)| {
+            let exercise_id = selected_pairs.read().get(i)?.1.clone()?;
+            let location = selected_location.read();
+            let logs = sessions
+                .iter()
+                .filter(|s| location.is_none() || s.location == *location)
+                .flat_map(|s| &s.exercise_logs)
+                .filter(|l| l.exercise_id == exercise_id && l.set_kind.counts_for_stats());
+            let one_rm = best_one_rep_max(logs)?;
+            Some((name.clone(), format!("{one_rm:.1} kg")))
+        })
+        .collect();
+
+    // Sets of the last days per custom category, equipment and muscle.
+    let tag_counts = {
+        let today = crate::datetime::today();
+        let from = today - time::Duration::days(TAG_STATS_DAYS - 1);
+        crate::models::tag_set_counts(&sessions_at_location, &overrides.read(), from, today)
+    };
+
     rsx! {
         header {
             h1 { {t!("analytics-title")} }
             p { {t!("analytics-subtitle")} }
-            label { {t!("analytics-pairs-label")} }
-            for i in 0..8 {
-                MetricSelector {
-                    i,
-                    color: COLORS[i],
-                    selected_pairs,
-                    available_by_metric,
+            if !locations.is_empty() {
+                select {
+                    class: "location",
+                    title: t!("analytics-location-title"),
+                    oninput: move |evt| {
+                        let name = evt.value();
+                        selected_location.set((!name.is_empty()).then_some(name));
+                    },
+                    option { value: "", {t!("analytics-location-all")} }
+                    for name in locations {
+                        option {
+                            value: "{name}",
+                            selected: selected_location.read().as_ref() == Some(&name),
+                            "📍 {name}"
+                        }
+                    }
+                }
+            }
+            button {
+                class: "label more",
+                r#type: "button",
+                onclick: move |_| comparing.toggle(),
+                if comparing() {
+                    {t!("analytics-compare-back")}
+                } else {
+                    {t!("analytics-compare-btn")}
+                }
+            }
+            if !comparing() {
+                label { {t!("analytics-pairs-label")} }
+                for i in 0..8 {
+                    MetricSelector {
+                        i,
+                        color: COLORS[i],
+                        selected_pairs,
+                        available_by_metric,
+                    }
                 }
             }
         }
         main { class: "analytics",
-            if chart_data.is_empty()
+            if comparing() {
+                PeriodComparison { sessions: sessions_at_location.clone(), available_by_metric }
+            } else if chart_data.is_empty()
                 || chart_data.iter().all(|(_, _, _, points)| points.is_empty())
             {
                 p { {t!("analytics-empty")} }
             } else {
                 ChartView { data: chart_data, colors: COLORS.to_vec() }
             }
+            if !comparing() && !asymmetries.is_empty() {
+                section {
+                    h2 { {t!("analytics-asymmetry-title")} }
+                    ul {
+                        for (name, text) in asymmetries {
+                            li { key: "{name}",
+                                strong { "{name}" }
+                                " · {text}"
+                            }
+                        }
+                    }
+                }
+            }
+            if !comparing() && !one_rep_maxes.is_empty() {
+                section {
+                    h2 { title: t!("analytics-one-rep-max-desc"), {t!("analytics-one-rep-max-title")} }
+                    ul {
+                        for (name, text) in one_rep_maxes {
+                            li { key: "{name}",
+                                strong { "{name}" }
+                                " · {text}"
+                            }
+                        }
+                    }
+                }
+            }
+            if !comparing() && !tag_counts.is_empty() {
+                section {
+                    h2 { {t!("analytics-tags-title", days: TAG_STATS_DAYS)} }
+                    ul {
+                        for (tag , count) in tag_counts {
+                            li { key: "{tag.display()}",
+                                strong { "{tag.display()}" }
+                                " · "
+                                {t!("analytics-tags-sets", count: count)}
+                            }
+                        }
+                    }
+                }
+            }
+            if !comparing() {
+                StrainChart { sessions: sessions_at_location.clone() }
+                RestChart { sessions: sessions_at_location }
+            }
         }
         BottomNav { active_tab: ActiveTab::Analytics }
     }
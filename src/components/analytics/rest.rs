@@ -0,0 +1,105 @@
+use crate::components::charting::{extent, LinearScale};
+use crate::models::{ExerciseLog, WorkoutSession};
+use crate::services::{exercise_db, rest_stats, storage};
+use crate::RestDurationSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+/// Number of most recent sessions charted.
+const CHARTED_SESSIONS: usize = 30;
+const WIDTH: f64 = 600.0;
+const HEIGHT: f64 = 150.0;
+/// Gap between two bars, as a share of a bar slot.
+const BAR_GAP_SHARE: f64 = 0.2;
+
+/// Average actual rest of the most recent sessions as a bar chart, the
+/// planned rest drawn as a line over it, followed by the average overrun of
+/// the early and late rests of a session.
+#[component]
+pub fn RestChart(sessions: Vec<WorkoutSession>) -> Element {
+    let exercises = exercise_db::use_exercises();
+    let custom_exercises = storage::use_custom_exercises();
+    let overrides = storage::use_exercise_overrides();
+    let skip_rest = storage::use_rest_skip_categories();
+    let rest_duration = use_context::<RestDurationSignal>().0;
+    let (series, halves) = {
+        let (exercises, custom, overrides) =
+            (exercises.read(), custom_exercises.read(), overrides.read());
+        let (skip_rest, default) = (skip_rest.read(), *rest_duration.read());
+        // Sets logged before their planned rest was recorded: the rest
+        // planned now.
+        let fallback = |log: &ExerciseLog| {
+            (!skip_rest.contains(&log.category)).then(|| {
+                exercise_db::preferred_rest(&exercises, &custom, &overrides, &log.exercise_id)
+                    .unwrap_or(default)
+            })
+        };
+        (
+            rest_stats::rest_series(&sessions, fallback),
+            rest_stats::overrun_by_half(&sessions, fallback),
+        )
+    };
+    if series.is_empty() {
+        return rsx! {};
+    }
+    let recent = &series[series.len().saturating_sub(CHARTED_SESSIONS)..];
+    let highest = extent(
+        recent
+            .iter()
+            .flat_map(|s| [s.actual_seconds, s.planned_seconds]),
+    )
+    .map_or(1.0, |(_, hi)| hi.max(1.0));
+    let y_scale = LinearScale::new((0.0, highest), (HEIGHT, 0.0));
+    #[allow(clippy::cast_precision_loss)]
+    let slot = WIDTH / recent.len() as f64;
+    let bars: Vec<(u64, f64, f64, f64)> = recent
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let top = y_scale.apply(s.actual_seconds);
+            #[allow(clippy::cast_precision_loss)]
+            let x = i as f64 * slot + slot * BAR_GAP_SHARE / 2.0;
+            (s.start_time, x, top, HEIGHT - top)
+        })
+        .collect();
+    let bar_width = slot * (1.0 - BAR_GAP_SHARE);
+    let planned_points: String = recent
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            #[allow(clippy::cast_precision_loss)]
+            let x = (i as f64 + 0.5) * slot;
+            format!("{x:.1},{:.1}", y_scale.apply(s.planned_seconds))
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    rsx! {
+        section { class: "rests",
+            h2 { title: t!("analytics-rest-desc"), {t!("analytics-rest-title")} }
+            svg {
+                view_box: "0 0 {WIDTH} {HEIGHT}",
+                preserve_aspect_ratio: "none",
+                for (time, x, y, height) in bars {
+                    rect {
+                        key: "{time}",
+                        x: "{x}",
+                        y: "{y}",
+                        width: "{bar_width}",
+                        height: "{height}",
+                    }
+                }
+                polyline { points: "{planned_points}" }
+            }
+            if let Some((early, late)) = halves {
+                p { class: if late > early { "ballooning" } else { "" },
+                    {
+                        t!(
+                            "analytics-rest-overrun", early : format!("{early:+.0}"), late :
+                            format!("{late:+.0}")
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
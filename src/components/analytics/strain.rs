@@ -0,0 +1,65 @@
+use crate::components::charting::{extent, LinearScale};
+use crate::models::{get_current_timestamp, WorkoutSession};
+use crate::services::strain::{self, OVERREACHING_RATIO};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+/// Number of most recent sessions charted.
+const CHARTED_SESSIONS: usize = 30;
+const WIDTH: f64 = 600.0;
+const HEIGHT: f64 = 150.0;
+/// Gap between two bars, as a share of a bar slot.
+const BAR_GAP_SHARE: f64 = 0.2;
+
+/// Strain score of the most recent sessions as a bar chart, followed by the
+/// acute:chronic load ratio, flagged when it suggests overreaching.
+#[component]
+pub fn StrainChart(sessions: Vec<WorkoutSession>) -> Element {
+    let series = strain::strain_series(&sessions);
+    if series.is_empty() {
+        return rsx! {};
+    }
+    let ratio = strain::acute_chronic_ratio(&series, get_current_timestamp());
+    let recent = &series[series.len().saturating_sub(CHARTED_SESSIONS)..];
+    let highest = extent(recent.iter().map(|&(_, s)| s)).map_or(1.0, |(_, hi)| hi.max(1.0));
+    let y_scale = LinearScale::new((0.0, highest), (HEIGHT, 0.0));
+    #[allow(clippy::cast_precision_loss)]
+    let slot = WIDTH / recent.len() as f64;
+    let bars: Vec<(u64, f64, f64, f64)> = recent
+        .iter()
+        .enumerate()
+        .map(|(i, &(time, score))| {
+            let top = y_scale.apply(score);
+            #[allow(clippy::cast_precision_loss)]
+            let x = i as f64 * slot + slot * BAR_GAP_SHARE / 2.0;
+            (time, x, top, HEIGHT - top)
+        })
+        .collect();
+    let bar_width = slot * (1.0 - BAR_GAP_SHARE);
+    rsx! {
+        section { class: "strain",
+            h2 { title: t!("analytics-strain-desc"), {t!("analytics-strain-title")} }
+            svg {
+                view_box: "0 0 {WIDTH} {HEIGHT}",
+                preserve_aspect_ratio: "none",
+                for (time, x, y, height) in bars {
+                    rect {
+                        key: "{time}",
+                        x: "{x}",
+                        y: "{y}",
+                        width: "{bar_width}",
+                        height: "{height}",
+                    }
+                }
+            }
+            if let Some(ratio) = ratio {
+                p { class: if ratio > OVERREACHING_RATIO { "overreaching" } else { "" },
+                    if ratio > OVERREACHING_RATIO {
+                        "⚠️ "
+                    }
+                    {t!("analytics-strain-ratio", ratio: format!("{ratio:.2}"))}
+                }
+            }
+        }
+    }
+}
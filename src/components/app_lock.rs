@@ -0,0 +1,155 @@
+use crate::services::app_lock::{self, LockError};
+use crate::services::storage;
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// Full-screen passcode prompt shown at start-up while the app lock is on.
+///
+/// Storage is not loaded until `on_unlock` fires, so nothing sensitive is
+/// ever rendered (or decrypted) before the passcode is verified.
+#[component]
+pub fn LockScreen(on_unlock: EventHandler<()>) -> Element {
+    let mut passcode = use_signal(String::new);
+    let mut wrong = use_signal(|| false);
+    rsx! {
+        Stylesheet { href: asset!("/assets/style.scss") }
+        main { class: "lock",
+            article {
+                h2 { {t!("lock-title")} }
+                form {
+                    onsubmit: move |evt: Event<FormData>| {
+                        evt.prevent_default();
+                        let result = app_lock::unlock(&passcode.peek());
+                        match result {
+                            Ok(()) => on_unlock.call(()),
+                            Err(_) => {
+                                wrong.set(true);
+                                passcode.set(String::new());
+                            }
+                        }
+                    },
+                    input {
+                        r#type: "password",
+                        inputmode: "numeric",
+                        autocomplete: "current-password",
+                        autofocus: true,
+                        value: "{passcode}",
+                        placeholder: t!("lock-passcode-placeholder"),
+                        oninput: move |evt| passcode.set(evt.value()),
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "icon save",
+                        aria_label: t!("lock-unlock-aria"),
+                        "🔓"
+                    }
+                }
+                if *wrong.read() {
+                    p { class: "error", {t!("lock-wrong-passcode")} }
+                }
+            }
+        }
+    }
+}
+/// Settings card for enabling / disabling the app lock (More page).
+///
+/// Toggling the lock rewrites the whole history and the sealed settings so
+/// they are sealed (or unsealed) right away rather than only on their next
+/// edit.
+#[component]
+pub fn AppLockSettings() -> Element {
+    let mut enabled = use_signal(app_lock::is_enabled);
+    let mut passcode = use_signal(String::new);
+    let mut confirm = use_signal(String::new);
+    let mut busy = use_signal(|| false);
+    let sessions = storage::use_sessions();
+    let toast = consume_context::<ToastSignal>().0;
+    let msg_enabled = use_memo(|| t!("lock-enabled"));
+    let msg_disabled = use_memo(|| t!("lock-disabled"));
+    let msg_mismatch = use_memo(|| t!("lock-mismatch"));
+    let msg_too_short = use_memo(|| t!("lock-too-short"));
+    let msg_wrong = use_memo(|| t!("lock-wrong-passcode"));
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        if *busy.peek() {
+            return;
+        }
+        let mut t = toast;
+        let pass = passcode.peek().clone();
+        let turning_on = !*enabled.peek();
+        if turning_on && pass != *confirm.peek() {
+            t.write().push_back(msg_mismatch());
+            return;
+        }
+        busy.set(true);
+        spawn(async move {
+            // Load history while still in the current lock state so it is
+            // readable, then rewrite it under the new one.
+            let history = super::more::load_all_sessions(sessions.peek().clone()).await;
+            let result = if turning_on {
+                app_lock::enable(&pass)
+            } else {
+                app_lock::disable(&pass)
+            };
+            match (result, history) {
+                (Ok(()), Ok(all)) => {
+                    storage::rewrite_stored_sessions(all);
+                    storage::rewrite_sealed_settings();
+                    enabled.set(turning_on);
+                    t.write().push_back(if turning_on {
+                        msg_enabled()
+                    } else {
+                        msg_disabled()
+                    });
+                }
+                (Ok(()), Err(e)) => {
+                    log::error!("Failed to load history for re-sealing: {e}");
+                    storage::rewrite_sealed_settings();
+                    enabled.set(turning_on);
+                }
+                (Err(LockError::TooShort), _) => t.write().push_back(msg_too_short()),
+                (Err(LockError::WrongPasscode), _) => t.write().push_back(msg_wrong()),
+                (Err(e), _) => t.write().push_back(format!("⚠️ {e}")),
+            }
+            passcode.set(String::new());
+            confirm.set(String::new());
+            busy.set(false);
+        });
+    };
+    rsx! {
+        article {
+            h2 { {t!("lock-section")} }
+            p { {t!("lock-desc")} }
+            form { onsubmit: on_submit,
+                input {
+                    r#type: "password",
+                    inputmode: "numeric",
+                    autocomplete: if *enabled.read() { "current-password" } else { "new-password" },
+                    value: "{passcode}",
+                    placeholder: t!("lock-passcode-placeholder"),
+                    oninput: move |evt| passcode.set(evt.value()),
+                }
+                if !*enabled.read() {
+                    input {
+                        r#type: "password",
+                        inputmode: "numeric",
+                        autocomplete: "new-password",
+                        value: "{confirm}",
+                        placeholder: t!("lock-confirm-placeholder"),
+                        oninput: move |evt| confirm.set(evt.value()),
+                    }
+                }
+                button {
+                    r#type: "submit",
+                    class: if *enabled.read() { "label less" } else { "label save" },
+                    disabled: *busy.read(),
+                    if *enabled.read() {
+                        {t!("lock-disable-btn")}
+                    } else {
+                        {t!("lock-enable-btn")}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::components::exercise_card::translate_enum;
+use crate::models::{AvoidRule, Force, Muscle};
+use crate::services::storage;
+use crate::DbI18nSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+use strum::IntoEnumIterator;
+/// Settings card of the injury mode (shown on the More page): movements to
+/// avoid for a while, and whether flagged exercises are hidden or only
+/// marked with a warning.
+#[component]
+pub fn AvoidListSettings() -> Element {
+    let avoid_list = storage::use_avoid_list();
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    let lang = use_memo(move || i18n().language().to_string());
+    let mut draft = use_signal(AvoidRule::default);
+    let label = move |kind: &str, value: &str| {
+        translate_enum(&db_i18n.read(), &lang.read(), kind, value).to_owned()
+    };
+    let add_rule = move |evt: FormEvent| {
+        evt.prevent_default();
+        let rule = draft.peek().clone();
+        if rule.muscle.is_none() && rule.force.is_none() {
+            return;
+        }
+        let mut next = avoid_list.peek().clone();
+        next.rules.push(rule);
+        storage::save_avoid_list(next);
+        draft.set(AvoidRule::default());
+    };
+    let remove = move |index: usize| {
+        let mut next = avoid_list.peek().clone();
+        if index < next.rules.len() {
+            next.rules.remove(index);
+            storage::save_avoid_list(next);
+        }
+    };
+    let hide = avoid_list.read().hide;
+    let descriptions: Vec<String> = avoid_list
+        .read()
+        .rules
+        .iter()
+        .map(|rule| {
+            let muscle = rule
+                .muscle
+                .map_or_else(|| t!("avoid-any-muscle"), |m| label("muscles", m.as_ref()));
+            let force = rule
+                .force
+                .map_or_else(|| t!("avoid-any-force"), |f| label("force", f.as_ref()));
+            match (rule.from.as_deref(), rule.until.as_deref()) {
+                (None, None) => format!("{muscle} · {force}"),
+                (from, until) => format!(
+                    "{muscle} · {force} · {} → {}",
+                    from.unwrap_or("…"),
+                    until.unwrap_or("…")
+                ),
+            }
+        })
+        .collect();
+    rsx! {
+        article {
+            h2 { {t!("avoid-section")} }
+            p { {t!("avoid-desc")} }
+            ul {
+                for (index, description) in descriptions.into_iter().enumerate() {
+                    li { class: "inputs",
+                        span { "{description}" }
+                        button {
+                            class: "del",
+                            title: t!("avoid-remove"),
+                            onclick: move |_| remove(index),
+                            "🗑️"
+                        }
+                    }
+                }
+            }
+            form { onsubmit: add_rule,
+                div { class: "inputs",
+                    select {
+                        aria_label: t!("avoid-muscle-label"),
+                        oninput: move |evt| {
+                            let value = evt.value();
+                            draft.write().muscle = Muscle::iter().find(|m| m.as_ref() == value);
+                        },
+                        option {
+                            value: "",
+                            selected: draft.read().muscle.is_none(),
+                            {t!("avoid-any-muscle")}
+                        }
+                        for muscle in Muscle::iter() {
+                            option {
+                                value: muscle.as_ref(),
+                                selected: draft.read().muscle == Some(muscle),
+                                {label("muscles", muscle.as_ref())}
+                            }
+                        }
+                    }
+                    select {
+                        aria_label: t!("avoid-force-label"),
+                        oninput: move |evt| {
+                            let value = evt.value();
+                            draft.write().force = Force::iter().find(|f| f.as_ref() == value);
+                        },
+                        option {
+                            value: "",
+                            selected: draft.read().force.is_none(),
+                            {t!("avoid-any-force")}
+                        }
+                        for force in Force::iter() {
+                            option {
+                                value: force.as_ref(),
+                                selected: draft.read().force == Some(force),
+                                {label("force", force.as_ref())}
+                            }
+                        }
+                    }
+                }
+                div { class: "inputs",
+                    input {
+                        r#type: "date",
+                        aria_label: t!("avoid-from-label"),
+                        value: draft.read().from.clone().unwrap_or_default(),
+                        oninput: move |evt| {
+                            let value = evt.value();
+                            draft.write().from = (!value.is_empty()).then_some(value);
+                        },
+                    }
+                    "→"
+                    input {
+                        r#type: "date",
+                        aria_label: t!("avoid-until-label"),
+                        value: draft.read().until.clone().unwrap_or_default(),
+                        oninput: move |evt| {
+                            let value = evt.value();
+                            draft.write().until = (!value.is_empty()).then_some(value);
+                        },
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "more",
+                        title: t!("avoid-add"),
+                        "+"
+                    }
+                }
+            }
+            button {
+                onclick: move |_| {
+                    let mut next = avoid_list.peek().clone();
+                    next.hide = !next.hide;
+                    storage::save_avoid_list(next);
+                },
+                if hide {
+                    {t!("avoid-warn-btn")}
+                } else {
+                    {t!("avoid-hide-btn")}
+                }
+            }
+        }
+    }
+}
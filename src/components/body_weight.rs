@@ -0,0 +1,54 @@
+use crate::models::parse_weight_kg;
+use crate::services::storage;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+/// Settings card of the body weight (shown on the More page), which
+/// bodyweight sets add their extra load to or subtract their assistance from.
+#[component]
+pub fn BodyWeightSettings() -> Element {
+    let body_weight = storage::use_body_weight();
+    let lang = i18n().language().to_string();
+    let mut weight_input = use_signal(move || {
+        (*body_weight.peek())
+            .map(|w| w.input_value(&lang))
+            .unwrap_or_default()
+    });
+    let weight_invalid = {
+        let input = weight_input.read();
+        !input.trim().is_empty() && parse_weight_kg(&input).is_none()
+    };
+    let save_weight = move |evt: FormEvent| {
+        evt.prevent_default();
+        let input = weight_input.peek().clone();
+        if input.trim().is_empty() {
+            storage::save_body_weight(None);
+        } else if let Some(weight) = parse_weight_kg(&input) {
+            storage::save_body_weight(Some(weight));
+        }
+    };
+    rsx! {
+        article {
+            h2 { {t!("body-weight-section")} }
+            p { {t!("body-weight-desc")} }
+            form { class: "inputs", onsubmit: save_weight,
+                input {
+                    // Text rather than number: number inputs reject a decimal comma.
+                    r#type: "text",
+                    inputmode: "decimal",
+                    placeholder: t!("weight-placeholder"),
+                    value: "{weight_input}",
+                    oninput: move |evt| weight_input.set(evt.value()),
+                    class: if weight_invalid { "invalid" } else { "" },
+                }
+                button {
+                    r#type: "submit",
+                    class: "icon save",
+                    disabled: weight_invalid,
+                    aria_label: t!("body-weight-save-aria"),
+                    "💾"
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::Route;
 use dioxus::prelude::*;
+use dioxus_i18n::t;
 #[derive(Clone, PartialEq)]
 pub enum ActiveTab {
     Exercises,
@@ -10,25 +11,33 @@ pub enum ActiveTab {
 #[component]
 pub fn BottomNav(active_tab: ActiveTab) -> Element {
     rsx! {
-        nav {
+        nav { aria_label: t!("nav-aria"),
             Link {
                 class: if active_tab == ActiveTab::Exercises { "exercises active" } else { "exercises" },
                 to: Route::Exercises {},
+                aria_label: t!("nav-exercises"),
+                aria_current: if active_tab == ActiveTab::Exercises { "page" } else { "false" },
                 "📚"
             }
             Link {
                 class: if active_tab == ActiveTab::Sessions { "home active" } else { "home" },
                 to: Route::Home {},
+                aria_label: t!("nav-sessions"),
+                aria_current: if active_tab == ActiveTab::Sessions { "page" } else { "false" },
                 "💪"
             }
             Link {
                 class: if active_tab == ActiveTab::Analytics { "analytics active" } else { "analytics" },
                 to: Route::Analytics {},
+                aria_label: t!("nav-analytics"),
+                aria_current: if active_tab == ActiveTab::Analytics { "page" } else { "false" },
                 "📊"
             }
             Link {
                 class: if active_tab == ActiveTab::More { "active" } else { "" },
                 to: Route::More {},
+                aria_label: t!("nav-more"),
+                aria_current: if active_tab == ActiveTab::More { "page" } else { "false" },
                 "⚙️"
             }
         }
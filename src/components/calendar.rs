@@ -0,0 +1,243 @@
+use crate::components::{ActiveTab, BottomNav};
+use crate::datetime::{self, DateGroup};
+use crate::models::{
+    month_weeks, shift_month, DayStatus, PlannedExercise, ScheduledWorkout, WorkoutSession,
+};
+use crate::services::storage;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use std::collections::HashSet;
+use time::Date;
+/// CSS class of a calendar day of `status`.
+fn status_class(status: DayStatus) -> &'static str {
+    match status {
+        DayStatus::Rest => "",
+        DayStatus::Planned => "planned",
+        DayStatus::Done => "done",
+        DayStatus::Missed => "missed",
+    }
+}
+/// Training calendar: a month of planned and completed days, templates and
+/// training plan days being assigned to the coming days, and the workouts
+/// planned today started in one tap.
+#[component]
+pub fn Calendar() -> Element {
+    let today = datetime::today();
+    let mut month = use_signal(|| today);
+    let mut selected = use_signal(|| today);
+    let schedule = storage::use_schedule();
+    let templates = storage::use_templates();
+    let plan = storage::use_training_plan();
+    let sessions = storage::use_sessions();
+    let history = use_resource(|| super::more::load_all_sessions(Vec::new()));
+    let done_days = use_memo(move || {
+        let history = history.read();
+        let sessions = sessions.read();
+        history
+            .as_ref()
+            .and_then(|h| h.as_ref().ok())
+            .into_iter()
+            .flatten()
+            .chain(sessions.iter())
+            .filter(|s| !s.is_active() && !s.exercise_logs.is_empty())
+            .map(|s| datetime::to_local(s.start_time).date())
+            .collect::<HashSet<Date>>()
+    });
+    let planned_days = use_memo(move || {
+        schedule
+            .read()
+            .iter()
+            .filter_map(ScheduledWorkout::day)
+            .collect::<HashSet<Date>>()
+    });
+    // Workouts that can be planned: the templates, then the plan days.
+    let choices = use_memo(move || {
+        let mut choices: Vec<(String, Vec<PlannedExercise>)> = templates
+            .read()
+            .iter()
+            .map(|t| (t.name.clone(), t.exercises.clone()))
+            .collect();
+        if let Some(plan) = plan.read().as_ref() {
+            choices.extend(
+                plan.days
+                    .iter()
+                    .map(|d| (format!("{} · {}", plan.name, d.name), d.exercises.clone())),
+            );
+        }
+        choices
+    });
+    let mut choice = use_signal(|| 0usize);
+    let assign = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        let Some((name, exercises)) = choices.peek().get(*choice.peek()).cloned() else {
+            return;
+        };
+        let mut next = schedule.peek().clone();
+        next.push(ScheduledWorkout {
+            date: selected.peek().to_string(),
+            name,
+            exercises,
+        });
+        next.sort_by(|a, b| a.date.cmp(&b.date));
+        storage::save_schedule(next);
+    };
+    let unassign = move |index: usize| {
+        let mut next = schedule.peek().clone();
+        if index < next.len() {
+            next.remove(index);
+            storage::save_schedule(next);
+        }
+    };
+    let start = move |workout: ScheduledWorkout| {
+        let mut session = WorkoutSession::new();
+        session.pending_exercises = workout.exercises;
+        if storage::start_session(session) {
+            navigator().push(crate::Route::Home {});
+        }
+    };
+    let shown = month();
+    let title = super::home::date_group_label(DateGroup::Month {
+        year: shown.year(),
+        month: shown.month(),
+    });
+    let weekdays = t!("calendar-weekdays");
+    let day = selected();
+    let day_class = move |date: Date| {
+        let status = DayStatus::of(
+            date,
+            today,
+            planned_days.read().contains(&date),
+            done_days.read().contains(&date),
+        );
+        let mut class = status_class(status).to_owned();
+        if date == today {
+            class.push_str(" today");
+        }
+        if date == day {
+            class.push_str(" selected");
+        }
+        class
+    };
+    let heading = t!(
+        "calendar-day-heading",
+        day: day.day().to_string(),
+        month: super::home::month_name(day.month())
+    );
+    let day_workouts: Vec<(usize, ScheduledWorkout)> = schedule
+        .read()
+        .iter()
+        .cloned()
+        .enumerate()
+        .filter(|(_, w)| w.day() == Some(day))
+        .collect();
+    let status = DayStatus::of(
+        day,
+        today,
+        !day_workouts.is_empty(),
+        done_days.read().contains(&day),
+    );
+    rsx! {
+        Stylesheet { href: asset!("/assets/sessions.scss") }
+        header {
+            h1 { {t!("calendar-title")} }
+        }
+        main { class: "sessions calendar",
+            nav {
+                button {
+                    class: "icon edit",
+                    aria_label: t!("calendar-previous-month"),
+                    onclick: move |_| month.set(shift_month(shown, -1)),
+                    "◀"
+                }
+                h2 { "{title}" }
+                button {
+                    class: "icon edit",
+                    aria_label: t!("calendar-next-month"),
+                    onclick: move |_| month.set(shift_month(shown, 1)),
+                    "▶"
+                }
+            }
+            div { class: "month",
+                for name in weekdays.split_whitespace() {
+                    span { key: "{name}", class: "weekday", "{name}" }
+                }
+                for (w , week) in month_weeks(shown).into_iter().enumerate() {
+                    for (i , cell) in week.into_iter().enumerate() {
+                        if let Some(date) = cell {
+                            button {
+                                key: "{date}",
+                                class: day_class(date),
+                                aria_pressed: date == day,
+                                onclick: move |_| selected.set(date),
+                                "{date.day()}"
+                            }
+                        } else {
+                            span { key: "blank-{w}-{i}" }
+                        }
+                    }
+                }
+            }
+            article {
+                h2 { "{heading}" }
+                match status {
+                    DayStatus::Done => rsx! {
+                        p { class: "done", {t!("calendar-day-done")} }
+                    },
+                    DayStatus::Missed => rsx! {
+                        p { class: "missed", {t!("calendar-day-missed")} }
+                    },
+                    DayStatus::Planned | DayStatus::Rest => rsx! {},
+                }
+                ul {
+                    for (index , workout) in day_workouts {
+                        li { key: "{index}",
+                            span { "{workout.name} ({workout.exercises.len()})" }
+                            if day == today {
+                                button {
+                                    class: "label save",
+                                    onclick: {
+                                        let workout = workout.clone();
+                                        move |_| start(workout.clone())
+                                    },
+                                    {t!("calendar-start-btn")}
+                                }
+                            }
+                            button {
+                                class: "del",
+                                aria_label: t!("calendar-unassign-aria"),
+                                onclick: move |_| unassign(index),
+                                "🗑️"
+                            }
+                        }
+                    }
+                }
+                if day >= today {
+                    if choices.read().is_empty() {
+                        p { {t!("calendar-no-choices")} }
+                    } else {
+                        form { onsubmit: assign,
+                            select {
+                                aria_label: t!("calendar-assign-aria"),
+                                oninput: move |evt| choice.set(evt.value().parse().unwrap_or(0)),
+                                for (index , (name , _)) in choices.read().iter().enumerate() {
+                                    option {
+                                        value: "{index}",
+                                        selected: index == choice(),
+                                        "{name}"
+                                    }
+                                }
+                            }
+                            button {
+                                r#type: "submit",
+                                class: "icon more",
+                                aria_label: t!("calendar-assign-aria"),
+                                "➕"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        BottomNav { active_tab: ActiveTab::Sessions }
+    }
+}
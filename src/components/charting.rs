@@ -0,0 +1,182 @@
+//! Shared SVG charting helpers: scales, axes and series geometry.
+//!
+//! Charts are drawn in SVG user units; a [`LinearScale`] maps data values to
+//! those units, and the [`YAxis`] component draws a labelled vertical axis.
+use dioxus::prelude::*;
+
+/// Share of the data range added above and below a value axis.
+pub const AXIS_PADDING_SHARE: f64 = 0.1;
+/// Number of intervals between the ticks of a value axis.
+pub const AXIS_TICK_INTERVALS: usize = 4;
+/// Length of a tick mark, in SVG units.
+const TICK_LENGTH: f64 = 4.0;
+/// Gap between a tick mark and its label, in SVG units.
+const TICK_LABEL_GAP: f64 = 3.0;
+/// Color of axis lines and ticks.
+pub const AXIS_COLOR: &str = "#555";
+
+/// Affine map from a data `domain` to an SVG `range`.  A degenerate domain
+/// (a single value) maps to the middle of the range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearScale {
+    pub domain: (f64, f64),
+    pub range: (f64, f64),
+}
+
+impl LinearScale {
+    pub fn new(domain: (f64, f64), range: (f64, f64)) -> Self {
+        Self { domain, range }
+    }
+
+    /// SVG coordinate of the data value `v`.
+    pub fn apply(&self, v: f64) -> f64 {
+        let (d0, d1) = self.domain;
+        let (r0, r1) = self.range;
+        if (d1 - d0).abs() < f64::EPSILON {
+            f64::midpoint(r0, r1)
+        } else {
+            r0 + (v - d0) / (d1 - d0) * (r1 - r0)
+        }
+    }
+
+    /// `count` evenly spaced values across the domain, ends included.
+    pub fn ticks(&self, count: usize) -> Vec<f64> {
+        let (d0, d1) = self.domain;
+        match count {
+            0 => Vec::new(),
+            1 => vec![f64::midpoint(d0, d1)],
+            _ => (0..count)
+                .map(|i| {
+                    #[allow(clippy::cast_precision_loss)]
+                    let frac = i as f64 / (count - 1) as f64;
+                    d0 + (d1 - d0) * frac
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Minimum and maximum of `values`, `None` when empty.
+pub fn extent(values: impl IntoIterator<Item = f64>) -> Option<(f64, f64)> {
+    values.into_iter().fold(None, |acc, v| match acc {
+        None => Some((v, v)),
+        Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+    })
+}
+
+/// Value axis domain showing `values` with [`AXIS_PADDING_SHARE`] of
+/// headroom on both sides, never below zero.
+pub fn padded_domain(values: impl IntoIterator<Item = f64>) -> Option<(f64, f64)> {
+    let (lo, hi) = extent(values)?;
+    let span = if (hi - lo).abs() < f64::EPSILON {
+        1.0
+    } else {
+        hi - lo
+    };
+    Some((
+        (lo - span * AXIS_PADDING_SHARE).max(0.0),
+        hi + span * AXIS_PADDING_SHARE,
+    ))
+}
+
+/// Least-squares line through `points`, as `(slope, intercept)`; `None`
+/// when every point shares the same X.
+pub fn linear_trend(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    #[allow(clippy::cast_precision_loss)]
+    let n = points.len() as f64;
+    let sum_x: f64 = points.iter().map(|(x, _)| x).sum();
+    let sum_y: f64 = points.iter().map(|(_, y)| y).sum();
+    let sum_xx: f64 = points.iter().map(|(x, _)| x * x).sum();
+    let sum_xy: f64 = points.iter().map(|(x, y)| x * y).sum();
+    let denom = n * sum_xx - sum_x * sum_x;
+    if denom.abs() <= f64::EPSILON {
+        return None;
+    }
+    let slope = (n * sum_xy - sum_x * sum_y) / denom;
+    Some((slope, (sum_y - slope * sum_x) / n))
+}
+
+/// Vertical value axis at `x` with its unit on top and
+/// [`AXIS_TICK_INTERVALS`] labelled ticks, labels on the right side when
+/// `right`.
+#[component]
+pub fn YAxis(x: f64, scale: LinearScale, unit: &'static str, right: bool) -> Element {
+    let (bottom, top) = scale.range;
+    let tick_x1 = if right { x } else { x - TICK_LENGTH };
+    let tick_x2 = if right { x + TICK_LENGTH } else { x };
+    let text_x = if right {
+        x + TICK_LENGTH + TICK_LABEL_GAP
+    } else {
+        x - TICK_LENGTH - TICK_LABEL_GAP
+    };
+    let text_anchor = if right { "start" } else { "end" };
+    let unit_anchor = if right { "start" } else { "middle" };
+    let ticks = scale.ticks(AXIS_TICK_INTERVALS + 1);
+    rsx! {
+        g {
+            line {
+                x1: "{x}",
+                y1: "{top}",
+                x2: "{x}",
+                y2: "{bottom}",
+                stroke: AXIS_COLOR,
+                stroke_width: "1",
+                stroke_opacity: "0.7",
+            }
+            text {
+                x: "{x}",
+                y: "{top - 6.0}",
+                text_anchor: unit_anchor,
+                font_size: "14",
+                font_weight: "bold",
+                fill: "#ccc",
+                "{unit}"
+            }
+            for (i, value) in ticks.into_iter().enumerate() {
+                g { key: "tick_{i}",
+                    line {
+                        x1: "{tick_x1}",
+                        y1: "{scale.apply(value)}",
+                        x2: "{tick_x2}",
+                        y2: "{scale.apply(value)}",
+                        stroke: AXIS_COLOR,
+                        stroke_width: "1",
+                    }
+                    text {
+                        x: "{text_x}",
+                        y: "{scale.apply(value) + 4.0}",
+                        text_anchor,
+                        font_size: "13",
+                        fill: "#bbb",
+                        "{value:.1}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_scale_maps_and_ticks_the_domain() {
+        let scale = LinearScale::new((0.0, 10.0), (100.0, 0.0));
+        assert!((scale.apply(2.5) - 75.0).abs() < 1e-9);
+        assert_eq!(scale.ticks(3), vec![0.0, 5.0, 10.0]);
+        let flat = LinearScale::new((4.0, 4.0), (0.0, 50.0));
+        assert!((flat.apply(4.0) - 25.0).abs() < 1e-9);
+        assert_eq!(padded_domain([10.0, 20.0]), Some((9.0, 21.0)));
+        assert_eq!(padded_domain([0.5, 0.5]), Some((0.4, 0.6)));
+        assert_eq!(padded_domain([]), None);
+    }
+
+    #[test]
+    fn linear_trend_fits_points() {
+        let (slope, intercept) = linear_trend(&[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)]).unwrap();
+        assert!((slope - 2.0).abs() < 1e-9);
+        assert!((intercept - 1.0).abs() < 1e-9);
+        assert_eq!(linear_trend(&[(1.0, 1.0), (1.0, 2.0)]), None);
+    }
+}
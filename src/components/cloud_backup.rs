@@ -0,0 +1,205 @@
+use crate::datetime;
+use crate::models::WorkoutSession;
+use crate::services::cloud_backup::{self, CloudProvider};
+use crate::services::storage;
+use crate::{InitialQuerySignal, ToastSignal};
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+/// Upload the backup to the linked cloud account, if any, once `finished`
+/// is saved.  Failures are reported in a toast; the next session retries.
+///
+/// Without a backup passphrase nothing is uploaded, as the backup would
+/// leave the device in clear.
+pub(crate) fn upload_after_session(finished: WorkoutSession) {
+    if cloud_backup::linked().is_none() {
+        return;
+    }
+    let mut toast = consume_context::<ToastSignal>().0;
+    let passphrase = storage::use_cloud_backup_passphrase().peek().clone();
+    if passphrase.is_empty() {
+        toast
+            .write()
+            .push_back(t!("cloud-backup-passphrase-required"));
+        return;
+    }
+    // Outlive the finished session's view, which unmounts right away.
+    dioxus::core::spawn_forever(async move {
+        let result = async {
+            // The finished session may not be written yet: replace its copy.
+            let mut sessions = super::more::load_all_sessions(Vec::new()).await?;
+            sessions.retain(|s| s.id != finished.id);
+            sessions.push(finished);
+            let exercises = storage::load_custom_exercises().await?;
+            Ok::<_, Box<dyn std::error::Error>>(
+                cloud_backup::upload_backup(&sessions, &exercises, &passphrase).await?,
+            )
+        }
+        .await;
+        if let Err(e) = result {
+            log::error!("Cloud backup upload failed: {e}");
+            toast
+                .write()
+                .push_back(t!("cloud-backup-failed", error: e.to_string()));
+        }
+    });
+}
+/// Web address the provider redirects back to: the current page, without
+/// its query.
+fn redirect_uri() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let location = web_sys::window()?.location();
+        Some(format!(
+            "{}{}",
+            location.origin().ok()?,
+            location.pathname().ok()?
+        ))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    None
+}
+/// Settings card linking a Google Drive or Dropbox account the backup is
+/// uploaded to after each finished session (shown on the More page).
+///
+/// Linking redirects to the provider and back to this page, whose query then
+/// holds the authorization code.  Only offered on the web, where such a
+/// redirect can reach the app, and for providers this build has a client ID
+/// for.
+#[component]
+pub fn CloudBackupSettings() -> Element {
+    let mut link = use_signal(cloud_backup::linked);
+    let mut busy = use_signal(|| false);
+    let passphrase = storage::use_cloud_backup_passphrase();
+    let mut toast = use_context::<ToastSignal>().0;
+    let mut initial_query = use_context::<InitialQuerySignal>().0;
+    let lang = use_memo(move || i18n().language().to_string());
+    use_hook(move || {
+        let query = initial_query.peek().trim_start_matches('?').to_owned();
+        let code = crate::utils::get_query_param(&query, "code");
+        let state = crate::utils::get_query_param(&query, "state");
+        let (Some(code), Some(state)) = (code, state) else {
+            return;
+        };
+        initial_query.set(String::new());
+        busy.set(true);
+        spawn(async move {
+            match cloud_backup::complete_authorization(&code, &state).await {
+                Ok(linked) => {
+                    toast.write().push_back(t!(
+                        "cloud-backup-linked",
+                        provider: linked.provider.name()
+                    ));
+                    link.set(Some(linked));
+                }
+                Err(e) => {
+                    log::error!("Cloud backup linking failed: {e}");
+                    toast
+                        .write()
+                        .push_back(t!("cloud-backup-failed", error: e.to_string()));
+                }
+            }
+            busy.set(false);
+        });
+    });
+    let mut start_link = move |provider: CloudProvider| {
+        let Some(redirect) = redirect_uri() else {
+            return;
+        };
+        match cloud_backup::authorization_url(provider, &redirect) {
+            Ok(url) => {
+                #[cfg(target_arch = "wasm32")]
+                if let Some(window) = web_sys::window() {
+                    let _ = window.location().set_href(&url);
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                let _ = url;
+            }
+            Err(e) => toast
+                .write()
+                .push_back(t!("cloud-backup-failed", error: e.to_string())),
+        }
+    };
+    let upload_now = move |_| {
+        busy.set(true);
+        spawn(async move {
+            let result = async {
+                let sessions = super::more::load_all_sessions(Vec::new()).await?;
+                let exercises = storage::load_custom_exercises().await?;
+                let passphrase = passphrase.peek().clone();
+                Ok::<_, Box<dyn std::error::Error>>(
+                    cloud_backup::upload_backup(&sessions, &exercises, &passphrase).await?,
+                )
+            }
+            .await;
+            match result {
+                Ok(_) => {
+                    toast.write().push_back(t!("cloud-backup-uploaded"));
+                    link.set(cloud_backup::linked());
+                }
+                Err(e) => toast
+                    .write()
+                    .push_back(t!("cloud-backup-failed", error: e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+    let providers: Vec<CloudProvider> = CloudProvider::ALL
+        .into_iter()
+        .filter(|p| p.is_available())
+        .collect();
+    if redirect_uri().is_none() || (providers.is_empty() && link.read().is_none()) {
+        return rsx! {};
+    }
+    rsx! {
+        article {
+            h2 { {t!("cloud-backup-section")} }
+            p { {t!("cloud-backup-desc")} }
+            if let Some(linked) = link.read().clone() {
+                p {
+                    {t!("cloud-backup-linked", provider: linked.provider.name())}
+                    if let Some(at) = linked.last_upload {
+                        " · "
+                        {t!("cloud-backup-last-upload", date: datetime::format_short_date(at, &lang.read()))}
+                    }
+                }
+                p { {t!("cloud-backup-passphrase-desc")} }
+                input {
+                    r#type: "password",
+                    autocomplete: "new-password",
+                    value: "{passphrase}",
+                    placeholder: t!("cloud-backup-passphrase-placeholder"),
+                    oninput: move |evt| storage::save_cloud_backup_passphrase(evt.value()),
+                }
+                div { class: "inputs",
+                    button {
+                        class: "label save",
+                        disabled: busy() || passphrase.read().is_empty(),
+                        onclick: upload_now,
+                        {t!("cloud-backup-upload-btn")}
+                    }
+                    button {
+                        class: "del label",
+                        onclick: move |_| {
+                            cloud_backup::unlink();
+                            link.set(None);
+                        },
+                        {t!("cloud-backup-unlink-btn")}
+                    }
+                }
+            } else {
+                div { class: "inputs",
+                    for provider in providers {
+                        button {
+                            key: "{provider.name()}",
+                            class: "label more",
+                            disabled: busy(),
+                            onclick: move |_| start_link(provider),
+                            {t!("cloud-backup-link-btn", provider: provider.name())}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,17 +1,19 @@
-use super::session_exercise_form::ExerciseInputForm;
+use super::session_exercise_form::{set_kind_label, ExerciseInputForm};
 use crate::components::HoldDeleteButton;
+use crate::datetime::{format_clock_time, with_clock_time};
 use crate::models::{
     format_time, parse_distance_km, parse_duration_seconds, parse_weight_kg, Category, ExerciseLog,
-    Force, Weight, WorkoutSession, HG_PER_KG, M_PER_KM,
+    Force, SetKind, Side, Weight, WorkoutSession,
 };
-use crate::services::{exercise_db, storage};
+use crate::services::activity_export::{self, ActivityFormat};
+use crate::services::{drafts, exercise_db, markdown, storage};
+use crate::ToastSignal;
 use dioxus::prelude::*;
 use dioxus_i18n::prelude::i18n;
 use dioxus_i18n::t;
 /// A single completed exercise log entry with inline edit support.
 #[component]
 pub fn CompletedExerciseLog(
-    idx: usize,
     log: ExerciseLog,
     session: Memo<WorkoutSession>,
     /// Called when the user clicks the replay button to start another set.
@@ -26,27 +28,43 @@ pub fn CompletedExerciseLog(
     let mut edit_reps_input = use_signal(String::new);
     let mut edit_distance_input = use_signal(String::new);
     let mut edit_time_input = use_signal(String::new);
+    let mut edit_notes_input = use_signal(String::new);
+    let mut edit_side_input = use_signal(|| None::<Side>);
+    let mut edit_assisted_input = use_signal(|| false);
+    let mut edit_set_kind_input = use_signal(SetKind::default);
+    let mut edit_start_input = use_signal(String::new);
+    // Whether the end time typed is before the start time (and so ignored).
+    let mut end_error = use_signal(|| false);
+    let lang_str = use_memo(move || i18n().language().to_string());
+    let notes_draft_key = format!("log-notes:{}:{}", session.peek().id, log.start_time);
     let start_edit = {
         let log = log.clone();
+        let key = notes_draft_key.clone();
         move |_| {
+            let lang = lang_str.peek();
             edit_weight_input.set(if log.weight_hg.0 == 0 {
                 String::new()
             } else {
-                format!("{:.1}", f64::from(log.weight_hg.0) / HG_PER_KG)
+                log.weight_hg.input_value(&lang)
             });
             edit_reps_input.set(log.reps.map(|r| r.to_string()).unwrap_or_default());
             edit_distance_input.set(
                 log.distance_m
-                    .map(|d| format!("{:.2}", f64::from(d.0) / M_PER_KM))
+                    .map(|d| d.input_value(&lang))
                     .unwrap_or_default(),
             );
             edit_time_input.set(log.duration_seconds().map(format_time).unwrap_or_default());
+            edit_notes_input.set(drafts::load(&key).unwrap_or_else(|| log.notes.clone()));
+            edit_side_input.set(log.side);
+            edit_assisted_input.set(log.assisted);
+            edit_set_kind_input.set(log.set_kind);
+            edit_start_input.set(format_clock_time(log.start_time));
+            end_error.set(false);
             is_editing.set(true);
         }
     };
     let all_exercises = exercise_db::use_exercises();
     let custom_exercises = storage::use_custom_exercises();
-    let lang_str = use_memo(move || i18n().language().to_string());
     let exercise_id_for_name = log.exercise_id.clone();
     let fallback_name = log.exercise_name.clone();
     let display_name = use_memo(move || {
@@ -58,9 +76,42 @@ pub fn CompletedExerciseLog(
             |ex| ex.name_for_lang(&lang).to_owned(),
         )
     });
+    let exercise_id_for_kind = log.exercise_id.clone();
+    let bodyweight = use_memo(move || {
+        exercise_db::resolve_exercise(
+            &all_exercises.read(),
+            &custom_exercises.read(),
+            &exercise_id_for_kind,
+        )
+        .is_some_and(|ex| ex.is_bodyweight())
+    });
     let force = log.force;
     let category = log.category;
     let exercise_id = log.exercise_id.clone();
+    let start_time = log.start_time;
+    // Start time and duration being edited, as timestamps; the end time shown
+    // follows from them.
+    let edited_start = move || with_clock_time(start_time, &edit_start_input.read());
+    let edited_end = move || {
+        let start = edited_start()?;
+        parse_duration_seconds(&edit_time_input.read()).map(|dur| start + dur)
+    };
+    let exportable = activity_export::is_exportable(&log);
+    // A GPX file without a track holds nothing but an empty segment.
+    let has_track = !log.gps_track.is_empty();
+    let mut toast = use_context::<ToastSignal>().0;
+    let export_as = {
+        let log = log.clone();
+        move |format: ActivityFormat| {
+            let content = activity_export::export_log(&log, format);
+            let filename = activity_export::export_filename(&log, format);
+            if let Some(msg) =
+                crate::components::more::trigger_download(&filename, &content, format.mime_type())
+            {
+                toast.write().push_back(msg);
+            }
+        }
+    };
     rsx! {
         article {
             header {
@@ -74,6 +125,28 @@ pub fn CompletedExerciseLog(
                             "🔁"
                         }
                     }
+                    if exportable && has_track {
+                        button {
+                            class: "edit",
+                            title: t!("log-export-gpx-title"),
+                            onclick: {
+                                let mut export_as = export_as.clone();
+                                move |_| export_as(ActivityFormat::Gpx)
+                            },
+                            "GPX"
+                        }
+                    }
+                    if exportable {
+                        button {
+                            class: "edit",
+                            title: t!("log-export-tcx-title"),
+                            onclick: {
+                                let mut export_as = export_as.clone();
+                                move |_| export_as(ActivityFormat::Tcx)
+                            },
+                            "TCX"
+                        }
+                    }
                     button {
                         class: "edit",
                         onclick: start_edit,
@@ -84,8 +157,10 @@ pub fn CompletedExerciseLog(
                         title: t!("log-delete-title").to_string(),
                         on_delete: move |()| {
                             let mut current_session = session.read().clone();
-                            current_session.exercise_logs.remove(idx);
-                            storage::save_session(current_session);
+                            if current_session.remove_log(start_time).is_some() {
+                                storage::save_session(current_session);
+                                toast.write().push_back(t!("log-deleted").to_string());
+                            }
                         },
                     }
                 }
@@ -100,55 +175,162 @@ pub fn CompletedExerciseLog(
                     force,
                     category,
                     time_input: Some(edit_time_input),
-                    on_complete: move |()| {
-                        let mut current_session = session.read().clone();
-                        if let Some(log) = current_session.exercise_logs.get_mut(idx) {
-                            log.weight_hg = if category == Category::Stretching {
-                                Weight::default()
-                            } else {
-                                parse_weight_kg(&edit_weight_input.read()).unwrap_or_default()
-                            };
-                            log.reps = if category != Category::Cardio
-                                && force.is_some_and(Force::has_reps)
+                    side_input: Some(edit_side_input),
+                    assisted_input: bodyweight().then_some(edit_assisted_input),
+                    set_kind_input: Some(edit_set_kind_input),
+                    on_complete: {
+                        let key = notes_draft_key.clone();
+                        move |()| {
+                            if *end_error.peek() {
+                                return;
+                            }
+                            let mut current_session = session.read().clone();
+                            let new_start = edited_start().unwrap_or(start_time);
+                            if new_start != start_time
+                                && current_session
+                                    .exercise_logs
+                                    .iter()
+                                    .any(|l| l.start_time == new_start)
                             {
-                                edit_reps_input.read().parse().ok()
-                            } else {
-                                None
-                            };
-                            if log.category == Category::Cardio {
-                                log.distance_m = parse_distance_km(&edit_distance_input.read());
+                                toast.write().push_back(t!("log-start-taken").to_string());
+                                return;
+                            }
+                            if let Some(log) = current_session.log_mut(start_time) {
+                                log.weight_hg = if category == Category::Stretching {
+                                    Weight::default()
+                                } else {
+                                    parse_weight_kg(&edit_weight_input.read()).unwrap_or_default()
+                                };
+                                log.reps = if category != Category::Cardio
+                                    && force.is_some_and(Force::has_reps)
+                                {
+                                    edit_reps_input.read().parse().ok()
+                                } else {
+                                    None
+                                };
+                                if log.category == Category::Cardio {
+                                    log.distance_m = parse_distance_km(&edit_distance_input.read());
+                                }
+                                log.notes = edit_notes_input.read().trim().to_owned();
+                                log.side = *edit_side_input.read();
+                                log.set_kind = *edit_set_kind_input.read();
+                                log.assisted = bodyweight()
+                                    && log.weight_hg.0 > 0
+                                    && *edit_assisted_input.read();
+                                let kept_duration = log.duration_seconds();
+                                log.start_time = new_start;
+                                let time_str = edit_time_input.read();
+                                if let Some(dur) = parse_duration_seconds(&time_str).or(kept_duration)
+                                {
+                                    log.end_time = Some(new_start + dur);
+                                }
+                                crate::components::goals::celebrate_goals_met(log, &display_name.peek());
                             }
-                            let time_str = edit_time_input.read();
-                            if !time_str.is_empty() {
-                                if let Some(dur) = parse_duration_seconds(&time_str) {
-                                    log.end_time = Some(log.start_time + dur);
+                            storage::save_session(current_session);
+                            drafts::clear(&key);
+                            is_editing.set(false);
+                            edit_weight_input.set(String::new());
+                            edit_reps_input.set(String::new());
+                            edit_distance_input.set(String::new());
+                            edit_time_input.set(String::new());
+                            edit_notes_input.set(String::new());
+                            edit_start_input.set(String::new());
+                        }
+                    },
+                    on_cancel: {
+                        let key = notes_draft_key.clone();
+                        move |()| {
+                            drafts::clear(&key);
+                            is_editing.set(false);
+                        }
+                    },
+                }
+                div { class: "inputs",
+                    input {
+                        r#type: "time",
+                        step: 1,
+                        aria_label: t!("log-start-label"),
+                        title: t!("log-start-label"),
+                        value: "{edit_start_input}",
+                        oninput: move |evt| {
+                            edit_start_input.set(evt.value());
+                            end_error.set(false);
+                        },
+                    }
+                    "→"
+                    input {
+                        r#type: "time",
+                        step: 1,
+                        aria_label: t!("log-end-label"),
+                        title: t!("log-end-label"),
+                        class: if end_error() { "invalid" } else { "" },
+                        value: edited_end().map(format_clock_time).unwrap_or_default(),
+                        oninput: move |evt| {
+                            let end = with_clock_time(start_time, &evt.value());
+                            match (edited_start(), end) {
+                                (Some(start), Some(end)) if end >= start => {
+                                    edit_time_input.set(format_time(end - start));
+                                    end_error.set(false);
                                 }
+                                _ => end_error.set(true),
                             }
+                        },
+                    }
+                }
+                if end_error() {
+                    p { class: "error", {t!("log-end-before-start")} }
+                }
+                textarea {
+                    class: "notes",
+                    placeholder: t!("log-notes-placeholder"),
+                    initial_value: "{edit_notes_input.peek()}",
+                    oninput: {
+                        let key = notes_draft_key.clone();
+                        move |evt: FormEvent| {
+                            drafts::save(&key, &evt.value());
+                            edit_notes_input.set(evt.value());
                         }
-                        storage::save_session(current_session);
-                        is_editing.set(false);
-                        edit_weight_input.set(String::new());
-                        edit_reps_input.set(String::new());
-                        edit_distance_input.set(String::new());
-                        edit_time_input.set(String::new());
                     },
-                    on_cancel: move |()| is_editing.set(false),
                 }
             } else {
                 ul {
+                    if !log.set_kind.is_normal() {
+                        li { class: "set-kind", {set_kind_label(log.set_kind)} }
+                    }
+                    match log.side {
+                        Some(Side::Left) => rsx! {
+                            li { {t!("side-left")} }
+                        },
+                        Some(Side::Right) => rsx! {
+                            li { {t!("side-right")} }
+                        },
+                        None => rsx! {},
+                    }
                     if log.weight_hg.0 > 0 {
-                        li { "{log.weight_hg}" }
+                        if log.assisted {
+                            li { "−{log.weight_hg.localized(&lang_str.read())}" }
+                        } else if bodyweight() {
+                            li { "+{log.weight_hg.localized(&lang_str.read())}" }
+                        } else {
+                            li { {log.weight_hg.localized(&lang_str.read())} }
+                        }
                     }
                     if let Some(reps) = log.reps {
                         li { "{reps} reps" }
                     }
                     if let Some(d) = log.distance_m {
-                        li { "{d}" }
+                        li { {d.localized(&lang_str.read())} }
                     }
                     if let Some(duration) = log.duration_seconds() {
                         li { "{crate::models::format_time(duration)}" }
                     }
                 }
+                if !log.notes.is_empty() {
+                    div {
+                        class: "notes",
+                        dangerous_inner_html: "{markdown::to_html(&log.notes)}",
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,77 @@
+use crate::ConfirmSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use futures_channel::oneshot;
+/// A question awaiting the user's answer in the [`ConfirmDialog`].
+pub struct ConfirmRequest {
+    message: String,
+    confirm_label: String,
+    reply: oneshot::Sender<bool>,
+}
+/// Ask the user to confirm `message` in the global dialog, the confirm
+/// button reading `confirm_label`.  Resolves to `true` only when the user
+/// confirms; a newer request dismisses (cancels) one still pending.
+pub async fn confirm(message: String, confirm_label: String) -> bool {
+    let (reply, answer) = oneshot::channel();
+    crate::services::a11y::remember_focus();
+    let mut dialog = consume_context::<ConfirmSignal>().0;
+    let previous = dialog.write().replace(ConfirmRequest {
+        message,
+        confirm_label,
+        reply,
+    });
+    if let Some(previous) = previous {
+        let _ = previous.reply.send(false);
+    }
+    let confirmed = answer.await.unwrap_or(false);
+    crate::services::a11y::restore_focus();
+    confirmed
+}
+/// Modal dialog answering the pending [`confirm`] request, if any.  Escape
+/// and a click outside the dialog cancel.
+#[component]
+pub fn ConfirmDialog() -> Element {
+    let mut dialog = use_context::<ConfirmSignal>().0;
+    let mut answer = move |confirmed: bool| {
+        if let Some(request) = dialog.write().take() {
+            let _ = request.reply.send(confirmed);
+        }
+    };
+    let Some((message, confirm_label)) = dialog
+        .read()
+        .as_ref()
+        .map(|r| (r.message.clone(), r.confirm_label.clone()))
+    else {
+        return rsx! {};
+    };
+    rsx! {
+        div { class: "backdrop", onclick: move |_| answer(false) }
+        dialog {
+            open: true,
+            role: "alertdialog",
+            aria_modal: "true",
+            aria_label: "{message}",
+            onclick: move |evt| evt.stop_propagation(),
+            onkeydown: move |evt| {
+                if evt.key() == Key::Escape {
+                    answer(false);
+                }
+            },
+            p { "{message}" }
+            div {
+                button {
+                    class: "no label",
+                    autofocus: true,
+                    onclick: move |_| answer(true),
+                    "{confirm_label}"
+                }
+                button {
+                    class: "yes",
+                    title: t!("confirm-cancel"),
+                    onclick: move |_| answer(false),
+                    "❌"
+                }
+            }
+        }
+    }
+}
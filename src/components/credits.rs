@@ -0,0 +1,54 @@
+use crate::services::{exercise_db, storage};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// License of the app.
+const APP_LICENSE: &str = "GPL-3.0";
+/// License of the default exercise database (free-exercise-db).
+const EXERCISE_DB_LICENSE: &str = "Unlicense";
+/// `timestamp` as a local date and time, e.g. "2024-01-10 18:05".
+fn format_fetch_time(timestamp: u64) -> String {
+    let dt = crate::datetime::to_local(timestamp);
+    format!("{} {:02}:{:02}", dt.date(), dt.hour(), dt.minute())
+}
+/// Credits card listing the versions and licenses of the app and of its
+/// exercise data, with a button refreshing the exercise database (shown on
+/// the More page).
+#[component]
+pub fn Credits() -> Element {
+    let exercises = exercise_db::use_exercises();
+    let db_url = crate::utils::get_exercise_db_url();
+    let is_default_db = db_url == crate::utils::EXERCISE_DB_BASE_URL;
+    let last_fetch =
+        exercise_db::last_fetch().map_or_else(|| t!("credits-never"), format_fetch_time);
+    rsx! {
+        article {
+            h2 { {t!("credits-section")} }
+            ul {
+                li {
+                    strong { "LogOut {env!(\"CARGO_PKG_VERSION\")}" }
+                    " · "
+                    {t!("credits-license", license: APP_LICENSE)}
+                }
+                li { {t!("credits-schema", version: storage::SCHEMA_VERSION)} }
+                li {
+                    {t!("credits-db-source")}
+                    " "
+                    a { href: "{db_url}", target: "_blank", rel: "noopener noreferrer", "{db_url}" }
+                    if is_default_db {
+                        " · "
+                        {t!("credits-license", license: EXERCISE_DB_LICENSE)}
+                    }
+                }
+                li { {t!("more-db-exercises-count", count: exercises.read().len())} }
+                li { {t!("credits-last-fetch", time: last_fetch)} }
+            }
+            div { class: "inputs",
+                button {
+                    class: "label more",
+                    onclick: move |_| super::exercise_sources::refresh_exercise_db(),
+                    {t!("more-db-refresh-btn")}
+                }
+            }
+        }
+    }
+}
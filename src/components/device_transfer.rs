@@ -0,0 +1,231 @@
+use crate::services::crypto::SecretKey;
+use crate::services::storage;
+use crate::services::transfer::{self, PairingOffer, TransferBundle};
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// ID of the `<video>` element used as camera preview while scanning.
+const SCAN_VIDEO_ID: &str = "transfer-scan-video";
+/// Current step of the transfer flow.
+#[derive(Clone, PartialEq)]
+enum Step {
+    Idle,
+    /// Old device: showing the offer, waiting for the answer code.
+    Offer {
+        svg: String,
+        code: String,
+    },
+    /// New device: waiting for the offer code.
+    AwaitOffer,
+    /// New device: showing the answer, waiting for the data.
+    Answer {
+        svg: String,
+        code: String,
+    },
+    /// Work in progress (connecting / transferring).
+    Busy,
+}
+/// "Transfer to new device" card for the More page.
+///
+/// Pairing happens by exchanging two QR codes (offer then answer); the data
+/// itself then flows directly between the devices over WebRTC, encrypted with
+/// a one-time key carried in the offer.
+#[component]
+pub fn DeviceTransfer() -> Element {
+    let mut step = use_signal(|| Step::Idle);
+    let mut code_input = use_signal(String::new);
+    let mut scanning = use_signal(|| false);
+    let mut sender_key: Signal<Option<SecretKey>> = use_signal(|| None);
+    let sessions = storage::use_sessions();
+    let custom_exercises = storage::use_custom_exercises();
+    let toast = consume_context::<ToastSignal>().0;
+    let msg_failed = use_memo(|| t!("transfer-failed"));
+    let msg_timeout = use_memo(|| t!("transfer-timeout"));
+    // A timeout gets its own message, other errors are detailed.
+    let failure = move |e: &transfer::TransferError| match e {
+        transfer::TransferError::Timeout => msg_timeout(),
+        e => format!("{}: {e}", msg_failed()),
+    };
+    let msg_sent = use_memo(|| t!("transfer-sent"));
+    let msg_received = use_memo(|| t!("transfer-received"));
+    let start_send = move |_| {
+        let mut t = toast;
+        step.set(Step::Busy);
+        spawn(async move {
+            let result = async {
+                let key = transfer::new_transfer_key()?;
+                let sdp = transfer::create_offer().await?;
+                let code = transfer::encode_offer(&PairingOffer {
+                    key: key.clone(),
+                    sdp,
+                });
+                Ok::<_, transfer::TransferError>((key, code))
+            }
+            .await;
+            match result {
+                Ok((key, code)) => {
+                    sender_key.set(Some(key));
+                    let svg = transfer::qr_svg(&code).unwrap_or_default();
+                    step.set(Step::Offer { svg, code });
+                }
+                Err(e) => {
+                    t.write().push_back(format!("{}: {e}", msg_failed()));
+                    step.set(Step::Idle);
+                }
+            }
+        });
+    };
+    // Handles a code typed / scanned in either role.
+    let mut submit_code = move |code: String| {
+        let current = step.peek().clone();
+        let mut t = toast;
+        code_input.set(String::new());
+        match current {
+            Step::Offer { .. } => {
+                let Some(key) = sender_key.peek().clone() else {
+                    return;
+                };
+                step.set(Step::Busy);
+                spawn(async move {
+                    let result = async {
+                        let answer = transfer::decode_answer(&code)?;
+                        let sessions = super::more::load_all_sessions(sessions.peek().clone())
+                            .await
+                            .map_err(|e| transfer::TransferError::Format(e.to_string()))?;
+                        let bundle = TransferBundle {
+                            sessions,
+                            custom_exercises: custom_exercises
+                                .peek()
+                                .iter()
+                                .map(|e| e.as_ref().clone())
+                                .collect(),
+                        };
+                        let sealed = transfer::seal_bundle(&key, &bundle)?;
+                        transfer::send_bundle(&answer, &sealed).await?;
+                        Ok::<_, transfer::TransferError>(bundle.sessions.len())
+                    }
+                    .await;
+                    match result {
+                        Ok(n) => t.write().push_back(format!("📤 {n} {}", msg_sent())),
+                        Err(e) => t.write().push_back(failure(&e)),
+                    }
+                    sender_key.set(None);
+                    step.set(Step::Idle);
+                });
+            }
+            Step::AwaitOffer => {
+                step.set(Step::Busy);
+                spawn(async move {
+                    let result = async {
+                        let offer = transfer::decode_offer(&code)?;
+                        let answer = transfer::accept_offer(&offer.sdp).await?;
+                        let answer_code = transfer::encode_answer(&answer);
+                        step.set(Step::Answer {
+                            svg: transfer::qr_svg(&answer_code).unwrap_or_default(),
+                            code: answer_code,
+                        });
+                        let sealed = transfer::receive_bundle().await?;
+                        transfer::open_bundle(&offer.key, &sealed)
+                    }
+                    .await;
+                    match result {
+                        Ok(bundle) => {
+                            let n = bundle.sessions.len();
+                            for session in bundle.sessions {
+                                storage::save_session(session);
+                            }
+                            for exercise in bundle.custom_exercises {
+                                let exists =
+                                    custom_exercises.peek().iter().any(|c| c.id == exercise.id);
+                                if !exists {
+                                    storage::add_custom_exercise(exercise);
+                                }
+                            }
+                            t.write().push_back(format!("📥 {n} {}", msg_received()));
+                        }
+                        Err(e) => t.write().push_back(failure(&e)),
+                    }
+                    step.set(Step::Idle);
+                });
+            }
+            _ => {}
+        }
+    };
+    let start_scan = move |_| {
+        scanning.set(true);
+        spawn(async move {
+            let result = transfer::scan_qr_code(SCAN_VIDEO_ID).await;
+            scanning.set(false);
+            match result {
+                Ok(code) => submit_code(code),
+                Err(e) => log::warn!("QR scan unavailable: {e}"),
+            }
+        });
+    };
+    let awaiting_code = matches!(*step.read(), Step::Offer { .. } | Step::AwaitOffer);
+    rsx! {
+        article {
+            h2 { {t!("transfer-section")} }
+            p { {t!("transfer-desc")} }
+            match step() {
+                Step::Idle => rsx! {
+                    div { class: "inputs",
+                        button { class: "label save", onclick: start_send, {t!("transfer-send-btn")} }
+                        button {
+                            class: "label more",
+                            onclick: move |_| step.set(Step::AwaitOffer),
+                            {t!("transfer-receive-btn")}
+                        }
+                    }
+                },
+                Step::Offer { svg, code } | Step::Answer { svg, code } => rsx! {
+                    p { {t!("transfer-show-code")} }
+                    div { class: "qr", dangerous_inner_html: "{svg}" }
+                    input { r#type: "text", readonly: true, value: "{code}" }
+                },
+                Step::AwaitOffer => rsx! {},
+                Step::Busy => rsx! {
+                    p { "⏳" }
+                },
+            }
+            if awaiting_code {
+                p { {t!("transfer-scan-hint")} }
+                video {
+                    id: SCAN_VIDEO_ID,
+                    hidden: !*scanning.read(),
+                    autoplay: true,
+                    playsinline: true,
+                }
+                form {
+                    onsubmit: move |evt: Event<FormData>| {
+                        evt.prevent_default();
+                        let code = code_input.peek().trim().to_owned();
+                        if !code.is_empty() {
+                            submit_code(code);
+                        }
+                    },
+                    input {
+                        r#type: "text",
+                        value: "{code_input}",
+                        placeholder: t!("transfer-paste-placeholder"),
+                        oninput: move |evt| code_input.set(evt.value()),
+                    }
+                    button {
+                        r#type: "button",
+                        class: "icon more",
+                        aria_label: t!("transfer-scan-aria"),
+                        onclick: start_scan,
+                        "📷"
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "icon save",
+                        aria_label: t!("transfer-submit-aria"),
+                        "✔️"
+                    }
+                }
+                button { class: "no label", onclick: move |_| step.set(Step::Idle), "❌" }
+            }
+        }
+    }
+}
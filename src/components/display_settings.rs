@@ -0,0 +1,71 @@
+use crate::services::display::UiScale;
+use crate::services::storage;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use strum::IntoEnumIterator;
+/// Localised name of a UI `scale`.
+fn scale_label(scale: UiScale) -> String {
+    match scale {
+        UiScale::Normal => t!("display-scale-normal"),
+        UiScale::Large => t!("display-scale-large"),
+        UiScale::ExtraLarge => t!("display-scale-extra-large"),
+    }
+}
+/// Settings card of the text size and of the session view touch targets and
+/// layout (shown on the More page).
+#[component]
+pub fn DisplaySettings() -> Element {
+    let prefs = storage::use_display_preferences();
+    let large_targets = prefs.read().large_targets;
+    let one_handed = prefs.read().one_handed;
+    rsx! {
+        article {
+            h2 { {t!("display-section")} }
+            p { {t!("display-desc")} }
+            select {
+                aria_label: t!("display-scale-aria"),
+                oninput: move |evt| {
+                    if let Ok(scale) = evt.value().parse() {
+                        let mut next = prefs.peek().clone();
+                        next.scale = scale;
+                        storage::save_display_preferences(next);
+                    }
+                },
+                for scale in UiScale::iter() {
+                    option {
+                        value: scale.as_ref(),
+                        selected: prefs.read().scale == scale,
+                        {scale_label(scale)}
+                    }
+                }
+            }
+            button {
+                class: if large_targets { "label less" } else { "label save" },
+                onclick: move |_| {
+                    let mut next = prefs.peek().clone();
+                    next.large_targets = !next.large_targets;
+                    storage::save_display_preferences(next);
+                },
+                if large_targets {
+                    {t!("display-large-targets-disable-btn")}
+                } else {
+                    {t!("display-large-targets-enable-btn")}
+                }
+            }
+            button {
+                class: if one_handed { "label less" } else { "label save" },
+                title: t!("display-one-handed-title"),
+                onclick: move |_| {
+                    let mut next = prefs.peek().clone();
+                    next.one_handed = !next.one_handed;
+                    storage::save_display_preferences(next);
+                },
+                if one_handed {
+                    {t!("display-one-handed-disable-btn")}
+                } else {
+                    {t!("display-one-handed-enable-btn")}
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,14 @@
-use crate::components::exercise_form_fields::ExerciseFormFields;
-use crate::models::{Equipment, Exercise, Force};
-use crate::services::storage;
+use crate::components::{ExerciseForm, HoldDeleteButton};
+use crate::models::Exercise;
+use crate::services::{storage, upstream_export};
+use crate::ToastSignal;
 use dioxus::prelude::*;
 use dioxus_i18n::t;
 #[component]
 pub fn EditExercise(id: String) -> Element {
     let custom_exercises = storage::use_custom_exercises();
     let exercise = use_memo(move || custom_exercises.read().iter().find(|e| e.id == id).cloned());
+    let mut toast = use_context::<ToastSignal>().0;
     let Some(ex) = exercise() else {
         return rsx! {
             main { class: "edit",
@@ -20,42 +22,19 @@ pub fn EditExercise(id: String) -> Element {
             }
         };
     };
-    let name_input = use_signal(|| ex.name.clone());
-    let category_input = use_signal(|| ex.category);
-    let force_input: Signal<Option<Force>> = use_signal(|| ex.force);
-    let equipment_input: Signal<Option<Equipment>> = use_signal(|| ex.equipment);
-    let muscle_input = use_signal(String::new);
-    let muscles_list = use_signal(|| ex.primary_muscles.clone());
-    let secondary_muscle_input = use_signal(String::new);
-    let secondary_muscles_list = use_signal(|| ex.secondary_muscles.clone());
-    let instructions_input = use_signal(String::new);
-    let instructions_list = use_signal(|| ex.instructions.clone());
-    let image_url_input = use_signal(String::new);
-    let images_list = use_signal(|| ex.images.clone());
-    let exercise_id = ex.id.clone();
-    let exercise_level = ex.level;
-    let exercise_mechanic = ex.mechanic;
-    let save_exercise = move |()| {
-        let name = name_input.read().trim().to_string();
-        if name.is_empty() {
-            return;
+    let delete_id = ex.id.clone();
+    let export_upstream = {
+        let ex = ex.clone();
+        move |_| {
+            let (filename, json) = upstream_export::export_exercise(&ex);
+            if let Some(msg) =
+                crate::components::more::trigger_download(&filename, &json, "application/json")
+            {
+                toast.write().push_back(msg);
+            }
         }
-        let name_lower = name.to_lowercase();
-        let updated = Exercise {
-            id: exercise_id.clone(),
-            name,
-            name_lower,
-            category: *category_input.read(),
-            force: *force_input.read(),
-            level: exercise_level,
-            mechanic: exercise_mechanic,
-            equipment: *equipment_input.read(),
-            primary_muscles: muscles_list.read().clone(),
-            secondary_muscles: secondary_muscles_list.read().clone(),
-            instructions: instructions_list.read().clone(),
-            images: images_list.read().clone(),
-            i18n: None,
-        };
+    };
+    let save_exercise = move |updated: Exercise| {
         storage::update_custom_exercise(updated);
         navigator().go_back();
     };
@@ -69,24 +48,26 @@ pub fn EditExercise(id: String) -> Element {
                 "❌"
             }
             h1 { {t!("edit-exercise-page-title")} }
+            HoldDeleteButton {
+                title: t!("exercise-delete-title").to_string(),
+                on_delete: move |()| {
+                    storage::delete_custom_exercise(&delete_id);
+                    navigator().go_back();
+                },
+            }
         }
         main { class: "edit",
-            ExerciseFormFields {
-                name_input,
-                category_input,
-                force_input,
-                equipment_input,
-                muscle_input,
-                muscles_list,
-                secondary_muscle_input,
-                secondary_muscles_list,
-                instructions_input,
-                instructions_list,
-                image_url_input,
-                images_list,
+            ExerciseForm {
+                exercise: Some((*ex).clone()),
                 save_label: t!("exercise-save-changes"),
                 on_save: save_exercise,
             }
+            button {
+                class: "label more",
+                onclick: export_upstream,
+                title: t!("exercise-export-upstream-title"),
+                {t!("exercise-export-upstream-btn")}
+            }
         }
     }
 }
@@ -0,0 +1,130 @@
+use crate::components::exercise_card::translate_enum;
+use crate::models::{Equipment, EquipmentProfile};
+use crate::services::storage;
+use crate::DbI18nSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+use strum::IntoEnumIterator;
+/// Picker of the equipment profile filtering exercise search, shown next to
+/// the search inputs once at least one profile is defined.
+#[component]
+pub fn EquipmentProfileSelect() -> Element {
+    let profiles = storage::use_equipment_profiles();
+    if profiles.read().profiles.is_empty() {
+        return rsx! {};
+    }
+    let active = profiles.read().active.clone().unwrap_or_default();
+    rsx! {
+        select {
+            class: "equipment-profile",
+            title: t!("equipment-profile-select-title"),
+            value: "{active}",
+            oninput: move |evt| {
+                let mut next = profiles.peek().clone();
+                let name = evt.value();
+                next.active = (!name.is_empty()).then_some(name);
+                storage::save_equipment_profiles(next);
+            },
+            option { value: "", selected: active.is_empty(), {t!("equipment-profile-all")} }
+            for profile in profiles.read().profiles.iter() {
+                option {
+                    value: "{profile.name}",
+                    selected: profile.name == active,
+                    "🏠 {profile.name}"
+                }
+            }
+        }
+    }
+}
+/// Settings card to define equipment profiles (shown on the More page).
+#[component]
+pub fn EquipmentProfilesSettings() -> Element {
+    let profiles = storage::use_equipment_profiles();
+    let mut name_input = use_signal(String::new);
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    let lang = use_memo(move || i18n().language().to_string());
+    let add_profile = move |evt: FormEvent| {
+        evt.prevent_default();
+        let name = name_input.peek().trim().to_owned();
+        let mut next = profiles.peek().clone();
+        if name.is_empty() || next.profiles.iter().any(|p| p.name == name) {
+            return;
+        }
+        next.profiles.push(EquipmentProfile {
+            name,
+            equipment: Vec::new(),
+        });
+        storage::save_equipment_profiles(next);
+        name_input.set(String::new());
+    };
+    let toggle = move |index: usize, equipment: Equipment| {
+        let mut next = profiles.peek().clone();
+        if let Some(profile) = next.profiles.get_mut(index) {
+            if let Some(pos) = profile.equipment.iter().position(|&e| e == equipment) {
+                profile.equipment.remove(pos);
+            } else {
+                profile.equipment.push(equipment);
+            }
+            storage::save_equipment_profiles(next);
+        }
+    };
+    let remove = move |index: usize| {
+        let mut next = profiles.peek().clone();
+        if index < next.profiles.len() {
+            let removed = next.profiles.remove(index);
+            if next.active.as_deref() == Some(removed.name.as_str()) {
+                next.active = None;
+            }
+            storage::save_equipment_profiles(next);
+        }
+    };
+    // Body-only exercises are doable with any profile.
+    let choices: Vec<Equipment> = Equipment::iter()
+        .filter(|&e| e != Equipment::BodyOnly)
+        .collect();
+    rsx! {
+        article {
+            h2 { {t!("equipment-profiles-section")} }
+            p { {t!("equipment-profiles-desc")} }
+            for (index, profile) in profiles.read().profiles.iter().enumerate() {
+                div { key: "{profile.name}",
+                    div { class: "inputs",
+                        h3 { "🏠 {profile.name}" }
+                        button {
+                            class: "del",
+                            title: t!("equipment-profile-delete"),
+                            onclick: move |_| remove(index),
+                            "🗑️"
+                        }
+                    }
+                    ul { class: "tags",
+                        for equipment in choices.iter().copied() {
+                            li { key: "{equipment}",
+                                button {
+                                    class: if profile.equipment.contains(&equipment) { "label save" } else { "label" },
+                                    onclick: move |_| toggle(index, equipment),
+                                    {translate_enum(&db_i18n.read(), &lang.read(), "equipment", equipment.as_ref()).to_owned()}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            form { class: "inputs", onsubmit: add_profile,
+                input {
+                    r#type: "text",
+                    placeholder: t!("equipment-profile-name-placeholder"),
+                    value: "{name_input}",
+                    oninput: move |evt| name_input.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "more",
+                    title: t!("equipment-profile-add"),
+                    "+"
+                }
+            }
+        }
+    }
+}
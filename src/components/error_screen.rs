@@ -0,0 +1,42 @@
+use crate::components::more::trigger_download;
+use crate::models::get_current_timestamp;
+use crate::services::diagnostics;
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// Recovery screen shown in place of the pages when rendering failed or
+/// panicked.  `on_retry` renders the pages again; diagnostics (recent log
+/// lines, storage metadata and `message`) can be exported for a bug report.
+#[component]
+pub fn ErrorScreen(message: String, on_retry: EventHandler) -> Element {
+    let mut toast = use_context::<ToastSignal>().0;
+    let error = message.clone();
+    let export = move |_| {
+        let error = error.clone();
+        spawn(async move {
+            let report = diagnostics::diagnostics_report(&error).await;
+            let filename = format!("logout_diagnostics_{}.json", get_current_timestamp());
+            if let Some(msg) = trigger_download(&filename, &report, "application/json") {
+                toast.write().push_back(msg);
+            }
+        });
+    };
+    rsx! {
+        main { class: "error-screen",
+            h1 { {t!("error-title")} }
+            p { {t!("error-desc")} }
+            pre { "{message}" }
+            div {
+                button { class: "label save", onclick: move |_| on_retry.call(()), {t!("error-retry-btn")} }
+                button {
+                    class: "label more",
+                    onclick: move |_| {
+                        document::eval("location.reload()");
+                    },
+                    {t!("error-reload-btn")}
+                }
+                button { class: "label more", onclick: export, {t!("error-export-btn")} }
+            }
+        }
+    }
+}
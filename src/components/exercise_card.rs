@@ -1,6 +1,8 @@
-use crate::models::{get_current_timestamp, DbI18n, Exercise};
+use crate::models::{get_current_timestamp, is_web_url, video_embed_url, DbI18n, Exercise};
+use crate::services::exercise_db::{highlight_range, MatchField};
+use crate::services::exercise_images::ExerciseImages;
 use crate::services::storage;
-use crate::{DbI18nSignal, Route};
+use crate::{DbI18nSignal, Route, ToastSignal};
 use dioxus::prelude::*;
 use dioxus_i18n::{prelude::i18n, t};
 use std::sync::Arc;
@@ -13,7 +15,12 @@ use std::sync::Arc;
 ///
 /// `lang` is a BCP-47 tag (e.g. `"fr"` or `"fr-FR"`).  Prefix matching
 /// (e.g. `"fr-FR"` → `"fr"`) is attempted automatically.
-fn translate_enum<'a>(db_i18n: &'a DbI18n, lang: &str, field: &str, value: &'a str) -> &'a str {
+pub(crate) fn translate_enum<'a>(
+    db_i18n: &'a DbI18n,
+    lang: &str,
+    field: &str,
+    value: &'a str,
+) -> &'a str {
     let lookup = |l: &str| -> Option<&'a str> {
         let lang_data = db_i18n.get(l)?;
         let map = match field {
@@ -32,6 +39,48 @@ fn translate_enum<'a>(db_i18n: &'a DbI18n, lang: &str, field: &str, value: &'a s
         .unwrap_or(value)
 }
 
+/// `text` with the first occurrence of the search `query` in it marked.
+#[component]
+pub(crate) fn Highlighted(text: String, query: String) -> Element {
+    match highlight_range(&text, &query) {
+        Some(range) => rsx! {
+            {text[..range.start].to_owned()}
+            mark { {text[range.clone()].to_owned()} }
+            {text[range.end..].to_owned()}
+        },
+        None => rsx! { "{text}" },
+    }
+}
+
+/// Small subtitle of a search result telling what the search `query`
+/// matched, when not the displayed name: an alias or another language name.
+#[component]
+pub(crate) fn MatchSubtitle(field: MatchField, query: String) -> Element {
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    let (label, text) = match field {
+        MatchField::Name => return rsx! {},
+        MatchField::Alias(alias) => (t!("search-match-alias"), alias),
+        MatchField::OtherLanguage(name) => (t!("search-match-other-language"), name),
+        MatchField::Muscle(muscle) => {
+            let lang = i18n().language().to_string();
+            let name =
+                translate_enum(&db_i18n.read(), &lang, "muscles", muscle.as_ref()).to_owned();
+            (t!("search-match-muscle"), name)
+        }
+        MatchField::Instructions => {
+            return rsx! {
+                small { class: "match", {t!("search-match-instructions")} }
+            };
+        }
+    };
+    rsx! {
+        small { class: "match",
+            "{label} "
+            Highlighted { text, query }
+        }
+    }
+}
+
 /// Renders a single exercise image, handling both regular URLs and `idb:`-prefixed
 /// keys that require async loading from `IndexedDB` on web.  Clicking cycles through
 /// multiple images when more than one is available.
@@ -156,11 +205,256 @@ fn ExerciseImage(exercise: Arc<Exercise>, display_name: String) -> Element {
     }
 }
 
+/// Button keeping the images of an exercise downloaded for offline use (see
+/// [`crate::services::image_prefetch`]), or letting them go.
+#[component]
+fn OfflineToggle(exercise_id: String) -> Element {
+    let overrides = storage::use_exercise_overrides();
+    let mut toast = use_context::<ToastSignal>().0;
+    let kept = overrides
+        .read()
+        .get(&exercise_id)
+        .is_some_and(|o| o.offline);
+    rsx! {
+        button {
+            class: "edit",
+            aria_pressed: kept,
+            title: if kept { t!("exercise-offline-forget") } else { t!("exercise-offline-keep") },
+            onclick: move |_| {
+                let mut over = overrides.peek().get(&exercise_id).cloned().unwrap_or_default();
+                over.offline = !kept;
+                storage::set_exercise_override(&exercise_id, over);
+                if !kept {
+                    toast.write().push_back(t!("exercise-offline-toast"));
+                }
+            },
+            "📥"
+        }
+    }
+}
+/// Technique videos of an expanded exercise card, embedded when the host is
+/// known and linked otherwise, with an editor for the personal video link.
+#[component]
+fn ExerciseVideos(exercise: Arc<Exercise>) -> Element {
+    let overrides = storage::use_exercise_overrides();
+    let mut editing = use_signal(|| false);
+    let mut url_input = use_signal(String::new);
+    let personal = overrides.read().get(&exercise.id).cloned();
+    let personal_url = personal.as_ref().and_then(|p| p.video.clone());
+    let videos: Vec<String> = exercise
+        .videos_with(personal.as_ref())
+        .into_iter()
+        .filter(|url| is_web_url(url))
+        .map(str::to_owned)
+        .collect();
+    let save = {
+        let id = exercise.id.clone();
+        move |url: Option<String>| {
+            let mut over = overrides.peek().get(&id).cloned().unwrap_or_default();
+            over.video = url;
+            storage::set_exercise_override(&id, over);
+            editing.set(false);
+        }
+    };
+    rsx! {
+        for url in videos {
+            if let Some(embed) = video_embed_url(&url) {
+                iframe {
+                    key: "{url}",
+                    class: "video",
+                    src: "{embed}",
+                    title: t!("exercise-video-title"),
+                    allow: "fullscreen; picture-in-picture",
+                    allowfullscreen: true,
+                }
+            } else {
+                a {
+                    key: "{url}",
+                    href: "{url}",
+                    target: "_blank",
+                    rel: "noopener noreferrer",
+                    "🎬 {url}"
+                }
+            }
+        }
+        if *editing.read() {
+            div { class: "inputs",
+                input {
+                    r#type: "url",
+                    placeholder: t!("exercise-video-placeholder"),
+                    value: "{url_input}",
+                    oninput: move |evt| url_input.set(evt.value()),
+                }
+                button {
+                    class: "more",
+                    disabled: !is_web_url(&url_input.read()),
+                    title: t!("form-save-aria"),
+                    onclick: {
+                        let mut save = save.clone();
+                        move |_| save(Some(url_input.peek().trim().to_owned()))
+                    },
+                    "💾"
+                }
+                if personal_url.is_some() {
+                    button {
+                        class: "del",
+                        title: t!("exercise-video-remove"),
+                        onclick: {
+                            let mut save = save.clone();
+                            move |_| save(None)
+                        },
+                        "🗑️"
+                    }
+                }
+            }
+        } else {
+            button {
+                class: "edit",
+                title: if personal_url.is_some() { t!("exercise-video-edit") } else { t!("exercise-video-add") },
+                onclick: move |_| {
+                    url_input.set(personal_url.clone().unwrap_or_default());
+                    editing.set(true);
+                },
+                "🎬"
+            }
+        }
+    }
+}
+
+/// Personal search aliases of an expanded exercise card, removable tags
+/// followed by an input to add another.
+#[component]
+fn ExerciseAliases(exercise_id: String) -> Element {
+    let overrides = storage::use_exercise_overrides();
+    let mut alias_input = use_signal(String::new);
+    let aliases = overrides
+        .read()
+        .get(&exercise_id)
+        .map(|o| o.aliases.clone())
+        .unwrap_or_default();
+    let set_aliases = move |id: &str, aliases: Vec<String>| {
+        let mut over = overrides.peek().get(id).cloned().unwrap_or_default();
+        over.aliases = aliases;
+        storage::set_exercise_override(id, over);
+    };
+    let add_alias = {
+        let id = exercise_id.clone();
+        let aliases = aliases.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let alias = alias_input.peek().trim().to_owned();
+            let known = aliases
+                .iter()
+                .any(|a| a.to_lowercase() == alias.to_lowercase());
+            if !alias.is_empty() && !known {
+                let mut next = aliases.clone();
+                next.push(alias);
+                set_aliases(&id, next);
+            }
+            alias_input.set(String::new());
+        }
+    };
+    rsx! {
+        if !aliases.is_empty() {
+            ul { class: "tags",
+                for alias in aliases.iter() {
+                    li { key: "{alias}",
+                        button {
+                            class: "del label",
+                            title: t!("exercise-alias-remove"),
+                            onclick: {
+                                let id = exercise_id.clone();
+                                let remaining: Vec<String> = aliases
+                                    .iter()
+                                    .filter(|a| *a != alias)
+                                    .cloned()
+                                    .collect();
+                                move |_| set_aliases(&id, remaining.clone())
+                            },
+                            "{alias}"
+                        }
+                    }
+                }
+            }
+        }
+        form {
+            class: "inputs",
+            onsubmit: add_alias,
+            input {
+                r#type: "text",
+                placeholder: t!("exercise-alias-placeholder"),
+                value: "{alias_input}",
+                oninput: move |evt| alias_input.set(evt.value()),
+            }
+            button {
+                r#type: "submit",
+                class: "more",
+                title: t!("exercise-alias-add"),
+                "+"
+            }
+        }
+    }
+}
+
+/// Personal rest after a set of a database exercise, replacing its own and
+/// the default rest duration (custom exercises set it in their form).
+#[component]
+fn ExerciseRest(exercise: Arc<Exercise>) -> Element {
+    let overrides = storage::use_exercise_overrides();
+    let personal = overrides
+        .read()
+        .get(&exercise.id)
+        .and_then(|o| o.rest_seconds);
+    let mut rest_input = use_signal(|| personal.map(|s| s.to_string()).unwrap_or_default());
+    let save = {
+        let id = exercise.id.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let mut over = overrides.peek().get(&id).cloned().unwrap_or_default();
+            over.rest_seconds = rest_input.peek().trim().parse().ok();
+            storage::set_exercise_override(&id, over);
+        }
+    };
+    let placeholder = exercise
+        .rest_seconds
+        .map_or_else(|| t!("form-rest-placeholder"), |s| s.to_string());
+    rsx! {
+        form {
+            class: "inputs",
+            aria_label: t!("exercise-rest-aria"),
+            onsubmit: save,
+            label { r#for: "exercise-rest-{exercise.id}", {t!("form-rest-label")} }
+            input {
+                id: "exercise-rest-{exercise.id}",
+                r#type: "number",
+                inputmode: "numeric",
+                min: "0",
+                placeholder,
+                value: "{rest_input}",
+                oninput: move |evt| rest_input.set(evt.value()),
+            }
+            button {
+                r#type: "submit",
+                class: "more",
+                disabled: rest_input.read().trim().parse::<u64>().ok() == personal,
+                title: t!("form-save-aria"),
+                "💾"
+            }
+        }
+    }
+}
+
 #[component]
 pub fn ExerciseCard(
     exercise: Arc<Exercise>,
     is_custom: bool,
     show_instructions_initial: Option<bool>,
+    /// What the search query matched in the exercise, if searched.
+    #[props(default)]
+    matched: Option<MatchField>,
+    /// Search query, whose occurrence in the name is highlighted.
+    #[props(default)]
+    query: String,
 ) -> Element {
     let initial = show_instructions_initial.unwrap_or(false);
     let mut show_instructions = use_signal(move || initial);
@@ -170,6 +464,10 @@ pub fn ExerciseCard(
     // below read this shared value so the BCP-47 lookup and prefix fallback
     // run only once per locale update, not three times.
     let lang_str = use_memo(move || i18n().language().to_string());
+    let avoid_list = storage::use_avoid_list();
+    let avoided = avoid_list.read().flags(&exercise, crate::datetime::today());
+    let overrides = storage::use_exercise_overrides();
+    let mut toast = use_context::<ToastSignal>().0;
 
     let display_name = {
         let ex = exercise.clone();
@@ -226,7 +524,13 @@ pub fn ExerciseCard(
                         let current = *show_instructions.read();
                         show_instructions.set(!current);
                     },
-                    "{display_name}"
+                    Highlighted { text: display_name(), query: query.clone() }
+                }
+                if let Some(field) = matched {
+                    MatchSubtitle { field, query }
+                }
+                if avoided {
+                    span { class: "avoid", title: t!("avoid-flag-title"), "⚠️" }
                 }
                 if is_custom {
                     Link {
@@ -257,7 +561,9 @@ pub fn ExerciseCard(
                                     secondary_muscles: exercise.secondary_muscles.clone(),
                                     instructions: exercise.instructions.clone(),
                                     images: exercise.images.clone(),
+                                    videos: exercise.videos.clone(),
                                     i18n: None,
+                                    rest_seconds: exercise.rest_seconds,
                                 };
                                 let clone_id = clone.id.clone();
                                 storage::add_custom_exercise(clone);
@@ -270,6 +576,23 @@ pub fn ExerciseCard(
                         title: t!("exercise-clone"),
                         "+"
                     }
+                    button {
+                        class: "edit",
+                        title: t!("exercise-hide"),
+                        onclick: {
+                            let id = exercise.id.clone();
+                            move |_| {
+                                let mut over = overrides.peek().get(&id).cloned().unwrap_or_default();
+                                over.hidden = true;
+                                storage::set_exercise_override(&id, over);
+                                toast.write().push_back(t!("exercise-hidden-toast"));
+                            }
+                        },
+                        "🙈"
+                    }
+                }
+                if !exercise.images.is_empty() {
+                    OfflineToggle { exercise_id: exercise.id.clone() }
                 }
             }
             if *show_instructions.read() && !display_instructions.read().is_empty() {
@@ -279,6 +602,16 @@ pub fn ExerciseCard(
                     }
                 }
             }
+            if *show_instructions.read() {
+                ExerciseVideos { exercise: exercise.clone() }
+                ExerciseAliases { exercise_id: exercise.id.clone() }
+                if !is_custom {
+                    ExerciseRest { exercise: exercise.clone() }
+                }
+                crate::components::ExerciseTags { exercise_id: exercise.id.clone() }
+                crate::components::ExerciseGoals { exercise_id: exercise.id.clone() }
+                crate::components::PlateauNotice { exercise_id: exercise.id.clone() }
+            }
             if !exercise.images.is_empty() {
                 ExerciseImage {
                     exercise: exercise.clone(),
@@ -1,37 +1,197 @@
-use crate::models::{Category, Equipment, Force, Muscle};
+use crate::models::{
+    get_current_timestamp, is_web_url, Category, Equipment, Exercise, Force, Level, Mechanic,
+    Muscle,
+};
+use crate::services::{drafts, storage};
 use dioxus::prelude::*;
 use dioxus_i18n::t;
+use std::sync::Arc;
 use strum::IntoEnumIterator;
-/// Shared form fields used by both `AddCustomExercisePage` and `EditCustomExercisePage`.
+/// Reason a custom exercise cannot be saved, shown next to its field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormError {
+    EmptyName,
+    DuplicateName,
+    NoPrimaryMuscle,
+}
+impl FormError {
+    fn message(self) -> String {
+        match self {
+            Self::EmptyName => t!("form-error-empty-name"),
+            Self::DuplicateName => t!("form-error-duplicate-name"),
+            Self::NoPrimaryMuscle => t!("form-error-no-primary-muscle"),
+        }
+    }
+}
+/// Errors of a custom exercise named `name` working `primary_muscles`,
+/// `existing` being the custom exercises it must not share its name with
+/// (case insensitively), except itself (`own_id`).
+fn validate(
+    name: &str,
+    primary_muscles: &[Muscle],
+    existing: &[Arc<Exercise>],
+    own_id: Option<&str>,
+) -> Vec<FormError> {
+    let name_lower = name.trim().to_lowercase();
+    let mut errors = Vec::new();
+    if name_lower.is_empty() {
+        errors.push(FormError::EmptyName);
+    } else if existing
+        .iter()
+        .any(|e| e.name_lower == name_lower && Some(e.id.as_str()) != own_id)
+    {
+        errors.push(FormError::DuplicateName);
+    }
+    if primary_muscles.is_empty() {
+        errors.push(FormError::NoPrimaryMuscle);
+    }
+    errors
+}
+/// Exercise the add form starts from.
+fn blank_exercise() -> Exercise {
+    Exercise {
+        id: String::new(),
+        name: String::new(),
+        name_lower: String::new(),
+        force: None,
+        level: None,
+        mechanic: None,
+        equipment: None,
+        primary_muscles: vec![],
+        secondary_muscles: vec![],
+        instructions: vec![],
+        category: Category::Strength,
+        images: vec![],
+        videos: vec![],
+        i18n: None,
+        rest_seconds: None,
+    }
+}
+/// Form of a custom exercise, blank to add one or filled with `exercise` to
+/// edit it.  The validated exercise, keeping the ID of `exercise` or given a
+/// new one, is passed to `on_save`.
 #[component]
-pub fn ExerciseFormFields(
-    name_input: Signal<String>,
-    category_input: Signal<Category>,
-    force_input: Signal<Option<Force>>,
-    equipment_input: Signal<Option<Equipment>>,
-    muscle_input: Signal<String>,
-    muscles_list: Signal<Vec<Muscle>>,
-    secondary_muscle_input: Signal<String>,
-    secondary_muscles_list: Signal<Vec<Muscle>>,
-    instructions_input: Signal<String>,
-    instructions_list: Signal<Vec<String>>,
-    image_url_input: Signal<String>,
-    images_list: Signal<Vec<String>>,
+pub fn ExerciseForm(
+    exercise: Option<Exercise>,
     save_label: String,
-    on_save: EventHandler<()>,
+    on_save: EventHandler<Exercise>,
 ) -> Element {
-    let mut name_input = name_input;
-    let mut category_input = category_input;
-    let mut force_input = force_input;
-    let mut equipment_input = equipment_input;
-    let mut muscle_input = muscle_input;
-    let mut muscles_list = muscles_list;
-    let mut secondary_muscle_input = secondary_muscle_input;
-    let mut secondary_muscles_list = secondary_muscles_list;
-    let mut instructions_input = instructions_input;
-    let mut instructions_list = instructions_list;
-    let mut image_url_input = image_url_input;
-    let mut images_list = images_list;
+    let original = exercise.clone().unwrap_or_else(blank_exercise);
+    let draft_key = format!("exercise:{}", exercise.as_ref().map_or("new", |e| &e.id));
+    let draft = use_hook({
+        let key = draft_key.clone();
+        move || drafts::load::<Exercise>(&key)
+    });
+    let mut restored = use_signal(|| draft.is_some());
+    let initial = draft.unwrap_or_else(|| original.clone());
+    let mut name_input = use_signal(|| initial.name.clone());
+    let mut category_input = use_signal(|| initial.category);
+    let mut force_input = use_signal(|| initial.force);
+    let mut level_input = use_signal(|| initial.level);
+    let mut mechanic_input = use_signal(|| initial.mechanic);
+    let mut equipment_input = use_signal(|| initial.equipment);
+    let mut muscle_input = use_signal(String::new);
+    let mut muscles_list = use_signal(|| initial.primary_muscles.clone());
+    let mut secondary_muscle_input = use_signal(String::new);
+    let mut secondary_muscles_list = use_signal(|| initial.secondary_muscles.clone());
+    let mut instructions_input = use_signal(String::new);
+    let mut instructions_list = use_signal(|| initial.instructions.clone());
+    let mut image_url_input = use_signal(String::new);
+    let mut images_list = use_signal(|| initial.images.clone());
+    let mut videos_list = use_signal(|| initial.videos.clone());
+    let mut rest_input = use_signal(|| initial.rest_seconds);
+    let mut submitted = use_signal(|| false);
+    let custom_exercises = storage::use_custom_exercises();
+    let own_id = exercise.as_ref().map(|e| e.id.clone());
+    let errors = {
+        let own_id = own_id.clone();
+        use_memo(move || {
+            validate(
+                &name_input.read(),
+                &muscles_list.read(),
+                &custom_exercises.read(),
+                own_id.as_deref(),
+            )
+        })
+    };
+    let field_error = move |field: &[FormError]| {
+        errors
+            .read()
+            .iter()
+            .find(|e| field.contains(e))
+            .filter(|e| submitted() || **e == FormError::DuplicateName)
+            .map(|e| e.message())
+    };
+    // Exercise as currently filled in, name untrimmed.
+    let filled = {
+        let id = original.id.clone();
+        move || Exercise {
+            id: id.clone(),
+            name: name_input.read().clone(),
+            name_lower: String::new(),
+            category: *category_input.read(),
+            force: *force_input.read(),
+            level: *level_input.read(),
+            mechanic: *mechanic_input.read(),
+            equipment: *equipment_input.read(),
+            primary_muscles: muscles_list.read().clone(),
+            secondary_muscles: secondary_muscles_list.read().clone(),
+            instructions: instructions_list.read().clone(),
+            images: images_list.read().clone(),
+            videos: videos_list.read().clone(),
+            i18n: None,
+            rest_seconds: *rest_input.read(),
+        }
+    };
+    {
+        let key = draft_key.clone();
+        let original = original.clone();
+        let filled = filled.clone();
+        use_effect(move || {
+            let current = filled();
+            // `name_lower` is not serialised, so comparing JSON ignores it.
+            if serde_json::to_value(&current).ok() == serde_json::to_value(&original).ok() {
+                drafts::clear(&key);
+            } else {
+                drafts::save(&key, &current);
+            }
+        });
+    }
+    let discard_draft = {
+        let key = draft_key.clone();
+        move |_| {
+            name_input.set(original.name.clone());
+            category_input.set(original.category);
+            force_input.set(original.force);
+            level_input.set(original.level);
+            mechanic_input.set(original.mechanic);
+            equipment_input.set(original.equipment);
+            muscles_list.set(original.primary_muscles.clone());
+            secondary_muscles_list.set(original.secondary_muscles.clone());
+            instructions_list.set(original.instructions.clone());
+            images_list.set(original.images.clone());
+            videos_list.set(original.videos.clone());
+            rest_input.set(original.rest_seconds);
+            submitted.set(false);
+            restored.set(false);
+            drafts::clear(&key);
+        }
+    };
+    let save = move |_| {
+        submitted.set(true);
+        if !errors.read().is_empty() {
+            return;
+        }
+        let mut exercise = filled();
+        exercise.name = exercise.name.trim().to_string();
+        exercise.name_lower = exercise.name.to_lowercase();
+        if own_id.is_none() {
+            exercise.id = format!("custom_{}", get_current_timestamp());
+        }
+        drafts::clear(&draft_key);
+        on_save.call(exercise);
+    };
+    let mut video_url_input = use_signal(String::new);
     #[cfg(not(target_arch = "wasm32"))]
     let mut local_image_path_input = use_signal(String::new);
     let add_muscle = move |_| {
@@ -206,6 +366,19 @@ pub fn ExerciseFormFields(
             }
         }
     };
+    let add_video = move |_| {
+        let url = video_url_input.read().trim().to_string();
+        if is_web_url(&url) && !videos_list.read().contains(&url) {
+            videos_list.write().push(url);
+            video_url_input.set(String::new());
+        }
+    };
+    let mut remove_video = move |idx: usize| {
+        let mut videos = videos_list.write();
+        if idx < videos.len() {
+            videos.remove(idx);
+        }
+    };
     #[cfg(target_arch = "wasm32")]
     let image_upload_widget: Element = rsx! {
         div { class: "inputs",
@@ -230,16 +403,28 @@ pub fn ExerciseFormFields(
             button { class: "more", onclick: add_local_image, "📁" }
         }
     };
+    let name_error = field_error(&[FormError::EmptyName, FormError::DuplicateName]);
+    let muscles_error = field_error(&[FormError::NoPrimaryMuscle]);
     rsx! {
+        if restored() {
+            div { class: "inputs",
+                p { {t!("form-draft-restored")} }
+                button { class: "label", onclick: discard_draft, {t!("form-draft-discard-btn")} }
+            }
+        }
         div {
             label { r#for: "exercise-name-input", {t!("form-name-label")} }
             input {
                 id: "exercise-name-input",
                 r#type: "text",
+                class: if name_error.is_some() { "invalid" } else { "" },
                 placeholder: t!("form-name-placeholder"),
                 value: "{name_input}",
                 oninput: move |evt| name_input.set(evt.value()),
             }
+            if let Some(error) = &name_error {
+                p { class: "error", "{error}" }
+            }
         }
         div {
             label { {t!("form-category-label")} }
@@ -277,6 +462,46 @@ pub fn ExerciseFormFields(
                 }
             }
         }
+        div {
+            label { {t!("form-level-label")} }
+            select {
+                value: if let Some(l) = *level_input.read() { l.to_string() } else { String::new() },
+                oninput: move |evt| {
+                    let val = evt.value();
+                    if val.is_empty() {
+                        level_input.set(None);
+                    } else if let Ok(l) = serde_json::from_value::<
+                        Level,
+                    >(serde_json::Value::String(val)) {
+                        level_input.set(Some(l));
+                    }
+                },
+                option { value: "", {t!("form-none-option")} }
+                for level in Level::iter() {
+                    option { value: "{level}", "{level}" }
+                }
+            }
+        }
+        div {
+            label { {t!("form-mechanic-label")} }
+            select {
+                value: if let Some(m) = *mechanic_input.read() { m.to_string() } else { String::new() },
+                oninput: move |evt| {
+                    let val = evt.value();
+                    if val.is_empty() {
+                        mechanic_input.set(None);
+                    } else if let Ok(m) = serde_json::from_value::<
+                        Mechanic,
+                    >(serde_json::Value::String(val)) {
+                        mechanic_input.set(Some(m));
+                    }
+                },
+                option { value: "", {t!("form-none-option")} }
+                for mechanic in Mechanic::iter() {
+                    option { value: "{mechanic}", "{mechanic}" }
+                }
+            }
+        }
         div {
             label { {t!("form-equipment-label")} }
             select {
@@ -310,6 +535,9 @@ pub fn ExerciseFormFields(
                 }
                 button { class: "more", onclick: add_muscle, "+" }
             }
+            if let Some(error) = &muscles_error {
+                p { class: "error", "{error}" }
+            }
             if !muscles_list.read().is_empty() {
                 ul { class: "tags",
                     for muscle in muscles_list.read().iter() {
@@ -411,11 +639,70 @@ pub fn ExerciseFormFields(
                 }
             }
         }
+        div {
+            label { {t!("form-videos-label")} }
+            div { class: "inputs",
+                input {
+                    r#type: "url",
+                    placeholder: t!("exercise-video-placeholder"),
+                    value: "{video_url_input}",
+                    oninput: move |evt| video_url_input.set(evt.value()),
+                }
+                button { class: "more", onclick: add_video, "+" }
+            }
+            if !videos_list.read().is_empty() {
+                ul { class: "tags",
+                    for (idx, url) in videos_list.read().iter().enumerate() {
+                        li { key: "{idx}",
+                            button {
+                                class: "del label",
+                                onclick: move |_| remove_video(idx),
+                                "{url}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        div {
+            label { r#for: "exercise-rest-field", {t!("form-rest-label")} }
+            input {
+                id: "exercise-rest-field",
+                r#type: "number",
+                inputmode: "numeric",
+                min: "0",
+                placeholder: t!("form-rest-placeholder"),
+                value: rest_input().map(|s| s.to_string()).unwrap_or_default(),
+                oninput: move |evt| rest_input.set(evt.value().trim().parse().ok()),
+            }
+        }
         button {
             class: "edit label",
-            onclick: move |_| on_save.call(()),
-            disabled: name_input.read().trim().is_empty(),
+            onclick: save,
+            disabled: submitted() && !errors.read().is_empty(),
             "💾 {save_label}"
         }
     }
 }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn rejects_duplicate_names_and_missing_primary_muscles() {
+        let existing = vec![Arc::new(Exercise {
+            id: "custom_1".into(),
+            name: "Ring Dip".into(),
+            name_lower: "ring dip".into(),
+            ..blank_exercise()
+        })];
+        assert_eq!(
+            validate(" ring DIP ", &[], &existing, None),
+            [FormError::DuplicateName, FormError::NoPrimaryMuscle]
+        );
+        assert!(validate("Ring Dip", &[Muscle::Triceps], &existing, Some("custom_1")).is_empty());
+        assert_eq!(
+            validate("  ", &[Muscle::Triceps], &existing, None),
+            [FormError::EmptyName]
+        );
+    }
+}
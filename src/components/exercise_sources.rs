@@ -0,0 +1,136 @@
+use crate::services::exercise_db;
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// Download the exercise database again from all its sources, bypassing the
+/// cached `ETag`, unless a download is already running (see
+/// [`crate::ExerciseDbDownloadSignal`]).
+pub(crate) fn refresh_exercise_db() {
+    let download = consume_context::<crate::ExerciseDbDownloadSignal>().0;
+    if download.peek().is_downloading() {
+        return;
+    }
+    exercise_db::clear_fetch_cache();
+    let sig = consume_context::<exercise_db::AllExercisesSignal>().0;
+    let toast = consume_context::<ToastSignal>().0;
+    #[cfg(not(target_arch = "wasm32"))]
+    let img_progress = consume_context::<crate::ImageDownloadProgressSignal>().0;
+    spawn(async move {
+        #[cfg(target_arch = "wasm32")]
+        exercise_db::reload_exercises(sig, toast, download).await;
+        #[cfg(not(target_arch = "wasm32"))]
+        exercise_db::reload_exercises(sig, toast, download, img_progress).await;
+    });
+}
+/// Settings card listing the additional exercise databases merged into the
+/// main one, their exercise IDs prefixed by the source (shown on the More
+/// page).
+#[component]
+pub fn ExerciseSourcesSettings() -> Element {
+    let mut sources = use_signal(crate::utils::get_extra_exercise_db_urls);
+    let mut url_input = use_signal(String::new);
+    let mut save = move |urls: Vec<String>| {
+        crate::utils::set_extra_exercise_db_urls(&urls);
+        sources.set(urls);
+        refresh_exercise_db();
+    };
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        let url = crate::utils::normalize_db_url(url_input.peek().trim());
+        url_input.set(String::new());
+        if url.is_empty() || sources.peek().contains(&url) {
+            return;
+        }
+        let mut urls = sources.peek().clone();
+        urls.push(url);
+        save(urls);
+    };
+    rsx! {
+        article {
+            h2 { {t!("exercise-sources-section")} }
+            p { {t!("exercise-sources-desc")} }
+            ul {
+                for url in sources.read().iter().cloned() {
+                    li { key: "{url}",
+                        span { "{url}" }
+                        span { class: "namespace", "{exercise_db::source_namespace(&url)}:" }
+                        button {
+                            class: "del label",
+                            aria_label: t!("exercise-sources-remove-aria"),
+                            onclick: move |_| {
+                                let urls = sources.peek().iter().filter(|u| **u != url).cloned().collect();
+                                save(urls);
+                            },
+                            "🗑️"
+                        }
+                    }
+                }
+            }
+            form { onsubmit: on_submit,
+                input {
+                    r#type: "url",
+                    value: "{url_input}",
+                    placeholder: "https://example.com/exercise-db/",
+                    oninput: move |evt| url_input.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "icon save",
+                    disabled: url_input.read().trim().is_empty(),
+                    aria_label: t!("exercise-sources-add-aria"),
+                    "➕"
+                }
+            }
+        }
+    }
+}
+/// Settings card for the language pack: translated names and instructions of
+/// the exercises, fetched from `exercises.<lang>.json` files under a base URL
+/// and shown in the app language (on the More page).
+#[component]
+pub fn LanguagePackSettings() -> Element {
+    let mut pack_url = use_signal(crate::utils::get_language_pack_url);
+    let mut url_input = use_signal(|| pack_url.peek().clone().unwrap_or_default());
+    let mut save = move |url: String| {
+        crate::utils::set_language_pack_url(&url);
+        url_input.set(url.clone());
+        pack_url.set(Some(url).filter(|u| !u.is_empty()));
+        refresh_exercise_db();
+    };
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        let url = crate::utils::normalize_db_url(url_input.peek().trim());
+        if Some(&url) != pack_url.peek().as_ref() {
+            save(url);
+        }
+    };
+    rsx! {
+        article {
+            h2 { {t!("language-pack-section")} }
+            p { {t!("language-pack-desc")} }
+            form { onsubmit: on_submit,
+                input {
+                    r#type: "url",
+                    value: "{url_input}",
+                    placeholder: "https://example.com/exercise-translations/",
+                    oninput: move |evt| url_input.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "icon save",
+                    aria_label: t!("language-pack-save-aria"),
+                    "💾"
+                }
+                if pack_url.read().is_some() {
+                    button {
+                        r#type: "button",
+                        class: "icon del",
+                        aria_label: t!("language-pack-remove-aria"),
+                        onclick: move |_| save(String::new()),
+                        "🗑️"
+                    }
+                }
+            }
+        }
+    }
+}
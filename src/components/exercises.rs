@@ -1,9 +1,9 @@
-use crate::components::{ActiveTab, BottomNav, ExerciseCard};
-use crate::models::Exercise;
+use crate::components::{ActiveTab, BottomNav, EquipmentProfileSelect, ExerciseCard};
+use crate::models::{is_hidden, Exercise};
 use crate::services::exercise_db::{
     detect_filter_suggestions, exercise_matches_filters, SearchFilter,
 };
-use crate::services::{exercise_db, storage};
+use crate::services::{background_search, exercise_db, recovery, shortcuts, storage};
 use crate::{ExerciseSearchSignal, Route};
 use dioxus::prelude::*;
 use dioxus_i18n::prelude::i18n;
@@ -19,10 +19,94 @@ const PAGE_SIZE: usize = 20;
 const SCROLL_THRESHOLD_PX: u32 = 300;
 /// Debounce delay in milliseconds before re-running the expensive exercise filter.
 const SEARCH_DEBOUNCE_MS: u32 = 200;
+/// Bytes in a kilobyte, for the download progress.
+const BYTES_PER_KB: u64 = 1_000;
+/// When the exercise database was last fetched, flagged once a refresh is
+/// due, with a button refreshing it; while it downloads, the bytes received
+/// and the retries, and once every attempt failed, the error and a button
+/// trying again.
+#[component]
+fn DbFreshness() -> Element {
+    let download = use_context::<crate::ExerciseDbDownloadSignal>().0;
+    match download() {
+        exercise_db::DbDownload::Idle => {}
+        exercise_db::DbDownload::Downloading {
+            received,
+            total,
+            attempt,
+        } => {
+            let kb = (received / BYTES_PER_KB).to_string();
+            let retries = (exercise_db::MAX_DOWNLOAD_ATTEMPTS - 1).to_string();
+            return rsx! {
+                p { class: "freshness",
+                    if let Some(total) = total {
+                        progress {
+                            aria_label: t!("exercises-db-refreshing"),
+                            value: received.to_string(),
+                            max: total.to_string(),
+                        }
+                    } else {
+                        progress { aria_label: t!("exercises-db-refreshing") }
+                    }
+                    " "
+                    {t!("exercises-db-downloading", received: kb)}
+                    if attempt > 0 {
+                        " "
+                        {t!("exercises-db-retrying", attempt: attempt.to_string(), max: retries)}
+                    }
+                }
+            };
+        }
+        exercise_db::DbDownload::Failed(error) => {
+            return rsx! {
+                p { class: "freshness failed", role: "alert",
+                    "❌ "
+                    {t!("exercises-db-failed", error: error)}
+                    " "
+                    button {
+                        class: "edit",
+                        onclick: move |_| super::exercise_sources::refresh_exercise_db(),
+                        {t!("exercises-db-retry")}
+                    }
+                }
+            };
+        }
+    }
+    let last_fetch = exercise_db::last_fetch();
+    let due = exercise_db::is_refresh_due(last_fetch, crate::models::get_current_timestamp());
+    let fetched = match last_fetch.map(crate::datetime::session_days_ago) {
+        None => t!("exercises-db-never-fetched"),
+        Some(0) => t!("exercises-db-fetched", when: t!("date-today")),
+        Some(1) => t!("exercises-db-fetched", when: t!("date-yesterday")),
+        Some(n) => t!(
+            "exercises-db-fetched",
+            when: t!("date-days-ago", count: n.to_string())
+        ),
+    };
+    rsx! {
+        p { class: if due { "freshness due" } else { "freshness" },
+            if due {
+                "⚠️ "
+            }
+            "{fetched} "
+            button {
+                class: "edit",
+                title: t!("exercises-db-refresh-title"),
+                onclick: move |_| super::exercise_sources::refresh_exercise_db(),
+                "🔄"
+            }
+        }
+    }
+}
 #[component]
 pub fn Exercises() -> Element {
-    let all_exercises = exercise_db::use_exercises();
+    let all_exercises = exercise_db::use_hydrated_exercises();
     let custom_exercises = storage::use_custom_exercises();
+    let overrides = storage::use_exercise_overrides();
+    let taxonomy = storage::use_custom_taxonomy();
+    let equipment_profiles = storage::use_equipment_profiles();
+    let avoid_list = storage::use_avoid_list();
+    let freshness = recovery::use_muscle_freshness();
     let sessions = storage::use_sessions();
     let lang_str = use_memo(move || i18n().language().to_string());
     // Raw query updated on every keystroke (drives the input value and filter-suggestion chips).
@@ -85,32 +169,60 @@ pub fn Exercises() -> Element {
             return Vec::new();
         }
         let current = active_filters.read();
-        detect_filter_suggestions(&query)
+        detect_filter_suggestions(&query, &taxonomy.read())
             .into_iter()
             .filter(|s| !current.contains(s))
             .collect::<Vec<_>>()
     });
-    // Step 1: filter the full list by active filter chips (only re-runs when chips change).
+    // Step 1: filter the full list by active filter chips and equipment
+    // profile (only re-runs when those change).
     let filter_pool = use_memo(move || {
         let all = all_exercises.read();
         let custom = custom_exercises.read();
         let filters = active_filters.read();
-        if filters.is_empty() {
+        let profiles = equipment_profiles.read();
+        let profile = profiles.active_profile();
+        let avoid = avoid_list.read();
+        let today = crate::datetime::today();
+        let hide_avoided = avoid.hide && avoid.is_active_on(today);
+        let overrides = overrides.read();
+        let any_hidden = overrides.values().any(|o| o.hidden);
+        if filters.is_empty() && profile.is_none() && !hide_avoided && !any_hidden {
             return (all.clone(), custom.clone());
         }
-        let filtered_all: Vec<Arc<Exercise>> = all
-            .iter()
-            .filter(|e| exercise_matches_filters(e.as_ref(), &filters))
-            .cloned()
-            .collect();
-        let filtered_custom: Vec<Arc<Exercise>> = custom
-            .iter()
-            .filter(|e| exercise_matches_filters(e.as_ref(), &filters))
-            .cloned()
-            .collect();
+        let keep = |e: &&Arc<Exercise>| {
+            exercise_matches_filters(e.as_ref(), &filters, &overrides)
+                && profile.is_none_or(|p| p.allows(e))
+                && !(hide_avoided && avoid.flags(e, today))
+                && !is_hidden(&overrides, &e.id)
+        };
+        let filtered_all: Vec<Arc<Exercise>> = all.iter().filter(keep).cloned().collect();
+        let filtered_custom: Vec<Arc<Exercise>> = custom.iter().filter(keep).cloned().collect();
         (filtered_all, filtered_custom)
     });
-    // Step 2: text-search (or list) within the pre-filtered pool (re-runs on debounced keystrokes).
+    // Step 2: text-search within the pre-filtered pool (re-runs on debounced
+    // keystrokes), off the UI thread (see `background_search`).
+    let text_matches = use_resource(move || {
+        let query = debounced_query();
+        let (all_pool, custom_pool) = filter_pool();
+        let lang = lang_str();
+        let overrides = overrides.read().clone();
+        async move {
+            if query.is_empty() {
+                return None;
+            }
+            let custom = background_search::search(
+                custom_pool,
+                query.clone(),
+                lang.clone(),
+                overrides.clone(),
+            )
+            .await;
+            let all = background_search::search(all_pool, query, lang, overrides).await;
+            Some((custom, all))
+        }
+    });
+    // Step 3: the matches, or the whole pool without a query.
     let exercises = use_memo(move || {
         let query = debounced_query.read();
         let (all_pool, custom_pool) = filter_pool();
@@ -120,35 +232,33 @@ pub fn Exercises() -> Element {
         if query.is_empty() {
             for ex in &custom_pool {
                 if seen_ids.insert(ex.id.clone()) {
-                    results.push((ex.clone(), true));
+                    results.push((ex.clone(), true, None));
                 }
             }
             for ex in &all_pool {
                 if seen_ids.insert(ex.id.clone()) {
-                    results.push((ex.clone(), false));
+                    results.push((ex.clone(), false, None));
                 }
             }
-        } else {
-            let custom_results =
-                exercise_db::search_exercises(&custom_pool, &query, &lang_str.read());
-            for ex in custom_results {
+        } else if let Some(Some((custom_matches, db_matches))) = &*text_matches.read() {
+            for (ex, field) in custom_matches {
                 if seen_ids.insert(ex.id.clone()) {
-                    results.push((ex.clone(), true));
+                    results.push((ex.clone(), true, Some(field.clone())));
                 }
             }
-            let db_results = exercise_db::search_exercises(&all_pool, &query, &lang_str.read());
-            for ex in db_results {
+            for (ex, field) in db_matches {
                 if seen_ids.insert(ex.id.clone()) {
-                    results.push((ex.clone(), false));
+                    results.push((ex.clone(), false, Some(field.clone())));
                 }
             }
         }
         let cur_id = current_exercise_id.read().clone();
-        if !active_ids.is_empty() || cur_id.is_some() {
-            results.sort_by_key(|(ex, _)| {
+        let freshness = freshness.read();
+        if !active_ids.is_empty() || cur_id.is_some() || !freshness.is_empty() {
+            results.sort_by_key(|(ex, _, _)| {
                 let is_current = cur_id.as_deref() == Some(ex.id.as_str());
                 let is_active = active_ids.contains(&ex.id);
-                (!is_current, !is_active)
+                (!is_current, !is_active, recovery::is_tired(ex, &freshness))
             });
         }
         results
@@ -210,10 +320,10 @@ pub fn Exercises() -> Element {
             .read()
             .iter()
             .take(count)
-            .map(|(ex, is_custom)| {
+            .map(|(ex, is_custom, matched)| {
                 let show_instructions =
                     active_ids.contains(&ex.id) || cur_id.as_deref() == Some(ex.id.as_str());
-                (ex.clone(), *is_custom, show_instructions)
+                (ex.clone(), *is_custom, show_instructions, matched.clone())
             })
             .collect::<Vec<_>>()
     });
@@ -222,8 +332,10 @@ pub fn Exercises() -> Element {
         header {
             h1 { tabindex: 0, "📚 Exercises" }
             p { {t!("browse-exercises", count : { total.to_string() })} }
+            DbFreshness {}
             div { class: "inputs",
                 input {
+                    id: shortcuts::SEARCH_INPUT_ID,
                     r#type: "text",
                     placeholder: t!("search-placeholder"),
                     value: "{search_query}",
@@ -231,6 +343,7 @@ pub fn Exercises() -> Element {
                         search_query.set(evt.value());
                     },
                 }
+                EquipmentProfileSelect {}
                 Link {
                     class: "more",
                     to: Route::AddExercise {},
@@ -280,12 +393,14 @@ pub fn Exercises() -> Element {
             }
         }
         main { class: "exercises",
-            for (exercise, is_custom, show_instructions) in visible_items() {
+            for (exercise, is_custom, show_instructions, matched) in visible_items() {
                 ExerciseCard {
                     key: "{exercise.id}",
                     exercise,
                     is_custom,
                     show_instructions_initial: show_instructions,
+                    matched,
+                    query: debounced_query(),
                 }
             }
         }
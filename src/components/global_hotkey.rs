@@ -0,0 +1,81 @@
+#[cfg(feature = "desktop-platform")]
+use crate::services::global_hotkey;
+#[cfg(feature = "desktop-platform")]
+use crate::ToastSignal;
+use dioxus::prelude::*;
+#[cfg(feature = "desktop-platform")]
+use dioxus_i18n::t;
+/// Settings card of the global shortcut bringing the window to the front
+/// (shown on the More page of desktop builds).
+#[cfg(feature = "desktop-platform")]
+#[component]
+pub fn GlobalHotkeySettings() -> Element {
+    let mut setting = global_hotkey::use_global_hotkey_setting();
+    let mut input = use_signal(|| {
+        setting
+            .peek()
+            .clone()
+            .unwrap_or_else(|| global_hotkey::DEFAULT_GLOBAL_HOTKEY.to_owned())
+    });
+    let mut toast = use_context::<ToastSignal>().0;
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        let accelerator = input.peek().trim().to_owned();
+        if !global_hotkey::is_valid(&accelerator) {
+            toast
+                .write()
+                .push_back(t!("global-hotkey-invalid", accelerator: accelerator));
+            return;
+        }
+        global_hotkey::set_global_hotkey(Some(&accelerator));
+        setting.set(Some(accelerator));
+    };
+    rsx! {
+        article {
+            h2 { {t!("global-hotkey-section")} }
+            p { {t!("global-hotkey-desc")} }
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: setting.read().is_some(),
+                    onchange: move |evt| {
+                        if evt.checked() {
+                            let accelerator = Some(input.peek().trim().to_owned())
+                                .filter(|a| global_hotkey::is_valid(a))
+                                .unwrap_or_else(|| global_hotkey::DEFAULT_GLOBAL_HOTKEY.to_owned());
+                            global_hotkey::set_global_hotkey(Some(&accelerator));
+                            input.set(accelerator.clone());
+                            setting.set(Some(accelerator));
+                        } else {
+                            global_hotkey::set_global_hotkey(None);
+                            setting.set(None);
+                        }
+                    },
+                }
+                " "
+                {t!("global-hotkey-enabled")}
+            }
+            form { onsubmit: on_submit,
+                input {
+                    r#type: "text",
+                    value: "{input}",
+                    disabled: setting.read().is_none(),
+                    aria_label: t!("global-hotkey-aria"),
+                    oninput: move |evt| input.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "label save",
+                    disabled: setting.read().is_none(),
+                    {t!("global-hotkey-save-btn")}
+                }
+            }
+        }
+    }
+}
+/// The global shortcut is only offered on desktop builds.
+#[cfg(not(feature = "desktop-platform"))]
+#[component]
+pub fn GlobalHotkeySettings() -> Element {
+    rsx! {}
+}
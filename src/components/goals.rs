@@ -0,0 +1,179 @@
+use crate::models::{get_current_timestamp, parse_weight_kg, ExerciseGoal, ExerciseLog};
+use crate::services::storage;
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+/// Target of `goal` as shown to the user, e.g. "100 kg × 5".
+pub(crate) fn goal_target_label(goal: &ExerciseGoal, lang: &str) -> String {
+    match (goal.weight_hg, goal.reps) {
+        (Some(w), Some(r)) => format!("{} × {r}", w.localized(lang)),
+        (Some(w), None) => w.localized(lang),
+        (None, Some(r)) => t!("goal-reps", reps: r),
+        (None, None) => String::new(),
+    }
+}
+/// Mark the goals reached by the completed set `log` and congratulate the
+/// user on each of them.
+pub(crate) fn celebrate_goals_met(log: &ExerciseLog, exercise_name: &str) {
+    let met = storage::mark_goals_met(log);
+    if met.is_empty() {
+        return;
+    }
+    let lang = i18n().language().to_string();
+    let mut toast = consume_context::<ToastSignal>().0;
+    for goal in met {
+        let target = goal_target_label(&goal, &lang);
+        toast
+            .write()
+            .push_back(t!("goal-achieved", exercise: exercise_name, target: target));
+    }
+}
+/// Goals of an expanded exercise card: a progress bar per goal, followed by
+/// a form to set another one.
+#[component]
+pub fn ExerciseGoals(exercise_id: String) -> Element {
+    let goals = storage::use_goals();
+    let sessions = storage::use_sessions();
+    let lang = use_memo(move || i18n().language().to_string());
+    let mut weight_input = use_signal(String::new);
+    let mut reps_input = use_signal(String::new);
+    let mut deadline_input = use_signal(String::new);
+    let logs = {
+        let exercise_id = exercise_id.clone();
+        use_resource(move || storage::load_exercise_logs(exercise_id.clone()))
+    };
+    let exercise_goals: Vec<ExerciseGoal> = goals
+        .read()
+        .iter()
+        .filter(|g| g.exercise_id == exercise_id)
+        .cloned()
+        .collect();
+    let today = crate::datetime::today();
+    let rows: Vec<(u64, String, u32, bool, bool)> = {
+        let stored = logs.read();
+        let in_memory = sessions.read();
+        // The active session is not stored as completed yet.
+        let active_logs = in_memory
+            .iter()
+            .filter(|s| s.is_active())
+            .flat_map(|s| &s.exercise_logs);
+        let all_logs: Vec<&ExerciseLog> = stored.iter().flatten().chain(active_logs).collect();
+        exercise_goals
+            .iter()
+            .map(|goal| {
+                let progress = goal.progress(all_logs.iter().copied());
+                let mut target = goal_target_label(goal, &lang.read());
+                if let Some(deadline) = &goal.deadline {
+                    target = t!("goal-by", target: target, deadline: deadline.clone());
+                }
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let percent = (progress * 100.0).floor() as u32;
+                (
+                    goal.created_at,
+                    target,
+                    percent,
+                    goal.achieved_at.is_some(),
+                    goal.is_overdue(today),
+                )
+            })
+            .collect()
+    };
+    let weight_invalid = {
+        let input = weight_input.read();
+        !input.trim().is_empty() && parse_weight_kg(&input).is_none()
+    };
+    let reps_invalid = {
+        let input = reps_input.read();
+        !input.trim().is_empty() && input.trim().parse::<u32>().is_err()
+    };
+    let add_goal = move |evt: FormEvent| {
+        evt.prevent_default();
+        let weight_hg = parse_weight_kg(&weight_input.peek());
+        let reps = reps_input.peek().trim().parse().ok().filter(|&r| r > 0);
+        if weight_hg.is_none() && reps.is_none() {
+            return;
+        }
+        let deadline = deadline_input.peek().clone();
+        let mut next = goals.peek().clone();
+        next.push(ExerciseGoal {
+            created_at: get_current_timestamp(),
+            exercise_id: exercise_id.clone(),
+            weight_hg,
+            reps,
+            deadline: (!deadline.is_empty()).then_some(deadline),
+            achieved_at: None,
+        });
+        storage::save_goals(next);
+        weight_input.set(String::new());
+        reps_input.set(String::new());
+        deadline_input.set(String::new());
+    };
+    let remove = move |created_at: u64| {
+        let mut next = goals.peek().clone();
+        next.retain(|g| g.created_at != created_at);
+        storage::save_goals(next);
+    };
+    rsx! {
+        if !rows.is_empty() {
+            ul { class: "goals",
+                for (created_at, target, percent, achieved, overdue) in rows {
+                    li {
+                        key: "{created_at}",
+                        class: if overdue { "overdue" } else { "" },
+                        label {
+                            if achieved {
+                                "✅ {target}"
+                            } else {
+                                "🎯 {target}"
+                            }
+                            progress { max: 100, value: "{percent}" }
+                            "{percent} %"
+                        }
+                        button {
+                            class: "del",
+                            title: t!("goal-remove"),
+                            onclick: move |_| remove(created_at),
+                            "🗑️"
+                        }
+                    }
+                }
+            }
+        }
+        form { class: "inputs", onsubmit: add_goal,
+            input {
+                // Text rather than number: number inputs reject a decimal comma.
+                r#type: "text",
+                inputmode: "decimal",
+                placeholder: t!("weight-placeholder"),
+                aria_label: t!("goal-weight-label"),
+                value: "{weight_input}",
+                oninput: move |evt| weight_input.set(evt.value()),
+                class: if weight_invalid { "invalid" } else { "" },
+            }
+            "×"
+            input {
+                r#type: "number",
+                inputmode: "numeric",
+                placeholder: t!("reps-placeholder"),
+                aria_label: t!("goal-reps-label"),
+                value: "{reps_input}",
+                oninput: move |evt| reps_input.set(evt.value()),
+                class: if reps_invalid { "invalid" } else { "" },
+            }
+            input {
+                r#type: "date",
+                aria_label: t!("goal-deadline-label"),
+                value: "{deadline_input}",
+                oninput: move |evt| deadline_input.set(evt.value()),
+            }
+            button {
+                r#type: "submit",
+                class: "more",
+                disabled: weight_invalid || reps_invalid,
+                title: t!("goal-add"),
+                "🎯"
+            }
+        }
+    }
+}
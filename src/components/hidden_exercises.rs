@@ -0,0 +1,55 @@
+use crate::services::{exercise_db, storage};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// Settings card listing the exercises hidden from the lists and search
+/// results, each with a button to show it again (shown on the More page
+/// when any is hidden).
+#[component]
+pub fn HiddenExercisesSettings() -> Element {
+    let overrides = storage::use_exercise_overrides();
+    let exercises = exercise_db::use_exercises();
+    let custom_exercises = storage::use_custom_exercises();
+    let hidden = use_memo(move || {
+        let overrides = overrides.read();
+        let mut hidden: Vec<(String, String)> = overrides
+            .iter()
+            .filter(|(_, o)| o.hidden)
+            .map(|(id, _)| {
+                let name = exercises
+                    .read()
+                    .iter()
+                    .chain(custom_exercises.read().iter())
+                    .find(|e| e.id == *id)
+                    .map_or_else(|| id.clone(), |e| e.name.clone());
+                (id.clone(), name)
+            })
+            .collect();
+        hidden.sort_by(|a, b| a.1.cmp(&b.1));
+        hidden
+    });
+    if hidden.read().is_empty() {
+        return rsx! {};
+    }
+    rsx! {
+        article {
+            h2 { {t!("hidden-exercises-section")} }
+            p { {t!("hidden-exercises-desc")} }
+            ul {
+                for (id , name) in hidden.read().iter().cloned() {
+                    li { key: "{id}",
+                        span { "{name}" }
+                        button {
+                            class: "label more",
+                            onclick: move |_| {
+                                let mut over = overrides.peek().get(&id).cloned().unwrap_or_default();
+                                over.hidden = false;
+                                storage::set_exercise_override(&id, over);
+                            },
+                            {t!("hidden-exercises-show-btn")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,25 +1,77 @@
+use crate::components::exercise_card::translate_enum;
 use crate::components::{ActiveTab, BottomNav, HoldDeleteButton, SessionView};
-use crate::models::{format_time, WorkoutSession};
-use crate::services::{exercise_db, storage};
-use crate::{ExerciseSearchSignal, Route};
+use crate::datetime::{self, DateGroup};
+use crate::models::{format_time, localize_decimal, DbI18n, Muscle, WorkoutSession};
+use crate::services::history_filter::HistoryFilter;
+use crate::services::session_stats::{self, SessionStats};
+use crate::services::{drafts, exercise_db, markdown, storage};
+use crate::{DbI18nSignal, ExerciseSearchSignal, Route};
 use dioxus::prelude::*;
 use dioxus_i18n::prelude::i18n;
 use dioxus_i18n::t;
-
-/// Convert a Markdown string to an HTML string using pulldown-cmark.
-fn markdown_to_html(md: &str) -> String {
-    use pulldown_cmark::{html, Options, Parser};
-    let mut opts = Options::empty();
-    opts.insert(Options::ENABLE_STRIKETHROUGH);
-    opts.insert(Options::ENABLE_TABLES);
-    opts.insert(Options::ENABLE_TASKLISTS);
-    let parser = Parser::new_ext(md, opts);
-    let mut output = String::new();
-    html::push_html(&mut output, parser);
-    output
-}
+use std::collections::HashSet;
 /// Number of sessions loaded per scroll increment
 const PAGE_SIZE: usize = 20;
+/// An entry of the past-session list.
+#[derive(Clone, PartialEq)]
+enum SessionRow {
+    /// Group header, e.g. "This week".
+    Header(String),
+    Card(Box<WorkoutSession>),
+}
+/// One-line localised summary of `stats`, e.g.
+/// "🏋️ 5 exercises · ⚖️ 3200 kg · 💪 chest, triceps".
+///
+/// Figures that do not apply (no volume for cardio, unknown muscles) are left out.
+pub(crate) fn session_stats_text(stats: &SessionStats, lang: &str, db_i18n: &DbI18n) -> String {
+    let mut parts = vec![t!(
+        "session-stats-exercises",
+        count: stats.exercise_count.to_string()
+    )];
+    if stats.volume_hg > 0 {
+        let volume = localize_decimal(&format!("{:.0}", stats.volume_kg()), lang);
+        parts.push(format!("⚖️ {volume} kg"));
+    }
+    if !stats.top_muscles.is_empty() {
+        let muscles: Vec<&str> = stats
+            .top_muscles
+            .iter()
+            .map(|m| translate_enum(db_i18n, lang, "muscles", m.as_ref()))
+            .collect();
+        parts.push(format!("💪 {}", muscles.join(", ")));
+    }
+    parts.join(" · ")
+}
+/// Localised name of `month`.
+pub(crate) fn month_name(month: time::Month) -> String {
+    use time::Month;
+    match month {
+        Month::January => t!("month-january"),
+        Month::February => t!("month-february"),
+        Month::March => t!("month-march"),
+        Month::April => t!("month-april"),
+        Month::May => t!("month-may"),
+        Month::June => t!("month-june"),
+        Month::July => t!("month-july"),
+        Month::August => t!("month-august"),
+        Month::September => t!("month-september"),
+        Month::October => t!("month-october"),
+        Month::November => t!("month-november"),
+        Month::December => t!("month-december"),
+    }
+}
+/// Localised header for a session-list group.
+pub(crate) fn date_group_label(group: DateGroup) -> String {
+    match group {
+        DateGroup::ThisWeek => t!("date-group-this-week"),
+        DateGroup::LastWeek => t!("date-group-last-week"),
+        DateGroup::Month { year, month } => t!(
+            "date-group-month",
+            month: month_name(month),
+            year: year.to_string()
+        ),
+    }
+}
 #[component]
 pub fn Home() -> Element {
     let sessions = storage::use_sessions();
@@ -31,7 +83,7 @@ pub fn Home() -> Element {
     use_hook(|| {
         is_loading.set(true);
         spawn(async move {
-            match storage::load_completed_sessions_page(PAGE_SIZE, 0).await {
+            match storage::load_completed_sessions_page(PAGE_SIZE, 0, false).await {
                 Ok(page) => {
                     let len = page.len();
                     completed_sessions.set(page);
@@ -63,7 +115,7 @@ pub fn Home() -> Element {
         let mut newly_completed: Vec<WorkoutSession> = sessions
             .peek()
             .iter()
-            .filter(|s| !s.is_active() && !viewed_ids.contains(&s.id))
+            .filter(|s| !s.is_active() && !s.archived && !viewed_ids.contains(&s.id))
             .cloned()
             .collect();
 
@@ -100,7 +152,7 @@ pub fn Home() -> Element {
     });
     let start_new_session = move |_| {
         let new_session = WorkoutSession::new();
-        storage::save_session(new_session);
+        storage::start_session(new_session);
     };
     // The most recently completed session (for the "resume" button).
     let last_session = use_memo(move || {
@@ -118,12 +170,152 @@ pub fn Home() -> Element {
             .iter()
             .find(|s| {
                 !s.exercise_logs.is_empty()
-                    && crate::utils::is_same_weekday_as_today(s.start_time)
-                    && crate::utils::session_days_ago(s.start_time) > 0
+                    && crate::datetime::is_same_weekday_as_today(s.start_time)
+                    && crate::datetime::session_days_ago(s.start_time) > 0
             })
             .cloned()
     });
     let lang_for_date = use_memo(move || i18n().language().to_string());
+    // History search: while a filter is set, the whole history is loaded once
+    // (instead of page by page) so older matches are found too.
+    let filter = use_signal(HistoryFilter::default);
+    let mut all_history = use_signal(|| None::<Vec<WorkoutSession>>);
+    let filter_active = use_memo(move || !filter.read().is_empty());
+    use_effect(move || {
+        if !filter_active() {
+            all_history.set(None);
+            return;
+        }
+        if all_history.peek().is_some() {
+            return;
+        }
+        all_history.set(Some(Vec::new()));
+        spawn(async move {
+            match super::more::load_all_sessions(Vec::new()).await {
+                Ok(mut all) => {
+                    all.reverse();
+                    all_history.set(Some(all));
+                }
+                Err(e) => log::error!("Failed to load history for search: {e}"),
+            }
+        });
+    });
+    let all_exercises = exercise_db::use_exercises();
+    let custom_exercises = storage::use_custom_exercises();
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    // The session list, with a header ("This week", "March 2025", …) before
+    // the first session of each group.
+    let session_rows = use_memo(move || {
+        let mut rows = Vec::new();
+        let mut current = None;
+        let filter = filter.read();
+        let lang = lang_for_date.read();
+        let (db, custom, i18n_db) = (
+            all_exercises.read(),
+            custom_exercises.read(),
+            db_i18n.read(),
+        );
+        let muscle_label =
+            |m: Muscle| translate_enum(&i18n_db, &lang, "muscles", m.as_ref()).to_owned();
+        let (paged, all) = (completed_sessions.read(), all_history.read());
+        let source: &[WorkoutSession] = if filter.is_empty() {
+            &paged
+        } else {
+            all.as_deref().unwrap_or_default()
+        };
+        for session in source
+            .iter()
+            .filter(|s| filter.matches(s, &db, &custom, &lang, muscle_label))
+        {
+            let group = datetime::session_group(session.start_time);
+            if current != Some(group) {
+                rows.push(SessionRow::Header(date_group_label(group)));
+                current = Some(group);
+            }
+            rows.push(SessionRow::Card(Box::new(session.clone())));
+        }
+        rows
+    });
+    // Multi-select mode: `Some` with the IDs of the ticked sessions.
+    let mut selection = use_signal(|| None::<HashSet<String>>);
+    // Archive (or unarchive) the given sessions.  Archived sessions leave the
+    // paged list but keep their flag in the search results.
+    let mut set_archived = move |ids: &HashSet<String>, archived: bool| {
+        let mut changed: Vec<WorkoutSession> = Vec::new();
+        for session in completed_sessions
+            .peek()
+            .iter()
+            .chain(all_history.peek().iter().flatten())
+        {
+            if ids.contains(&session.id) && !changed.iter().any(|c| c.id == session.id) {
+                let mut session = session.clone();
+                session.archived = archived;
+                changed.push(session);
+            }
+        }
+        if let Some(all) = all_history.write().as_mut() {
+            for session in all.iter_mut().filter(|s| ids.contains(&s.id)) {
+                session.archived = archived;
+            }
+        }
+        {
+            let mut cs = completed_sessions.write();
+            if archived {
+                cs.retain(|s| !ids.contains(&s.id));
+            } else {
+                // Only insert sessions within the loaded range: older ones
+                // are reached by scrolling, like any other.
+                let oldest = cs.last().map(|s| s.start_time);
+                let everything_loaded = *all_loaded.peek();
+                cs.extend(
+                    changed
+                        .iter()
+                        .filter(|s| {
+                            everything_loaded || oldest.is_some_and(|oldest| s.start_time >= oldest)
+                        })
+                        .cloned(),
+                );
+                cs.sort_by_key(|s| std::cmp::Reverse(s.start_time));
+            }
+            sessions_loaded_offset.set(cs.len());
+        }
+        for session in changed {
+            storage::save_session(session);
+        }
+    };
+    let mut set_notes = move |id: &str, notes: String| {
+        let mut updated = None;
+        for session in completed_sessions
+            .write()
+            .iter_mut()
+            .chain(all_history.write().iter_mut().flatten())
+            .filter(|s| s.id == id)
+        {
+            session.notes.clone_from(&notes);
+            updated = Some(session.clone());
+        }
+        if let Some(session) = updated {
+            storage::save_session(session);
+        }
+    };
+    let delete_selected = move |()| {
+        let Some(ids) = selection.write().take() else {
+            return;
+        };
+        for id in &ids {
+            storage::delete_session(id);
+        }
+        if let Some(all) = all_history.write().as_mut() {
+            all.retain(|s| !ids.contains(&s.id));
+        }
+        let new_len = {
+            let mut cs = completed_sessions.write();
+            cs.retain(|s| !ids.contains(&s.id));
+            cs.len()
+        };
+        sessions_loaded_offset.set(new_len);
+    };
+    let selected_count = selection.read().as_ref().map_or(0, HashSet::len);
     rsx! {
         Stylesheet { href: asset!("/assets/sessions.scss") }
         if *has_active.read() {
@@ -134,25 +326,62 @@ pub fn Home() -> Element {
                 p { tabindex: 0, {t!("app-subtitle")} }
             }
             main { class: "sessions",
-                if completed_sessions.read().is_empty() && !*is_loading.read() {
+                if !filter_active() {
+                    crate::components::WeeklySetCounter {}
+                    crate::components::RecoveryIndicator {}
+                    crate::components::SessionTemplates {}
+                }
+                if !completed_sessions.read().is_empty() || filter_active() {
+                    HistorySearch { filter }
+                }
+                if completed_sessions.read().is_empty() && !*is_loading.read() && !filter_active() {
                     p { {t!("no-sessions")} }
                     p { {t!("start-first-workout")} }
+                } else if filter_active() && session_rows.read().is_empty()
+                    && all_history.read().as_ref().is_some_and(|h| !h.is_empty())
+                {
+                    p { {t!("history-search-no-match")} }
                 } else {
-                    for session in completed_sessions.read().iter() {
-                        SessionCard {
-                            key: "{session.id}",
-                            session: session.clone(),
-                            on_delete: move |id: String| {
-                                let new_len = {
-                                    let mut cs = completed_sessions.write();
-                                    cs.retain(|s| s.id != id);
-                                    cs.len()
-                                };
-                                sessions_loaded_offset.set(new_len);
+                    for row in session_rows.read().iter() {
+                        match row {
+                            SessionRow::Header(label) => rsx! {
+                                h2 { key: "{label}", "{label}" }
+                            },
+                            SessionRow::Card(session) => rsx! {
+                                SessionCard {
+                                    key: "{session.id}",
+                                    session: (**session).clone(),
+                                    selected: selection
+                                        .read()
+                                        .as_ref()
+                                        .map(|ids| ids.contains(&session.id)),
+                                    on_toggle: move |id: String| {
+                                        if let Some(ids) = selection.write().as_mut() {
+                                            if !ids.remove(&id) {
+                                                ids.insert(id);
+                                            }
+                                        }
+                                    },
+                                    on_unarchive: move |id: String| {
+                                        set_archived(&HashSet::from([id]), false);
+                                    },
+                                    on_edit_notes: move |(id, notes): (String, String)| set_notes(&id, notes),
+                                    on_delete: move |id: String| {
+                                        if let Some(all) = all_history.write().as_mut() {
+                                            all.retain(|s| s.id != id);
+                                        }
+                                        let new_len = {
+                                            let mut cs = completed_sessions.write();
+                                            cs.retain(|s| s.id != id);
+                                            cs.len()
+                                        };
+                                        sessions_loaded_offset.set(new_len);
+                                    },
+                                }
                             },
                         }
                     }
-                    if !*all_loaded.read() {
+                    if !*all_loaded.read() && !filter_active() {
                         InfiniteScrollSentinel {
                             is_loading,
                             all_loaded,
@@ -163,67 +392,108 @@ pub fn Home() -> Element {
                 }
             }
             div { class: "main-actions",
-                button {
-                    class: "icon more",
-                    onclick: start_new_session,
-                    title: t!("start-new-workout"),
-                    "+"
-                }
-                if let Some(ref last_sess) = *last_session.read() {
-                    {
-                        let session_to_resume = {
-                            let mut s = last_sess.clone();
-                            s.end_time = None;
-                            s.paused_at = None;
-                            // Clear transient fields that are stale after the
-                            // session was completed (rest/exercise timers).
-                            s.rest_start_time = None;
-                            s.current_exercise_id = None;
-                            s.current_exercise_start = None;
-                            s
-                        };
-                        rsx! {
-                            button {
-                                class: "icon edit",
-                                onclick: move |_| {
-                                    storage::save_session(session_to_resume.clone());
-                                },
-                                title: t!("session-resume-last-title"),
-                                "▶️"
+                if selection.read().is_some() {
+                    button {
+                        class: "icon less",
+                        onclick: move |_| selection.set(None),
+                        title: t!("session-select-cancel"),
+                        "✕"
+                    }
+                    if selected_count > 0 {
+                        button {
+                            class: "icon edit label",
+                            onclick: move |_| {
+                                if let Some(ids) = selection.write().take() {
+                                    set_archived(&ids, true);
+                                }
+                            },
+                            title: t!("session-archive-selected-title"),
+                            "🗄️ {selected_count}"
+                        }
+                        HoldDeleteButton {
+                            title: t!("session-delete-selected-title").to_string(),
+                            on_delete: delete_selected,
+                        }
+                    }
+                } else {
+                    if !completed_sessions.read().is_empty() || filter_active() {
+                        button {
+                            class: "icon edit",
+                            onclick: move |_| selection.set(Some(HashSet::new())),
+                            title: t!("session-select-title"),
+                            "☑️"
+                        }
+                    }
+                    button {
+                        class: "icon edit",
+                        onclick: move |_| {
+                            navigator().push(Route::Calendar {});
+                        },
+                        title: t!("calendar-open-title"),
+                        "📅"
+                    }
+                    button {
+                        class: "icon more",
+                        onclick: start_new_session,
+                        title: t!("start-new-workout"),
+                        "+"
+                    }
+                    if let Some(ref last_sess) = *last_session.read() {
+                        {
+                            let session_to_resume = {
+                                let mut s = last_sess.clone();
+                                s.end_time = None;
+                                s.paused_at = None;
+                                // Clear transient fields that are stale after the
+                                // session was completed (rest/exercise timers).
+                                s.rest_start_time = None;
+                                s.current_exercise_id = None;
+                                s.current_exercise_start = None;
+                                s
+                            };
+                            rsx! {
+                                button {
+                                    class: "icon edit",
+                                    onclick: move |_| {
+                                        storage::start_session(session_to_resume.clone());
+                                    },
+                                    title: t!("session-resume-last-title"),
+                                    "▶️"
+                                }
                             }
                         }
                     }
-                }
-                if let Some(ref sw_session) = *same_weekday_session.read() {
-                    {
-                        let pending_ids: Vec<String> = {
-                            let mut seen = std::collections::HashSet::new();
-                            sw_session
-                                .exercise_logs
-                                .iter()
-                                .filter_map(|log| {
-                                    if seen.insert(log.exercise_id.clone()) {
-                                        Some(log.exercise_id.clone())
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .collect()
-                        };
-                        let short_date = crate::utils::format_short_date(
-                            sw_session.start_time,
-                            &lang_for_date.read(),
-                        );
-                        rsx! {
-                            button {
-                                class: "icon edit label",
-                                onclick: move |_| {
-                                    let mut new_session = WorkoutSession::new();
-                                    new_session.pending_exercise_ids.clone_from(&pending_ids);
-                                    storage::save_session(new_session);
-                                },
-                                title: t!("session-repeat-weekday-title"),
-                                "🔁 {short_date}"
+                    if let Some(ref sw_session) = *same_weekday_session.read() {
+                        {
+                            let pending_ids: Vec<String> = {
+                                let mut seen = std::collections::HashSet::new();
+                                sw_session
+                                    .exercise_logs
+                                    .iter()
+                                    .filter_map(|log| {
+                                        if seen.insert(log.exercise_id.clone()) {
+                                            Some(log.exercise_id.clone())
+                                        } else {
+                                            None
+                                        }
+                                    })
+                                    .collect()
+                            };
+                            let short_date = crate::datetime::format_short_date(
+                                sw_session.start_time,
+                                &lang_for_date.read(),
+                            );
+                            rsx! {
+                                button {
+                                    class: "icon edit label",
+                                    onclick: move |_| {
+                                        let mut new_session = WorkoutSession::new();
+                                        new_session.pending_exercises = pending_ids.iter().cloned().map(Into::into).collect();
+                                        storage::start_session(new_session);
+                                    },
+                                    title: t!("session-repeat-weekday-title"),
+                                    "🔁 {short_date}"
+                                }
                             }
                         }
                     }
@@ -233,22 +503,78 @@ pub fn Home() -> Element {
         BottomNav { active_tab: ActiveTab::Sessions }
     }
 }
+/// Search bar above the session history: exercise / muscle name and an
+/// optional date range.
 #[component]
-fn SessionCard(session: WorkoutSession, on_delete: EventHandler<String>) -> Element {
+fn HistorySearch(filter: Signal<HistoryFilter>) -> Element {
+    let date_value = |d: Option<time::Date>| d.map(|d| d.to_string()).unwrap_or_default();
+    let from = date_value(filter.read().from);
+    let to = date_value(filter.read().to);
+    rsx! {
+        form { class: "history-search", onsubmit: move |evt| evt.prevent_default(),
+            input {
+                r#type: "search",
+                placeholder: t!("history-search-placeholder"),
+                value: "{filter.read().query}",
+                oninput: move |evt| filter.write().query = evt.value(),
+            }
+            input {
+                r#type: "date",
+                aria_label: t!("history-search-from-aria"),
+                value: "{from}",
+                oninput: move |evt| filter.write().from = datetime::parse_iso_date(&evt.value()),
+            }
+            input {
+                r#type: "date",
+                aria_label: t!("history-search-to-aria"),
+                value: "{to}",
+                oninput: move |evt| filter.write().to = datetime::parse_iso_date(&evt.value()),
+            }
+            if !filter.read().is_empty() {
+                button {
+                    r#type: "button",
+                    class: "icon less",
+                    title: t!("history-search-clear"),
+                    onclick: move |_| filter.set(HistoryFilter::default()),
+                    "✕"
+                }
+            }
+        }
+    }
+}
+/// A past session.  In multi-select mode (`selected` is `Some`) a tap
+/// toggles the selection instead of the per-session actions.
+#[component]
+fn SessionCard(
+    session: WorkoutSession,
+    selected: Option<bool>,
+    on_toggle: EventHandler<String>,
+    on_unarchive: EventHandler<String>,
+    on_delete: EventHandler<String>,
+    /// Called with the session ID and its edited notes.
+    on_edit_notes: EventHandler<(String, String)>,
+) -> Element {
     const MAX_VISIBLE: usize = 9;
     let mut show_all_exercises = use_signal(|| false);
     let mut show_notes = use_signal(|| false);
+    let mut show_link = use_signal(|| false);
+    let mut show_template = use_signal(|| false);
+    let mut editing_notes = use_signal(|| false);
+    let mut notes_draft = use_signal(String::new);
     let session_id = session.id.clone();
-    let has_notes = !session.notes.is_empty();
+    let toggle_id = session.id.clone();
+    let archived = session.archived;
     let session_notes = session.notes.clone();
     let mut search_signal = use_context::<ExerciseSearchSignal>().0;
     let navigator = use_navigator();
     let all_exercises = exercise_db::use_exercises();
     let custom_exercises = storage::use_custom_exercises();
     let lang_str = use_memo(move || i18n().language().to_string());
-    let duration = session.duration_seconds();
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    let stats = session_stats::compute(&session, &all_exercises.read(), &custom_exercises.read());
+    let stats_text = session_stats_text(&stats, &lang_str.read(), &db_i18n.read());
     let date_str = {
-        let days = crate::utils::session_days_ago(session.start_time);
+        let days = crate::datetime::session_days_ago(session.start_time);
         match days {
             0 => t!("date-today"),
             1 => t!("date-yesterday"),
@@ -278,6 +604,35 @@ fn SessionCard(session: WorkoutSession, on_delete: EventHandler<String>) -> Elem
             })
             .collect()
     };
+    // Notes of individual sets, with the localised exercise name.
+    let log_notes: Vec<(String, String)> = {
+        let all = all_exercises.read();
+        let custom = custom_exercises.read();
+        let lang = lang_str.read();
+        session
+            .exercise_logs
+            .iter()
+            .filter(|log| !log.notes.trim().is_empty())
+            .map(|log| {
+                let name = exercise_db::resolve_exercise(&all, &custom, &log.exercise_id)
+                    .map_or_else(
+                        || log.exercise_name.clone(),
+                        |ex| ex.name_for_lang(&lang).to_owned(),
+                    );
+                (name, log.notes.clone())
+            })
+            .collect()
+    };
+    let has_notes = !session_notes.is_empty() || !log_notes.is_empty();
+    let notes_draft_key = format!("session-notes:{}", session.id);
+    let mut start_editing_notes = {
+        let notes = session_notes.clone();
+        let key = notes_draft_key.clone();
+        move || {
+            notes_draft.set(drafts::load(&key).unwrap_or_else(|| notes.clone()));
+            editing_notes.set(true);
+        }
+    };
     let pending_ids: Vec<String> = {
         let mut seen = std::collections::HashSet::new();
         session
@@ -301,33 +656,91 @@ fn SessionCard(session: WorkoutSession, on_delete: EventHandler<String>) -> Elem
     let hidden_count = total_unique.saturating_sub(visible_count);
     rsx! {
         article {
+            class: if selected == Some(true) { "selected" },
+            onclick: move |_| {
+                if selected.is_some() {
+                    on_toggle.call(toggle_id.clone());
+                }
+            },
             header {
+                if let Some(checked) = selected {
+                    input {
+                        r#type: "checkbox",
+                        checked,
+                        aria_label: t!("session-select-title"),
+                    }
+                }
                 time { "{date_str}" }
+                if let Some(location) = &session.location {
+                    span { class: "location", "📍 {location}" }
+                }
                 div {
                     label { "⏱️" }
-                    time { "{format_time(duration)}" }
+                    time { "{format_time(stats.duration)}" }
                 }
-                if !pending_ids.is_empty() {
+                if selected.is_none() && archived {
+                    button {
+                        class: "edit",
+                        onclick: {
+                            let id = session.id.clone();
+                            move |_| on_unarchive.call(id.clone())
+                        },
+                        title: t!("session-unarchive-title"),
+                        "🗄️"
+                    }
+                }
+                if selected.is_none() && !pending_ids.is_empty() {
                     button {
                         class: "edit",
                         onclick: {
                             let pending_ids = pending_ids.clone();
                             move |_| {
                                 let mut new_session = WorkoutSession::new();
-                                new_session.pending_exercise_ids.clone_from(&pending_ids);
-                                storage::save_session(new_session);
+                                new_session.pending_exercises = pending_ids.iter().cloned().map(Into::into).collect();
+                                storage::start_session(new_session);
                             }
                         },
                         title: t!("session-repeat-title"),
                         "🔁"
                     }
                 }
-                HoldDeleteButton {
-                    title: t!("session-delete-title").to_string(),
-                    on_delete: move |()| {
-                        storage::delete_session(&session_id);
-                        on_delete.call(session_id.clone());
-                    },
+                if selected.is_none() && session.end_time.is_some() {
+                    crate::components::ShareSessionButton { session: session.clone() }
+                    button {
+                        class: "edit",
+                        onclick: move |_| show_link.toggle(),
+                        title: t!("share-link-btn-title"),
+                        "🔗"
+                    }
+                    if !pending_ids.is_empty() {
+                        button {
+                            class: "edit",
+                            onclick: move |_| show_template.toggle(),
+                            title: t!("template-save-title"),
+                            "📋"
+                        }
+                    }
+                }
+                if selected.is_none() {
+                    HoldDeleteButton {
+                        title: t!("session-delete-title").to_string(),
+                        on_delete: move |()| {
+                            storage::delete_session(&session_id);
+                            on_delete.call(session_id.clone());
+                        },
+                    }
+                }
+            }
+            if stats.set_count > 0 {
+                p { class: "stats", "{stats_text}" }
+            }
+            if *show_link.read() {
+                crate::components::SessionLink { session: session.clone() }
+            }
+            if *show_template.read() {
+                crate::components::SaveTemplateForm {
+                    session: session.clone(),
+                    on_saved: move |()| show_template.set(false),
                 }
             }
             if !unique_exercises.is_empty() {
@@ -338,8 +751,10 @@ fn SessionCard(session: WorkoutSession, on_delete: EventHandler<String>) -> Elem
                             onclick: {
                                 let name = name.clone();
                                 move |_| {
-                                    search_signal.set(Some(name.clone()));
-                                    navigator.push(Route::Exercises {});
+                                    if selected.is_none() {
+                                        search_signal.set(Some(name.clone()));
+                                        navigator.push(Route::Exercises {});
+                                    }
                                 }
                             },
                             "{tag_icon} {name}"
@@ -354,16 +769,76 @@ fn SessionCard(session: WorkoutSession, on_delete: EventHandler<String>) -> Elem
                     }
                 }
             }
-            if has_notes {
-                if *show_notes.read() {
-                    div { dangerous_inner_html: "{markdown_to_html(&session_notes)}" }
-                } else {
+            if *editing_notes.read() {
+                textarea {
+                    class: "notes",
+                    placeholder: t!("session-notes-placeholder"),
+                    initial_value: "{notes_draft.peek()}",
+                    oninput: {
+                        let key = notes_draft_key.clone();
+                        move |evt: FormEvent| {
+                            drafts::save(&key, &evt.value());
+                            notes_draft.set(evt.value());
+                        }
+                    },
+                }
+                div { class: "inputs",
+                    button {
+                        class: "label save",
+                        onclick: {
+                            let id = session.id.clone();
+                            let key = notes_draft_key.clone();
+                            move |_| {
+                                drafts::clear(&key);
+                                on_edit_notes.call((id.clone(), notes_draft.peek().trim().to_owned()));
+                                editing_notes.set(false);
+                                show_notes.set(true);
+                            }
+                        },
+                        {t!("session-notes-save")}
+                    }
+                    button {
+                        class: "label",
+                        onclick: {
+                            let key = notes_draft_key.clone();
+                            move |_| {
+                                drafts::clear(&key);
+                                editing_notes.set(false);
+                            }
+                        },
+                        {t!("cancel-btn")}
+                    }
+                }
+            } else if has_notes && *show_notes.read() {
+                if !session_notes.is_empty() {
+                    div { dangerous_inner_html: "{markdown::to_html(&session_notes)}" }
+                }
+                for (name, notes) in log_notes.iter() {
+                    div { class: "notes",
+                        h5 { "{name}" }
+                        div { dangerous_inner_html: "{markdown::to_html(notes)}" }
+                    }
+                }
+                if selected.is_none() {
                     button {
-                        title: t!("session-notes-unfold"),
-                        onclick: move |_| show_notes.set(true),
-                        "📝"
+                        class: "edit",
+                        title: t!("session-notes-edit-title"),
+                        onclick: move |_| start_editing_notes(),
+                        "✏️"
                     }
                 }
+            } else if has_notes {
+                button {
+                    title: t!("session-notes-unfold"),
+                    onclick: move |_| show_notes.set(true),
+                    "📝"
+                }
+            } else if selected.is_none() {
+                button {
+                    title: t!("session-notes-add-title"),
+                    onclick: move |_| start_editing_notes(),
+                    "📝"
+                }
             }
         }
     }
@@ -402,13 +877,17 @@ fn InfiniteScrollSentinel(
                             let off = *sessions_loaded_offset.peek();
                             wasm_bindgen_futures::spawn_local(async move {
                                 match crate::services::storage::load_completed_sessions_page(
-                                    PAGE_SIZE, off,
+                                    PAGE_SIZE, off, false,
                                 )
                                 .await
                                 {
-                                    Ok(next) => {
+                                    Ok(mut next) => {
                                         let len = next.len();
-                                        completed_sessions.write().extend(next);
+                                        let mut cs = completed_sessions.write();
+                                        // Unarchived sessions may already be listed.
+                                        next.retain(|s| !cs.iter().any(|c| c.id == s.id));
+                                        cs.extend(next);
+                                        drop(cs);
                                         sessions_loaded_offset.set(off + len);
                                         all_loaded.set(len < PAGE_SIZE);
                                     }
@@ -0,0 +1,90 @@
+#[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+use crate::services::local_api;
+#[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+use crate::ToastSignal;
+use dioxus::prelude::*;
+#[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+use dioxus_i18n::t;
+/// Settings card enabling the localhost JSON API (shown on the More page of
+/// desktop builds).
+///
+/// The API stays enabled across restarts; writes are a separate opt-in.
+#[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+#[component]
+pub fn LocalApiSettings() -> Element {
+    let mut running = use_signal(local_api::running_port);
+    let mut port_input = use_signal(|| local_api::configured_port().to_string());
+    let mut writes = use_signal(local_api::writes_allowed);
+    let mut toast = use_context::<ToastSignal>().0;
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        if running.peek().is_some() {
+            local_api::stop();
+            crate::utils::set_config(local_api::LOCAL_API_ENABLED_KEY, "");
+            running.set(None);
+            return;
+        }
+        let Ok(port) = port_input.peek().trim().parse::<u16>() else {
+            port_input.set(local_api::configured_port().to_string());
+            return;
+        };
+        match local_api::start(port) {
+            Ok(()) => {
+                crate::utils::set_config(local_api::LOCAL_API_PORT_KEY, &port.to_string());
+                crate::utils::set_config(local_api::LOCAL_API_ENABLED_KEY, "true");
+                running.set(local_api::running_port());
+            }
+            Err(e) => toast
+                .write()
+                .push_back(t!("local-api-failed", error: e.to_string())),
+        }
+    };
+    rsx! {
+        article {
+            h2 { {t!("local-api-section")} }
+            p { {t!("local-api-desc")} }
+            form { onsubmit: on_submit,
+                input {
+                    r#type: "number",
+                    min: "1",
+                    max: "65535",
+                    value: "{port_input}",
+                    disabled: running.read().is_some(),
+                    aria_label: t!("local-api-port-aria"),
+                    oninput: move |evt| port_input.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: if running.read().is_some() { "del label" } else { "label save" },
+                    if running.read().is_some() {
+                        {t!("local-api-stop-btn")}
+                    } else {
+                        {t!("local-api-start-btn")}
+                    }
+                }
+            }
+            if let Some(port) = running() {
+                p { {t!("local-api-running", url: format!("http://127.0.0.1:{port}/api/sessions"))} }
+            }
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: writes(),
+                    onchange: move |evt| {
+                        let allowed = evt.checked();
+                        local_api::set_writes_allowed(allowed);
+                        writes.set(allowed);
+                    },
+                }
+                " "
+                {t!("local-api-writes")}
+            }
+        }
+    }
+}
+/// The localhost API is only offered on desktop builds.
+#[cfg(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))]
+#[component]
+pub fn LocalApiSettings() -> Element {
+    rsx! {}
+}
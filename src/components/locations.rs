@@ -0,0 +1,87 @@
+use crate::services::storage;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// Picker of the gym or place the active session takes place at, shown once
+/// at least one location is saved.
+#[component]
+pub fn SessionLocationSelect(current: Option<String>) -> Element {
+    let locations = storage::use_locations();
+    if locations.read().is_empty() && current.is_none() {
+        return rsx! {};
+    }
+    let current = current.unwrap_or_default();
+    // A location removed from the saved list stays selectable for the
+    // sessions already tagged with it.
+    let unsaved =
+        (!current.is_empty() && !locations.read().contains(&current)).then(|| current.clone());
+    rsx! {
+        select {
+            class: "location",
+            title: t!("location-select-title"),
+            value: "{current}",
+            oninput: move |evt| {
+                let name = evt.value();
+                storage::set_session_location((!name.is_empty()).then_some(name));
+            },
+            option { value: "", selected: current.is_empty(), {t!("location-none")} }
+            for name in locations.read().iter().chain(unsaved.iter()) {
+                option { value: "{name}", selected: *name == current, "📍 {name}" }
+            }
+        }
+    }
+}
+/// Settings card to manage the saved session locations (shown on the More page).
+#[component]
+pub fn LocationsSettings() -> Element {
+    let locations = storage::use_locations();
+    let mut name_input = use_signal(String::new);
+    let add_location = move |evt: FormEvent| {
+        evt.prevent_default();
+        let name = name_input.peek().trim().to_owned();
+        let mut next = locations.peek().clone();
+        if name.is_empty() || next.contains(&name) {
+            return;
+        }
+        next.push(name);
+        storage::save_locations(next);
+        name_input.set(String::new());
+    };
+    rsx! {
+        article {
+            h2 { {t!("locations-section")} }
+            p { {t!("locations-desc")} }
+            ul { class: "tags",
+                for (index, name) in locations.read().iter().enumerate() {
+                    li { key: "{name}",
+                        button {
+                            class: "label",
+                            title: t!("location-remove"),
+                            onclick: move |_| {
+                                let mut next = locations.peek().clone();
+                                if index < next.len() {
+                                    next.remove(index);
+                                    storage::save_locations(next);
+                                }
+                            },
+                            "📍 {name} ✕"
+                        }
+                    }
+                }
+            }
+            form { class: "inputs", onsubmit: add_location,
+                input {
+                    r#type: "text",
+                    placeholder: t!("location-name-placeholder"),
+                    value: "{name_input}",
+                    oninput: move |evt| name_input.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "more",
+                    title: t!("location-add"),
+                    "+"
+                }
+            }
+        }
+    }
+}
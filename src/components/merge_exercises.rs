@@ -0,0 +1,111 @@
+use crate::components::confirm;
+use crate::models::Exercise;
+use crate::services::{exercise_db, storage};
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use std::sync::Arc;
+/// Settings card merging a duplicate custom exercise into another one, its
+/// whole history following (shown on the More page).
+#[component]
+pub fn MergeExercisesSettings() -> Element {
+    let custom_exercises = storage::use_custom_exercises();
+    let exercises = exercise_db::use_exercises();
+    let sessions = storage::use_sessions();
+    let mut from_id = use_signal(String::new);
+    let mut into_id = use_signal(String::new);
+    let mut busy = use_signal(|| false);
+    let mut toast = use_context::<ToastSignal>().0;
+    let targets = use_memo(move || {
+        let from = from_id.read();
+        let mut all: Vec<Arc<Exercise>> = custom_exercises
+            .read()
+            .iter()
+            .chain(exercises.read().iter())
+            .filter(|e| e.id != *from)
+            .cloned()
+            .collect();
+        all.sort_by(|a, b| a.name_lower.cmp(&b.name_lower));
+        all
+    });
+    let merge = move |_| {
+        let from = from_id.peek().clone();
+        let Some(into) = targets
+            .peek()
+            .iter()
+            .find(|e| e.id == *into_id.peek())
+            .cloned()
+        else {
+            return;
+        };
+        let Some(from_name) = custom_exercises
+            .peek()
+            .iter()
+            .find(|e| e.id == from)
+            .map(|e| e.name.clone())
+        else {
+            return;
+        };
+        spawn(async move {
+            let message = t!(
+                "merge-exercises-confirm",
+                from: from_name,
+                into: into.name.clone()
+            );
+            if !confirm(message, t!("merge-exercises-btn")).await {
+                return;
+            }
+            busy.set(true);
+            match super::more::load_all_sessions(sessions.peek().clone()).await {
+                Ok(history) => {
+                    let count = storage::merge_exercises(history, &from, &into);
+                    toast
+                        .write()
+                        .push_back(t!("merge-exercises-done", count: count));
+                    from_id.set(String::new());
+                    into_id.set(String::new());
+                }
+                Err(e) => toast
+                    .write()
+                    .push_back(t!("merge-exercises-failed", error: e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+    if custom_exercises.read().is_empty() {
+        return rsx! {};
+    }
+    rsx! {
+        article {
+            h2 { {t!("merge-exercises-section")} }
+            p { {t!("merge-exercises-desc")} }
+            select {
+                value: "{from_id}",
+                aria_label: t!("merge-exercises-from-aria"),
+                oninput: move |evt| from_id.set(evt.value()),
+                option { value: "", {t!("merge-exercises-from-placeholder")} }
+                for exercise in custom_exercises.read().iter() {
+                    option { key: "{exercise.id}", value: "{exercise.id}", "{exercise.name}" }
+                }
+            }
+            select {
+                value: "{into_id}",
+                aria_label: t!("merge-exercises-into-aria"),
+                disabled: from_id.read().is_empty(),
+                oninput: move |evt| into_id.set(evt.value()),
+                option { value: "", {t!("merge-exercises-into-placeholder")} }
+                for exercise in targets.read().iter() {
+                    option { key: "{exercise.id}", value: "{exercise.id}", "{exercise.name}" }
+                }
+            }
+            div { class: "inputs",
+                button {
+                    class: "del label",
+                    disabled: busy() || from_id.read().is_empty() || into_id.read().is_empty(),
+                    onclick: merge,
+                    {t!("merge-exercises-btn")}
+                }
+            }
+        }
+    }
+}
@@ -1,25 +1,91 @@
+pub mod achievements;
 pub mod active_session;
 pub mod add_exercise;
 pub mod analytics;
+pub mod app_lock;
+pub mod avoid_list;
+pub mod body_weight;
 pub mod bottom_nav;
+pub mod calendar;
+pub mod charting;
+pub mod cloud_backup;
 pub mod completed_exercise_log;
+pub mod confirm_dialog;
+pub mod credits;
+pub mod device_transfer;
+pub mod display_settings;
 pub mod edit_exercise;
+pub mod equipment_profiles;
+pub mod error_screen;
 pub mod exercise_card;
-pub mod exercise_form_fields;
+mod exercise_form;
+pub mod exercise_sources;
 pub mod exercises;
+pub mod global_hotkey;
+pub mod goals;
+pub mod hidden_exercises;
 pub mod hold_delete;
 pub mod home;
+pub mod local_api;
+pub mod locations;
+pub mod merge_exercises;
 pub mod more;
+pub mod plateau;
+pub mod recovery;
+pub mod rest_skip;
+pub mod sample_data;
 mod session_exercise_form;
 mod session_timers;
+pub mod share_session;
+pub mod storage_usage;
+pub mod sync_settings;
+pub mod taxonomy;
+pub mod templates;
+pub mod training_plan;
+pub mod trash;
+pub mod webhook;
+pub mod weekly_sets;
+pub use achievements::Achievements;
 pub use active_session::{GlobalSessionHeader, SessionView};
 pub use add_exercise::AddExercise;
 pub use analytics::Analytics;
+pub use app_lock::{AppLockSettings, LockScreen};
+pub use avoid_list::AvoidListSettings;
+pub use body_weight::BodyWeightSettings;
 pub use bottom_nav::{ActiveTab, BottomNav};
+pub use calendar::Calendar;
+pub use cloud_backup::CloudBackupSettings;
 pub use completed_exercise_log::CompletedExerciseLog;
+pub use confirm_dialog::{confirm, ConfirmDialog, ConfirmRequest};
+pub use credits::Credits;
+pub use device_transfer::DeviceTransfer;
+pub use display_settings::DisplaySettings;
 pub use edit_exercise::EditExercise;
+pub use equipment_profiles::{EquipmentProfileSelect, EquipmentProfilesSettings};
+pub use error_screen::ErrorScreen;
 pub use exercise_card::ExerciseCard;
+pub use exercise_form::ExerciseForm;
+pub use exercise_sources::{ExerciseSourcesSettings, LanguagePackSettings};
 pub use exercises::Exercises;
+pub use global_hotkey::GlobalHotkeySettings;
+pub use goals::ExerciseGoals;
+pub use hidden_exercises::HiddenExercisesSettings;
 pub use hold_delete::HoldDeleteButton;
 pub use home::Home;
+pub use local_api::LocalApiSettings;
+pub use locations::{LocationsSettings, SessionLocationSelect};
+pub use merge_exercises::MergeExercisesSettings;
 pub use more::More;
+pub use plateau::PlateauNotice;
+pub use recovery::RecoveryIndicator;
+pub use rest_skip::RestSkipSettings;
+pub use sample_data::SampleDataSettings;
+pub use share_session::{ImportSharedSession, SessionLink, ShareSessionButton};
+pub use storage_usage::StorageUsage;
+pub use sync_settings::SyncSettings;
+pub use taxonomy::{CustomTaxonomySettings, ExerciseTags};
+pub use templates::{SaveTemplateForm, SessionTemplates};
+pub use training_plan::TrainingPlanSettings;
+pub use trash::Trash;
+pub use webhook::WebhookSettings;
+pub use weekly_sets::{MuscleTargetsSettings, WeeklySetCounter};
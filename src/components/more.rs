@@ -1,20 +1,21 @@
 use crate::components::{ActiveTab, BottomNav};
 use crate::models::Exercise;
+use crate::services::backup;
 use crate::services::{exercise_db, storage};
-use crate::{ImageDownloadProgressSignal, ToastSignal};
+use crate::{FlowModeSignal, KeepAwakeSignal, ToastSignal};
 use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
 use dioxus_i18n::t;
 #[component]
 pub fn More() -> Element {
     let mut url_input = use_signal(crate::utils::get_exercise_db_url);
+    let mut backup_passphrase = use_signal(String::new);
     let mut toast = consume_context::<ToastSignal>().0;
     let exercises_sig = exercise_db::use_exercises();
     let mut exercises_to_confirm: Signal<Vec<Exercise>> = use_signal(Vec::new);
     let sessions = storage::use_sessions();
     let custom_exercises = storage::use_custom_exercises();
     let all_exercises = exercise_db::use_exercises();
-    #[cfg(not(target_arch = "wasm32"))]
-    let img_progress = consume_context::<ImageDownloadProgressSignal>().0;
 
     // Total session count (active + completed) from storage.
     let session_count_resource =
@@ -62,48 +63,27 @@ pub fn More() -> Element {
         evt.prevent_default();
         let url = crate::utils::normalize_db_url(url_input.read().trim());
         url_input.set(url.clone());
-        #[cfg(target_arch = "wasm32")]
-        {
-            if let Some(window) = web_sys::window() {
-                if let Ok(Some(storage)) = window.local_storage() {
-                    if url.is_empty() || url == crate::utils::EXERCISE_DB_BASE_URL {
-                        let _ = storage.remove_item(crate::utils::EXERCISE_DB_URL_STORAGE_KEY);
-                    } else {
-                        let _ = storage.set_item(crate::utils::EXERCISE_DB_URL_STORAGE_KEY, &url);
-                    }
-                }
-            }
-            crate::services::exercise_db::clear_fetch_cache();
-        }
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            use crate::services::storage::native_storage;
-            if url.is_empty() || url == crate::utils::EXERCISE_DB_BASE_URL {
-                let _ =
-                    native_storage::remove_config_value(crate::utils::EXERCISE_DB_URL_STORAGE_KEY);
-            } else {
-                let _ = native_storage::set_config_value(
-                    crate::utils::EXERCISE_DB_URL_STORAGE_KEY,
-                    &url,
-                );
-            }
-            crate::services::exercise_db::clear_fetch_cache();
-        }
-        let sig = exercises_sig;
-        spawn(async move {
-            #[cfg(target_arch = "wasm32")]
-            exercise_db::reload_exercises(sig, toast).await;
-            #[cfg(not(target_arch = "wasm32"))]
-            exercise_db::reload_exercises(sig, toast, img_progress).await;
-        });
+        crate::utils::set_exercise_db_url(&url);
+        super::exercise_sources::refresh_exercise_db();
+    };
+    let reset_url = move |_| {
+        url_input.set(crate::utils::EXERCISE_DB_BASE_URL.to_owned());
+        crate::utils::set_exercise_db_url("");
+        super::exercise_sources::refresh_exercise_db();
     };
     let export_exercises = {
         let msg_export_failed = msg_export_failed.clone();
         move |_| {
             let exercises = custom_exercises.read().clone();
-            match serde_json::to_string_pretty(&exercises) {
-                Ok(json) => {
-                    if let Some(msg) = trigger_download("custom_exercises.json", &json) {
+            let file = serde_json::to_string_pretty(&exercises)
+                .map_err(|e| e.to_string())
+                .and_then(|json| {
+                    backup::backup_file("custom_exercises", json, &backup_passphrase.peek())
+                        .map_err(|e| e.to_string())
+                });
+            match file {
+                Ok((name, content)) => {
+                    if let Some(msg) = trigger_download(&name, &content, "application/json") {
                         toast.write().push_back(msg);
                     }
                 }
@@ -113,41 +93,59 @@ pub fn More() -> Element {
             }
         }
     };
-    let export_sessions = move |_| {
+    let export_sessions = {
         let msg_export_sessions_failed = msg_export_sessions_failed.clone();
         let msg_export_failed = msg_export_failed.clone();
-        let mut t = toast;
-        spawn(async move {
-            let active = sessions.peek().clone();
-            let mut all = active;
-            let mut offset = 0usize;
-            let page_size = 500usize;
-            loop {
-                match storage::load_completed_sessions_page(page_size, offset).await {
-                    Ok(page) => {
-                        let fetched = page.len();
-                        all.extend(page);
-                        if fetched < page_size {
-                            break;
-                        }
-                        offset += fetched;
-                    }
+        move |_| {
+            let msg_export_sessions_failed = msg_export_sessions_failed.clone();
+            let msg_export_failed = msg_export_failed.clone();
+            let mut t = toast;
+            spawn(async move {
+                let all = match load_all_sessions(sessions.peek().clone()).await {
+                    Ok(all) => all,
                     Err(e) => {
                         t.write()
                             .push_back(format!("{msg_export_sessions_failed}: {e}"));
                         return;
                     }
+                };
+                let file = serde_json::to_string_pretty(&all)
+                    .map_err(|e| e.to_string())
+                    .and_then(|json| {
+                        backup::backup_file("sessions", json, &backup_passphrase.peek())
+                            .map_err(|e| e.to_string())
+                    });
+                match file {
+                    Ok((name, content)) => {
+                        if let Some(msg) = trigger_download(&name, &content, "application/json") {
+                            t.write().push_back(msg);
+                        }
+                    }
+                    Err(e) => {
+                        t.write().push_back(format!("{msg_export_failed}: {e}"));
+                    }
                 }
-            }
-            all.sort_by(|a, b| a.start_time.cmp(&b.start_time));
-            match serde_json::to_string_pretty(&all) {
-                Ok(json) => {
-                    if let Some(msg) = trigger_download("sessions.json", &json) {
+            });
+        }
+    };
+    let calendar_summary = use_memo(|| t!("ics-event-summary"));
+    let export_calendar = move |_| {
+        let msg_export_sessions_failed = msg_export_sessions_failed.clone();
+        let mut t = toast;
+        spawn(async move {
+            match load_all_sessions(sessions.peek().clone()).await {
+                Ok(all) => {
+                    let ics = crate::services::calendar_export::sessions_to_ics(
+                        &all,
+                        &calendar_summary(),
+                    );
+                    if let Some(msg) = trigger_download("workouts.ics", &ics, "text/calendar") {
                         t.write().push_back(msg);
                     }
                 }
                 Err(e) => {
-                    t.write().push_back(format!("{msg_export_failed}: {e}"));
+                    t.write()
+                        .push_back(format!("{msg_export_sessions_failed}: {e}"));
                 }
             }
         });
@@ -217,9 +215,14 @@ pub fn More() -> Element {
     let on_sessions_file_change = move |_| {
         log::debug!("on_sessions_file_change triggered");
         spawn(async move {
-            if let Some(json) = read_file_input("import-sessions-input").await {
-                log::info!("Successfully read sessions JSON ({} bytes)", json.len());
-                handle_sessions_json(json);
+            if let Some(content) = read_file_input("import-sessions-input").await {
+                log::info!("Successfully read sessions JSON ({} bytes)", content.len());
+                match backup::open_backup(&content, &backup_passphrase.peek()) {
+                    Ok(json) => handle_sessions_json(json),
+                    Err(e) => toast
+                        .write()
+                        .push_back(t!("backup-open-failed", error: e.to_string())),
+                }
             } else {
                 log::warn!("Failed to read sessions JSON or no file selected");
             }
@@ -229,9 +232,14 @@ pub fn More() -> Element {
         log::debug!("on_exercises_file_change triggered");
         let mut handler = handle_exercises_json;
         spawn(async move {
-            if let Some(json) = read_file_input("import-exercises-input").await {
-                log::info!("Successfully read exercises JSON ({} bytes)", json.len());
-                handler(json);
+            if let Some(content) = read_file_input("import-exercises-input").await {
+                log::info!("Successfully read exercises JSON ({} bytes)", content.len());
+                match backup::open_backup(&content, &backup_passphrase.peek()) {
+                    Ok(json) => handler(json),
+                    Err(e) => toast
+                        .write()
+                        .push_back(t!("backup-open-failed", error: e.to_string())),
+                }
             } else {
                 log::warn!("Failed to read exercises JSON or no file selected");
             }
@@ -248,12 +256,38 @@ pub fn More() -> Element {
     let skip_replace = move |_| {
         exercises_to_confirm.write().remove(0);
     };
+    let mut keep_awake = use_context::<KeepAwakeSignal>().0;
+    let mut flow_mode = use_context::<FlowModeSignal>().0;
+    let mut quiet_mode = use_signal(crate::services::notifications::quiet_mode_enabled);
+    let mut media_controls = use_signal(crate::services::media_session::media_controls_enabled);
+    let mut language =
+        use_signal(|| crate::utils::get_config(crate::LANGUAGE_KEY).unwrap_or_default());
+    let on_language = move |evt: Event<FormData>| {
+        let tag = evt.value();
+        crate::utils::set_config(crate::LANGUAGE_KEY, &tag);
+        let id = tag
+            .parse()
+            .unwrap_or_else(|_| crate::detect_preferred_language());
+        i18n().set_language(id);
+        language.set(tag);
+    };
     rsx! {
         Stylesheet { href: asset!("/assets/more.scss") }
         header {
             h1 { {t!("more-title")} }
         }
         main { class: "more",
+            article {
+                h2 { {t!("backup-passphrase-section")} }
+                p { {t!("backup-passphrase-desc")} }
+                input {
+                    r#type: "password",
+                    autocomplete: "new-password",
+                    value: "{backup_passphrase}",
+                    placeholder: t!("backup-passphrase-placeholder"),
+                    oninput: move |evt| backup_passphrase.set(evt.value()),
+                }
+            }
             article {
                 h2 { {t!("more-export-section")} }
                 div { class: "inputs",
@@ -263,6 +297,9 @@ pub fn More() -> Element {
                     button { class: "label save", onclick: export_sessions,
                         {t!("more-export-sessions-btn", count : total_session_count.unwrap_or(0))}
                     }
+                    button { class: "label save", onclick: export_calendar,
+                        {t!("more-export-calendar-btn")}
+                    }
                 }
             }
             article {
@@ -302,6 +339,46 @@ pub fn More() -> Element {
                     }
                 }
             }
+            crate::components::SampleDataSettings {}
+            article {
+                h2 { {t!("trash-title")} }
+                p { {t!("trash-description", days: crate::models::TRASH_RETENTION_DAYS)} }
+                div { class: "inputs",
+                    button {
+                        class: "label more",
+                        onclick: move |_| {
+                            navigator().push(crate::Route::Trash {});
+                        },
+                        {t!("more-trash-btn")}
+                    }
+                }
+            }
+            article {
+                h2 { {t!("achievements-title")} }
+                p { {t!("achievements-desc")} }
+                div { class: "inputs",
+                    button {
+                        class: "label more",
+                        onclick: move |_| {
+                            navigator().push(crate::Route::Achievements {});
+                        },
+                        {t!("more-achievements-btn")}
+                    }
+                }
+            }
+            article {
+                h2 { {t!("storage-title")} }
+                p { {t!("storage-desc")} }
+                div { class: "inputs",
+                    button {
+                        class: "label more",
+                        onclick: move |_| {
+                            navigator().push(crate::Route::StorageUsage {});
+                        },
+                        {t!("more-storage-btn")}
+                    }
+                }
+            }
             article {
                 h2 { {t!("more-about-section")} }
                 p { {t!("app-subtitle")} }
@@ -340,7 +417,129 @@ pub fn More() -> Element {
                         "💾"
                     }
                 }
+                div { class: "inputs",
+                    button {
+                        class: "label more",
+                        onclick: move |_| super::exercise_sources::refresh_exercise_db(),
+                        {t!("more-db-refresh-btn")}
+                    }
+                    if *url_input.read() != crate::utils::EXERCISE_DB_BASE_URL {
+                        button { class: "label more", onclick: reset_url,
+                            {t!("more-db-url-reset-btn")}
+                        }
+                    }
+                }
+            }
+            crate::components::ExerciseSourcesSettings {}
+            crate::components::LanguagePackSettings {}
+            crate::components::MergeExercisesSettings {}
+            crate::components::HiddenExercisesSettings {}
+            crate::components::CustomTaxonomySettings {}
+            article {
+                h2 { {t!("more-language-section")} }
+                select { value: "{language}", oninput: on_language,
+                    option { value: "", {t!("more-language-system")} }
+                    for (tag , name) in crate::SUPPORTED_LANGUAGES.iter().copied() {
+                        option { value: tag, selected: *language.read() == tag, "{name}" }
+                    }
+                }
+            }
+            crate::components::DisplaySettings {}
+            article {
+                h2 { {t!("more-keep-awake-section")} }
+                p { {t!("more-keep-awake-desc")} }
+                button {
+                    class: if *keep_awake.read() { "label less" } else { "label save" },
+                    onclick: move |_| {
+                        let enabled = !*keep_awake.peek();
+                        crate::services::wake_lock::set_keep_awake_enabled(enabled);
+                        keep_awake.set(enabled);
+                    },
+                    if *keep_awake.read() {
+                        {t!("more-keep-awake-disable-btn")}
+                    } else {
+                        {t!("more-keep-awake-enable-btn")}
+                    }
+                }
+            }
+            article {
+                h2 { {t!("more-flow-section")} }
+                p { {t!("more-flow-desc")} }
+                button {
+                    class: if *flow_mode.read() { "label less" } else { "label save" },
+                    onclick: move |_| {
+                        let enabled = !*flow_mode.peek();
+                        crate::services::flow::set_flow_mode_enabled(enabled);
+                        flow_mode.set(enabled);
+                    },
+                    if *flow_mode.read() {
+                        {t!("more-flow-disable-btn")}
+                    } else {
+                        {t!("more-flow-enable-btn")}
+                    }
+                }
+            }
+            article {
+                h2 { {t!("tour-section")} }
+                p { {t!("tour-desc")} }
+                button {
+                    class: "label more",
+                    onclick: move |_| {
+                        crate::services::tour::restart_tour();
+                        toast.write().push_back(t!("tour-restarted"));
+                    },
+                    {t!("tour-restart-btn")}
+                }
             }
+            article {
+                h2 { {t!("more-quiet-section")} }
+                p { {t!("more-quiet-desc")} }
+                button {
+                    class: if *quiet_mode.read() { "label less" } else { "label save" },
+                    onclick: move |_| {
+                        let enabled = !*quiet_mode.peek();
+                        crate::services::notifications::set_quiet_mode_enabled(enabled);
+                        quiet_mode.set(enabled);
+                    },
+                    if *quiet_mode.read() {
+                        {t!("more-quiet-disable-btn")}
+                    } else {
+                        {t!("more-quiet-enable-btn")}
+                    }
+                }
+            }
+            article {
+                h2 { {t!("more-media-controls-section")} }
+                p { {t!("more-media-controls-desc")} }
+                button {
+                    class: if *media_controls.read() { "label less" } else { "label save" },
+                    onclick: move |_| {
+                        let enabled = !*media_controls.peek();
+                        crate::services::media_session::set_media_controls_enabled(enabled);
+                        media_controls.set(enabled);
+                    },
+                    if *media_controls.read() {
+                        {t!("more-media-controls-disable-btn")}
+                    } else {
+                        {t!("more-media-controls-enable-btn")}
+                    }
+                }
+            }
+            crate::components::RestSkipSettings {}
+            crate::components::BodyWeightSettings {}
+            crate::components::EquipmentProfilesSettings {}
+            crate::components::LocationsSettings {}
+            crate::components::MuscleTargetsSettings {}
+            crate::components::AvoidListSettings {}
+            crate::components::TrainingPlanSettings {}
+            crate::components::AppLockSettings {}
+            crate::components::SyncSettings {}
+            crate::components::CloudBackupSettings {}
+            crate::components::WebhookSettings {}
+            crate::components::LocalApiSettings {}
+            crate::components::GlobalHotkeySettings {}
+            crate::components::DeviceTransfer {}
+            crate::components::ImportSharedSession {}
             article {
                 h2 { {t!("more-oss-section")} }
                 p {
@@ -388,6 +587,7 @@ pub fn More() -> Element {
                     li { {t!("more-built-with-others")} }
                 }
             }
+            crate::components::Credits {}
             article {
                 h2 { {t!("more-privacy-section")} }
                 p { {t!("more-privacy-desc")} }
@@ -406,6 +606,26 @@ pub fn More() -> Element {
         BottomNav { active_tab: ActiveTab::More }
     }
 }
+/// Load every session (the given active ones plus all completed pages from
+/// storage), sorted chronologically by start time.
+pub(crate) async fn load_all_sessions(
+    active: Vec<crate::models::WorkoutSession>,
+) -> Result<Vec<crate::models::WorkoutSession>, storage::StorageError> {
+    let mut all = active;
+    let mut offset = 0usize;
+    let page_size = 500usize;
+    loop {
+        let page = storage::load_completed_sessions_page(page_size, offset, true).await?;
+        let fetched = page.len();
+        all.extend(page);
+        if fetched < page_size {
+            break;
+        }
+        offset += fetched;
+    }
+    all.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+    Ok(all)
+}
 /// Trigger a file download.
 ///
 /// On WASM the `web_sys` DOM APIs are used directly for efficiency.
@@ -418,7 +638,7 @@ pub fn More() -> Element {
 ///
 /// Returns `Some(message)` when there is something worth reporting to the user
 /// (Android: the path the file was saved to), `None` otherwise.
-fn trigger_download(filename: &str, content: &str) -> Option<String> {
+pub(crate) fn trigger_download(filename: &str, content: &str, mime: &str) -> Option<String> {
     #[cfg(target_arch = "wasm32")]
     {
         use wasm_bindgen::JsCast;
@@ -433,7 +653,7 @@ fn trigger_download(filename: &str, content: &str) -> Option<String> {
         };
         blob_parts.push(&wasm_bindgen::JsValue::from_str(content));
         let props = web_sys::BlobPropertyBag::new();
-        props.set_type("application/json");
+        props.set_type(mime);
         let Ok(blob) = web_sys::Blob::new_with_str_sequence_and_options(&blob_parts, &props) else {
             return None;
         };
@@ -466,7 +686,7 @@ fn trigger_download(filename: &str, content: &str) -> Option<String> {
         // system's MediaStore Downloads collection so it appears in the
         // global Downloads folder and is accessible to all file managers.
         use crate::services::storage::native_storage;
-        match native_storage::android_save_to_downloads(filename, content) {
+        match native_storage::android_save_to_downloads(filename, content, mime) {
             Ok(relative_path) => {
                 log::info!("Exported {filename} to {relative_path}");
                 Some(format!("💾 {relative_path}"))
@@ -496,9 +716,10 @@ fn trigger_download(filename: &str, content: &str) -> Option<String> {
         // in the JavaScript snippet without any injection risk.
         let content_js = serde_json::to_string(content).unwrap_or_default();
         let filename_js = serde_json::to_string(filename).unwrap_or_default();
+        let mime_js = serde_json::to_string(mime).unwrap_or_default();
         document::eval(&format!(
             r"(function(){{
-  var b=new Blob([{content_js}],{{type:'application/json'}});
+  var b=new Blob([{content_js}],{{type:{mime_js}}});
   var u=URL.createObjectURL(b);
   var a=document.createElement('a');
   a.href=u; a.download={filename_js};
@@ -516,7 +737,7 @@ fn trigger_download(filename: &str, content: &str) -> Option<String> {
 /// On WASM the `web_sys` `FileReader` API is used.  On native the read is
 /// performed inside the `WebView` via `document::eval` and the result is
 /// returned through `dioxus.send()`.
-async fn read_file_input(id: &str) -> Option<String> {
+pub(crate) async fn read_file_input(id: &str) -> Option<String> {
     #[cfg(target_arch = "wasm32")]
     {
         use wasm_bindgen::JsCast;
@@ -0,0 +1,44 @@
+use crate::models::analytics::{sessions_without_progress, PLATEAU_SESSIONS};
+use crate::services::storage;
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// Suggest a deload or a variation, in a toast, when the exercise being
+/// started has not progressed for [`PLATEAU_SESSIONS`] sessions.
+pub(crate) fn warn_if_plateaued(exercise_id: String) {
+    let mut toast = consume_context::<ToastSignal>().0;
+    spawn(async move {
+        let logs = storage::load_exercise_logs(exercise_id).await;
+        let Some(sessions) = sessions_without_progress(&logs) else {
+            return;
+        };
+        if sessions >= PLATEAU_SESSIONS {
+            let exercise = logs
+                .last()
+                .map(|l| l.exercise_name.clone())
+                .unwrap_or_default();
+            toast
+                .write()
+                .push_back(t!("plateau-toast", exercise: exercise, sessions: sessions));
+        }
+    });
+}
+/// Plateau warning of an expanded exercise card, with what to do about it.
+/// Renders nothing while the exercise keeps progressing.
+#[component]
+pub fn PlateauNotice(exercise_id: String) -> Element {
+    let logs = use_resource(move || storage::load_exercise_logs(exercise_id.clone()));
+    let sessions = logs
+        .read()
+        .as_ref()
+        .and_then(sessions_without_progress)
+        .filter(|&n| n >= PLATEAU_SESSIONS);
+    rsx! {
+        if let Some(sessions) = sessions {
+            p { class: "plateau",
+                "📉 "
+                {t!("plateau-notice", sessions: sessions)}
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::components::exercise_card::translate_enum;
+use crate::services::recovery::{self, READY_FRESHNESS, RECOVERY_HOURS};
+/// Hours in a day, to express the recovery window in days.
+const HOURS_PER_DAY: u64 = 24;
+use crate::DbI18nSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+/// Freshness of the recently trained muscles, most tired first, shown on the
+/// home page.  Renders nothing when every muscle is fully recovered.
+#[component]
+pub fn RecoveryIndicator() -> Element {
+    let freshness = recovery::use_muscle_freshness();
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    let lang = use_memo(move || i18n().language().to_string());
+    let mut muscles: Vec<(String, u8)> = freshness
+        .read()
+        .iter()
+        .map(|(muscle, &fresh)| {
+            let name = translate_enum(&db_i18n.read(), &lang.read(), "muscles", muscle.as_ref())
+                .to_owned();
+            (name, fresh)
+        })
+        .collect();
+    if muscles.is_empty() {
+        return rsx! {};
+    }
+    muscles.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    let days = RECOVERY_HOURS / HOURS_PER_DAY;
+    let description = t!("recovery-desc", days: days, ready: READY_FRESHNESS);
+    rsx! {
+        div { class: "recovery",
+            label { title: "{description}", {t!("recovery-title")} }
+            ul { class: "tags",
+                for (name, fresh) in muscles {
+                    li {
+                        key: "{name}",
+                        class: if fresh < READY_FRESHNESS { "tired" } else { "ready" },
+                        if fresh < READY_FRESHNESS {
+                            "🪫 {name} {fresh} %"
+                        } else {
+                            "🔋 {name} {fresh} %"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
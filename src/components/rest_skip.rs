@@ -0,0 +1,42 @@
+use crate::components::exercise_card::translate_enum;
+use crate::models::Category;
+use crate::services::storage;
+use crate::DbI18nSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+use strum::IntoEnumIterator;
+/// Settings card of the exercise categories after which no rest is timed,
+/// e.g. cardio or stretching (shown on the More page).
+#[component]
+pub fn RestSkipSettings() -> Element {
+    let skipped = storage::use_rest_skip_categories();
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    let lang = use_memo(move || i18n().language().to_string());
+    let toggle = move |category: Category| {
+        let mut next = skipped.peek().clone();
+        if let Some(index) = next.iter().position(|c| *c == category) {
+            next.remove(index);
+        } else {
+            next.push(category);
+        }
+        storage::save_rest_skip_categories(next);
+    };
+    rsx! {
+        article {
+            h2 { {t!("rest-skip-section")} }
+            p { {t!("rest-skip-desc")} }
+            ul { class: "tags",
+                for category in Category::iter() {
+                    li { key: "{category}",
+                        button {
+                            class: if skipped.read().contains(&category) { "label save" } else { "label" },
+                            onclick: move |_| toggle(category),
+                            {translate_enum(&db_i18n.read(), &lang.read(), "category", category.as_ref()).to_owned()}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
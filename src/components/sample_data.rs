@@ -0,0 +1,61 @@
+use crate::components::confirm;
+use crate::services::{demo, storage};
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// Settings card loading sample sessions to explore the app with, then
+/// purging them (shown on the More page).
+#[component]
+pub fn SampleDataSettings() -> Element {
+    let sessions = storage::use_sessions();
+    let mut loaded = use_signal(demo::sample_data_loaded);
+    let mut busy = use_signal(|| false);
+    let mut toast = use_context::<ToastSignal>().0;
+    let load = move |_| {
+        demo::load_sample_data();
+        loaded.set(true);
+        toast.write().push_back(t!(
+            "sample-data-loaded",
+            count: demo::sample_sessions(crate::models::get_current_timestamp()).len()
+        ));
+    };
+    let purge = move |_| {
+        spawn(async move {
+            if !confirm(t!("sample-data-purge-confirm"), t!("sample-data-purge-btn")).await {
+                return;
+            }
+            busy.set(true);
+            let purged = match super::more::load_all_sessions(sessions.peek().clone()).await {
+                Ok(history) => demo::purge_sample_data(&history).await,
+                Err(e) => Err(e),
+            };
+            match purged {
+                Ok(count) => {
+                    loaded.set(false);
+                    toast
+                        .write()
+                        .push_back(t!("sample-data-purged", count: count));
+                }
+                Err(e) => toast
+                    .write()
+                    .push_back(t!("sample-data-purge-failed", error: e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+    rsx! {
+        article {
+            h2 { {t!("sample-data-section")} }
+            p { {t!("sample-data-desc", weeks: demo::DEMO_WEEKS)} }
+            div { class: "inputs",
+                if loaded() {
+                    button { class: "label del", disabled: busy(), onclick: purge,
+                        {t!("sample-data-purge-btn")}
+                    }
+                } else {
+                    button { class: "label more", onclick: load, {t!("sample-data-load-btn")} }
+                }
+            }
+        }
+    }
+}
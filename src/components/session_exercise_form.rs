@@ -1,11 +1,25 @@
 use super::session_timers::InlineExerciseTimer;
 use crate::models::{
-    format_time, parse_distance_km, parse_duration_seconds, parse_weight_kg, Category, Force,
+    format_time, localize_decimal, parse_decimal, parse_distance_km, parse_duration_seconds,
+    parse_weight_kg, Category, Force, SetKind, Side,
 };
+use crate::services::shortcuts::{self, Shortcut};
 use crate::services::{exercise_db, storage};
 use dioxus::prelude::*;
 use dioxus_i18n::prelude::i18n;
 use dioxus_i18n::t;
+/// Step of the − / + buttons of the target duration, in seconds.
+const TARGET_STEP_SECONDS: u64 = 5;
+/// Badge text of a set kind, e.g. "🔥 Warm-up".
+pub(super) fn set_kind_label(kind: SetKind) -> String {
+    match kind {
+        SetKind::Normal => t!("set-kind-normal"),
+        SetKind::WarmUp => t!("set-kind-warm-up"),
+        SetKind::Dropset => t!("set-kind-dropset"),
+        SetKind::Amrap => t!("set-kind-amrap"),
+        SetKind::Failure => t!("set-kind-failure"),
+    }
+}
 /// Shared exercise input form used both for performing a new set and for
 /// editing a completed log entry.
 ///
@@ -42,7 +56,27 @@ pub(super) fn ExerciseInputForm(
     /// Session paused timestamp (perform mode only).
     #[props(default)]
     paused_at: Option<u64>,
+    /// Target duration of a static hold (perform mode only); the 🎯 row is
+    /// only shown for static exercises when provided.
+    #[props(default)]
+    target_input: Option<Signal<String>>,
+    /// Side worked by a unilateral set; `None` in the signal for both sides.
+    /// The side toggle is only shown when provided.
+    #[props(default)]
+    side_input: Option<Signal<Option<Side>>>,
+    /// Whether the weight is assistance rather than added load, for
+    /// bodyweight exercises.  The ⚖️ cell becomes a ➕ / ➖ toggle when
+    /// provided.
+    #[props(default)]
+    assisted_input: Option<Signal<bool>>,
+    /// Purpose of the set; the set-kind toggle is only shown when provided.
+    #[props(default)]
+    set_kind_input: Option<Signal<SetKind>>,
     on_complete: EventHandler<()>,
+    /// Completes the set with the values of the last one of the exercise;
+    /// the "same as last time" button is only shown when provided.
+    #[props(default)]
+    on_same_as_last: Option<EventHandler<()>>,
     on_cancel: EventHandler<()>,
 ) -> Element {
     let mut weight_input = weight_input;
@@ -55,6 +89,7 @@ pub(super) fn ExerciseInputForm(
     let is_editing_time = time_input.is_some();
     let is_perform_mode = !is_editing_time && exercise_start.is_some();
     let bests = storage::get_exercise_bests(&exercise_id);
+    let lang_str = use_memo(move || i18n().language().to_string());
     let weight = weight_input.read();
     let weight_invalid = !weight.is_empty() && parse_weight_kg(&weight).is_none();
     let reps = reps_input.read();
@@ -71,6 +106,15 @@ pub(super) fn ExerciseInputForm(
     let complete_disabled = !weight_valid || !reps_valid || !distance_valid || !time_valid;
     // Show the ⏱️ row when editing (edit mode), when performing (perform mode), or when an ATH exists.
     let show_duration_row = is_editing_time || is_perform_mode || bests.duration.is_some();
+    let target_input = target_input.filter(|_| is_perform_mode && force == Some(Force::Static));
+    let target_str = target_input.map_or_else(String::new, |ti| ti.read().clone());
+    let target = parse_duration_seconds(&target_str).filter(|&t| t > 0);
+    let target_invalid = !target_str.is_empty() && target.is_none();
+    shortcuts::use_shortcut(move |shortcut| match shortcut {
+        Shortcut::Complete if is_perform_mode && !complete_disabled => on_complete.call(()),
+        Shortcut::Cancel if is_perform_mode => on_cancel.call(()),
+        _ => {}
+    });
     rsx! {
         div { class: "exercise-edit",
             h3 { "{exercise_name}" }
@@ -111,6 +155,7 @@ pub(super) fn ExerciseInputForm(
                             InlineExerciseTimer {
                                 exercise_start,
                                 last_duration: bests.duration,
+                                target,
                                 duration_bell_rung: bell_sig,
                                 paused_at,
                                 force,
@@ -140,29 +185,92 @@ pub(super) fn ExerciseInputForm(
                     time { "{format_time(bests.duration.unwrap_or(0))}" }
                 }
             }
+            // 🎯 Target duration of a static hold, counted down by the timer
+            if let Some(mut ti) = target_input {
+                div { class: "input-row",
+                    span { title: t!("target-duration-title"), "🎯" }
+                    button {
+                        class: "less",
+                        r#type: "button",
+                        tabindex: -1,
+                        onclick: move |_| {
+                            let secs = parse_duration_seconds(&ti.read()).unwrap_or(0);
+                            let next = secs.saturating_sub(TARGET_STEP_SECONDS);
+                            ti.set(if next == 0 { String::new() } else { format_time(next) });
+                            if let Some(mut bell) = duration_bell_rung {
+                                bell.set(false);
+                            }
+                        },
+                        "−"
+                    }
+                    input {
+                        r#type: "text",
+                        inputmode: "numeric",
+                        placeholder: t!("target-duration-placeholder"),
+                        aria_label: t!("target-duration-title"),
+                        value: "{ti}",
+                        oninput: move |evt| {
+                            ti.set(evt.value());
+                            if let Some(mut bell) = duration_bell_rung {
+                                bell.set(false);
+                            }
+                        },
+                        class: if target_invalid { "invalid" } else { "" },
+                    }
+                    button {
+                        class: "more",
+                        r#type: "button",
+                        tabindex: -1,
+                        onclick: move |_| {
+                            let secs = parse_duration_seconds(&ti.read()).unwrap_or(0);
+                            ti.set(format_time(secs + TARGET_STEP_SECONDS));
+                            if let Some(mut bell) = duration_bell_rung {
+                                bell.set(false);
+                            }
+                        },
+                        "+"
+                    }
+                    span {}
+                }
+            }
             // ⚖️ Weight input and ATH (not shown for stretching exercises)
             if show_weight {
                 div { class: "input-row",
-                    span { "⚖️" }
+                    if let Some(mut assisted) = assisted_input {
+                        button {
+                            class: "sign",
+                            r#type: "button",
+                            title: t!("assisted-toggle-title"),
+                            onclick: move |_| assisted.toggle(),
+                            if *assisted.read() {
+                                "⚖️➖"
+                            } else {
+                                "⚖️➕"
+                            }
+                        }
+                    } else {
+                        span { "⚖️" }
+                    }
                     button {
                         class: "less",
                         r#type: "button",
                         tabindex: -1,
                         onclick: move |_| {
-                            let cur: f64 = weight_input.read().parse().unwrap_or(0.0);
+                            let cur = parse_decimal(&weight_input.read()).unwrap_or(0.0);
                             let next = cur - 0.5;
                             if next <= 0.0 {
                                 weight_input.set(String::new());
                             } else {
-                                weight_input.set(format!("{next:.1}"));
+                                weight_input
+                                    .set(localize_decimal(&format!("{next:.1}"), &lang_str.read()));
                             }
                         },
                         "−"
                     }
                     input {
-                        r#type: "number",
+                        // Text rather than number: number inputs reject a decimal comma.
+                        r#type: "text",
                         inputmode: "decimal",
-                        step: "0.1",
                         placeholder: t!("weight-placeholder"),
                         value: "{weight_input}",
                         oninput: move |evt| weight_input.set(evt.value()),
@@ -178,13 +286,14 @@ pub(super) fn ExerciseInputForm(
                         r#type: "button",
                         tabindex: -1,
                         onclick: move |_| {
-                            let cur: f64 = weight_input.read().parse().unwrap_or(0.0);
-                            weight_input.set(format!("{:.1}", cur + 0.5));
+                            let cur = parse_decimal(&weight_input.read()).unwrap_or(0.0);
+                            weight_input
+                                .set(localize_decimal(&format!("{:.1}", cur + 0.5), &lang_str.read()));
                         },
                         "+"
                     }
                     if let Some(best) = bests.weight_hg {
-                        span { "{best}" }
+                        span { {best.localized(&lang_str.read())} }
                     } else {
                         span { "0" }
                     }
@@ -199,16 +308,17 @@ pub(super) fn ExerciseInputForm(
                         r#type: "button",
                         tabindex: -1,
                         onclick: move |_| {
-                            let cur: f64 = distance_input.read().parse().unwrap_or(0.0);
+                            let cur = parse_decimal(&distance_input.read()).unwrap_or(0.0);
                             let next = (cur - 0.1).max(0.0);
-                            distance_input.set(format!("{next:.2}"));
+                            distance_input
+                                .set(localize_decimal(&format!("{next:.2}"), &lang_str.read()));
                         },
                         "−"
                     }
                     input {
-                        r#type: "number",
+                        // Text rather than number: number inputs reject a decimal comma.
+                        r#type: "text",
                         inputmode: "decimal",
-                        step: "0.1",
                         placeholder: t!("distance-placeholder"),
                         value: "{distance_input}",
                         oninput: move |evt| distance_input.set(evt.value()),
@@ -224,13 +334,14 @@ pub(super) fn ExerciseInputForm(
                         r#type: "button",
                         tabindex: -1,
                         onclick: move |_| {
-                            let cur: f64 = distance_input.read().parse().unwrap_or(0.0);
-                            distance_input.set(format!("{:.2}", cur + 0.1));
+                            let cur = parse_decimal(&distance_input.read()).unwrap_or(0.0);
+                            distance_input
+                                .set(localize_decimal(&format!("{:.2}", cur + 0.1), &lang_str.read()));
                         },
                         "+"
                     }
                     if let Some(best) = bests.distance_m {
-                        span { "{best}" }
+                        span { {best.localized(&lang_str.read())} }
                     } else {
                         span { "0" }
                     }
@@ -282,6 +393,47 @@ pub(super) fn ExerciseInputForm(
             }
         }
         footer {
+            if let Some(mut kind) = set_kind_input {
+                button {
+                    class: "edit",
+                    r#type: "button",
+                    title: t!("set-kind-toggle-title"),
+                    onclick: move |_| {
+                        let next = kind.read().next();
+                        kind.set(next);
+                    },
+                    {set_kind_label(*kind.read())}
+                }
+            }
+            if let Some(mut side) = side_input.filter(|_| !is_cardio) {
+                button {
+                    class: "edit",
+                    r#type: "button",
+                    title: t!("side-toggle-title"),
+                    onclick: move |_| {
+                        let next = match *side.read() {
+                            None => Some(Side::Left),
+                            Some(Side::Left) => Some(Side::Right),
+                            Some(Side::Right) => None,
+                        };
+                        side.set(next);
+                    },
+                    match *side.read() {
+                        None => t!("side-both"),
+                        Some(Side::Left) => t!("side-left"),
+                        Some(Side::Right) => t!("side-right"),
+                    }
+                }
+            }
+            if let Some(on_same_as_last) = on_same_as_last {
+                button {
+                    class: "edit",
+                    r#type: "button",
+                    title: t!("exercise-same-as-last-title"),
+                    onclick: move |_| on_same_as_last.call(()),
+                    {t!("exercise-same-as-last-btn")}
+                }
+            }
             button {
                 class: "save",
                 onclick: move |_| on_complete.call(()),
@@ -291,6 +443,9 @@ pub(super) fn ExerciseInputForm(
             }
             button { class: "back", onclick: move |_| on_cancel.call(()), "❌" }
         }
+        if is_perform_mode {
+            super::active_session::TourTip { hint: crate::services::tour::Hint::CompleteCancel }
+        }
     }
 }
 /// The active exercise input form.
@@ -314,22 +469,38 @@ pub(super) fn ExerciseFormPanel(
     duration_bell_rung: Signal<bool>,
     /// Timestamp when the session was paused; `None` when running.
     paused_at: Option<u64>,
+    /// Side worked by the set of a unilateral movement.
+    side_input: Signal<Option<Side>>,
+    /// Whether the weight is assistance, for bodyweight exercises.
+    assisted_input: Signal<bool>,
+    /// Purpose of the set (warm-up, dropset…).
+    set_kind_input: Signal<SetKind>,
+    /// Target duration of a static hold.
+    target_input: Signal<String>,
     /// Called when the user clicks "✓ Complete Exercise".
     on_complete: EventHandler<()>,
+    /// Called when the user clicks "Same as last time", if offered.
+    #[props(default)]
+    on_same_as_last: Option<EventHandler<()>>,
     /// Called when the user clicks "Cancel".
     on_cancel: EventHandler<()>,
 ) -> Element {
-    let all_exercises = exercise_db::use_exercises();
+    let all_exercises = exercise_db::use_hydrated_exercises();
     let custom_exercises = storage::use_custom_exercises();
     let lang_str = use_memo(move || i18n().language().to_string());
-    let (exercise_name, category, force) = {
+    let (exercise_name, category, force, bodyweight) = {
         let all = all_exercises.read();
         let custom = custom_exercises.read();
         let lang = lang_str.read();
         if let Some(ex) = exercise_db::resolve_exercise(&all, &custom, &exercise_id) {
-            (ex.name_for_lang(&lang).to_owned(), ex.category, ex.force)
+            (
+                ex.name_for_lang(&lang).to_owned(),
+                ex.category,
+                ex.force,
+                ex.is_bodyweight(),
+            )
         } else {
-            ("Unknown".to_string(), Category::Strength, None)
+            ("Unknown".to_string(), Category::Strength, None, false)
         }
     };
     rsx! {
@@ -356,7 +527,12 @@ pub(super) fn ExerciseFormPanel(
                 exercise_start: *current_exercise_start.read(),
                 duration_bell_rung: Some(duration_bell_rung),
                 paused_at,
+                target_input: Some(target_input),
+                side_input: Some(side_input),
+                assisted_input: bodyweight.then_some(assisted_input),
+                set_kind_input: Some(set_kind_input),
                 on_complete,
+                on_same_as_last,
                 on_cancel,
             }
         }
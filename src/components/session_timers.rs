@@ -1,11 +1,10 @@
-use crate::models::{format_time, format_time_i64, get_current_timestamp, Force};
+#[cfg(target_arch = "wasm32")]
+use crate::models::get_current_timestamp;
+use crate::models::{format_time, format_time_i64, Force};
+use crate::services::clock;
 use dioxus::prelude::*;
 use dioxus_i18n::t;
 
-/// Timer tick interval in milliseconds.
-#[cfg(target_arch = "wasm32")]
-const TIMER_TICK_MS: u32 = 1_000;
-
 /// How many milliseconds ahead of the target time to fire notifications.
 ///
 /// Sending slightly early compensates for scheduling jitter so the alert
@@ -21,12 +20,17 @@ pub const NOTIF_EARLY_MS: u64 = 250;
 /// * On **native**: the tick-based path is accurate enough (±1 s) and avoids
 ///   the complexity of crossing Dioxus signal boundaries from a `tokio::spawn`
 ///   thread; no extra task is spawned here.
+///
+/// `due` holds the duration the bell is currently due at: a notification
+/// scheduled for an older target is dropped when it fires.
 #[allow(unused_mut)]
 fn schedule_duration_notification(
     exercise_start: Option<u64>,
     last_duration: Option<u64>,
     mut duration_bell_rung: Signal<bool>,
+    mut due: Signal<Option<u64>>,
 ) {
+    due.set(last_duration);
     #[cfg(target_arch = "wasm32")]
     {
         let Some(start) = exercise_start else { return };
@@ -48,8 +52,9 @@ fn schedule_duration_notification(
         let body = t!("notif-duration-body").to_string();
         wasm_bindgen_futures::spawn_local(async move {
             gloo_timers::future::TimeoutFuture::new(delay_ms).await;
-            // Re-check to avoid a duplicate if the tick fired first.
-            if !*duration_bell_rung.peek() {
+            // Re-check to avoid a duplicate if the tick fired first, or a
+            // bell for a target changed meanwhile.
+            if !*duration_bell_rung.peek() && *due.peek() == Some(dur) {
                 duration_bell_rung.set(true);
                 crate::services::notifications::send_notification(&title, &body, "logout-duration");
             }
@@ -57,7 +62,7 @@ fn schedule_duration_notification(
     }
     // On native, suppress unused-variable warnings; the tick handles it.
     #[cfg(not(target_arch = "wasm32"))]
-    let _ = (exercise_start, last_duration, duration_bell_rung);
+    let _ = (exercise_start, duration_bell_rung);
 }
 
 /// Renders the rest-timer with a countdown.
@@ -74,16 +79,7 @@ pub fn RestTimer(
     paused_at: Option<u64>,
     mut bell_count: Signal<u64>,
 ) -> Element {
-    let mut now_tick = use_signal(get_current_timestamp);
-    use_coroutine(move |_: UnboundedReceiver<()>| async move {
-        loop {
-            #[cfg(target_arch = "wasm32")]
-            gloo_timers::future::TimeoutFuture::new(TIMER_TICK_MS).await;
-            #[cfg(not(target_arch = "wasm32"))]
-            tokio::time::sleep(std::time::Duration::from_millis(1_000)).await;
-            now_tick.set(get_current_timestamp());
-        }
-    });
+    let now_tick = clock::use_clock();
 
     let Some(start) = start_time else {
         return rsx! {
@@ -131,20 +127,12 @@ pub fn ExerciseElapsedTimer(
     force: Option<Force>,
 ) -> Element {
     // Schedule a precise one-shot notification (WASM only; native uses tick).
+    let due = use_signal(|| None);
     use_effect(move || {
-        schedule_duration_notification(exercise_start, last_duration, duration_bell_rung);
+        schedule_duration_notification(exercise_start, last_duration, duration_bell_rung, due);
     });
 
-    let mut now_tick = use_signal(get_current_timestamp);
-    use_coroutine(move |_: UnboundedReceiver<()>| async move {
-        loop {
-            #[cfg(target_arch = "wasm32")]
-            gloo_timers::future::TimeoutFuture::new(TIMER_TICK_MS).await;
-            #[cfg(not(target_arch = "wasm32"))]
-            tokio::time::sleep(std::time::Duration::from_millis(1_000)).await;
-            now_tick.set(get_current_timestamp());
-        }
-    });
+    let now_tick = clock::use_clock();
 
     let effective_now = paused_at.unwrap_or_else(|| *now_tick.read());
     let elapsed = if let Some(start) = exercise_start {
@@ -177,29 +165,30 @@ pub fn ExerciseElapsedTimer(
 }
 
 /// Renders the exercise elapsed timer inline inside the ⏱️ form row (perform mode).
+///
+/// With an explicit `target` duration (static holds), it counts down to the
+/// target, rings the bell there and then counts up; otherwise the bell rings
+/// at `last_duration`.
 #[component]
 pub(super) fn InlineExerciseTimer(
     exercise_start: Option<u64>,
     last_duration: Option<u64>,
+    /// Duration set for this set, beating `last_duration`.
+    #[props(default)]
+    target: Option<u64>,
     mut duration_bell_rung: Signal<bool>,
     paused_at: Option<u64>,
     force: Option<Force>,
 ) -> Element {
-    // Schedule a precise one-shot notification (WASM only; native uses tick).
-    use_effect(move || {
-        schedule_duration_notification(exercise_start, last_duration, duration_bell_rung);
-    });
+    let bell_at = target.or(last_duration);
+    // Schedule a precise one-shot notification (WASM only; native uses tick),
+    // again whenever the target changes.
+    let due = use_signal(|| None);
+    use_effect(use_reactive!(|bell_at| {
+        schedule_duration_notification(exercise_start, bell_at, duration_bell_rung, due);
+    }));
 
-    let mut now_tick = use_signal(get_current_timestamp);
-    use_coroutine(move |_: UnboundedReceiver<()>| async move {
-        loop {
-            #[cfg(target_arch = "wasm32")]
-            gloo_timers::future::TimeoutFuture::new(TIMER_TICK_MS).await;
-            #[cfg(not(target_arch = "wasm32"))]
-            tokio::time::sleep(std::time::Duration::from_millis(1_000)).await;
-            now_tick.set(get_current_timestamp());
-        }
-    });
+    let now_tick = clock::use_clock();
 
     let effective_now = paused_at.unwrap_or_else(|| *now_tick.read());
     let elapsed = if let Some(start) = exercise_start {
@@ -210,7 +199,7 @@ pub(super) fn InlineExerciseTimer(
 
     // Tick-based fallback.
     if !*duration_bell_rung.read() {
-        if let Some(dur) = last_duration {
+        if let Some(dur) = bell_at {
             if dur > 0 && elapsed >= dur {
                 duration_bell_rung.set(true);
                 crate::services::notifications::send_notification(
@@ -223,9 +212,14 @@ pub(super) fn InlineExerciseTimer(
     }
 
     let is_static = force == Some(Force::Static);
-    let timer_reached = is_static && last_duration.is_some_and(|d| d > 0 && elapsed >= d);
-    rsx! {
-        span { class: if timer_reached { "reached" } else { "" }, "{format_time(elapsed)}" }
+    let timer_reached = is_static && bell_at.is_some_and(|d| d > 0 && elapsed >= d);
+    match target {
+        Some(target) if elapsed < target => rsx! {
+            span { class: "countdown", {format_time_i64(elapsed.cast_signed() - target.cast_signed())} }
+        },
+        _ => rsx! {
+            span { class: if timer_reached { "reached" } else { "" }, "{format_time(elapsed)}" }
+        },
     }
 }
 
@@ -237,16 +231,7 @@ pub fn RestTimerDisplay(
     rest_duration: u64,
     paused_at: Option<u64>,
 ) -> Element {
-    let mut now_tick = use_signal(get_current_timestamp);
-    use_coroutine(move |_: UnboundedReceiver<()>| async move {
-        loop {
-            #[cfg(target_arch = "wasm32")]
-            gloo_timers::future::TimeoutFuture::new(TIMER_TICK_MS).await;
-            #[cfg(not(target_arch = "wasm32"))]
-            tokio::time::sleep(std::time::Duration::from_millis(1_000)).await;
-            now_tick.set(get_current_timestamp());
-        }
-    });
+    let now_tick = clock::use_clock();
 
     let Some(start) = start_time else {
         return rsx! {
@@ -274,16 +259,7 @@ pub fn SessionDurationDisplay(
     paused_at: Option<u64>,
     total_paused_duration: u64,
 ) -> Element {
-    let mut now_tick = use_signal(get_current_timestamp);
-    use_coroutine(move |_: UnboundedReceiver<()>| async move {
-        loop {
-            #[cfg(target_arch = "wasm32")]
-            gloo_timers::future::TimeoutFuture::new(TIMER_TICK_MS).await;
-            #[cfg(not(target_arch = "wasm32"))]
-            tokio::time::sleep(std::time::Duration::from_millis(1_000)).await;
-            now_tick.set(get_current_timestamp());
-        }
-    });
+    let now_tick = clock::use_clock();
 
     let effective_now = if session_is_active {
         paused_at.unwrap_or_else(|| *now_tick.read())
@@ -0,0 +1,174 @@
+use crate::models::WorkoutSession;
+use crate::services::share_image::{self, ShareCard, ShareOutcome};
+use crate::services::{session_stats, share_link, storage, transfer};
+use crate::utils::DeepLinkAction;
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+/// Button sharing a picture of the finished `session`.
+///
+/// Records are looked up in the whole stored history, loaded on demand.
+#[component]
+pub fn ShareSessionButton(session: WorkoutSession) -> Element {
+    let mut busy = use_signal(|| false);
+    let mut toast = use_context::<ToastSignal>().0;
+    let share = move |_| {
+        let stats = session_stats::compute::<crate::models::Exercise>(&session, &[], &[]);
+        let lang = i18n().language().to_string();
+        let mut card = ShareCard {
+            title: t!(
+                "share-image-title",
+                date: crate::datetime::format_short_date(session.start_time, &lang)
+            ),
+            labels: [
+                t!("share-image-duration"),
+                t!("share-image-volume"),
+                t!("share-image-sets"),
+            ],
+            duration_s: stats.duration,
+            volume_kg: stats.volume_kg(),
+            set_count: stats.set_count,
+            exercises: share_image::exercise_sets(&session),
+            records: Vec::new(),
+        };
+        let date = crate::datetime::to_local(session.start_time).date();
+        let filename = format!("logout-{date}.png");
+        let session = session.clone();
+        busy.set(true);
+        spawn(async move {
+            match super::more::load_all_sessions(Vec::new()).await {
+                Ok(history) => card.records = share_image::session_records(&session, &history),
+                Err(e) => log::warn!("Records left out of the shared picture: {e}"),
+            }
+            let svg = share_image::render_svg(&card);
+            let height = share_image::card_height(&card);
+            match share_image::share_png(&svg, height, &filename, &card.title).await {
+                Ok(ShareOutcome::Shared | ShareOutcome::Saved | ShareOutcome::Cancelled) => {}
+                Ok(ShareOutcome::Unsupported) => {
+                    let svg_name = filename.replace(".png", ".svg");
+                    if let Some(msg) =
+                        super::more::trigger_download(&svg_name, &svg, "image/svg+xml")
+                    {
+                        toast.write().push_back(msg);
+                    }
+                }
+                Err(e) => toast.write().push_back(t!("share-image-failed", error: e)),
+            }
+            busy.set(false);
+        });
+    };
+    rsx! {
+        button {
+            class: "edit",
+            disabled: busy(),
+            onclick: share,
+            title: t!("share-image-btn-title"),
+            "📸"
+        }
+    }
+}
+/// Address of `code` in the web app: the current page on the web,
+/// [`crate::utils::WEB_APP_URL`] elsewhere.
+fn share_url(code: &str) -> String {
+    #[cfg(target_arch = "wasm32")]
+    let base = web_sys::window()
+        .and_then(|w| {
+            let location = w.location();
+            Some(format!(
+                "{}{}",
+                location.origin().ok()?,
+                location.pathname().ok()?
+            ))
+        })
+        .unwrap_or_else(|| crate::utils::WEB_APP_URL.to_owned());
+    #[cfg(not(target_arch = "wasm32"))]
+    let base = crate::utils::WEB_APP_URL.to_owned();
+    format!("{base}?dl_import={code}")
+}
+/// Import the session of a shared link, or of its bare code, as a new
+/// completed session.  Reports the outcome in a toast; returns whether the
+/// session was imported.
+pub(crate) fn import_shared_session(input: &str) -> bool {
+    let input = input.trim();
+    let code = input
+        .split_once('?')
+        .and_then(
+            |(_, query)| match crate::utils::parse_web_deep_link_query(query) {
+                Some(DeepLinkAction::ImportSession(code)) => Some(code),
+                _ => None,
+            },
+        )
+        .unwrap_or_else(|| input.to_owned());
+    let mut toast = consume_context::<ToastSignal>().0;
+    match share_link::decode_session(&code, WorkoutSession::new().id) {
+        Ok(session) => {
+            storage::save_session(session);
+            toast.write().push_back(t!("share-link-imported"));
+            true
+        }
+        Err(e) => {
+            toast
+                .write()
+                .push_back(t!("share-link-invalid", error: e.to_string()));
+            false
+        }
+    }
+}
+/// Link and QR code another `LogOut` opens to import a copy of `session`.
+#[component]
+pub fn SessionLink(session: WorkoutSession) -> Element {
+    let url = use_memo(move || share_url(&share_link::encode_session(&session)));
+    let qr = use_memo(move || transfer::qr_svg(&url.read()));
+    rsx! {
+        div { class: "share-link",
+            if let Some(svg) = qr() {
+                div { class: "qr", dangerous_inner_html: "{svg}" }
+            } else {
+                p { {t!("share-link-too-long")} }
+            }
+            input {
+                r#type: "url",
+                readonly: true,
+                value: "{url}",
+                aria_label: t!("share-link-aria"),
+                onfocus: move |_| {
+                    document::eval("document.activeElement.select()");
+                },
+            }
+        }
+    }
+}
+/// Settings card importing a session from a shared link or code (shown on
+/// the More page), for native builds, which do not open such links.
+#[component]
+pub fn ImportSharedSession() -> Element {
+    let mut input = use_signal(String::new);
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        if import_shared_session(&input.peek()) {
+            input.set(String::new());
+        }
+    };
+    rsx! {
+        article {
+            h2 { {t!("share-link-import-section")} }
+            p { {t!("share-link-import-desc")} }
+            form { onsubmit: on_submit,
+                input {
+                    r#type: "text",
+                    value: "{input}",
+                    placeholder: t!("share-link-import-placeholder"),
+                    oninput: move |evt| input.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "icon save",
+                    disabled: input.read().trim().is_empty(),
+                    aria_label: t!("share-link-import-aria"),
+                    "📥"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::components::{ActiveTab, BottomNav};
+use crate::services::image_prefetch::{self, ImagePrefetch};
+use crate::services::storage_usage::{self, format_bytes, Store};
+use crate::services::{exercise_db, storage::StorageError};
+use crate::{PersistentStorageSignal, ToastSignal};
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use strum::IntoEnumIterator;
+/// Translated name of a [`Store`].
+fn store_label(store: Store) -> String {
+    match store {
+        Store::Sessions => t!("storage-sessions"),
+        Store::CustomExercises => t!("storage-custom-exercises"),
+        Store::Exercises => t!("storage-exercises"),
+        Store::Trash => t!("storage-trash"),
+        Store::Images => t!("storage-images"),
+        Store::Other => t!("storage-other"),
+    }
+}
+/// Localised name of an image prefetch `policy`.
+fn prefetch_label(policy: ImagePrefetch) -> String {
+    match policy {
+        ImagePrefetch::Off => t!("storage-prefetch-off"),
+        ImagePrefetch::WifiOnly => t!("storage-prefetch-wifi-only"),
+        ImagePrefetch::Always => t!("storage-prefetch-always"),
+    }
+}
+/// Storage usage page: the space each store consumes, the image prefetch
+/// policy, with actions to clear the exercise image cache and, on native
+/// platforms, compact the database.
+#[component]
+pub fn StorageUsage() -> Element {
+    let mut toast = use_context::<ToastSignal>().0;
+    let exercises = exercise_db::use_exercises();
+    let mut usage = use_resource(storage_usage::measure);
+    let mut busy = use_signal(|| false);
+    let mut persistent = use_context::<PersistentStorageSignal>().0;
+    let mut prefetch = use_signal(image_prefetch::image_prefetch);
+    let mut report = move |result: Result<String, StorageError>| {
+        match result {
+            Ok(msg) => toast.write().push_back(msg),
+            Err(e) => {
+                log::error!("Storage cleanup failed: {e}");
+                toast
+                    .write()
+                    .push_back(t!("storage-failed", error: e.to_string()));
+            }
+        }
+        busy.set(false);
+        usage.restart();
+    };
+    let clear_images = move |_| {
+        busy.set(true);
+        spawn(async move {
+            let list = exercises.read().clone();
+            let result = storage_usage::clear_image_cache(&list).await;
+            report(result.map(|_| t!("storage-images-cleared")));
+        });
+    };
+    // Only the SQLite database (native platforms) can be compacted.
+    #[cfg(not(target_arch = "wasm32"))]
+    let compact_button = rsx! {
+        button {
+            class: "label more",
+            disabled: busy(),
+            onclick: move |_| {
+                busy.set(true);
+                spawn(async move {
+                    let result = storage_usage::compact().await;
+                    report(result.map(|()| t!("storage-compacted")));
+                });
+            },
+            {t!("storage-compact-btn")}
+        }
+    };
+    #[cfg(target_arch = "wasm32")]
+    let compact_button = rsx! {};
+    let total = match &*usage.read() {
+        Some(Ok(u)) => match (u.total, u.quota) {
+            (Some(used), Some(quota)) => Some(t!(
+                "storage-total-quota",
+                used: format_bytes(used),
+                quota: format_bytes(quota)
+            )),
+            (Some(used), None) => Some(t!("storage-total", used: format_bytes(used))),
+            _ => None,
+        },
+        _ => None,
+    };
+    rsx! {
+        Stylesheet { href: asset!("/assets/more.scss") }
+        header {
+            h1 { {t!("storage-title")} }
+        }
+        main { class: "more storage",
+            p { {t!("storage-desc")} }
+            article {
+                match &*usage.read() {
+                    None => rsx! {},
+                    Some(Err(e)) => rsx! {
+                        p { {t!("storage-failed", error: e.to_string())} }
+                    },
+                    Some(Ok(u)) => rsx! {
+                        table {
+                            tbody {
+                                for (store, bytes) in u.stores.iter().copied() {
+                                    tr { key: "{store:?}",
+                                        th { {store_label(store)} }
+                                        td { {format_bytes(bytes)} }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                }
+                if let Some(total) = total {
+                    p { "{total}" }
+                }
+                match *persistent.read() {
+                    Some(true) => rsx! {
+                        p { {t!("storage-persistent")} }
+                    },
+                    Some(false) => rsx! {
+                        p { class: "error", {t!("storage-not-persistent")} }
+                        div { class: "inputs",
+                            button {
+                                class: "label save",
+                                onclick: move |_| {
+                                    spawn(async move {
+                                        let granted = storage_usage::request_persistence().await;
+                                        persistent.set(Some(granted));
+                                    });
+                                },
+                                {t!("storage-persist-btn")}
+                            }
+                        }
+                    },
+                    None => rsx! {},
+                }
+            }
+            article {
+                p { {t!("storage-prefetch-desc")} }
+                select {
+                    aria_label: t!("storage-prefetch-aria"),
+                    oninput: move |evt| {
+                        if let Ok(policy) = evt.value().parse() {
+                            image_prefetch::set_image_prefetch(policy);
+                            prefetch.set(policy);
+                        }
+                    },
+                    for policy in ImagePrefetch::iter() {
+                        option {
+                            value: policy.as_ref(),
+                            selected: prefetch() == policy,
+                            {prefetch_label(policy)}
+                        }
+                    }
+                }
+                div { class: "inputs",
+                    button {
+                        class: "label more",
+                        disabled: busy(),
+                        onclick: clear_images,
+                        {t!("storage-clear-images-btn")}
+                    }
+                    {compact_button}
+                }
+            }
+        }
+        BottomNav { active_tab: ActiveTab::More }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::services::storage;
+use crate::services::sync::{self, SyncPayload};
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// Settings card for the end-to-end encrypted sync (shown on the More page).
+///
+/// The endpoint URL is persisted; the passphrase is kept in memory only and
+/// must be re-entered after a restart.
+#[component]
+pub fn SyncSettings() -> Element {
+    let mut endpoint_input = use_signal(|| sync::configured_endpoint().unwrap_or_default());
+    let mut passphrase = use_signal(String::new);
+    let mut is_syncing = use_signal(|| false);
+    let sessions = storage::use_sessions();
+    let custom_exercises = storage::use_custom_exercises();
+    let toast = consume_context::<ToastSignal>().0;
+    let msg_done = use_memo(|| t!("sync-done"));
+    let msg_failed = use_memo(|| t!("sync-failed"));
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        if *is_syncing.peek() {
+            return;
+        }
+        let endpoint = crate::utils::normalize_db_url(endpoint_input.read().trim());
+        endpoint_input.set(endpoint.clone());
+        crate::utils::set_config(sync::SYNC_ENDPOINT_KEY, &endpoint);
+        if endpoint.is_empty() || passphrase.read().is_empty() {
+            return;
+        }
+        let pass = passphrase.peek().clone();
+        let mut t = toast;
+        is_syncing.set(true);
+        spawn(async move {
+            let mut local: Vec<SyncPayload> = Vec::new();
+            match super::more::load_all_sessions(sessions.peek().clone()).await {
+                Ok(all) => local.extend(all.into_iter().map(SyncPayload::Session)),
+                Err(e) => {
+                    t.write().push_back(format!("{}: {e}", msg_failed()));
+                    is_syncing.set(false);
+                    return;
+                }
+            }
+            local.extend(
+                custom_exercises
+                    .peek()
+                    .iter()
+                    .map(|e| SyncPayload::CustomExercise(e.as_ref().clone())),
+            );
+            match sync::sync_now(&pass, local).await {
+                Ok(outcome) => {
+                    let pulled = outcome.to_apply.len();
+                    for payload in outcome.to_apply {
+                        match payload {
+                            SyncPayload::Session(s) => storage::save_session(s),
+                            SyncPayload::CustomExercise(e) => {
+                                let exists = custom_exercises.peek().iter().any(|c| c.id == e.id);
+                                if exists {
+                                    storage::update_custom_exercise(e);
+                                } else {
+                                    storage::add_custom_exercise(e);
+                                }
+                            }
+                            // Deleted on another device: trashed here too.
+                            SyncPayload::Deleted {
+                                kind: sync::RecordKind::Session,
+                                id,
+                            } => storage::delete_session(&id),
+                            SyncPayload::Deleted {
+                                kind: sync::RecordKind::CustomExercise,
+                                id,
+                            } => storage::delete_custom_exercise(&id),
+                        }
+                    }
+                    t.write()
+                        .push_back(format!("🔄 {} ↓{pulled} ↑{}", msg_done(), outcome.pushed));
+                }
+                Err(e) => {
+                    t.write().push_back(format!("{}: {e}", msg_failed()));
+                }
+            }
+            is_syncing.set(false);
+        });
+    };
+    rsx! {
+        article {
+            h2 { {t!("sync-section")} }
+            p { {t!("sync-desc")} }
+            form { onsubmit: on_submit,
+                input {
+                    r#type: "url",
+                    value: "{endpoint_input}",
+                    placeholder: "https://sync.example.com/",
+                    oninput: move |evt| endpoint_input.set(evt.value()),
+                }
+                input {
+                    r#type: "password",
+                    value: "{passphrase}",
+                    placeholder: t!("sync-passphrase-placeholder"),
+                    autocomplete: "current-password",
+                    oninput: move |evt| passphrase.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "icon save",
+                    disabled: *is_syncing.read(),
+                    aria_label: t!("sync-now-aria"),
+                    if *is_syncing.read() {
+                        "⏳"
+                    } else {
+                        "🔄"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::models::{CustomTag, TaxonomyKind};
+use crate::services::storage;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use strum::IntoEnumIterator;
+/// Localised name of a taxonomy `kind`.
+fn kind_label(kind: TaxonomyKind) -> String {
+    match kind {
+        TaxonomyKind::Category => t!("taxonomy-kind-category"),
+        TaxonomyKind::Equipment => t!("taxonomy-kind-equipment"),
+        TaxonomyKind::Muscle => t!("taxonomy-kind-muscle"),
+    }
+}
+/// Settings card of the custom categories, equipment and muscles (shown on
+/// the More page).
+#[component]
+pub fn CustomTaxonomySettings() -> Element {
+    let taxonomy = storage::use_custom_taxonomy();
+    let mut kind_input = use_signal(|| TaxonomyKind::Category);
+    let mut label_input = use_signal(String::new);
+    let mut toast = use_context::<crate::ToastSignal>().0;
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        let mut next = taxonomy.peek().clone();
+        if next.add(*kind_input.peek(), &label_input.peek()) {
+            storage::save_custom_taxonomy(next);
+            label_input.set(String::new());
+        } else {
+            toast.write().push_back(t!("taxonomy-invalid"));
+        }
+    };
+    rsx! {
+        article {
+            h2 { {t!("taxonomy-section")} }
+            p { {t!("taxonomy-desc")} }
+            ul { class: "tags",
+                for tag in taxonomy.read().tags.iter().cloned() {
+                    li { key: "{tag.display()}",
+                        button {
+                            class: "del label",
+                            title: t!("taxonomy-remove", kind: kind_label(tag.kind)),
+                            onclick: move |_| storage::remove_custom_tag(&tag),
+                            "{tag.display()}"
+                        }
+                    }
+                }
+            }
+            form { onsubmit: on_submit,
+                select {
+                    aria_label: t!("taxonomy-kind-aria"),
+                    oninput: move |evt| {
+                        if let Some(kind) = TaxonomyKind::iter().find(|k| k.as_ref() == evt.value()) {
+                            kind_input.set(kind);
+                        }
+                    },
+                    for kind in TaxonomyKind::iter() {
+                        option {
+                            value: kind.as_ref(),
+                            selected: *kind_input.read() == kind,
+                            "{kind.icon()} {kind_label(kind)}"
+                        }
+                    }
+                }
+                input {
+                    r#type: "text",
+                    value: "{label_input}",
+                    maxlength: crate::models::MAX_TAG_LABEL_LEN,
+                    placeholder: t!("taxonomy-placeholder"),
+                    oninput: move |evt| label_input.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "icon save",
+                    disabled: label_input.read().trim().is_empty(),
+                    aria_label: t!("taxonomy-add-aria"),
+                    "➕"
+                }
+            }
+        }
+    }
+}
+/// Custom tags of an expanded exercise card, removable, followed by a
+/// picker of the other custom tags.  Renders nothing while no custom tag is
+/// defined.
+#[component]
+pub fn ExerciseTags(exercise_id: String) -> Element {
+    let overrides = storage::use_exercise_overrides();
+    let taxonomy = storage::use_custom_taxonomy();
+    let tags: Vec<CustomTag> = overrides
+        .read()
+        .get(&exercise_id)
+        .map(|o| o.tags.clone())
+        .unwrap_or_default();
+    let available: Vec<CustomTag> = taxonomy
+        .read()
+        .tags
+        .iter()
+        .filter(|t| !tags.contains(t))
+        .cloned()
+        .collect();
+    if tags.is_empty() && available.is_empty() {
+        return rsx! {};
+    }
+    let set_tags = move |id: &str, tags: Vec<CustomTag>| {
+        let mut over = overrides.peek().get(id).cloned().unwrap_or_default();
+        over.tags = tags;
+        storage::set_exercise_override(id, over);
+    };
+    rsx! {
+        if !tags.is_empty() {
+            ul { class: "tags",
+                for tag in tags.iter() {
+                    li { key: "{tag.display()}",
+                        button {
+                            class: "del label",
+                            title: t!("exercise-tag-remove"),
+                            onclick: {
+                                let id = exercise_id.clone();
+                                let remaining: Vec<CustomTag> = tags
+                                    .iter()
+                                    .filter(|t| *t != tag)
+                                    .cloned()
+                                    .collect();
+                                move |_| set_tags(&id, remaining.clone())
+                            },
+                            "{tag.display()}"
+                        }
+                    }
+                }
+            }
+        }
+        if !available.is_empty() {
+            select {
+                aria_label: t!("exercise-tag-add"),
+                value: "",
+                oninput: {
+                    let id = exercise_id.clone();
+                    let tags = tags.clone();
+                    move |evt: FormEvent| {
+                        if let Some(tag) = available.iter().find(|t| t.display() == evt.value()) {
+                            let mut next = tags.clone();
+                            next.push(tag.clone());
+                            set_tags(&id, next);
+                        }
+                    }
+                },
+                option { value: "", {t!("exercise-tag-add")} }
+                for tag in available.iter() {
+                    option { key: "{tag.display()}", value: "{tag.display()}", "{tag.display()}" }
+                }
+            }
+        }
+    }
+}
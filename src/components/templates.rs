@@ -0,0 +1,100 @@
+use crate::models::{SessionTemplate, WorkoutSession};
+use crate::services::storage;
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// Start a new session queuing the exercises of `template`, with their
+/// targets.
+fn start_template(template: &SessionTemplate) {
+    let mut session = WorkoutSession::new();
+    session.pending_exercises = template.exercises.clone();
+    storage::start_session(session);
+}
+/// Saved session templates above the history, each started in one tap.
+#[component]
+pub fn SessionTemplates() -> Element {
+    let templates = storage::use_templates();
+    let remove = move |name: String| {
+        spawn(async move {
+            let message = t!("template-delete-confirm", name: name.clone());
+            if super::confirm(message, t!("template-delete")).await {
+                let mut next = templates.peek().clone();
+                next.retain(|t| t.name != name);
+                storage::save_templates(next);
+            }
+        });
+    };
+    if templates.read().is_empty() {
+        return rsx! {};
+    }
+    rsx! {
+        div { class: "templates",
+            label { title: t!("templates-desc"), {t!("templates-title")} }
+            ul {
+                for template in templates.read().iter().cloned() {
+                    li { key: "{template.name}",
+                        button {
+                            class: "label save",
+                            title: t!("template-start-title"),
+                            onclick: {
+                                let template = template.clone();
+                                move |_| start_template(&template)
+                            },
+                            "▶️ {template.name} ({template.exercises.len()})"
+                        }
+                        button {
+                            class: "del",
+                            aria_label: t!("template-delete"),
+                            onclick: {
+                                let name = template.name.clone();
+                                move |_| remove(name.clone())
+                            },
+                            "🗑️"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+/// Form naming a template saved from `session`, replacing any template of the
+/// same name; `on_saved` is called once saved.
+#[component]
+pub fn SaveTemplateForm(session: WorkoutSession, on_saved: EventHandler<()>) -> Element {
+    let mut name = use_signal(String::new);
+    let mut toast = use_context::<ToastSignal>().0;
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        let name = name.peek().trim().to_owned();
+        if name.is_empty() {
+            return;
+        }
+        let template = SessionTemplate::from_session(name.clone(), &session);
+        let mut templates = storage::use_templates().peek().clone();
+        match templates.iter_mut().find(|t| t.name == name) {
+            Some(existing) => *existing = template,
+            None => templates.push(template),
+        }
+        storage::save_templates(templates);
+        toast.write().push_back(t!("template-saved", name: name));
+        on_saved.call(());
+    };
+    rsx! {
+        form { class: "save-template", onsubmit: on_submit,
+            input {
+                r#type: "text",
+                value: "{name}",
+                placeholder: t!("template-name-placeholder"),
+                aria_label: t!("template-name-placeholder"),
+                oninput: move |evt| name.set(evt.value()),
+            }
+            button {
+                r#type: "submit",
+                class: "icon save",
+                disabled: name.read().trim().is_empty(),
+                aria_label: t!("template-save-title"),
+                "💾"
+            }
+        }
+    }
+}
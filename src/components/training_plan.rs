@@ -0,0 +1,153 @@
+use crate::models::{adherence_csv, TrainingPlan, WorkoutSession};
+use crate::services::{exercise_db, storage};
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use std::collections::HashSet;
+/// ID of the file input importing a plan.
+const IMPORT_PLAN_INPUT_ID: &str = "import-plan-input";
+/// Settings card importing a coach's training plan, starting its days and
+/// exporting the weekly adherence report (shown on the More page).
+#[component]
+pub fn TrainingPlanSettings() -> Element {
+    let plan = storage::use_training_plan();
+    let exercises = exercise_db::use_exercises();
+    let custom_exercises = storage::use_custom_exercises();
+    let mut toast = use_context::<ToastSignal>().0;
+    let on_file_change = move |_| {
+        spawn(async move {
+            let Some(content) = super::more::read_file_input(IMPORT_PLAN_INPUT_ID).await else {
+                return;
+            };
+            let result = {
+                let exercises = exercises.read();
+                let customs = custom_exercises.read();
+                let known: HashSet<&str> = exercises
+                    .iter()
+                    .chain(customs.iter())
+                    .map(|e| e.id.as_str())
+                    .collect();
+                TrainingPlan::from_json(&content, &known)
+            };
+            match result {
+                Ok(mut imported) => {
+                    imported.started_on = Some(crate::datetime::today().to_string());
+                    toast
+                        .write()
+                        .push_back(t!("training-plan-imported", name: imported.name.clone()));
+                    storage::save_training_plan(Some(imported));
+                }
+                Err(e) => toast
+                    .write()
+                    .push_back(t!("training-plan-invalid", error: e.to_string())),
+            }
+        });
+    };
+    let export_plan = move |_| {
+        let Some(current) = plan.read().clone() else {
+            return;
+        };
+        let exported = TrainingPlan {
+            started_on: None,
+            ..current
+        };
+        if let Ok(json) = serde_json::to_string_pretty(&exported) {
+            if let Some(msg) =
+                super::more::trigger_download("training_plan.json", &json, "application/json")
+            {
+                toast.write().push_back(msg);
+            }
+        }
+    };
+    let export_report = move |_| {
+        let Some(current) = plan.read().clone() else {
+            return;
+        };
+        let today = crate::datetime::today();
+        let started = current
+            .started_on
+            .as_deref()
+            .and_then(crate::datetime::parse_iso_date)
+            .unwrap_or(today);
+        spawn(async move {
+            match super::more::load_all_sessions(Vec::new()).await {
+                Ok(history) => {
+                    let csv = adherence_csv(&current.adherence(started, &history, today));
+                    let filename = format!("adherence-{today}.csv");
+                    if let Some(msg) = super::more::trigger_download(&filename, &csv, "text/csv") {
+                        toast.write().push_back(msg);
+                    }
+                }
+                Err(e) => toast
+                    .write()
+                    .push_back(t!("training-plan-report-failed", error: e.to_string())),
+            }
+        });
+    };
+    let start_day = move |index: usize| {
+        let Some(day) = plan
+            .read()
+            .as_ref()
+            .and_then(|p| p.days.get(index).cloned())
+        else {
+            return;
+        };
+        let mut session = WorkoutSession::new();
+        session.pending_exercises = day.exercises;
+        if storage::start_session(session) {
+            navigator().push(crate::Route::Home {});
+        }
+    };
+    let remove = move |_| storage::save_training_plan(None);
+    rsx! {
+        article {
+            h2 { {t!("training-plan-section")} }
+            if let Some(current) = plan.read().as_ref() {
+                h3 { "{current.name}" }
+                if !current.author.is_empty() {
+                    p { {t!("training-plan-author", author: current.author.clone())} }
+                }
+                if !current.notes.is_empty() {
+                    p { "{current.notes}" }
+                }
+                p { {t!("training-plan-per-week", count: current.sessions_per_week)} }
+                ul {
+                    for (index, day) in current.days.iter().enumerate() {
+                        li { key: "{index}",
+                            span { "{day.name} ({day.exercises.len()})" }
+                            button {
+                                class: "label save",
+                                onclick: move |_| start_day(index),
+                                {t!("training-plan-start-btn")}
+                            }
+                        }
+                    }
+                }
+                div { class: "inputs",
+                    button { class: "label more", onclick: export_report,
+                        {t!("training-plan-report-btn")}
+                    }
+                    button { class: "label more", onclick: export_plan,
+                        {t!("training-plan-export-btn")}
+                    }
+                    button { class: "del label", onclick: remove,
+                        {t!("training-plan-remove-btn")}
+                    }
+                }
+            } else {
+                p { {t!("training-plan-desc")} }
+                div { class: "file-upload-btn",
+                    label { class: "label more", r#for: IMPORT_PLAN_INPUT_ID,
+                        {t!("training-plan-import-btn")}
+                    }
+                    input {
+                        r#type: "file",
+                        id: IMPORT_PLAN_INPUT_ID,
+                        accept: ".json",
+                        onchange: on_file_change,
+                    }
+                }
+            }
+        }
+    }
+}
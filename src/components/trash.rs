@@ -0,0 +1,125 @@
+use crate::components::{ActiveTab, BottomNav, HoldDeleteButton};
+use crate::datetime;
+use crate::models::{get_current_timestamp, TrashEntry, TrashedItem, TRASH_RETENTION_DAYS};
+use crate::services::storage;
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+/// Short description of a trashed item, e.g. "📅 03/28 · 🏋️ 5 exercises".
+fn entry_label(item: &TrashedItem, lang: &str) -> String {
+    match item {
+        TrashedItem::Session(session) => {
+            let exercises: std::collections::HashSet<&str> = session
+                .exercise_logs
+                .iter()
+                .map(|log| log.exercise_id.as_str())
+                .collect();
+            format!(
+                "📅 {} · {}",
+                datetime::format_short_date(session.start_time, lang),
+                t!("session-stats-exercises", count: exercises.len().to_string())
+            )
+        }
+        TrashedItem::Exercise(exercise) => format!("📋 {}", exercise.name_for_lang(lang)),
+    }
+}
+/// Deleted sessions and custom exercises, restorable for
+/// [`TRASH_RETENTION_DAYS`] days after their deletion.
+#[component]
+pub fn Trash() -> Element {
+    let mut entries = use_signal(|| None::<Vec<TrashEntry>>);
+    let mut toast = use_context::<ToastSignal>().0;
+    let lang = use_memo(move || i18n().language().to_string());
+    use_hook(|| {
+        spawn(async move {
+            match storage::load_trash().await {
+                Ok(list) => entries.set(Some(list)),
+                Err(e) => {
+                    log::error!("Failed to load the trash: {e}");
+                    toast
+                        .write()
+                        .push_back(format!("⚠️ Failed to load the trash: {e}"));
+                    entries.set(Some(Vec::new()));
+                }
+            }
+        });
+    });
+    let mut forget = move |id: &str| {
+        if let Some(list) = entries.write().as_mut() {
+            list.retain(|e| e.id != id);
+        }
+    };
+    let restore = move |id: String| {
+        spawn(async move {
+            match storage::restore_from_trash(&id).await {
+                Ok(restored) => {
+                    if let Some(item) = restored {
+                        storage::apply_restored_item(item);
+                        toast.write().push_back(t!("trash-restored").to_string());
+                    }
+                    forget(&id);
+                }
+                Err(e) => {
+                    log::error!("Failed to restore {id}: {e}");
+                    toast
+                        .write()
+                        .push_back(format!("⚠️ Failed to restore: {e}"));
+                }
+            }
+        });
+    };
+    let delete = move |id: String| {
+        spawn(async move {
+            match storage::delete_from_trash(&id).await {
+                Ok(()) => forget(&id),
+                Err(e) => {
+                    log::error!("Failed to delete {id} from the trash: {e}");
+                    toast.write().push_back(format!("⚠️ Failed to delete: {e}"));
+                }
+            }
+        });
+    };
+    let now = get_current_timestamp();
+    rsx! {
+        Stylesheet { href: asset!("/assets/more.scss") }
+        header {
+            h1 { {t!("trash-title")} }
+        }
+        main { class: "more",
+            p { {t!("trash-description", days: TRASH_RETENTION_DAYS)} }
+            match entries.read().as_deref() {
+                None => rsx! {},
+                Some([]) => rsx! {
+                    p { {t!("trash-empty")} }
+                },
+                Some(list) => rsx! {
+                    for entry in list.iter() {
+                        article { key: "{entry.id}",
+                            h2 { "{entry_label(&entry.item, &lang.read())}" }
+                            p { {t!("trash-days-left", count: entry.days_left(now).to_string())} }
+                            div { class: "inputs",
+                                button {
+                                    class: "label save",
+                                    onclick: {
+                                        let id = entry.id.clone();
+                                        move |_| restore(id.clone())
+                                    },
+                                    {t!("trash-restore-btn")}
+                                }
+                                HoldDeleteButton {
+                                    title: t!("trash-delete-title").to_string(),
+                                    on_delete: {
+                                        let id = entry.id.clone();
+                                        move |()| delete(id.clone())
+                                    },
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+        BottomNav { active_tab: ActiveTab::More }
+    }
+}
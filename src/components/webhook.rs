@@ -0,0 +1,88 @@
+use crate::models::WorkoutSession;
+use crate::services::webhook::{self, WebhookPayload};
+use crate::ToastSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+/// Call the configured webhook, if any, with the summary of `finished`.
+/// Failures are reported in a toast.
+pub(crate) fn notify_after_session(finished: &WorkoutSession) {
+    let Some(url) = webhook::configured_url() else {
+        return;
+    };
+    let payload = WebhookPayload::session_finished(finished);
+    let mut toast = consume_context::<ToastSignal>().0;
+    // Outlive the finished session's view, which unmounts right away.
+    dioxus::core::spawn_forever(async move {
+        if let Err(e) = webhook::notify(&url, &payload).await {
+            log::error!("Webhook call failed: {e}");
+            toast
+                .write()
+                .push_back(t!("webhook-failed", error: e.to_string()));
+        }
+    });
+}
+/// Settings card for the webhook called when a session finishes (shown on
+/// the More page).
+#[component]
+pub fn WebhookSettings() -> Element {
+    let mut url_input = use_signal(|| webhook::configured_url().unwrap_or_default());
+    let mut busy = use_signal(|| false);
+    let mut toast = use_context::<ToastSignal>().0;
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        let url = url_input.read().trim().to_owned();
+        url_input.set(url.clone());
+        crate::utils::set_config(webhook::WEBHOOK_URL_KEY, &url);
+        if !url.is_empty() {
+            toast.write().push_back(t!("webhook-saved"));
+        }
+    };
+    let send_test = move |_| {
+        let url = url_input.peek().trim().to_owned();
+        let sample = WorkoutSession {
+            id: "test".into(),
+            end_time: Some(crate::models::get_current_timestamp()),
+            ..WorkoutSession::new()
+        };
+        busy.set(true);
+        spawn(async move {
+            match webhook::notify(&url, &WebhookPayload::session_finished(&sample)).await {
+                Ok(()) => toast.write().push_back(t!("webhook-test-sent")),
+                Err(e) => toast
+                    .write()
+                    .push_back(t!("webhook-failed", error: e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+    rsx! {
+        article {
+            h2 { {t!("webhook-section")} }
+            p { {t!("webhook-desc")} }
+            form { onsubmit: on_submit,
+                input {
+                    r#type: "url",
+                    value: "{url_input}",
+                    placeholder: "http://homeassistant.local:8123/api/webhook/…",
+                    oninput: move |evt| url_input.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "icon save",
+                    aria_label: t!("webhook-save-aria"),
+                    "💾"
+                }
+            }
+            if !url_input.read().trim().is_empty() {
+                div { class: "inputs",
+                    button {
+                        class: "label more",
+                        disabled: busy(),
+                        onclick: send_test,
+                        {t!("webhook-test-btn")}
+                    }
+                }
+            }
+        }
+    }
+}
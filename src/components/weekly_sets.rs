@@ -0,0 +1,134 @@
+use crate::components::exercise_card::translate_enum;
+use crate::models::Muscle;
+use crate::services::{storage, weekly_sets};
+use crate::DbI18nSignal;
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+use strum::IntoEnumIterator;
+/// Hard sets done this week against the target of every muscle that has
+/// one, shown on the home page.  Renders nothing without any target.
+#[component]
+pub fn WeeklySetCounter() -> Element {
+    let targets = storage::use_muscle_set_targets();
+    let done = weekly_sets::use_weekly_hard_sets();
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    let lang = use_memo(move || i18n().language().to_string());
+    let rows: Vec<(String, u32, u32)> = targets
+        .read()
+        .iter()
+        .map(|(muscle, &target)| {
+            let name = translate_enum(&db_i18n.read(), &lang.read(), "muscles", muscle.as_ref())
+                .to_owned();
+            (name, done.read().get(muscle).copied().unwrap_or(0), target)
+        })
+        .collect();
+    if rows.is_empty() {
+        return rsx! {};
+    }
+    rsx! {
+        div { class: "weekly-sets",
+            label { title: t!("weekly-sets-desc"), {t!("weekly-sets-title")} }
+            ul {
+                for (name, sets, target) in rows {
+                    li { key: "{name}", class: if sets >= target { "met" } else { "" },
+                        span { "{name}" }
+                        progress { max: "{target}", value: "{sets.min(target)}" }
+                        span { "{sets} / {target}" }
+                    }
+                }
+            }
+        }
+    }
+}
+/// Settings card of the weekly hard-set targets (shown on the More page).
+#[component]
+pub fn MuscleTargetsSettings() -> Element {
+    let targets = storage::use_muscle_set_targets();
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    let lang = use_memo(move || i18n().language().to_string());
+    let mut muscle_input = use_signal(|| None::<Muscle>);
+    let mut sets_input = use_signal(String::new);
+    let label = move |muscle: Muscle| {
+        translate_enum(&db_i18n.read(), &lang.read(), "muscles", muscle.as_ref()).to_owned()
+    };
+    let sets_invalid = {
+        let input = sets_input.read();
+        !input.trim().is_empty() && input.trim().parse::<u32>().map_or(true, |n| n == 0)
+    };
+    let set_target = move |evt: FormEvent| {
+        evt.prevent_default();
+        let Some(muscle) = *muscle_input.peek() else {
+            return;
+        };
+        let Some(sets) = sets_input.peek().trim().parse().ok().filter(|&n| n > 0) else {
+            return;
+        };
+        let mut next = targets.peek().clone();
+        next.insert(muscle, sets);
+        storage::save_muscle_set_targets(next);
+        muscle_input.set(None);
+        sets_input.set(String::new());
+    };
+    let remove = move |muscle: Muscle| {
+        let mut next = targets.peek().clone();
+        next.remove(&muscle);
+        storage::save_muscle_set_targets(next);
+    };
+    let rows: Vec<(Muscle, String, u32)> = targets
+        .read()
+        .iter()
+        .map(|(&muscle, &sets)| (muscle, label(muscle), sets))
+        .collect();
+    rsx! {
+        article {
+            h2 { {t!("weekly-sets-section")} }
+            p { {t!("weekly-sets-desc")} }
+            ul {
+                for (muscle, name, sets) in rows {
+                    li { key: "{name}", class: "inputs",
+                        span { {t!("weekly-sets-target", muscle: name.clone(), sets: sets)} }
+                        button {
+                            class: "del",
+                            title: t!("weekly-sets-remove"),
+                            onclick: move |_| remove(muscle),
+                            "🗑️"
+                        }
+                    }
+                }
+            }
+            form { class: "inputs", onsubmit: set_target,
+                select {
+                    aria_label: t!("weekly-sets-muscle-label"),
+                    oninput: move |evt| {
+                        muscle_input.set(Muscle::iter().find(|m| m.as_ref() == evt.value()));
+                    },
+                    option { value: "", selected: muscle_input.read().is_none(), "--" }
+                    for muscle in Muscle::iter() {
+                        option {
+                            value: "{muscle.as_ref()}",
+                            selected: *muscle_input.read() == Some(muscle),
+                            {label(muscle)}
+                        }
+                    }
+                }
+                input {
+                    r#type: "number",
+                    inputmode: "numeric",
+                    placeholder: t!("weekly-sets-placeholder"),
+                    aria_label: t!("weekly-sets-sets-label"),
+                    value: "{sets_input}",
+                    oninput: move |evt| sets_input.set(evt.value()),
+                    class: if sets_invalid { "invalid" } else { "" },
+                }
+                button {
+                    r#type: "submit",
+                    class: "more",
+                    disabled: sets_invalid || muscle_input.read().is_none(),
+                    title: t!("weekly-sets-add"),
+                    "🎯"
+                }
+            }
+        }
+    }
+}
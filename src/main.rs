@@ -8,14 +8,15 @@
 use dioxus::prelude::*;
 use dioxus_i18n::prelude::*;
 use dioxus_i18n::t;
+use logout_core::{datetime, models};
 use unic_langid::langid;
 mod components;
-mod models;
 mod services;
-/// Pure utility helpers (date formatting, URL resolution, timestamp helpers).
+/// Pure utility helpers (URL resolution, deep links, persisted settings).
 pub mod utils;
 use components::{
-    AddExercise, Analytics, EditExercise, Exercises, GlobalSessionHeader, Home, More,
+    Achievements, AddExercise, Analytics, Calendar, ConfirmDialog, EditExercise, ErrorScreen,
+    Exercises, GlobalSessionHeader, Home, More, StorageUsage, Trash,
 };
 /// Global context signal for the congratulations toast shown after completing a session.
 #[derive(Clone, Copy)]
@@ -26,6 +27,19 @@ pub struct CongratulationsSignal(pub Signal<bool>);
 /// not immediately overwritten but displayed in turn.
 #[derive(Clone, Copy)]
 pub struct ToastSignal(pub Signal<std::collections::VecDeque<String>>);
+/// Global context signal holding the latest message for screen readers,
+/// rendered in a visually hidden live region.  Use
+/// [`services::a11y::announce`] to speak a message.
+#[derive(Clone, Copy)]
+pub struct AnnouncementSignal(pub Signal<String>);
+/// Global context signal holding the question shown in the confirmation
+/// dialog, if any.  Use [`components::confirm`] to ask and await the answer.
+#[derive(Clone, Copy)]
+pub struct ConfirmSignal(pub Signal<Option<components::ConfirmRequest>>);
+/// Global context signal holding whether the browser granted persistent
+/// storage (`None` until asked).  The app data may be evicted otherwise.
+#[derive(Clone, Copy)]
+pub struct PersistentStorageSignal(pub Signal<Option<bool>>);
 /// Global context signal that, when `true`, shows a persistent notification-
 /// permission warning toast.  The toast prompts the user to click it in order
 /// to trigger the browser permission dialog.
@@ -66,12 +80,25 @@ pub struct DbEmptyToastSignal(pub Signal<bool>);
 /// `None` when idle; `Some((downloaded, total))` while downloading images.
 #[derive(Clone, Copy)]
 pub struct ImageDownloadProgressSignal(pub Signal<Option<(usize, usize)>>);
+/// Global context signal holding the state of the download of the exercise
+/// database: its progress, or its failure after every retry.
+#[derive(Clone, Copy)]
+pub struct ExerciseDbDownloadSignal(pub Signal<services::exercise_db::DbDownload>);
 /// Global context signal that is `true` while the Android keyguard (lock screen)
 /// is active **and** the app is being shown over it (i.e. there is or was an
 /// active session).  While this is `true`, all writes except those targeting the
 /// currently active session are refused.
 #[derive(Clone, Copy)]
 pub struct ScreenLockedSignal(pub Signal<bool>);
+/// Global context signal mirroring the "keep screen awake during sessions"
+/// preference, so toggling it in [`More`] releases or re-acquires the wake
+/// lock immediately.
+#[derive(Clone, Copy)]
+pub struct KeepAwakeSignal(pub Signal<bool>);
+/// Global context signal mirroring the flow mode preference (see
+/// [`services::flow`]), read by the session view.
+#[derive(Clone, Copy)]
+pub struct FlowModeSignal(pub Signal<bool>);
 /// Holds the raw URL query string captured in [`App`] **before** the Dioxus
 /// [`Router`] calls `history.replaceState` during its initialisation, which
 /// strips the query parameters from `window.location`.  Deep-link parameters
@@ -95,6 +122,25 @@ enum Route {
     AddExercise {},
     #[route("/edit-exercise/:id")]
     EditExercise { id: String },
+    #[route("/trash")]
+    Trash {},
+    #[route("/achievements")]
+    Achievements {},
+    #[route("/calendar")]
+    Calendar {},
+    #[route("/storage")]
+    StorageUsage {},
+}
+/// Config key holding the language chosen in settings (absent: follow the system).
+pub(crate) const LANGUAGE_KEY: &str = "language";
+/// Locales shipped with the app, as (tag, native name) pairs.
+pub(crate) const SUPPORTED_LANGUAGES: &[(&str, &str)] =
+    &[("en", "English"), ("fr", "Français"), ("es", "Español")];
+/// Language to start in: the one chosen in settings, else the detected one.
+fn initial_language() -> unic_langid::LanguageIdentifier {
+    utils::get_config(LANGUAGE_KEY)
+        .and_then(|tag| tag.parse().ok())
+        .unwrap_or_else(detect_preferred_language)
 }
 /// Detects the user's preferred language from the browser/system, returning a
 /// `LanguageIdentifier`.  Falls back to English (`"en"`) when the language
@@ -102,7 +148,7 @@ enum Route {
 ///
 /// Parse failures are logged at `warn` level so they are visible in diagnostics
 /// without crashing the application.
-fn detect_preferred_language() -> unic_langid::LanguageIdentifier {
+pub(crate) fn detect_preferred_language() -> unic_langid::LanguageIdentifier {
     #[cfg(target_arch = "wasm32")]
     if let Some(lang_str) = web_sys::window().and_then(|w| w.navigator().language()) {
         match lang_str.parse() {
@@ -133,13 +179,17 @@ fn detect_preferred_language() -> unic_langid::LanguageIdentifier {
 }
 /// Main entry point of the `LogOut` application.
 ///
-/// Initializes logging, sets up platform-specific notification channels (Android),
+/// Initializes logging (with the diagnostics log capture), sets up platform-specific notification channels (Android),
 /// registers the service worker (PWA), and launches the Dioxus UI application.
 fn main() {
     dioxus_logger::init(dioxus_logger::tracing::Level::DEBUG).expect("failed to init logger");
+    services::diagnostics::init_log_capture();
     services::notifications::setup_notification_channel();
+    #[cfg(not(target_arch = "wasm32"))]
+    services::demo::request_from_args();
     services::service_worker::register_service_worker();
-    services::wake_lock::enable_wake_lock();
+    #[cfg(target_os = "android")]
+    services::wake_lock::acquire_download_wake_lock();
     // On mobile (Android) the Dioxus WebView runs under the `https://dioxus.index.html/`
     // origin.  Android's WebView security policy blocks loading `file://` resources from
     // that origin, so we register a custom `imgcache://` protocol that serves locally-
@@ -157,36 +207,62 @@ fn main() {
                         services::imgcache::handle_imgcache_request(request)
                     }),
             )
-            .launch(App);
+            .launch(Root);
     }
     #[cfg(not(feature = "mobile-platform"))]
-    launch(App);
+    launch(Root);
 }
 /// Default rest time in seconds offered to the user in the rest input form.
 const DEFAULT_REST_SECONDS: u64 = 30;
 
+/// Top-level component: sets up i18n and gates the app behind the optional
+/// passcode lock, so storage is only loaded once unlocked.
 #[component]
-fn App() -> Element {
+fn Root() -> Element {
     use_init_i18n(|| {
-        let preferred_lang = detect_preferred_language();
+        let preferred_lang = initial_language();
         I18nConfig::new(preferred_lang)
             .with_locale((langid!("en"), include_str!("../assets/en.ftl")))
             .with_locale((langid!("fr"), include_str!("../assets/fr.ftl")))
             .with_locale((langid!("es"), include_str!("../assets/es.ftl")))
             .with_fallback(langid!("en"))
     });
+    let mut unlocked = use_signal(services::app_lock::is_unlocked);
+    if *unlocked.read() {
+        rsx! {
+            App {}
+        }
+    } else {
+        rsx! {
+            components::LockScreen { on_unlock: move |()| unlocked.set(true) }
+        }
+    }
+}
+#[component]
+fn App() -> Element {
     // Provide all contexts before any service that may consume them.
     use_context_provider(|| DbI18nSignal(Signal::new(models::DbI18n::default())));
     use_context_provider(|| CongratulationsSignal(Signal::new(false)));
     use_context_provider(|| ToastSignal(Signal::new(std::collections::VecDeque::new())));
+    use_context_provider(|| ConfirmSignal(Signal::new(None)));
+    use_context_provider(|| AnnouncementSignal(Signal::new(String::new())));
+    let mut persistent = use_context_provider(|| PersistentStorageSignal(Signal::new(None))).0;
+    use_future(move || async move {
+        persistent.set(Some(services::storage_usage::request_persistence().await));
+    });
     use_context_provider(|| NotificationPermissionToastSignal(Signal::new(false)));
     use_context_provider(|| DbEmptyToastSignal(Signal::new(false)));
     use_context_provider(|| ImageDownloadProgressSignal(Signal::new(None)));
+    use_context_provider(|| ExerciseDbDownloadSignal(Signal::new(Default::default())));
     use_context_provider(|| ExerciseSearchSignal(Signal::new(None)));
     use_context_provider(|| PendingDeepLinkSignal(Signal::new(None)));
     use_context_provider(|| ShowRestInputSignal(Signal::new(false)));
     use_context_provider(|| RestDurationSignal(Signal::new(DEFAULT_REST_SECONDS)));
     use_context_provider(|| ScreenLockedSignal(Signal::new(false)));
+    use_context_provider(|| {
+        KeepAwakeSignal(Signal::new(services::wake_lock::keep_awake_enabled()))
+    });
+    use_context_provider(|| FlowModeSignal(Signal::new(services::flow::flow_mode_enabled())));
     // Capture the URL query string now, before the Router's WebHistory::new()
     // calls history.replaceState() and strips it from window.location.
     #[cfg(target_arch = "wasm32")]
@@ -200,22 +276,29 @@ fn App() -> Element {
     #[cfg(not(target_arch = "wasm32"))]
     use_context_provider(|| InitialQuerySignal(Signal::new(String::new())));
 
+    services::clock::provide_clock();
+
     // Services that consume contexts (must run after context providers above).
     services::storage::provide_app_state();
+    services::shortcuts::use_keyboard_shortcuts();
+    {
+        let display = services::storage::use_display_preferences();
+        use_effect(move || services::display::apply(&display.read()));
+    }
 
-    // On Android: show the app over the lock screen and keep the screen on
-    // while a session is active, so the user can leave the phone on the bench
-    // at the gym.  The effect fires whenever the sessions signal changes (start
-    // or end of a session) and enables/disables the lock-screen wake lock.
-    #[cfg(target_os = "android")]
+    // Keep the screen on while a session is active (unless disabled in
+    // settings), so the user can leave the phone on the bench at the gym.  On
+    // Android the app is also shown over the lock screen.  The effect fires
+    // whenever a session starts or ends, or the preference changes.
     {
         let sessions = services::storage::use_sessions();
+        let keep_awake = consume_context::<KeepAwakeSignal>().0;
         use_effect(move || {
             let has_active = sessions
                 .read()
                 .iter()
                 .any(models::WorkoutSession::is_active);
-            services::wake_lock::set_active_session_lock_screen(has_active);
+            services::wake_lock::set_session_wake_lock(has_active && *keep_awake.read());
         });
     }
 
@@ -245,8 +328,17 @@ fn App() -> Element {
     #[cfg(target_arch = "wasm32")]
     use_hook(|| {
         services::storage::idb_queue::register_pagehide_flush();
+        services::autosave::register_flush_on_hide();
+        services::storage::idb_queue::register_journal_replay(
+            consume_context::<ToastSignal>().0,
+            services::storage::use_sessions(),
+            dioxus_i18n::prelude::i18n(),
+        );
     });
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+    use_hook(services::local_api::start_if_enabled);
     services::exercise_db::provide_exercises();
+    services::image_prefetch::use_offline_images();
     #[cfg(any(target_arch = "wasm32", target_os = "android"))]
     {
         let mut notif_toast = use_context::<NotificationPermissionToastSignal>().0;
@@ -278,12 +370,25 @@ fn App() -> Element {
         }
         document::Link { href: asset!("/assets/manifest.json"), rel: "manifest" }
         Stylesheet { href: asset!("/assets/style.scss") }
-        Router::<Route> {}
+        ErrorBoundary {
+            handle_error: |errors: ErrorContext| {
+                let message = errors.error().map(|e| e.to_string()).unwrap_or_default();
+                rsx! {
+                    ErrorScreen { message, on_retry: move |()| errors.clear_errors() }
+                }
+            },
+            Router::<Route> {}
+        }
         CongratulationsToast {}
         Toast {}
+        LiveAnnouncer {}
+        ConfirmDialog {}
         NotificationPermissionToast {}
         DbEmptyToast {}
         ImageDownloadProgressToast {}
+        UpdateAvailableToast {}
+        InstallBanner {}
+        PersistenceWarningBanner {}
     }
 }
 /// Layout component rendered inside the Router context for all routes.
@@ -316,6 +421,7 @@ fn DeepLinkLayout() -> Element {
             let Some(action) = utils::parse_web_deep_link_query(query) else {
                 return;
             };
+            services::exercise_db::request_exercise_hydration();
             match action {
                 DeepLinkAction::Navigate(path) => {
                     let route = path_to_route(&path);
@@ -338,14 +444,21 @@ fn DeepLinkLayout() -> Element {
                     }
                     services::exercise_db::clear_fetch_cache();
                     let toast = consume_context::<ToastSignal>().0;
+                    let download = consume_context::<ExerciseDbDownloadSignal>().0;
                     #[cfg(not(target_arch = "wasm32"))]
                     let img_progress = consume_context::<ImageDownloadProgressSignal>().0;
                     spawn(async move {
                         #[cfg(target_arch = "wasm32")]
-                        services::exercise_db::reload_exercises(exercises_sig, toast).await;
-                        #[cfg(not(target_arch = "wasm32"))]
-                        services::exercise_db::reload_exercises(exercises_sig, toast, img_progress)
+                        services::exercise_db::reload_exercises(exercises_sig, toast, download)
                             .await;
+                        #[cfg(not(target_arch = "wasm32"))]
+                        services::exercise_db::reload_exercises(
+                            exercises_sig,
+                            toast,
+                            download,
+                            img_progress,
+                        )
+                        .await;
                     });
                 }
                 DeepLinkAction::StartSession(exercise_ids) => {
@@ -354,6 +467,11 @@ fn DeepLinkLayout() -> Element {
                 action @ DeepLinkAction::CreateSession(_) => {
                     pending.set(Some(action));
                 }
+                DeepLinkAction::ImportSession(code) => {
+                    if components::share_session::import_shared_session(&code) {
+                        nav.push(Route::Home {});
+                    }
+                }
             }
         });
         use_effect(move || {
@@ -379,7 +497,7 @@ fn DeepLinkLayout() -> Element {
                         .filter(|id| known_ids.contains(id.as_str()))
                         .collect();
                     let mut session = models::WorkoutSession::new();
-                    session.pending_exercise_ids = valid_ids;
+                    session.pending_exercises = valid_ids.into_iter().map(Into::into).collect();
                     services::storage::save_session(session);
                     nav.push(Route::Home {});
                 }
@@ -387,6 +505,21 @@ fn DeepLinkLayout() -> Element {
             }
         });
     }
+    services::global_hotkey::use_global_hotkey();
+    services::launcher::use_launcher();
+    services::demo::use_demo_request();
+    services::tour::use_tour();
+    // Move the focus to the heading of each page navigated to (not the first
+    // one, which the screen reader reads anyway).
+    let route = use_route::<Route>();
+    let first_route = use_hook(|| std::rc::Rc::new(std::cell::Cell::new(true)));
+    use_effect(use_reactive!(|route| {
+        let _ = route;
+        if first_route.replace(false) {
+            return;
+        }
+        services::a11y::focus_page_heading();
+    }));
     rsx! {
         GlobalSessionHeader {}
         Outlet::<Route> {}
@@ -401,6 +534,10 @@ fn path_to_route(path: &str) -> Route {
         "/analytics" | "analytics" => Route::Analytics {},
         "/credits" | "credits" | "/more" | "more" => Route::More {},
         "/add-exercise" | "add-exercise" => Route::AddExercise {},
+        "/trash" | "trash" => Route::Trash {},
+        "/achievements" | "achievements" => Route::Achievements {},
+        "/calendar" | "calendar" => Route::Calendar {},
+        "/storage" | "storage" => Route::StorageUsage {},
         other => {
             if let Some(id) = other.strip_prefix("/edit-exercise/") {
                 Route::EditExercise { id: id.to_string() }
@@ -430,7 +567,7 @@ fn build_session_from_entries<E>(
 where
     E: AsRef<models::Exercise>,
 {
-    use models::{Category, Distance, ExerciseLog, Force, Weight, WorkoutSession};
+    use models::{Category, Distance, ExerciseLog, Force, SetKind, Weight, WorkoutSession};
     let base_time = models::get_current_timestamp().saturating_sub(utils::SECONDS_IN_HOUR);
     let mut session = WorkoutSession::new();
     session.start_time = base_time;
@@ -474,6 +611,14 @@ where
             weight_hg,
             reps,
             distance_m,
+            gps_track: Vec::new(),
+            hr_samples: Vec::new(),
+            notes: String::new(),
+            side: None,
+            assisted: false,
+            body_weight_hg: None,
+            set_kind: SetKind::Normal,
+            planned_rest_s: None,
         });
     }
     session.end_time = Some(
@@ -507,9 +652,38 @@ fn CongratulationsToast() -> Element {
             });
         }
     });
+    // Summary of the session that was just finished (latest end time).
+    let sessions = services::storage::use_sessions();
+    let exercises = services::exercise_db::use_exercises();
+    let custom_exercises = services::storage::use_custom_exercises();
+    let db_i18n = use_context::<DbI18nSignal>().0;
+    let summary = use_memo(move || {
+        if !*show.read() {
+            return None;
+        }
+        let sessions = sessions.read();
+        let finished = sessions
+            .iter()
+            .filter(|s| s.end_time.is_some())
+            .max_by_key(|s| s.end_time)?;
+        let stats =
+            services::session_stats::compute(finished, &exercises.read(), &custom_exercises.read());
+        let lang = i18n().language().to_string();
+        Some(format!(
+            "⏱️ {} · {}",
+            models::format_time(stats.duration),
+            components::home::session_stats_text(&stats, &lang, &db_i18n.read())
+        ))
+    });
     if *show.read() {
         rsx! {
-            div { class: "snackbar", onclick: move |_| show.set(false), {t!("congratulations")} }
+            div { class: "snackbar", onclick: move |_| show.set(false),
+                {t!("congratulations")}
+                if let Some(summary) = summary() {
+                    br {}
+                    "{summary}"
+                }
+            }
         }
     } else {
         rsx! {}
@@ -542,20 +716,29 @@ fn Toast() -> Element {
             });
         }
     });
-    let guard = toast.read();
-    if let Some(msg) = guard.front() {
-        let msg = msg.clone();
-        rsx! {
-            div {
-                class: "snackbar",
-                onclick: move |_| {
-                    toast.write().pop_front();
-                },
-                "{msg}"
+    let msg = toast.read().front().cloned();
+    // The live region stays in the DOM so that screen readers notice the
+    // messages appearing in it.
+    rsx! {
+        div { role: "status", aria_live: "polite", aria_atomic: "true",
+            if let Some(msg) = msg {
+                div {
+                    class: "snackbar",
+                    onclick: move |_| {
+                        toast.write().pop_front();
+                    },
+                    "{msg}"
+                }
             }
         }
-    } else {
-        rsx! {}
+    }
+}
+/// Visually hidden live region speaking the [`AnnouncementSignal`] message.
+#[component]
+fn LiveAnnouncer() -> Element {
+    let live = use_context::<AnnouncementSignal>().0;
+    rsx! {
+        div { class: "sr-only", role: "status", aria_live: "polite", "{live}" }
     }
 }
 /// Persistent notification-permission warning toast.
@@ -609,18 +792,29 @@ fn NotificationPermissionToast() -> Element {
         }
     }
 }
-/// Persistent toast shown when the exercise database has never been downloaded.
-/// Tapping it triggers a download from the currently configured URL.
+/// Persistent toast shown when the exercise database has never been downloaded,
+/// or when downloading it failed.  Tapping it triggers a download from the
+/// currently configured URL.
 #[component]
 fn DbEmptyToast() -> Element {
     let mut show = use_context::<DbEmptyToastSignal>().0;
     let exercises_sig = services::exercise_db::use_exercises();
     let toast = consume_context::<ToastSignal>().0;
+    let download = use_context::<ExerciseDbDownloadSignal>().0;
     let img_progress = consume_context::<ImageDownloadProgressSignal>().0;
-    // Hide automatically once exercises have been loaded (e.g. after a successful download).
+    let failed = use_memo(move || {
+        matches!(
+            *download.read(),
+            services::exercise_db::DbDownload::Failed(_)
+        )
+    });
+    // Hide automatically once exercises have been loaded (e.g. after a successful download),
+    // and show again to retry when the download fails with none loaded.
     use_effect(move || {
         if !exercises_sig.read().is_empty() {
             show.set(false);
+        } else if failed() {
+            show.set(true);
         }
     });
     if !*show.read() {
@@ -637,12 +831,116 @@ fn DbEmptyToast() -> Element {
                 let p = img_progress;
                 spawn(async move {
                     #[cfg(target_arch = "wasm32")]
-                    services::exercise_db::reload_exercises(sig, t).await;
+                    services::exercise_db::reload_exercises(sig, t, download).await;
                     #[cfg(not(target_arch = "wasm32"))]
-                    services::exercise_db::reload_exercises(sig, t, p).await;
+                    services::exercise_db::reload_exercises(sig, t, download, p).await;
+                });
+            },
+            if failed() {
+                {t!("db-download-failed-toast")}
+            } else {
+                {t!("db-empty-toast")}
+            }
+        }
+    }
+}
+/// Interval between checks for a newly deployed build (web only).
+#[cfg(target_arch = "wasm32")]
+const UPDATE_CHECK_INTERVAL_MS: u32 = 15 * 60 * 1000;
+/// Non-blocking toast shown when a newer build has been deployed.
+/// Tapping it activates the waiting service worker and reloads the app.
+#[component]
+fn UpdateAvailableToast() -> Element {
+    #[allow(unused_mut)]
+    let mut available = use_signal(|| false);
+    #[cfg(target_arch = "wasm32")]
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(UPDATE_CHECK_INTERVAL_MS).await;
+            if services::service_worker::check_for_update().await {
+                available.set(true);
+                break;
+            }
+        }
+    });
+    if !*available.read() {
+        return rsx! {};
+    }
+    rsx! {
+        div {
+            class: "snackbar",
+            onclick: move |_| {
+                available.set(false);
+                services::service_worker::apply_update();
+            },
+            {t!("update-available-toast")}
+        }
+    }
+}
+/// "Install LogOut" banner, shown once the browser allows installing the PWA.
+/// Tapping it opens the native install dialog; ✕ hides it for good.
+#[component]
+fn InstallBanner() -> Element {
+    #[allow(unused_mut)]
+    let mut available = use_signal(|| false);
+    #[cfg(target_arch = "wasm32")]
+    use_future(move || async move {
+        if services::service_worker::wait_for_install_prompt().await {
+            available.set(true);
+        }
+    });
+    if !*available.read() {
+        return rsx! {};
+    }
+    rsx! {
+        div {
+            class: "snackbar install",
+            onclick: move |_| {
+                available.set(false);
+                services::service_worker::prompt_install();
+            },
+            {t!("install-banner")}
+            button {
+                class: "no label",
+                aria_label: t!("install-banner-dismiss-aria"),
+                onclick: move |evt| {
+                    evt.stop_propagation();
+                    available.set(false);
+                    services::service_worker::dismiss_install_prompt();
+                },
+                "✕"
+            }
+        }
+    }
+}
+/// Warning banner shown while the browser has not granted persistent storage,
+/// as it may then evict the workout history.  Tapping it asks again; ✕ hides
+/// it until the next launch.
+#[component]
+fn PersistenceWarningBanner() -> Element {
+    let mut persistent = use_context::<PersistentStorageSignal>().0;
+    let mut dismissed = use_signal(|| false);
+    if *persistent.read() != Some(false) || *dismissed.read() {
+        return rsx! {};
+    }
+    rsx! {
+        div {
+            class: "snackbar install persistence",
+            onclick: move |_| {
+                spawn(async move {
+                    persistent.set(Some(services::storage_usage::request_persistence().await));
                 });
             },
-            {t!("db-empty-toast")}
+            {t!("persistence-banner")}
+            button {
+                class: "no label",
+                aria_label: t!("persistence-banner-dismiss-aria"),
+                onclick: move |evt| {
+                    evt.stop_propagation();
+                    dismissed.set(true);
+                },
+                "✕"
+            }
         }
     }
 }
@@ -679,7 +977,9 @@ mod tests {
                 instructions: vec![],
                 category: Category::Strength,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             },
             Exercise {
                 id: "Barbell_Full_Squat".into(),
@@ -694,7 +994,9 @@ mod tests {
                 instructions: vec![],
                 category: Category::Strength,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             },
             Exercise {
                 id: "Running".into(),
@@ -709,7 +1011,9 @@ mod tests {
                 instructions: vec![],
                 category: Category::Cardio,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             },
         ]
     }
@@ -780,4 +1084,25 @@ mod tests {
         );
         assert_eq!(session.exercise_logs[1].weight_hg, models::Weight(775));
     }
+    #[test]
+    fn supported_languages_parse_and_are_complete() {
+        fn keys(ftl: &str) -> std::collections::BTreeSet<&str> {
+            ftl.lines()
+                .filter_map(|l| l.split_once(" = ").map(|(k, _)| k))
+                .filter(|k| !k.starts_with([' ', '#', '.']))
+                .collect()
+        }
+        for (tag, _) in SUPPORTED_LANGUAGES {
+            assert!(tag.parse::<unic_langid::LanguageIdentifier>().is_ok());
+        }
+        let en = keys(include_str!("../assets/en.ftl"));
+        for (tag, ftl) in [
+            ("fr", include_str!("../assets/fr.ftl")),
+            ("es", include_str!("../assets/es.ftl")),
+        ] {
+            let translated = keys(ftl);
+            let missing: Vec<_> = en.difference(&translated).collect();
+            assert!(missing.is_empty(), "{tag} is missing {missing:?}");
+        }
+    }
 }
@@ -0,0 +1,44 @@
+/// Screen reader support: spoken announcements and focus management.
+///
+/// Announcements are written to the visually hidden live region rendered by
+/// the root component (see [`crate::AnnouncementSignal`]).  Focus moves to
+/// the heading of each page reached and back to the control that opened a
+/// dialog once it closes.
+use dioxus::prelude::*;
+/// Have screen readers read `message` out, without moving the focus.
+pub fn announce(message: &str) {
+    let Some(crate::AnnouncementSignal(mut live)) = try_consume_context() else {
+        return;
+    };
+    // A live region only speaks when its content changes: tell a repeated
+    // message apart with a trailing no-break space.
+    let message = if *live.peek() == message {
+        format!("{message}\u{a0}")
+    } else {
+        message.to_owned()
+    };
+    live.set(message);
+}
+/// Focus the main heading of the page just navigated to, so screen readers
+/// start reading it.
+pub fn focus_page_heading() {
+    document::eval(
+        "requestAnimationFrame(function(){\
+            var h=document.querySelector('main h1, header h1, h1');\
+            if(!h)return;\
+            if(!h.hasAttribute('tabindex'))h.setAttribute('tabindex','-1');\
+            h.focus({preventScroll:true});\
+        });",
+    );
+}
+/// Remember the focused control, to [`restore_focus`] once a dialog closes.
+pub fn remember_focus() {
+    document::eval("window.__logoutReturnFocus=document.activeElement;");
+}
+/// Focus again the control remembered by [`remember_focus`], if still shown.
+pub fn restore_focus() {
+    document::eval(
+        "var el=window.__logoutReturnFocus;window.__logoutReturnFocus=null;\
+         if(el&&el.isConnected&&el.focus)el.focus();",
+    );
+}
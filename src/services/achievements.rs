@@ -0,0 +1,62 @@
+use crate::models::{get_current_timestamp, WorkoutSession};
+use crate::services::storage;
+/// Milestone badges ("100 sessions", "10,000 kg in a week", "12-week
+/// streak"…) for the Achievements page.
+///
+/// The badges and the records they are measured against live in
+/// [`logout_core::achievements`]; they are remembered, with the time they
+/// were unlocked, in the `achievements` setting.
+pub use logout_core::achievements::{
+    newly_earned, Achievement, Milestone, Records, Unlocked, ACHIEVEMENTS,
+};
+/// Settings key of the JSON-encoded map of unlocked badge IDs to the Unix
+/// timestamp (seconds) they were unlocked at.
+const ACHIEVEMENTS_KEY: &str = "achievements";
+/// Completed sessions loaded per storage page when checking badges.
+const PAGE_SIZE: usize = 500;
+/// Badges already unlocked.
+#[must_use]
+pub fn load_unlocked() -> Unlocked {
+    crate::utils::get_config(ACHIEVEMENTS_KEY)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+/// Measure every stored session, plus `just_finished` whose write may still
+/// be queued, unlock and persist the newly earned badges, and return the
+/// records along with those badges.
+pub async fn check(just_finished: Option<WorkoutSession>) -> (Records, Vec<Achievement>) {
+    let mut sessions = Vec::new();
+    let mut offset = 0;
+    loop {
+        match storage::load_completed_sessions_page(PAGE_SIZE, offset, true).await {
+            Ok(page) => {
+                let fetched = page.len();
+                sessions.extend(page);
+                if fetched < PAGE_SIZE {
+                    break;
+                }
+                offset += fetched;
+            }
+            Err(e) => {
+                log::error!("Failed to load sessions for achievements: {e}");
+                break;
+            }
+        }
+    }
+    if let Some(session) = just_finished {
+        sessions.retain(|s| s.id != session.id);
+        sessions.push(session);
+    }
+    let records = Records::measure(&sessions);
+    let mut unlocked = load_unlocked();
+    let earned = newly_earned(&records, &unlocked);
+    if !earned.is_empty() {
+        let now = get_current_timestamp();
+        unlocked.extend(earned.iter().map(|a| (a.id.to_owned(), now)));
+        match serde_json::to_string(&unlocked) {
+            Ok(json) => crate::utils::set_config(ACHIEVEMENTS_KEY, &json),
+            Err(e) => log::warn!("Failed to serialise achievements: {e}"),
+        }
+    }
+    (records, earned)
+}
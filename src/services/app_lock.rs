@@ -0,0 +1,363 @@
+/// Optional passcode lock and at-rest encryption of sensitive session data.
+///
+/// When enabled, a key is derived from the passcode (PBKDF2, random salt) and
+/// held in memory only after a successful [`unlock`].  Free-text notes (of
+/// the session and of each set), GPS tracks, heart-rate samples and the body
+/// weight recorded with each set are then moved out of each stored session
+/// into an encrypted [`WorkoutSession::sealed`] blob.  The body weight and
+/// injury avoid list settings are sealed the same way (see
+/// [`seal_setting`]).
+///
+/// What stays readable without the passcode: session timestamps, location,
+/// tags and archive flag, and for each set the exercise ID and name,
+/// category, start/end times, load, reps, distance, force, side, set kind
+/// and assistance flag, so the storage layer can keep filtering and
+/// aggregating without decrypting history.  Custom exercises, goals, plans,
+/// templates and the other settings are not sealed.  Trash entries and
+/// packed sessions hold the stored (sealed) form.
+///
+/// A known plaintext encrypted with the key (the "verifier") is stored next
+/// to the salt so a wrong passcode is detected without touching any session.
+use crate::models::{HeartRateSample, TrackPoint, Weight, WorkoutSession};
+use crate::services::crypto::{self, SecretKey};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+/// Config key holding the base64 key-derivation salt.
+const LOCK_SALT_KEY: &str = "app_lock_salt";
+/// Config key holding the base64 encrypted verifier.
+const LOCK_VERIFIER_KEY: &str = "app_lock_verifier";
+/// Prefix of a setting value sealed by [`seal_setting`].
+const SEALED_SETTING_PREFIX: &str = "sealed:";
+/// Plaintext sealed into the verifier.
+const VERIFIER_PLAINTEXT: &[u8] = b"logout-app-lock-v1";
+/// Length in bytes of the random salt.
+const SALT_LEN: usize = 16;
+/// Minimum passcode length accepted by [`enable`].
+pub const MIN_PASSCODE_LEN: usize = 4;
+/// Key of the unlocked app, `None` while locked or when the lock is disabled.
+static UNLOCKED_KEY: Mutex<Option<SecretKey>> = Mutex::new(None);
+/// Errors raised by the app lock.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum LockError {
+    /// The passcode does not match the stored verifier.
+    #[error("wrong passcode")]
+    WrongPasscode,
+    /// The passcode is shorter than [`MIN_PASSCODE_LEN`].
+    #[error("passcode too short")]
+    TooShort,
+    /// Key material could not be generated or data could not be sealed.
+    #[error(transparent)]
+    Crypto(#[from] crypto::CryptoError),
+}
+/// Sensitive session fields stored encrypted while the lock is enabled.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SealedFields {
+    notes: String,
+    /// Per exercise-log index: GPS track and heart-rate samples.
+    logs: Vec<(usize, Vec<TrackPoint>, Vec<HeartRateSample>)>,
+    /// Per exercise-log index: notes of the set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    log_notes: Vec<(usize, String)>,
+    /// Per exercise-log index: body weight at the time of the set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    body_weights: Vec<(usize, Weight)>,
+}
+fn current_key() -> Option<SecretKey> {
+    UNLOCKED_KEY
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+fn set_current_key(key: Option<SecretKey>) {
+    *UNLOCKED_KEY
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = key;
+}
+/// Whether a passcode has been configured.
+#[must_use]
+pub fn is_enabled() -> bool {
+    crate::utils::get_config(LOCK_VERIFIER_KEY).is_some()
+}
+/// Whether the app may show data (lock disabled, or unlocked this run).
+#[must_use]
+pub fn is_unlocked() -> bool {
+    !is_enabled() || current_key().is_some()
+}
+/// Check `passcode` against the stored verifier and keep the key in memory.
+///
+/// # Errors
+///
+/// Returns [`LockError::WrongPasscode`] on mismatch or missing configuration.
+pub fn unlock(passcode: &str) -> Result<(), LockError> {
+    let salt = crate::utils::get_config(LOCK_SALT_KEY)
+        .and_then(|s| crypto::from_base64(&s))
+        .ok_or(LockError::WrongPasscode)?;
+    let verifier = crate::utils::get_config(LOCK_VERIFIER_KEY)
+        .and_then(|s| crypto::from_base64(&s))
+        .ok_or(LockError::WrongPasscode)?;
+    let key = crypto::derive_key(passcode, &salt);
+    check_verifier(&key, &verifier)?;
+    set_current_key(Some(key));
+    Ok(())
+}
+fn check_verifier(key: &SecretKey, verifier: &[u8]) -> Result<(), LockError> {
+    match crypto::decrypt(key, verifier) {
+        Ok(plain) if plain == VERIFIER_PLAINTEXT => Ok(()),
+        _ => Err(LockError::WrongPasscode),
+    }
+}
+/// Configure `passcode` and unlock immediately.
+///
+/// Sessions written afterwards are sealed; callers should rewrite existing
+/// history so it gets sealed too.
+///
+/// # Errors
+///
+/// Returns [`LockError::TooShort`] or a crypto error.
+pub fn enable(passcode: &str) -> Result<(), LockError> {
+    if passcode.chars().count() < MIN_PASSCODE_LEN {
+        return Err(LockError::TooShort);
+    }
+    let salt = crypto::random_bytes(SALT_LEN)?;
+    let key = crypto::derive_key(passcode, &salt);
+    let verifier = crypto::encrypt(&key, VERIFIER_PLAINTEXT)?;
+    crate::utils::set_config(LOCK_SALT_KEY, &crypto::to_base64(&salt));
+    crate::utils::set_config(LOCK_VERIFIER_KEY, &crypto::to_base64(&verifier));
+    set_current_key(Some(key));
+    Ok(())
+}
+/// Remove the passcode after checking it.
+///
+/// Sessions written afterwards are stored in clear; callers should rewrite
+/// existing history (loaded while still unlocked) so it gets unsealed.
+///
+/// # Errors
+///
+/// Returns [`LockError::WrongPasscode`] on mismatch.
+pub fn disable(passcode: &str) -> Result<(), LockError> {
+    unlock(passcode)?;
+    crate::utils::set_config(LOCK_SALT_KEY, "");
+    crate::utils::set_config(LOCK_VERIFIER_KEY, "");
+    set_current_key(None);
+    Ok(())
+}
+/// Return the form of `session` to write to storage.
+///
+/// Sensitive fields are sealed when the lock is unlocked; otherwise the
+/// session is returned unchanged.
+#[must_use]
+pub fn seal_for_storage(session: WorkoutSession) -> WorkoutSession {
+    match current_key() {
+        Some(key) => seal_with(&key, session),
+        None => session,
+    }
+}
+/// Restore the sensitive fields of a session read from storage.
+///
+/// Sessions that are not sealed, or that cannot be opened with the current
+/// key, are returned unchanged.
+#[must_use]
+pub fn unseal_from_storage(session: WorkoutSession) -> WorkoutSession {
+    match current_key() {
+        Some(key) if session.sealed.is_some() => unseal_with(&key, session),
+        _ => session,
+    }
+}
+/// Seal the sensitive fields of `session` with `key`.
+#[must_use]
+pub fn seal_with(key: &SecretKey, mut session: WorkoutSession) -> WorkoutSession {
+    let mut fields = SealedFields {
+        notes: std::mem::take(&mut session.notes),
+        logs: Vec::new(),
+        log_notes: Vec::new(),
+        body_weights: Vec::new(),
+    };
+    for (i, log) in session.exercise_logs.iter_mut().enumerate() {
+        if !log.gps_track.is_empty() || !log.hr_samples.is_empty() {
+            fields.logs.push((
+                i,
+                std::mem::take(&mut log.gps_track),
+                std::mem::take(&mut log.hr_samples),
+            ));
+        }
+        if !log.notes.is_empty() {
+            fields.log_notes.push((i, std::mem::take(&mut log.notes)));
+        }
+        if let Some(weight) = log.body_weight_hg.take() {
+            fields.body_weights.push((i, weight));
+        }
+    }
+    if fields.notes.is_empty()
+        && fields.logs.is_empty()
+        && fields.log_notes.is_empty()
+        && fields.body_weights.is_empty()
+    {
+        return session;
+    }
+    let sealed = serde_json::to_vec(&fields)
+        .ok()
+        .and_then(|json| crypto::encrypt(key, &json).ok());
+    match sealed {
+        Some(bytes) => session.sealed = Some(crypto::to_base64(&bytes)),
+        None => {
+            // Never lose data: fall back to storing the fields in clear.
+            log::warn!("Failed to seal session {}", session.id);
+            restore(&mut session, fields);
+        }
+    }
+    session
+}
+/// Open a session sealed by [`seal_with`].
+#[must_use]
+pub fn unseal_with(key: &SecretKey, mut session: WorkoutSession) -> WorkoutSession {
+    let fields = session
+        .sealed
+        .as_deref()
+        .and_then(crypto::from_base64)
+        .and_then(|bytes| crypto::decrypt(key, &bytes).ok())
+        .and_then(|json| serde_json::from_slice::<SealedFields>(&json).ok());
+    match fields {
+        Some(fields) => {
+            session.sealed = None;
+            restore(&mut session, fields);
+        }
+        None => log::warn!("Failed to unseal session {}", session.id),
+    }
+    session
+}
+fn restore(session: &mut WorkoutSession, fields: SealedFields) {
+    session.notes = fields.notes;
+    for (i, track, hr) in fields.logs {
+        if let Some(log) = session.exercise_logs.get_mut(i) {
+            log.gps_track = track;
+            log.hr_samples = hr;
+        }
+    }
+    for (i, notes) in fields.log_notes {
+        if let Some(log) = session.exercise_logs.get_mut(i) {
+            log.notes = notes;
+        }
+    }
+    for (i, weight) in fields.body_weights {
+        if let Some(log) = session.exercise_logs.get_mut(i) {
+            log.body_weight_hg = Some(weight);
+        }
+    }
+}
+/// Return the form of a setting `value` to write to storage: sealed while
+/// the lock is unlocked, unchanged otherwise.
+#[must_use]
+pub fn seal_setting(value: &str) -> String {
+    match current_key() {
+        Some(key) => seal_setting_with(&key, value),
+        None => value.to_owned(),
+    }
+}
+/// Open a setting value read from storage.
+///
+/// Returns values that are not sealed unchanged, and `None` for those that
+/// cannot be opened with the current key.
+#[must_use]
+pub fn open_setting(value: &str) -> Option<String> {
+    match value.strip_prefix(SEALED_SETTING_PREFIX) {
+        Some(_) => open_setting_with(&current_key()?, value),
+        None => Some(value.to_owned()),
+    }
+}
+fn seal_setting_with(key: &SecretKey, value: &str) -> String {
+    match crypto::encrypt(key, value.as_bytes()) {
+        Ok(bytes) => format!("{SEALED_SETTING_PREFIX}{}", crypto::to_base64(&bytes)),
+        Err(e) => {
+            // Never lose data: fall back to storing the value in clear.
+            log::warn!("Failed to seal a setting: {e}");
+            value.to_owned()
+        }
+    }
+}
+fn open_setting_with(key: &SecretKey, value: &str) -> Option<String> {
+    let bytes = crypto::from_base64(value.strip_prefix(SEALED_SETTING_PREFIX)?)?;
+    String::from_utf8(crypto::decrypt(key, &bytes).ok()?).ok()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, Distance, ExerciseLog, Weight};
+    fn key(pass: &str) -> SecretKey {
+        crypto::derive_key_with_iterations(pass, b"salt", 1_000)
+    }
+    fn sensitive_session() -> WorkoutSession {
+        let mut s = WorkoutSession::new();
+        s.notes = "knee pain".into();
+        let mut log = ExerciseLog {
+            exercise_id: "run".into(),
+            exercise_name: "Run".into(),
+            category: Category::Cardio,
+            start_time: 1,
+            end_time: Some(60),
+            distance_m: Some(Distance(1000)),
+            notes: "left hamstring".into(),
+            body_weight_hg: Some(Weight(815)),
+            ..Default::default()
+        };
+        log.gps_track.push(TrackPoint {
+            time: 1,
+            lat: 48.8,
+            lon: 2.3,
+            ele: None,
+        });
+        s.exercise_logs.push(log);
+        s
+    }
+    #[test]
+    fn seal_hides_sensitive_fields() {
+        let sealed = seal_with(&key("1234"), sensitive_session());
+        assert!(sealed.notes.is_empty());
+        assert!(sealed.exercise_logs[0].gps_track.is_empty());
+        assert_eq!(sealed.exercise_logs[0].body_weight_hg, None);
+        let json = serde_json::to_string(&sealed).unwrap();
+        assert!(!json.contains("knee"));
+        assert!(!json.contains("hamstring"));
+        assert!(json.contains("\"exercise_id\":\"run\""));
+    }
+    #[test]
+    fn seal_round_trip() {
+        let original = sensitive_session();
+        let restored = unseal_with(&key("1234"), seal_with(&key("1234"), original.clone()));
+        assert_eq!(restored, original);
+    }
+    #[test]
+    fn wrong_key_keeps_session_sealed() {
+        let sealed = seal_with(&key("1234"), sensitive_session());
+        let opened = unseal_with(&key("0000"), sealed.clone());
+        assert_eq!(opened, sealed);
+    }
+    #[test]
+    fn nothing_to_seal_is_left_unchanged() {
+        let s = WorkoutSession::new();
+        assert_eq!(seal_with(&key("1234"), s.clone()), s);
+    }
+    #[test]
+    fn sealed_setting_round_trip() {
+        let sealed = seal_setting_with(&key("1234"), "{\"hg\":815}");
+        assert!(sealed.starts_with(SEALED_SETTING_PREFIX));
+        assert!(!sealed.contains('{'));
+        assert_eq!(
+            open_setting_with(&key("1234"), &sealed).as_deref(),
+            Some("{\"hg\":815}")
+        );
+        assert_eq!(open_setting_with(&key("0000"), &sealed), None);
+        assert_eq!(
+            open_setting("{\"hg\":815}").as_deref(),
+            Some("{\"hg\":815}")
+        );
+    }
+    #[test]
+    fn verifier_detects_wrong_passcode() {
+        let verifier = crypto::encrypt(&key("1234"), VERIFIER_PLAINTEXT).unwrap();
+        assert!(check_verifier(&key("1234"), &verifier).is_ok());
+        assert_eq!(
+            check_verifier(&key("4321"), &verifier),
+            Err(LockError::WrongPasscode)
+        );
+    }
+}
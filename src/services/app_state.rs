@@ -5,11 +5,44 @@
 //! [`storage`](super::storage) module; this module just wires the Dioxus
 //! reactive primitives to those backends.
 use crate::models::{
-    get_current_timestamp, Distance, Exercise, ExerciseLog, Weight, WorkoutSession,
+    get_current_timestamp, AvoidList, Category, CustomTag, CustomTaxonomy, Distance,
+    EquipmentProfiles, Exercise, ExerciseGoal, ExerciseLog, ExerciseOverride, ExerciseOverrides,
+    Muscle, PlannedExercise, ScheduledWorkout, SessionTemplate, TrainingPlan, Weight,
+    WorkoutSession,
 };
+use crate::services::display::DisplayPreferences;
 use crate::ToastSignal;
 use dioxus::prelude::*;
+use std::collections::BTreeMap;
 use std::sync::Arc;
+/// Settings key of the JSON-encoded [`ExerciseOverrides`].
+const EXERCISE_OVERRIDES_KEY: &str = "exercise_overrides";
+/// Settings key of the JSON-encoded [`EquipmentProfiles`].
+const EQUIPMENT_PROFILES_KEY: &str = "equipment_profiles";
+/// Settings key of the JSON-encoded list of saved session locations.
+const LOCATIONS_KEY: &str = "locations";
+/// Settings key of the JSON-encoded injury-mode [`AvoidList`].
+const AVOID_LIST_KEY: &str = "avoid_list";
+/// Settings key of the JSON-encoded [`CustomTaxonomy`].
+const CUSTOM_TAXONOMY_KEY: &str = "custom_taxonomy";
+/// Settings key of the JSON-encoded body weight entered by the user.
+const BODY_WEIGHT_KEY: &str = "body_weight";
+/// Settings key of the JSON-encoded list of [`ExerciseGoal`]s.
+const GOALS_KEY: &str = "exercise_goals";
+/// Settings key of the JSON-encoded weekly hard-set target per muscle.
+const MUSCLE_SET_TARGETS_KEY: &str = "muscle_set_targets";
+/// Settings key of the JSON-encoded imported [`TrainingPlan`].
+const TRAINING_PLAN_KEY: &str = "training_plan";
+/// Settings key of the JSON-encoded list of [`SessionTemplate`]s.
+const TEMPLATES_KEY: &str = "session_templates";
+/// Settings key of the JSON-encoded list of [`ScheduledWorkout`]s.
+const SCHEDULE_KEY: &str = "training_schedule";
+/// Settings key of the JSON-encoded [`DisplayPreferences`].
+const DISPLAY_PREFERENCES_KEY: &str = "display_preferences";
+/// Settings key of the JSON-encoded categories after which no rest is timed.
+const REST_SKIP_CATEGORIES_KEY: &str = "rest_skip_categories";
+/// Settings key of the JSON-encoded passphrase cloud backups are sealed with.
+const CLOUD_BACKUP_PASSPHRASE_KEY: &str = "cloud_backup_passphrase";
 
 /// Returns `true` when the screen is currently locked and a write would be
 /// restricted to the active session only.
@@ -33,6 +66,46 @@ pub fn provide_app_state() {
     let sessions_sig = use_context_provider(|| Signal::new(Vec::<WorkoutSession>::new()));
     let custom_sig = use_context_provider(|| Signal::new(Vec::<Arc<Exercise>>::new()));
     let cache_sig = use_context_provider(|| Signal::new(BestsCache::new()));
+    use_context_provider(|| {
+        Signal::new(load_json_config::<ExerciseOverrides>(
+            EXERCISE_OVERRIDES_KEY,
+        ))
+    });
+    use_context_provider(|| {
+        Signal::new(load_json_config::<EquipmentProfiles>(
+            EQUIPMENT_PROFILES_KEY,
+        ))
+    });
+    use_context_provider(|| LocationsSignal(Signal::new(load_json_config(LOCATIONS_KEY))));
+    use_context_provider(|| Signal::new(load_sealed_json_config::<AvoidList>(AVOID_LIST_KEY)));
+    use_context_provider(|| Signal::new(load_json_config::<CustomTaxonomy>(CUSTOM_TAXONOMY_KEY)));
+    use_context_provider(|| {
+        Signal::new(load_sealed_json_config::<Option<Weight>>(BODY_WEIGHT_KEY))
+    });
+    use_context_provider(|| GoalsSignal(Signal::new(load_json_config(GOALS_KEY))));
+    use_context_provider(|| {
+        Signal::new(load_json_config::<BTreeMap<Muscle, u32>>(
+            MUSCLE_SET_TARGETS_KEY,
+        ))
+    });
+    use_context_provider(|| {
+        Signal::new(load_json_config::<Option<TrainingPlan>>(TRAINING_PLAN_KEY))
+    });
+    use_context_provider(|| TemplatesSignal(Signal::new(load_json_config(TEMPLATES_KEY))));
+    use_context_provider(|| ScheduleSignal(Signal::new(load_json_config(SCHEDULE_KEY))));
+    use_context_provider(|| {
+        Signal::new(load_json_config::<DisplayPreferences>(
+            DISPLAY_PREFERENCES_KEY,
+        ))
+    });
+    use_context_provider(|| {
+        RestSkipSignal(Signal::new(load_json_config(REST_SKIP_CATEGORIES_KEY)))
+    });
+    use_context_provider(|| {
+        CloudBackupPassphraseSignal(Signal::new(load_sealed_json_config(
+            CLOUD_BACKUP_PASSPHRASE_KEY,
+        )))
+    });
     let toast = consume_context::<ToastSignal>().0;
     use_resource(move || load_storage_data(sessions_sig, custom_sig, cache_sig, toast));
 }
@@ -44,6 +117,244 @@ pub fn use_sessions() -> Signal<Vec<WorkoutSession>> {
 pub fn use_custom_exercises() -> Signal<Vec<Arc<Exercise>>> {
     consume_context::<Signal<Vec<Arc<Exercise>>>>()
 }
+/// Obtain the reactive personal exercise overrides from the Dioxus context.
+pub fn use_exercise_overrides() -> Signal<ExerciseOverrides> {
+    consume_context::<Signal<ExerciseOverrides>>()
+}
+/// Read the JSON-encoded setting `key`, falling back to the default value
+/// when absent or unreadable.
+fn load_json_config<T: serde::de::DeserializeOwned + Default>(key: &str) -> T {
+    crate::utils::get_config(key)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+fn store_json_config<T: serde::Serialize>(key: &str, value: &T) {
+    match serde_json::to_string(value) {
+        Ok(json) => crate::utils::set_config(key, &json),
+        Err(e) => log::warn!("Failed to serialise setting {key}: {e}"),
+    }
+}
+/// [`load_json_config`] for a setting sealed while the app lock is enabled.
+fn load_sealed_json_config<T: serde::de::DeserializeOwned + Default>(key: &str) -> T {
+    crate::utils::get_config(key)
+        .and_then(|value| super::app_lock::open_setting(&value))
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+/// [`store_json_config`] for a setting sealed while the app lock is enabled.
+fn store_sealed_json_config<T: serde::Serialize>(key: &str, value: &T) {
+    match serde_json::to_string(value) {
+        Ok(json) => crate::utils::set_config(key, &super::app_lock::seal_setting(&json)),
+        Err(e) => log::warn!("Failed to serialise setting {key}: {e}"),
+    }
+}
+/// Write the sealed settings back to storage, after toggling the app lock,
+/// so they are sealed (or unsealed) with the new setting.
+pub fn rewrite_sealed_settings() {
+    store_sealed_json_config(AVOID_LIST_KEY, &*use_avoid_list().peek());
+    store_sealed_json_config(BODY_WEIGHT_KEY, &*use_body_weight().peek());
+    store_sealed_json_config(
+        CLOUD_BACKUP_PASSPHRASE_KEY,
+        &*use_cloud_backup_passphrase().peek(),
+    );
+}
+/// Replace the personal override of exercise `id` and persist all overrides.
+/// An empty override removes the entry.
+pub fn set_exercise_override(id: &str, over: ExerciseOverride) {
+    let mut sig = use_exercise_overrides();
+    if over.is_empty() {
+        sig.write().remove(id);
+    } else {
+        sig.write().insert(id.to_owned(), over);
+    }
+    store_json_config(EXERCISE_OVERRIDES_KEY, &*sig.peek());
+}
+/// Obtain the reactive custom categories, equipment and muscles from the
+/// Dioxus context.
+pub fn use_custom_taxonomy() -> Signal<CustomTaxonomy> {
+    consume_context::<Signal<CustomTaxonomy>>()
+}
+/// Replace the custom taxonomy and persist it.
+pub fn save_custom_taxonomy(taxonomy: CustomTaxonomy) {
+    store_json_config(CUSTOM_TAXONOMY_KEY, &taxonomy);
+    use_custom_taxonomy().set(taxonomy);
+}
+/// Delete the custom `tag`, also removing it from every exercise.
+pub fn remove_custom_tag(tag: &CustomTag) {
+    let mut taxonomy = use_custom_taxonomy().peek().clone();
+    taxonomy.tags.retain(|t| t != tag);
+    save_custom_taxonomy(taxonomy);
+    let mut sig = use_exercise_overrides();
+    {
+        let mut overrides = sig.write();
+        for over in overrides.values_mut() {
+            over.tags.retain(|t| t != tag);
+        }
+        overrides.retain(|_, o| !o.is_empty());
+    }
+    store_json_config(EXERCISE_OVERRIDES_KEY, &*sig.peek());
+}
+/// Obtain the reactive equipment profiles from the Dioxus context.
+pub fn use_equipment_profiles() -> Signal<EquipmentProfiles> {
+    consume_context::<Signal<EquipmentProfiles>>()
+}
+/// Replace the equipment profiles and persist them.
+pub fn save_equipment_profiles(profiles: EquipmentProfiles) {
+    store_json_config(EQUIPMENT_PROFILES_KEY, &profiles);
+    use_equipment_profiles().set(profiles);
+}
+/// Obtain the reactive injury-mode avoid list from the Dioxus context.
+pub fn use_avoid_list() -> Signal<AvoidList> {
+    consume_context::<Signal<AvoidList>>()
+}
+/// Replace the injury-mode avoid list and persist it.
+pub fn save_avoid_list(list: AvoidList) {
+    store_sealed_json_config(AVOID_LIST_KEY, &list);
+    use_avoid_list().set(list);
+}
+/// Newtype wrapper for the saved session locations (gyms, home…), so its
+/// context slot is distinct from any other `Signal<Vec<String>>`.
+#[derive(Clone, Copy)]
+struct LocationsSignal(Signal<Vec<String>>);
+/// Obtain the reactive list of saved session locations, in the user's order.
+pub fn use_locations() -> Signal<Vec<String>> {
+    consume_context::<LocationsSignal>().0
+}
+/// Replace the saved session locations and persist them.  Sessions keep the
+/// location they were tagged with even when it is removed from this list.
+pub fn save_locations(locations: Vec<String>) {
+    store_json_config(LOCATIONS_KEY, &locations);
+    use_locations().set(locations);
+}
+/// Obtain the reactive body weight entered on the More page (`None` when
+/// unset).
+pub fn use_body_weight() -> Signal<Option<Weight>> {
+    consume_context::<Signal<Option<Weight>>>()
+}
+/// Replace the body weight entered by the user and persist it.
+pub fn save_body_weight(weight: Option<Weight>) {
+    store_sealed_json_config(BODY_WEIGHT_KEY, &weight);
+    use_body_weight().set(weight);
+}
+/// Current body weight entered by the user, if any.
+pub fn current_body_weight() -> Option<Weight> {
+    *use_body_weight().peek()
+}
+/// Newtype wrapper for the exercise goals, so its context slot is distinct
+/// from any other `Signal<Vec<_>>`.
+#[derive(Clone, Copy)]
+struct GoalsSignal(Signal<Vec<ExerciseGoal>>);
+/// Obtain the reactive exercise goals, oldest first.
+pub fn use_goals() -> Signal<Vec<ExerciseGoal>> {
+    consume_context::<GoalsSignal>().0
+}
+/// Replace the exercise goals and persist them.
+pub fn save_goals(goals: Vec<ExerciseGoal>) {
+    store_json_config(GOALS_KEY, &goals);
+    use_goals().set(goals);
+}
+/// Obtain the reactive weekly hard-set targets, per muscle.
+pub fn use_muscle_set_targets() -> Signal<BTreeMap<Muscle, u32>> {
+    consume_context::<Signal<BTreeMap<Muscle, u32>>>()
+}
+/// Replace the weekly hard-set targets and persist them.
+pub fn save_muscle_set_targets(targets: BTreeMap<Muscle, u32>) {
+    store_json_config(MUSCLE_SET_TARGETS_KEY, &targets);
+    use_muscle_set_targets().set(targets);
+}
+/// Obtain the reactive imported training plan, if any.
+pub fn use_training_plan() -> Signal<Option<TrainingPlan>> {
+    consume_context::<Signal<Option<TrainingPlan>>>()
+}
+/// Replace (or remove) the training plan and persist it.
+pub fn save_training_plan(plan: Option<TrainingPlan>) {
+    match &plan {
+        Some(plan) => store_json_config(TRAINING_PLAN_KEY, plan),
+        None => crate::utils::set_config(TRAINING_PLAN_KEY, ""),
+    }
+    use_training_plan().set(plan);
+}
+/// Newtype wrapper for the session templates, so its context slot is
+/// distinct from any other `Signal<Vec<_>>`.
+#[derive(Clone, Copy)]
+struct TemplatesSignal(Signal<Vec<SessionTemplate>>);
+/// Obtain the reactive session templates, in the order saved.
+pub fn use_templates() -> Signal<Vec<SessionTemplate>> {
+    consume_context::<TemplatesSignal>().0
+}
+/// Replace the session templates and persist them.
+pub fn save_templates(templates: Vec<SessionTemplate>) {
+    store_json_config(TEMPLATES_KEY, &templates);
+    use_templates().set(templates);
+}
+/// Newtype wrapper for the workouts of the training calendar, so its context
+/// slot is distinct from any other `Signal<Vec<_>>`.
+#[derive(Clone, Copy)]
+struct ScheduleSignal(Signal<Vec<ScheduledWorkout>>);
+/// Obtain the reactive workouts planned on the training calendar.
+pub fn use_schedule() -> Signal<Vec<ScheduledWorkout>> {
+    consume_context::<ScheduleSignal>().0
+}
+/// Replace the workouts of the training calendar and persist them.
+pub fn save_schedule(schedule: Vec<ScheduledWorkout>) {
+    store_json_config(SCHEDULE_KEY, &schedule);
+    use_schedule().set(schedule);
+}
+/// Obtain the reactive display preferences from the Dioxus context.
+pub fn use_display_preferences() -> Signal<DisplayPreferences> {
+    consume_context::<Signal<DisplayPreferences>>()
+}
+/// Replace the display preferences and persist them.
+pub fn save_display_preferences(prefs: DisplayPreferences) {
+    store_json_config(DISPLAY_PREFERENCES_KEY, &prefs);
+    use_display_preferences().set(prefs);
+}
+/// Newtype wrapper for the categories after which the rest is skipped, so
+/// its context slot is distinct from any other `Signal<Vec<_>>`.
+#[derive(Clone, Copy)]
+struct RestSkipSignal(Signal<Vec<Category>>);
+/// Obtain the reactive categories after which no rest timer is started
+/// (e.g. a long run or a stretch), empty by default.
+pub fn use_rest_skip_categories() -> Signal<Vec<Category>> {
+    consume_context::<RestSkipSignal>().0
+}
+/// Replace the categories after which the rest is skipped and persist them.
+pub fn save_rest_skip_categories(categories: Vec<Category>) {
+    store_json_config(REST_SKIP_CATEGORIES_KEY, &categories);
+    use_rest_skip_categories().set(categories);
+}
+/// Newtype wrapper for the cloud backup passphrase, so its context slot is
+/// distinct from any other `Signal<String>`.
+#[derive(Clone, Copy)]
+struct CloudBackupPassphraseSignal(Signal<String>);
+/// Obtain the reactive passphrase cloud backups are sealed with, empty
+/// until the user sets one.
+pub fn use_cloud_backup_passphrase() -> Signal<String> {
+    consume_context::<CloudBackupPassphraseSignal>().0
+}
+/// Replace the cloud backup passphrase and persist it, sealed while the app
+/// lock is enabled.
+pub fn save_cloud_backup_passphrase(passphrase: String) {
+    store_sealed_json_config(CLOUD_BACKUP_PASSPHRASE_KEY, &passphrase);
+    use_cloud_backup_passphrase().set(passphrase);
+}
+/// Mark the pending goals reached by the completed set `log` as achieved,
+/// persist them and return them (for congratulating the user).
+pub fn mark_goals_met(log: &ExerciseLog) -> Vec<ExerciseGoal> {
+    let mut goals = use_goals().peek().clone();
+    let mut met = Vec::new();
+    for goal in goals
+        .iter_mut()
+        .filter(|g| g.achieved_at.is_none() && g.is_met_by(log))
+    {
+        goal.achieved_at = log.end_time;
+        met.push(goal.clone());
+    }
+    if !met.is_empty() {
+        save_goals(goals);
+    }
+    met
+}
 /// Load initial data from storage into the app signals.
 ///
 /// Only **active** sessions are placed into the sessions signal; completed
@@ -105,13 +416,19 @@ async fn load_storage_data(
         bests_rows.len(),
         custom.len(),
     );
+    if active.len() > 1 {
+        // Shown for resolution above the session view.
+        log::warn!("{} sessions are active at once", active.len());
+    }
     if !active.is_empty() {
         sessions_sig.set(active);
     }
     cache_sig.set(bests_rows_to_cache(bests_rows));
     if !custom.is_empty() {
         custom_sig.set(custom.into_iter().map(Arc::new).collect());
-    }
+    } // Startup is the only time the trash is purged, keeping deletions cheap.
+    storage::purge_expired_trash().await;
+    storage::pack_old_sessions().await;
 }
 /// Upsert `session` into the in-memory signal, then persist it to the backend.
 ///
@@ -122,6 +439,9 @@ async fn load_storage_data(
 /// is confirmed.  If the background write fails the signal is reverted to its
 /// previous state and an error toast is shown.
 ///
+/// **Write-behind**: the writes of active sessions are held for a moment and
+/// coalesced, so rapid taps end in one write (see [`super::autosave`]).
+///
 /// **`BestsCache` maintenance**:
 /// * When a session is **completed for the first time**, its logs are merged
 ///   incrementally so the cache stays up-to-date without a storage query.
@@ -170,7 +490,46 @@ pub fn save_session(session: WorkoutSession) {
     let cache_sig = consume_context::<Signal<BestsCache>>();
     update_bests_cache_on_session_save(&session, previous.as_ref(), is_update, cache_sig);
     let toast = consume_context::<ToastSignal>().0;
-    super::storage::enqueue_put_session(session, toast, sig, previous);
+    super::autosave::save(session, toast, sig, previous);
+}
+/// Write `sessions` back to storage without touching the reactive signals.
+///
+/// Used after toggling the app lock so that existing history is re-sealed
+/// (or unsealed) with the new setting.
+pub fn rewrite_stored_sessions(sessions: Vec<WorkoutSession>) {
+    let sig = use_sessions();
+    let toast = consume_context::<ToastSignal>().0;
+    for session in sessions {
+        let previous = Some(session.clone());
+        super::storage::enqueue_put_session(session, toast, sig, previous);
+    }
+}
+/// Merge the exercise `from` into `into`: reassign its sets, pending and
+/// current entries throughout `history` and its goals, persist what changed,
+/// then move `from` to the trash if it is a custom exercise.  Returns the
+/// number of sessions changed.
+pub fn merge_exercises(history: Vec<WorkoutSession>, from: &str, into: &Exercise) -> usize {
+    let (active, finished): (Vec<WorkoutSession>, Vec<WorkoutSession>) = history
+        .into_iter()
+        .filter_map(|mut s| s.reassign_exercise(from, &into.id, &into.name).then_some(s))
+        .partition(WorkoutSession::is_active);
+    let count = active.len() + finished.len();
+    for session in active {
+        save_session(session);
+    }
+    rewrite_stored_sessions(finished);
+    let mut goals = use_goals().peek().clone();
+    if goals.iter().any(|g| g.exercise_id == from) {
+        for goal in goals.iter_mut().filter(|g| g.exercise_id == from) {
+            goal.exercise_id.clone_from(&into.id);
+        }
+        save_goals(goals);
+    }
+    if use_custom_exercises().peek().iter().any(|e| e.id == from) {
+        delete_custom_exercise(from);
+    }
+    recompute_all_bests(consume_context::<Signal<BestsCache>>());
+    count
 }
 /// Update the [`BestsCache`] after a session has been upserted.
 ///
@@ -271,7 +630,8 @@ fn update_bests_cache_on_session_save(
         }
     }
 }
-/// Remove the session with `id` from the in-memory signal and from the backend.
+/// Remove the session with `id` from the in-memory signal and move it to the
+/// trash in the backend.
 ///
 /// **Optimistic update**: the session is removed from the signal before the
 /// backend delete is confirmed.  On failure the signal is restored and a toast
@@ -316,6 +676,62 @@ pub fn delete_session(id: &str) {
     let toast = consume_context::<ToastSignal>().0;
     super::storage::enqueue_delete_session(id, toast, sig, snapshot);
 }
+/// Permanently delete the sessions with `ids` in one batch, skipping the
+/// trash, then rebuild the bests once.  Returns how many were deleted.
+///
+/// On failure the sessions are put back into the signal.
+pub async fn delete_sessions(ids: Vec<String>) -> Result<usize, super::storage::StorageError> {
+    let mut sig = use_sessions();
+    let removed: Vec<WorkoutSession> = {
+        let mut sessions = sig.write();
+        let (removed, kept) = std::mem::take(&mut *sessions)
+            .into_iter()
+            .partition(|s| ids.contains(&s.id));
+        *sessions = kept;
+        removed
+    };
+    let count = ids.len();
+    if let Err(e) = super::storage::delete_sessions_permanently(ids).await {
+        sig.write().extend(removed);
+        return Err(e);
+    }
+    recompute_all_bests(consume_context::<Signal<BestsCache>>());
+    Ok(count)
+}
+/// Start `session` (new, repeated or resumed) unless a session is already
+/// active, in which case the user is told so.  Returns whether it started.
+pub fn start_session(session: WorkoutSession) -> bool {
+    if use_sessions().peek().iter().any(WorkoutSession::is_active) {
+        let mut toast = consume_context::<ToastSignal>().0;
+        toast
+            .write()
+            .push_back(dioxus_i18n::t!("toast-session-already-active").to_string());
+        return false;
+    }
+    save_session(session);
+    true
+}
+/// Merge all the active sessions into the earliest one, when several ended up
+/// active at once (sync, import…).  The others are deleted (to the trash).
+pub fn merge_active_sessions() {
+    let mut active: Vec<WorkoutSession> = use_sessions()
+        .peek()
+        .iter()
+        .filter(|s| s.is_active())
+        .cloned()
+        .collect();
+    if active.len() < 2 {
+        return;
+    }
+    active.sort_by_key(|s| s.start_time);
+    let mut merged = active.remove(0);
+    for other in active {
+        let id = other.id.clone();
+        merged.absorb(other);
+        delete_session(&id);
+    }
+    save_session(merged);
+}
 /// Mark `exercise_id` as the active exercise in the current session.
 ///
 /// Clears the rest timer, sets `current_exercise_id` and
@@ -332,17 +748,30 @@ pub fn begin_exercise_in_session(exercise_id: String, exercise_start: u64) {
     updated.current_exercise_start = Some(exercise_start);
     save_session(updated);
 }
-/// Append a completed exercise log to the active session and start the rest timer.
+/// Tag the current session with `location` (`None` clears it), then persist.
+/// No-op when there is no active session.
+pub fn set_session_location(location: Option<String>) {
+    let sig = use_sessions();
+    let Some(session) = sig.read().iter().find(|s| s.is_active()).cloned() else {
+        return;
+    };
+    let mut updated = session;
+    updated.location = location;
+    save_session(updated);
+}
+/// Append a completed exercise log to the active session and, when `rest` is
+/// set, start the rest timer.
 ///
 /// Pushes `log` onto the session's `exercise_logs`, records the current time
-/// as `rest_start_time`, and clears `current_exercise_id` /
+/// as `rest_start_time` (or clears it without `rest`), counts the set against the planned sets of the
+/// exercise, if any, and clears `current_exercise_id` /
 /// `current_exercise_start`, then persists.  No-op when there is no active
 /// session.
 ///
 /// **`BestsCache` maintenance**: the new log is merged into the cache
 /// immediately (incrementally) so that the ATH is updated at exercise
 /// completion rather than waiting for the full session to be saved.
-pub fn append_exercise_log(log: ExerciseLog) {
+pub fn append_exercise_log(log: ExerciseLog, rest: bool) {
     let sig = use_sessions();
     let Some(session) = sig.read().iter().find(|s| s.is_active()).cloned() else {
         return;
@@ -355,8 +784,9 @@ pub fn append_exercise_log(log: ExerciseLog) {
         merge_log_into_bests(entry, &log);
     }
     let mut updated = session;
+    updated.count_planned_set(&log.exercise_id);
     updated.exercise_logs.push(log);
-    updated.rest_start_time = Some(get_current_timestamp());
+    updated.rest_start_time = rest.then(get_current_timestamp);
     updated.current_exercise_id = None;
     updated.current_exercise_start = None;
     save_session(updated);
@@ -375,27 +805,31 @@ pub fn cancel_exercise_in_session() {
     updated.current_exercise_start = None;
     save_session(updated);
 }
-/// Remove `exercise_id` from the pending list and make it the active exercise.
+/// Append `exercise_ids` to the pending list of the active session, then
+/// persist.  No-op when there is no active session.
+pub fn add_pending_exercises_to_session(exercise_ids: Vec<String>) {
+    let sig = use_sessions();
+    let Some(mut updated) = sig.read().iter().find(|s| s.is_active()).cloned() else {
+        return;
+    };
+    updated
+        .pending_exercises
+        .extend(exercise_ids.into_iter().map(PlannedExercise::from));
+    save_session(updated);
+}
+/// Take `exercise_id` from the pending list and make it the active exercise.
 ///
-/// Only the **first** occurrence of `exercise_id` in `pending_exercise_ids` is
-/// removed (FIFO order).  Clears the rest timer, sets `current_exercise_id`
-/// and `current_exercise_start`, then persists.  No-op when there is no
-/// active session.
+/// Only the **first** entry of `exercise_id` in `pending_exercises` is taken
+/// (FIFO order); one with planned sets stays until they are logged.  Clears
+/// the rest timer, sets `current_exercise_id` and `current_exercise_start`,
+/// then persists.  No-op when there is no active session.
 pub fn start_pending_exercise_in_session(exercise_id: String, exercise_start: u64) {
     let sig = use_sessions();
     let Some(session) = sig.read().iter().find(|s| s.is_active()).cloned() else {
         return;
     };
     let mut updated = session;
-    let mut removed = false;
-    updated.pending_exercise_ids.retain(|x| {
-        if !removed && x == &exercise_id {
-            removed = true;
-            false
-        } else {
-            true
-        }
-    });
+    updated.take_pending(&exercise_id);
     updated.rest_start_time = None;
     updated.current_exercise_id = Some(exercise_id);
     updated.current_exercise_start = Some(exercise_start);
@@ -445,6 +879,44 @@ pub fn update_custom_exercise(exercise: Exercise) {
     let toast = consume_context::<ToastSignal>().0;
     super::storage::enqueue_put_exercise(exercise, toast);
 }
+/// Remove the custom exercise with `id` from the signal and move it to the
+/// trash in the backend.
+///
+/// **Lock-screen guard**: deleting is only allowed when the screen is unlocked.
+pub fn delete_custom_exercise(id: &str) {
+    if screen_is_locked() {
+        let mut toast = consume_context::<ToastSignal>().0;
+        toast
+            .write()
+            .push_back(dioxus_i18n::t!("toast-screen-locked").to_string());
+        return;
+    }
+    use_custom_exercises().write().retain(|e| e.id != id);
+    let toast = consume_context::<ToastSignal>().0;
+    super::storage::enqueue_delete_exercise(id.to_owned(), toast);
+}
+/// Bring an item restored from the trash back into the in-memory state.
+///
+/// The item is already back in storage (see
+/// [`super::storage::restore_from_trash`]).  Restored sessions may hold
+/// personal records, so the [`BestsCache`] is rebuilt.
+pub fn apply_restored_item(item: crate::models::TrashedItem) {
+    use crate::models::TrashedItem;
+    match item {
+        TrashedItem::Session(session) => {
+            if session.is_active() {
+                use_sessions().write().push(session);
+            }
+            recompute_all_bests(consume_context::<Signal<BestsCache>>());
+        }
+        TrashedItem::Exercise(exercise) => {
+            let mut sig = use_custom_exercises();
+            let mut exercises = sig.write();
+            exercises.retain(|e| e.id != exercise.id);
+            exercises.push(Arc::new(exercise));
+        }
+    }
+}
 /// Returns the last completed [`ExerciseLog`] for `exercise_id` across all
 /// stored sessions, or `None` if the exercise has never been logged.
 ///
@@ -472,11 +944,94 @@ pub(crate) fn find_last_exercise_log<'a>(
     }
     None
 }
+/// Most recent completed log of every exercise among the `sessions` tagged
+/// with `location`, keyed by exercise ID.
+pub(crate) fn last_logs_at_location(
+    sessions: &[WorkoutSession],
+    location: &str,
+) -> std::collections::HashMap<String, ExerciseLog> {
+    let mut last: std::collections::HashMap<String, ExerciseLog> = std::collections::HashMap::new();
+    for session in sessions {
+        if session.location.as_deref() != Some(location) {
+            continue;
+        }
+        for log in session.exercise_logs.iter().filter(|l| l.is_complete()) {
+            let newer = last
+                .get(&log.exercise_id)
+                .is_none_or(|prev| log.end_time > prev.end_time);
+            if newer {
+                last.insert(log.exercise_id.clone(), log.clone());
+            }
+        }
+    }
+    last
+}
+/// Load the completed sessions tagged with `location` and return the last
+/// log of every exercise done there (see [`last_logs_at_location`]), so that
+/// inputs are prefilled from the same gym, whose machines may feel different.
+pub async fn load_last_logs_at_location(
+    location: String,
+) -> std::collections::HashMap<String, ExerciseLog> {
+    const PAGE_SIZE: usize = 500;
+    let mut last = std::collections::HashMap::new();
+    let mut offset = 0;
+    loop {
+        let page = match super::storage::load_completed_sessions_page(PAGE_SIZE, offset, true).await
+        {
+            Ok(page) => page,
+            Err(e) => {
+                log::error!("Failed to load sessions at location {location}: {e}");
+                break;
+            }
+        };
+        for (id, log) in last_logs_at_location(&page, &location) {
+            let newer = last
+                .get(&id)
+                .is_none_or(|prev: &ExerciseLog| log.end_time > prev.end_time);
+            if newer {
+                last.insert(id, log);
+            }
+        }
+        if page.len() < PAGE_SIZE {
+            break;
+        }
+        offset += page.len();
+    }
+    last
+}
+/// Load every completed log of `exercise_id`, oldest sessions last, to
+/// measure the progress towards its goals.
+pub async fn load_exercise_logs(exercise_id: String) -> Vec<ExerciseLog> {
+    const PAGE_SIZE: usize = 500;
+    let mut logs = Vec::new();
+    let mut offset = 0;
+    loop {
+        let page = match super::storage::load_completed_sessions_page(PAGE_SIZE, offset, true).await
+        {
+            Ok(page) => page,
+            Err(e) => {
+                log::error!("Failed to load sessions for {exercise_id}: {e}");
+                break;
+            }
+        };
+        logs.extend(
+            page.iter()
+                .flat_map(|s| &s.exercise_logs)
+                .filter(|l| l.exercise_id == exercise_id && l.is_complete())
+                .cloned(),
+        );
+        if page.len() < PAGE_SIZE {
+            break;
+        }
+        offset += page.len();
+    }
+    logs
+}
 /// All-time best (personal record) values for a specific exercise, derived by
 /// scanning every completed log across all stored sessions.
 #[derive(Clone, Default)]
 pub struct ExerciseBests {
-    /// Heaviest weight ever lifted for this exercise.
+    /// Heaviest weight ever lifted for this exercise (assisted sets excluded).
     pub weight_hg: Option<Weight>,
     /// Most repetitions ever performed in a single set.
     pub reps: Option<u32>,
@@ -490,6 +1045,8 @@ pub struct ExerciseBests {
     pub last_reps: Option<u32>,
     /// Distance from the most-recently completed log.
     pub last_distance_m: Option<Distance>,
+    /// Whether the most-recently completed log was an assisted set.
+    pub last_assisted: bool,
     /// `end_time` of the most-recently completed log.
     pub last_log_end_time: Option<u64>,
 }
@@ -507,11 +1064,12 @@ pub struct ExerciseBests {
 /// synchronous hot path is never blocked by an O(N) scan.
 pub(crate) type BestsCache = std::collections::HashMap<String, ExerciseBests>;
 /// Merge one exercise log's values into an existing best, updating it in place.
+/// Warm-up sets are ignored.
 pub(crate) fn merge_log_into_bests(bests: &mut ExerciseBests, log: &ExerciseLog) {
-    if !log.is_complete() {
+    if !log.is_complete() || !log.set_kind.counts_for_stats() {
         return;
     }
-    if log.weight_hg.0 > 0 {
+    if log.weight_hg.0 > 0 && !log.assisted {
         let w = log.weight_hg;
         bests.weight_hg = Some(match bests.weight_hg {
             None => w,
@@ -555,6 +1113,7 @@ pub(crate) fn merge_log_into_bests(bests: &mut ExerciseBests, log: &ExerciseLog)
         bests.last_weight_hg = (log.weight_hg.0 > 0).then_some(log.weight_hg);
         bests.last_reps = log.reps;
         bests.last_distance_m = log.distance_m;
+        bests.last_assisted = log.assisted;
     }
 }
 /// Returns `true` when any of the log's recorded values exactly matches the
@@ -563,10 +1122,10 @@ pub(crate) fn merge_log_into_bests(bests: &mut ExerciseBests, log: &ExerciseLog)
 /// Only complete logs are checked; an incomplete log always returns `false`.
 /// Used to determine whether deleting / editing a log requires a cache eviction.
 pub(crate) fn log_was_personal_record(log: &ExerciseLog, bests: &ExerciseBests) -> bool {
-    if !log.is_complete() {
+    if !log.is_complete() || !log.set_kind.counts_for_stats() {
         return false;
     }
-    (log.weight_hg.0 > 0 && bests.weight_hg.is_some_and(|b| b == log.weight_hg))
+    (log.weight_hg.0 > 0 && !log.assisted && bests.weight_hg.is_some_and(|b| b == log.weight_hg))
         || (log.reps.is_some() && log.reps == bests.reps)
         || (log.distance_m.is_some() && log.distance_m == bests.distance_m)
         || (log.duration_seconds().is_some() && log.duration_seconds() == bests.duration)
@@ -642,6 +1201,7 @@ fn exercise_bests_from_row(row: &super::storage::BestsRow) -> ExerciseBests {
         last_weight_hg: row.last_weight_hg.map(Weight),
         last_reps: row.last_reps,
         last_distance_m: row.last_distance_m.map(Distance),
+        last_assisted: row.last_assisted,
         last_log_end_time: row.last_log_end_time,
     }
 }
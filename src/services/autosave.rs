@@ -0,0 +1,138 @@
+/// Write-behind autosave of the active sessions.
+///
+/// Every tap in the session view saves the whole session.  The sessions
+/// signal is updated right away, but the storage writes of active sessions
+/// are held for [`AUTOSAVE_DELAY_MS`] and coalesced, so a burst of taps ends
+/// in a single write of the latest state.  Pending writes are flushed as soon
+/// as the session ends or is deleted, and on the web when the page is hidden
+/// or unloaded (see [`register_flush_on_hide`]).
+use crate::models::WorkoutSession;
+use dioxus::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+/// Delay after the first held write before the pending writes are flushed.
+const AUTOSAVE_DELAY_MS: u64 = 1_000;
+/// Session writes waiting for the next flush, the latest one per session.
+#[derive(Default)]
+struct PendingWrites {
+    /// `(session, previous)`, `previous` being the stored value before the
+    /// first held write, to revert to if the write fails.
+    writes: Vec<(WorkoutSession, Option<WorkoutSession>)>,
+}
+impl PendingWrites {
+    /// Hold `session`, superseding an earlier held write of the same session
+    /// but keeping its `previous`.
+    fn hold(&mut self, session: WorkoutSession, previous: Option<WorkoutSession>) {
+        match self.writes.iter_mut().find(|(s, _)| s.id == session.id) {
+            Some(write) => write.0 = session,
+            None => self.writes.push((session, previous)),
+        }
+    }
+    /// Remove and return the held write of session `id`, if any.
+    fn take(&mut self, id: &str) -> Option<(WorkoutSession, Option<WorkoutSession>)> {
+        let pos = self.writes.iter().position(|(s, _)| s.id == id)?;
+        Some(self.writes.remove(pos))
+    }
+}
+/// Signals handed over to the storage queue along with the held writes.
+type QueueSignals = (Signal<VecDeque<String>>, Signal<Vec<WorkoutSession>>);
+thread_local! {
+    static PENDING: RefCell<PendingWrites> = RefCell::new(PendingWrites::default());
+    static SIGNALS: RefCell<Option<QueueSignals>> = const { RefCell::new(None) };
+    /// Whether a flush is scheduled.
+    static SCHEDULED: Cell<bool> = const { Cell::new(false) };
+}
+/// Save `session` to storage, held and coalesced while it is active.
+///
+/// `previous` is the stored value it replaces (see
+/// [`super::storage::enqueue_put_session`]).
+pub fn save(
+    session: WorkoutSession,
+    toast: Signal<VecDeque<String>>,
+    sessions_sig: Signal<Vec<WorkoutSession>>,
+    previous: Option<WorkoutSession>,
+) {
+    if !session.is_active() {
+        // The value stored before any held write is the one to revert to.
+        let previous = match PENDING.with(|p| p.borrow_mut().take(&session.id)) {
+            Some((_, held_previous)) => held_previous,
+            None => previous,
+        };
+        super::storage::enqueue_put_session(session, toast, sessions_sig, previous);
+        return;
+    }
+    SIGNALS.with(|s| *s.borrow_mut() = Some((toast, sessions_sig)));
+    PENDING.with(|p| p.borrow_mut().hold(session, previous));
+    if !SCHEDULED.with(|s| s.replace(true)) {
+        // Outlive the session view, which may unmount before the flush.
+        dioxus::core::spawn_forever(async {
+            #[cfg(target_arch = "wasm32")]
+            gloo_timers::future::TimeoutFuture::new(AUTOSAVE_DELAY_MS as u32).await;
+            #[cfg(not(target_arch = "wasm32"))]
+            tokio::time::sleep(std::time::Duration::from_millis(AUTOSAVE_DELAY_MS)).await;
+            flush();
+        });
+    }
+}
+/// Drop the held write of session `id`, e.g. because it is being deleted.
+pub fn discard(id: &str) {
+    PENDING.with(|p| p.borrow_mut().take(id));
+}
+/// Hand every held write over to the storage queue now.
+pub fn flush() {
+    SCHEDULED.with(|s| s.set(false));
+    let writes = PENDING.with(|p| std::mem::take(&mut p.borrow_mut().writes));
+    let Some((toast, sessions_sig)) = SIGNALS.with(|s| *s.borrow()) else {
+        return;
+    };
+    for (session, previous) in writes {
+        super::storage::enqueue_put_session(session, toast, sessions_sig, previous);
+    }
+}
+/// Flush the held writes whenever the page gets hidden or unloaded, as the
+/// browser may then terminate it before the delay is over.
+///
+/// Call once at app startup.  The closure is intentionally leaked because it
+/// must live for the duration of the page.
+#[cfg(target_arch = "wasm32")]
+pub fn register_flush_on_hide() {
+    use wasm_bindgen::prelude::Closure;
+    use wasm_bindgen::JsCast as _;
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    // Flushing when the page gets visible again only writes a bit early.
+    let closure: Closure<dyn Fn()> = Closure::wrap(Box::new(|| {
+        flush();
+        super::storage::idb_queue::flush();
+    }));
+    if let Some(document) = window.document() {
+        let _ = document
+            .add_event_listener_with_callback("visibilitychange", closure.as_ref().unchecked_ref());
+    }
+    let _ = window.add_event_listener_with_callback("pagehide", closure.as_ref().unchecked_ref());
+    closure.forget();
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn held_writes_keep_the_latest_session_and_the_first_previous() {
+        let mut pending = PendingWrites::default();
+        let stored = WorkoutSession::new();
+        let mut first = stored.clone();
+        first.notes = "first".into();
+        pending.hold(first.clone(), Some(stored.clone()));
+        let mut second = first.clone();
+        second.notes = "second".into();
+        pending.hold(second, Some(first));
+        let mut other = WorkoutSession::new();
+        other.id = format!("{}-other", stored.id);
+        pending.hold(other, None);
+        assert_eq!(pending.writes.len(), 2);
+        let (session, previous) = pending.take(&stored.id).unwrap();
+        assert_eq!(session.notes, "second");
+        assert_eq!(previous.unwrap().notes, stored.notes);
+        assert!(pending.take(&stored.id).is_none());
+    }
+}
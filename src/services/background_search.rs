@@ -0,0 +1,390 @@
+/// Exercise search off the UI thread, so typing stays smooth on low-end
+/// phones although every query scans the whole exercise database.
+///
+/// Natively the search runs on a blocking thread of the async runtime, and
+/// the `SQLite` full-text index adds the exercises whose muscles or
+/// instructions match (see [`native_storage::search_exercise_ids`]).  On the
+/// web the names of the exercises are scanned by a web worker of plain
+/// JavaScript (`public/search-worker.js`), sent each list of exercises once
+/// and then only the queries: it answers the exercises that may match, which
+/// are scored on the UI thread among these few.  Should the worker fail to
+/// start or crash, the whole list is searched a chunk at a time instead,
+/// yielding to the browser in between so input keeps being handled.
+/// Dropping the future, as `use_resource` does when the query changes,
+/// abandons a stale search.
+use crate::models::{Exercise, ExerciseOverrides};
+use crate::services::exercise_db::{search_matches, MatchField};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::services::storage::native_storage;
+use std::sync::Arc;
+#[cfg(target_arch = "wasm32")]
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+/// Exercises searched between two yields to the browser, without a worker.
+#[cfg(target_arch = "wasm32")]
+const CHUNK_SIZE: usize = 150;
+/// Script of the search worker, served from `public/`.
+#[cfg(target_arch = "wasm32")]
+const WORKER_URL: &str = "./search-worker.js";
+/// Lists of exercises the worker keeps, enough for the custom and database
+/// pools of both search screens.
+#[cfg(target_arch = "wasm32")]
+const LISTS_KEPT: usize = 4;
+/// Full-text matches added at most, after those of the names.
+#[cfg(not(target_arch = "wasm32"))]
+const FTS_MAX_RESULTS: usize = 50;
+/// Search results, best first, with what the query matched in each.
+pub type Matches = Vec<(Arc<Exercise>, MatchField)>;
+/// What to search for, moved to the search task.
+struct Query {
+    text: String,
+    lang: String,
+    overrides: ExerciseOverrides,
+}
+/// Scored matches of `query` among `exercises`, in their order.
+fn score_chunk(
+    exercises: &[Arc<Exercise>],
+    query: &Query,
+) -> Vec<(u32, Arc<Exercise>, MatchField)> {
+    search_matches(exercises, &query.text, &query.lang, &query.overrides)
+        .into_iter()
+        .map(|m| (m.score, Arc::clone(m.exercise), m.field))
+        .collect()
+}
+/// Chunk matches ranked like a single search: best first and, the sort
+/// being stable, in database order among equals.
+fn rank(mut scored: Vec<(u32, Arc<Exercise>, MatchField)>) -> Matches {
+    scored.sort_by_key(|(score, _, _)| std::cmp::Reverse(*score));
+    scored
+        .into_iter()
+        .map(|(_, ex, field)| (ex, field))
+        .collect()
+}
+/// What a full-text hit on `ex` for `query` matched: a word of the name
+/// starting with one of the query words, else a muscle, else instructions.
+#[cfg(not(target_arch = "wasm32"))]
+fn fts_match_field(ex: &Exercise, query: &str) -> MatchField {
+    let words: Vec<String> = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let starts_a_word = |text: &str| {
+        text.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|part| words.iter().any(|w| part.starts_with(w.as_str())))
+    };
+    if starts_a_word(&ex.name) {
+        return MatchField::Name;
+    }
+    ex.primary_muscles
+        .iter()
+        .chain(&ex.secondary_muscles)
+        .find(|m| starts_a_word(m.as_ref()))
+        .map_or(MatchField::Instructions, |m| MatchField::Muscle(*m))
+}
+/// `matches` followed by the full-text hits among `exercises` they lack.
+#[cfg(not(target_arch = "wasm32"))]
+fn with_fts_matches(exercises: &[Arc<Exercise>], query: &Query, mut matches: Matches) -> Matches {
+    let ids = match native_storage::search_exercise_ids(&query.text, FTS_MAX_RESULTS) {
+        Ok(ids) => ids,
+        Err(e) => {
+            log::warn!("Full-text exercise search failed: {e}");
+            return matches;
+        }
+    };
+    let by_id: std::collections::HashMap<&str, &Arc<Exercise>> =
+        exercises.iter().map(|ex| (ex.id.as_str(), ex)).collect();
+    let mut seen: std::collections::HashSet<String> =
+        matches.iter().map(|(ex, _)| ex.id.clone()).collect();
+    for id in ids {
+        if let Some(ex) = by_id.get(id.as_str()) {
+            if seen.insert(id) {
+                matches.push((Arc::clone(ex), fts_match_field(ex, &query.text)));
+            }
+        }
+    }
+    matches
+}
+/// Message to the search worker.
+#[cfg(target_arch = "wasm32")]
+#[derive(serde::Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum ToWorker<'a> {
+    /// Keep the names of a list of exercises, forgetting an older one.
+    Index {
+        key: &'a str,
+        drop: Option<String>,
+        names: Vec<Vec<&'a str>>,
+    },
+    /// Answer the indices of the exercises of list `key` that may match.
+    Search {
+        id: u32,
+        key: &'a str,
+        query: &'a str,
+    },
+}
+/// Answer of the search worker, without hits when it lacks the list.
+#[cfg(target_arch = "wasm32")]
+#[derive(serde::Deserialize)]
+struct FromWorker {
+    id: u32,
+    hits: Option<Vec<usize>>,
+}
+/// Searches waiting for a worker answer, by request ID.
+#[cfg(target_arch = "wasm32")]
+type Pending = Rc<RefCell<HashMap<u32, futures_channel::oneshot::Sender<Option<Vec<usize>>>>>>;
+/// The running search worker.
+#[cfg(target_arch = "wasm32")]
+struct SearchWorker {
+    worker: web_sys::Worker,
+    pending: Pending,
+    /// Set by the worker `error` handler, to fall back on the next search.
+    failed: Rc<std::cell::Cell<bool>>,
+    next_id: u32,
+    /// Keys of the lists sent to the worker, oldest first.
+    lists: std::collections::VecDeque<String>,
+}
+#[cfg(target_arch = "wasm32")]
+enum WorkerState {
+    Unstarted,
+    Running(SearchWorker),
+    /// The worker could not start or crashed: search on the UI thread.
+    Failed,
+}
+#[cfg(target_arch = "wasm32")]
+thread_local! {
+    static WORKER: RefCell<WorkerState> = const { RefCell::new(WorkerState::Unstarted) };
+}
+/// Names of `ex` a query is matched against: its own and its translations.
+#[cfg(target_arch = "wasm32")]
+fn searched_names(ex: &Exercise) -> impl Iterator<Item = &str> {
+    std::iter::once(ex.name.as_str()).chain(
+        ex.i18n
+            .iter()
+            .flat_map(std::collections::HashMap::values)
+            .filter_map(|t| t.name.as_deref()),
+    )
+}
+/// Key of a list of exercises for the worker, changing with their names.
+#[cfg(target_arch = "wasm32")]
+fn list_key(exercises: &[Arc<Exercise>]) -> String {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for ex in exercises {
+        ex.id.hash(&mut hasher);
+        searched_names(ex).for_each(|name| name.hash(&mut hasher));
+    }
+    format!("{:016x}", hasher.finish())
+}
+#[cfg(target_arch = "wasm32")]
+impl SearchWorker {
+    fn start() -> Option<Self> {
+        use wasm_bindgen::prelude::Closure;
+        use wasm_bindgen::JsCast as _;
+        let worker = web_sys::Worker::new(WORKER_URL)
+            .map_err(|e| log::warn!("Search worker could not start: {e:?}"))
+            .ok()?;
+        let pending: Pending = Rc::default();
+        let failed = Rc::new(std::cell::Cell::new(false));
+        let answers = Rc::clone(&pending);
+        let on_message: Closure<dyn Fn(web_sys::MessageEvent)> =
+            Closure::wrap(Box::new(move |event: web_sys::MessageEvent| {
+                match serde_wasm_bindgen::from_value::<FromWorker>(event.data()) {
+                    Ok(answer) => {
+                        if let Some(tx) = answers.borrow_mut().remove(&answer.id) {
+                            let _ = tx.send(answer.hits);
+                        }
+                    }
+                    Err(e) => log::warn!("Unexpected search worker message: {e}"),
+                }
+            }));
+        let waiting = Rc::clone(&pending);
+        let crashed = Rc::clone(&failed);
+        let on_error: Closure<dyn Fn(web_sys::Event)> =
+            Closure::wrap(Box::new(move |_: web_sys::Event| {
+                log::warn!("Search worker failed, searching on the UI thread");
+                crashed.set(true);
+                // Dropping the senders ends the waiting searches.
+                waiting.borrow_mut().clear();
+            }));
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+        // The worker lives as long as the page.
+        on_message.forget();
+        on_error.forget();
+        Some(Self {
+            worker,
+            pending,
+            failed,
+            next_id: 0,
+            lists: std::collections::VecDeque::new(),
+        })
+    }
+    fn post(&self, message: &ToWorker) -> Option<()> {
+        let value = serde_wasm_bindgen::to_value(message).ok()?;
+        self.worker
+            .post_message(&value)
+            .map_err(|e| log::warn!("Search worker message failed: {e:?}"))
+            .ok()
+    }
+    /// Sends `query` for `exercises`, and their names first if the worker
+    /// lacks them, returning where the answer will arrive.
+    fn request(
+        &mut self,
+        exercises: &[Arc<Exercise>],
+        query: &str,
+    ) -> Option<futures_channel::oneshot::Receiver<Option<Vec<usize>>>> {
+        let key = list_key(exercises);
+        if !self.lists.contains(&key) {
+            let drop = (self.lists.len() >= LISTS_KEPT)
+                .then(|| self.lists.pop_front())
+                .flatten();
+            let names = exercises
+                .iter()
+                .map(|ex| searched_names(ex).collect())
+                .collect();
+            self.post(&ToWorker::Index {
+                key: &key,
+                drop,
+                names,
+            })?;
+            self.lists.push_back(key.clone());
+        }
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        let (tx, rx) = futures_channel::oneshot::channel();
+        self.pending.borrow_mut().insert(id, tx);
+        self.post(&ToWorker::Search {
+            id,
+            key: &key,
+            query,
+        })?;
+        Some(rx)
+    }
+}
+/// Indices of the `exercises` the worker finds `query` may match, a superset
+/// of those [`search_matches`] keeps, or `None` without a working worker.
+#[cfg(target_arch = "wasm32")]
+async fn worker_candidates(exercises: &[Arc<Exercise>], query: &str) -> Option<Vec<usize>> {
+    let answer = WORKER.with_borrow_mut(|state| {
+        if let WorkerState::Running(worker) = state {
+            if worker.failed.get() {
+                worker.worker.terminate();
+                *state = WorkerState::Failed;
+            }
+        }
+        if matches!(state, WorkerState::Unstarted) {
+            *state = SearchWorker::start().map_or(WorkerState::Failed, WorkerState::Running);
+        }
+        match state {
+            WorkerState::Running(worker) => worker.request(exercises, query),
+            _ => None,
+        }
+    })?;
+    answer.await.ok().flatten()
+}
+/// [`search_matches`] of `query` among `exercises`, without blocking the UI.
+pub async fn search(
+    exercises: Vec<Arc<Exercise>>,
+    query: String,
+    lang: String,
+    overrides: ExerciseOverrides,
+) -> Matches {
+    let query = Query {
+        text: query,
+        lang,
+        overrides,
+    };
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(hits) = worker_candidates(&exercises, &query.text).await {
+            // The worker knows nothing of aliases: their exercises are
+            // always scored.
+            let mut keep = vec![false; exercises.len()];
+            for i in hits {
+                if let Some(hit) = keep.get_mut(i) {
+                    *hit = true;
+                }
+            }
+            let candidates: Vec<Arc<Exercise>> = exercises
+                .iter()
+                .zip(keep)
+                .filter(|(ex, hit)| {
+                    *hit || query
+                        .overrides
+                        .get(&ex.id)
+                        .is_some_and(|o| !o.aliases.is_empty())
+                })
+                .map(|(ex, _)| Arc::clone(ex))
+                .collect();
+            return rank(score_chunk(&candidates, &query));
+        }
+        let mut scored = Vec::new();
+        for chunk in exercises.chunks(CHUNK_SIZE) {
+            scored.extend(score_chunk(chunk, &query));
+            gloo_timers::future::TimeoutFuture::new(0).await;
+        }
+        rank(scored)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::task::spawn_blocking(move || {
+            let matches = rank(score_chunk(&exercises, &query));
+            with_fts_matches(&exercises, &query, matches)
+        })
+        .await
+        .unwrap_or_default()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Category;
+    fn exercise(id: &str, name: &str) -> Arc<Exercise> {
+        Arc::new(Exercise {
+            id: id.into(),
+            name: name.into(),
+            name_lower: name.to_lowercase(),
+            force: None,
+            level: None,
+            mechanic: None,
+            equipment: None,
+            primary_muscles: vec![],
+            secondary_muscles: vec![],
+            instructions: vec![],
+            category: Category::Strength,
+            images: vec![],
+            videos: vec![],
+            i18n: None,
+            rest_seconds: None,
+        })
+    }
+    #[test]
+    fn chunked_search_ranks_like_a_single_one() {
+        let exercises = vec![
+            exercise("incline", "Incline Bench Press"),
+            exercise("row", "Barbell Row"),
+            exercise("bench", "Bench Press"),
+            exercise("close", "Close-Grip Bench Press"),
+            exercise("bench_dip", "Bench Dip"),
+        ];
+        let query = Query {
+            text: "bench".into(),
+            lang: String::new(),
+            overrides: ExerciseOverrides::new(),
+        };
+        let chunked = rank(
+            exercises
+                .chunks(2)
+                .flat_map(|chunk| score_chunk(chunk, &query))
+                .collect(),
+        );
+        let single: Vec<&str> = search_matches(&exercises, "bench", "", &query.overrides)
+            .iter()
+            .map(|m| m.exercise.id.as_str())
+            .collect();
+        let chunked: Vec<&str> = chunked.iter().map(|(ex, _)| ex.id.as_str()).collect();
+        assert_eq!(chunked, single);
+        assert_eq!(chunked, ["bench", "bench_dip", "incline", "close"]);
+    }
+}
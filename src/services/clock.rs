@@ -0,0 +1,71 @@
+/// App-wide clock driving the session timers.
+///
+/// A single task sets [`ClockSignal`] to the wall-clock time every second,
+/// and every timer derives its value from timestamps rather than by counting
+/// ticks.  While the page is hidden browsers throttle timeouts to about one a
+/// minute, and a sleeping device runs none: the clock is set again as soon as
+/// the page is shown (see [`register_clock_resume`]), so the displays and the
+/// notification checks reading it catch up at once, each missed notification
+/// firing a single time.
+use crate::models::get_current_timestamp;
+use dioxus::prelude::*;
+/// Interval between two clock ticks.
+const TICK_MS: u32 = 1_000;
+/// Current Unix timestamp (seconds), updated every [`TICK_MS`] and on resume.
+#[derive(Clone, Copy)]
+pub struct ClockSignal(pub Signal<u64>);
+/// Provide the [`ClockSignal`] and start ticking it.
+///
+/// Call once inside the root `App` component.
+pub fn provide_clock() {
+    let mut now = use_context_provider(|| ClockSignal(Signal::new(get_current_timestamp()))).0;
+    use_hook(move || {
+        spawn(async move {
+            loop {
+                crate::utils::sleep_ms(TICK_MS).await;
+                now.set(get_current_timestamp());
+            }
+        });
+        #[cfg(target_arch = "wasm32")]
+        register_clock_resume(now);
+    });
+}
+/// Consumes the clock signal from the Dioxus context.
+pub fn use_clock() -> Signal<u64> {
+    use_context::<ClockSignal>().0
+}
+/// Set the clock whenever the page becomes visible or is restored from the
+/// back-forward cache, instead of waiting for the next throttled tick.
+///
+/// The listener only forwards the wake-up to a Dioxus task, which writes the
+/// signal.  The closure is intentionally leaked because it must live for the
+/// duration of the page.
+#[cfg(target_arch = "wasm32")]
+fn register_clock_resume(mut now: Signal<u64>) {
+    use futures_util::StreamExt as _;
+    use wasm_bindgen::prelude::Closure;
+    use wasm_bindgen::JsCast as _;
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let (tx, mut rx) = futures_channel::mpsc::unbounded::<()>();
+    spawn(async move {
+        while rx.next().await.is_some() {
+            now.set(get_current_timestamp());
+        }
+    });
+    let closure: Closure<dyn Fn()> = Closure::wrap(Box::new(move || {
+        let visible = web_sys::window()
+            .and_then(|w| w.document())
+            .is_some_and(|d| d.visibility_state() == web_sys::VisibilityState::Visible);
+        if visible {
+            let _ = tx.unbounded_send(());
+        }
+    }));
+    if let Some(document) = window.document() {
+        let _ = document
+            .add_event_listener_with_callback("visibilitychange", closure.as_ref().unchecked_ref());
+    }
+    let _ = window.add_event_listener_with_callback("pageshow", closure.as_ref().unchecked_ref());
+    closure.forget();
+}
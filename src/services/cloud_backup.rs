@@ -0,0 +1,445 @@
+/// Backup upload to the app folder of a Google Drive or Dropbox account.
+///
+/// Linking uses the OAuth 2.0 authorization code flow with PKCE: the user is
+/// sent to the provider, which redirects back to the app with a code that
+/// [`complete_authorization`] exchanges for a refresh token.  The backup is
+/// the same pair of files as the encrypted manual exports
+/// (`sessions.encrypted.json` and `custom_exercises.encrypted.json`, see
+/// [`backup::backup_file`]), overwritten on every upload, so restoring is a
+/// matter of importing them with the backup passphrase.  Nothing is uploaded
+/// in clear: an upload without a passphrase is refused.
+///
+/// OAuth client IDs are set at build time through the
+/// `LOGOUT_GOOGLE_CLIENT_ID` (and `LOGOUT_GOOGLE_CLIENT_SECRET`, which
+/// Google requires even for public clients) and `LOGOUT_DROPBOX_CLIENT_ID`
+/// environment variables; a provider without one is not offered.
+use crate::models::{get_current_timestamp, Exercise, WorkoutSession};
+use crate::services::{backup, crypto};
+use serde::{Deserialize, Serialize};
+use sha2::Digest as _;
+/// Config key holding the serialised [`CloudLink`].
+const CLOUD_BACKUP_KEY: &str = "cloud_backup";
+/// Config key holding the [`PendingAuth`] of an authorization in progress.
+const CLOUD_BACKUP_PENDING_KEY: &str = "cloud_backup_pending";
+/// Random bytes of a PKCE code verifier (43 characters once encoded).
+const VERIFIER_LEN: usize = 32;
+/// Random bytes of the OAuth `state` parameter.
+const STATE_LEN: usize = 16;
+/// Seconds before its announced expiry an access token is renewed.
+const EXPIRY_MARGIN_SECS: u64 = 60;
+const SESSIONS_FILE: &str = "sessions";
+const EXERCISES_FILE: &str = "custom_exercises";
+const GOOGLE_FILES_API: &str = "https://www.googleapis.com/drive/v3/files";
+const GOOGLE_UPLOAD_API: &str = "https://www.googleapis.com/upload/drive/v3/files";
+const DROPBOX_UPLOAD_API: &str = "https://content.dropboxapi.com/2/files/upload";
+/// Separator of the parts of a Google Drive multipart upload.
+const MULTIPART_BOUNDARY: &str = "logout-backup-part";
+/// Cloud storage a backup can be uploaded to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CloudProvider {
+    GoogleDrive,
+    Dropbox,
+}
+impl CloudProvider {
+    pub const ALL: [Self; 2] = [Self::GoogleDrive, Self::Dropbox];
+    /// Brand name, not translated.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::GoogleDrive => "Google Drive",
+            Self::Dropbox => "Dropbox",
+        }
+    }
+    fn client_id(self) -> Option<&'static str> {
+        match self {
+            Self::GoogleDrive => option_env!("LOGOUT_GOOGLE_CLIENT_ID"),
+            Self::Dropbox => option_env!("LOGOUT_DROPBOX_CLIENT_ID"),
+        }
+    }
+    fn client_secret(self) -> Option<&'static str> {
+        match self {
+            Self::GoogleDrive => option_env!("LOGOUT_GOOGLE_CLIENT_SECRET"),
+            Self::Dropbox => None,
+        }
+    }
+    /// Whether this build has an OAuth client ID for the provider.
+    #[must_use]
+    pub fn is_available(self) -> bool {
+        self.client_id().is_some()
+    }
+    fn authorize_endpoint(self) -> &'static str {
+        match self {
+            Self::GoogleDrive => "https://accounts.google.com/o/oauth2/v2/auth",
+            Self::Dropbox => "https://www.dropbox.com/oauth2/authorize",
+        }
+    }
+    fn token_endpoint(self) -> &'static str {
+        match self {
+            Self::GoogleDrive => "https://oauth2.googleapis.com/token",
+            Self::Dropbox => "https://api.dropboxapi.com/oauth2/token",
+        }
+    }
+    /// Authorization parameters asking for app-folder access and a refresh
+    /// token.
+    fn authorize_params(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::GoogleDrive => &[
+                ("scope", "https://www.googleapis.com/auth/drive.appdata"),
+                ("access_type", "offline"),
+                ("prompt", "consent"),
+            ],
+            Self::Dropbox => &[("token_access_type", "offline")],
+        }
+    }
+}
+/// A linked cloud account.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloudLink {
+    pub provider: CloudProvider,
+    refresh_token: String,
+    access_token: String,
+    /// Unix time (seconds) the access token expires at.
+    expires_at: u64,
+    /// Unix time (seconds) of the last successful upload.
+    pub last_upload: Option<u64>,
+}
+/// An authorization started by [`authorization_url`].
+#[derive(Serialize, Deserialize)]
+struct PendingAuth {
+    provider: CloudProvider,
+    verifier: String,
+    state: String,
+    redirect_uri: String,
+}
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<u64>,
+}
+/// Errors raised while linking or uploading.
+#[derive(Debug, thiserror::Error)]
+pub enum CloudError {
+    /// No cloud account is linked.
+    #[error("no cloud account linked")]
+    NotLinked,
+    /// No backup passphrase is set to encrypt the upload with.
+    #[error("set a backup passphrase first")]
+    PassphraseRequired,
+    /// The redirect does not match the authorization in progress.
+    #[error("unexpected authorization response, try linking again")]
+    StateMismatch,
+    /// The provider refused the authorization or the token.
+    #[error("authorization refused: {0}")]
+    Auth(String),
+    /// HTTP / transport failure.
+    #[error("network error: {0}")]
+    Network(String),
+    /// The random source is unavailable.
+    #[error(transparent)]
+    Crypto(#[from] crypto::CryptoError),
+    /// The backup could not be sealed.
+    #[error(transparent)]
+    Backup(#[from] backup::BackupError),
+    /// Malformed data from the provider.
+    #[error("invalid data: {0}")]
+    Format(String),
+}
+/// The linked cloud account, if any.
+#[must_use]
+pub fn linked() -> Option<CloudLink> {
+    crate::utils::get_config(CLOUD_BACKUP_KEY).and_then(|json| serde_json::from_str(&json).ok())
+}
+fn save_link(link: &CloudLink) {
+    if let Ok(json) = serde_json::to_string(link) {
+        crate::utils::set_config(CLOUD_BACKUP_KEY, &json);
+    }
+}
+/// Forget the linked cloud account (the uploaded files are kept).
+pub fn unlink() {
+    crate::utils::set_config(CLOUD_BACKUP_KEY, "");
+}
+fn random_token(len: usize) -> Result<String, CloudError> {
+    use base64::Engine as _;
+    Ok(base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(crypto::random_bytes(len)?))
+}
+/// PKCE `S256` code challenge of `verifier`.
+fn code_challenge(verifier: &str) -> String {
+    use base64::Engine as _;
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(sha2::Sha256::digest(verifier))
+}
+/// Start linking `provider`: returns the URL to send the user to, which
+/// redirects back to `redirect_uri` with the code for
+/// [`complete_authorization`].
+///
+/// # Errors
+///
+/// Fails when the provider has no client ID or without a random source.
+pub fn authorization_url(
+    provider: CloudProvider,
+    redirect_uri: &str,
+) -> Result<String, CloudError> {
+    let client_id = provider
+        .client_id()
+        .ok_or_else(|| CloudError::Auth(format!("{} is not configured", provider.name())))?;
+    let pending = PendingAuth {
+        provider,
+        verifier: random_token(VERIFIER_LEN)?,
+        state: random_token(STATE_LEN)?,
+        redirect_uri: redirect_uri.to_owned(),
+    };
+    let challenge = code_challenge(&pending.verifier);
+    let mut params = vec![
+        ("client_id", client_id),
+        ("response_type", "code"),
+        ("redirect_uri", redirect_uri),
+        ("code_challenge", &challenge),
+        ("code_challenge_method", "S256"),
+        ("state", &pending.state),
+    ];
+    params.extend_from_slice(provider.authorize_params());
+    let url = reqwest::Url::parse_with_params(provider.authorize_endpoint(), &params)
+        .map_err(|e| CloudError::Format(e.to_string()))?;
+    let json = serde_json::to_string(&pending).map_err(|e| CloudError::Format(e.to_string()))?;
+    crate::utils::set_config(CLOUD_BACKUP_PENDING_KEY, &json);
+    Ok(url.into())
+}
+/// Finish linking with the `code` and `state` the provider redirected back
+/// with, storing and returning the new [`CloudLink`].
+///
+/// # Errors
+///
+/// Fails when no authorization is in progress, `state` does not match it,
+/// or the provider refuses the code.
+pub async fn complete_authorization(code: &str, state: &str) -> Result<CloudLink, CloudError> {
+    let pending: PendingAuth = crate::utils::get_config(CLOUD_BACKUP_PENDING_KEY)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .ok_or(CloudError::StateMismatch)?;
+    crate::utils::set_config(CLOUD_BACKUP_PENDING_KEY, "");
+    if pending.state != state {
+        return Err(CloudError::StateMismatch);
+    }
+    let token = request_token(
+        pending.provider,
+        &[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("code_verifier", &pending.verifier),
+            ("redirect_uri", &pending.redirect_uri),
+        ],
+    )
+    .await?;
+    let refresh_token = token
+        .refresh_token
+        .ok_or_else(|| CloudError::Auth("no refresh token granted".into()))?;
+    let link = CloudLink {
+        provider: pending.provider,
+        refresh_token,
+        access_token: token.access_token,
+        expires_at: get_current_timestamp() + token.expires_in.unwrap_or(0),
+        last_upload: None,
+    };
+    save_link(&link);
+    Ok(link)
+}
+async fn request_token(
+    provider: CloudProvider,
+    params: &[(&str, &str)],
+) -> Result<TokenResponse, CloudError> {
+    let mut form: Vec<(&str, &str)> = params.to_vec();
+    form.extend(provider.client_id().map(|id| ("client_id", id)));
+    form.extend(provider.client_secret().map(|s| ("client_secret", s)));
+    let response = reqwest::Client::new()
+        .post(provider.token_endpoint())
+        .form(&form)
+        .send()
+        .await
+        .map_err(|e| CloudError::Network(e.to_string()))?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(CloudError::Auth(format!("HTTP {status}: {body}")));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| CloudError::Format(e.to_string()))
+}
+/// A valid access token of `link`, refreshed (and saved) when expired.
+async fn access_token(link: &mut CloudLink) -> Result<String, CloudError> {
+    if get_current_timestamp() + EXPIRY_MARGIN_SECS < link.expires_at {
+        return Ok(link.access_token.clone());
+    }
+    let refresh_token = link.refresh_token.clone();
+    let token = request_token(
+        link.provider,
+        &[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", &refresh_token),
+        ],
+    )
+    .await?;
+    link.access_token = token.access_token;
+    link.expires_at = get_current_timestamp() + token.expires_in.unwrap_or(0);
+    if let Some(rotated) = token.refresh_token {
+        link.refresh_token = rotated;
+    }
+    save_link(link);
+    Ok(link.access_token.clone())
+}
+/// Body of a Google Drive multipart upload creating `name` in the app folder.
+fn drive_multipart_body(name: &str, content: &str) -> String {
+    let metadata = serde_json::json!({ "name": name, "parents": ["appDataFolder"] });
+    format!(
+        "--{MULTIPART_BOUNDARY}\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n{metadata}\r\n\
+         --{MULTIPART_BOUNDARY}\r\nContent-Type: application/json\r\n\r\n{content}\r\n\
+         --{MULTIPART_BOUNDARY}--"
+    )
+}
+async fn check(
+    response: Result<reqwest::Response, reqwest::Error>,
+) -> Result<reqwest::Response, CloudError> {
+    let response = response.map_err(|e| CloudError::Network(e.to_string()))?;
+    if response.status().is_success() {
+        Ok(response)
+    } else {
+        Err(CloudError::Network(format!("HTTP {}", response.status())))
+    }
+}
+/// Create or overwrite the file `name` of the Google Drive app folder.
+async fn upload_to_drive(token: &str, name: &str, content: &str) -> Result<(), CloudError> {
+    #[derive(Deserialize)]
+    struct FileList {
+        files: Vec<FileId>,
+    }
+    #[derive(Deserialize)]
+    struct FileId {
+        id: String,
+    }
+    let client = reqwest::Client::new();
+    let query = format!("name = '{name}'");
+    let list: FileList = check(
+        client
+            .get(GOOGLE_FILES_API)
+            .bearer_auth(token)
+            .query(&[
+                ("spaces", "appDataFolder"),
+                ("q", &query),
+                ("fields", "files(id)"),
+            ])
+            .send()
+            .await,
+    )
+    .await?
+    .json()
+    .await
+    .map_err(|e| CloudError::Format(e.to_string()))?;
+    let request = match list.files.first() {
+        Some(file) => client
+            .patch(format!("{GOOGLE_UPLOAD_API}/{}?uploadType=media", file.id))
+            .header("Content-Type", "application/json")
+            .body(content.to_owned()),
+        None => client
+            .post(format!("{GOOGLE_UPLOAD_API}?uploadType=multipart"))
+            .header(
+                "Content-Type",
+                format!("multipart/related; boundary={MULTIPART_BOUNDARY}"),
+            )
+            .body(drive_multipart_body(name, content)),
+    };
+    check(request.bearer_auth(token).send().await).await?;
+    Ok(())
+}
+/// Create or overwrite the file `name` of the Dropbox app folder.
+async fn upload_to_dropbox(token: &str, name: &str, content: &str) -> Result<(), CloudError> {
+    let arg = serde_json::json!({ "path": format!("/{name}"), "mode": "overwrite", "mute": true });
+    check(
+        reqwest::Client::new()
+            .post(DROPBOX_UPLOAD_API)
+            .bearer_auth(token)
+            .header("Dropbox-API-Arg", arg.to_string())
+            .header("Content-Type", "application/octet-stream")
+            .body(content.to_owned())
+            .send()
+            .await,
+    )
+    .await?;
+    Ok(())
+}
+/// Names and encrypted contents of the backup files of `sessions` and
+/// `exercises`.
+fn backup_files(
+    sessions: &[WorkoutSession],
+    exercises: &[Exercise],
+    passphrase: &str,
+) -> Result<[(String, String); 2], CloudError> {
+    if passphrase.is_empty() {
+        return Err(CloudError::PassphraseRequired);
+    }
+    let format = |e: serde_json::Error| CloudError::Format(e.to_string());
+    Ok([
+        backup::backup_file(
+            SESSIONS_FILE,
+            serde_json::to_string_pretty(sessions).map_err(format)?,
+            passphrase,
+        )?,
+        backup::backup_file(
+            EXERCISES_FILE,
+            serde_json::to_string_pretty(exercises).map_err(format)?,
+            passphrase,
+        )?,
+    ])
+}
+/// Encrypt `sessions` and `exercises` with `passphrase` and upload them to
+/// the linked account, returning the upload time.
+///
+/// # Errors
+///
+/// Fails when `passphrase` is empty, no account is linked, the token cannot
+/// be refreshed or an upload fails.
+pub async fn upload_backup(
+    sessions: &[WorkoutSession],
+    exercises: &[Exercise],
+    passphrase: &str,
+) -> Result<u64, CloudError> {
+    let files = backup_files(sessions, exercises, passphrase)?;
+    let mut link = linked().ok_or(CloudError::NotLinked)?;
+    let token = access_token(&mut link).await?;
+    for (name, content) in files {
+        let name = name.as_str();
+        match link.provider {
+            CloudProvider::GoogleDrive => upload_to_drive(&token, name, &content).await?,
+            CloudProvider::Dropbox => upload_to_dropbox(&token, name, &content).await?,
+        }
+    }
+    let now = get_current_timestamp();
+    link.last_upload = Some(now);
+    save_link(&link);
+    Ok(now)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn code_challenge_is_the_unpadded_base64url_sha256() {
+        assert_eq!(
+            code_challenge("dBjftJeZ4CVP-mJ92K9d8mp6COXuCNk0y8B3BDaa1qzTo"),
+            "WwYhuarSjmEJq_t7Cfx1fT-miP39EvYiQeZILZb4888"
+        );
+        assert_eq!(random_token(VERIFIER_LEN).unwrap().len(), 43);
+    }
+    #[test]
+    fn drive_upload_body_puts_metadata_before_content() {
+        let body = drive_multipart_body("sessions.json", "[]");
+        let metadata = body.find("appDataFolder").unwrap();
+        assert!(metadata < body.find("\r\n\r\n[]").unwrap());
+        assert!(body.ends_with(&format!("--{MULTIPART_BOUNDARY}--")));
+    }
+    #[test]
+    fn refuses_to_build_a_backup_without_a_passphrase() {
+        assert!(matches!(
+            backup_files(&[], &[], ""),
+            Err(CloudError::PassphraseRequired)
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+/// Sample training history, so analytics can be explored before having one.
+///
+/// [`sample_sessions`] generates [`DEMO_WEEKS`] weeks of plausible workouts:
+/// two strength days alternating two routines, with loads slowly rising and
+/// rests lengthening late in the session, and a run on weekends.  Their IDs
+/// start with [`DEMO_ID_PREFIX`], so [`purge_sample_data`] removes exactly
+/// them whatever history was added since.
+///
+/// Loaded from the More page, or on native builds with the `--demo` flag.
+use crate::models::{Category, Distance, ExerciseLog, Weight, WorkoutSession};
+use crate::utils::{SECONDS_IN_DAY, SECONDS_IN_HOUR};
+use std::sync::atomic::{AtomicBool, Ordering};
+/// Prefix of the IDs of the sample sessions.
+pub const DEMO_ID_PREFIX: &str = "demo_";
+/// Weeks of history generated.
+pub const DEMO_WEEKS: u64 = 12;
+/// Config key set while the sample sessions are stored.
+const DEMO_LOADED_KEY: &str = "demo_loaded";
+/// Command-line flag loading the sample sessions on start (native builds).
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+const DEMO_FLAG: &str = "--demo";
+/// Offset (hours) of a workout into its day.
+const WORKOUT_HOUR: u64 = 18;
+/// Sets performed of each strength exercise.
+const SETS: u64 = 3;
+/// Seconds a strength set lasts.
+const SET_SECS: u64 = 40;
+/// Rest (seconds) after the first set of a session, growing afterwards.
+const FIRST_REST_SECS: u64 = 90;
+/// Rest (seconds) added after every set, for rests getting longer as the
+/// session goes on.
+const REST_GROWTH_SECS: u64 = 8;
+/// Pace of the weekend run, in seconds per km.
+const RUN_PACE_SECS_PER_KM: u64 = 330;
+/// Whether `--demo` was passed, see [`request_from_args`].
+static DEMO_REQUESTED: AtomicBool = AtomicBool::new(false);
+/// A strength exercise of the sample routines: free-exercise-db ID, name,
+/// starting load (kg) and weekly increase (kg).
+struct Lift(&'static str, &'static str, f64, f64);
+/// The two strength routines, alternated.
+const ROUTINES: [&[Lift]; 2] = [
+    &[
+        Lift("Barbell_Full_Squat", "Barbell Full Squat", 60.0, 2.5),
+        Lift(
+            "Barbell_Bench_Press_-_Medium_Grip",
+            "Barbell Bench Press - Medium Grip",
+            45.0,
+            1.25,
+        ),
+        Lift("Bent_Over_Barbell_Row", "Bent Over Barbell Row", 40.0, 1.25),
+    ],
+    &[
+        Lift("Barbell_Deadlift", "Barbell Deadlift", 80.0, 2.5),
+        Lift(
+            "Standing_Military_Press",
+            "Standing Military Press",
+            30.0,
+            0.5,
+        ),
+        Lift("Pullups", "Pullups", 0.0, 0.0),
+    ],
+];
+/// Day offsets into a week of the strength workouts, and of the run.
+const STRENGTH_DAYS: [u64; 2] = [0, 3];
+const RUN_DAY: u64 = 5;
+/// Deterministic noise in `0..n` for `seed` (splitmix64), so the sample
+/// history stays the same between loads.
+fn noise(seed: u64, n: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (z ^ (z >> 31)) % n.max(1)
+}
+fn log(id: &str, name: &str, category: Category, start_time: u64, secs: u64) -> ExerciseLog {
+    ExerciseLog {
+        exercise_id: id.into(),
+        exercise_name: name.into(),
+        category,
+        start_time,
+        end_time: Some(start_time + secs),
+        ..Default::default()
+    }
+}
+fn session(start_time: u64, exercise_logs: Vec<ExerciseLog>) -> WorkoutSession {
+    let mut session = WorkoutSession::new();
+    session.id = format!("{DEMO_ID_PREFIX}{start_time}");
+    session.start_time = start_time;
+    session.end_time = exercise_logs.last().and_then(|l| l.end_time);
+    session.exercise_logs = exercise_logs;
+    session
+}
+/// A strength workout of `routine`, `week` weeks into the history.
+fn strength(routine: &[Lift], week: u64, start_time: u64) -> WorkoutSession {
+    let mut logs = Vec::new();
+    let mut time = start_time;
+    for (i, Lift(id, name, base_kg, weekly_kg)) in routine.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let kg = base_kg + weekly_kg * week as f64;
+        for set in 0..SETS {
+            let seed = time ^ ((i as u64) << 8) ^ set;
+            let mut log = log(id, name, Category::Strength, time, SET_SECS);
+            log.weight_hg = Weight::from_kg(kg).unwrap_or_default();
+            // Reps drop over the sets, and now and then by one more.
+            #[allow(clippy::cast_possible_truncation)]
+            let reps = 8 - set as u32 - u32::from(noise(seed, 4) == 0);
+            log.reps = Some(reps);
+            logs.push(log);
+            let rest = FIRST_REST_SECS + REST_GROWTH_SECS * logs.len() as u64 + noise(seed, 30);
+            time += SET_SECS + rest;
+        }
+    }
+    session(start_time, logs)
+}
+/// A run, `week` weeks into the history: a little longer every week.
+fn run(week: u64, start_time: u64) -> WorkoutSession {
+    let metres = 4_000 + 250 * week + 50 * noise(start_time, 6);
+    let secs = metres * RUN_PACE_SECS_PER_KM / 1_000;
+    let mut log = log(
+        "Running,_Treadmill",
+        "Running, Treadmill",
+        Category::Cardio,
+        start_time,
+        secs,
+    );
+    log.distance_m = u32::try_from(metres).ok().map(Distance);
+    session(start_time, vec![log])
+}
+/// [`DEMO_WEEKS`] weeks of sample sessions, all finished before `now`, sorted
+/// by time.
+#[must_use]
+pub fn sample_sessions(now: u64) -> Vec<WorkoutSession> {
+    let today = now - now % SECONDS_IN_DAY;
+    let first_day = today - DEMO_WEEKS * 7 * SECONDS_IN_DAY;
+    let mut sessions = Vec::new();
+    for week in 0..DEMO_WEEKS {
+        let week_start = first_day + week * 7 * SECONDS_IN_DAY;
+        let at = |day: u64| week_start + day * SECONDS_IN_DAY + WORKOUT_HOUR * SECONDS_IN_HOUR;
+        for (i, day) in STRENGTH_DAYS.into_iter().enumerate() {
+            let routine = ROUTINES[(2 * week as usize + i) % ROUTINES.len()];
+            sessions.push(strength(routine, week, at(day)));
+        }
+        sessions.push(run(week, at(RUN_DAY)));
+    }
+    sessions.retain(|s| s.end_time.is_some_and(|end| end < now));
+    sessions
+}
+/// Whether `session` is one of the sample sessions.
+#[must_use]
+pub fn is_sample(session: &WorkoutSession) -> bool {
+    session.id.starts_with(DEMO_ID_PREFIX)
+}
+/// Whether the sample sessions are stored.
+#[must_use]
+pub fn sample_data_loaded() -> bool {
+    crate::utils::get_config(DEMO_LOADED_KEY).is_some()
+}
+/// Store the sample sessions.
+pub fn load_sample_data() {
+    for session in sample_sessions(crate::models::get_current_timestamp()) {
+        super::storage::save_session(session);
+    }
+    crate::utils::set_config(DEMO_LOADED_KEY, "1");
+}
+/// Permanently delete the sample sessions of `history` in one batch,
+/// keeping the rest (and the trash) untouched.  Returns how many were
+/// deleted.
+///
+/// # Errors
+/// If storage could not delete them.
+pub async fn purge_sample_data(
+    history: &[WorkoutSession],
+) -> Result<usize, super::storage::StorageError> {
+    let ids = history
+        .iter()
+        .filter(|s| is_sample(s))
+        .map(|s| s.id.clone())
+        .collect();
+    let purged = super::storage::delete_sessions(ids).await?;
+    crate::utils::set_config(DEMO_LOADED_KEY, "");
+    Ok(purged)
+}
+/// Remember whether the app was started with the `--demo` flag.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn request_from_args() {
+    if std::env::args().skip(1).any(|arg| arg == DEMO_FLAG) {
+        DEMO_REQUESTED.store(true, Ordering::Relaxed);
+    }
+}
+/// Load the sample sessions if the app was started with `--demo` and they
+/// are not stored yet.
+pub fn use_demo_request() {
+    use dioxus::prelude::*;
+    use_effect(|| {
+        if DEMO_REQUESTED.swap(false, Ordering::Relaxed) && !sample_data_loaded() {
+            load_sample_data();
+        }
+    });
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    /// Wednesday 2024-01-10, 12:00 UTC.
+    const NOW: u64 = 1_704_888_000;
+    #[test]
+    fn sample_sessions_span_the_past_weeks() {
+        let sessions = sample_sessions(NOW);
+        assert_eq!(sessions.len(), 3 * DEMO_WEEKS as usize);
+        assert!(sessions.iter().all(|s| is_sample(s) && !s.is_active()));
+        assert!(sessions
+            .windows(2)
+            .all(|w| w[0].end_time < Some(w[1].start_time)));
+        assert!(sessions.iter().all(|s| s.end_time < Some(NOW)));
+        assert_eq!(sample_sessions(NOW), sessions);
+        // Loads rise over the weeks.
+        let squats: Vec<u16> = sessions
+            .iter()
+            .flat_map(|s| &s.exercise_logs)
+            .filter(|l| l.exercise_id == "Barbell_Full_Squat")
+            .map(|l| l.weight_hg.0)
+            .collect();
+        assert!(squats.first() < squats.last());
+    }
+}
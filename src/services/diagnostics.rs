@@ -0,0 +1,109 @@
+/// Diagnostics for bug reports: recent log lines and storage metadata.
+///
+/// [`init_log_capture`] installs a `log` logger that keeps the last
+/// [`MAX_LOG_LINES`] records in memory (forwarding them to `tracing`, which
+/// prints them as before) and a panic hook recording panic messages too.
+/// [`diagnostics_report`] bundles those lines with the app version, platform
+/// and storage counts into a JSON document the user can attach to an issue.
+use crate::models::get_current_timestamp;
+use crate::services::storage;
+use dioxus_logger::tracing;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+/// Number of most recent log lines kept for the diagnostics report.
+pub const MAX_LOG_LINES: usize = 200;
+static LOG_LINES: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+static LOGGER: CaptureLogger = CaptureLogger;
+/// `log` logger keeping recent records and forwarding them to `tracing`.
+struct CaptureLogger;
+impl log::Log for CaptureLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let target = record.target();
+        let message = record.args();
+        match record.level() {
+            log::Level::Error => tracing::error!("{target}: {message}"),
+            log::Level::Warn => tracing::warn!("{target}: {message}"),
+            log::Level::Info => tracing::info!("{target}: {message}"),
+            log::Level::Debug => tracing::debug!("{target}: {message}"),
+            log::Level::Trace => tracing::trace!("{target}: {message}"),
+        }
+        push_line(format!("{} {target}: {message}", record.level()));
+    }
+    fn flush(&self) {}
+}
+/// Append `line` to the kept log lines, stamped with the current time,
+/// dropping the oldest beyond [`MAX_LOG_LINES`].
+fn push_line(line: String) {
+    let Ok(mut lines) = LOG_LINES.lock() else {
+        return;
+    };
+    if lines.len() >= MAX_LOG_LINES {
+        lines.pop_front();
+    }
+    lines.push_back(format!("{} {line}", get_current_timestamp()));
+}
+/// Start keeping recent `log` records and panic messages for
+/// [`diagnostics_report`].  Call once, after the `tracing` subscriber is set.
+pub fn init_log_capture() {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(log::LevelFilter::Debug);
+    }
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        push_line(format!("PANIC {info}"));
+        previous(info);
+    }));
+}
+/// Kept log lines, oldest first.
+#[must_use]
+pub fn recent_log_lines() -> Vec<String> {
+    LOG_LINES
+        .lock()
+        .map(|lines| lines.iter().cloned().collect())
+        .unwrap_or_default()
+}
+/// Pretty-printed JSON report of the app version, platform, storage counts
+/// and recent log lines, `error` being the failure that prompted it.
+pub async fn diagnostics_report(error: &str) -> String {
+    let count = |r: Result<usize, storage::StorageError>| match r {
+        Ok(n) => serde_json::json!(n),
+        Err(e) => serde_json::json!(e.to_string()),
+    };
+    let sessions = count(storage::load_session_count().await);
+    let custom_exercises = count(storage::load_custom_exercises().await.map(|e| e.len()));
+    let trash = count(storage::load_trash().await.map(|t| t.len()));
+    let report = serde_json::json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "generated_at": get_current_timestamp(),
+        "error": error,
+        "storage": {
+            "sessions": sessions,
+            "custom_exercises": custom_exercises,
+            "trash": trash,
+        },
+        "log": recent_log_lines(),
+    });
+    serde_json::to_string_pretty(&report).unwrap_or_default()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn log_lines_are_capped_oldest_first() {
+        for i in 0..=MAX_LOG_LINES {
+            push_line(format!("line {i}"));
+        }
+        let lines = recent_log_lines();
+        assert_eq!(lines.len(), MAX_LOG_LINES);
+        assert!(lines[0].ends_with(" line 1"));
+        assert!(lines[MAX_LOG_LINES - 1].ends_with(&format!(" line {MAX_LOG_LINES}")));
+    }
+}
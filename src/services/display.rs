@@ -0,0 +1,77 @@
+/// Display preferences: text and control scale, larger touch targets and
+/// one-handed layout of the session view.
+///
+/// They are applied to the document root, the scale as the `--ui-scale` CSS
+/// variable (the root font size and the button size derive from it) and the
+/// others as the `large-targets` and `one-handed` classes.
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+/// Size of the text and controls.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    strum::EnumIter,
+    strum::AsRefStr,
+    strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum UiScale {
+    #[default]
+    Normal,
+    Large,
+    ExtraLarge,
+}
+impl UiScale {
+    /// Factor applied to the default sizes.
+    #[must_use]
+    pub fn factor(self) -> f32 {
+        match self {
+            Self::Normal => 1.0,
+            Self::Large => 1.2,
+            Self::ExtraLarge => 1.4,
+        }
+    }
+}
+/// Display preferences, stored as one JSON setting.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplayPreferences {
+    #[serde(default)]
+    pub scale: UiScale,
+    /// Bigger buttons in the session view, the complete button widest.
+    #[serde(default)]
+    pub large_targets: bool,
+    /// Session controls and the complete button moved within thumb reach,
+    /// at the bottom of the screen, and enlarged.
+    #[serde(default)]
+    pub one_handed: bool,
+}
+/// Apply `prefs` to the document.
+pub fn apply(prefs: &DisplayPreferences) {
+    document::eval(&format!(
+        "var r=document.documentElement;\
+         r.style.setProperty('--ui-scale','{}');\
+         r.classList.toggle('large-targets',{});\
+         r.classList.toggle('one-handed',{});",
+        prefs.scale.factor(),
+        prefs.large_targets,
+        prefs.one_handed,
+    ));
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn missing_fields_keep_the_defaults() {
+        let prefs: DisplayPreferences = serde_json::from_str(r#"{"scale":"extra-large"}"#).unwrap();
+        assert_eq!(prefs.scale, UiScale::ExtraLarge);
+        assert!(!prefs.large_targets && !prefs.one_handed);
+        assert_eq!("large".parse::<UiScale>().unwrap().factor(), 1.2);
+    }
+}
@@ -0,0 +1,80 @@
+/// Unsaved form content (custom exercise being added or edited, notes being
+/// written) kept across navigation and reloads.
+///
+/// Every draft lives under a key naming its form, e.g. `exercise:new` or
+/// `session-notes:<session id>`, in a single JSON setting.  A form saves its
+/// draft as it is edited, restores it when reopened and clears it once its
+/// content is saved or discarded.
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+/// Setting holding the drafts.
+const DRAFTS_KEY: &str = "drafts";
+/// Drafts by form key.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct Drafts(BTreeMap<String, serde_json::Value>);
+impl Drafts {
+    fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.0
+            .get(key)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+    fn set<T: Serialize>(&mut self, key: &str, value: &T) {
+        if let Ok(v) = serde_json::to_value(value) {
+            self.0.insert(key.to_owned(), v);
+        }
+    }
+    fn remove(&mut self, key: &str) -> bool {
+        self.0.remove(key).is_some()
+    }
+}
+fn read() -> Drafts {
+    crate::utils::get_config(DRAFTS_KEY)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+fn write(drafts: &Drafts) {
+    let json = if drafts.0.is_empty() {
+        String::new()
+    } else {
+        serde_json::to_string(drafts).unwrap_or_default()
+    };
+    crate::utils::set_config(DRAFTS_KEY, &json);
+}
+/// Draft of the form `key`, if one is kept and still readable as `T`.
+#[must_use]
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+    read().get(key)
+}
+/// Keep `value` as the draft of the form `key`.
+pub fn save<T: Serialize>(key: &str, value: &T) {
+    let mut drafts = read();
+    drafts.set(key, value);
+    write(&drafts);
+}
+/// Forget the draft of the form `key`.
+pub fn clear(key: &str) {
+    let mut drafts = read();
+    if drafts.remove(key) {
+        write(&drafts);
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn drafts_round_trip_by_key() {
+        let mut drafts = Drafts::default();
+        drafts.set("session-notes:1", &"Felt strong".to_owned());
+        drafts.set("exercise:new", &vec![1, 2]);
+        assert_eq!(
+            drafts.get::<String>("session-notes:1").as_deref(),
+            Some("Felt strong")
+        );
+        assert_eq!(drafts.get::<String>("exercise:new"), None);
+        assert!(drafts.remove("exercise:new"));
+        assert!(!drafts.remove("exercise:new"));
+        let json = serde_json::to_string(&drafts).unwrap();
+        assert_eq!(serde_json::from_str::<Drafts>(&json).unwrap(), drafts);
+    }
+}
@@ -1,5 +1,7 @@
+pub use crate::models::resolve_exercise;
 use crate::models::{
-    Category, DbI18n, Equipment, Exercise, ExerciseI18n, ExerciseLangEntry, Force, Level, Muscle,
+    Category, CustomTag, CustomTaxonomy, DbI18n, Equipment, Exercise, ExerciseI18n,
+    ExerciseLangEntry, ExerciseOverrides, Force, Level, Muscle, TaxonomyKind,
 };
 use dioxus::prelude::*;
 use std::sync::Arc;
@@ -14,6 +16,12 @@ pub(crate) struct AllExercisesSignal(pub(crate) Signal<Vec<Arc<Exercise>>>);
 /// Storage key used to persist the `ETag` returned by the last successful
 /// `exercises.json` download (localStorage on WASM, config on native).
 const EXERCISES_ETAG_KEY: &str = "exercise_db_etag";
+/// Config key storing when `exercises.json` was last fetched (Unix seconds),
+/// downloaded or confirmed unchanged.
+const LAST_FETCH_KEY: &str = "exercise_db_last_fetch";
+/// Days after the last fetch from which the exercise database is flagged as
+/// due for a refresh.
+pub const REFRESH_INTERVAL_DAYS: u64 = 30;
 /// Language codes for which per-exercise translation files are fetched and
 /// merged into the exercise database on download.
 const SUPPORTED_TRANSLATION_LANGS: &[&str] = &["fr"];
@@ -23,10 +31,10 @@ fn exercises_json_url() -> String {
     let base_url = crate::utils::get_exercise_db_url();
     format!("{base_url}exercises.json")
 }
-/// Returns the URL for a per-language exercise translation file.
-/// For example, `exercises_lang_json_url("fr")` returns the URL for `exercises.fr.json`.
-fn exercises_lang_json_url(lang: &str) -> String {
-    let base_url = crate::utils::get_exercise_db_url();
+/// Returns the URL for a per-language exercise translation file of the
+/// database at `base_url`.
+/// For example, `exercises_lang_json_url(base, "fr")` returns the URL for `exercises.fr.json`.
+fn exercises_lang_json_url(base_url: &str, lang: &str) -> String {
     format!("{base_url}exercises.{lang}.json")
 }
 /// Returns the URL for the enum-translation file (`i18n.json`).
@@ -34,10 +42,16 @@ fn db_i18n_url() -> String {
     let base_url = crate::utils::get_exercise_db_url();
     format!("{base_url}i18n.json")
 }
+/// Hydrate the exercises outside of a component wanting them (deep links).
+#[cfg(target_arch = "wasm32")]
+pub use crate::services::exercise_loader::request_exercise_hydration;
 /// Provide the exercises signal in the Dioxus context.
-/// Loads from the local cache on startup; never auto-downloads.
+/// Hydrates it from the local cache once wanted; never auto-downloads.
 /// If the cache is empty a toast is shown inviting the user to download.
-pub use crate::services::exercise_loader::{provide_exercises, reload_exercises, use_exercises};
+pub use crate::services::exercise_loader::{
+    provide_exercises, reload_exercises, use_exercises, use_hydrated_exercises, DbDownload,
+    MAX_DOWNLOAD_ATTEMPTS,
+};
 /// Clears the stored `ETag` so that the next download fetches fresh data
 /// regardless of whether the server considers the content unchanged.
 /// Call this when the database URL changes.
@@ -59,6 +73,23 @@ pub fn clear_fetch_cache() {
     use crate::services::storage::native_storage;
     let _ = native_storage::remove_config_value(EXERCISES_ETAG_KEY);
 }
+/// When `exercises.json` was last fetched, see [`LAST_FETCH_KEY`].
+#[must_use]
+pub fn last_fetch() -> Option<u64> {
+    crate::utils::get_config(LAST_FETCH_KEY)?.parse().ok()
+}
+/// Whether the exercise database, last fetched at `last_fetch`, is due for a
+/// refresh at `now`: never fetched, or over [`REFRESH_INTERVAL_DAYS`] ago.
+#[must_use]
+pub fn is_refresh_due(last_fetch: Option<u64>, now: u64) -> bool {
+    last_fetch.is_none_or(|at| {
+        now.saturating_sub(at) > REFRESH_INTERVAL_DAYS * crate::utils::SECONDS_IN_DAY
+    })
+}
+fn record_fetch() {
+    let now = crate::models::get_current_timestamp();
+    crate::utils::set_config(LAST_FETCH_KEY, &now.to_string());
+}
 /// Returns the stored `ETag` for `exercises.json`, if any.
 #[cfg(target_arch = "wasm32")]
 fn get_stored_etag() -> Option<String> {
@@ -101,9 +132,15 @@ fn store_etag(etag: &str) {
 /// `ETag` (if provided) is persisted for the next request, and the parsed
 /// exercise list is returned as `Ok(Some(exercises))`.
 ///
+/// The body is streamed, `on_progress` being called with the bytes received
+/// so far and the total size when the server sends it.
+///
 /// Works on all platforms: reqwest uses the browser's `fetch` on WASM and
 /// native TLS on Android / desktop.
-pub(crate) async fn download_exercises() -> Result<Option<Vec<Exercise>>, String> {
+pub(crate) async fn download_exercises(
+    mut on_progress: impl FnMut(u64, Option<u64>),
+) -> Result<Option<Vec<Exercise>>, String> {
+    use futures_util::StreamExt;
     let url = exercises_json_url();
     let mut request = reqwest::Client::new().get(&url);
     if let Some(etag) = get_stored_etag() {
@@ -115,6 +152,7 @@ pub(crate) async fn download_exercises() -> Result<Option<Vec<Exercise>>, String
         .map_err(|e| format!("HTTP error: {e}"))?;
     if response.status() == reqwest::StatusCode::NOT_MODIFIED {
         log::info!("exercises.json is up to date (304 Not Modified)");
+        record_fetch();
         return Ok(None);
     }
     if !response.status().is_success() {
@@ -128,22 +166,115 @@ pub(crate) async fn download_exercises() -> Result<Option<Vec<Exercise>>, String
     {
         store_etag(etag);
     }
+    let total = response.content_length();
+    let mut body = Vec::new();
+    let mut chunks = response.bytes_stream();
+    while let Some(chunk) = chunks.next().await {
+        body.extend_from_slice(&chunk.map_err(|e| format!("HTTP error: {e}"))?);
+        on_progress(body.len() as u64, total);
+    }
+    let mut exercises: Vec<Exercise> =
+        serde_json::from_slice(&body).map_err(|e| format!("JSON parse error: {e}"))?;
+    let base_url = crate::utils::get_exercise_db_url();
+    merge_translations(&mut exercises, &base_url).await;
+    for extra_url in crate::utils::get_extra_exercise_db_urls() {
+        match download_extra_source(&extra_url).await {
+            Ok(extra) => exercises.extend(extra),
+            Err(e) => log::warn!("Skipping exercise source {extra_url}: {e}"),
+        }
+    }
+    merge_language_pack(&mut exercises).await;
+    record_fetch();
+    Ok(Some(exercises))
+}
+/// Fetches the available translations of the database at `base_url` into
+/// `exercises`.
+async fn merge_translations(exercises: &mut [Exercise], base_url: &str) {
+    for lang in SUPPORTED_TRANSLATION_LANGS {
+        if let Ok(entries) = download_exercise_lang(base_url, lang).await {
+            merge_lang_entries(exercises, lang, &entries);
+        }
+    }
+}
+/// Fetches the configured language pack, if any, into `exercises`: one
+/// `exercises.<lang>.json` file per app language (see
+/// [`crate::SUPPORTED_LANGUAGES`]), overriding the translations of the
+/// databases.  All languages are fetched so that switching the app language
+/// takes effect offline.
+async fn merge_language_pack(exercises: &mut [Exercise]) {
+    let Some(base_url) = crate::utils::get_language_pack_url() else {
+        return;
+    };
+    for (lang, _) in crate::SUPPORTED_LANGUAGES {
+        match download_exercise_lang(&base_url, lang).await {
+            Ok(entries) => merge_lang_entries(exercises, lang, &entries),
+            Err(e) => log::warn!("Skipping {lang} language pack from {base_url}: {e}"),
+        }
+    }
+}
+/// Downloads the exercises of an additional database at `base_url`, with
+/// their translations, namespaced by [`namespace_exercises`].
+///
+/// No `ETag` is kept for additional sources: they are fetched again whenever
+/// the main database is.
+async fn download_extra_source(base_url: &str) -> Result<Vec<Exercise>, String> {
+    let response = reqwest::get(format!("{base_url}exercises.json"))
+        .await
+        .map_err(|e| format!("HTTP error: {e}"))?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
     let mut exercises: Vec<Exercise> = response
         .json()
         .await
         .map_err(|e| format!("JSON parse error: {e}"))?;
-    for lang in SUPPORTED_TRANSLATION_LANGS {
-        if let Ok(entries) = download_exercise_lang(lang).await {
-            merge_lang_entries(&mut exercises, lang, &entries);
+    merge_translations(&mut exercises, base_url).await;
+    namespace_exercises(&mut exercises, base_url);
+    Ok(exercises)
+}
+/// Short identifier of the database at `base_url`: its host and path, with
+/// any character other than ASCII letters, digits, `.` or `-` replaced by `-`.
+#[must_use]
+pub fn source_namespace(base_url: &str) -> String {
+    let without_scheme = base_url
+        .split_once("://")
+        .map_or(base_url, |(_, rest)| rest);
+    without_scheme
+        .trim_end_matches('/')
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+/// Prefixes the IDs of `exercises`, coming from the additional database at
+/// `base_url`, with its [`source_namespace`] so they cannot collide with those
+/// of the main database, and makes their relative image paths absolute since
+/// images are otherwise looked up in the main database.
+fn namespace_exercises(exercises: &mut [Exercise], base_url: &str) {
+    use crate::models::EXERCISES_IMAGE_SUB_PATH;
+    let namespace = source_namespace(base_url);
+    for exercise in exercises.iter_mut() {
+        exercise.id = format!("{namespace}:{}", exercise.id);
+        for image in &mut exercise.images {
+            if !image.contains("://") && !image.starts_with('/') {
+                *image = format!("{base_url}{EXERCISES_IMAGE_SUB_PATH}{image}");
+            }
         }
     }
-    Ok(Some(exercises))
 }
 /// Downloads a per-language exercise translation file (e.g. `exercises.fr.json`)
-/// and returns the parsed entries.  Returns `Ok(vec![])` on HTTP 404 so the
-/// caller can safely ignore missing languages.
-async fn download_exercise_lang(lang: &str) -> Result<Vec<ExerciseLangEntry>, String> {
-    let url = exercises_lang_json_url(lang);
+/// of the database at `base_url` and returns the parsed entries.  Returns
+/// `Ok(vec![])` on HTTP 404 so the caller can safely ignore missing languages.
+async fn download_exercise_lang(
+    base_url: &str,
+    lang: &str,
+) -> Result<Vec<ExerciseLangEntry>, String> {
+    let url = exercises_lang_json_url(base_url, lang);
     let response = reqwest::get(&url)
         .await
         .map_err(|e| format!("HTTP error fetching {lang} lang file: {e}"))?;
@@ -163,7 +294,8 @@ async fn download_exercise_lang(lang: &str) -> Result<Vec<ExerciseLangEntry>, St
 }
 /// Merges a slice of [`ExerciseLangEntry`] values into the in-memory exercise
 /// list by matching on `id`.  Each entry's `name` and `instructions` are
-/// inserted into the exercise's `i18n` map under the given language code.
+/// inserted into the exercise's `i18n` map under the given language code,
+/// keeping any translated field the entry lacks.
 fn merge_lang_entries(exercises: &mut [Exercise], lang: &str, entries: &[ExerciseLangEntry]) {
     use std::collections::HashMap;
     let entry_map: HashMap<&str, &ExerciseLangEntry> =
@@ -172,11 +304,19 @@ fn merge_lang_entries(exercises: &mut [Exercise], lang: &str, entries: &[Exercis
         if let Some(entry) = entry_map.get(exercise.id.as_str()) {
             if entry.name.is_some() || entry.instructions.is_some() {
                 let map = exercise.i18n.get_or_insert_with(HashMap::new);
+                let previous = map.remove(lang);
+                let previous = previous.as_ref();
                 map.insert(
                     lang.to_owned(),
                     ExerciseI18n {
-                        name: entry.name.clone(),
-                        instructions: entry.instructions.clone(),
+                        name: entry
+                            .name
+                            .clone()
+                            .or_else(|| previous.and_then(|p| p.name.clone())),
+                        instructions: entry
+                            .instructions
+                            .clone()
+                            .or_else(|| previous.and_then(|p| p.instructions.clone())),
                     },
                 );
             }
@@ -396,8 +536,47 @@ fn score_name_str(name_lc: &str, query_lower: &str, query_norm: &str, tokens: &[
     }
     0
 }
+/// What a search query matched in an exercise, to tell the user why it is
+/// listed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchField {
+    /// Its name, in English or in the user's language.
+    Name,
+    /// One of the personal aliases the user gave it.
+    Alias(String),
+    /// Its name in another language.
+    OtherLanguage(String),
+    /// One of its muscles (native full-text search only).
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    Muscle(Muscle),
+    /// Its instructions (native full-text search only).
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    Instructions,
+}
+/// A search result: the exercise and what the query matched in it.
+#[derive(Debug, PartialEq)]
+pub struct SearchMatch<'a, E> {
+    pub exercise: &'a E,
+    pub field: MatchField,
+    /// Relevance of the match, higher first.
+    pub score: u32,
+}
+/// Byte range of `query` within `text`, ignoring case, to highlight it.
+/// `None` when `text` only matches accent- or word-order-insensitively.
+#[must_use]
+pub fn highlight_range(text: &str, query: &str) -> Option<std::ops::Range<usize>> {
+    let query = query.trim().to_lowercase();
+    let text_lower = text.to_lowercase();
+    // Lowercasing may change byte lengths (e.g. 'İ'): offsets would be off.
+    if query.is_empty() || text_lower.len() != text.len() {
+        return None;
+    }
+    let start = text_lower.find(&query)?;
+    Some(start..start + query.len())
+}
 /// Computes a relevance score for `exercise` against the pre-computed query
-/// components.  Returns 0 if the exercise does not match the query at all.
+/// components, with what matched.  Returns a 0 score if the exercise does not
+/// match the query at all.
 /// Only the exercise title (English and all available localized names) is
 /// searched; attribute filtering is handled exclusively by hard filters.
 ///
@@ -408,11 +587,12 @@ fn score_name_str(name_lc: &str, query_lower: &str, query_norm: &str, tokens: &[
 /// `SCORE_I18N_NAME` tier.
 fn score_exercise(
     exercise: &Exercise,
+    aliases: &[String],
     query_lower: &str,
     query_norm: &str,
     tokens: &[String],
     lang: &str,
-) -> u32 {
+) -> (u32, MatchField) {
     let computed_name_lower;
     let name_lc: &str = if exercise.name_lower.is_empty() {
         computed_name_lower = exercise.name.to_lowercase();
@@ -421,7 +601,10 @@ fn score_exercise(
         &exercise.name_lower
     };
     // Score the default (English) name.
-    let mut best = score_name_str(name_lc, query_lower, query_norm, tokens);
+    let mut best = (
+        score_name_str(name_lc, query_lower, query_norm, tokens),
+        MatchField::Name,
+    );
     // Score the localized name for the user's language with the same full
     // tiers, so a "Pompe" search in French can rank as highly as an exact
     // English name match.
@@ -430,24 +613,36 @@ fn score_exercise(
         // Only re-score when the translation actually differs from the default.
         if loc_name != exercise.name {
             let loc_lc = loc_name.to_lowercase();
-            best = best.max(score_name_str(&loc_lc, query_lower, query_norm, tokens));
+            let score = score_name_str(&loc_lc, query_lower, query_norm, tokens);
+            if score > best.0 {
+                best = (score, MatchField::Name);
+            }
+        }
+    }
+    // The user's own aliases are names they chose: same full tiers.
+    for alias in aliases {
+        let alias_lc = alias.to_lowercase();
+        let score = score_name_str(&alias_lc, query_lower, query_norm, tokens);
+        if score > best.0 {
+            best = (score, MatchField::Alias(alias.clone()));
         }
     }
-    if best > 0 {
+    if best.0 > 0 {
         return best;
     }
     // Fall back: any i18n name match (other languages) earns a lower score.
-    if exercise.i18n.as_ref().is_some_and(|map| {
-        map.values().any(|i18n| {
-            i18n.name.as_deref().is_some_and(|n| {
+    let other_name = exercise.i18n.as_ref().and_then(|map| {
+        map.values().find_map(|i18n| {
+            i18n.name.as_deref().filter(|n| {
                 let n_lc = n.to_lowercase();
                 name_lc_matches(&n_lc, query_lower, query_norm, tokens)
             })
         })
-    }) {
-        return SCORE_I18N_NAME;
+    });
+    match other_name {
+        Some(name) => (SCORE_I18N_NAME, MatchField::OtherLanguage(name.to_owned())),
+        None => (0, MatchField::Name),
     }
-    0
 }
 /// Search exercises by title (English name and all available localized names).
 ///
@@ -464,9 +659,20 @@ fn score_exercise(
 /// with the same full tier set as the English name.  Pass `""` to disable
 /// language-aware scoring (e.g. in unit tests that only exercise English names).
 ///
+/// The personal aliases of each exercise in `overrides` are scored like its
+/// own name, so nicknames such as "bench" find long database names.
+///
+/// Each result tells what the query matched (see [`MatchField`]), to
+/// highlight it and explain the less obvious ones.
+///
 /// Works with any element type that dereferences to [`Exercise`] (e.g. plain
 /// `Exercise` in tests, `Arc<Exercise>` in production signals).
-pub fn search_exercises<'a, E>(exercises: &'a [E], query: &str, lang: &str) -> Vec<&'a E>
+pub fn search_matches<'a, E>(
+    exercises: &'a [E],
+    query: &str,
+    lang: &str,
+    overrides: &ExerciseOverrides,
+) -> Vec<SearchMatch<'a, E>>
 where
     E: AsRef<Exercise>,
 {
@@ -477,19 +683,46 @@ where
         .map(normalize_for_search)
         .filter(|t| t.chars().any(char::is_alphanumeric))
         .collect();
-    let mut scored: Vec<(u32, &E)> = exercises
+    let mut matches: Vec<SearchMatch<'a, E>> = exercises
         .iter()
         .filter_map(|exercise| {
-            let score = score_exercise(exercise.as_ref(), &query_lower, &query_norm, &tokens, lang);
-            if score > 0 {
-                Some((score, exercise))
-            } else {
-                None
-            }
+            let exercise_ref = exercise.as_ref();
+            let aliases = overrides
+                .get(&exercise_ref.id)
+                .map_or(&[][..], |o| o.aliases.as_slice());
+            let (score, field) = score_exercise(
+                exercise_ref,
+                aliases,
+                &query_lower,
+                &query_norm,
+                &tokens,
+                lang,
+            );
+            (score > 0).then_some(SearchMatch {
+                exercise,
+                field,
+                score,
+            })
         })
         .collect();
-    scored.sort_by(|a, b| b.0.cmp(&a.0));
-    scored.into_iter().map(|(_, ex)| ex).collect()
+    matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+    matches
+}
+/// [`search_matches`] without what matched.
+#[cfg(test)]
+pub fn search_exercises<'a, E>(
+    exercises: &'a [E],
+    query: &str,
+    lang: &str,
+    overrides: &ExerciseOverrides,
+) -> Vec<&'a E>
+where
+    E: AsRef<Exercise>,
+{
+    search_matches(exercises, query, lang, overrides)
+        .into_iter()
+        .map(|m| m.exercise)
+        .collect()
 }
 /// A hard filter that restricts the exercise list to a specific attribute value.
 ///
@@ -506,6 +739,9 @@ pub enum SearchFilter {
     Level(Level),
     /// Matches exercises where `muscle` is either a primary or secondary muscle.
     Muscle(Muscle),
+    /// Matches exercises the user gave this custom tag.  It is grouped with
+    /// the built-in filters of the same kind.
+    Custom(CustomTag),
 }
 impl SearchFilter {
     /// Human-readable label for display in the UI (e.g. "💪 strength").
@@ -516,15 +752,41 @@ impl SearchFilter {
             Self::Equipment(e) => format!("🔧 {e}"),
             Self::Level(l) => format!("📊 {l}"),
             Self::Muscle(m) => format!("💪 {m}"),
+            Self::Custom(tag) => tag.display(),
+        }
+    }
+    /// Attribute filtered on, custom tags sharing the group of the built-in
+    /// attribute they extend.
+    fn group(&self) -> usize {
+        match self {
+            Self::Category(_)
+            | Self::Custom(CustomTag {
+                kind: TaxonomyKind::Category,
+                ..
+            }) => 0,
+            Self::Force(_) => 1,
+            Self::Equipment(_)
+            | Self::Custom(CustomTag {
+                kind: TaxonomyKind::Equipment,
+                ..
+            }) => 2,
+            Self::Level(_) => 3,
+            Self::Muscle(_)
+            | Self::Custom(CustomTag {
+                kind: TaxonomyKind::Muscle,
+                ..
+            }) => 4,
         }
     }
-    /// Returns true if `self` and `other` are the same variant (regardless of
-    /// the inner value).  Used to group contradictory filters into OR unions.
+    /// Returns true if `self` and `other` filter on the same attribute
+    /// (regardless of the value).  Used to group contradictory filters into
+    /// OR unions.
     pub fn same_kind(&self, other: &SearchFilter) -> bool {
-        std::mem::discriminant(self) == std::mem::discriminant(other)
+        self.group() == other.group()
     }
-    /// Returns true when `exercise` satisfies this individual filter.
-    fn matches(&self, exercise: &Exercise) -> bool {
+    /// Returns true when `exercise`, with the personal `overrides`, satisfies
+    /// this individual filter.
+    fn matches(&self, exercise: &Exercise, overrides: &ExerciseOverrides) -> bool {
         match self {
             Self::Category(c) => &exercise.category == c,
             Self::Force(f) => exercise.force.as_ref() == Some(f),
@@ -533,13 +795,21 @@ impl SearchFilter {
             Self::Muscle(m) => {
                 exercise.primary_muscles.contains(m) || exercise.secondary_muscles.contains(m)
             }
+            Self::Custom(tag) => overrides
+                .get(&exercise.id)
+                .is_some_and(|o| o.tags.contains(tag)),
         }
     }
 }
-/// Returns true when `exercise` passes **all** active filters.
+/// Returns true when `exercise`, with the personal `overrides` holding its
+/// custom tags, passes **all** active filters.
 ///
-/// Filters of the same variant form an OR group; OR groups are `AND`ed together.
-pub fn exercise_matches_filters(exercise: &Exercise, filters: &[SearchFilter]) -> bool {
+/// Filters of the same kind form an OR group; OR groups are `AND`ed together.
+pub fn exercise_matches_filters(
+    exercise: &Exercise,
+    filters: &[SearchFilter],
+    overrides: &ExerciseOverrides,
+) -> bool {
     if filters.is_empty() {
         return true;
     }
@@ -552,7 +822,7 @@ pub fn exercise_matches_filters(exercise: &Exercise, filters: &[SearchFilter]) -
         let group_ok = filters
             .iter()
             .filter(|f| f.same_kind(filter))
-            .any(|f| f.matches(exercise));
+            .any(|f| f.matches(exercise, overrides));
         if !group_ok {
             return false;
         }
@@ -563,8 +833,8 @@ pub fn exercise_matches_filters(exercise: &Exercise, filters: &[SearchFilter]) -
 /// value.  A suggestion is emitted when the query **exactly equals** (case-
 /// insensitive) or starts with a known attribute value (or vice-versa) so that
 /// typing "card", "cardio", or "CARDIO" all suggest the `Category::Cardio`
-/// filter.
-pub fn detect_filter_suggestions(query: &str) -> Vec<SearchFilter> {
+/// filter.  The custom tags of `taxonomy` are suggested the same way.
+pub fn detect_filter_suggestions(query: &str, taxonomy: &CustomTaxonomy) -> Vec<SearchFilter> {
     use strum::IntoEnumIterator;
     let q = query.to_lowercase();
     if q.len() < 2 {
@@ -601,29 +871,30 @@ pub fn detect_filter_suggestions(query: &str) -> Vec<SearchFilter> {
             suggestions.push(SearchFilter::Muscle(muscle));
         }
     }
+    for tag in &taxonomy.tags {
+        let val = tag.label.to_lowercase();
+        if val.contains(&q) || q.contains(&val) {
+            suggestions.push(SearchFilter::Custom(tag.clone()));
+        }
+    }
     suggestions
 }
-/// Looks up an exercise by ID in a slice.
-///
-/// Works with any element type that dereferences to [`Exercise`] (e.g. plain
-/// `Exercise` in tests, `Arc<Exercise>` in production signals).
-pub fn get_exercise_by_id<'a, E>(exercises: &'a [E], id: &str) -> Option<&'a E>
+/// Preferred rest (seconds) after a set of exercise `id`: the personal one of
+/// `overrides`, otherwise the exercise's own (see [`Exercise::rest_with`]).
+pub fn preferred_rest<E>(
+    db: &[E],
+    custom: &[E],
+    overrides: &ExerciseOverrides,
+    id: &str,
+) -> Option<u64>
 where
     E: AsRef<Exercise>,
 {
-    exercises.iter().find(|e| e.as_ref().id == id)
-}
-/// Resolves an exercise by ID: checks the main DB slice first, then falls back
-/// to the custom-exercises slice.  Centralises the lookup logic used across
-/// multiple components.
-///
-/// Works with any element type that dereferences to [`Exercise`] (e.g. plain
-/// `Exercise` in tests, `Arc<Exercise>` in production signals).
-pub fn resolve_exercise<'a, E>(db: &'a [E], custom: &'a [E], id: &str) -> Option<&'a E>
-where
-    E: AsRef<Exercise>,
-{
-    get_exercise_by_id(db, id).or_else(|| get_exercise_by_id(custom, id))
+    let personal = overrides.get(id);
+    resolve_exercise(db, custom, id).map_or_else(
+        || personal.and_then(|p| p.rest_seconds),
+        |e| e.as_ref().rest_with(personal),
+    )
 }
 #[cfg(test)]
 pub fn get_equipment_types(exercises: &[Exercise]) -> Vec<Equipment> {
@@ -645,7 +916,18 @@ pub fn get_muscle_groups(exercises: &[Exercise]) -> Vec<Muscle> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Category, Equipment, Force, Level, Muscle};
+    use crate::models::{get_exercise_by_id, Category, Equipment, Force, Level, Muscle};
+    #[test]
+    fn refresh_is_due_when_never_fetched_or_stale() {
+        let day = crate::utils::SECONDS_IN_DAY;
+        let now = 100 * day;
+        assert!(is_refresh_due(None, now));
+        assert!(!is_refresh_due(Some(now - day), now));
+        assert!(is_refresh_due(
+            Some(now - (REFRESH_INTERVAL_DAYS + 1) * day),
+            now
+        ));
+    }
     fn sample_exercises() -> Vec<Exercise> {
         vec![
             Exercise {
@@ -661,7 +943,9 @@ mod tests {
                 instructions: vec![],
                 category: Category::Strength,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             }
             .with_lowercase(),
             Exercise {
@@ -677,7 +961,9 @@ mod tests {
                 instructions: vec![],
                 category: Category::Strength,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             }
             .with_lowercase(),
             Exercise {
@@ -693,7 +979,9 @@ mod tests {
                 instructions: vec![],
                 category: Category::Cardio,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             }
             .with_lowercase(),
         ]
@@ -701,70 +989,93 @@ mod tests {
     #[test]
     fn search_by_name() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "bench", "");
+        let results = search_exercises(&exercises, "bench", "", &ExerciseOverrides::new());
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].id, "bench_press");
     }
     #[test]
+    fn search_matches_personal_aliases() {
+        let exercises = sample_exercises();
+        let mut overrides = ExerciseOverrides::new();
+        overrides.entry("pull_up".into()).or_default().aliases = vec!["Chin".into()];
+        let results = search_exercises(&exercises, "chin", "", &overrides);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "pull_up");
+        assert!(search_exercises(&exercises, "chin", "", &ExerciseOverrides::new()).is_empty());
+    }
+    #[test]
+    fn search_tells_what_matched_and_where() {
+        let exercises = sample_exercises();
+        let mut overrides = ExerciseOverrides::new();
+        overrides.entry("pull_up".into()).or_default().aliases = vec!["Chin".into()];
+        let pull_up = search_matches(&exercises, "chin", "", &overrides);
+        assert_eq!(pull_up[0].field, MatchField::Alias("Chin".into()));
+        let bench = search_matches(&exercises, "bench", "", &overrides);
+        assert_eq!(bench[0].field, MatchField::Name);
+        assert_eq!(highlight_range("Bench Press", "bench "), Some(0..5));
+        assert_eq!(highlight_range("Bench Press", "press"), Some(6..11));
+        assert_eq!(highlight_range("Bench Press", "press bench"), None);
+    }
+    #[test]
     fn search_by_muscle_returns_empty() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "lats", "");
+        let results = search_exercises(&exercises, "lats", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
     fn search_by_category_returns_empty() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "cardio", "");
+        let results = search_exercises(&exercises, "cardio", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
     fn search_by_force_returns_empty() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "push", "");
+        let results = search_exercises(&exercises, "push", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
     fn search_by_equipment_returns_empty() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "barbell", "");
+        let results = search_exercises(&exercises, "barbell", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
     fn search_by_level_returns_empty() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "beginner", "");
+        let results = search_exercises(&exercises, "beginner", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
     fn search_case_insensitive() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "BENCH", "");
+        let results = search_exercises(&exercises, "BENCH", "", &ExerciseOverrides::new());
         assert_eq!(results.len(), 1);
     }
     #[test]
     fn search_no_match() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "zzz_no_match", "");
+        let results = search_exercises(&exercises, "zzz_no_match", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
     fn search_hyphenated_query_finds_unhyphenated_name() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "pull-up", "");
+        let results = search_exercises(&exercises, "pull-up", "", &ExerciseOverrides::new());
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].id, "pull_up");
     }
     #[test]
     fn search_plain_query_finds_hyphenated_name() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "pullup", "");
+        let results = search_exercises(&exercises, "pullup", "", &ExerciseOverrides::new());
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].id, "pull_up");
     }
     #[test]
     fn search_pluralised_query_finds_exercise() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "bench press", "");
+        let results = search_exercises(&exercises, "bench press", "", &ExerciseOverrides::new());
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].id, "bench_press");
     }
@@ -783,10 +1094,13 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }
         .with_lowercase()];
-        let results = search_exercises(&exercises, "wide grip bench", "");
+        let results =
+            search_exercises(&exercises, "wide grip bench", "", &ExerciseOverrides::new());
         assert_eq!(
             results.len(),
             1,
@@ -809,10 +1123,12 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }
         .with_lowercase()];
-        let results = search_exercises(&exercises, "… pushups", "");
+        let results = search_exercises(&exercises, "… pushups", "", &ExerciseOverrides::new());
         assert_eq!(results.len(), 1, "punctuation-only token should be ignored");
         assert_eq!(results[0].id, "pushups");
     }
@@ -857,11 +1173,18 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: Some(i18n_map),
+            rest_seconds: None,
         }
         .with_lowercase()];
         // Searching without accents should find exercises whose localized name has accents.
-        let results = search_exercises(&exercises, "couche developpe", "fr");
+        let results = search_exercises(
+            &exercises,
+            "couche developpe",
+            "fr",
+            &ExerciseOverrides::new(),
+        );
         assert_eq!(results.len(), 1, "accent-insensitive search should work");
         assert_eq!(results[0].id, "bench_press");
     }
@@ -869,7 +1192,7 @@ mod tests {
     fn search_reversed_words_finds_exercise() {
         // "press bench" (reversed) should still find "Bench Press".
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "press bench", "");
+        let results = search_exercises(&exercises, "press bench", "", &ExerciseOverrides::new());
         assert!(
             !results.is_empty(),
             "reversed word order should still find the exercise"
@@ -879,7 +1202,7 @@ mod tests {
     #[test]
     fn search_empty_query_returns_all() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "", "");
+        let results = search_exercises(&exercises, "", "", &ExerciseOverrides::new());
         assert_eq!(results.len(), exercises.len());
     }
     #[test]
@@ -919,7 +1242,9 @@ mod tests {
             instructions: vec![],
             category: crate::models::Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }];
         let found = resolve_exercise(&db, &custom, "custom_1");
         assert!(found.is_some());
@@ -941,7 +1266,9 @@ mod tests {
             instructions: vec![],
             category: crate::models::Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }];
         let found = resolve_exercise(&db, &custom, "pull_up");
         assert_eq!(found.unwrap().name, "Pull-Up");
@@ -968,7 +1295,7 @@ mod tests {
     #[test]
     fn search_with_none_force_does_not_match_by_name_of_pull() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "pull", "");
+        let results = search_exercises(&exercises, "pull", "", &ExerciseOverrides::new());
         for r in &results {
             assert_ne!(r.id, "running");
         }
@@ -976,7 +1303,7 @@ mod tests {
     #[test]
     fn search_with_body_only_equipment_returns_empty() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "body only", "");
+        let results = search_exercises(&exercises, "body only", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
@@ -994,10 +1321,12 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }
         .with_lowercase()];
-        let results = search_exercises(&exercises, "kettlebell", "");
+        let results = search_exercises(&exercises, "kettlebell", "", &ExerciseOverrides::new());
         assert!(
             results.is_empty(),
             "ID token matching is removed; title 'KB Pistol Squat' does not contain 'kettlebell'",
@@ -1006,25 +1335,25 @@ mod tests {
     #[test]
     fn search_by_secondary_muscle_returns_empty() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "triceps", "");
+        let results = search_exercises(&exercises, "triceps", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
     fn search_by_secondary_muscle_biceps_returns_empty() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "biceps", "");
+        let results = search_exercises(&exercises, "biceps", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
     fn search_muscle_word_start_no_false_positive() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "ring", "");
+        let results = search_exercises(&exercises, "ring", "", &ExerciseOverrides::new());
         assert!(!results.iter().any(|e| e.id == "running"));
     }
     #[test]
     fn search_muscle_word_start_prefix_returns_empty() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "ham", "");
+        let results = search_exercises(&exercises, "ham", "", &ExerciseOverrides::new());
         assert!(!results.iter().any(|e| e.id == "running"));
     }
     #[test]
@@ -1053,9 +1382,11 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }];
-        let results = search_exercises(&exercises, "quadriceps", "");
+        let results = search_exercises(&exercises, "quadriceps", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
@@ -1073,9 +1404,11 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }];
-        let results = search_exercises(&exercises, "glutes", "");
+        let results = search_exercises(&exercises, "glutes", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
@@ -1093,9 +1426,11 @@ mod tests {
             instructions: vec![],
             category: Category::Cardio,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }];
-        let results = search_exercises(&exercises, "cardio", "");
+        let results = search_exercises(&exercises, "cardio", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
@@ -1121,10 +1456,12 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: Some(i18n_map),
+            rest_seconds: None,
         }
         .with_lowercase()];
-        let results = search_exercises(&exercises, "développé", "");
+        let results = search_exercises(&exercises, "développé", "", &ExerciseOverrides::new());
         assert_eq!(results.len(), 1, "should find by French name");
         assert_eq!(results[0].id, "bench_press");
     }
@@ -1154,7 +1491,9 @@ mod tests {
                 instructions: vec![],
                 category: Category::Strength,
                 images: vec![],
+                videos: vec![],
                 i18n: Some(i18n_pompe),
+                rest_seconds: None,
             }
             .with_lowercase(),
             Exercise {
@@ -1170,20 +1509,22 @@ mod tests {
                 instructions: vec![],
                 category: Category::Strength,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             }
             .with_lowercase(),
         ];
         // With lang="fr", "pompe" should exactly match "Pompe" → SCORE_EXACT_NAME.
         // "Push-Up Wide" has no French translation so it doesn't match "pompe" at all.
-        let results = search_exercises(&exercises, "pompe", "fr");
+        let results = search_exercises(&exercises, "pompe", "fr", &ExerciseOverrides::new());
         assert!(!results.is_empty(), "should find at least one result");
         assert_eq!(
             results[0].id, "push_up",
             "Push-Up (French: Pompe) should be the top result for 'pompe' search in French",
         );
         // Without lang, only the i18n fallback (score 55) should fire.
-        let results_no_lang = search_exercises(&exercises, "pompe", "");
+        let results_no_lang = search_exercises(&exercises, "pompe", "", &ExerciseOverrides::new());
         assert!(
             !results_no_lang.is_empty(),
             "should still find by i18n name without lang hint"
@@ -1194,20 +1535,20 @@ mod tests {
     fn search_lang_aware_does_not_affect_english_results() {
         // Passing a lang should not break English name matching.
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "bench press", "fr");
+        let results = search_exercises(&exercises, "bench press", "fr", &ExerciseOverrides::new());
         assert!(!results.is_empty());
         assert_eq!(results[0].id, "bench_press");
     }
     #[test]
     fn search_by_translated_tag_returns_empty() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "musculation", "");
+        let results = search_exercises(&exercises, "musculation", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
     fn search_by_translated_tag_without_db_i18n_does_not_match() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "musculation", "");
+        let results = search_exercises(&exercises, "musculation", "", &ExerciseOverrides::new());
         assert!(results.is_empty());
     }
     #[test]
@@ -1280,7 +1621,7 @@ mod tests {
                 .enable_all()
                 .build()
                 .unwrap();
-            let result = rt.block_on(download_exercises());
+            let result = rt.block_on(download_exercises(|_, _| {}));
             assert!(
                 result.is_err(),
                 "expected connection error, got: {result:?}"
@@ -1306,7 +1647,7 @@ mod tests {
                 .enable_all()
                 .build()
                 .unwrap();
-            let result = rt.block_on(download_exercises());
+            let result = rt.block_on(download_exercises(|_, _| {}));
             assert!(result.is_err(), "expected HTTP error, got: {result:?}");
             let err = result.unwrap_err();
             assert!(
@@ -1336,7 +1677,7 @@ mod tests {
                 .enable_all()
                 .build()
                 .unwrap();
-            let result = rt.block_on(download_exercises());
+            let result = rt.block_on(download_exercises(|_, _| {}));
             assert!(result.is_ok(), "expected Ok(Some([])), got: {result:?}");
             assert!(result.unwrap().unwrap().is_empty());
         }
@@ -1356,7 +1697,7 @@ mod tests {
                 .enable_all()
                 .build()
                 .unwrap();
-            let result = rt.block_on(download_exercises());
+            let result = rt.block_on(download_exercises(|_, _| {}));
             assert!(
                 matches!(result, Ok(None)),
                 "expected Ok(None) on 304, got: {result:?}",
@@ -1400,7 +1741,9 @@ mod tests {
             instructions: vec!["Step 1".into()],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }];
         let entries = vec![ExerciseLangEntry {
             id: "bench_press".into(),
@@ -1417,6 +1760,44 @@ mod tests {
         );
     }
     #[test]
+    fn merge_lang_entries_keeps_fields_missing_from_the_overlay() {
+        let mut exercises = vec![Exercise {
+            id: "squat".into(),
+            name: "Squat".into(),
+            name_lower: String::new(),
+            force: None,
+            level: None,
+            mechanic: None,
+            equipment: None,
+            primary_muscles: vec![],
+            secondary_muscles: vec![],
+            instructions: vec!["Step 1".into()],
+            category: Category::Strength,
+            images: vec![],
+            videos: vec![],
+            i18n: None,
+            rest_seconds: None,
+        }];
+        let database = vec![ExerciseLangEntry {
+            id: "squat".into(),
+            name: Some("Squat".into()),
+            instructions: Some(vec!["Étape 1".into()]),
+        }];
+        let pack = vec![ExerciseLangEntry {
+            id: "squat".into(),
+            name: Some("Flexion de jambes".into()),
+            instructions: None,
+        }];
+        merge_lang_entries(&mut exercises, "fr", &database);
+        merge_lang_entries(&mut exercises, "fr", &pack);
+        let fr = &exercises[0].i18n.as_ref().unwrap()["fr"];
+        assert_eq!(fr.name.as_deref(), Some("Flexion de jambes"));
+        assert_eq!(
+            fr.instructions.as_deref(),
+            Some(&["Étape 1".to_owned()][..])
+        );
+    }
+    #[test]
     fn merge_lang_entries_skips_unmatched_ids() {
         let mut exercises = vec![Exercise {
             id: "squat".into(),
@@ -1431,7 +1812,9 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }];
         let entries = vec![ExerciseLangEntry {
             id: "bench_press".into(),
@@ -1468,7 +1851,9 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: Some(existing_i18n),
+            rest_seconds: None,
         }];
         let entries = vec![ExerciseLangEntry {
             id: "bench_press".into(),
@@ -1484,7 +1869,7 @@ mod tests {
     fn exercises_lang_json_url_returns_correct_format() {
         #[cfg(not(target_arch = "wasm32"))]
         let _g = crate::services::storage::native_storage::test_lock();
-        let url = exercises_lang_json_url("fr");
+        let url = exercises_lang_json_url(&crate::utils::get_exercise_db_url(), "fr");
         assert!(url.contains("gfauredev"), "URL should reference gfauredev");
         assert!(
             url.ends_with("exercises.fr.json"),
@@ -1492,6 +1877,22 @@ mod tests {
         );
     }
     #[test]
+    fn extra_source_exercises_are_namespaced() {
+        let mut exercises = sample_exercises();
+        exercises[0].images = vec!["Bench/0.jpg".into(), "https://cdn.example/1.jpg".into()];
+        let base = "https://example.com/my-db/";
+        namespace_exercises(&mut exercises, base);
+        assert_eq!(source_namespace(base), "example.com-my-db");
+        assert_eq!(exercises[0].id, "example.com-my-db:bench_press");
+        assert_eq!(
+            exercises[0].images,
+            vec![
+                "https://example.com/my-db/exercises/Bench/0.jpg",
+                "https://cdn.example/1.jpg"
+            ]
+        );
+    }
+    #[test]
     fn db_i18n_url_returns_correct_format() {
         #[cfg(not(target_arch = "wasm32"))]
         let _g = crate::services::storage::native_storage::test_lock();
@@ -1518,7 +1919,9 @@ mod tests {
                 instructions: vec![],
                 category: Category::Strength,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             }
             .with_lowercase(),
             Exercise {
@@ -1534,11 +1937,13 @@ mod tests {
                 instructions: vec![],
                 category: Category::Strength,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             }
             .with_lowercase(),
         ];
-        let results = search_exercises(&exercises, "push-up", "");
+        let results = search_exercises(&exercises, "push-up", "", &ExerciseOverrides::new());
         assert!(
             !results.is_empty(),
             "search should find at least the Push-Up exercise"
@@ -1551,7 +1956,7 @@ mod tests {
     #[test]
     fn search_exact_name_ranks_first() {
         let exercises = sample_exercises();
-        let results = search_exercises(&exercises, "bench press", "");
+        let results = search_exercises(&exercises, "bench press", "", &ExerciseOverrides::new());
         assert!(!results.is_empty());
         assert_eq!(results[0].id, "bench_press");
     }
@@ -1571,7 +1976,9 @@ mod tests {
                 instructions: vec![],
                 category: Category::Strength,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             }
             .with_lowercase(),
             Exercise {
@@ -1587,11 +1994,13 @@ mod tests {
                 instructions: vec![],
                 category: Category::Strength,
                 images: vec![],
+                videos: vec![],
                 i18n: None,
+                rest_seconds: None,
             }
             .with_lowercase(),
         ];
-        let results = search_exercises(&exercises, "pull", "");
+        let results = search_exercises(&exercises, "pull", "", &ExerciseOverrides::new());
         assert!(results.len() >= 2);
         assert_eq!(
             results[0].id, "pull_up",
@@ -1599,12 +2008,36 @@ mod tests {
         );
     }
     #[test]
+    fn custom_tag_filters_join_their_built_in_group() {
+        let exercises = sample_exercises();
+        let sled = CustomTag {
+            kind: TaxonomyKind::Equipment,
+            label: "sled".into(),
+        };
+        let mut overrides = ExerciseOverrides::new();
+        overrides.entry("running".into()).or_default().tags = vec![sled.clone()];
+        let filters = vec![
+            SearchFilter::Custom(sled.clone()),
+            SearchFilter::Equipment(Equipment::Barbell),
+        ];
+        let ids: Vec<&str> = exercises
+            .iter()
+            .filter(|e| exercise_matches_filters(e, &filters, &overrides))
+            .map(|e| e.id.as_str())
+            .collect();
+        assert_eq!(ids, ["bench_press", "running"]);
+        let taxonomy = CustomTaxonomy { tags: vec![sled] };
+        assert!(detect_filter_suggestions("sle", &taxonomy)
+            .iter()
+            .any(|s| s.label() == "🔧 sled"));
+    }
+    #[test]
     fn filter_by_category_matches_correct_exercises() {
         let exercises = sample_exercises();
         let filters = vec![SearchFilter::Category(Category::Cardio)];
         let results: Vec<_> = exercises
             .iter()
-            .filter(|e| exercise_matches_filters(e, &filters))
+            .filter(|e| exercise_matches_filters(e, &filters, &ExerciseOverrides::new()))
             .collect();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].id, "running");
@@ -1615,7 +2048,7 @@ mod tests {
         let filters = vec![SearchFilter::Muscle(Muscle::Triceps)];
         let results: Vec<_> = exercises
             .iter()
-            .filter(|e| exercise_matches_filters(e, &filters))
+            .filter(|e| exercise_matches_filters(e, &filters, &ExerciseOverrides::new()))
             .collect();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].id, "bench_press");
@@ -1629,7 +2062,7 @@ mod tests {
         ];
         let results: Vec<_> = exercises
             .iter()
-            .filter(|e| exercise_matches_filters(e, &filters))
+            .filter(|e| exercise_matches_filters(e, &filters, &ExerciseOverrides::new()))
             .collect();
         assert_eq!(results.len(), 3);
     }
@@ -1642,7 +2075,7 @@ mod tests {
         ];
         let results: Vec<_> = exercises
             .iter()
-            .filter(|e| exercise_matches_filters(e, &filters))
+            .filter(|e| exercise_matches_filters(e, &filters, &ExerciseOverrides::new()))
             .collect();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].id, "bench_press");
@@ -1652,13 +2085,13 @@ mod tests {
         let exercises = sample_exercises();
         let results: Vec<_> = exercises
             .iter()
-            .filter(|e| exercise_matches_filters(e, &[]))
+            .filter(|e| exercise_matches_filters(e, &[], &ExerciseOverrides::new()))
             .collect();
         assert_eq!(results.len(), exercises.len());
     }
     #[test]
     fn detect_filter_suggests_category_for_cardio() {
-        let suggestions = detect_filter_suggestions("cardio");
+        let suggestions = detect_filter_suggestions("cardio", &CustomTaxonomy::default());
         assert!(
             suggestions
                 .iter()
@@ -1668,7 +2101,7 @@ mod tests {
     }
     #[test]
     fn detect_filter_suggests_muscle_prefix() {
-        let suggestions = detect_filter_suggestions("bicep");
+        let suggestions = detect_filter_suggestions("bicep", &CustomTaxonomy::default());
         assert!(
             suggestions
                 .iter()
@@ -1678,7 +2111,7 @@ mod tests {
     }
     #[test]
     fn detect_filter_short_query_returns_empty() {
-        let suggestions = detect_filter_suggestions("a");
+        let suggestions = detect_filter_suggestions("a", &CustomTaxonomy::default());
         assert!(
             suggestions.is_empty(),
             "single-character query should return no suggestions",
@@ -1686,7 +2119,7 @@ mod tests {
     }
     #[test]
     fn detect_filter_suggests_level_beginner() {
-        let suggestions = detect_filter_suggestions("beginner");
+        let suggestions = detect_filter_suggestions("beginner", &CustomTaxonomy::default());
         assert!(
             suggestions
                 .iter()
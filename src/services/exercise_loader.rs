@@ -9,36 +9,78 @@ use crate::services::exercise_db;
 use crate::{DbEmptyToastSignal, DbI18nSignal, ToastSignal};
 use dioxus::prelude::*;
 use std::sync::Arc;
-/// Provides the exercises signal and kicks off the background load from cache.
+/// Attempts at downloading the exercise database before giving up.
+pub const MAX_DOWNLOAD_ATTEMPTS: u32 = 4;
+/// Delay before the first retry of a failed download, doubled at each retry.
+const RETRY_BASE_DELAY_MS: u32 = 1_000;
+/// State of the download of the exercise database, see [`reload_exercises`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DbDownload {
+    #[default]
+    Idle,
+    /// Bytes `received` so far, of `total` when known, at attempt `attempt`
+    /// (from 0).
+    Downloading {
+        received: u64,
+        total: Option<u64>,
+        attempt: u32,
+    },
+    /// Every attempt failed, the last one with this error.
+    Failed(String),
+}
+impl DbDownload {
+    #[must_use]
+    pub fn is_downloading(&self) -> bool {
+        matches!(self, Self::Downloading { .. })
+    }
+}
+/// Delay (ms) before retrying after the failed attempt `attempt` (from 0).
+fn retry_delay_ms(attempt: u32) -> u32 {
+    RETRY_BASE_DELAY_MS.saturating_mul(1 << attempt.min(16))
+}
+/// Set once the exercise database is wanted, to hydrate it from the cache.
+#[derive(Clone, Copy)]
+struct HydrationSignal(Signal<bool>);
+/// Delay after startup before the exercise database is hydrated anyway, for
+/// the views that read it without asking for it (e.g. the home statistics).
+const IDLE_HYDRATION_DELAY_MS: u64 = 3_000;
+/// Provides the exercises signal, hydrated from the cache only once a view
+/// asks for it (see [`use_hydrated_exercises`]) or the app has been idle for
+/// [`IDLE_HYDRATION_DELAY_MS`], so that the ~800 exercises never delay the
+/// first paint of the home screen and its sessions.
 /// Never auto-downloads; if the cache is empty a toast is shown instead.
 /// Call once inside the root `App` component.
 pub fn provide_exercises() {
     let wrapper = use_context_provider(|| exercise_db::AllExercisesSignal(Signal::new(Vec::new())));
     let sig = wrapper.0;
+    let requested = use_context_provider(|| HydrationSignal(Signal::new(false))).0;
     let mut i18n_sig = use_context::<DbI18nSignal>().0;
     let mut toast = use_context::<ToastSignal>().0;
     let db_empty_toast = use_context::<DbEmptyToastSignal>().0;
     #[cfg(not(target_arch = "wasm32"))]
     let img_progress = use_context::<crate::ImageDownloadProgressSignal>().0;
 
-    // Load cached exercises immediately (no network call), then download any
-    // missing images in the background.
-    spawn(async move {
-        load_exercises(sig, db_empty_toast).await;
-        // After loading from cache, download any images that are missing on
-        // disk.  This handles the case where a previous image download was
-        // interrupted (e.g. by the screen locking).  A separate Dioxus task
-        // is spawned so the download runs concurrently without blocking the
-        // rest of the startup sequence.
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            let exercises: Vec<Exercise> = sig.read().iter().map(|e| e.as_ref().clone()).collect();
-            if !exercises.is_empty() {
-                spawn(async move {
-                    exercise_db::download_db_images(&exercises, img_progress).await;
-                });
-            }
+    use_hook(move || {
+        spawn(async move {
+            #[cfg(target_arch = "wasm32")]
+            gloo_timers::future::TimeoutFuture::new(IDLE_HYDRATION_DELAY_MS as u32).await;
+            #[cfg(not(target_arch = "wasm32"))]
+            tokio::time::sleep(std::time::Duration::from_millis(IDLE_HYDRATION_DELAY_MS)).await;
+            request(requested);
+        });
+    });
+    // Load cached exercises once requested (no network call), then download
+    // any missing images in the background.
+    use_effect(move || {
+        if !*requested.read() {
+            return;
         }
+        spawn(hydrate(
+            sig,
+            db_empty_toast,
+            #[cfg(not(target_arch = "wasm32"))]
+            img_progress,
+        ));
     });
 
     // Download i18n data in background
@@ -59,13 +101,92 @@ pub fn provide_exercises() {
         }
     });
 }
+/// Mark the exercise database as wanted, unless it already is.
+fn request(mut requested: Signal<bool>) {
+    if !*requested.peek() {
+        requested.set(true);
+    }
+}
+/// Hydrate the exercise database from the cache now, if not done yet.
+pub fn request_exercise_hydration() {
+    request(consume_context::<HydrationSignal>().0);
+}
+/// Consumes the exercises signal, hydrating it first if needed.  For the
+/// views built around the exercise database (session view, exercise list).
+pub fn use_hydrated_exercises() -> Signal<Vec<Arc<Exercise>>> {
+    use_hook(request_exercise_hydration);
+    use_exercises()
+}
+/// Load the cached exercises into `sig`, then download the missing images.
+async fn hydrate(
+    sig: Signal<Vec<Arc<Exercise>>>,
+    db_empty_toast: Signal<bool>,
+    #[cfg(not(target_arch = "wasm32"))] img_progress: Signal<Option<(usize, usize)>>,
+) {
+    load_exercises(sig, db_empty_toast).await;
+    // After loading from cache, download any images that are missing on
+    // disk.  This handles the case where a previous image download was
+    // interrupted (e.g. by the screen locking).  A separate Dioxus task
+    // is spawned so the download runs concurrently without blocking the
+    // rest of the app.
+    #[cfg(not(target_arch = "wasm32"))]
+    if super::image_prefetch::prefetch_allowed() {
+        let exercises: Vec<Exercise> = sig.read().iter().map(|e| e.as_ref().clone()).collect();
+        if !exercises.is_empty() {
+            spawn(async move {
+                exercise_db::download_db_images(&exercises, img_progress).await;
+            });
+        }
+    }
+}
 /// Consumes the exercises signal from the Dioxus context.
 pub fn use_exercises() -> Signal<Vec<Arc<Exercise>>> {
     use_context::<exercise_db::AllExercisesSignal>().0
 }
-/// Clears the current exercise list and immediately re-downloads from the
-/// configured URL.  Intended to be called after saving a new database URL so
-/// the app reflects the change without requiring a full reload.
+/// Download the exercise database, reporting its progress in `download` and
+/// retrying up to [`MAX_DOWNLOAD_ATTEMPTS`] times with an exponential backoff.
+async fn download_with_retries(
+    mut download: Signal<DbDownload>,
+) -> Result<Option<Vec<Exercise>>, String> {
+    let mut attempt = 0;
+    loop {
+        download.set(DbDownload::Downloading {
+            received: 0,
+            total: None,
+            attempt,
+        });
+        let result = exercise_db::download_exercises(|received, total| {
+            download.set(DbDownload::Downloading {
+                received,
+                total,
+                attempt,
+            });
+        })
+        .await;
+        match result {
+            Err(e) if attempt + 1 < MAX_DOWNLOAD_ATTEMPTS => {
+                log::warn!("Exercise database download failed (attempt {attempt}): {e}");
+                crate::utils::sleep_ms(retry_delay_ms(attempt)).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                download.set(DbDownload::Failed(e.clone()));
+                return Err(e);
+            }
+            Ok(exercises) => {
+                download.set(DbDownload::Idle);
+                return Ok(exercises);
+            }
+        }
+    }
+}
+/// Re-downloads the exercise list from the configured URL, unless a download
+/// is already running.  Intended to be called after saving a new database URL
+/// so the app reflects the change without requiring a full reload.
+///
+/// The progress, retries and final failure are reported in `download` (see
+/// [`download_with_retries`]); the cached exercises are only replaced once
+/// the new ones are downloaded, so a failure keeps the previous list.
 ///
 /// On success the toast shows a confirmation message; on error (network,
 /// empty response, JSON parse) it shows an appropriate error message so the
@@ -73,104 +194,77 @@ pub fn use_exercises() -> Signal<Vec<Arc<Exercise>>> {
 pub async fn reload_exercises(
     mut sig: Signal<Vec<Arc<Exercise>>>,
     mut toast: Signal<std::collections::VecDeque<String>>,
+    download: Signal<DbDownload>,
     #[cfg(not(target_arch = "wasm32"))] img_progress: Signal<Option<(usize, usize)>>,
 ) {
-    #[cfg(target_arch = "wasm32")]
-    {
-        use crate::services::storage::idb_exercises;
-        toast
-            .write()
-            .push_back("⬇️ Downloading exercise database…".to_string());
-        idb_exercises::clear_all_exercises().await;
-        match exercise_db::download_exercises().await {
-            Ok(Some(exercises)) if !exercises.is_empty() => {
+    if download.peek().is_downloading() {
+        return;
+    }
+    toast
+        .write()
+        .push_back("⬇️ Downloading exercise database…".to_string());
+    match download_with_retries(download).await {
+        Ok(Some(exercises)) if !exercises.is_empty() => {
+            #[cfg(target_arch = "wasm32")]
+            {
+                use crate::services::storage::idb_exercises;
                 log::info!(
                     "Reloaded {} exercises from new URL, storing in IndexedDB",
                     exercises.len()
                 );
+                idb_exercises::clear_all_exercises().await;
                 idb_exercises::store_all_exercises(&exercises).await;
-                sig.set(
-                    exercises
-                        .into_iter()
-                        .map(|e| Arc::new(Exercise::with_lowercase(e)))
-                        .collect(),
-                );
-                toast
-                    .write()
-                    .push_back("💾 Exercise database reloaded successfully".to_string());
-            }
-            Ok(Some(_)) => {
-                log::warn!("Reloaded exercises file was empty");
-                toast
-                    .write()
-                    .push_back("⚠️ exercises.json was empty — check the database URL".to_string());
             }
-            Ok(None) => {
-                log::info!("exercises.json unchanged (304) — no reload needed");
-                toast
-                    .write()
-                    .push_back("ℹ️ Exercise database is already up to date".to_string());
-            }
-            Err(e) => {
-                log::warn!("Failed to reload exercises: {e:?}");
-                toast
-                    .write()
-                    .push_back(format!("❌ Failed to reload exercises: {e}"));
-            }
-        }
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        use crate::services::storage::native_exercises;
-        toast
-            .write()
-            .push_back("⬇️ Downloading exercise database…".to_string());
-        native_exercises::clear_all_exercises();
-        match exercise_db::download_exercises().await {
-            Ok(Some(exercises)) if !exercises.is_empty() => {
+            #[cfg(not(target_arch = "wasm32"))]
+            let exercises_for_download = {
+                use crate::services::storage::native_exercises;
                 log::info!(
                     "Reloaded {} exercises from new URL, storing in local file",
                     exercises.len()
                 );
+                native_exercises::clear_all_exercises();
                 native_exercises::store_all_exercises(&exercises);
                 // Clone for the background image download before consuming exercises.
-                let exercises_for_download = exercises.clone();
-                // Show exercises immediately — do not block on image download.
-                sig.set(
-                    exercises
-                        .into_iter()
-                        .map(|e| Arc::new(Exercise::with_lowercase(e)))
-                        .collect(),
-                );
-                toast
-                    .write()
-                    .push_back("💾 Exercise database reloaded successfully".to_string());
-                // Spawn image download as a separate Dioxus task so that it
-                // continues running after reload_exercises returns and so that
-                // exercises are visible immediately without waiting for all
-                // images to download first.
+                exercises.clone()
+            };
+            // Show exercises immediately — do not block on image download.
+            sig.set(
+                exercises
+                    .into_iter()
+                    .map(|e| Arc::new(Exercise::with_lowercase(e)))
+                    .collect(),
+            );
+            toast
+                .write()
+                .push_back("💾 Exercise database reloaded successfully".to_string());
+            // Spawn image download as a separate Dioxus task so that it
+            // continues running after reload_exercises returns and so that
+            // exercises are visible immediately without waiting for all
+            // images to download first.
+            #[cfg(not(target_arch = "wasm32"))]
+            if super::image_prefetch::prefetch_allowed() {
                 spawn(async move {
                     exercise_db::download_db_images(&exercises_for_download, img_progress).await;
                 });
             }
-            Ok(Some(_)) => {
-                log::warn!("Reloaded exercises file was empty");
-                toast
-                    .write()
-                    .push_back("⚠️ exercises.json was empty — check the database URL".to_string());
-            }
-            Ok(None) => {
-                log::info!("exercises.json unchanged (304) — no reload needed");
-                toast
-                    .write()
-                    .push_back("ℹ️ Exercise database is already up to date".to_string());
-            }
-            Err(e) => {
-                log::warn!("Failed to reload exercises: {e:?}");
-                toast
-                    .write()
-                    .push_back(format!("❌ Failed to reload exercises: {e}"));
-            }
+        }
+        Ok(Some(_)) => {
+            log::warn!("Reloaded exercises file was empty");
+            toast
+                .write()
+                .push_back("⚠️ exercises.json was empty — check the database URL".to_string());
+        }
+        Ok(None) => {
+            log::info!("exercises.json unchanged (304) — no reload needed");
+            toast
+                .write()
+                .push_back("ℹ️ Exercise database is already up to date".to_string());
+        }
+        Err(e) => {
+            log::warn!("Failed to reload exercises: {e:?}");
+            toast
+                .write()
+                .push_back(format!("❌ Failed to reload exercises: {e}"));
         }
     }
 }
@@ -220,3 +314,13 @@ async fn load_exercises(mut sig: Signal<Vec<Arc<Exercise>>>, mut db_empty_toast:
         }
     }
 }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn retries_back_off_exponentially() {
+        let delays: Vec<u32> = (0..MAX_DOWNLOAD_ATTEMPTS - 1).map(retry_delay_ms).collect();
+        assert_eq!(delays, vec![1_000, 2_000, 4_000]);
+        assert_eq!(retry_delay_ms(40), RETRY_BASE_DELAY_MS << 16);
+    }
+}
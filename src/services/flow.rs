@@ -0,0 +1,52 @@
+/// Flow mode – chain the exercises of a session without tapping, e.g. for
+/// circuit training.
+///
+/// Completing an exercise already starts the rest countdown; with flow mode
+/// on, the next pending exercise is started, with the inputs prefilled from
+/// its last log, as soon as that countdown is over (see [`rest_is_over`]), or
+/// right away when the rest is skipped after the category of the exercise.
+use crate::models::WorkoutSession;
+/// Config key storing `"true"` when the user enabled flow mode.
+const FLOW_MODE_KEY: &str = "flow_mode";
+/// Whether flow mode is enabled (default `false`).
+#[must_use]
+pub fn flow_mode_enabled() -> bool {
+    crate::utils::get_config(FLOW_MODE_KEY).as_deref() == Some("true")
+}
+/// Persist the flow mode preference (see [`flow_mode_enabled`]).
+pub fn set_flow_mode_enabled(enabled: bool) {
+    // The default is stored as an absent key.
+    crate::utils::set_config(FLOW_MODE_KEY, if enabled { "true" } else { "" });
+}
+/// Whether the rest of `session`, lasting `rest_duration` seconds, is over at
+/// `now` with no exercise in progress, so the next one can be started.  A
+/// paused session never ends its rest.
+#[must_use]
+pub fn rest_is_over(session: &WorkoutSession, rest_duration: u64, now: u64) -> bool {
+    session.current_exercise_id.is_none()
+        && !session.is_paused()
+        && rest_duration > 0
+        && session
+            .rest_start_time
+            .is_some_and(|start| now >= start + rest_duration)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn rest_ends_after_its_duration_unless_paused() {
+        let mut s = WorkoutSession::new();
+        s.rest_start_time = Some(2_000);
+        assert!(!rest_is_over(&s, 30, 2_029));
+        assert!(rest_is_over(&s, 30, 2_030));
+        assert!(!rest_is_over(&s, 0, 2_030));
+        s.paused_at = Some(2_010);
+        assert!(!rest_is_over(&s, 30, 2_030));
+        s.paused_at = None;
+        s.current_exercise_id = Some("squat".into());
+        assert!(!rest_is_over(&s, 30, 2_030));
+        s.current_exercise_id = None;
+        s.rest_start_time = None;
+        assert!(!rest_is_over(&s, 30, 2_030));
+    }
+}
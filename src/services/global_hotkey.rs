@@ -0,0 +1,147 @@
+/// Global keyboard shortcut of desktop builds, working while another
+/// application has the focus.
+///
+/// Pressing it brings the LogOut window to the front and, with no session
+/// running, starts a new one, so stepping away from the computer to train
+/// takes a single key combination.  The accelerator (default
+/// [`DEFAULT_GLOBAL_HOTKEY`]) can be changed or turned off on the More page.
+///
+/// Only registered with the `desktop-platform` feature; elsewhere
+/// [`use_global_hotkey`] only provides the setting.
+use dioxus::prelude::*;
+/// Config key storing the accelerator of the global shortcut.
+const GLOBAL_HOTKEY_KEY: &str = "global_hotkey";
+/// Stored value turning the global shortcut off.
+const GLOBAL_HOTKEY_OFF: &str = "off";
+/// Accelerator of the global shortcut unless configured otherwise.
+pub const DEFAULT_GLOBAL_HOTKEY: &str = "CmdOrCtrl+Shift+L";
+/// The accelerator of the global shortcut for the `stored` config value,
+/// `None` when turned off.
+fn accelerator_of(stored: Option<String>) -> Option<String> {
+    match stored.as_deref() {
+        None | Some("") => Some(DEFAULT_GLOBAL_HOTKEY.to_owned()),
+        Some(GLOBAL_HOTKEY_OFF) => None,
+        Some(_) => stored,
+    }
+}
+/// The configured accelerator of the global shortcut, `None` when turned off.
+#[must_use]
+pub fn global_hotkey() -> Option<String> {
+    accelerator_of(crate::utils::get_config(GLOBAL_HOTKEY_KEY))
+}
+/// Persist the accelerator of the global shortcut, `None` to turn it off
+/// (see [`global_hotkey`]).
+#[cfg_attr(not(feature = "desktop-platform"), allow(dead_code))]
+pub fn set_global_hotkey(accelerator: Option<&str>) {
+    // The default is stored as an absent key.
+    let value = match accelerator {
+        None => GLOBAL_HOTKEY_OFF,
+        Some(DEFAULT_GLOBAL_HOTKEY) => "",
+        Some(accelerator) => accelerator,
+    };
+    crate::utils::set_config(GLOBAL_HOTKEY_KEY, value);
+}
+/// Whether `accelerator` can be registered, e.g. `Ctrl+Alt+L`: modifiers then
+/// a single key, joined by `+`.
+#[cfg(feature = "desktop-platform")]
+#[must_use]
+pub fn is_valid(accelerator: &str) -> bool {
+    accelerator.parse::<global_hotkey::hotkey::HotKey>().is_ok()
+}
+/// The accelerator in use, see [`use_global_hotkey_setting`].
+#[derive(Clone, Copy)]
+struct GlobalHotkeySignal(Signal<Option<String>>);
+/// The accelerator of the global shortcut in use; setting it registers the
+/// new one right away.
+#[cfg_attr(not(feature = "desktop-platform"), allow(dead_code))]
+pub fn use_global_hotkey_setting() -> Signal<Option<String>> {
+    use_context::<GlobalHotkeySignal>().0
+}
+/// Provide the setting of the global shortcut and keep it registered.
+///
+/// Call once inside the router, as the shortcut navigates to the session.
+pub fn use_global_hotkey() {
+    let accelerator = use_context_provider(|| GlobalHotkeySignal(Signal::new(global_hotkey()))).0;
+    #[cfg(feature = "desktop-platform")]
+    desktop::use_registration(accelerator);
+    #[cfg(not(feature = "desktop-platform"))]
+    let _ = accelerator;
+}
+#[cfg(feature = "desktop-platform")]
+mod desktop {
+    use crate::models::WorkoutSession;
+    use crate::services::storage;
+    use crate::Route;
+    use dioxus::desktop::{window, HotKeyState, ShortcutHandle};
+    use dioxus::prelude::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    /// Register `accelerator`, replacing the previous one whenever it changes.
+    pub(super) fn use_registration(accelerator: Signal<Option<String>>) {
+        let nav = use_navigator();
+        let sessions = storage::use_sessions();
+        let on_press = use_callback(move |()| {
+            let window = window();
+            window.set_visible(true);
+            window.set_minimized(false);
+            window.set_focus();
+            if !sessions.peek().iter().any(WorkoutSession::is_active) {
+                storage::start_session(WorkoutSession::new());
+            }
+            nav.push(Route::Home {});
+        });
+        let registered = use_hook(|| Rc::new(Cell::new(None::<ShortcutHandle>)));
+        let unregister = registered.clone();
+        use_effect(move || {
+            if let Some(previous) = registered.take() {
+                previous.remove();
+            }
+            let Some(accelerator) = accelerator() else {
+                return;
+            };
+            let Ok(hotkey) = accelerator.parse::<global_hotkey::hotkey::HotKey>() else {
+                log::warn!("Invalid global shortcut {accelerator}");
+                return;
+            };
+            let handle = window().create_shortcut(hotkey, move |state| {
+                if state == HotKeyState::Pressed {
+                    on_press.call(());
+                }
+            });
+            match handle {
+                Ok(handle) => registered.set(Some(handle)),
+                Err(e) => log::warn!("Failed to register the global shortcut {accelerator}: {e:?}"),
+            }
+        });
+        use_drop(move || {
+            if let Some(handle) = unregister.take() {
+                handle.remove();
+            }
+        });
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn absent_key_is_the_default_and_off_turns_the_shortcut_off() {
+        assert_eq!(accelerator_of(None).as_deref(), Some(DEFAULT_GLOBAL_HOTKEY));
+        assert_eq!(
+            accelerator_of(Some(String::new())).as_deref(),
+            Some(DEFAULT_GLOBAL_HOTKEY)
+        );
+        assert_eq!(accelerator_of(Some(GLOBAL_HOTKEY_OFF.into())), None);
+        assert_eq!(
+            accelerator_of(Some("Alt+F9".into())).as_deref(),
+            Some("Alt+F9")
+        );
+    }
+    #[cfg(feature = "desktop-platform")]
+    #[test]
+    fn accelerators_are_modifiers_then_one_key() {
+        assert!(is_valid(DEFAULT_GLOBAL_HOTKEY));
+        assert!(is_valid("Alt+F9"));
+        assert!(!is_valid("Ctrl+"));
+        assert!(!is_valid("Ctrl+L+K"));
+    }
+}
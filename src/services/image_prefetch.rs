@@ -0,0 +1,288 @@
+/// Exercise image prefetching, and the images kept available offline.
+///
+/// Images always load lazily, once their card scrolls into view.  Ahead of
+/// that, they can be downloaded in the background off, on Wi-Fi only or
+/// always (see [`ImagePrefetch`]): on native every image of the exercise
+/// database, into the image directory, and on the web the images of the
+/// exercises already performed, which are the only ones the service worker
+/// keeps so its cache stays bounded by the training history (see
+/// [`use_offline_images`]).  Without prefetching, the service worker still
+/// caches those images the first time they are displayed.
+///
+/// The images of the exercises kept offline on purpose, from their card or
+/// as part of the training plan, are downloaded whatever the policy, into
+/// the service worker cache or the image directory.
+use crate::models::{kept_offline, Exercise, ExerciseOverrides, TrainingPlan};
+use crate::services::exercise_images::ExerciseImages as _;
+use std::sync::Arc;
+/// Config key storing the [`ImagePrefetch`] policy.
+const IMAGE_PREFETCH_KEY: &str = "image_prefetch";
+/// When exercise images are downloaded ahead of being displayed.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, strum::EnumIter, strum::AsRefStr, strum::EnumString,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ImagePrefetch {
+    Off,
+    /// Only on an unmetered (Wi-Fi or wired) connection.
+    #[default]
+    WifiOnly,
+    Always,
+}
+impl ImagePrefetch {
+    /// Whether images may be prefetched, `unmetered` telling whether the
+    /// current connection is Wi-Fi or wired.
+    #[must_use]
+    pub fn allows(self, unmetered: bool) -> bool {
+        match self {
+            Self::Off => false,
+            Self::WifiOnly => unmetered,
+            Self::Always => true,
+        }
+    }
+}
+/// The image prefetch policy (default [`ImagePrefetch::WifiOnly`]).
+#[must_use]
+pub fn image_prefetch() -> ImagePrefetch {
+    crate::utils::get_config(IMAGE_PREFETCH_KEY)
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_default()
+}
+/// Persist the image prefetch policy (see [`image_prefetch`]).
+pub fn set_image_prefetch(policy: ImagePrefetch) {
+    // The default is stored as an absent key.
+    let value = if policy == ImagePrefetch::default() {
+        ""
+    } else {
+        policy.as_ref()
+    };
+    crate::utils::set_config(IMAGE_PREFETCH_KEY, value);
+}
+/// Whether images may be prefetched right now, given the policy and the
+/// current connection.
+#[must_use]
+pub fn prefetch_allowed() -> bool {
+    let policy = image_prefetch();
+    // Only probe the connection when the policy depends on it.
+    policy.allows(policy == ImagePrefetch::WifiOnly && on_unmetered_network())
+}
+/// Whether the current connection is Wi-Fi or wired.
+///
+/// Relies on the Network Information API, which only some browsers expose;
+/// the connection is assumed metered when it cannot be told.
+#[cfg(target_arch = "wasm32")]
+fn on_unmetered_network() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    js_sys::Reflect::get(&window.navigator(), &"connection".into())
+        .and_then(|connection| js_sys::Reflect::get(&connection, &"type".into()))
+        .ok()
+        .and_then(|kind| kind.as_string())
+        .is_some_and(|kind| kind == "wifi" || kind == "ethernet")
+}
+/// Whether the active network is not metered, as told by the Android
+/// `ConnectivityManager` (needs the `ACCESS_NETWORK_STATE` permission).
+#[cfg(target_os = "android")]
+fn on_unmetered_network() -> bool {
+    use jni::{objects::JObject, JavaVM};
+    let result = (|| -> Result<bool, String> {
+        let ctx = ndk_context::android_context();
+        // SAFETY: the raw pointers come from the Android runtime and are valid
+        // for the lifetime of the process.
+        let vm = unsafe { JavaVM::from_raw(ctx.vm().cast()) }
+            .map_err(|e| format!("JavaVM::from_raw: {e}"))?;
+        let mut env = vm
+            .attach_current_thread()
+            .map_err(|e| format!("attach_current_thread: {e}"))?;
+        let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
+        let service_name = env
+            .get_static_field(
+                "android/content/Context",
+                "CONNECTIVITY_SERVICE",
+                "Ljava/lang/String;",
+            )
+            .map_err(|e| format!("get CONNECTIVITY_SERVICE: {e}"))?
+            .l()
+            .map_err(|e| format!("CONNECTIVITY_SERVICE as object: {e}"))?;
+        let manager = env
+            .call_method(
+                &activity,
+                "getSystemService",
+                "(Ljava/lang/String;)Ljava/lang/Object;",
+                &[(&service_name).into()],
+            )
+            .map_err(|e| format!("getSystemService: {e}"))?
+            .l()
+            .map_err(|e| format!("ConnectivityManager as object: {e}"))?;
+        let metered = env
+            .call_method(&manager, "isActiveNetworkMetered", "()Z", &[])
+            .map_err(|e| format!("isActiveNetworkMetered: {e}"))?
+            .z()
+            .map_err(|e| format!("isActiveNetworkMetered as bool: {e}"))?;
+        Ok(!metered)
+    })();
+    result.unwrap_or_else(|e| {
+        log::warn!("Could not tell whether the network is metered: {e}");
+        false
+    })
+}
+/// Desktop connections are assumed unmetered.
+#[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+fn on_unmetered_network() -> bool {
+    true
+}
+/// IDs of the exercises whose images the user wants offline: those kept
+/// offline from their card and those of the training `plan`.
+fn kept_offline_ids<'a>(
+    overrides: &'a ExerciseOverrides,
+    plan: Option<&'a TrainingPlan>,
+) -> impl Iterator<Item = &'a str> {
+    let planned = plan
+        .into_iter()
+        .flat_map(|p| p.days.iter())
+        .flat_map(|d| d.exercises.iter())
+        .map(|e| e.exercise_id.as_str());
+    kept_offline(overrides).chain(planned)
+}
+/// Sorted network URLs of the images of the exercises `performed`, looked up
+/// in `exercises`.
+#[must_use]
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+fn offline_image_urls<'a>(
+    performed: impl IntoIterator<Item = &'a str>,
+    exercises: &[Arc<Exercise>],
+) -> Vec<String> {
+    let mut urls: Vec<String> = performed
+        .into_iter()
+        .filter_map(|id| exercises.iter().find(|e| e.id == id))
+        .flat_map(|e| (0..e.images.len()).filter_map(|i| e.get_image_url(i)))
+        .filter(|url| url.starts_with("https://") || url.starts_with("http://"))
+        .collect();
+    urls.sort_unstable();
+    urls.dedup();
+    urls
+}
+/// Keep the images of the exercises performed (on the web) and of those kept
+/// offline (see [`kept_offline_ids`]) available offline.
+///
+/// On the web the service worker image cache is kept in line with them,
+/// downloading the missing kept ones, and the performed ones when allowed.
+/// On native the kept ones are downloaded into the image directory.
+///
+/// Call once inside the root `App` component.
+pub fn use_offline_images() {
+    use dioxus::prelude::*;
+    let overrides = super::storage::use_exercise_overrides();
+    let plan = super::storage::use_training_plan();
+    let exercises = super::exercise_db::use_exercises();
+    let custom = super::storage::use_custom_exercises();
+    let all_exercises = move || {
+        let mut all = exercises.read().clone();
+        all.extend(custom.read().iter().cloned());
+        all
+    };
+    #[cfg(target_arch = "wasm32")]
+    {
+        let bests = use_context::<Signal<super::app_state::BestsCache>>();
+        // Only change, and so reach the worker, when an exercise is performed
+        // for the first time, kept offline, or the database is reloaded.
+        let urls = use_memo(move || {
+            let all = all_exercises();
+            let overrides = overrides.read();
+            let plan = plan.read();
+            let kept = offline_image_urls(kept_offline_ids(&overrides, plan.as_ref()), &all);
+            let bests = bests.read();
+            let performed = offline_image_urls(bests.keys().map(String::as_str), &all);
+            (kept, performed)
+        });
+        use_effect(move || {
+            let (kept, performed) = urls.read().clone();
+            // Empty until the exercise database is hydrated: keep the cache.
+            if kept.is_empty() && performed.is_empty() {
+                return;
+            }
+            let download = if prefetch_allowed() {
+                kept.iter().chain(&performed).cloned().collect()
+            } else {
+                kept.clone()
+            };
+            let mut keep = kept;
+            keep.extend(performed);
+            super::service_worker::post_offline_images(keep, download);
+        });
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let img_progress = use_context::<crate::ImageDownloadProgressSignal>().0;
+        let kept = use_memo(move || {
+            let all = all_exercises();
+            let overrides = overrides.read();
+            let plan = plan.read();
+            let ids: std::collections::HashSet<&str> =
+                kept_offline_ids(&overrides, plan.as_ref()).collect();
+            all.iter()
+                .filter(|e| ids.contains(e.id.as_str()))
+                .map(|e| e.as_ref().clone())
+                .collect::<Vec<Exercise>>()
+        });
+        use_effect(move || {
+            let kept = kept.read().clone();
+            if kept.is_empty() {
+                return;
+            }
+            // Already downloaded images are skipped.
+            spawn(async move {
+                super::exercise_db::download_db_images(&kept, img_progress).await;
+            });
+        });
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Category;
+    #[test]
+    fn offline_images_are_those_of_performed_exercises() {
+        let exercise = |id: &str, images: &[&str]| {
+            Arc::new(Exercise {
+                id: id.into(),
+                name: id.into(),
+                name_lower: String::new(),
+                force: None,
+                level: None,
+                mechanic: None,
+                equipment: None,
+                primary_muscles: vec![],
+                secondary_muscles: vec![],
+                instructions: vec![],
+                category: Category::Strength,
+                images: images.iter().map(|&i| i.into()).collect(),
+                videos: vec![],
+                i18n: None,
+                rest_seconds: None,
+            })
+        };
+        let exercises = vec![
+            exercise("squat", &["https://img/s1.jpg", "https://img/s0.jpg"]),
+            exercise("curl", &["https://img/c0.jpg"]),
+            exercise("mine", &["idb:photo", "https://img/s0.jpg"]),
+        ];
+        assert_eq!(
+            offline_image_urls(["mine", "squat", "unknown"], &exercises),
+            ["https://img/s0.jpg", "https://img/s1.jpg"],
+        );
+        let overrides = ExerciseOverrides::from([(
+            "curl".to_owned(),
+            crate::models::ExerciseOverride {
+                offline: true,
+                ..Default::default()
+            },
+        )]);
+        let kept: Vec<&str> = kept_offline_ids(&overrides, None).collect();
+        assert_eq!(offline_image_urls(kept, &exercises), ["https://img/c0.jpg"]);
+        assert!(ImagePrefetch::WifiOnly.allows(true));
+        assert!(!ImagePrefetch::WifiOnly.allows(false));
+        assert!(!ImagePrefetch::Off.allows(true) && ImagePrefetch::Always.allows(false));
+    }
+}
@@ -0,0 +1,282 @@
+/// Android launcher integration: home-screen shortcuts and the summary read
+/// by an app widget.
+///
+/// Two dynamic shortcuts are published, "Start session" and "Last session"
+/// (a new session queuing the exercises of the latest one).  Each opens the
+/// app on a `logworkout://session/start` deep link (see
+/// [`crate::utils::parse_deep_link`]), which the activity keeps until the app
+/// polls it.  Static shortcuts would additionally need an `android:shortcuts`
+/// resource in the manifest.
+///
+/// The [`WidgetSummary`] (week streak, last workout) is written as JSON to the
+/// `logout_widget` shared preferences, and the app widget providers of the
+/// package, if any, are asked to redraw.
+///
+/// On every other platform [`use_launcher`] does nothing.
+use crate::models::WorkoutSession;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use time::{Date, Duration};
+/// Deep link of the "Start session" shortcut.
+#[cfg_attr(not(target_os = "android"), allow(dead_code))]
+const START_SESSION_LINK: &str = "logworkout://session/start";
+/// Summary of the training history shown by the app widget.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WidgetSummary {
+    /// Consecutive weeks with a workout, up to this week or the last one.
+    pub streak_weeks: u32,
+    /// The latest completed workout.
+    pub last_workout: Option<LastWorkout>,
+}
+/// The latest completed workout, see [`WidgetSummary`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LastWorkout {
+    /// Unix timestamp (seconds) the workout started at.
+    pub start_time: u64,
+    pub duration_seconds: u64,
+    /// Number of exercises logged.
+    pub exercises: usize,
+}
+/// Sessions that count as workouts: completed, with something logged.
+fn workouts(sessions: &[WorkoutSession]) -> impl Iterator<Item = &WorkoutSession> {
+    sessions
+        .iter()
+        .filter(|s| !s.is_active() && !s.exercise_logs.is_empty())
+}
+/// The latest workout among `sessions`.
+fn last_workout(sessions: &[WorkoutSession]) -> Option<&WorkoutSession> {
+    workouts(sessions).max_by_key(|s| s.start_time)
+}
+/// Summarize `sessions` for the widget, `this_week` being the Monday of the
+/// current week.
+#[must_use]
+#[cfg_attr(not(target_os = "android"), allow(dead_code))]
+pub fn summary(sessions: &[WorkoutSession], this_week: Date) -> WidgetSummary {
+    let weeks: BTreeSet<Date> = workouts(sessions)
+        .map(|s| crate::datetime::monday_of(crate::datetime::to_local(s.start_time).date()))
+        .collect();
+    // A week not trained yet does not break the streak.
+    let mut week = if weeks.contains(&this_week) {
+        this_week
+    } else {
+        this_week - Duration::weeks(1)
+    };
+    let mut streak_weeks = 0;
+    while weeks.contains(&week) {
+        streak_weeks += 1;
+        week -= Duration::weeks(1);
+    }
+    WidgetSummary {
+        streak_weeks,
+        last_workout: last_workout(sessions).map(|s| LastWorkout {
+            start_time: s.start_time,
+            duration_seconds: s.duration_seconds(),
+            exercises: s.exercise_logs.len(),
+        }),
+    }
+}
+/// Deep link starting a session with the exercises of the latest workout, in
+/// their order, or `None` without any workout.
+#[must_use]
+#[cfg_attr(not(target_os = "android"), allow(dead_code))]
+pub fn last_session_link(sessions: &[WorkoutSession]) -> Option<String> {
+    use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+    let last = last_workout(sessions)?;
+    let mut seen = BTreeSet::new();
+    let ids: Vec<String> = last
+        .exercise_logs
+        .iter()
+        .filter(|log| seen.insert(log.exercise_id.as_str()))
+        .map(|log| utf8_percent_encode(&log.exercise_id, NON_ALPHANUMERIC).to_string())
+        .collect();
+    Some(format!("{START_SESSION_LINK}?exercises={}", ids.join(",")))
+}
+/// Keep the shortcuts and the widget summary in line with the sessions, and
+/// start the sessions asked for from the shortcuts.
+///
+/// Call once inside the router, as the shortcuts navigate to the session.
+#[cfg(target_os = "android")]
+pub fn use_launcher() {
+    use crate::utils::{parse_deep_link, DeepLinkAction};
+    use dioxus::prelude::*;
+    use dioxus_i18n::t;
+    let sessions = super::storage::use_sessions();
+    use_effect(move || {
+        let sessions = sessions.read();
+        let this_week = crate::datetime::monday_of(crate::datetime::today());
+        let summary = serde_json::to_string(&summary(&sessions, this_week)).unwrap_or_default();
+        let last = last_session_link(&sessions);
+        if let Err(e) = android::update_launcher(
+            &summary,
+            (t!("launcher-start-session"), START_SESSION_LINK),
+            last.as_deref()
+                .map(|link| (t!("launcher-last-session"), link)),
+        ) {
+            log::warn!("Failed to update the launcher shortcuts: {e}");
+        }
+    });
+    // Like the lock screen state, the shortcut intents are polled, on the
+    // app clock.
+    let nav = use_navigator();
+    let clock = super::clock::use_clock();
+    use_effect(move || {
+        clock();
+        let link = match android::take_deep_link() {
+            Ok(Some(link)) => link,
+            Ok(None) => return,
+            Err(e) => {
+                log::warn!("Failed to read the launcher intent: {e}");
+                return;
+            }
+        };
+        match parse_deep_link(&link) {
+            Some(DeepLinkAction::StartSession(exercise_ids)) => {
+                let mut session = WorkoutSession::new();
+                session.pending_exercises = exercise_ids.into_iter().map(Into::into).collect();
+                super::storage::start_session(session);
+                nav.push(crate::Route::Home {});
+            }
+            _ => log::warn!("Unsupported launcher link {link}"),
+        }
+    });
+}
+#[cfg(not(target_os = "android"))]
+pub fn use_launcher() {}
+#[cfg(target_os = "android")]
+mod android {
+    use jni::{objects::JObject, objects::JString, JavaVM};
+    /// Call `MainActivity.updateLauncher(summaryJson, startLabel, startLink,
+    /// lastLabel, lastLink)`, `start` and `last` being the label and link of
+    /// the "Start session" and "Last session" shortcuts.
+    pub(super) fn update_launcher(
+        summary_json: &str,
+        start: (String, &str),
+        last: Option<(String, &str)>,
+    ) -> Result<(), String> {
+        let ctx = ndk_context::android_context();
+        // SAFETY: raw pointers come from the Android runtime; valid for process lifetime.
+        let vm = unsafe { JavaVM::from_raw(ctx.vm().cast()) }
+            .map_err(|e| format!("JavaVM::from_raw: {e}"))?;
+        let mut env = vm
+            .attach_current_thread()
+            .map_err(|e| format!("attach_current_thread: {e}"))?;
+        let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
+        let jsummary = env
+            .new_string(summary_json)
+            .map_err(|e| format!("new_string summary: {e}"))?;
+        let jstart_label = env
+            .new_string(start.0)
+            .map_err(|e| format!("new_string start label: {e}"))?;
+        let jstart_link = env
+            .new_string(start.1)
+            .map_err(|e| format!("new_string start link: {e}"))?;
+        let (jlast_label, jlast_link) = match last {
+            Some((label, link)) => (
+                JObject::from(
+                    env.new_string(label)
+                        .map_err(|e| format!("new_string last label: {e}"))?,
+                ),
+                JObject::from(
+                    env.new_string(link)
+                        .map_err(|e| format!("new_string last link: {e}"))?,
+                ),
+            ),
+            None => (JObject::null(), JObject::null()),
+        };
+        env.call_method(
+            &activity,
+            "updateLauncher",
+            "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;)V",
+            &[
+                (&jsummary).into(),
+                (&jstart_label).into(),
+                (&jstart_link).into(),
+                (&jlast_label).into(),
+                (&jlast_link).into(),
+            ],
+        )
+        .map_err(|e| format!("updateLauncher: {e}"))?;
+        Ok(())
+    }
+    /// Call `MainActivity.takeDeepLink()`: the `logworkout://` link the app
+    /// was last opened on, if not taken yet.
+    pub(super) fn take_deep_link() -> Result<Option<String>, String> {
+        let ctx = ndk_context::android_context();
+        // SAFETY: raw pointers come from the Android runtime; valid for process lifetime.
+        let vm = unsafe { JavaVM::from_raw(ctx.vm().cast()) }
+            .map_err(|e| format!("JavaVM::from_raw: {e}"))?;
+        let mut env = vm
+            .attach_current_thread()
+            .map_err(|e| format!("attach_current_thread: {e}"))?;
+        let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
+        let link = env
+            .call_method(&activity, "takeDeepLink", "()Ljava/lang/String;", &[])
+            .map_err(|e| format!("takeDeepLink: {e}"))?
+            .l()
+            .map_err(|e| format!("takeDeepLink as object: {e}"))?;
+        if link.is_null() {
+            return Ok(None);
+        }
+        let link: String = env
+            .get_string(&JString::from(link))
+            .map_err(|e| format!("get_string link: {e}"))?
+            .into();
+        Ok(Some(link))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ExerciseLog;
+    const WEEK_SECONDS: u64 = 7 * 24 * 3_600;
+    /// Wednesday 2024-01-10, 12:00 UTC: still mid-week in any time zone.
+    const WEDNESDAY: u64 = 1_704_888_000;
+    fn workout(start_time: u64, exercise_ids: &[&str]) -> WorkoutSession {
+        let mut s = WorkoutSession::new();
+        s.start_time = start_time;
+        s.end_time = Some(start_time + 1_800);
+        s.exercise_logs = exercise_ids
+            .iter()
+            .map(|&id| ExerciseLog {
+                exercise_id: id.into(),
+                exercise_name: id.into(),
+                start_time,
+                end_time: Some(start_time + 60),
+                ..Default::default()
+            })
+            .collect();
+        s
+    }
+    #[test]
+    fn summary_counts_the_weeks_trained_in_a_row() {
+        let this_week = crate::datetime::monday_of(crate::datetime::to_local(WEDNESDAY).date());
+        let sessions = [
+            workout(WEDNESDAY - WEEK_SECONDS, &["squat"]),
+            workout(WEDNESDAY - 2 * WEEK_SECONDS, &["squat"]),
+            workout(WEDNESDAY - 4 * WEEK_SECONDS, &["squat"]),
+            WorkoutSession::new(),
+        ];
+        let widget = summary(&sessions, this_week);
+        assert_eq!(widget.streak_weeks, 2);
+        let last = widget.last_workout.unwrap();
+        assert_eq!(last.start_time, WEDNESDAY - WEEK_SECONDS);
+        assert_eq!((last.duration_seconds, last.exercises), (1_800, 1));
+        assert_eq!(summary(&[], this_week), WidgetSummary::default());
+    }
+    #[test]
+    fn last_session_link_restarts_its_exercises() {
+        let sessions = [
+            workout(1_000, &["curl"]),
+            workout(2_000, &["squat", "bench press", "squat"]),
+        ];
+        let link = last_session_link(&sessions).unwrap();
+        assert_eq!(
+            crate::utils::parse_deep_link(&link),
+            Some(crate::utils::DeepLinkAction::StartSession(vec![
+                "squat".into(),
+                "bench press".into()
+            ])),
+        );
+        assert_eq!(last_session_link(&[WorkoutSession::new()]), None);
+    }
+}
@@ -0,0 +1,448 @@
+/// Optional HTTP API on localhost, serving the stored training data as JSON
+/// to dashboards and scripts (Grafana, spreadsheets) on desktop builds.
+///
+/// When enabled, a background thread answers on `127.0.0.1` only:
+///
+/// - `GET /api/sessions` → every session, active ones included
+/// - `GET /api/sessions/{id}` → one session
+/// - `GET /api/exercises` → the custom exercises
+/// - `GET /api/analytics?from=YYYY-MM-DD&to=YYYY-MM-DD` → [`PeriodSummary`]
+///   of the range, the last [`DEFAULT_ANALYTICS_DAYS`] days by default
+///
+/// The API is read-only unless writes are allowed in the settings:
+/// `POST /api/sessions` then stores the posted session, replacing any with
+/// the same ID.  The app shows sessions written this way after a restart.
+///
+/// Requests whose `Host` is not the loopback are refused, so that a web page
+/// cannot reach the API through DNS rebinding, and so are those sent from a
+/// web page of another origin (a non-loopback `Origin` header).  Posted
+/// bodies must be sent as `Content-Type: application/json`, which browsers
+/// cannot send cross-origin without a preflight the API never answers.
+use crate::models::analytics::PeriodSummary;
+use crate::models::{Exercise, WorkoutSession};
+use crate::services::storage::native_storage::{self, StorageError};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+/// Config key set to `"true"` while the API is enabled.
+pub const LOCAL_API_ENABLED_KEY: &str = "local_api_enabled";
+/// Config key holding the port the API listens on.
+pub const LOCAL_API_PORT_KEY: &str = "local_api_port";
+/// Config key set to `"true"` when the API accepts writes.
+pub const LOCAL_API_WRITES_KEY: &str = "local_api_writes";
+/// Port used when none is configured.
+pub const DEFAULT_PORT: u16 = 7_878;
+/// Length of the period summarised by `/api/analytics` without a range.
+pub const DEFAULT_ANALYTICS_DAYS: i64 = 30;
+/// Largest request body accepted, in bytes.
+const MAX_BODY_BYTES: usize = 4 * 1024 * 1024;
+/// Time a client gets to send its request before being dropped.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// Whether `POST` requests are accepted (read by the server thread).
+static ALLOW_WRITES: AtomicBool = AtomicBool::new(false);
+/// The running server, if any.
+static SERVER: Mutex<Option<Server>> = Mutex::new(None);
+/// Handle on the server thread.
+struct Server {
+    port: u16,
+    stop: Arc<AtomicBool>,
+}
+/// A parsed HTTP request.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Request {
+    method: String,
+    path: String,
+    query: String,
+    host: String,
+    /// `Origin` header, empty when absent.
+    origin: String,
+    /// `Content-Type` header, empty when absent.
+    content_type: String,
+    body: String,
+}
+/// An HTTP response with a JSON body.
+#[derive(Debug, PartialEq, Eq)]
+struct Response {
+    status: u16,
+    body: String,
+}
+impl Response {
+    fn json<T: serde::Serialize>(status: u16, value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(body) => Self { status, body },
+            Err(e) => Self::error(500, &e.to_string()),
+        }
+    }
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: serde_json::json!({ "error": message }).to_string(),
+        }
+    }
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            201 => "Created",
+            400 => "Bad Request",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            415 => "Unsupported Media Type",
+            _ => "Internal Server Error",
+        }
+    }
+}
+impl From<StorageError> for Response {
+    fn from(e: StorageError) -> Self {
+        Self::error(500, &e.to_string())
+    }
+}
+/// Port the API listens on, if running.
+#[must_use]
+pub fn running_port() -> Option<u16> {
+    SERVER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .map(|s| s.port)
+}
+/// Configured port, [`DEFAULT_PORT`] if unset or invalid.
+#[must_use]
+pub fn configured_port() -> u16 {
+    crate::utils::get_config(LOCAL_API_PORT_KEY)
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(DEFAULT_PORT)
+}
+/// Whether the API accepts writes.
+#[must_use]
+pub fn writes_allowed() -> bool {
+    ALLOW_WRITES.load(Ordering::Relaxed)
+}
+/// Allow or refuse writes, taking effect on the next request.
+pub fn set_writes_allowed(allowed: bool) {
+    ALLOW_WRITES.store(allowed, Ordering::Relaxed);
+    crate::utils::set_config(LOCAL_API_WRITES_KEY, if allowed { "true" } else { "" });
+}
+/// Start the API at launch if it was left enabled.
+pub fn start_if_enabled() {
+    ALLOW_WRITES.store(
+        crate::utils::get_config(LOCAL_API_WRITES_KEY).is_some(),
+        Ordering::Relaxed,
+    );
+    if crate::utils::get_config(LOCAL_API_ENABLED_KEY).is_some() {
+        if let Err(e) = start(configured_port()) {
+            log::error!("Local API could not start: {e}");
+        }
+    }
+}
+/// Listen on `port` of the loopback, stopping any running server first.
+///
+/// # Errors
+/// If the port cannot be bound (typically already in use).
+pub fn start(port: u16) -> std::io::Result<()> {
+    stop();
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+    let port = listener.local_addr()?.port();
+    let stop = Arc::new(AtomicBool::new(false));
+    let stopped = Arc::clone(&stop);
+    std::thread::Builder::new()
+        .name("local-api".into())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                if stopped.load(Ordering::Relaxed) {
+                    break;
+                }
+                match stream {
+                    Ok(stream) => serve(stream),
+                    Err(e) => log::warn!("Local API connection failed: {e}"),
+                }
+            }
+        })?;
+    *SERVER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(Server { port, stop });
+    log::info!("Local API listening on http://127.0.0.1:{port}/api/");
+    Ok(())
+}
+/// Stop the running server, if any.
+pub fn stop() {
+    let server = SERVER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(server) = server {
+        server.stop.store(true, Ordering::Relaxed);
+        // Wake the thread blocked in `accept` so it sees the flag.
+        let _ = TcpStream::connect((Ipv4Addr::LOCALHOST, server.port));
+    }
+}
+/// Answer one connection.
+fn serve(mut stream: TcpStream) {
+    let _ = stream.set_read_timeout(Some(REQUEST_TIMEOUT));
+    let response = match read_request(&mut stream) {
+        Ok(Some(request)) => respond(&request, writes_allowed()),
+        Ok(None) => Response::error(413, "request too large"),
+        Err(_) => return,
+    };
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    );
+}
+/// Read the request line, headers and body; `None` if the body is larger
+/// than [`MAX_BODY_BYTES`].
+fn read_request(stream: impl Read) -> std::io::Result<Option<Request>> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_owned();
+    let target = parts.next().unwrap_or_default();
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let mut request = Request {
+        method,
+        path: path.to_owned(),
+        query: query.to_owned(),
+        ..Request::default()
+    };
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("host") {
+                value.clone_into(&mut request.host);
+            } else if name.eq_ignore_ascii_case("origin") {
+                value.clone_into(&mut request.origin);
+            } else if name.eq_ignore_ascii_case("content-type") {
+                value.clone_into(&mut request.content_type);
+            } else if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.parse().unwrap_or(0);
+            }
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Ok(None);
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    request.body = String::from_utf8_lossy(&body).into_owned();
+    Ok(Some(request))
+}
+/// Whether the `Host` header names the loopback (port excluded).
+fn is_loopback_host(host: &str) -> bool {
+    // A bracketed IPv6 address holds colons of its own: the port, if any,
+    // follows the closing bracket.
+    if let Some(rest) = host.strip_prefix('[') {
+        return rest.split_once(']').is_some_and(|(address, port)| {
+            address == "::1" && (port.is_empty() || port.starts_with(':'))
+        });
+    }
+    let name = host.split_once(':').map_or(host, |(name, _)| name);
+    matches!(name, "localhost" | "127.0.0.1")
+}
+/// Whether the `Origin` header is absent (not a browser request) or names
+/// a page served from the loopback, e.g. a local dashboard.
+fn is_loopback_origin(origin: &str) -> bool {
+    origin.is_empty()
+        || origin
+            .strip_prefix("http://")
+            .or_else(|| origin.strip_prefix("https://"))
+            .is_some_and(is_loopback_host)
+}
+/// Whether the `Content-Type` header is JSON, parameters ignored.
+fn is_json(content_type: &str) -> bool {
+    content_type
+        .split(';')
+        .next()
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("application/json"))
+}
+/// Route `request` to the stored data.
+fn respond(request: &Request, allow_writes: bool) -> Response {
+    if !is_loopback_host(&request.host) {
+        return Response::error(403, "the API only answers on localhost");
+    }
+    if !is_loopback_origin(&request.origin) {
+        return Response::error(403, "cross-origin requests are refused");
+    }
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let result = match (request.method.as_str(), &segments[..]) {
+        ("GET", ["api", "sessions"]) => {
+            native_storage::get_all::<WorkoutSession>(native_storage::STORE_SESSIONS)
+                .map(|sessions| Response::json(200, &sessions))
+        }
+        ("GET", ["api", "sessions", id]) => {
+            native_storage::get_all::<WorkoutSession>(native_storage::STORE_SESSIONS).map(
+                |sessions| match sessions.into_iter().find(|s| s.id == *id) {
+                    Some(session) => Response::json(200, &session),
+                    None => Response::error(404, "no such session"),
+                },
+            )
+        }
+        ("GET", ["api", "exercises"]) => {
+            native_storage::get_all::<Exercise>(native_storage::STORE_CUSTOM_EXERCISES)
+                .map(|exercises| Response::json(200, &exercises))
+        }
+        ("GET", ["api", "analytics"]) => analytics(&request.query),
+        ("POST", ["api", "sessions"]) if allow_writes && !is_json(&request.content_type) => {
+            Ok(Response::error(415, "the body must be application/json"))
+        }
+        ("POST", ["api", "sessions"]) if allow_writes => {
+            match serde_json::from_str::<WorkoutSession>(&request.body) {
+                Ok(session) => {
+                    let session = crate::services::app_lock::seal_for_storage(session);
+                    native_storage::put_item(native_storage::STORE_SESSIONS, &session.id, &session)
+                        .map(|()| Response::json(201, &session.id))
+                }
+                Err(e) => Ok(Response::error(400, &e.to_string())),
+            }
+        }
+        ("POST", ["api", "sessions"]) => Ok(Response::error(405, "the API is read-only")),
+        _ => Ok(Response::error(404, "unknown route")),
+    };
+    result.unwrap_or_else(Response::from)
+}
+/// Summary of the range given by the `from` and `to` query parameters.
+fn analytics(query: &str) -> Result<Response, StorageError> {
+    let date = |key: &str| crate::utils::get_query_param(query, key);
+    let to = match date("to") {
+        Some(to) => crate::datetime::parse_iso_date(&to),
+        None => Some(crate::datetime::today()),
+    };
+    let from = match (date("from"), to) {
+        (Some(from), _) => crate::datetime::parse_iso_date(&from),
+        (None, Some(to)) => Some(to - time::Duration::days(DEFAULT_ANALYTICS_DAYS - 1)),
+        (None, None) => None,
+    };
+    let (Some(from), Some(to)) = (from, to) else {
+        return Ok(Response::error(400, "dates must be YYYY-MM-DD"));
+    };
+    let sessions = native_storage::get_all::<WorkoutSession>(native_storage::STORE_SESSIONS)?;
+    Ok(Response::json(200, &PeriodSummary::of(&sessions, from, to)))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn get(path: &str) -> Request {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        Request {
+            method: "GET".into(),
+            path: path.into(),
+            query: query.into(),
+            host: "127.0.0.1:7878".into(),
+            ..Request::default()
+        }
+    }
+    fn post(body: String) -> Request {
+        Request {
+            method: "POST".into(),
+            content_type: "application/json".into(),
+            body,
+            ..get("/api/sessions")
+        }
+    }
+    #[test]
+    fn reads_a_request_with_its_body() {
+        let raw = "POST /api/sessions?x=1 HTTP/1.1\r\nHost: localhost\r\n\
+                   Origin: http://localhost:3000\r\n\
+                   Content-Type: application/json; charset=utf-8\r\n\
+                   Content-Length: 2\r\n\r\n{}";
+        let request = read_request(raw.as_bytes()).unwrap().unwrap();
+        assert_eq!(
+            request,
+            Request {
+                method: "POST".into(),
+                path: "/api/sessions".into(),
+                query: "x=1".into(),
+                host: "localhost".into(),
+                origin: "http://localhost:3000".into(),
+                content_type: "application/json; charset=utf-8".into(),
+                body: "{}".into(),
+            }
+        );
+    }
+    #[test]
+    fn serves_sessions_read_only_by_default() {
+        let _g = native_storage::test_lock();
+        let session = WorkoutSession {
+            id: "local_api_session".into(),
+            start_time: 1_000_000_000,
+            end_time: Some(1_000_003_600),
+            ..WorkoutSession::default()
+        };
+        let post = post(serde_json::to_string(&session).unwrap());
+        assert_eq!(respond(&post, false).status, 405);
+        assert_eq!(respond(&post, true).status, 201);
+        let found = respond(&get("/api/sessions/local_api_session"), false);
+        assert_eq!(found.status, 200);
+        assert!(found.body.contains("\"local_api_session\""));
+        let summary = respond(&get("/api/analytics?from=2001-09-01&to=2001-09-30"), false);
+        assert!(summary.body.contains("\"sessions\":1"));
+        assert_eq!(respond(&get("/api/analytics?to=soon"), false).status, 400);
+        assert_eq!(
+            respond(
+                &Request {
+                    host: "evil.example:7878".into(),
+                    ..get("/api/sessions")
+                },
+                false
+            )
+            .status,
+            403
+        );
+    }
+    #[test]
+    fn recognises_loopback_hosts_with_and_without_port() {
+        for host in ["[::1]", "[::1]:8080", "localhost", "127.0.0.1:80"] {
+            assert!(is_loopback_host(host), "{host}");
+        }
+        for host in ["[::2]", "[::1]x", "evil.example", "127.0.0.2:80", ""] {
+            assert!(!is_loopback_host(host), "{host}");
+        }
+        assert!(is_loopback_origin("http://[::1]:3000"));
+    }
+    #[test]
+    fn refuses_cross_origin_and_non_json_posts() {
+        let _g = native_storage::test_lock();
+        let body = serde_json::to_string(&WorkoutSession {
+            id: "local_api_cross_origin".into(),
+            ..WorkoutSession::default()
+        })
+        .unwrap();
+        let cross_origin = Request {
+            origin: "https://evil.example".into(),
+            ..post(body.clone())
+        };
+        assert_eq!(respond(&cross_origin, true).status, 403);
+        let opaque = Request {
+            origin: "null".into(),
+            ..post(body.clone())
+        };
+        assert_eq!(respond(&opaque, true).status, 403);
+        let form = Request {
+            content_type: "text/plain".into(),
+            ..post(body.clone())
+        };
+        assert_eq!(respond(&form, true).status, 415);
+        assert_eq!(
+            respond(&get("/api/sessions/local_api_cross_origin"), false).status,
+            404
+        );
+        let dashboard = Request {
+            origin: "http://localhost:3000".into(),
+            ..post(body)
+        };
+        assert_eq!(respond(&dashboard, true).status, 201);
+    }
+}
@@ -0,0 +1,40 @@
+/// Rendering of user-written Markdown (session and set notes) to HTML.
+///
+/// Only basic formatting is supported: emphasis, lists, links, strikethrough,
+/// tables and task lists.  Raw HTML in the source is shown as text rather
+/// than injected, since notes may come from imported or synced sessions.
+use pulldown_cmark::{html, Event, Options, Parser};
+/// Convert the Markdown `md` to an HTML fragment.
+#[must_use]
+pub fn to_html(md: &str) -> String {
+    let mut opts = Options::empty();
+    opts.insert(Options::ENABLE_STRIKETHROUGH);
+    opts.insert(Options::ENABLE_TABLES);
+    opts.insert(Options::ENABLE_TASKLISTS);
+    let parser = Parser::new_ext(md, opts).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        other => other,
+    });
+    let mut output = String::new();
+    html::push_html(&mut output, parser);
+    output
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn renders_basic_formatting() {
+        assert_eq!(
+            to_html("**heavy** day"),
+            "<p><strong>heavy</strong> day</p>\n"
+        );
+        assert!(to_html("- a\n- b").contains("<li>a</li>"));
+    }
+    #[test]
+    fn escapes_raw_html() {
+        let html = to_html("<script>alert(1)</script> <b>x</b>");
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<b>"));
+        assert!(html.contains("&lt;b&gt;x&lt;/b&gt;"));
+    }
+}
@@ -0,0 +1,133 @@
+/// Media Session integration for the rest timer.
+///
+/// Exposes the running rest period through the
+/// [Media Session API](https://developer.mozilla.org/en-US/docs/Web/API/Media_Session_API)
+/// so the lock screen and OS media controls show e.g. "Rest 0:42 — Bench
+/// Press next".  Browsers only surface a media session while audio is
+/// playing, so a silent looping clip is played for the duration of the rest,
+/// which can take the audio focus from the user's own music: the feature is
+/// off unless enabled on the More page (see [`media_controls_enabled`]).
+///
+/// The hardware / lock-screen buttons are mapped to rest actions:
+/// - pause / next track → [`RestAction::Skip`]
+/// - play / seek forward → [`RestAction::Extend`]
+///
+/// On native platforms every function is a no-op; the Android foreground
+/// service notification plays the same role there.
+use serde::Deserialize;
+/// Seconds added to the rest period by [`RestAction::Extend`].
+pub const REST_EXTEND_SECONDS: u64 = 30;
+/// Config key set to `"true"` when the rest timer is shown in the OS media
+/// controls.
+const MEDIA_CONTROLS_KEY: &str = "rest_media_controls";
+/// Whether the rest timer is shown in the OS media controls (default
+/// `false`).
+#[must_use]
+pub fn media_controls_enabled() -> bool {
+    crate::utils::get_config(MEDIA_CONTROLS_KEY).as_deref() == Some("true")
+}
+/// Persist the media controls preference (see [`media_controls_enabled`]).
+pub fn set_media_controls_enabled(enabled: bool) {
+    // The default is stored as an absent key.
+    crate::utils::set_config(MEDIA_CONTROLS_KEY, if enabled { "true" } else { "" });
+}
+/// An action requested from the OS media controls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RestAction {
+    /// End the rest period now.
+    Skip,
+    /// Add [`REST_EXTEND_SECONDS`] to the rest period.
+    Extend,
+}
+/// Lock-screen title for a rest period with `remaining` seconds left
+/// (negative once the rest is exceeded).
+#[must_use]
+pub fn rest_title(label: &str, remaining: i64) -> String {
+    format!("{label} {}", crate::models::format_time_i64(remaining))
+}
+/// Install the action handlers and wait for the next requested action.
+///
+/// Returns `None` when the Media Session API is unavailable.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+pub async fn next_action() -> Option<RestAction> {
+    const JS: &str = r"
+const ms = navigator.mediaSession;
+if (!ms) { dioxus.send(null); } else {
+  const send = function(a){ return function(){ dioxus.send(a); }; };
+  ms.setActionHandler('pause', send('skip'));
+  ms.setActionHandler('nexttrack', send('skip'));
+  ms.setActionHandler('play', send('extend'));
+  try { ms.setActionHandler('seekforward', send('extend')); } catch (e) {}
+}";
+    let mut eval = dioxus::prelude::document::eval(JS);
+    eval.recv::<Option<RestAction>>().await.ok().flatten()
+}
+/// Show (or refresh) the rest timer in the OS media controls.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+pub fn show_rest(title: &str, subtitle: &str) {
+    let title = serde_json::to_string(title).unwrap_or_default();
+    let subtitle = serde_json::to_string(subtitle).unwrap_or_default();
+    let js = format!(
+        r"
+const ms = navigator.mediaSession;
+if (ms) {{
+  if (!window.__logoutSilence) {{
+    // 1 s of 8 kHz 8-bit mono silence.
+    const len = 8000, buf = new Uint8Array(44 + len), v = new DataView(buf.buffer);
+    const w = function(o, s){{ for (let i = 0; i < s.length; i++) buf[o + i] = s.charCodeAt(i); }};
+    w(0, 'RIFF'); v.setUint32(4, 36 + len, true); w(8, 'WAVEfmt ');
+    v.setUint32(16, 16, true); v.setUint16(20, 1, true); v.setUint16(22, 1, true);
+    v.setUint32(24, 8000, true); v.setUint32(28, 8000, true); v.setUint16(32, 1, true);
+    v.setUint16(34, 8, true); w(36, 'data'); v.setUint32(40, len, true); buf.fill(128, 44);
+    const audio = new Audio(URL.createObjectURL(new Blob([buf], {{type: 'audio/wav'}})));
+    audio.loop = true;
+    window.__logoutSilence = audio;
+  }}
+  window.__logoutSilence.play().catch(function(){{}});
+  ms.metadata = new MediaMetadata({{title: {title}, artist: {subtitle}, album: 'LogOut'}});
+  ms.playbackState = 'playing';
+}}"
+    );
+    let _ = dioxus::prelude::document::eval(&js);
+}
+/// Remove the rest timer from the OS media controls.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+pub fn clear() {
+    let _ = dioxus::prelude::document::eval(
+        r"
+const ms = navigator.mediaSession;
+if (ms) { ms.metadata = null; ms.playbackState = 'none'; }
+if (window.__logoutSilence) window.__logoutSilence.pause();",
+    );
+}
+#[cfg(not(all(target_arch = "wasm32", feature = "web-platform")))]
+pub async fn next_action() -> Option<RestAction> {
+    None
+}
+#[cfg(not(all(target_arch = "wasm32", feature = "web-platform")))]
+pub fn show_rest(title: &str, subtitle: &str) {
+    let _ = (title, subtitle);
+}
+#[cfg(not(all(target_arch = "wasm32", feature = "web-platform")))]
+pub fn clear() {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn rest_title_formats_remaining_time() {
+        assert_eq!(rest_title("Rest", 42), "Rest 00:42");
+        assert!(rest_title("Rest", -5).starts_with("Rest "));
+    }
+    #[test]
+    fn actions_deserialize_from_js_names() {
+        assert_eq!(
+            serde_json::from_str::<RestAction>("\"skip\"").unwrap(),
+            RestAction::Skip
+        );
+        assert_eq!(
+            serde_json::from_str::<RestAction>("\"extend\"").unwrap(),
+            RestAction::Extend
+        );
+    }
+}
@@ -1,11 +1,46 @@
+pub use logout_core::{
+    activity_export, backup, calendar_export, crypto, history_filter, rest_stats, session_stats,
+    share_link, strain, upstream_export,
+};
+pub mod a11y;
+pub mod achievements;
+pub mod app_lock;
 pub mod app_state;
+pub mod autosave;
+pub mod background_search;
+pub mod clock;
+pub mod cloud_backup;
+pub mod demo;
+pub mod diagnostics;
+pub mod display;
+pub mod drafts;
 pub mod exercise_db;
+pub mod exercise_images;
 pub mod exercise_loader;
+pub mod flow;
+pub mod global_hotkey;
+pub mod image_prefetch;
 #[cfg(feature = "mobile-platform")]
 pub(crate) mod imgcache;
+pub mod launcher;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod local_api;
+pub mod markdown;
+pub mod media_session;
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) mod native_queue;
 pub mod notifications;
+pub mod recovery;
 pub mod service_worker;
+pub mod share_image;
+pub mod shortcuts;
 pub mod storage;
+pub mod storage_usage;
+pub mod sync;
+pub mod timer_service;
+pub mod tour;
+pub mod transfer;
+pub mod tray;
 pub mod wake_lock;
+pub mod webhook;
+pub mod weekly_sets;
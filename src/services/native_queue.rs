@@ -6,7 +6,7 @@ use std::sync::{Arc, Mutex, OnceLock};
 /// A pending write operation. Data only, no signals.
 pub enum NativeOp {
     PutSession {
-        session: WorkoutSession,
+        session: Box<WorkoutSession>,
         previous: Option<WorkoutSession>,
     },
     DeleteSession {
@@ -14,6 +14,8 @@ pub enum NativeOp {
         snapshot: Option<WorkoutSession>,
     },
     PutExercise(Exercise),
+    /// Move a custom exercise to the trash.
+    DeleteExercise(String),
 }
 
 /// Result of a native operation, to be sent back to the UI.
@@ -32,6 +34,10 @@ pub enum NativeResult {
         id: String,
         result: Result<(), String>,
     },
+    DeleteExercise {
+        id: String,
+        result: Result<(), String>,
+    },
 }
 
 struct QueueState {
@@ -97,9 +103,10 @@ pub fn use_native_results() {
                         }
                         Err(e) => {
                             log::error!("Failed to save session {id}: {e}");
-                            toast
-                                .write()
-                                .push_back(format!("⚠️ Failed to save session: {e}"));
+                            toast.write().push_back(format!(
+                                "{}: {e}",
+                                dioxus_i18n::t!("toast-session-save-failed")
+                            ));
                             let mut sessions = sessions_sig.write();
                             match previous {
                                 None => sessions.retain(|x| x.id != id),
@@ -121,9 +128,10 @@ pub fn use_native_results() {
                         }
                         Err(e) => {
                             log::error!("Failed to delete session {id}: {e}");
-                            toast
-                                .write()
-                                .push_back(format!("⚠️ Failed to delete session: {e}"));
+                            toast.write().push_back(format!(
+                                "{}: {e}",
+                                dioxus_i18n::t!("toast-session-delete-failed")
+                            ));
                             if let Some(session) = snapshot {
                                 sessions_sig.write().push(session);
                             }
@@ -135,9 +143,22 @@ pub fn use_native_results() {
                         }
                         Err(e) => {
                             log::error!("Failed to save exercise {id}: {e}");
-                            toast
-                                .write()
-                                .push_back(format!("⚠️ Failed to save exercise: {e}"));
+                            toast.write().push_back(format!(
+                                "{}: {e}",
+                                dioxus_i18n::t!("toast-exercise-save-failed")
+                            ));
+                        }
+                    },
+                    NativeResult::DeleteExercise { id, result } => match result {
+                        Ok(()) => {
+                            log::info!("Successfully deleted exercise {id}");
+                        }
+                        Err(e) => {
+                            log::error!("Failed to delete exercise {id}: {e}");
+                            toast.write().push_back(format!(
+                                "{}: {e}",
+                                dioxus_i18n::t!("toast-exercise-delete-failed")
+                            ));
                         }
                     },
                 }
@@ -191,9 +212,10 @@ async fn drain() {
             NativeOp::DeleteSession { id, snapshot } => {
                 let id2 = id.clone();
                 let res = tokio::task::spawn_blocking(move || {
-                    super::storage::native_storage::delete_item(
+                    super::storage::native_storage::move_to_trash(
                         super::storage::native_storage::STORE_SESSIONS,
                         &id,
+                        crate::models::get_current_timestamp(),
                     )
                 })
                 .await;
@@ -225,6 +247,23 @@ async fn drain() {
                 };
                 let _ = tx.send(NativeResult::PutExercise { id, result });
             }
+            NativeOp::DeleteExercise(id) => {
+                let id2 = id.clone();
+                let res = tokio::task::spawn_blocking(move || {
+                    super::storage::native_storage::move_to_trash(
+                        super::storage::native_storage::STORE_CUSTOM_EXERCISES,
+                        &id,
+                        crate::models::get_current_timestamp(),
+                    )
+                })
+                .await;
+                let result = match res {
+                    Ok(Ok(())) => Ok(()),
+                    Ok(Err(e)) => Err(e.to_string()),
+                    Err(e) => Err(format!("Task panicked: {e}")),
+                };
+                let _ = tx.send(NativeResult::DeleteExercise { id: id2, result });
+            }
         }
         tokio::task::yield_now().await;
     }
@@ -4,12 +4,43 @@
 /// platform-native APIs:
 /// - **Android**: JNI calls to `NotificationManager` (foreground sounds/vibrate).
 /// - **Web**: PWA `ServiceWorkerRegistration.showNotification` (sound/vibrate support).
-/// - **Desktop**: (TODO) local system notification implementation.
+/// - **Desktop**: the system notification daemon or center through
+///   `notify-rust` (D-Bus on Linux, WinRT toasts on Windows, macOS
+///   notification center).
+///
+/// In quiet mode (see [`quiet_mode_enabled`]) the alerts make no sound and
+/// only vibrate: through a vibration-only channel on Android and the
+/// Vibration API on the web.  Desktop notifications are then silent.
 
 /// Notification channel ID used for workout alerts on Android.
 #[cfg(target_os = "android")]
 pub const WORKOUT_CHANNEL_ID: &str = "workout_reminders";
 
+/// Notification channel ID used for workout alerts in quiet mode on Android:
+/// the sound of a channel cannot be changed once it is created.
+#[cfg(target_os = "android")]
+pub const QUIET_CHANNEL_ID: &str = "workout_reminders_quiet";
+
+/// Config key storing `"true"` when the user enabled quiet mode.
+const QUIET_MODE_KEY: &str = "quiet_mode";
+
+/// Vibration pattern of the alerts, in milliseconds: vibrate, pause, vibrate.
+#[cfg(any(target_arch = "wasm32", target_os = "android"))]
+const VIBRATION_PATTERN_MS: [u32; 3] = [200, 100, 200];
+
+/// Whether quiet mode is enabled (default `false`): the rest and duration
+/// alerts vibrate without any sound, e.g. at the gym or during quiet hours.
+#[must_use]
+pub fn quiet_mode_enabled() -> bool {
+    crate::utils::get_config(QUIET_MODE_KEY).as_deref() == Some("true")
+}
+
+/// Persist the quiet mode preference (see [`quiet_mode_enabled`]).
+pub fn set_quiet_mode_enabled(enabled: bool) {
+    // The default is stored as an absent key.
+    crate::utils::set_config(QUIET_MODE_KEY, if enabled { "true" } else { "" });
+}
+
 /// Returns `true` if notification permission has been granted by the user.
 ///
 /// On Android, this checks `NotificationManager.areNotificationsEnabled()`.
@@ -140,9 +171,12 @@ pub fn open_notification_settings() {
 
 /// Cross-platform notification dispatch.
 ///
-/// Dispatches the request to the best available platform-specific implementation.
-/// On platforms without an implementation yet, this is a no-op.
+/// Dispatches the request to the best available platform-specific implementation,
+/// the one API every timer alert goes through.  On platforms without an
+/// implementation yet (iOS), the notification is only logged.
 pub fn send_notification(title: &str, body: &str, tag: &str) {
+    // Timer milestones are also spoken while the app is in front.
+    super::a11y::announce(&format!("{title}. {body}"));
     #[cfg(target_os = "android")]
     {
         match try_send_android_notification(title, body, tag) {
@@ -154,9 +188,12 @@ pub fn send_notification(title: &str, body: &str, tag: &str) {
     {
         send_web_notification(title, body, tag);
     }
-    #[cfg(all(not(target_os = "android"), not(target_arch = "wasm32")))]
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+    {
+        send_desktop_notification(title, body, tag);
+    }
+    #[cfg(target_os = "ios")]
     {
-        // Suppress unused warnings for other native platforms (e.g. desktop).
         let _ = (title, body, tag);
         log::info!("Notification [tag={tag}]: {title} - {body}");
     }
@@ -208,37 +245,78 @@ pub fn setup_notification_channel() {
                 .i()
                 .map_err(|e| format!("IMPORTANCE_HIGH as int: {e}"))?;
 
-            let channel_id = env
-                .new_string(WORKOUT_CHANNEL_ID)
-                .map_err(|e| format!("new_string channel_id: {e}"))?;
-            let channel_name = env
-                .new_string("Workout Reminders")
-                .map_err(|e| format!("new_string channel_name: {e}"))?;
-            let channel = env
-                .new_object(
-                    "android/app/NotificationChannel",
-                    "(Ljava/lang/String;Ljava/lang/CharSequence;I)V",
-                    &[
-                        (&channel_id).into(),
-                        (&channel_name).into(),
-                        jni::objects::JValue::Int(importance_high),
-                    ],
-                )
-                .map_err(|e| format!("new NotificationChannel: {e}"))?;
+            for (id, name, quiet) in [
+                (WORKOUT_CHANNEL_ID, "Workout Reminders", false),
+                (QUIET_CHANNEL_ID, "Workout Reminders (vibration only)", true),
+            ] {
+                let channel_id = env
+                    .new_string(id)
+                    .map_err(|e| format!("new_string channel_id: {e}"))?;
+                let channel_name = env
+                    .new_string(name)
+                    .map_err(|e| format!("new_string channel_name: {e}"))?;
+                let channel = env
+                    .new_object(
+                        "android/app/NotificationChannel",
+                        "(Ljava/lang/String;Ljava/lang/CharSequence;I)V",
+                        &[
+                            (&channel_id).into(),
+                            (&channel_name).into(),
+                            jni::objects::JValue::Int(importance_high),
+                        ],
+                    )
+                    .map_err(|e| format!("new NotificationChannel: {e}"))?;
+
+                if quiet {
+                    let none = JObject::null();
+                    env.call_method(
+                        &channel,
+                        "setSound",
+                        "(Landroid/net/Uri;Landroid/media/AudioAttributes;)V",
+                        &[(&none).into(), (&none).into()],
+                    )
+                    .map_err(|e| format!("setSound: {e}"))?;
+                    env.call_method(
+                        &channel,
+                        "enableVibration",
+                        "(Z)V",
+                        &[jni::objects::JValue::Bool(1)],
+                    )
+                    .map_err(|e| format!("enableVibration: {e}"))?;
+                    let pattern_ms = VIBRATION_PATTERN_MS.map(i64::from);
+                    // The pattern starts with the delay before the first vibration.
+                    let pattern = env
+                        .new_long_array(4)
+                        .map_err(|e| format!("new_long_array: {e}"))?;
+                    env.set_long_array_region(
+                        &pattern,
+                        0,
+                        &[0, pattern_ms[0], pattern_ms[1], pattern_ms[2]],
+                    )
+                    .map_err(|e| format!("set_long_array_region: {e}"))?;
+                    env.call_method(
+                        &channel,
+                        "setVibrationPattern",
+                        "([J)V",
+                        &[jni::objects::JValue::Object(&pattern)],
+                    )
+                    .map_err(|e| format!("setVibrationPattern: {e}"))?;
+                }
 
-            env.call_method(
-                &nm,
-                "createNotificationChannel",
-                "(Landroid/app/NotificationChannel;)V",
-                &[(&channel).into()],
-            )
-            .map_err(|e| format!("createNotificationChannel: {e}"))?;
+                env.call_method(
+                    &nm,
+                    "createNotificationChannel",
+                    "(Landroid/app/NotificationChannel;)V",
+                    &[(&channel).into()],
+                )
+                .map_err(|e| format!("createNotificationChannel: {e}"))?;
+            }
 
             Ok(())
         })();
 
         match result {
-            Ok(()) => log::info!("Android notification channel '{WORKOUT_CHANNEL_ID}' created"),
+            Ok(()) => log::info!("Android notification channels '{WORKOUT_CHANNEL_ID}' and '{QUIET_CHANNEL_ID}' created"),
             Err(e) => log::warn!("Failed to create Android notification channel: {e}"),
         }
     }
@@ -278,8 +356,13 @@ fn try_send_android_notification(title: &str, body: &str, tag: &str) -> Result<(
         .l()
         .map_err(|e| format!("NotificationManager obj: {e}"))?;
 
+    let channel_id = if quiet_mode_enabled() {
+        QUIET_CHANNEL_ID
+    } else {
+        WORKOUT_CHANNEL_ID
+    };
     let channel_id_js = env
-        .new_string(WORKOUT_CHANNEL_ID)
+        .new_string(channel_id)
         .map_err(|e| format!("new_string channel_id: {e}"))?;
     let builder = env
         .new_object(
@@ -370,6 +453,31 @@ fn try_send_android_notification(title: &str, body: &str, tag: &str) -> Result<(
     Ok(())
 }
 
+/// Desktop notification delivery through `notify-rust`.
+///
+/// Showing a notification is a blocking D-Bus or WinRT call, so it is made
+/// on a short-lived thread rather than on the UI thread.
+#[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+fn send_desktop_notification(title: &str, body: &str, tag: &str) {
+    let title = title.to_owned();
+    let body = body.to_owned();
+    let tag = tag.to_owned();
+    let quiet = quiet_mode_enabled();
+    std::thread::spawn(move || {
+        let mut notification = notify_rust::Notification::new();
+        notification.appname("LogOut").summary(&title).body(&body);
+        // Only the freedesktop notification servers support silencing.
+        #[cfg(all(unix, not(target_os = "macos")))]
+        notification.hint(notify_rust::Hint::SuppressSound(quiet));
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        let _ = quiet;
+        match notification.show() {
+            Ok(_) => log::debug!("Desktop notification sent: tag={tag}"),
+            Err(e) => log::warn!("Failed to send desktop notification (tag={tag}): {e}"),
+        }
+    });
+}
+
 /// Web-specific notification delivery using the browser's ServiceWorker API.
 #[cfg(target_arch = "wasm32")]
 fn send_web_notification(title: &str, body: &str, tag: &str) {
@@ -383,9 +491,16 @@ fn send_web_notification(title: &str, body: &str, tag: &str) {
     let opts = NotificationOptions::new();
     opts.set_body(&body);
     opts.set_tag(&tag);
-    let vibrate = serde_wasm_bindgen::to_value(&[200u32, 100, 200]).ok();
-    if let Some(v) = vibrate {
-        opts.set_vibrate(&v);
+    let vibrate = serde_wasm_bindgen::to_value(&VIBRATION_PATTERN_MS).ok();
+    if quiet_mode_enabled() {
+        // A silent notification does not vibrate either: vibrate the device
+        // through the Vibration API instead.
+        opts.set_silent(Some(true));
+        if let (Some(window), Some(v)) = (web_sys::window(), &vibrate) {
+            let _ = window.navigator().vibrate_with_pattern(v);
+        }
+    } else if let Some(v) = &vibrate {
+        opts.set_vibrate(v);
     }
     wasm_bindgen_futures::spawn_local(async move {
         if let Some(window) = web_sys::window() {
@@ -0,0 +1,174 @@
+/// Simple muscle recovery model behind the "ready to train" indicator.
+///
+/// Every set logged in the last [`RECOVERY_HOURS`] tires the muscles it
+/// works: one unit for primary muscles, [`SECONDARY_SHARE`] for secondary
+/// ones, fading linearly to nothing as the muscle rests.  A muscle with
+/// [`FULL_FATIGUE_SETS`] fresh units is at 0 % freshness.
+use crate::models::{get_current_timestamp, Exercise, Muscle, WorkoutSession};
+use crate::services::{exercise_db, storage};
+use dioxus::prelude::*;
+use std::collections::HashMap;
+/// Hours after which a set no longer weighs on the muscles it worked.
+pub const RECOVERY_HOURS: u64 = 72;
+/// Fatigue units (fresh sets) leaving a muscle at 0 % freshness.
+const FULL_FATIGUE_SETS: f64 = 10.0;
+/// Fatigue units a set costs its secondary muscles.
+const SECONDARY_SHARE: f64 = 0.5;
+/// Freshness (percent) from which a muscle is ready to be trained again.
+pub const READY_FRESHNESS: u8 = 70;
+/// Number of most recent completed sessions scanned for recent sets.
+const RECENT_SESSIONS: usize = 50;
+const SECS_PER_HOUR: u64 = 3_600;
+/// Freshness percentage of every muscle trained in the last
+/// [`RECOVERY_HOURS`]; muscles absent from the map are fully fresh.
+#[must_use]
+pub fn muscle_freshness<E: AsRef<Exercise>>(
+    sessions: &[WorkoutSession],
+    db: &[E],
+    custom: &[E],
+    now: u64,
+) -> HashMap<Muscle, u8> {
+    let window = RECOVERY_HOURS * SECS_PER_HOUR;
+    let mut fatigue: HashMap<Muscle, f64> = HashMap::new();
+    for log in sessions.iter().flat_map(|s| &s.exercise_logs) {
+        let Some(end) = log.end_time else {
+            continue;
+        };
+        let rested = now.saturating_sub(end);
+        if rested >= window {
+            continue;
+        }
+        let Some(exercise) = exercise_db::resolve_exercise(db, custom, &log.exercise_id) else {
+            continue;
+        };
+        let exercise = exercise.as_ref();
+        #[allow(clippy::cast_precision_loss)]
+        let remaining = 1.0 - rested as f64 / window as f64;
+        for &muscle in &exercise.primary_muscles {
+            *fatigue.entry(muscle).or_default() += remaining;
+        }
+        for &muscle in &exercise.secondary_muscles {
+            *fatigue.entry(muscle).or_default() += remaining * SECONDARY_SHARE;
+        }
+    }
+    fatigue
+        .into_iter()
+        .map(|(muscle, units)| {
+            let fresh = 100.0 * (1.0 - units / FULL_FATIGUE_SETS).clamp(0.0, 1.0);
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            (muscle, fresh.round() as u8)
+        })
+        .filter(|&(_, fresh)| fresh < 100)
+        .collect()
+}
+/// Freshness of `exercise`: that of its most tired primary muscle.
+#[must_use]
+pub fn exercise_freshness(exercise: &Exercise, freshness: &HashMap<Muscle, u8>) -> u8 {
+    exercise
+        .primary_muscles
+        .iter()
+        .filter_map(|m| freshness.get(m).copied())
+        .min()
+        .unwrap_or(100)
+}
+/// Whether `exercise` mainly works a muscle that is not ready yet.
+#[must_use]
+pub fn is_tired(exercise: &Exercise, freshness: &HashMap<Muscle, u8>) -> bool {
+    exercise_freshness(exercise, freshness) < READY_FRESHNESS
+}
+/// Reactive muscle freshness, from the recent completed sessions and those
+/// in memory (including the active one).
+pub fn use_muscle_freshness() -> Memo<HashMap<Muscle, u8>> {
+    let sessions = storage::use_sessions();
+    let all_exercises = exercise_db::use_exercises();
+    let custom_exercises = storage::use_custom_exercises();
+    let recent = use_resource(|| async {
+        storage::load_completed_sessions_page(RECENT_SESSIONS, 0, true)
+            .await
+            .unwrap_or_else(|e| {
+                log::error!("Failed to load recent sessions for recovery: {e}");
+                Vec::new()
+            })
+    });
+    use_memo(move || {
+        let in_memory = sessions.read();
+        let mut all: Vec<WorkoutSession> = in_memory.clone();
+        if let Some(recent) = recent.read().as_ref() {
+            all.extend(
+                recent
+                    .iter()
+                    .filter(|s| !in_memory.iter().any(|m| m.id == s.id))
+                    .cloned(),
+            );
+        }
+        muscle_freshness(
+            &all,
+            &all_exercises.read(),
+            &custom_exercises.read(),
+            get_current_timestamp(),
+        )
+    })
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, ExerciseLog, Weight};
+    const NOW: u64 = 1_800_000_000;
+    fn exercise(id: &str, primary: Vec<Muscle>, secondary: Vec<Muscle>) -> Exercise {
+        Exercise {
+            id: id.into(),
+            name: id.into(),
+            name_lower: id.into(),
+            force: None,
+            level: None,
+            mechanic: None,
+            equipment: None,
+            primary_muscles: primary,
+            secondary_muscles: secondary,
+            instructions: vec![],
+            category: Category::Strength,
+            images: vec![],
+            videos: vec![],
+            i18n: None,
+            rest_seconds: None,
+        }
+    }
+    fn session(id: &str, sets: usize, hours_ago: u64) -> WorkoutSession {
+        let end = NOW - hours_ago * SECS_PER_HOUR;
+        let mut s = WorkoutSession::new();
+        s.end_time = Some(end);
+        s.exercise_logs = (0..sets)
+            .map(|_| ExerciseLog {
+                exercise_id: id.into(),
+                exercise_name: id.into(),
+                start_time: end - 60,
+                end_time: Some(end),
+                weight_hg: Weight(600),
+                reps: Some(8),
+                ..Default::default()
+            })
+            .collect();
+        s
+    }
+    #[test]
+    fn recent_sets_tire_primary_more_than_secondary() {
+        let db = vec![exercise(
+            "bench",
+            vec![Muscle::Chest],
+            vec![Muscle::Triceps],
+        )];
+        let freshness = muscle_freshness(&[session("bench", 5, 0)], &db, &[], NOW);
+        assert_eq!(freshness[&Muscle::Chest], 50);
+        assert_eq!(freshness[&Muscle::Triceps], 75);
+        assert!(is_tired(&db[0], &freshness));
+    }
+    #[test]
+    fn muscles_recover_with_time() {
+        let db = vec![exercise("squat", vec![Muscle::Quadriceps], vec![])];
+        let half = muscle_freshness(&[session("squat", 10, RECOVERY_HOURS / 2)], &db, &[], NOW);
+        assert_eq!(half[&Muscle::Quadriceps], 50);
+        let rested = muscle_freshness(&[session("squat", 10, RECOVERY_HOURS)], &db, &[], NOW);
+        assert!(rested.is_empty());
+        assert_eq!(exercise_freshness(&db[0], &rested), 100);
+    }
+}
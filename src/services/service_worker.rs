@@ -1,7 +1,17 @@
-/// Service Worker registration for offline image caching
+/// Service Worker registration for offline app shell and image caching
 ///
 /// This module handles the registration of the Service Worker (sw.js) which provides
-/// offline caching for exercise images loaded from the GitHub CDN.
+/// offline caching for the app shell (index, WASM bundle, scripts, stylesheets) and
+/// for the images of the exercises performed (see [`post_offline_images`]).
+///
+/// The worker is registered as `sw.js?v=<crate version>`: the version names the
+/// app-shell cache, so a new release installs a fresh worker that precaches the new
+/// (hashed) assets and deletes the previous cache on activation.
+///
+/// Assets injected at runtime (component stylesheets) are not referenced from
+/// `index.html`, so once the app has rendered, every same-origin resource the page
+/// loaded is sent to the worker in a `precache` message.  The app therefore works
+/// fully offline from the first visit.
 ///
 /// ## Platform Compatibility
 ///
@@ -26,17 +36,25 @@
 /// ```bash
 /// cargo build --no-default-features
 /// ```
+/// Service Worker script URL, versioned for cache busting.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+const SW_URL: &str = concat!("./sw.js?v=", env!("CARGO_PKG_VERSION"));
+/// Delay before reporting loaded resources, so lazily mounted stylesheets are included.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+const PRECACHE_DELAY_MS: u32 = 5_000;
 #[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
 pub fn register_service_worker() {
     use web_sys::window;
     if let Some(window) = window() {
         let navigator = window.navigator();
         let sw_container = navigator.service_worker();
-        let registration = sw_container.register("./sw.js");
+        let registration = sw_container.register(SW_URL);
         let _ = wasm_bindgen_futures::spawn_local(async move {
             match wasm_bindgen_futures::JsFuture::from(registration).await {
-                Ok(_) => {
-                    log::info!("Service Worker registered successfully for offline image caching");
+                Ok(registration) => {
+                    log::info!("Service Worker registered successfully for offline caching");
+                    gloo_timers::future::TimeoutFuture::new(PRECACHE_DELAY_MS).await;
+                    send_loaded_resources_to_worker(&registration.into());
                 }
                 Err(err) => {
                     log::error!("Service Worker registration failed: {:?}", err);
@@ -46,6 +64,179 @@ pub fn register_service_worker() {
         });
     }
 }
+/// Post the URLs of every same-origin resource loaded so far to the active worker.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+fn send_loaded_resources_to_worker(registration: &web_sys::ServiceWorkerRegistration) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Some(worker) = registration.active() else {
+        // Still installing: resources fetched once it activates are cached
+        // by its fetch handler instead.
+        return;
+    };
+    let Ok(origin) = window.location().origin() else {
+        return;
+    };
+    let Some(performance) = window.performance() else {
+        return;
+    };
+    let urls = js_sys::Array::new();
+    for entry in performance.get_entries_by_type("resource") {
+        if let Some(name) = wasm_bindgen::JsCast::dyn_ref::<web_sys::PerformanceEntry>(&entry)
+            .map(web_sys::PerformanceEntry::name)
+        {
+            if is_precachable(&name, &origin) {
+                urls.push(&name.into());
+            }
+        }
+    }
+    let message = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&message, &"type".into(), &"precache".into());
+    let _ = js_sys::Reflect::set(&message, &"urls".into(), &urls);
+    if let Err(e) = worker.post_message(&message) {
+        log::warn!("Could not send precache list to Service Worker: {e:?}");
+    }
+}
+/// Send the worker the exercise image `urls` to keep offline, dropping the
+/// other cached images, and those of them to `download` right away if missing
+/// (see [`super::image_prefetch`]).
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+pub fn post_offline_images(urls: Vec<String>, download: Vec<String>) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Ok(ready) = window.navigator().service_worker().ready() else {
+        return;
+    };
+    wasm_bindgen_futures::spawn_local(async move {
+        // Wait for the worker on the first visit, before it controls the page.
+        let Ok(registration) = wasm_bindgen_futures::JsFuture::from(ready).await else {
+            return;
+        };
+        let registration: web_sys::ServiceWorkerRegistration = registration.into();
+        let Some(worker) = registration.active() else {
+            return;
+        };
+        let array = |urls: Vec<String>| -> js_sys::Array {
+            urls.into_iter().map(wasm_bindgen::JsValue::from).collect()
+        };
+        let message = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&message, &"type".into(), &"offline-images".into());
+        let _ = js_sys::Reflect::set(&message, &"urls".into(), &array(urls));
+        let _ = js_sys::Reflect::set(&message, &"download".into(), &array(download));
+        if let Err(e) = worker.post_message(&message) {
+            log::warn!("Could not send offline images to Service Worker: {e:?}");
+        }
+    });
+}
+#[cfg(all(target_arch = "wasm32", not(feature = "web-platform")))]
+pub fn post_offline_images(_urls: Vec<String>, _download: Vec<String>) {}
+/// Whether `url` is an app asset worth precaching: same origin, and not the
+/// worker script itself (which the browser fetches and caches on its own).
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web-platform")))]
+fn is_precachable(url: &str, origin: &str) -> bool {
+    url.strip_prefix(origin)
+        .is_some_and(|path| path.starts_with('/') && !path.contains("/sw.js"))
+}
+/// Whether a newer build has been deployed than the one currently running.
+///
+/// Detects both a new worker waiting to activate and a changed (hashed) WASM
+/// bundle name in a fresh copy of `index.html`, since the worker script itself
+/// does not change between releases.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+pub async fn check_for_update() -> bool {
+    const JS: &str = r#"
+(async function(){
+  try {
+    const container = navigator.serviceWorker;
+    const reg = container && await container.getRegistration();
+    if (reg) {
+      await reg.update().catch(function(){});
+      if (reg.waiting && container.controller) { dioxus.send(true); return; }
+    }
+    const bundle = function(html){ const m = html.match(/wasm\/[^"']+\.js/); return m && m[0]; };
+    const res = await fetch('./', {cache: 'no-store'});
+    const fresh = bundle(await res.text());
+    const running = bundle(document.documentElement.outerHTML);
+    dioxus.send(!!(fresh && running && fresh !== running));
+  } catch (e) { dioxus.send(false); }
+})();"#;
+    dioxus::prelude::document::eval(JS)
+        .recv::<bool>()
+        .await
+        .unwrap_or(false)
+}
+/// Activate the waiting worker (if any) and reload into the new build.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+pub fn apply_update() {
+    const JS: &str = r#"
+(async function(){
+  const container = navigator.serviceWorker;
+  const reg = container && await container.getRegistration();
+  if (reg && reg.waiting) {
+    container.addEventListener('controllerchange', function(){ location.reload(); });
+    reg.waiting.postMessage({type: 'skip-waiting'});
+  } else {
+    location.reload();
+  }
+})();"#;
+    let _ = dioxus::prelude::document::eval(JS);
+}
+/// `localStorage` key set once the user dismissed the install banner.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+const INSTALL_DISMISSED_KEY: &str = "install_banner_dismissed";
+/// Resolve once the browser offers to install the PWA (`beforeinstallprompt`).
+///
+/// The event is kept on `window.__logoutInstall` so [`prompt_install`] can
+/// show the native dialog later from a user gesture.  Returns `false` when
+/// the banner was dismissed before or the app is already installed.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+pub async fn wait_for_install_prompt() -> bool {
+    if crate::utils::get_config(INSTALL_DISMISSED_KEY).is_some() {
+        return false;
+    }
+    const JS: &str = r#"
+if (window.__logoutInstall) {
+  dioxus.send(true);
+} else {
+  window.addEventListener('beforeinstallprompt', function(e){
+    e.preventDefault();
+    window.__logoutInstall = e;
+    dioxus.send(true);
+  }, {once: true});
+}"#;
+    dioxus::prelude::document::eval(JS)
+        .recv::<bool>()
+        .await
+        .unwrap_or(false)
+}
+/// Show the browser's install dialog captured by [`wait_for_install_prompt`].
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+pub fn prompt_install() {
+    let _ = dioxus::prelude::document::eval(
+        "const e = window.__logoutInstall; if (e) { window.__logoutInstall = null; e.prompt(); }",
+    );
+}
+/// Never show the install banner again.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+pub fn dismiss_install_prompt() {
+    crate::utils::set_config(INSTALL_DISMISSED_KEY, "1");
+}
+#[cfg(all(target_arch = "wasm32", not(feature = "web-platform")))]
+pub async fn wait_for_install_prompt() -> bool {
+    false
+}
+#[cfg(not(all(target_arch = "wasm32", feature = "web-platform")))]
+pub fn prompt_install() {}
+#[cfg(not(all(target_arch = "wasm32", feature = "web-platform")))]
+pub fn dismiss_install_prompt() {}
+#[cfg(all(target_arch = "wasm32", not(feature = "web-platform")))]
+pub async fn check_for_update() -> bool {
+    false
+}
+#[cfg(not(all(target_arch = "wasm32", feature = "web-platform")))]
+pub fn apply_update() {}
 #[cfg(not(all(target_arch = "wasm32", feature = "web-platform")))]
 pub fn register_service_worker() {
     log::info!("Service Worker disabled: running on non-web platform (Blitz-compatible mode)");
@@ -57,4 +248,22 @@ mod tests {
     fn register_service_worker_noop_on_native() {
         register_service_worker();
     }
+    #[test]
+    fn precache_filter_keeps_same_origin_assets() {
+        let origin = "https://logout.example";
+        assert!(is_precachable(
+            "https://logout.example/assets/style-dxh12.css",
+            origin
+        ));
+        assert!(is_precachable(
+            "https://logout.example/wasm/log-out_bg.wasm",
+            origin
+        ));
+        assert!(!is_precachable("https://logout.example/sw.js?v=1", origin));
+        assert!(!is_precachable(
+            "https://raw.githubusercontent.com/a.jpg",
+            origin
+        ));
+        assert!(!is_precachable("https://logout.example.evil/x.js", origin));
+    }
 }
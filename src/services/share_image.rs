@@ -0,0 +1,285 @@
+/// Shareable picture of a finished session.
+///
+/// A [`ShareCard`] (exercises, volume, duration and the personal records of
+/// the session) is drawn as an SVG in Rust, then rasterised to PNG by a
+/// canvas inside the page or `WebView` and handed to the Web Share API.
+/// Where sharing files is not supported the PNG is downloaded instead; on
+/// Android, whose `WebView` does neither, the SVG is saved by the caller.
+use crate::models::{format_time, WorkoutSession, HG_PER_KG};
+use dioxus::prelude::*;
+use std::fmt::Write as _;
+/// Width of the picture, in pixels.
+pub const CARD_WIDTH: u32 = 1080;
+/// Height of everything but the exercise and record rows, in pixels.
+const CARD_BASE_HEIGHT: u32 = 520;
+/// Height of one exercise or record row, in pixels.
+const ROW_HEIGHT: u32 = 56;
+/// Exercises listed before the rest are summed up as "+N".
+pub const MAX_EXERCISE_ROWS: usize = 8;
+/// Horizontal margin, in pixels.
+const MARGIN: u32 = 72;
+/// Accent color of the app.
+const ACCENT: &str = "#ff7a18";
+/// Localised text and figures drawn on the picture.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ShareCard {
+    /// Headline, e.g. "Workout of 12 March".
+    pub title: String,
+    /// Localised labels of the duration, volume and sets figures.
+    pub labels: [String; 3],
+    pub duration_s: u64,
+    pub volume_kg: f64,
+    pub set_count: usize,
+    /// Exercise names with their number of sets, in order.
+    pub exercises: Vec<(String, usize)>,
+    /// Exercises whose heaviest load beat every earlier session, in kg.
+    pub records: Vec<(String, f64)>,
+}
+/// Exercise names of `session` with their number of completed sets, in
+/// order of first appearance.
+#[must_use]
+pub fn exercise_sets(session: &WorkoutSession) -> Vec<(String, usize)> {
+    let mut rows: Vec<(String, usize)> = Vec::new();
+    for log in session.exercise_logs.iter().filter(|l| l.is_complete()) {
+        match rows.iter_mut().find(|(name, _)| *name == log.exercise_name) {
+            Some((_, sets)) => *sets += 1,
+            None => rows.push((log.exercise_name.clone(), 1)),
+        }
+    }
+    rows
+}
+/// Heaviest loads of `session` beating those of every completed session of
+/// `history` started before it (warm-up sets excluded), in kg.  A first
+/// performance of an exercise is not a record.
+#[must_use]
+pub fn session_records(session: &WorkoutSession, history: &[WorkoutSession]) -> Vec<(String, f64)> {
+    let heaviest = |s: &WorkoutSession, id: &str| {
+        s.exercise_logs
+            .iter()
+            .filter(|l| l.exercise_id == id && l.is_complete() && l.set_kind.counts_for_stats())
+            .map(|l| l.weight_hg.0)
+            .max()
+    };
+    let mut records: Vec<(String, f64)> = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    for log in &session.exercise_logs {
+        if !seen.insert(log.exercise_id.as_str()) {
+            continue;
+        }
+        let Some(best) = heaviest(session, &log.exercise_id).filter(|w| *w > 0) else {
+            continue;
+        };
+        let previous = history
+            .iter()
+            .filter(|s| s.id != session.id && !s.is_active() && s.start_time < session.start_time)
+            .filter_map(|s| heaviest(s, &log.exercise_id))
+            .max();
+        if previous.is_some_and(|p| best > p) {
+            records.push((log.exercise_name.clone(), f64::from(best) / HG_PER_KG));
+        }
+    }
+    records
+}
+/// Escape `text` for an SVG text node.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+/// Height of the picture of `card`, in pixels.
+#[must_use]
+pub fn card_height(card: &ShareCard) -> u32 {
+    let exercise_rows = card.exercises.len().min(MAX_EXERCISE_ROWS + 1);
+    let rows = u32::try_from(exercise_rows + card.records.len()).unwrap_or(u32::MAX);
+    CARD_BASE_HEIGHT.saturating_add(rows.saturating_mul(ROW_HEIGHT))
+}
+/// Draw `card` as a standalone SVG document.
+#[must_use]
+pub fn render_svg(card: &ShareCard) -> String {
+    let height = card_height(card);
+    let mut svg = format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{CARD_WIDTH}" height="{height}" viewBox="0 0 {CARD_WIDTH} {height}" font-family="system-ui, sans-serif"><defs><linearGradient id="bg" x1="0" y1="0" x2="0" y2="1"><stop offset="0" stop-color="#1e1e24"/><stop offset="1" stop-color="#121215"/></linearGradient></defs><rect width="100%" height="100%" fill="url(#bg)"/><text x="{MARGIN}" y="110" fill="{ACCENT}" font-size="44" font-weight="700">LogOut</text><text x="{MARGIN}" y="180" fill="#fff" font-size="52" font-weight="700">{}</text>"##,
+        escape(&card.title)
+    );
+    let figures = [
+        format_time(card.duration_s),
+        format!("{:.0} kg", card.volume_kg),
+        card.set_count.to_string(),
+    ];
+    let column = (CARD_WIDTH - 2 * MARGIN) / 3;
+    for (i, (figure, label)) in (0u32..).zip(figures.iter().zip(&card.labels)) {
+        let x = MARGIN + i * column;
+        let _ = write!(
+            svg,
+            r##"<text x="{x}" y="300" fill="#fff" font-size="64" font-weight="700">{}</text><text x="{x}" y="350" fill="#aaa" font-size="32">{}</text>"##,
+            escape(figure),
+            escape(label)
+        );
+    }
+    let mut y = 450;
+    let right = CARD_WIDTH - MARGIN;
+    for (name, sets) in card.exercises.iter().take(MAX_EXERCISE_ROWS) {
+        let _ = write!(
+            svg,
+            r##"<text x="{MARGIN}" y="{y}" fill="#eee" font-size="36">{}</text><text x="{right}" y="{y}" fill="#aaa" font-size="36" text-anchor="end">× {sets}</text>"##,
+            escape(name)
+        );
+        y += ROW_HEIGHT;
+    }
+    if card.exercises.len() > MAX_EXERCISE_ROWS {
+        let _ = write!(
+            svg,
+            r##"<text x="{MARGIN}" y="{y}" fill="#aaa" font-size="36">+{}</text>"##,
+            card.exercises.len() - MAX_EXERCISE_ROWS
+        );
+        y += ROW_HEIGHT;
+    }
+    for (name, kg) in &card.records {
+        let _ = write!(
+            svg,
+            r##"<text x="{MARGIN}" y="{y}" fill="{ACCENT}" font-size="36">🏆 {}</text><text x="{right}" y="{y}" fill="{ACCENT}" font-size="36" text-anchor="end">{kg} kg</text>"##,
+            escape(name)
+        );
+        y += ROW_HEIGHT;
+    }
+    svg.push_str("</svg>");
+    svg
+}
+/// Outcome of [`share_png`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareOutcome {
+    /// Handed to the system share sheet.
+    Shared,
+    /// Saved as a file instead.
+    Saved,
+    /// The user dismissed the share sheet.
+    Cancelled,
+    /// Neither sharing nor downloading files is possible (Android `WebView`):
+    /// the caller saves the SVG instead.
+    Unsupported,
+}
+/// Rasterise `svg` (of `height` pixels) to PNG and share it as `filename`,
+/// falling back to a download.
+///
+/// # Errors
+/// If the picture cannot be drawn or shared, with the browser's message.
+pub async fn share_png(
+    svg: &str,
+    height: u32,
+    filename: &str,
+    title: &str,
+) -> Result<ShareOutcome, String> {
+    // Encode the values as JSON strings so they are safely embedded in the
+    // JavaScript snippet without any injection risk.
+    let svg_js = serde_json::to_string(svg).unwrap_or_default();
+    let filename_js = serde_json::to_string(filename).unwrap_or_default();
+    let title_js = serde_json::to_string(title).unwrap_or_default();
+    let can_download = !cfg!(target_os = "android");
+    let mut eval = document::eval(&format!(
+        r"(function(){{
+  var img=new Image();
+  img.onload=function(){{
+    var c=document.createElement('canvas');
+    c.width={CARD_WIDTH}; c.height={height};
+    c.getContext('2d').drawImage(img,0,0);
+    c.toBlob(function(blob){{
+      if(!blob){{dioxus.send('error:canvas');return;}}
+      var file=new File([blob],{filename_js},{{type:'image/png'}});
+      if(navigator.canShare&&navigator.canShare({{files:[file]}})){{
+        navigator.share({{files:[file],title:{title_js}}})
+          .then(function(){{dioxus.send('shared');}})
+          .catch(function(e){{dioxus.send(e&&e.name==='AbortError'?'cancelled':'error:'+e);}});
+      }}else if({can_download}){{
+        var u=URL.createObjectURL(blob);
+        var a=document.createElement('a');
+        a.href=u; a.download={filename_js};
+        document.body.appendChild(a); a.click(); document.body.removeChild(a);
+        setTimeout(function(){{URL.revokeObjectURL(u);}},100);
+        dioxus.send('saved');
+      }}else{{dioxus.send('unsupported');}}
+    }},'image/png');
+  }};
+  img.onerror=function(){{dioxus.send('error:image');}};
+  img.src='data:image/svg+xml;charset=utf-8,'+encodeURIComponent({svg_js});
+}})();"
+    ));
+    match eval
+        .recv::<String>()
+        .await
+        .map_err(|e| e.to_string())?
+        .as_str()
+    {
+        "shared" => Ok(ShareOutcome::Shared),
+        "saved" => Ok(ShareOutcome::Saved),
+        "cancelled" => Ok(ShareOutcome::Cancelled),
+        "unsupported" => Ok(ShareOutcome::Unsupported),
+        other => Err(other.trim_start_matches("error:").to_owned()),
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ExerciseLog, Weight};
+    fn session(id: &str, start_time: u64, weights: &[(&str, u16)]) -> WorkoutSession {
+        WorkoutSession {
+            id: id.into(),
+            start_time,
+            end_time: Some(start_time + 3_600),
+            exercise_logs: weights
+                .iter()
+                .map(|(name, hg)| ExerciseLog {
+                    exercise_id: name.to_lowercase(),
+                    exercise_name: (*name).into(),
+                    start_time,
+                    end_time: Some(start_time + 60),
+                    weight_hg: Weight(*hg),
+                    reps: Some(5),
+                    ..Default::default()
+                })
+                .collect(),
+            ..WorkoutSession::default()
+        }
+    }
+    #[test]
+    fn records_beat_every_earlier_session() {
+        let earlier = session("a", 1_000, &[("Squat", 1_000), ("Bench", 800)]);
+        let current = session(
+            "b",
+            10_000,
+            &[
+                ("Squat", 1_025),
+                ("Squat", 900),
+                ("Bench", 800),
+                ("Row", 600),
+            ],
+        );
+        let later = session("c", 20_000, &[("Squat", 2_000)]);
+        let history = [earlier, current.clone(), later];
+        assert_eq!(
+            session_records(&current, &history),
+            vec![("Squat".to_owned(), 102.5)]
+        );
+        assert_eq!(
+            exercise_sets(&current),
+            vec![
+                ("Squat".to_owned(), 2),
+                ("Bench".to_owned(), 1),
+                ("Row".to_owned(), 1)
+            ]
+        );
+    }
+    #[test]
+    fn svg_escapes_text_and_grows_with_rows() {
+        let card = ShareCard {
+            title: "Legs & <core>".into(),
+            exercises: (0..12).map(|i| (format!("Ex {i}"), 3)).collect(),
+            records: vec![("Squat".into(), 102.5)],
+            ..ShareCard::default()
+        };
+        let svg = render_svg(&card);
+        assert!(svg.contains("Legs &amp; &lt;core&gt;"));
+        assert!(svg.contains(">+4<"));
+        assert!(svg.contains("102.5 kg"));
+        assert_eq!(card_height(&card), CARD_BASE_HEIGHT + 10 * ROW_HEIGHT);
+    }
+}
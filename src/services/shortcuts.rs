@@ -0,0 +1,113 @@
+/// Keyboard shortcuts, for use with a physical keyboard (desktop browser,
+/// tablet with a keyboard).
+///
+/// A single `keydown` listener on the document forwards the relevant keys;
+/// they are mapped to a [`Shortcut`] passed to every component that called
+/// [`use_shortcut`] and is still mounted.  Typing in a text field and any
+/// modifier key disable the shortcuts, except `Escape` (the set inputs
+/// already complete the exercise on `Enter`).  While a dialog is open it
+/// handles the keys itself.
+use dioxus::prelude::*;
+/// ID of the exercise search input that `/` focuses (the one on the current
+/// page).
+pub const SEARCH_INPUT_ID: &str = "exercise-search-input";
+/// Action triggered by a keyboard shortcut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shortcut {
+    /// `/`: focus the exercise search.
+    FocusSearch,
+    /// `Enter`: complete the exercise in progress.
+    Complete,
+    /// `N`: start the next pending exercise.
+    StartNext,
+    /// `Esc`: cancel the exercise in progress.
+    Cancel,
+}
+/// Shortcut of the `key` pressed without modifier in an element of tag
+/// `tag` (lowercase, e.g. `input`), if any.
+#[must_use]
+pub fn shortcut_for(key: &str, tag: &str) -> Option<Shortcut> {
+    let typing = matches!(tag, "input" | "textarea" | "select");
+    match key {
+        "Escape" => Some(Shortcut::Cancel),
+        "Enter" if !typing && !matches!(tag, "button" | "a") => Some(Shortcut::Complete),
+        "/" if !typing => Some(Shortcut::FocusSearch),
+        "n" | "N" if !typing => Some(Shortcut::StartNext),
+        _ => None,
+    }
+}
+/// Handlers of the mounted components, see [`use_shortcut`].
+#[derive(Clone, Copy)]
+struct ShortcutListeners(Signal<Vec<Callback<Shortcut>>>);
+/// Listen to the keyboard for the lifetime of the app.  Call once from the
+/// root component.
+pub fn use_keyboard_shortcuts() {
+    let listeners = use_context_provider(|| ShortcutListeners(Signal::new(Vec::new()))).0;
+    use_future(move || async move {
+        let js = r#"
+            document.addEventListener('keydown', function(e) {
+                if (e.ctrlKey || e.metaKey || e.altKey || e.isComposing) return;
+                if (['/', 'Enter', 'Escape', 'n', 'N'].indexOf(e.key) < 0) return;
+                if (document.querySelector('dialog[open]')) return;
+                var tag = (e.target && e.target.tagName || '').toLowerCase();
+                if (e.key === '/' && ['input', 'textarea', 'select'].indexOf(tag) < 0) {
+                    e.preventDefault();
+                }
+                dioxus.send({ key: e.key, tag: tag });
+            });
+        "#;
+        let mut eval = document::eval(js);
+        while let Ok(val) = eval.recv::<serde_json::Value>().await {
+            let key = val["key"].as_str().unwrap_or_default();
+            let tag = val["tag"].as_str().unwrap_or_default();
+            match shortcut_for(key, tag) {
+                Some(Shortcut::FocusSearch) => {
+                    document::eval(&format!(
+                        "var el=document.getElementById('{SEARCH_INPUT_ID}');if(el)el.focus();"
+                    ));
+                }
+                Some(shortcut) => dispatch_to(listeners, shortcut),
+                None => {}
+            }
+        }
+    });
+}
+/// Trigger `shortcut` as if its key was pressed, e.g. from a desktop tray
+/// menu.
+pub fn dispatch(shortcut: Shortcut) {
+    if let Some(ShortcutListeners(listeners)) = try_consume_context() {
+        dispatch_to(listeners, shortcut);
+    }
+}
+fn dispatch_to(listeners: Signal<Vec<Callback<Shortcut>>>, shortcut: Shortcut) {
+    let handlers = listeners.peek().clone();
+    for handler in handlers {
+        handler.call(shortcut);
+    }
+}
+/// Call `handler` with the shortcuts pressed while the calling component is
+/// mounted.  The latest `handler` is used, so it can rely on the state of the
+/// last render.
+pub fn use_shortcut(handler: impl FnMut(Shortcut) + 'static) {
+    let callback = use_callback(handler);
+    let mut listeners = use_context::<ShortcutListeners>().0;
+    use_hook(move || listeners.write().push(callback));
+    use_drop(move || listeners.write().retain(|c| *c != callback));
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn typing_disables_letter_shortcuts_but_not_escape() {
+        assert_eq!(shortcut_for("/", "body"), Some(Shortcut::FocusSearch));
+        assert_eq!(shortcut_for("N", "body"), Some(Shortcut::StartNext));
+        assert_eq!(shortcut_for("n", "input"), None);
+        assert_eq!(shortcut_for("/", "textarea"), None);
+        assert_eq!(shortcut_for("Enter", "div"), Some(Shortcut::Complete));
+        assert_eq!(shortcut_for("Enter", "input"), None);
+        assert_eq!(shortcut_for("Enter", "textarea"), None);
+        assert_eq!(shortcut_for("Enter", "button"), None);
+        assert_eq!(shortcut_for("Escape", "textarea"), Some(Shortcut::Cancel));
+        assert_eq!(shortcut_for("x", "body"), None);
+    }
+}
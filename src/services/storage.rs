@@ -1,6 +1,7 @@
-//! Platform-specific storage backends for the `LogOut` application.
+//! Platform-specific storage for the `LogOut` application.
 //!
-//! This module provides two storage backends that share the same logical interface:
+//! This module drives two storage backends, implemented in
+//! [`logout_core::storage`], behind the same logical interface:
 //!
 //! - **Web** (`wasm32`): `IndexedDB` via the `rexie` crate, serialised through an
 //!   async write queue so concurrent callers never fight over read-write
@@ -12,37 +13,30 @@
 //! in the sibling [`app_state`](super::app_state) module and is re-exported here
 //! for backward compatibility.
 pub use super::app_state::{
-    add_custom_exercise, append_exercise_log, begin_exercise_in_session,
-    cancel_exercise_in_session, delete_session, get_exercise_bests, get_last_exercise_log,
-    provide_app_state, save_session, start_pending_exercise_in_session, update_custom_exercise,
-    use_custom_exercises, use_sessions,
+    add_custom_exercise, add_pending_exercises_to_session, append_exercise_log,
+    apply_restored_item, begin_exercise_in_session, cancel_exercise_in_session,
+    current_body_weight, delete_custom_exercise, delete_session, delete_sessions,
+    get_exercise_bests, get_last_exercise_log, load_exercise_logs, load_last_logs_at_location,
+    mark_goals_met, merge_active_sessions, merge_exercises, provide_app_state, remove_custom_tag,
+    rewrite_sealed_settings, rewrite_stored_sessions, save_avoid_list, save_body_weight,
+    save_cloud_backup_passphrase, save_custom_taxonomy, save_display_preferences,
+    save_equipment_profiles, save_goals, save_locations, save_muscle_set_targets,
+    save_rest_skip_categories, save_schedule, save_session, save_templates, save_training_plan,
+    set_exercise_override, set_session_location, start_pending_exercise_in_session, start_session,
+    update_custom_exercise, use_avoid_list, use_body_weight, use_cloud_backup_passphrase,
+    use_custom_exercises, use_custom_taxonomy, use_display_preferences, use_equipment_profiles,
+    use_exercise_overrides, use_goals, use_locations, use_muscle_set_targets,
+    use_rest_skip_categories, use_schedule, use_sessions, use_templates, use_training_plan,
 };
-/// Aggregated per-exercise personal-record values returned by
-/// [`compute_all_bests_rows`] and [`compute_bests_rows_for_exercises`].
-///
-/// Raw numeric types match the storage representation so callers can build
-/// [`crate::services::app_state::ExerciseBests`] values without an extra
-/// conversion step.
-pub struct BestsRow {
-    /// The exercise this row describes.
-    pub exercise_id: String,
-    /// Maximum `weight_hg` (hectograms) across all completed logs.
-    pub max_weight_hg: Option<u16>,
-    /// Maximum repetition count across all completed logs.
-    pub max_reps: Option<u32>,
-    /// Maximum `distance_m` (metres) across all completed logs.
-    pub max_distance_m: Option<u32>,
-    /// Maximum set duration (seconds) across all completed logs.
-    pub max_duration_s: Option<u64>,
-    /// `weight_hg` from the most-recently completed log (for input prefilling).
-    pub last_weight_hg: Option<u16>,
-    /// Repetition count from the most-recently completed log.
-    pub last_reps: Option<u32>,
-    /// `distance_m` from the most-recently completed log.
-    pub last_distance_m: Option<u32>,
-    /// `end_time` of the most-recently completed log (used to merge entries).
-    pub last_log_end_time: Option<u64>,
-}
+pub use logout_core::storage::BestsRow;
+/// Version of the storage schema of this platform (`IndexedDB` on web,
+/// `SQLite` on native).
+#[cfg(target_arch = "wasm32")]
+pub const SCHEMA_VERSION: u32 = logout_core::storage::idb::DB_VERSION;
+/// Version of the storage schema of this platform (`IndexedDB` on web,
+/// `SQLite` on native).
+#[cfg(not(target_arch = "wasm32"))]
+pub const SCHEMA_VERSION: u32 = logout_core::storage::sqlite::SCHEMA_VERSION;
 /// Unified error type returned by all async storage read operations.
 ///
 /// Wraps platform-specific errors (`IndexedDB` on `wasm32`, `SQLite` on native)
@@ -78,10 +72,13 @@ impl From<native_storage::StorageError> for StorageError {
 /// logic is therefore decoupled from the platform-specific storage layer.
 pub trait AsyncStorageProvider {
     /// Load a page of completed sessions, sorted by `start_time` descending.
+    ///
+    /// Archived sessions are skipped unless `include_archived` is set.
     async fn load_completed_sessions_page(
         &self,
         limit: usize,
         offset: usize,
+        include_archived: bool,
     ) -> Result<Vec<crate::models::WorkoutSession>, StorageError>;
     /// Load all active (in-progress) sessions.
     async fn load_active_sessions(
@@ -98,6 +95,22 @@ pub trait AsyncStorageProvider {
     ) -> Result<Vec<BestsRow>, StorageError>;
     /// Returns the total number of sessions in storage.
     async fn session_count(&self) -> Result<usize, StorageError>;
+    /// Load every trash entry.
+    async fn load_trash(&self) -> Result<Vec<crate::models::TrashEntry>, StorageError>;
+    /// Move a trashed item back to its original store.
+    async fn restore_from_trash(
+        &self,
+        entry_id: String,
+    ) -> Result<Option<crate::models::TrashedItem>, StorageError>;
+    /// Permanently delete a trash entry.
+    async fn delete_from_trash(&self, entry_id: String) -> Result<(), StorageError>;
+    /// Permanently delete the trash entries deleted before `cutoff`.
+    async fn purge_trash(&self, cutoff: u64) -> Result<usize, StorageError>;
+    /// Compress the sessions completed before `cutoff` (see
+    /// [`logout_core::storage::PackedSession`]).
+    async fn pack_sessions(&self, cutoff: u64) -> Result<usize, StorageError>;
+    /// Permanently delete the sessions with `ids`, skipping the trash.
+    async fn delete_sessions(&self, ids: Vec<String>) -> Result<(), StorageError>;
 }
 /// Returns the platform-specific storage backend.
 ///
@@ -125,26 +138,38 @@ fn platform_storage() -> NativeStorage {
 /// filtered, sorted, and sliced — true cursor-based pagination requires IDB
 /// indices which would add schema-migration complexity.
 ///
+/// Archived sessions are hidden from the home page history but still needed
+/// by analytics and exports: pass `include_archived` accordingly.
+///
 /// Returns `Err` when storage access fails, allowing the UI to surface the
 /// error appropriately.
 pub async fn load_completed_sessions_page(
     limit: usize,
     offset: usize,
+    include_archived: bool,
 ) -> Result<Vec<crate::models::WorkoutSession>, StorageError> {
-    platform_storage()
-        .load_completed_sessions_page(limit, offset)
-        .await
+    let sessions = platform_storage()
+        .load_completed_sessions_page(limit, offset, include_archived)
+        .await?;
+    Ok(sessions
+        .into_iter()
+        .map(super::app_lock::unseal_from_storage)
+        .collect())
 }
 /// Load only the **active** (in-progress) sessions from storage.
 ///
 /// On native this issues `SELECT … WHERE end_time IS NULL`, so completed
-/// sessions are never deserialised.  On wasm all sessions are fetched from
-/// `IndexedDB` and completed ones are discarded immediately.
+/// sessions are never deserialised.  On wasm only the keys of the completed
+/// sessions are read, from an `IndexedDB` index on `end_time`.
 ///
 /// Returns `Err` when storage access fails, allowing the UI to surface the
 /// error appropriately.
 pub async fn load_active_sessions() -> Result<Vec<crate::models::WorkoutSession>, StorageError> {
-    platform_storage().load_active_sessions().await
+    let sessions = platform_storage().load_active_sessions().await?;
+    Ok(sessions
+        .into_iter()
+        .map(super::app_lock::unseal_from_storage)
+        .collect())
 }
 /// Returns the total number of sessions in storage (active + completed).
 ///
@@ -153,6 +178,69 @@ pub async fn load_active_sessions() -> Result<Vec<crate::models::WorkoutSession>
 pub async fn load_session_count() -> Result<usize, StorageError> {
     platform_storage().session_count().await
 }
+/// Load the trash, most recently deleted first.
+///
+/// Returns `Err` when storage access fails, allowing the UI to surface the
+/// error appropriately.
+pub async fn load_trash() -> Result<Vec<crate::models::TrashEntry>, StorageError> {
+    let mut entries = platform_storage().load_trash().await?;
+    entries.sort_by_key(|e| std::cmp::Reverse(e.deleted_at));
+    Ok(entries)
+}
+/// Put the item of trash entry `entry_id` back where it was deleted from.
+///
+/// Returns the restored item (unsealed, see [`super::app_lock`]) so the
+/// caller can refresh the in-memory state, or `None` if the entry no longer
+/// exists.
+pub async fn restore_from_trash(
+    entry_id: &str,
+) -> Result<Option<crate::models::TrashedItem>, StorageError> {
+    use crate::models::TrashedItem;
+    let item = platform_storage()
+        .restore_from_trash(entry_id.to_owned())
+        .await?;
+    Ok(item.map(|item| match item {
+        TrashedItem::Session(s) => TrashedItem::Session(super::app_lock::unseal_from_storage(s)),
+        other => other,
+    }))
+}
+/// Permanently delete the trash entry `entry_id`.
+pub async fn delete_from_trash(entry_id: &str) -> Result<(), StorageError> {
+    platform_storage()
+        .delete_from_trash(entry_id.to_owned())
+        .await
+}
+/// Permanently delete the trash entries older than
+/// [`crate::models::TRASH_RETENTION_DAYS`].  Called once at startup.
+pub async fn purge_expired_trash() {
+    let cutoff = crate::models::trash_cutoff(crate::models::get_current_timestamp());
+    match platform_storage().purge_trash(cutoff).await {
+        Ok(0) => {}
+        Ok(n) => log::info!("Purged {n} expired trash entries"),
+        Err(e) => log::warn!("Failed to purge the trash: {e}"),
+    }
+}
+/// Compress the sessions completed more than
+/// [`logout_core::storage::PACK_AFTER_SECS`] ago, which reads unpack
+/// transparently.  Called once at startup.
+pub async fn pack_old_sessions() {
+    let now = crate::models::get_current_timestamp();
+    let cutoff = now.saturating_sub(logout_core::storage::PACK_AFTER_SECS);
+    match platform_storage().pack_sessions(cutoff).await {
+        Ok(0) => {}
+        Ok(n) => log::info!("Packed {n} old sessions"),
+        Err(e) => log::warn!("Failed to pack old sessions: {e}"),
+    }
+}
+/// Permanently delete the sessions with `ids` in one batch, skipping the
+/// trash, e.g. the sample sessions.  Callers update the in-memory state.
+pub async fn delete_sessions_permanently(ids: Vec<String>) -> Result<(), StorageError> {
+    for id in &ids {
+        // A held autosave would bring the session back.
+        super::autosave::discard(id);
+    }
+    platform_storage().delete_sessions(ids).await
+}
 /// Load all custom exercises from storage.
 ///
 /// Returns `Err` when storage access fails, allowing the UI to surface the
@@ -201,7 +289,7 @@ fn bests_rows_from_sessions(sessions: &[crate::models::WorkoutSession]) -> Vec<B
     for session in sessions {
         if !session.is_active() {
             for log in &session.exercise_logs {
-                if !log.is_complete() {
+                if !log.is_complete() || !log.set_kind.counts_for_stats() {
                     continue;
                 }
                 let entry = map
@@ -215,9 +303,10 @@ fn bests_rows_from_sessions(sessions: &[crate::models::WorkoutSession]) -> Vec<B
                         last_weight_hg: None,
                         last_reps: None,
                         last_distance_m: None,
+                        last_assisted: false,
                         last_log_end_time: None,
                     });
-                if log.weight_hg.0 > 0 {
+                if log.weight_hg.0 > 0 && !log.assisted {
                     update_max(&mut entry.max_weight_hg, log.weight_hg.0);
                 }
                 if let Some(r) = log.reps {
@@ -236,6 +325,7 @@ fn bests_rows_from_sessions(sessions: &[crate::models::WorkoutSession]) -> Vec<B
                     entry.last_weight_hg = (log.weight_hg.0 > 0).then_some(log.weight_hg.0);
                     entry.last_reps = log.reps;
                     entry.last_distance_m = log.distance_m.map(|d| d.0);
+                    entry.last_assisted = log.assisted;
                 }
             }
         }
@@ -244,6 +334,9 @@ fn bests_rows_from_sessions(sessions: &[crate::models::WorkoutSession]) -> Vec<B
 }
 /// Enqueue a session upsert on the platform-specific background write queue.
 ///
+/// Sensitive fields are sealed first when the app lock is enabled (see
+/// [`super::app_lock`]).
+///
 /// Abstracts over [`idb_queue`] (web) and [`native_queue`] (native) so
 /// callers in [`super::app_state`] need no `#[cfg]` for this operation.
 pub fn enqueue_put_session(
@@ -252,6 +345,7 @@ pub fn enqueue_put_session(
     sessions_sig: dioxus::signals::Signal<Vec<crate::models::WorkoutSession>>,
     previous: Option<crate::models::WorkoutSession>,
 ) {
+    let session = super::app_lock::seal_for_storage(session);
     #[cfg(target_arch = "wasm32")]
     idb_queue::enqueue(idb_queue::IdbOp::PutSession {
         session,
@@ -262,16 +356,23 @@ pub fn enqueue_put_session(
     #[cfg(not(target_arch = "wasm32"))]
     {
         let _ = (toast, sessions_sig); // Used via use_native_results
-        native_queue::enqueue(native_queue::NativeOp::PutSession { session, previous });
+        native_queue::enqueue(native_queue::NativeOp::PutSession {
+            session: Box::new(session),
+            previous,
+        });
     }
 }
 /// Enqueue a session deletion on the platform-specific background write queue.
+///
+/// The session is moved to the trash (see [`crate::models::TrashEntry`]).
 pub fn enqueue_delete_session(
     id: String,
     toast: dioxus::signals::Signal<std::collections::VecDeque<String>>,
     sessions_sig: dioxus::signals::Signal<Vec<crate::models::WorkoutSession>>,
     snapshot: Option<crate::models::WorkoutSession>,
 ) {
+    // A held autosave would bring the session back.
+    super::autosave::discard(&id);
     #[cfg(target_arch = "wasm32")]
     idb_queue::enqueue(idb_queue::IdbOp::DeleteSession {
         id,
@@ -298,137 +399,31 @@ pub fn enqueue_put_exercise(
         native_queue::enqueue(native_queue::NativeOp::PutExercise(exercise));
     }
 }
-
-#[cfg(target_arch = "wasm32")]
-pub(crate) mod idb {
-    use rexie::{ObjectStore, Rexie, TransactionMode};
-    use wasm_bindgen::JsValue;
-    const DB_NAME: &str = "log_out_db";
-    const DB_VERSION: u32 = 3;
-    pub const STORE_SESSIONS: &str = "sessions";
-    pub const STORE_CUSTOM_EXERCISES: &str = "custom_exercises";
-    pub const STORE_EXERCISES: &str = "exercises";
-    /// Dedicated object store for binary image data (key: UUID string, value: `Uint8Array`).
-    pub const STORE_IMAGES: &str = "images";
-    /// Structured error type for `IndexedDB` operations via the `rexie` crate.
-    ///
-    /// Using a typed enum instead of `String` preserves the underlying cause so
-    /// that callers can inspect or display it with full context.
-    #[derive(Debug, thiserror::Error)]
-    pub enum IdbError {
-        /// A lower-level `rexie` / IndexedDB error.
-        #[error("IndexedDB error: {0}")]
-        Rexie(#[from] rexie::Error),
-        /// A `serde-wasm-bindgen` serialisation or deserialisation error.
-        #[error("Serialization error: {0}")]
-        Serde(#[from] serde_wasm_bindgen::Error),
-    }
-    /// Open (or create) the IndexedDB database via rexie.
-    pub(super) async fn open_db() -> Result<Rexie, rexie::Error> {
-        Rexie::builder(DB_NAME)
-            .version(DB_VERSION)
-            .add_object_store(ObjectStore::new(STORE_SESSIONS).key_path("id"))
-            .add_object_store(ObjectStore::new(STORE_CUSTOM_EXERCISES).key_path("id"))
-            .add_object_store(ObjectStore::new(STORE_EXERCISES).key_path("id"))
-            .add_object_store(ObjectStore::new(STORE_IMAGES))
-            .build()
-            .await
-    }
-    /// Put a single serialisable item into a store (upsert by key).
-    pub async fn put_item<T: serde::Serialize>(store_name: &str, item: &T) -> Result<(), IdbError> {
-        let db = open_db().await?;
-        let tx = db.transaction(&[store_name], TransactionMode::ReadWrite)?;
-        let store = tx.store(store_name)?;
-        let js_val = serde_wasm_bindgen::to_value(item)?;
-        store.put(&js_val, None).await?;
-        tx.done().await?;
-        Ok(())
-    }
-    /// Put many serialisable items into a store in a single transaction.
-    /// More efficient than calling [`put_item`] in a loop because only one
-    /// database connection and one transaction are opened.
-    ///
-    /// Serialisation is performed in chunks of [`PUT_ALL_CHUNK_SIZE`] items,
-    /// yielding to the browser's macro-task queue between each chunk via a
-    /// zero-delay `setTimeout`.  This prevents large datasets (e.g. 800+
-    /// exercises) from synchronously blocking the UI thread during the
-    /// serialisation phase.  The IndexedDB transaction is opened only after
-    /// all serialisation is complete so the yield points cannot cause the
-    /// transaction to auto-commit prematurely.
-    ///
-    /// All individual `put` requests are issued concurrently within the same
-    /// transaction via [`futures_util::future::try_join_all`] so the browser can
-    /// pipeline them instead of waiting for each one before issuing the next.
-    pub async fn put_all<T: serde::Serialize>(
-        store_name: &str,
-        items: &[T],
-    ) -> Result<(), IdbError> {
-        /// Number of items to serialise per chunk before yielding.
-        const PUT_ALL_CHUNK_SIZE: usize = 50;
-        // Serialise in chunks, yielding to the macro-task queue between each
-        // chunk so the browser can paint frames and handle input events.
-        // The transaction is opened only after all serialisation completes so
-        // that the yield points never risk triggering an early auto-commit.
-        let mut js_values = Vec::with_capacity(items.len());
-        for chunk in items.chunks(PUT_ALL_CHUNK_SIZE) {
-            for item in chunk {
-                js_values.push(serde_wasm_bindgen::to_value(item)?);
-            }
-            gloo_timers::future::TimeoutFuture::new(0).await;
-        }
-        let db = open_db().await?;
-        let tx = db.transaction(&[store_name], TransactionMode::ReadWrite)?;
-        let store = tx.store(store_name)?;
-        let put_futs: Vec<_> = js_values
-            .iter()
-            .map(|js_val| store.put(js_val, None))
-            .collect();
-        futures_util::future::try_join_all(put_futs).await?;
-        tx.done().await?;
-        Ok(())
-    }
-    /// Delete an item from a store by its key.
-    pub async fn delete_item(store_name: &str, key: &str) -> Result<(), IdbError> {
-        let db = open_db().await?;
-        let tx = db.transaction(&[store_name], TransactionMode::ReadWrite)?;
-        let store = tx.store(store_name)?;
-        store.delete(JsValue::from_str(key)).await?;
-        tx.done().await?;
-        Ok(())
-    }
-    /// Remove all items from a store.
-    pub async fn clear_all(store_name: &str) -> Result<(), IdbError> {
-        let db = open_db().await?;
-        let tx = db.transaction(&[store_name], TransactionMode::ReadWrite)?;
-        let store = tx.store(store_name)?;
-        store.clear().await?;
-        tx.done().await?;
-        Ok(())
-    }
-    /// Load all items from a store.
-    pub async fn get_all<T: serde::de::DeserializeOwned>(
-        store_name: &str,
-    ) -> Result<Vec<T>, IdbError> {
-        let db = open_db().await?;
-        let tx = db.transaction(&[store_name], TransactionMode::ReadOnly)?;
-        let store = tx.store(store_name)?;
-        let js_values = store.get_all(None, None).await?;
-        let mut items = Vec::new();
-        for (i, js_val) in js_values.into_iter().enumerate() {
-            match serde_wasm_bindgen::from_value::<T>(js_val) {
-                Ok(item) => items.push(item),
-                Err(e) => {
-                    log::warn!("Skipping corrupt IndexedDB entry at index {i}: {e}")
-                }
-            }
-        }
-        Ok(items)
+/// Enqueue moving a custom exercise to the trash on the platform-specific
+/// background write queue.
+pub fn enqueue_delete_exercise(
+    id: String,
+    toast: dioxus::signals::Signal<std::collections::VecDeque<String>>,
+) {
+    #[cfg(target_arch = "wasm32")]
+    idb_queue::enqueue(idb_queue::IdbOp::DeleteExercise(id, toast));
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = toast; // Used via use_native_results
+        native_queue::enqueue(native_queue::NativeOp::DeleteExercise(id));
     }
 }
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use logout_core::storage::idb;
 /// Zero-size marker type that binds [`AsyncStorageProvider`] to the
 /// `IndexedDB` backend exposed by [`idb`].
 #[cfg(target_arch = "wasm32")]
 pub struct IdbStorage;
+/// Config key set once the sessions stored before the exercise index of
+/// `IndexedDB` existed were indexed (see [`idb::index_sessions`]).
+#[cfg(target_arch = "wasm32")]
+const SESSIONS_INDEXED_KEY: &str = "idb_sessions_indexed";
 /// [`AsyncStorageProvider`] implementation for the `IndexedDB` (wasm32) backend.
 #[cfg(target_arch = "wasm32")]
 impl AsyncStorageProvider for IdbStorage {
@@ -436,18 +431,14 @@ impl AsyncStorageProvider for IdbStorage {
         &self,
         limit: usize,
         offset: usize,
+        include_archived: bool,
     ) -> Result<Vec<crate::models::WorkoutSession>, StorageError> {
-        let mut sessions =
-            idb::get_all::<crate::models::WorkoutSession>(idb::STORE_SESSIONS).await?;
-        sessions.retain(|s| !s.is_active());
-        sessions.sort_by(|a, b| b.start_time.cmp(&a.start_time));
-        Ok(sessions.into_iter().skip(offset).take(limit).collect())
+        Ok(idb::get_completed_sessions_page(limit, offset, include_archived).await?)
     }
     async fn load_active_sessions(
         &self,
     ) -> Result<Vec<crate::models::WorkoutSession>, StorageError> {
-        let sessions = idb::get_all::<crate::models::WorkoutSession>(idb::STORE_SESSIONS).await?;
-        Ok(sessions.into_iter().filter(|s| s.is_active()).collect())
+        Ok(idb::get_active_sessions().await?)
     }
     async fn load_custom_exercises(&self) -> Result<Vec<crate::models::Exercise>, StorageError> {
         Ok(idb::get_all::<crate::models::Exercise>(idb::STORE_CUSTOM_EXERCISES).await?)
@@ -460,19 +451,41 @@ impl AsyncStorageProvider for IdbStorage {
         &self,
         exercise_ids: Vec<String>,
     ) -> Result<Vec<BestsRow>, StorageError> {
+        if crate::utils::get_config(SESSIONS_INDEXED_KEY).is_none() {
+            let count = idb::index_sessions().await?;
+            log::info!("Indexed the exercises of {count} stored sessions");
+            crate::utils::set_config(SESSIONS_INDEXED_KEY, "true");
+        }
+        let sessions = idb::get_sessions_with_exercises(&exercise_ids).await?;
         let id_set: std::collections::HashSet<String> = exercise_ids.into_iter().collect();
-        let all = self.compute_all_bests_rows().await?;
-        Ok(all
+        Ok(bests_rows_from_sessions(&sessions)
             .into_iter()
             .filter(|row| id_set.contains(&row.exercise_id))
             .collect())
     }
     async fn session_count(&self) -> Result<usize, StorageError> {
-        Ok(
-            idb::get_all::<crate::models::WorkoutSession>(idb::STORE_SESSIONS)
-                .await?
-                .len(),
-        )
+        Ok(idb::count(idb::STORE_SESSIONS).await?)
+    }
+    async fn load_trash(&self) -> Result<Vec<crate::models::TrashEntry>, StorageError> {
+        Ok(idb::get_all::<crate::models::TrashEntry>(idb::STORE_TRASH).await?)
+    }
+    async fn restore_from_trash(
+        &self,
+        entry_id: String,
+    ) -> Result<Option<crate::models::TrashedItem>, StorageError> {
+        Ok(idb::restore_from_trash(&entry_id).await?)
+    }
+    async fn delete_from_trash(&self, entry_id: String) -> Result<(), StorageError> {
+        Ok(idb::delete_item(idb::STORE_TRASH, &entry_id).await?)
+    }
+    async fn purge_trash(&self, cutoff: u64) -> Result<usize, StorageError> {
+        Ok(idb::purge_trash(cutoff).await?)
+    }
+    async fn pack_sessions(&self, cutoff: u64) -> Result<usize, StorageError> {
+        Ok(idb::pack_sessions_ended_before(cutoff).await?)
+    }
+    async fn delete_sessions(&self, ids: Vec<String>) -> Result<(), StorageError> {
+        Ok(idb::delete_items(idb::STORE_SESSIONS, &ids).await?)
     }
 }
 #[cfg(target_arch = "wasm32")]
@@ -481,9 +494,14 @@ pub(crate) mod idb_queue {
     use crate::models::{Exercise, WorkoutSession};
     use dioxus::prelude::WritableExt;
     use dioxus::signals::Signal;
-    use std::cell::RefCell;
-    use std::collections::VecDeque;
+    use dioxus_i18n::prelude::I18n;
+    use std::cell::{Cell, RefCell};
+    use std::collections::{HashMap, VecDeque};
     /// A pending write operation, including the toast signal for error reporting.
+    ///
+    /// Failed writes are retried with back-off; if they still fail they are
+    /// journaled to `localStorage` and replayed later (see [`replay_journal`]),
+    /// so the optimistic signal update is only reverted as a last resort.
     pub enum IdbOp {
         /// Upsert a session.  On write failure the sessions signal is reverted to
         /// `previous` (the value before the optimistic update).
@@ -505,6 +523,17 @@ pub(crate) mod idb_queue {
             snapshot: Option<WorkoutSession>,
         },
         PutExercise(Exercise, Signal<std::collections::VecDeque<String>>),
+        /// Move a custom exercise to the trash.
+        DeleteExercise(String, Signal<std::collections::VecDeque<String>>),
+        /// Upsert journaled sessions and custom exercises in one transaction
+        /// (see [`replay_journal`]).  On success the sessions signal is
+        /// refreshed with the replayed sessions.
+        PutBatch {
+            sessions: Vec<WorkoutSession>,
+            exercises: Vec<Exercise>,
+            toast: Signal<std::collections::VecDeque<String>>,
+            sessions_sig: Signal<Vec<WorkoutSession>>,
+        },
     }
     thread_local! {
         /// (draining, pending_ops)
@@ -512,9 +541,65 @@ pub(crate) mod idb_queue {
             false,
             VecDeque::new(),
         ));
+        static SESSION_WRITES: RefCell<SessionWrites> = RefCell::new(SessionWrites::default());
+        /// Translator captured by [`register_journal_replay`]: the queue
+        /// drains outside any component scope, where `t!` cannot be used.
+        static I18N: Cell<Option<I18n>> = const { Cell::new(None) };
+    }
+    /// Order of the session writes of this page lifetime, so
+    /// [`replay_journal`] can tell stale journal entries apart.
+    #[derive(Default)]
+    struct SessionWrites {
+        /// Incremented on every write.
+        counter: u64,
+        /// Counter value at the latest write enqueued, per session ID.
+        enqueued: HashMap<String, u64>,
+        /// Counter value at the latest write journaled, per session ID.
+        journaled: HashMap<String, u64>,
+    }
+    /// Record that a write to session `id` was enqueued (`journaled` false)
+    /// or journaled (`journaled` true).
+    fn note_session_write(id: &str, journaled: bool) {
+        SESSION_WRITES.with(|w| {
+            let w = &mut *w.borrow_mut();
+            w.counter += 1;
+            let map = if journaled {
+                &mut w.journaled
+            } else {
+                &mut w.enqueued
+            };
+            map.insert(id.to_owned(), w.counter);
+        });
+    }
+    /// Whether a write to session `id` was enqueued after its journal entry,
+    /// which must then not be replayed over it.  Entries journaled by an
+    /// earlier page lifetime are older than any write of this one.
+    fn superseded(id: &str) -> bool {
+        SESSION_WRITES.with(|w| {
+            let w = w.borrow();
+            w.enqueued
+                .get(id)
+                .is_some_and(|&at| at > w.journaled.get(id).copied().unwrap_or(0))
+        })
+    }
+    /// Toast made of the translated `key` followed by the error detail.
+    fn error_toast(key: &str, e: &impl std::fmt::Display) -> String {
+        let prefix = I18N
+            .with(Cell::get)
+            .map_or_else(|| key.to_owned(), |i18n| i18n.translate(key));
+        format!("{prefix}: {e}")
     }
     /// Enqueue a write operation.  If no drain is currently running, starts one.
     pub fn enqueue(op: IdbOp) {
+        match &op {
+            IdbOp::PutSession { session, .. } => note_session_write(&session.id, false),
+            IdbOp::DeleteSession { id, .. } => note_session_write(id, false),
+            _ => {}
+        }
+        push(op);
+    }
+    /// Enqueue `op` without recording it as a new write (journal replays).
+    fn push(op: IdbOp) {
         QUEUE.with(|q| {
             let mut q = q.borrow_mut();
             q.1.push_back(op);
@@ -539,6 +624,160 @@ pub(crate) mod idb_queue {
             }
         });
     }
+    /// Number of attempts for a single write before it is journaled.
+    const WRITE_ATTEMPTS: u32 = 4;
+    /// Delay before the first retry; doubled after every failed attempt.
+    const RETRY_BASE_DELAY_MS: u32 = 250;
+    /// `localStorage` key of the journal of writes that exhausted their retries.
+    const JOURNAL_KEY: &str = "pending_writes";
+    /// A write that could not reach `IndexedDB`, persisted for later replay.
+    #[derive(serde::Serialize, serde::Deserialize)]
+    enum JournalEntry {
+        PutSession(WorkoutSession),
+        DeleteSession(String),
+        PutExercise(Exercise),
+        DeleteExercise(String),
+    }
+    impl JournalEntry {
+        fn session_id(&self) -> Option<&str> {
+            match self {
+                Self::PutSession(s) => Some(&s.id),
+                Self::DeleteSession(id) => Some(id),
+                Self::PutExercise(_) | Self::DeleteExercise(_) => None,
+            }
+        }
+        fn exercise_id(&self) -> Option<&str> {
+            match self {
+                Self::PutExercise(ex) => Some(&ex.id),
+                Self::DeleteExercise(id) => Some(id),
+                Self::PutSession(_) | Self::DeleteSession(_) => None,
+            }
+        }
+    }
+    fn local_storage() -> Option<web_sys::Storage> {
+        web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+    }
+    fn read_journal() -> Vec<JournalEntry> {
+        local_storage()
+            .and_then(|ls| ls.get_item(JOURNAL_KEY).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+    fn write_journal(entries: &[JournalEntry]) -> bool {
+        let Some(ls) = local_storage() else {
+            return false;
+        };
+        if entries.is_empty() {
+            return ls.remove_item(JOURNAL_KEY).is_ok();
+        }
+        serde_json::to_string(entries)
+            .ok()
+            .is_some_and(|json| ls.set_item(JOURNAL_KEY, &json).is_ok())
+    }
+    /// Append `entry` to the journal, superseding older writes to the same
+    /// session.  Returns `false` if `localStorage` is unavailable too.
+    fn journal(entry: JournalEntry) -> bool {
+        let mut entries = read_journal();
+        if let Some(id) = entry.session_id() {
+            note_session_write(id, true);
+            entries.retain(|e| e.session_id() != Some(id));
+        }
+        entries.push(entry);
+        write_journal(&entries)
+    }
+    /// Drop journaled writes to session `id` once a newer write succeeded.
+    fn forget_journaled_session(id: &str) {
+        let mut entries = read_journal();
+        let before = entries.len();
+        entries.retain(|e| e.session_id() != Some(id));
+        if entries.len() != before {
+            write_journal(&entries);
+        }
+    }
+    /// Re-enqueue every journaled write.
+    ///
+    /// Called at start-up and whenever the browser comes back online, so a
+    /// write that failed (storage pressure, private-mode quirks) is retried
+    /// until it lands instead of being lost.  Only the last write to each
+    /// exercise is kept, and all the upserts are replayed in a single
+    /// transaction.  Writes to sessions that were written again since they
+    /// were journaled are dropped, so a stale copy never overwrites a newer
+    /// one; replayed deletions update the sessions signal right away, and
+    /// replayed upserts once stored.
+    pub fn replay_journal(
+        toast: Signal<VecDeque<String>>,
+        mut sessions_sig: Signal<Vec<WorkoutSession>>,
+    ) {
+        let entries = read_journal();
+        if entries.is_empty() {
+            return;
+        }
+        log::info!("IDB queue: replaying {} journaled write(s)", entries.len());
+        write_journal(&[]);
+        let mut seen_exercises = std::collections::HashSet::new();
+        let mut latest: Vec<JournalEntry> = entries
+            .into_iter()
+            .rev()
+            .filter(|e| {
+                e.exercise_id()
+                    .is_none_or(|id| seen_exercises.insert(id.to_owned()))
+            })
+            .filter(|e| e.session_id().is_none_or(|id| !superseded(id)))
+            .collect();
+        latest.reverse();
+        let (mut sessions, mut exercises) = (Vec::new(), Vec::new());
+        for entry in latest {
+            match entry {
+                JournalEntry::PutSession(session) => sessions.push(session),
+                JournalEntry::PutExercise(ex) => exercises.push(ex),
+                JournalEntry::DeleteSession(id) => {
+                    let snapshot = {
+                        let mut sessions = sessions_sig.write();
+                        sessions
+                            .iter()
+                            .position(|s| s.id == id)
+                            .map(|pos| sessions.remove(pos))
+                    };
+                    push(IdbOp::DeleteSession {
+                        id,
+                        toast,
+                        sessions_sig,
+                        snapshot,
+                    });
+                }
+                JournalEntry::DeleteExercise(id) => push(IdbOp::DeleteExercise(id, toast)),
+            }
+        }
+        if !sessions.is_empty() || !exercises.is_empty() {
+            push(IdbOp::PutBatch {
+                sessions,
+                exercises,
+                toast,
+                sessions_sig,
+            });
+        }
+    }
+    /// Run `op` up to [`WRITE_ATTEMPTS`] times with exponential back-off.
+    async fn with_retry<F, Fut>(what: &str, mut op: F) -> Result<(), idb::IdbError>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<(), idb::IdbError>>,
+    {
+        let mut delay = RETRY_BASE_DELAY_MS;
+        let mut attempt = 1;
+        loop {
+            match op().await {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt >= WRITE_ATTEMPTS => return Err(e),
+                Err(e) => {
+                    log::warn!("IDB queue: {what} failed (attempt {attempt}), retrying: {e}");
+                    gloo_timers::future::TimeoutFuture::new(delay).await;
+                    delay *= 2;
+                    attempt += 1;
+                }
+            }
+        }
+    }
     async fn drain() {
         loop {
             let op = QUEUE.with(|q| q.borrow_mut().1.pop_front());
@@ -553,18 +792,29 @@ pub(crate) mod idb_queue {
                     mut sessions_sig,
                     previous,
                 }) => {
-                    if let Err(e) = idb::put_item(idb::STORE_SESSIONS, &s).await {
-                        log::error!("IDB queue: failed to put session {}: {e}", s.id);
-                        toast
-                            .write()
-                            .push_back(format!("⚠️ Failed to save session: {e}"));
-                        // Revert the optimistic signal update.
-                        let mut sessions = sessions_sig.write();
-                        match previous {
-                            None => sessions.retain(|x| x.id != s.id),
-                            Some(old) => {
-                                if let Some(pos) = sessions.iter().position(|x| x.id == s.id) {
-                                    sessions[pos] = old;
+                    let result = with_retry("put session", || idb::put_session(&s)).await;
+                    match result {
+                        Ok(()) => forget_journaled_session(&s.id),
+                        Err(e) => {
+                            log::error!("IDB queue: failed to put session {}: {e}", s.id);
+                            let id = s.id.clone();
+                            if journal(JournalEntry::PutSession(s)) {
+                                toast
+                                    .write()
+                                    .push_back(error_toast("toast-session-save-delayed", &e));
+                                continue;
+                            }
+                            toast
+                                .write()
+                                .push_back(error_toast("toast-session-save-failed", &e));
+                            // Revert the optimistic signal update.
+                            let mut sessions = sessions_sig.write();
+                            match previous {
+                                None => sessions.retain(|x| x.id != id),
+                                Some(old) => {
+                                    if let Some(pos) = sessions.iter().position(|x| x.id == id) {
+                                        sessions[pos] = old;
+                                    }
                                 }
                             }
                         }
@@ -576,29 +826,146 @@ pub(crate) mod idb_queue {
                     mut sessions_sig,
                     snapshot,
                 }) => {
-                    if let Err(e) = idb::delete_item(idb::STORE_SESSIONS, &id).await {
-                        log::error!("IDB queue: failed to delete session {id}: {e}");
-                        toast
-                            .write()
-                            .push_back(format!("⚠️ Failed to delete session: {e}"));
-                        // Revert: re-insert the session into the signal if we
-                        // had a snapshot of it.
-                        if let Some(session) = snapshot {
-                            sessions_sig.write().push(session);
+                    let result = with_retry("delete session", || {
+                        idb::move_to_trash(
+                            idb::STORE_SESSIONS,
+                            &id,
+                            crate::models::get_current_timestamp(),
+                        )
+                    })
+                    .await;
+                    match result {
+                        Ok(()) => forget_journaled_session(&id),
+                        Err(e) => {
+                            log::error!("IDB queue: failed to delete session {id}: {e}");
+                            if journal(JournalEntry::DeleteSession(id)) {
+                                toast
+                                    .write()
+                                    .push_back(error_toast("toast-session-delete-delayed", &e));
+                                continue;
+                            }
+                            toast
+                                .write()
+                                .push_back(error_toast("toast-session-delete-failed", &e));
+                            // Revert: re-insert the session into the signal if we
+                            // had a snapshot of it.
+                            if let Some(session) = snapshot {
+                                sessions_sig.write().push(session);
+                            }
                         }
                     }
                 }
                 Some(IdbOp::PutExercise(ex, mut toast)) => {
-                    if let Err(e) = idb::put_item(idb::STORE_CUSTOM_EXERCISES, &ex).await {
+                    let result = with_retry("put exercise", || {
+                        idb::put_item(idb::STORE_CUSTOM_EXERCISES, &ex)
+                    })
+                    .await;
+                    if let Err(e) = result {
                         log::error!("IDB queue: failed to put exercise {}: {e}", ex.id);
-                        toast
-                            .write()
-                            .push_back(format!("⚠️ Failed to save exercise: {e}"));
+                        let message = if journal(JournalEntry::PutExercise(ex)) {
+                            error_toast("toast-exercise-save-delayed", &e)
+                        } else {
+                            error_toast("toast-exercise-save-failed", &e)
+                        };
+                        toast.write().push_back(message);
+                    }
+                }
+                Some(IdbOp::PutBatch {
+                    sessions,
+                    exercises,
+                    mut toast,
+                    mut sessions_sig,
+                }) => {
+                    let result = with_retry("put journaled writes", || {
+                        idb::put_sessions_and_exercises(&sessions, &exercises)
+                    })
+                    .await;
+                    match result {
+                        Ok(()) => {
+                            for s in &sessions {
+                                forget_journaled_session(&s.id);
+                            }
+                            // A session written while the batch was pending
+                            // is newer than its replayed copy: keep it.
+                            let fresh: Vec<WorkoutSession> = sessions
+                                .into_iter()
+                                .filter(|s| !superseded(&s.id))
+                                .map(crate::services::app_lock::unseal_from_storage)
+                                .collect();
+                            if !fresh.is_empty() {
+                                let mut all = sessions_sig.write();
+                                for session in fresh {
+                                    match all.iter().position(|x| x.id == session.id) {
+                                        Some(pos) => all[pos] = session,
+                                        None => all.push(session),
+                                    }
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            log::error!("IDB queue: failed to replay journaled writes: {e}");
+                            let mut journaled = true;
+                            let entries = sessions
+                                .into_iter()
+                                .map(JournalEntry::PutSession)
+                                .chain(exercises.into_iter().map(JournalEntry::PutExercise));
+                            for entry in entries {
+                                journaled &= journal(entry);
+                            }
+                            let message = if journaled {
+                                error_toast("toast-save-delayed", &e)
+                            } else {
+                                error_toast("toast-save-failed", &e)
+                            };
+                            toast.write().push_back(message);
+                        }
+                    }
+                }
+                Some(IdbOp::DeleteExercise(id, mut toast)) => {
+                    let result = with_retry("delete exercise", || {
+                        idb::move_to_trash(
+                            idb::STORE_CUSTOM_EXERCISES,
+                            &id,
+                            crate::models::get_current_timestamp(),
+                        )
+                    })
+                    .await;
+                    if let Err(e) = result {
+                        log::error!("IDB queue: failed to delete exercise {id}: {e}");
+                        let message = if journal(JournalEntry::DeleteExercise(id)) {
+                            error_toast("toast-exercise-delete-delayed", &e)
+                        } else {
+                            error_toast("toast-exercise-delete-failed", &e)
+                        };
+                        toast.write().push_back(message);
                     }
                 }
             }
         }
     }
+    /// Replay the journal now and every time the browser comes back online.
+    ///
+    /// Call once at app startup, inside a component so `i18n` is the app's
+    /// translator.  The closure is intentionally leaked because it must live
+    /// for the duration of the page.
+    pub fn register_journal_replay(
+        toast: Signal<VecDeque<String>>,
+        sessions_sig: Signal<Vec<WorkoutSession>>,
+        i18n: I18n,
+    ) {
+        use wasm_bindgen::prelude::Closure;
+        use wasm_bindgen::JsCast as _;
+        I18N.with(|t| t.set(Some(i18n)));
+        replay_journal(toast, sessions_sig);
+        let closure: Closure<dyn Fn()> = Closure::wrap(Box::new(move || {
+            replay_journal(toast, sessions_sig);
+        }));
+        if let Some(window) = web_sys::window() {
+            let _ =
+                window.add_event_listener_with_callback("online", closure.as_ref().unchecked_ref());
+        }
+        closure.forget();
+    }
     /// Register a `pagehide` event listener that flushes any remaining queued
     /// writes before the browser may terminate the page.
     ///
@@ -626,9 +993,10 @@ pub mod idb_exercises {
     pub async fn get_all_exercises() -> Result<Vec<Exercise>, idb::IdbError> {
         idb::get_all::<Exercise>(idb::STORE_EXERCISES).await
     }
-    /// Persist `exercises` to the IndexedDB exercises store in a single transaction.
+    /// Replace the content of the IndexedDB exercises store with `exercises`,
+    /// in a single transaction.
     pub async fn store_all_exercises(exercises: &[Exercise]) {
-        if let Err(e) = idb::put_all(idb::STORE_EXERCISES, exercises).await {
+        if let Err(e) = idb::replace_all(idb::STORE_EXERCISES, exercises).await {
             log::error!("Failed to store exercises in IndexedDB: {e}");
         }
     }
@@ -717,875 +1085,8 @@ pub mod native_exercises {
 }
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) use super::native_queue;
-/// `SQLite`-backed storage for Android and desktop builds.
-///
-/// A single `log-out.db` `SQLite` database file is kept inside the app-
-/// specific data directory.  On Android this is `Context.getFilesDir()`
-/// (queried via `ndk-context` at runtime); on other platforms it is
-/// `dirs::data_local_dir()/log-out/`.
-/// Each "store" maps to a table with columns `id TEXT PRIMARY KEY, data TEXT`.
-/// A separate `config` table holds arbitrary key/value string pairs.
-///
-/// On first launch, the database is initialized with the current schema.
 #[cfg(not(target_arch = "wasm32"))]
-pub(crate) mod native_storage {
-    use rusqlite::{params, Connection};
-    use serde::{de::DeserializeOwned, Serialize};
-    use std::path::PathBuf;
-    /// On Android, ask the OS for the app's internal files directory via JNI.
-    ///
-    /// Uses `ndk_context::android_context()` (which Dioxus already sets up)
-    /// to obtain the `JavaVM` and `Activity` pointers without requiring any
-    /// custom `MainActivity.kt`.  Calls `Activity.getFilesDir()` and then
-    /// `File.getAbsolutePath()` to get a `String` path back.
-    ///
-    /// Returns `None` on any JNI error; `data_dir()` will then fall back to
-    /// `dirs::data_local_dir()`.
-    #[cfg(target_os = "android")]
-    fn android_files_dir() -> Option<PathBuf> {
-        use jni::{objects::JObject, JavaVM};
-        let ctx = ndk_context::android_context();
-        if ctx.vm().is_null() {
-            log::error!("android_files_dir: JavaVM pointer is NULL! ndk-context not initialized?");
-            return None;
-        }
-        if ctx.context().is_null() {
-            log::error!("android_files_dir: Context pointer is NULL! ndk-context not initialized?");
-            return None;
-        }
-        // SAFETY: pointers are valid for the lifetime of the process and were
-        // set up by the Dioxus / Android runtime before Rust code runs.
-        let vm = match unsafe { JavaVM::from_raw(ctx.vm().cast()) } {
-            Ok(vm) => vm,
-            Err(e) => {
-                log::error!("android_files_dir: JavaVM::from_raw failed: {e:?}");
-                return None;
-            }
-        };
-        let mut env = match vm.attach_current_thread() {
-            Ok(env) => env,
-            Err(e) => {
-                log::error!("android_files_dir: attach_current_thread failed: {e:?}");
-                return None;
-            }
-        };
-        let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
-        let files_dir = match env
-            .call_method(&activity, "getFilesDir", "()Ljava/io/File;", &[])
-            .and_then(jni::objects::JValueGen::l)
-        {
-            Ok(obj) => obj,
-            Err(e) => {
-                log::error!("android_files_dir: getFilesDir() failed: {e:?}");
-                return None;
-            }
-        };
-        let path_jobj = match env
-            .call_method(&files_dir, "getAbsolutePath", "()Ljava/lang/String;", &[])
-            .and_then(jni::objects::JValueGen::l)
-        {
-            Ok(obj) => obj,
-            Err(e) => {
-                log::error!("android_files_dir: getAbsolutePath() failed: {e:?}");
-                return None;
-            }
-        };
-        let path_str: jni::objects::JString = path_jobj.into();
-        let result = match env.get_string(&path_str) {
-            Ok(s) => {
-                let p = PathBuf::from(String::from(s));
-                log::info!("android_files_dir: Success! Path: {}", p.display());
-                Some(p)
-            }
-            Err(e) => {
-                log::error!("android_files_dir: get_string failed: {e:?}");
-                None
-            }
-        };
-        result
-    }
-    /// Returns the app's external files directory via JNI (`getExternalFilesDir(null)`).
-    ///
-    /// On Android, this resolves to a path like
-    /// `/storage/emulated/0/Android/data/<package>/files/`.  The directory is
-    /// readable by the user via a file manager without any special permissions.
-    /// Returns `None` on any JNI error.
-    ///
-    /// SAFETY: the `JavaVM` pointer is process-lifetime, set up by the Android /
-    /// Dioxus runtime before Rust code runs.  `JavaVM::from_raw` wraps the raw
-    /// pointer without taking ownership; the JVM is not destroyed when `vm` is
-    /// dropped because `jni::JavaVM::drop` is a no-op for attached VMs.
-    #[cfg(target_os = "android")]
-    pub fn android_external_files_dir() -> Option<std::path::PathBuf> {
-        use jni::{objects::JObject, JavaVM};
-        let ctx = ndk_context::android_context();
-        if ctx.vm().is_null() || ctx.context().is_null() {
-            log::error!("android_external_files_dir: Context or VM is NULL!");
-            return None;
-        }
-        let vm = match unsafe { JavaVM::from_raw(ctx.vm().cast()) } {
-            Ok(vm) => vm,
-            Err(e) => {
-                log::error!("android_external_files_dir: JavaVM::from_raw: {e:?}");
-                return None;
-            }
-        };
-        let mut env = match vm.attach_current_thread() {
-            Ok(env) => env,
-            Err(e) => {
-                log::error!("android_external_files_dir: attach_current_thread: {e:?}");
-                return None;
-            }
-        };
-        let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
-        let null_obj = JObject::null();
-        let files_dir = match env
-            .call_method(
-                &activity,
-                "getExternalFilesDir",
-                "(Ljava/lang/String;)Ljava/io/File;",
-                &[jni::objects::JValue::Object(&null_obj)],
-            )
-            .and_then(jni::objects::JValueGen::l)
-        {
-            Ok(obj) => obj,
-            Err(e) => {
-                log::error!("android_external_files_dir: getExternalFilesDir: {e:?}");
-                return None;
-            }
-        };
-        if files_dir.is_null() {
-            log::warn!("android_external_files_dir: getExternalFilesDir returned null (external storage not mounted?)");
-            return None;
-        }
-        let path_jobj = match env
-            .call_method(&files_dir, "getAbsolutePath", "()Ljava/lang/String;", &[])
-            .and_then(jni::objects::JValueGen::l)
-        {
-            Ok(obj) => obj,
-            Err(e) => {
-                log::error!("android_external_files_dir: getAbsolutePath: {e:?}");
-                return None;
-            }
-        };
-        let path_str: jni::objects::JString = path_jobj.into();
-        // Bind to a local so the `JavaStr` temporary is dropped before `vm`
-        // and `path_str` go out of scope (avoids E0597).
-        let result = match env.get_string(&path_str) {
-            Ok(s) => {
-                let p = std::path::PathBuf::from(String::from(s));
-                log::info!("android_external_files_dir: {}", p.display());
-                Some(p)
-            }
-            Err(e) => {
-                log::error!("android_external_files_dir: get_string: {e:?}");
-                None
-            }
-        };
-        result
-    }
-
-    /// Saves a text file to the global Android Downloads folder using MediaStore.
-    ///
-    /// On Android 10+ (API 29) this is the preferred way to write to public
-    /// directories without requiring the broad `WRITE_EXTERNAL_STORAGE`
-    /// permission.  The file is inserted into the `MediaStore.Downloads`
-    /// collection.
-    #[cfg(target_os = "android")]
-    pub fn android_save_to_downloads(filename: &str, content: &str) -> Result<String, String> {
-        use jni::{objects::JObject, JavaVM};
-        let ctx = ndk_context::android_context();
-        if ctx.vm().is_null() || ctx.context().is_null() {
-            return Err("Android context not available".into());
-        }
-        let vm = unsafe { JavaVM::from_raw(ctx.vm().cast()) }
-            .map_err(|e| format!("JavaVM::from_raw: {e}"))?;
-        let mut env = vm
-            .attach_current_thread()
-            .map_err(|e| format!("attach_current_thread: {e}"))?;
-        let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
-
-        // ContentValues values = new ContentValues();
-        let values = env
-            .new_object("android/content/ContentValues", "()V", &[])
-            .map_err(|e| format!("new ContentValues: {e}"))?;
-
-        let jfilename = env
-            .new_string(filename)
-            .map_err(|e| format!("new_string filename: {e}"))?;
-        let jmime = env
-            .new_string("application/json")
-            .map_err(|e| format!("new_string mime: {e}"))?;
-        let jrel_path = env
-            .new_string("Download/")
-            .map_err(|e| format!("new_string rel_path: {e}"))?;
-
-        let jdisplay_name_key = env
-            .new_string("_display_name")
-            .map_err(|e| format!("new_string _display_name: {e}"))?;
-        let jmime_type_key = env
-            .new_string("mime_type")
-            .map_err(|e| format!("new_string mime_type: {e}"))?;
-        let jrelative_path_key = env
-            .new_string("relative_path")
-            .map_err(|e| format!("new_string relative_path: {e}"))?;
-
-        // values.put("_display_name", filename);
-        env.call_method(
-            &values,
-            "put",
-            "(Ljava/lang/String;Ljava/lang/String;)V",
-            &[
-                jni::objects::JValue::from(&jdisplay_name_key),
-                jni::objects::JValue::from(&jfilename),
-            ],
-        )
-        .map_err(|e| format!("ContentValues.put name: {e}"))?;
-
-        // values.put("mime_type", "application/json");
-        env.call_method(
-            &values,
-            "put",
-            "(Ljava/lang/String;Ljava/lang/String;)V",
-            &[
-                jni::objects::JValue::from(&jmime_type_key),
-                jni::objects::JValue::from(&jmime),
-            ],
-        )
-        .map_err(|e| format!("ContentValues.put mime: {e}"))?;
-
-        // values.put("relative_path", "Download/");
-        env.call_method(
-            &values,
-            "put",
-            "(Ljava/lang/String;Ljava/lang/String;)V",
-            &[
-                jni::objects::JValue::from(&jrelative_path_key),
-                jni::objects::JValue::from(&jrel_path),
-            ],
-        )
-        .map_err(|e| format!("ContentValues.put path: {e}"))?;
-
-        // ContentResolver resolver = context.getContentResolver();
-        let resolver = env
-            .call_method(
-                &activity,
-                "getContentResolver",
-                "()Landroid/content/ContentResolver;",
-                &[],
-            )
-            .map_err(|e| format!("getContentResolver: {e}"))?
-            .l()
-            .map_err(|e| format!("ContentResolver obj: {e}"))?;
-
-        // Uri uri = MediaStore.Downloads.getContentUri("external");
-        let jexternal = env
-            .new_string("external")
-            .map_err(|e| format!("new_string external: {e}"))?;
-        let external_uri = env
-            .call_static_method(
-                "android/provider/MediaStore$Downloads",
-                "getContentUri",
-                "(Ljava/lang/String;)Landroid/net/Uri;",
-                &[jni::objects::JValue::from(&jexternal)],
-            )
-            .map_err(|e| format!("MediaStore.Downloads.getContentUri: {e}"))?
-            .l()
-            .map_err(|e| format!("Uri obj: {e}"))?;
-
-        // Uri fileUri = resolver.insert(external_uri, values);
-        let file_uri = env
-            .call_method(
-                &resolver,
-                "insert",
-                "(Landroid/net/Uri;Landroid/content/ContentValues;)Landroid/net/Uri;",
-                &[
-                    jni::objects::JValue::from(&external_uri),
-                    jni::objects::JValue::from(&values),
-                ],
-            )
-            .map_err(|e| format!("resolver.insert: {e}"))?
-            .l()
-            .map_err(|e| format!("fileUri obj: {e}"))?;
-
-        if file_uri.is_null() {
-            return Err("MediaStore insert returned null (duplicate filename?)".into());
-        }
-
-        // OutputStream os = resolver.openOutputStream(fileUri, "w");
-        let jwrite_mode = env
-            .new_string("w")
-            .map_err(|e| format!("new_string w: {e}"))?;
-        let os = env
-            .call_method(
-                &resolver,
-                "openOutputStream",
-                "(Landroid/net/Uri;Ljava/lang/String;)Ljava/io/OutputStream;",
-                &[
-                    jni::objects::JValue::from(&file_uri),
-                    jni::objects::JValue::from(&jwrite_mode),
-                ],
-            )
-            .map_err(|e| format!("openOutputStream: {e}"))?
-            .l()
-            .map_err(|e| format!("OutputStream obj: {e}"))?;
-
-        // os.write(content.getBytes());
-        let jcontent_bytes = env
-            .byte_array_from_slice(content.as_bytes())
-            .map_err(|e| format!("byte_array_from_slice: {e}"))?;
-        let content_bytes_obj = jni::objects::JObject::from(jcontent_bytes);
-        env.call_method(
-            &os,
-            "write",
-            "([B)V",
-            &[jni::objects::JValue::from(&content_bytes_obj)],
-        )
-        .map_err(|e| format!("OutputStream.write: {e}"))?;
-
-        // os.close();
-        env.call_method(&os, "close", "()V", &[])
-            .map_err(|e| format!("OutputStream.close: {e}"))?;
-
-        Ok(format!("Download/{filename}"))
-    }
-    pub const STORE_SESSIONS: &str = "sessions";
-    pub const STORE_CUSTOM_EXERCISES: &str = "custom_exercises";
-    pub const STORE_EXERCISES: &str = "exercises";
-    /// Name of the application data sub-directory under the OS data dir.
-    #[cfg(not(test))]
-    const APP_DATA_DIR_NAME: &str = "log-out";
-    /// File name of the `SQLite` database within the application data directory.
-    pub const DB_FILENAME: &str = "log-out.db";
-    /// `SQLite` `user_version` value written on a successful schema migration.
-    /// Any database with a lower version is wiped and recreated from scratch.
-    const SCHEMA_VERSION: u32 = 2;
-    /// Structured error type for native (`SQLite`) storage operations.
-    #[derive(Debug, thiserror::Error)]
-    pub enum StorageError {
-        /// Unknown store name — indicates a programming error.
-        #[error("Unknown store: {0}")]
-        UnknownStore(String),
-        /// Database-level error from `rusqlite`.
-        #[error("Database error: {0}")]
-        Database(#[from] rusqlite::Error),
-        /// JSON serialisation / deserialisation error.
-        #[error("Serialization error: {0}")]
-        Serialization(#[from] serde_json::Error),
-        /// OS-level I/O error (directory creation, file access, etc.).
-        #[error("IO error: {0}")]
-        Io(#[from] std::io::Error),
-    }
-    /// Returns a static SQL table name for a known store, or
-    /// `Err(StorageError::UnknownStore)` for an unrecognised name.
-    ///
-    /// Using this function for all table-name resolution ensures that no
-    /// dynamic string can ever reach a SQL statement, eliminating table-name
-    /// injection as a risk regardless of call order.
-    ///
-    /// The return type is `&'static str`, which means the value is always one
-    /// of a fixed set of compile-time string literals — never arbitrary
-    /// user-controlled input.  Interpolating this value into a SQL string is
-    /// therefore safe, equivalent in risk to writing the table name directly.
-    fn store_table(store_name: &str) -> Result<&'static str, StorageError> {
-        match store_name {
-            STORE_SESSIONS => Ok("sessions"),
-            STORE_CUSTOM_EXERCISES => Ok("custom_exercises"),
-            STORE_EXERCISES => Ok("exercises"),
-            other => Err(StorageError::UnknownStore(other.to_string())),
-        }
-    }
-    /// Returns the application data directory, creating it if necessary.
-    ///
-    /// The result is computed once and cached in a process-wide `OnceLock` so
-    /// that all call sites — including the `imgcache://` custom-protocol handler
-    /// running on a `WebView` thread — always see the same path regardless of
-    /// whether the JNI call succeeds on every thread.
-    pub fn data_dir() -> PathBuf {
-        // In test builds each nextest process gets its own isolated directory
-        // so concurrent test runs never share the same SQLite file.
-        #[cfg(test)]
-        return test_data_dir();
-        #[cfg(not(test))]
-        {
-            static DATA_DIR: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
-            DATA_DIR
-                .get_or_init(|| {
-                    let dir = if let Ok(custom) = std::env::var("LOGOUT_DATA_DIR") {
-                        PathBuf::from(custom)
-                    } else {
-                        #[cfg(target_os = "android")]
-                        if let Some(dir) = android_files_dir() {
-                            dir
-                        } else {
-                            dirs::data_local_dir()
-                                .unwrap_or_else(|| PathBuf::from("."))
-                                .join(APP_DATA_DIR_NAME)
-                        }
-                        #[cfg(not(target_os = "android"))]
-                        dirs::data_local_dir()
-                            .unwrap_or_else(|| PathBuf::from("."))
-                            .join(APP_DATA_DIR_NAME)
-                    };
-                    log::info!("Resolved data_dir: {}", dir.display());
-                    dir
-                })
-                .clone()
-        }
-    }
-    /// Returns a per-process temporary directory for test isolation.
-    ///
-    /// Each nextest invocation runs in its own process, so using the process
-    /// ID as a unique suffix guarantees that concurrent tests never share the
-    /// same `SQLite` database file.
-    #[cfg(test)]
-    fn test_data_dir() -> PathBuf {
-        static DIR: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
-        DIR.get_or_init(|| {
-            let dir = std::env::temp_dir().join(format!("logout-test-{}", std::process::id()));
-            std::fs::create_dir_all(&dir)
-                .expect("failed to create per-process test data directory");
-            dir
-        })
-        .clone()
-    }
-    fn db_path() -> PathBuf {
-        data_dir().join(DB_FILENAME)
-    }
-    /// Returns the directory used for storing cached exercise images.
-    ///
-    /// On Android, this prefers the external files directory so that the images
-    /// are visible to the user and can be backed up or managed by the system
-    /// gallery.  Falls back to the internal data directory if external storage
-    /// is unavailable.
-    pub fn images_dir() -> PathBuf {
-        static IMAGES_DIR: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
-        IMAGES_DIR
-            .get_or_init(|| {
-                let dir = {
-                    #[cfg(target_os = "android")]
-                    if let Some(dir) = android_external_files_dir() {
-                        dir.join("images")
-                    } else {
-                        data_dir().join("images")
-                    }
-                    #[cfg(not(target_os = "android"))]
-                    data_dir().join("images")
-                };
-                log::info!("Resolved images_dir: {}", dir.display());
-                dir
-            })
-            .clone()
-    }
-    /// Runs incremental schema migrations to bring the database up to the current version.
-    ///
-    /// Any schema version below 2 (including a blank database) causes all tables to be
-    /// dropped and recreated fresh.  Data preservation is not attempted — the app has no
-    /// established user base yet.
-    ///
-    /// Separated from [`open_db`] so it can be called in tests after a manual schema
-    /// reset without needing to re-create the long-lived connection.
-    fn apply_migration_if_needed(conn: &Connection) -> Result<(), StorageError> {
-        let schema_version: u32 = conn.query_row("PRAGMA user_version", [], |r| r.get(0))?;
-        if schema_version < SCHEMA_VERSION {
-            // Fresh install or outdated schema: drop everything and start clean.
-            // SCHEMA_VERSION must match the `PRAGMA user_version` value at the end.
-            conn.execute_batch(
-                "DROP TABLE IF EXISTS sessions;
-                 DROP TABLE IF EXISTS custom_exercises;
-                 DROP TABLE IF EXISTS exercises;
-                 DROP TABLE IF EXISTS config;
-                 CREATE TABLE sessions (
-                     id          TEXT    PRIMARY KEY,
-                     data        TEXT    NOT NULL,
-                     start_time  INTEGER GENERATED ALWAYS AS (
-                                     CASE WHEN json_valid(data)
-                                          THEN CAST(json_extract(data, '$.start_time') AS INTEGER)
-                                          ELSE NULL END
-                                 ) STORED,
-                     end_time    INTEGER GENERATED ALWAYS AS (
-                                     CASE WHEN json_valid(data)
-                                          THEN CAST(json_extract(data, '$.end_time') AS INTEGER)
-                                          ELSE NULL END
-                                 ) STORED
-                 );
-                 CREATE INDEX IF NOT EXISTS idx_sessions_end_time   ON sessions(end_time)   WHERE end_time   IS NOT NULL;
-                 CREATE INDEX IF NOT EXISTS idx_sessions_start_time ON sessions(start_time) WHERE start_time IS NOT NULL;
-                 CREATE TABLE custom_exercises (id TEXT PRIMARY KEY, data TEXT NOT NULL);
-                 CREATE TABLE exercises         (id TEXT PRIMARY KEY, data TEXT NOT NULL);
-                 CREATE TABLE config            (key TEXT PRIMARY KEY, value TEXT NOT NULL);
-                 PRAGMA user_version = 2;",
-            )?;
-        }
-        Ok(())
-    }
-    /// Returns a mutex guard for the long-lived `SQLite` connection.
-    ///
-    /// The connection is opened **once** via [`std::sync::OnceLock`] and reused for the
-    /// lifetime of the process.  The schema migration is also applied exactly once,
-    /// inside the `OnceLock` initialiser, so it never runs on subsequent calls.
-    ///
-    /// If the data directory cannot be created or the database file cannot be opened on
-    /// the first call, an error is returned and cached permanently — all subsequent calls
-    /// will return the same error without retrying.
-    fn open_db() -> Result<std::sync::MutexGuard<'static, Connection>, StorageError> {
-        static DB: std::sync::OnceLock<Result<std::sync::Mutex<Connection>, String>> =
-            std::sync::OnceLock::new();
-        let result = DB.get_or_init(|| {
-            (|| {
-                let dir = data_dir();
-                std::fs::create_dir_all(&dir).map_err(|e| {
-                    format!(
-                        "open_db: failed to create data directory {}: {e}",
-                        dir.display()
-                    )
-                })?;
-                let path = db_path();
-                let conn = Connection::open(&path).map_err(|e| {
-                    format!(
-                        "open_db: failed to open SQLite database at {}: {e}",
-                        path.display()
-                    )
-                })?;
-                apply_migration_if_needed(&conn)
-                    .map_err(|e| format!("open_db: failed to apply schema migration: {e}"))?;
-                Ok(std::sync::Mutex::new(conn))
-            })()
-        });
-        match result {
-            Ok(mutex) => Ok(mutex
-                .lock()
-                .unwrap_or_else(std::sync::PoisonError::into_inner)),
-            Err(e) => Err(StorageError::Io(std::io::Error::other(e.clone()))),
-        }
-    }
-    /// Re-applies the schema migration using the shared long-lived connection.
-    ///
-    /// Only available in tests.  Use this after manually dropping tables to simulate
-    /// a fresh-database migration without needing a separate `Connection`.
-    #[cfg(test)]
-    pub(crate) fn apply_migration_for_testing() -> Result<(), StorageError> {
-        let conn = open_db()?;
-        apply_migration_if_needed(&conn)
-    }
-    /// Reads all items from a store, deserialising each row's JSON `data` column.
-    pub fn get_all<T: DeserializeOwned>(store_name: &str) -> Result<Vec<T>, StorageError> {
-        let table = store_table(store_name)?;
-        let conn = open_db()?;
-        let query = format!("SELECT data FROM {table}");
-        let mut stmt = conn.prepare(&query)?;
-        let items = stmt
-            .query_map([], |row| row.get::<_, String>(0))?
-            .filter_map(Result::ok)
-            .filter_map(|data| {
-                serde_json::from_str::<T>(&data)
-                    .inspect_err(|e| log::warn!("Skipping corrupt SQLite row: {e}"))
-                    .ok()
-            })
-            .collect();
-        Ok(items)
-    }
-    /// Reads completed sessions ordered by `start_time` descending, with
-    /// database-level `LIMIT` / `OFFSET` pagination to avoid loading the
-    /// entire history into memory.
-    ///
-    /// Uses the `end_time` and `start_time` generated columns (and their
-    /// covering indices) so `SQLite` never needs to parse JSON for filtering or
-    /// sorting.
-    ///
-    /// `limit` and `offset` are clamped to `i64::MAX` before being passed to
-    /// `SQLite`; in practice both will always be tiny (tens to hundreds).
-    pub fn get_completed_sessions_paged(
-        limit: usize,
-        offset: usize,
-    ) -> Result<Vec<crate::models::WorkoutSession>, StorageError> {
-        let conn = open_db()?;
-        let mut stmt = conn.prepare(
-            "SELECT data FROM sessions \
-             WHERE end_time IS NOT NULL \
-             ORDER BY start_time DESC \
-             LIMIT ?1 OFFSET ?2",
-        )?;
-        let limit_i64 = i64::try_from(limit).unwrap_or(i64::MAX);
-        let offset_i64 = i64::try_from(offset).unwrap_or(i64::MAX);
-        let items = stmt
-            .query_map(params![limit_i64, offset_i64], |row| {
-                row.get::<_, String>(0)
-            })?
-            .filter_map(Result::ok)
-            .filter_map(|data| {
-                serde_json::from_str::<crate::models::WorkoutSession>(&data)
-                    .inspect_err(|e| log::warn!("Skipping corrupt SQLite row: {e}"))
-                    .ok()
-            })
-            .collect();
-        Ok(items)
-    }
-    /// Replaces the entire contents of a store with `items` in a single transaction.
-    ///
-    /// JSON serialisation is performed **before** the `SQLite` mutex is acquired so
-    /// that expensive serialisation work never blocks other threads waiting for the
-    /// lock.
-    ///
-    /// Uses a RAII `Transaction` guard so that the database is automatically
-    /// rolled back if an error or panic occurs before `commit()`.
-    pub fn store_all<T: Serialize>(store_name: &str, items: &[T]) -> Result<(), StorageError> {
-        let table = store_table(store_name)?;
-        // Serialise every item to (id, JSON) *before* acquiring the database mutex.
-        let rows: Vec<(String, String)> = items
-            .iter()
-            .map(|item| {
-                let val = serde_json::to_value(item)?;
-                let id = val
-                    .get("id")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("")
-                    .to_string();
-                let data = serde_json::to_string(item)?;
-                Ok((id, data))
-            })
-            .collect::<Result<_, serde_json::Error>>()?;
-        let mut conn = open_db()?;
-        let tx = conn.transaction()?;
-        let delete_sql = format!("DELETE FROM {table}");
-        tx.execute(&delete_sql, [])?;
-        let insert_sql = format!("INSERT OR REPLACE INTO {table} (id, data) VALUES (?1, ?2)");
-        for (id, data) in &rows {
-            tx.execute(&insert_sql, params![id, data])?;
-        }
-        tx.commit()?;
-        Ok(())
-    }
-    /// Upserts one item (identified by `id`) into a store.
-    ///
-    /// JSON serialisation is performed **before** the `SQLite` mutex is acquired so
-    /// that serialisation work never blocks other threads waiting for the lock.
-    pub fn put_item<T: Serialize>(
-        store_name: &str,
-        id: &str,
-        item: &T,
-    ) -> Result<(), StorageError> {
-        let table = store_table(store_name)?;
-        // Serialise outside the lock to keep the critical section minimal.
-        let data = serde_json::to_string(item)?;
-        let conn = open_db()?;
-        let insert_sql = format!("INSERT OR REPLACE INTO {table} (id, data) VALUES (?1, ?2)");
-        conn.execute(&insert_sql, params![id, data])?;
-        Ok(())
-    }
-    /// Deletes the item with `id` from a store (no-op if absent).
-    pub fn delete_item(store_name: &str, id: &str) -> Result<(), StorageError> {
-        let table = store_table(store_name)?;
-        let conn = open_db()?;
-        let delete_sql = format!("DELETE FROM {table} WHERE id = ?1");
-        conn.execute(&delete_sql, params![id])?;
-        Ok(())
-    }
-    /// Returns the total number of rows in the `sessions` table.
-    pub fn get_session_count() -> Result<usize, StorageError> {
-        let conn = open_db()?;
-        let count: usize = conn.query_row("SELECT COUNT(*) FROM sessions", [], |r| r.get(0))?;
-        Ok(count)
-    }
-    /// Returns the string value for `key`, or `None` if absent.
-    pub fn get_config_value(key: &str) -> Option<String> {
-        let conn = open_db().ok()?;
-        conn.query_row(
-            "SELECT value FROM config WHERE key = ?1",
-            params![key],
-            |row| row.get(0),
-        )
-        .ok()
-    }
-    /// Sets `key` to `value`.  Passing an empty `value` removes the key.
-    pub fn set_config_value(key: &str, value: &str) -> Result<(), StorageError> {
-        let conn = open_db()?;
-        if value.is_empty() {
-            conn.execute("DELETE FROM config WHERE key = ?1", params![key])?;
-        } else {
-            conn.execute(
-                "INSERT OR REPLACE INTO config (key, value) VALUES (?1, ?2)",
-                params![key, value],
-            )?;
-        }
-        Ok(())
-    }
-    /// Removes `key` from the config (no-op if absent).
-    pub fn remove_config_value(key: &str) -> Result<(), StorageError> {
-        set_config_value(key, "")
-    }
-    /// Load only the active (in-progress) sessions by filtering at the SQL level.
-    ///
-    /// More memory-efficient than [`get_all`] because completed sessions, which
-    /// can represent the bulk of history, are never deserialised into Rust.
-    pub fn get_active_sessions() -> Result<Vec<crate::models::WorkoutSession>, StorageError> {
-        let conn = open_db()?;
-        let mut stmt = conn.prepare("SELECT data FROM sessions WHERE end_time IS NULL")?;
-        let items = stmt
-            .query_map([], |row| row.get::<_, String>(0))?
-            .filter_map(Result::ok)
-            .filter_map(|data| {
-                serde_json::from_str::<crate::models::WorkoutSession>(&data)
-                    .inspect_err(|e| log::warn!("Skipping corrupt active session row: {e}"))
-                    .ok()
-            })
-            .collect();
-        Ok(items)
-    }
-    /// Compute per-exercise all-time bests using a single SQL aggregation query.
-    ///
-    /// Uses `json_each` to iterate the `exercise_logs` array inside each
-    /// completed session row, so **no session JSON is ever deserialised into a
-    /// Rust struct**.  This is the most memory-efficient path available on
-    /// native.
-    ///
-    /// Only completed logs (those whose `end_time` field is non-null) contribute
-    /// to the aggregation, matching the behaviour of
-    /// [`crate::services::app_state::merge_log_into_bests`].
-    pub fn compute_bests_rows() -> Result<Vec<super::BestsRow>, StorageError> {
-        bests_rows_query(None)
-    }
-    /// Same as [`compute_bests_rows`] but restricted to the given exercise IDs.
-    ///
-    /// Passes the IDs as a JSON array parameter and uses a sub-select to avoid
-    /// aggregating exercises the caller does not need.
-    pub fn compute_bests_rows_for(
-        exercise_ids: &[String],
-    ) -> Result<Vec<super::BestsRow>, StorageError> {
-        bests_rows_query(Some(exercise_ids))
-    }
-    /// Shared implementation: if `ids` is `None` aggregates all exercises;
-    /// if `Some`, adds a `json_each` IN-filter on the `exercise_id` column.
-    fn bests_rows_query(ids: Option<&[String]>) -> Result<Vec<super::BestsRow>, StorageError> {
-        let conn = open_db()?;
-        let id_filter = if ids.is_some() {
-            "AND json_extract(log.value, '$.exercise_id') \
-             IN (SELECT value FROM json_each(?1))"
-        } else {
-            ""
-        };
-        // CTE-based query that computes both ATH (max) values and the values from
-        // the most-recently completed log per exercise in a single pass.
-        let sql = format!(
-            "WITH all_logs AS ( \
-                 SELECT \
-                     json_extract(log.value, '$.exercise_id')                           AS exercise_id, \
-                     CAST(json_extract(log.value, '$.weight_hg')   AS INTEGER)          AS weight, \
-                     CAST(json_extract(log.value, '$.reps')        AS INTEGER)          AS reps, \
-                     CAST(json_extract(log.value, '$.distance_m')  AS INTEGER)          AS dist, \
-                     CAST(json_extract(log.value, '$.end_time')    AS INTEGER) \
-                   - CAST(json_extract(log.value, '$.start_time')  AS INTEGER)          AS dur, \
-                     CAST(json_extract(log.value, '$.end_time')    AS INTEGER)          AS end_ts \
-                 FROM sessions \
-                 CROSS JOIN json_each(json_extract(data, '$.exercise_logs')) AS log \
-                 WHERE end_time IS NOT NULL \
-                   AND json_extract(log.value, '$.end_time') IS NOT NULL \
-                   {id_filter} \
-             ), \
-             bests AS ( \
-                 SELECT exercise_id, \
-                        MAX(weight) AS max_weight, \
-                        MAX(reps)   AS max_reps, \
-                        MAX(dist)   AS max_dist, \
-                        MAX(dur)    AS max_dur \
-                 FROM all_logs \
-                 GROUP BY exercise_id \
-             ), \
-             ranked AS ( \
-                 SELECT exercise_id, weight, reps, dist, end_ts, \
-                        ROW_NUMBER() OVER ( \
-                            PARTITION BY exercise_id \
-                            ORDER BY end_ts DESC \
-                        ) AS rn \
-                 FROM all_logs \
-             ), \
-             lasts AS ( \
-                 SELECT exercise_id, \
-                        weight AS last_weight, \
-                        reps   AS last_reps, \
-                        dist   AS last_dist, \
-                        end_ts AS last_ts \
-                 FROM ranked WHERE rn = 1 \
-             ) \
-             SELECT b.exercise_id, \
-                    b.max_weight, b.max_reps, b.max_dist, b.max_dur, \
-                    l.last_weight, l.last_reps, l.last_dist, l.last_ts \
-             FROM bests b \
-             LEFT JOIN lasts l ON b.exercise_id = l.exercise_id"
-        );
-        let mut stmt = conn.prepare(&sql)?;
-        let map_row = |row: &rusqlite::Row<'_>| {
-            Ok((
-                row.get::<_, String>(0)?,
-                row.get::<_, Option<i64>>(1)?,
-                row.get::<_, Option<i64>>(2)?,
-                row.get::<_, Option<i64>>(3)?,
-                row.get::<_, Option<i64>>(4)?,
-                row.get::<_, Option<i64>>(5)?,
-                row.get::<_, Option<i64>>(6)?,
-                row.get::<_, Option<i64>>(7)?,
-                row.get::<_, Option<i64>>(8)?,
-            ))
-        };
-        let rows: Vec<super::BestsRow> = if let Some(ids) = ids {
-            let json = serde_json::to_string(ids).unwrap_or_else(|_| "[]".into());
-            stmt.query_map(rusqlite::params![json], map_row)?
-                .filter_map(Result::ok)
-                .map(bests_row_from_tuple)
-                .collect()
-        } else {
-            stmt.query_map([], map_row)?
-                .filter_map(Result::ok)
-                .map(bests_row_from_tuple)
-                .collect()
-        };
-        Ok(rows)
-    }
-    /// Raw SQL projection tuple returned by `bests_rows_query`.
-    type BestsSqlTuple = (
-        String,
-        Option<i64>,
-        Option<i64>,
-        Option<i64>,
-        Option<i64>,
-        Option<i64>,
-        Option<i64>,
-        Option<i64>,
-        Option<i64>,
-    );
-    /// Convert the raw SQL tuple into a [`BestsRow`].
-    fn bests_row_from_tuple(
-        (exercise_id, w, r, d, dur, lw, lr, ld, lts): BestsSqlTuple,
-    ) -> super::BestsRow {
-        super::BestsRow {
-            exercise_id,
-            max_weight_hg: w.and_then(|v| u16::try_from(v).ok()),
-            max_reps: r.and_then(|v| u32::try_from(v).ok()),
-            max_distance_m: d.and_then(|v| u32::try_from(v).ok()),
-            max_duration_s: dur.and_then(|v| u64::try_from(v).ok()),
-            last_weight_hg: lw.and_then(|v| u16::try_from(v).ok()),
-            last_reps: lr.and_then(|v| u32::try_from(v).ok()),
-            last_distance_m: ld.and_then(|v| u32::try_from(v).ok()),
-            last_log_end_time: lts.and_then(|v| u64::try_from(v).ok()),
-        }
-    }
-    /// Global mutex that serialises all tests touching native storage within a
-    /// single process.
-    ///
-    /// With nextest each test runs in its own process, so cross-process
-    /// isolation is handled by [`test_data_dir`]'s per-process directory.
-    /// This mutex provides additional within-process serialisation for the
-    /// (rare) case where multiple storage tests share a process.
-    ///
-    /// Recovers from a poisoned mutex so a previous test failure does not
-    /// cascade into every subsequent test that needs storage isolation.
-    #[cfg(test)]
-    pub(crate) fn test_lock() -> std::sync::MutexGuard<'static, ()> {
-        static LOCK: std::sync::OnceLock<std::sync::Mutex<()>> = std::sync::OnceLock::new();
-        let m = LOCK.get_or_init(|| std::sync::Mutex::new(()));
-        m.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
-    }
-}
+pub(crate) use logout_core::storage::sqlite as native_storage;
 /// Zero-size marker type that binds [`AsyncStorageProvider`] to the `SQLite`
 /// backend exposed by [`native_storage`].
 #[cfg(not(target_arch = "wasm32"))]
@@ -1596,9 +1097,10 @@ impl AsyncStorageProvider for NativeStorage {
         &self,
         limit: usize,
         offset: usize,
+        include_archived: bool,
     ) -> Result<Vec<crate::models::WorkoutSession>, StorageError> {
         tokio::task::spawn_blocking(move || {
-            native_storage::get_completed_sessions_paged(limit, offset)
+            native_storage::get_completed_sessions_paged(limit, offset, include_archived)
         })
         .await
         .map_err(|e| StorageError::TaskPanic(e.to_string()))?
@@ -1643,6 +1145,49 @@ impl AsyncStorageProvider for NativeStorage {
             .map_err(|e| StorageError::TaskPanic(e.to_string()))?
             .map_err(StorageError::from)
     }
+    async fn load_trash(&self) -> Result<Vec<crate::models::TrashEntry>, StorageError> {
+        tokio::task::spawn_blocking(|| native_storage::get_all(native_storage::STORE_TRASH))
+            .await
+            .map_err(|e| StorageError::TaskPanic(e.to_string()))?
+            .map_err(StorageError::from)
+    }
+    async fn restore_from_trash(
+        &self,
+        entry_id: String,
+    ) -> Result<Option<crate::models::TrashedItem>, StorageError> {
+        tokio::task::spawn_blocking(move || native_storage::restore_from_trash(&entry_id))
+            .await
+            .map_err(|e| StorageError::TaskPanic(e.to_string()))?
+            .map_err(StorageError::from)
+    }
+    async fn delete_from_trash(&self, entry_id: String) -> Result<(), StorageError> {
+        tokio::task::spawn_blocking(move || {
+            native_storage::delete_item(native_storage::STORE_TRASH, &entry_id)
+        })
+        .await
+        .map_err(|e| StorageError::TaskPanic(e.to_string()))?
+        .map_err(StorageError::from)
+    }
+    async fn purge_trash(&self, cutoff: u64) -> Result<usize, StorageError> {
+        tokio::task::spawn_blocking(move || native_storage::purge_trash(cutoff))
+            .await
+            .map_err(|e| StorageError::TaskPanic(e.to_string()))?
+            .map_err(StorageError::from)
+    }
+    async fn pack_sessions(&self, cutoff: u64) -> Result<usize, StorageError> {
+        tokio::task::spawn_blocking(move || native_storage::pack_sessions_ended_before(cutoff))
+            .await
+            .map_err(|e| StorageError::TaskPanic(e.to_string()))?
+            .map_err(StorageError::from)
+    }
+    async fn delete_sessions(&self, ids: Vec<String>) -> Result<(), StorageError> {
+        tokio::task::spawn_blocking(move || {
+            native_storage::delete_items(native_storage::STORE_SESSIONS, &ids).map(|_| ())
+        })
+        .await
+        .map_err(|e| StorageError::TaskPanic(e.to_string()))?
+        .map_err(StorageError::from)
+    }
 }
 #[cfg(test)]
 #[cfg(not(target_arch = "wasm32"))]
@@ -1691,13 +1236,17 @@ mod tests {
             start_time: 1_000,
             end_time: None,
             exercise_logs: vec![],
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         };
         native_storage::put_item(native_storage::STORE_SESSIONS, &session.id, &session).unwrap();
         let loaded: Vec<WorkoutSession> =
@@ -1717,26 +1266,34 @@ mod tests {
             start_time: 1_000,
             end_time: None,
             exercise_logs: vec![],
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         };
         let s2 = WorkoutSession {
             id: id.into(),
             start_time: 2_000,
             end_time: Some(3_000),
             exercise_logs: vec![],
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         };
         native_storage::put_item(native_storage::STORE_SESSIONS, id, &s1).unwrap();
         native_storage::put_item(native_storage::STORE_SESSIONS, id, &s2).unwrap();
@@ -1763,13 +1320,17 @@ mod tests {
             start_time: 500,
             end_time: None,
             exercise_logs: vec![],
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         };
         native_storage::put_item(native_storage::STORE_SESSIONS, id, &session).unwrap();
         native_storage::delete_item(native_storage::STORE_SESSIONS, id).unwrap();
@@ -1903,6 +1464,25 @@ mod tests {
         let sessions = vec![make_session("s1", vec![log])];
         assert!(find_last_exercise_log(&sessions, "deadlift").is_none());
     }
+    #[test]
+    fn last_logs_at_location_only_uses_sessions_there() {
+        use super::super::app_state::last_logs_at_location;
+        let mut home = make_session("s1", vec![make_exercise_log("squat", 3_000, Some(3_060))]);
+        home.location = Some("Home".into());
+        let mut gym = make_session(
+            "s2",
+            vec![
+                make_exercise_log("squat", 1_000, Some(1_060)),
+                make_exercise_log("squat", 2_000, Some(2_060)),
+                make_exercise_log("bench", 2_100, None),
+            ],
+        );
+        gym.location = Some("Gym".into());
+        let untagged = make_session("s3", vec![make_exercise_log("squat", 4_000, Some(4_060))]);
+        let last = last_logs_at_location(&[home, gym, untagged], "Gym");
+        assert_eq!(last.len(), 1);
+        assert_eq!(last["squat"].start_time, 2_000);
+    }
     /// Verify that the schema migration creates all required tables and leaves
     /// them in a usable state.
     ///
@@ -1941,13 +1521,17 @@ mod tests {
             start_time: 1_000,
             end_time: None,
             exercise_logs: vec![],
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         };
         native_storage::put_item(native_storage::STORE_SESSIONS, &session.id, &session).unwrap();
         let loaded: Vec<WorkoutSession> =
@@ -1995,30 +1579,39 @@ mod tests {
             start_time: 5_000,
             end_time: None,
             exercise_logs: vec![],
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         };
         let done = WorkoutSession {
             id: "paged_done".into(),
             start_time: 4_000,
             end_time: Some(5_000),
             exercise_logs: vec![],
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         };
         native_storage::put_item(native_storage::STORE_SESSIONS, &active.id, &active).unwrap();
         native_storage::put_item(native_storage::STORE_SESSIONS, &done.id, &done).unwrap();
-        let page = native_storage::get_completed_sessions_paged(10, 0).expect("paged query failed");
+        let page =
+            native_storage::get_completed_sessions_paged(10, 0, false).expect("paged query failed");
         assert!(
             page.iter().any(|s| s.id == done.id),
             "completed session must appear"
@@ -2031,6 +1624,22 @@ mod tests {
         native_storage::delete_item(native_storage::STORE_SESSIONS, &done.id).unwrap();
     }
     #[test]
+    fn completed_sessions_paged_skips_archived_unless_asked() {
+        let _g = lock();
+        let mut archived = WorkoutSession::new();
+        archived.id = "paged_archived".into();
+        archived.end_time = Some(archived.start_time + 60);
+        archived.archived = true;
+        native_storage::put_item(native_storage::STORE_SESSIONS, &archived.id, &archived).unwrap();
+        let visible = native_storage::get_completed_sessions_paged(100, 0, false)
+            .expect("paged query failed");
+        assert!(!visible.iter().any(|s| s.id == archived.id));
+        let all =
+            native_storage::get_completed_sessions_paged(100, 0, true).expect("paged query failed");
+        assert!(all.iter().any(|s| s.id == archived.id && s.archived));
+        native_storage::delete_item(native_storage::STORE_SESSIONS, &archived.id).unwrap();
+    }
+    #[test]
     fn completed_sessions_paged_respects_limit_and_offset() {
         let _g = lock();
         let ids: Vec<String> = (1u64..=5).map(|i| format!("paged_limit_s{i}")).collect();
@@ -2040,21 +1649,25 @@ mod tests {
                 start_time: (i as u64 + 1) * 1_000,
                 end_time: Some((i as u64 + 1) * 1_000 + 60),
                 exercise_logs: vec![],
-                pending_exercise_ids: vec![],
+                pending_exercises: vec![],
                 rest_start_time: None,
                 current_exercise_id: None,
                 current_exercise_start: None,
                 paused_at: None,
                 total_paused_duration: 0,
+                pauses: Vec::new(),
                 notes: String::new(),
+                sealed: None,
+                archived: false,
+                location: None,
             };
             native_storage::put_item(native_storage::STORE_SESSIONS, &s.id, &s).unwrap();
         }
         let page1 =
-            native_storage::get_completed_sessions_paged(2, 0).expect("page 1 query failed");
+            native_storage::get_completed_sessions_paged(2, 0, false).expect("page 1 query failed");
         assert_eq!(page1.len(), 2, "limit 2 must return 2 sessions");
         let page2 =
-            native_storage::get_completed_sessions_paged(2, 2).expect("page 2 query failed");
+            native_storage::get_completed_sessions_paged(2, 2, false).expect("page 2 query failed");
         assert_eq!(page2.len(), 2, "offset 2 must skip first 2 sessions");
         assert!(
             page1[0].start_time > page2[0].start_time,
@@ -2078,35 +1691,138 @@ mod tests {
             instructions: vec![],
             category: Category::Strength,
             images: vec![],
+            videos: vec![],
             i18n: None,
+            rest_seconds: None,
         }
     }
+    #[test]
+    fn full_text_search_follows_the_stored_exercises() {
+        let _g = lock();
+        let mut ex = make_exercise("custom_fts_1", "Tucked Press");
+        ex.primary_muscles = vec![crate::models::Muscle::Triceps];
+        ex.instructions = vec!["Keep the elbows close to the body".into()];
+        native_storage::put_item(native_storage::STORE_CUSTOM_EXERCISES, &ex.id, &ex).unwrap();
+        let hits = |q: &str| native_storage::search_exercise_ids(q, 10).unwrap();
+        assert_eq!(hits("elbo"), ["custom_fts_1"]);
+        assert_eq!(hits("tricep press"), ["custom_fts_1"]);
+        assert_eq!(hits("\"close to the body\""), ["custom_fts_1"]);
+        assert!(hits("\"body the close\"").is_empty());
+        assert!(hits("\" * ").is_empty());
+        ex.instructions = vec!["Flare the arms".into()];
+        native_storage::put_item(native_storage::STORE_CUSTOM_EXERCISES, &ex.id, &ex).unwrap();
+        assert!(hits("elbows").is_empty());
+        native_storage::delete_item(native_storage::STORE_CUSTOM_EXERCISES, &ex.id).unwrap();
+        assert!(hits("flare").is_empty());
+    }
+    #[test]
+    fn packed_sessions_read_like_plain_ones() {
+        let _g = lock();
+        let mut session = make_session(
+            "packed_session_1",
+            vec![make_exercise_log("packed_ex", 1_100, Some(1_200))],
+        );
+        session.end_time = Some(2_000);
+        native_storage::put_item(native_storage::STORE_SESSIONS, &session.id, &session).unwrap();
+        assert!(native_storage::pack_sessions_ended_before(3_000).unwrap() >= 1);
+        let all: Vec<WorkoutSession> =
+            native_storage::get_all(native_storage::STORE_SESSIONS).unwrap();
+        assert!(all.contains(&session));
+        let page = native_storage::get_completed_sessions_paged(usize::MAX, 0, true).unwrap();
+        assert!(page.contains(&session));
+        let rows = native_storage::compute_bests_rows_for(&["packed_ex".into()]).unwrap();
+        assert_eq!(rows.len(), 1);
+        native_storage::move_to_trash(native_storage::STORE_SESSIONS, &session.id, 5_000).unwrap();
+        let trash: Vec<crate::models::TrashEntry> =
+            native_storage::get_all(native_storage::STORE_TRASH).unwrap();
+        let entry = trash
+            .iter()
+            .find(|e| e.item.original_id() == session.id)
+            .unwrap();
+        assert!(matches!(&entry.item, crate::models::TrashedItem::Session(s) if *s == session));
+        native_storage::delete_item(native_storage::STORE_TRASH, &entry.id).unwrap();
+    }
+    #[test]
+    fn batch_delete_skips_the_trash() {
+        let _g = lock();
+        let ids: Vec<String> = (0..3).map(|i| format!("batch_delete_{i}")).collect();
+        for id in &ids {
+            let mut session =
+                make_session(id, vec![make_exercise_log("batch_ex", 1_100, Some(1_200))]);
+            session.end_time = Some(2_000);
+            native_storage::put_item(native_storage::STORE_SESSIONS, id, &session).unwrap();
+        }
+        assert_eq!(
+            native_storage::delete_items(native_storage::STORE_SESSIONS, &ids[..2]).unwrap(),
+            2
+        );
+        let all: Vec<WorkoutSession> =
+            native_storage::get_all(native_storage::STORE_SESSIONS).unwrap();
+        assert!(!all.iter().any(|s| s.id == ids[0] || s.id == ids[1]));
+        assert!(all.iter().any(|s| s.id == ids[2]));
+        let trash: Vec<crate::models::TrashEntry> =
+            native_storage::get_all(native_storage::STORE_TRASH).unwrap();
+        assert!(!trash
+            .iter()
+            .any(|e| ids.contains(&e.item.original_id().to_owned())));
+        native_storage::delete_item(native_storage::STORE_SESSIONS, &ids[2]).unwrap();
+    }
+    #[test]
+    fn packed_and_trashed_sessions_stay_sealed() {
+        let _g = lock();
+        let key = crate::services::crypto::derive_key_with_iterations("1234", b"salt", 1_000);
+        let mut log = make_exercise_log("sealed_ex", 1_100, Some(1_200));
+        log.notes = "sore shoulder".into();
+        log.body_weight_hg = Some(Weight(815));
+        let mut session = make_session("sealed_packed_session", vec![log]);
+        session.end_time = Some(2_000);
+        let sealed = crate::services::app_lock::seal_with(&key, session);
+        assert!(sealed.sealed.is_some());
+        native_storage::put_item(native_storage::STORE_SESSIONS, &sealed.id, &sealed).unwrap();
+        assert!(native_storage::pack_sessions_ended_before(3_000).unwrap() >= 1);
+        let all: Vec<WorkoutSession> =
+            native_storage::get_all(native_storage::STORE_SESSIONS).unwrap();
+        assert!(all.contains(&sealed));
+        native_storage::move_to_trash(native_storage::STORE_SESSIONS, &sealed.id, 5_000).unwrap();
+        let trash: Vec<crate::models::TrashEntry> =
+            native_storage::get_all(native_storage::STORE_TRASH).unwrap();
+        let entry = trash
+            .iter()
+            .find(|e| e.item.original_id() == sealed.id)
+            .unwrap();
+        let json = serde_json::to_string(entry).unwrap();
+        assert!(!json.contains("shoulder"));
+        assert!(!json.contains("\"body_weight_hg\":815"));
+        assert!(matches!(&entry.item, crate::models::TrashedItem::Session(s) if *s == sealed));
+        native_storage::delete_item(native_storage::STORE_TRASH, &entry.id).unwrap();
+    }
     fn make_session(id: &str, logs: Vec<ExerciseLog>) -> WorkoutSession {
         WorkoutSession {
             id: id.into(),
             start_time: 1_000,
             end_time: None,
             exercise_logs: logs,
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         }
     }
     fn make_exercise_log(exercise_id: &str, start: u64, end: Option<u64>) -> ExerciseLog {
         ExerciseLog {
             exercise_id: exercise_id.into(),
             exercise_name: exercise_id.into(),
-            category: Category::Strength,
             start_time: start,
             end_time: end,
-            weight_hg: Weight(0),
-            reps: None,
-            distance_m: None,
             force: Some(Force::Push),
+            ..Default::default()
         }
     }
     #[test]
@@ -2119,26 +1835,34 @@ mod tests {
             start_time: 100,
             end_time: None,
             exercise_logs: vec![],
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         };
         let done = WorkoutSession {
             id: id_done.into(),
             start_time: 200,
             end_time: Some(300),
             exercise_logs: vec![],
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         };
         native_storage::put_item(native_storage::STORE_SESSIONS, id_active, &active).unwrap();
         native_storage::put_item(native_storage::STORE_SESSIONS, id_done, &done).unwrap();
@@ -2166,8 +1890,7 @@ mod tests {
             end_time: Some(1_060), // duration 60s
             weight_hg: Weight(1_000),
             reps: Some(10),
-            distance_m: None,
-            force: None,
+            ..Default::default()
         };
         let log2 = ExerciseLog {
             exercise_id: "cb_ex1".into(),
@@ -2178,20 +1901,24 @@ mod tests {
             weight_hg: Weight(800), // lower than log1, should not win
             reps: Some(12),         // higher reps
             distance_m: Some(Distance(500)),
-            force: None,
+            ..Default::default()
         };
         let session = WorkoutSession {
             id: id.into(),
             start_time: 1_000,
             end_time: Some(3_000),
             exercise_logs: vec![log1, log2],
-            pending_exercise_ids: vec![],
+            pending_exercises: vec![],
             rest_start_time: None,
             current_exercise_id: None,
             current_exercise_start: None,
             paused_at: None,
             total_paused_duration: 0,
+            pauses: Vec::new(),
             notes: String::new(),
+            sealed: None,
+            archived: false,
+            location: None,
         };
         native_storage::put_item(native_storage::STORE_SESSIONS, id, &session).unwrap();
         let rows = native_storage::compute_bests_rows().expect("compute_bests_rows failed");
@@ -2204,4 +1931,70 @@ mod tests {
         assert_eq!(row.max_duration_s, Some(90), "max duration must be 90s");
         native_storage::delete_item(native_storage::STORE_SESSIONS, id).unwrap();
     }
+    #[test]
+    fn compute_bests_rows_ignores_assisted_weight() {
+        let _g = lock();
+        let mut loaded = make_exercise_log("cb_dip", 1_000, Some(1_060));
+        loaded.weight_hg = Weight(100);
+        let mut assisted = make_exercise_log("cb_dip", 2_000, Some(2_060));
+        assisted.weight_hg = Weight(300);
+        assisted.assisted = true;
+        let mut session = make_session("cb_assisted_s1", vec![loaded, assisted]);
+        session.end_time = Some(3_000);
+        native_storage::put_item(native_storage::STORE_SESSIONS, &session.id, &session).unwrap();
+        let rows = native_storage::compute_bests_rows().expect("compute_bests_rows failed");
+        let row = rows.iter().find(|r| r.exercise_id == "cb_dip").unwrap();
+        assert_eq!(row.max_weight_hg, Some(100), "assistance is not a record");
+        assert_eq!(row.last_weight_hg, Some(300));
+        assert!(row.last_assisted);
+        native_storage::delete_item(native_storage::STORE_SESSIONS, &session.id).unwrap();
+    }
+    #[test]
+    fn trashed_session_can_be_restored_or_purged() {
+        use crate::models::{TrashEntry, TrashedItem};
+        let _g = lock();
+        let session = make_session(
+            "trash_s1",
+            vec![make_exercise_log("squat", 1_000, Some(1_060))],
+        );
+        let entry_id = TrashEntry::key(&TrashedItem::Session(session.clone()));
+        let in_store = |store: &str, id: &str| {
+            native_storage::get_all::<serde_json::Value>(store)
+                .unwrap()
+                .iter()
+                .any(|v| v["id"] == id)
+        };
+        native_storage::put_item(native_storage::STORE_SESSIONS, &session.id, &session).unwrap();
+        native_storage::move_to_trash(native_storage::STORE_SESSIONS, &session.id, 5_000).unwrap();
+        assert!(!in_store(native_storage::STORE_SESSIONS, &session.id));
+        assert!(in_store(native_storage::STORE_TRASH, &entry_id));
+        let restored = native_storage::restore_from_trash(&entry_id).unwrap();
+        assert_eq!(restored, Some(TrashedItem::Session(session.clone())));
+        assert!(in_store(native_storage::STORE_SESSIONS, &session.id));
+        assert!(!in_store(native_storage::STORE_TRASH, &entry_id));
+        native_storage::move_to_trash(native_storage::STORE_SESSIONS, &session.id, 5_000).unwrap();
+        native_storage::purge_trash(5_000).unwrap();
+        assert!(
+            in_store(native_storage::STORE_TRASH, &entry_id),
+            "not expired yet"
+        );
+        native_storage::purge_trash(5_001).unwrap();
+        assert!(!in_store(native_storage::STORE_TRASH, &entry_id));
+    }
+    #[test]
+    fn sessions_without_logs_skip_the_trash() {
+        let _g = lock();
+        let session = make_session("trash_empty_s1", vec![]);
+        native_storage::put_item(native_storage::STORE_SESSIONS, &session.id, &session).unwrap();
+        native_storage::move_to_trash(native_storage::STORE_SESSIONS, &session.id, 5_000).unwrap();
+        let trash: Vec<crate::models::TrashEntry> =
+            native_storage::get_all(native_storage::STORE_TRASH).unwrap();
+        assert!(!trash.iter().any(|e| e.item.original_id() == session.id));
+        assert!(
+            native_storage::get_all::<WorkoutSession>(native_storage::STORE_SESSIONS)
+                .unwrap()
+                .iter()
+                .all(|s| s.id != session.id)
+        );
+    }
 }
@@ -0,0 +1,260 @@
+/// Storage usage report and cleanup tools.
+///
+/// [`measure`] reports the bytes each store consumes.  On the web the total
+/// and quota come from `navigator.storage.estimate()`; on native platforms the
+/// total is the size of the `SQLite` file plus the image directory.  Whatever
+/// the stores do not account for (indexes, caches, app files) is reported as
+/// [`Store::Other`].
+///
+/// Browsers may evict web storage under pressure unless the origin was
+/// granted persistence, which [`request_persistence`] asks for at startup.
+///
+/// [`clear_image_cache`] deletes the downloaded exercise database images
+/// (custom exercise images are kept) and [`compact`] reclaims the space of
+/// deleted `SQLite` rows.
+use crate::models::Exercise;
+#[cfg(target_arch = "wasm32")]
+use crate::services::storage::idb;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::services::storage::native_storage;
+use crate::services::storage::StorageError;
+use std::sync::Arc;
+/// Name of the service worker cache holding exercise images (see `sw.js`).
+#[cfg(target_arch = "wasm32")]
+const IMAGE_CACHE_NAME: &str = "workout-images-v1";
+const BYTES_PER_KIB: f64 = 1024.0;
+const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+/// A part of the app storage whose size is reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Store {
+    Sessions,
+    CustomExercises,
+    Exercises,
+    Trash,
+    Images,
+    /// Space not accounted for by the other stores.
+    Other,
+}
+/// Bytes consumed by each [`Store`], with the total and quota when known.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StorageUsage {
+    pub stores: Vec<(Store, u64)>,
+    pub total: Option<u64>,
+    pub quota: Option<u64>,
+}
+impl StorageUsage {
+    /// Usage of `stores` out of `total`, adding a [`Store::Other`] entry for
+    /// the remainder.
+    fn new(mut stores: Vec<(Store, u64)>, total: Option<u64>, quota: Option<u64>) -> Self {
+        if let Some(total) = total {
+            let accounted: u64 = stores.iter().map(|&(_, bytes)| bytes).sum();
+            stores.push((Store::Other, total.saturating_sub(accounted)));
+        }
+        Self {
+            stores,
+            total,
+            quota,
+        }
+    }
+}
+/// Human-readable size, e.g. `"512 B"` or `"1.5 MB"`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64;
+    if value < BYTES_PER_KIB {
+        return format!("{bytes} B");
+    }
+    let mut unit = UNITS[0];
+    for next in UNITS {
+        value /= BYTES_PER_KIB;
+        unit = next;
+        if value < BYTES_PER_KIB {
+            break;
+        }
+    }
+    format!("{value:.1} {unit}")
+}
+/// Whether an exercise image key points to an image downloaded from the
+/// exercise database, rather than a URL or a custom exercise image.
+#[cfg(not(target_arch = "wasm32"))]
+fn is_db_image_key(key: &str) -> bool {
+    !key.contains("://") && !key.starts_with("idb:") && !key.starts_with("local:")
+}
+/// Total size in bytes of the files under `dir`.
+#[cfg(not(target_arch = "wasm32"))]
+fn dir_bytes(dir: &std::path::Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            Some(if metadata.is_dir() {
+                dir_bytes(&entry.path())
+            } else {
+                metadata.len()
+            })
+        })
+        .sum()
+}
+/// Measure how much space each store consumes.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn measure() -> Result<StorageUsage, StorageError> {
+    tokio::task::spawn_blocking(|| {
+        let mut stores = Vec::new();
+        for (store, name) in [
+            (Store::Sessions, native_storage::STORE_SESSIONS),
+            (
+                Store::CustomExercises,
+                native_storage::STORE_CUSTOM_EXERCISES,
+            ),
+            (Store::Exercises, native_storage::STORE_EXERCISES),
+            (Store::Trash, native_storage::STORE_TRASH),
+        ] {
+            stores.push((store, native_storage::store_bytes(name)?));
+        }
+        let images = dir_bytes(&native_storage::images_dir());
+        stores.push((Store::Images, images));
+        let total = native_storage::db_file_bytes() + images;
+        Ok(StorageUsage::new(stores, Some(total), None))
+    })
+    .await
+    .map_err(|e| StorageError::TaskPanic(e.to_string()))?
+}
+/// Measure how much space each store consumes.
+#[cfg(target_arch = "wasm32")]
+pub async fn measure() -> Result<StorageUsage, StorageError> {
+    let mut stores = Vec::new();
+    for (store, name) in [
+        (Store::Sessions, idb::STORE_SESSIONS),
+        (Store::CustomExercises, idb::STORE_CUSTOM_EXERCISES),
+        (Store::Exercises, idb::STORE_EXERCISES),
+        (Store::Trash, idb::STORE_TRASH),
+        (Store::Images, idb::STORE_IMAGES),
+    ] {
+        stores.push((store, idb::store_bytes(name).await?));
+    }
+    let (total, quota) = estimate().await.unwrap_or((None, None));
+    Ok(StorageUsage::new(stores, total, quota))
+}
+/// Usage and quota of the origin from `navigator.storage.estimate()`.
+#[cfg(target_arch = "wasm32")]
+async fn estimate() -> Option<(Option<u64>, Option<u64>)> {
+    let storage = web_sys::window()?.navigator().storage();
+    let promise = storage.estimate().ok()?;
+    let estimate: web_sys::StorageEstimate = wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .ok()?
+        .into();
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let bytes = |v: Option<f64>| v.map(|v| v as u64);
+    Some((bytes(estimate.get_usage()), bytes(estimate.get_quota())))
+}
+/// Ask the browser to never evict the app storage, `true` once granted.
+#[cfg(target_arch = "wasm32")]
+pub async fn request_persistence() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    let storage = window.navigator().storage();
+    let granted = |promise: Result<js_sys::Promise, wasm_bindgen::JsValue>| async move {
+        match promise {
+            Ok(p) => wasm_bindgen_futures::JsFuture::from(p)
+                .await
+                .ok()
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            Err(_) => false,
+        }
+    };
+    if granted(storage.persisted()).await {
+        return true;
+    }
+    let persisted = granted(storage.persist()).await;
+    if !persisted {
+        log::warn!("Persistent storage not granted, the browser may evict the app data");
+    }
+    persisted
+}
+/// Native storage is never evicted by the system.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn request_persistence() -> bool {
+    true
+}
+/// Delete the cached exercise database images of `exercises`, returning how
+/// many files were removed.  They are downloaded again on the next database
+/// reload.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn clear_image_cache(exercises: &[Arc<Exercise>]) -> Result<usize, StorageError> {
+    let keys: std::collections::HashSet<String> = exercises
+        .iter()
+        .flat_map(|e| e.images.iter())
+        .filter(|key| is_db_image_key(key))
+        .cloned()
+        .collect();
+    tokio::task::spawn_blocking(move || {
+        let images_dir = native_storage::images_dir();
+        let mut removed = 0;
+        for key in keys {
+            let path = images_dir.join(&key);
+            match std::fs::remove_file(&path) {
+                Ok(()) => removed += 1,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(StorageError::Backend(e.to_string())),
+            }
+            // Drop the per-exercise directory once empty; fails otherwise.
+            if let Some(parent) = path.parent().filter(|p| *p != images_dir) {
+                let _ = std::fs::remove_dir(parent);
+            }
+        }
+        Ok(removed)
+    })
+    .await
+    .map_err(|e| StorageError::TaskPanic(e.to_string()))?
+}
+/// Delete the service worker cache of exercise images.  They are fetched
+/// again when next displayed.  Returns `0`: the cache does not tell how many
+/// images it held.
+#[cfg(target_arch = "wasm32")]
+pub async fn clear_image_cache(_exercises: &[Arc<Exercise>]) -> Result<usize, StorageError> {
+    let caches = web_sys::window()
+        .ok_or_else(|| StorageError::Backend("no window".into()))?
+        .caches()
+        .map_err(|e| StorageError::Backend(format!("{e:?}")))?;
+    wasm_bindgen_futures::JsFuture::from(caches.delete(IMAGE_CACHE_NAME))
+        .await
+        .map_err(|e| StorageError::Backend(format!("{e:?}")))?;
+    Ok(0)
+}
+/// Rebuild the `SQLite` database, reclaiming the space of deleted rows.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn compact() -> Result<(), StorageError> {
+    tokio::task::spawn_blocking(native_storage::vacuum)
+        .await
+        .map_err(|e| StorageError::TaskPanic(e.to_string()))?
+        .map_err(StorageError::from)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn format_bytes_picks_a_readable_unit() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1_536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+    #[test]
+    fn usage_reports_unaccounted_space_as_other() {
+        let usage = StorageUsage::new(
+            vec![(Store::Sessions, 300), (Store::Images, 200)],
+            Some(1_000),
+            None,
+        );
+        assert_eq!(usage.stores.last(), Some(&(Store::Other, 500)));
+        let unknown = StorageUsage::new(vec![(Store::Sessions, 300)], None, None);
+        assert_eq!(unknown.stores.len(), 1);
+    }
+}
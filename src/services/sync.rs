@@ -0,0 +1,648 @@
+/// End-to-end encrypted sync against a small self-hostable endpoint.
+///
+/// Every session and custom exercise is serialised, encrypted client-side with
+/// a key derived from the user's passphrase (see [`crate::services::crypto`])
+/// and exchanged as an opaque [`EncryptedRecord`].  The server only ever sees
+/// record IDs, kinds and vector clocks; it needs just two routes:
+///
+/// - `GET  {endpoint}records` → JSON array of [`EncryptedRecord`]
+/// - `PUT  {endpoint}records` ← JSON array of [`EncryptedRecord`] to upsert
+///
+/// `logout-cli sync-serve` runs such a server (see
+/// [`logout_core::sync_server`]).
+///
+/// Each device keeps a per-record vector clock in [`SyncState`].  A record is
+/// re-stamped (its own device counter incremented) whenever its plaintext
+/// changed since the last sync.  On pull, clocks are compared:
+/// - remote dominates → the remote version is applied locally;
+/// - local dominates → the local version is pushed;
+/// - concurrent → [`resolve_conflict`] picks a deterministic winner so all
+///   devices converge, and the merged clock is pushed.
+///
+/// A synced record missing from the local records was deleted or trashed on
+/// this device: it becomes a tombstone ([`SyncPayload::Deleted`]) with its
+/// own clock tick, pushed like any edit, and remote tombstones delete the
+/// local copy.  Tombstones are encrypted too, so the server cannot tell them
+/// from other records.
+use crate::models::{Exercise, WorkoutSession};
+use crate::services::crypto::{self, SecretKey};
+use serde::{Deserialize, Serialize};
+use sha2::Digest as _;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+/// Config key holding the sync endpoint base URL.
+pub const SYNC_ENDPOINT_KEY: &str = "sync_endpoint";
+/// Config key holding this device's random sync identifier.
+const SYNC_DEVICE_ID_KEY: &str = "sync_device_id";
+/// Config key holding the serialised [`SyncState`].
+const SYNC_STATE_KEY: &str = "sync_state";
+/// Domain separator mixed into the key-derivation salt.
+const SALT_PREFIX: &str = "logout-sync-v1:";
+/// A vector clock: per-device monotonically increasing counters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct VectorClock(pub BTreeMap<String, u64>);
+/// Causal relation between two [`VectorClock`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Causality {
+    /// Both clocks are identical.
+    Equal,
+    /// `self` happened before `other` (other dominates).
+    Before,
+    /// `self` happened after `other` (self dominates).
+    After,
+    /// Neither dominates: the record was edited independently on two devices.
+    Concurrent,
+}
+impl VectorClock {
+    /// Increment this device's counter.
+    pub fn tick(&mut self, device: &str) {
+        *self.0.entry(device.to_owned()).or_insert(0) += 1;
+    }
+    /// Element-wise maximum of both clocks.
+    #[must_use]
+    pub fn merged(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        for (device, &count) in &other.0 {
+            let entry = out.0.entry(device.clone()).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+        out
+    }
+    /// Compare two clocks causally.
+    #[must_use]
+    pub fn compare(&self, other: &Self) -> Causality {
+        let mut less = false;
+        let mut greater = false;
+        for device in self.0.keys().chain(other.0.keys()) {
+            let a = self.0.get(device).copied().unwrap_or(0);
+            let b = other.0.get(device).copied().unwrap_or(0);
+            match a.cmp(&b) {
+                Ordering::Less => less = true,
+                Ordering::Greater => greater = true,
+                Ordering::Equal => {}
+            }
+        }
+        match (less, greater) {
+            (false, false) => Causality::Equal,
+            (true, false) => Causality::Before,
+            (false, true) => Causality::After,
+            (true, true) => Causality::Concurrent,
+        }
+    }
+}
+/// Type of a synced record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordKind {
+    /// A [`WorkoutSession`].
+    Session,
+    /// A user-defined [`Exercise`].
+    CustomExercise,
+}
+/// Decrypted content of a synced record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum SyncPayload {
+    /// A workout session.
+    Session(WorkoutSession),
+    /// A custom exercise.
+    CustomExercise(Exercise),
+    /// The record `id` of `kind` was deleted.
+    Deleted { kind: RecordKind, id: String },
+}
+impl SyncPayload {
+    /// Record kind of this payload.
+    #[must_use]
+    pub fn kind(&self) -> RecordKind {
+        match self {
+            Self::Session(_) => RecordKind::Session,
+            Self::CustomExercise(_) => RecordKind::CustomExercise,
+            Self::Deleted { kind, .. } => *kind,
+        }
+    }
+    /// Record ID of this payload.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Session(s) => &s.id,
+            Self::CustomExercise(e) => &e.id,
+            Self::Deleted { id, .. } => id,
+        }
+    }
+    /// Whether this is a tombstone.
+    #[must_use]
+    pub fn is_deleted(&self) -> bool {
+        matches!(self, Self::Deleted { .. })
+    }
+    fn key(&self) -> String {
+        record_key(self.kind(), self.id())
+    }
+}
+/// Wire format exchanged with the sync server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncryptedRecord {
+    /// Record ID (session / exercise ID).
+    pub id: String,
+    /// Record kind.
+    pub kind: RecordKind,
+    /// Vector clock of this version.
+    pub clock: VectorClock,
+    /// Base64 of `nonce || ciphertext` of the JSON-serialised [`SyncPayload`].
+    pub data: String,
+}
+/// Per-record bookkeeping persisted on each device between syncs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecordState {
+    /// Clock of the last synced version.
+    pub clock: VectorClock,
+    /// SHA-256 digest (base64) of the plaintext at the last sync.
+    pub digest: String,
+    /// Whether the last synced version is a tombstone.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deleted: bool,
+}
+/// Sync bookkeeping for every known record, keyed by `kind:id`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyncState(pub HashMap<String, RecordState>);
+/// Errors reported by [`sync_now`].
+#[derive(Debug, thiserror::Error)]
+pub enum SyncError {
+    /// No endpoint configured.
+    #[error("no sync endpoint configured")]
+    NoEndpoint,
+    /// HTTP / transport failure.
+    #[error("network error: {0}")]
+    Network(String),
+    /// Encryption or decryption failure (usually a wrong passphrase).
+    #[error(transparent)]
+    Crypto(#[from] crypto::CryptoError),
+    /// Malformed JSON from the server or in a decrypted record.
+    #[error("invalid data: {0}")]
+    Format(String),
+}
+/// Result of a sync round.
+#[derive(Debug, Default)]
+pub struct SyncOutcome {
+    /// Remote versions that must be written to local storage.
+    pub to_apply: Vec<SyncPayload>,
+    /// Number of records pushed to the server.
+    pub pushed: usize,
+}
+fn record_key(kind: RecordKind, id: &str) -> String {
+    match kind {
+        RecordKind::Session => format!("session:{id}"),
+        RecordKind::CustomExercise => format!("exercise:{id}"),
+    }
+}
+/// Kind and ID of the record stored under `key`.
+fn parse_record_key(key: &str) -> Option<(RecordKind, &str)> {
+    match key.split_once(':')? {
+        ("session", id) => Some((RecordKind::Session, id)),
+        ("exercise", id) => Some((RecordKind::CustomExercise, id)),
+        _ => None,
+    }
+}
+/// Bookkeeping of the synced version `payload` with `clock`.
+fn record_state(payload: &SyncPayload, clock: VectorClock) -> RecordState {
+    let bytes = serde_json::to_vec(payload).unwrap_or_default();
+    RecordState {
+        clock,
+        digest: digest(&bytes),
+        deleted: payload.is_deleted(),
+    }
+}
+fn digest(bytes: &[u8]) -> String {
+    crypto::to_base64(&sha2::Sha256::digest(bytes))
+}
+/// Derive the sync key; the salt is bound to the endpoint so every device
+/// syncing against the same server derives the same key.
+#[must_use]
+pub fn derive_sync_key(passphrase: &str, endpoint: &str) -> SecretKey {
+    let salt = format!("{SALT_PREFIX}{}", endpoint.trim_end_matches('/'));
+    crypto::derive_key(passphrase, salt.as_bytes())
+}
+/// Pick the winner between two concurrently edited versions; returns `true`
+/// when `remote` wins.
+///
+/// An edit wins over a concurrent deletion, so no change is lost.  Sessions
+/// prefer the version with more logged exercises, then the later end time;
+/// otherwise the lexicographically larger plaintext digest wins, so every
+/// device reaches the same decision independently.
+#[must_use]
+pub fn resolve_conflict(local: &SyncPayload, remote: &SyncPayload) -> bool {
+    if local.is_deleted() != remote.is_deleted() {
+        return local.is_deleted();
+    }
+    if let (SyncPayload::Session(l), SyncPayload::Session(r)) = (local, remote) {
+        let by_logs = r.exercise_logs.len().cmp(&l.exercise_logs.len());
+        if by_logs != Ordering::Equal {
+            return by_logs == Ordering::Greater;
+        }
+        let by_end = r.end_time.cmp(&l.end_time);
+        if by_end != Ordering::Equal {
+            return by_end == Ordering::Greater;
+        }
+    }
+    let l = serde_json::to_vec(local).unwrap_or_default();
+    let r = serde_json::to_vec(remote).unwrap_or_default();
+    digest(&r) > digest(&l)
+}
+/// Core merge step, independent of networking and encryption.
+///
+/// `local` are the current local records, `remote` the decrypted server
+/// records with their clocks.  Updates `state` in place and returns the
+/// payloads to apply locally (tombstones meaning deletions) plus the
+/// `(payload, clock)` pairs to push.
+pub fn merge(
+    device: &str,
+    state: &mut SyncState,
+    local: Vec<SyncPayload>,
+    remote: Vec<(SyncPayload, VectorClock)>,
+) -> (Vec<SyncPayload>, Vec<(SyncPayload, VectorClock)>) {
+    // Stamp locally changed records.
+    let mut local_map: HashMap<String, (SyncPayload, VectorClock)> = HashMap::new();
+    for payload in local {
+        let key = payload.key();
+        let bytes = serde_json::to_vec(&payload).unwrap_or_default();
+        let dig = digest(&bytes);
+        let entry = state.0.entry(key.clone()).or_default();
+        if entry.digest != dig {
+            entry.clock.tick(device);
+            entry.digest = dig;
+            entry.deleted = false;
+        }
+        local_map.insert(key, (payload, entry.clock.clone()));
+    }
+    // Synced records now missing locally were deleted here: stamp their
+    // tombstones.
+    for (key, entry) in &mut state.0 {
+        if local_map.contains_key(key) {
+            continue;
+        }
+        let Some((kind, id)) = parse_record_key(key) else {
+            continue;
+        };
+        let tombstone = SyncPayload::Deleted {
+            kind,
+            id: id.to_owned(),
+        };
+        if !entry.deleted {
+            entry.clock.tick(device);
+            *entry = record_state(&tombstone, entry.clock.clone());
+        }
+        local_map.insert(key.clone(), (tombstone, entry.clock.clone()));
+    }
+    let mut to_apply = Vec::new();
+    let mut to_push = Vec::new();
+    let mut remote_keys = std::collections::HashSet::new();
+    for (remote_payload, remote_clock) in remote {
+        let key = remote_payload.key();
+        remote_keys.insert(key.clone());
+        let Some((local_payload, local_clock)) = local_map.remove(&key) else {
+            // Unknown locally: adopt it, unless there is nothing to delete.
+            state
+                .0
+                .insert(key, record_state(&remote_payload, remote_clock));
+            if !remote_payload.is_deleted() {
+                to_apply.push(remote_payload);
+            }
+            continue;
+        };
+        match local_clock.compare(&remote_clock) {
+            Causality::Equal => {}
+            Causality::After => to_push.push((local_payload, local_clock)),
+            Causality::Before => {
+                state
+                    .0
+                    .insert(key, record_state(&remote_payload, remote_clock));
+                if !(local_payload.is_deleted() && remote_payload.is_deleted()) {
+                    to_apply.push(remote_payload);
+                }
+            }
+            Causality::Concurrent => {
+                let mut clock = local_clock.merged(&remote_clock);
+                clock.tick(device);
+                let winner = if resolve_conflict(&local_payload, &remote_payload) {
+                    if !local_payload.is_deleted() || !remote_payload.is_deleted() {
+                        to_apply.push(remote_payload.clone());
+                    }
+                    remote_payload
+                } else {
+                    local_payload
+                };
+                state.0.insert(key, record_state(&winner, clock.clone()));
+                to_push.push((winner, clock));
+            }
+        }
+    }
+    // Records the server has never seen.
+    to_push.extend(
+        local_map
+            .into_iter()
+            .filter(|(key, _)| !remote_keys.contains(key))
+            .map(|(_, v)| v),
+    );
+    (to_apply, to_push)
+}
+/// This device's sync identifier, generated on first use.
+fn device_id() -> String {
+    if let Some(id) = crate::utils::get_config(SYNC_DEVICE_ID_KEY) {
+        return id;
+    }
+    let id = crypto::random_bytes(8).map_or_else(
+        |_| format!("device_{}", crate::models::get_current_timestamp()),
+        |b| b.iter().map(|x| format!("{x:02x}")).collect(),
+    );
+    crate::utils::set_config(SYNC_DEVICE_ID_KEY, &id);
+    id
+}
+fn load_state() -> SyncState {
+    crate::utils::get_config(SYNC_STATE_KEY)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+fn save_state(state: &SyncState) {
+    if let Ok(json) = serde_json::to_string(state) {
+        crate::utils::set_config(SYNC_STATE_KEY, &json);
+    }
+}
+/// Returns the configured sync endpoint, if any.
+#[must_use]
+pub fn configured_endpoint() -> Option<String> {
+    crate::utils::get_config(SYNC_ENDPOINT_KEY)
+}
+/// Run one full sync round: pull, merge, push.
+///
+/// The returned [`SyncOutcome::to_apply`] must be written to local storage by
+/// the caller (so this module stays free of Dioxus context).
+///
+/// # Errors
+///
+/// Fails on network errors, a wrong passphrase or malformed server data; the
+/// local sync state is only persisted after a successful push.
+pub async fn sync_now(passphrase: &str, local: Vec<SyncPayload>) -> Result<SyncOutcome, SyncError> {
+    let endpoint = configured_endpoint().ok_or(SyncError::NoEndpoint)?;
+    let endpoint = crate::utils::normalize_db_url(&endpoint);
+    let key = derive_sync_key(passphrase, &endpoint);
+    sync_with_key(&endpoint, &key, local).await
+}
+/// [`sync_now`] against `endpoint`, with the already derived `key`.
+async fn sync_with_key(
+    endpoint: &str,
+    key: &SecretKey,
+    local: Vec<SyncPayload>,
+) -> Result<SyncOutcome, SyncError> {
+    let url = format!("{endpoint}records");
+    let client = reqwest::Client::new();
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| SyncError::Network(e.to_string()))?;
+    if !response.status().is_success() {
+        return Err(SyncError::Network(format!("HTTP {}", response.status())));
+    }
+    let encrypted: Vec<EncryptedRecord> = response
+        .json()
+        .await
+        .map_err(|e| SyncError::Format(e.to_string()))?;
+    let mut remote = Vec::with_capacity(encrypted.len());
+    for record in encrypted {
+        let sealed = crypto::from_base64(&record.data)
+            .ok_or_else(|| SyncError::Format(format!("bad base64 in {}", record.id)))?;
+        let plain = crypto::decrypt(key, &sealed)?;
+        let payload: SyncPayload =
+            serde_json::from_slice(&plain).map_err(|e| SyncError::Format(e.to_string()))?;
+        remote.push((payload, record.clock));
+    }
+    let device = device_id();
+    let mut state = load_state();
+    let (to_apply, to_push) = merge(&device, &mut state, local, remote);
+    let mut outgoing = Vec::with_capacity(to_push.len());
+    for (payload, clock) in to_push {
+        let plain = serde_json::to_vec(&payload).map_err(|e| SyncError::Format(e.to_string()))?;
+        outgoing.push(EncryptedRecord {
+            id: payload.id().to_owned(),
+            kind: payload.kind(),
+            clock,
+            data: crypto::to_base64(&crypto::encrypt(key, &plain)?),
+        });
+    }
+    if !outgoing.is_empty() {
+        let response = client
+            .put(&url)
+            .json(&outgoing)
+            .send()
+            .await
+            .map_err(|e| SyncError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(SyncError::Network(format!("HTTP {}", response.status())));
+        }
+    }
+    save_state(&state);
+    Ok(SyncOutcome {
+        to_apply,
+        pushed: outgoing.len(),
+    })
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn clock(pairs: &[(&str, u64)]) -> VectorClock {
+        VectorClock(pairs.iter().map(|(d, c)| ((*d).to_owned(), *c)).collect())
+    }
+    fn session(id: &str, logs: usize) -> SyncPayload {
+        let mut s = WorkoutSession::new();
+        s.id = id.into();
+        s.start_time = 1000;
+        s.end_time = Some(2000);
+        for _ in 0..logs {
+            s.exercise_logs.push(crate::models::ExerciseLog {
+                exercise_id: "squat".into(),
+                exercise_name: "Squat".into(),
+                category: crate::models::Category::Strength,
+                start_time: 1000,
+                end_time: Some(1100),
+                weight_hg: crate::models::Weight(0),
+                reps: Some(5),
+                ..Default::default()
+            });
+        }
+        SyncPayload::Session(s)
+    }
+    #[test]
+    fn clock_comparison() {
+        let a = clock(&[("a", 1)]);
+        let b = clock(&[("a", 1), ("b", 1)]);
+        assert_eq!(a.compare(&a), Causality::Equal);
+        assert_eq!(a.compare(&b), Causality::Before);
+        assert_eq!(b.compare(&a), Causality::After);
+        assert_eq!(
+            clock(&[("a", 2)]).compare(&clock(&[("b", 1)])),
+            Causality::Concurrent
+        );
+        assert_eq!(a.merged(&clock(&[("b", 3)])), clock(&[("a", 1), ("b", 3)]));
+    }
+    #[test]
+    fn new_local_records_are_pushed_with_ticked_clock() {
+        let mut state = SyncState::default();
+        let (apply, push) = merge("dev1", &mut state, vec![session("s1", 1)], vec![]);
+        assert!(apply.is_empty());
+        assert_eq!(push.len(), 1);
+        assert_eq!(push[0].1, clock(&[("dev1", 1)]));
+        // Unchanged on the next round → not re-stamped.
+        let (_, push) = merge(
+            "dev1",
+            &mut state,
+            vec![session("s1", 1)],
+            vec![(session("s1", 1), clock(&[("dev1", 1)]))],
+        );
+        assert!(push.is_empty());
+    }
+    #[test]
+    fn dominating_remote_is_applied() {
+        let mut state = SyncState::default();
+        merge("dev1", &mut state, vec![session("s1", 1)], vec![]);
+        let remote = vec![(session("s1", 2), clock(&[("dev1", 1), ("dev2", 1)]))];
+        let (apply, push) = merge("dev1", &mut state, vec![session("s1", 1)], remote);
+        assert_eq!(apply, vec![session("s1", 2)]);
+        assert!(push.is_empty());
+    }
+    #[test]
+    fn unknown_remote_is_adopted() {
+        let mut state = SyncState::default();
+        let remote = vec![(session("s9", 1), clock(&[("dev2", 4)]))];
+        let (apply, push) = merge("dev1", &mut state, vec![], remote);
+        assert_eq!(apply.len(), 1);
+        assert!(push.is_empty());
+        assert_eq!(state.0["session:s9"].clock, clock(&[("dev2", 4)]));
+    }
+    #[test]
+    fn concurrent_edits_converge() {
+        let mut state = SyncState::default();
+        merge("dev1", &mut state, vec![session("s1", 1)], vec![]);
+        // Local edit (dev1 → 2) concurrent with a remote edit by dev2.
+        let remote = vec![(session("s1", 3), clock(&[("dev1", 1), ("dev2", 1)]))];
+        let (apply, push) = merge("dev1", &mut state, vec![session("s1", 2)], remote);
+        // Remote has more logs → remote wins and is applied locally.
+        assert_eq!(apply, vec![session("s1", 3)]);
+        assert_eq!(push.len(), 1);
+        assert_eq!(
+            push[0].1.compare(&clock(&[("dev1", 2), ("dev2", 1)])),
+            Causality::After
+        );
+    }
+    /// One sync round of `device` against `server`, upserting what it pushes.
+    fn round(
+        device: &str,
+        state: &mut SyncState,
+        local: Vec<SyncPayload>,
+        server: &mut Vec<(SyncPayload, VectorClock)>,
+    ) -> Vec<SyncPayload> {
+        let (apply, push) = merge(device, state, local, server.clone());
+        for (payload, clock) in push {
+            server.retain(|(p, _)| p.key() != payload.key());
+            server.push((payload, clock));
+        }
+        apply
+    }
+    #[test]
+    fn deletions_stay_deleted_after_a_round_trip() {
+        let mut server = Vec::new();
+        let (mut dev1, mut dev2) = (SyncState::default(), SyncState::default());
+        round("dev1", &mut dev1, vec![session("s1", 1)], &mut server);
+        assert_eq!(
+            round("dev2", &mut dev2, vec![], &mut server),
+            vec![session("s1", 1)]
+        );
+        // Deleted on dev1: its tombstone reaches dev2, which deletes it too.
+        assert!(round("dev1", &mut dev1, vec![], &mut server).is_empty());
+        let deleted = SyncPayload::Deleted {
+            kind: RecordKind::Session,
+            id: "s1".into(),
+        };
+        assert_eq!(server, vec![(deleted.clone(), clock(&[("dev1", 2)]))]);
+        assert_eq!(
+            round("dev2", &mut dev2, vec![session("s1", 1)], &mut server),
+            vec![deleted]
+        );
+        // Neither device gets it back afterwards.
+        assert!(round("dev2", &mut dev2, vec![], &mut server).is_empty());
+        assert!(round("dev1", &mut dev1, vec![], &mut server).is_empty());
+        assert!(dev1.0["session:s1"].deleted && dev2.0["session:s1"].deleted);
+        // A new device does not even see it.
+        assert!(round("dev3", &mut SyncState::default(), vec![], &mut server).is_empty());
+    }
+    #[test]
+    fn an_edit_beats_a_concurrent_deletion() {
+        let mut server = Vec::new();
+        let (mut dev1, mut dev2) = (SyncState::default(), SyncState::default());
+        round("dev1", &mut dev1, vec![session("s1", 1)], &mut server);
+        round("dev2", &mut dev2, vec![], &mut server);
+        round("dev1", &mut dev1, vec![], &mut server);
+        // dev2 edited it before seeing the deletion: the edit is restored.
+        let apply = round("dev2", &mut dev2, vec![session("s1", 2)], &mut server);
+        assert!(apply.is_empty());
+        assert_eq!(
+            round("dev1", &mut dev1, vec![], &mut server),
+            vec![session("s1", 2)]
+        );
+    }
+    #[test]
+    fn payload_round_trips_through_encryption() {
+        let key = crypto::derive_key_with_iterations("pw", b"salt", 1_000);
+        let payload = session("s1", 1);
+        let sealed = crypto::encrypt(&key, &serde_json::to_vec(&payload).unwrap()).unwrap();
+        let back: SyncPayload =
+            serde_json::from_slice(&crypto::decrypt(&key, &sealed).unwrap()).unwrap();
+        assert_eq!(back, payload);
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn devices_sync_through_the_bundled_server() {
+        use crate::services::storage::native_storage;
+        use logout_core::sync_server::{self, RecordStore};
+        let _lock = native_storage::test_lock();
+        let forget_device = || {
+            crate::utils::set_config(SYNC_DEVICE_ID_KEY, "");
+            crate::utils::set_config(SYNC_STATE_KEY, "");
+        };
+        forget_device();
+        let store_path = native_storage::data_dir().join("sync-test-records.json");
+        let _ = std::fs::remove_file(&store_path);
+        let mut store = RecordStore::open(&store_path).unwrap();
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/", listener.local_addr().unwrap());
+        std::thread::spawn(move || sync_server::serve(&listener, &mut store));
+        let key = crypto::derive_key_with_iterations("pw", b"salt", 1_000);
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let sync = |local| rt.block_on(sync_with_key(&endpoint, &key, local));
+        // A first device pushes its session…
+        let outcome = sync(vec![session("s1", 1)]).unwrap();
+        assert_eq!(outcome.pushed, 1);
+        assert!(outcome.to_apply.is_empty());
+        // …that a second one, knowing nothing yet, receives.
+        forget_device();
+        let outcome = sync(vec![]).unwrap();
+        assert_eq!(outcome.to_apply, vec![session("s1", 1)]);
+        assert_eq!(outcome.pushed, 0);
+        // Its edit then reaches the server, and is not pushed twice.
+        let outcome = sync(vec![session("s1", 2)]).unwrap();
+        assert_eq!(outcome.pushed, 1);
+        let outcome = sync(vec![session("s1", 2)]).unwrap();
+        assert_eq!(outcome.pushed, 0);
+        assert!(outcome.to_apply.is_empty());
+        // The server only holds what the passphrase opens.
+        let other = crypto::derive_key_with_iterations("other", b"salt", 1_000);
+        assert!(matches!(
+            rt.block_on(sync_with_key(&endpoint, &other, vec![])),
+            Err(SyncError::Crypto(_))
+        ));
+        forget_device();
+        let _ = std::fs::remove_file(&store_path);
+    }
+}
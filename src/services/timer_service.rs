@@ -0,0 +1,169 @@
+/// Persistent "workout in progress" notification backed by an Android
+/// foreground service.
+///
+/// Android may stop a backgrounded app, which used to freeze the rest and
+/// exercise timers.  While a session is active, `MainActivity` starts
+/// `WorkoutTimerService` in the foreground with an ongoing notification
+/// whose chronometer is driven by the OS, so it keeps counting with the
+/// screen off without the app waking up every second.  The notification
+/// only needs updating when the timer *kind* changes (rest started, new
+/// exercise, …) — see [`notice_for`].
+///
+/// On every other platform the functions are no-ops.
+use crate::models::WorkoutSession;
+/// What the ongoing notification is timing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerKind {
+    /// Counting down the rest period.
+    Rest,
+    /// Counting up the current exercise.
+    Exercise,
+    /// Counting up the whole session (no exercise or rest running).
+    Session,
+}
+/// State of the ongoing notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerNotice {
+    /// What is being timed (selects the notification title).
+    pub kind: TimerKind,
+    /// Unix timestamp (seconds) the chronometer counts from, or to.
+    pub when: u64,
+    /// `true` to count down to `when`, `false` to count up from it.
+    pub count_down: bool,
+}
+/// Notification to show for `session`, or `None` to hide it (no active
+/// session, or paused: the chronometer cannot be frozen).
+#[must_use]
+pub fn notice_for(session: &WorkoutSession, rest_duration: u64) -> Option<TimerNotice> {
+    if !session.is_active() || session.is_paused() {
+        return None;
+    }
+    if let (Some(start), true) = (session.rest_start_time, rest_duration > 0) {
+        return Some(TimerNotice {
+            kind: TimerKind::Rest,
+            when: start + rest_duration,
+            count_down: true,
+        });
+    }
+    if let Some(start) = session.current_exercise_start {
+        return Some(TimerNotice {
+            kind: TimerKind::Exercise,
+            when: start,
+            count_down: false,
+        });
+    }
+    Some(TimerNotice {
+        kind: TimerKind::Session,
+        // Shift the origin by the paused time so the chronometer matches
+        // `WorkoutSession::duration_seconds`.
+        when: session.start_time + session.total_paused_duration,
+        count_down: false,
+    })
+}
+/// Show (or update) the ongoing timer notification.
+pub fn show(title: &str, notice: TimerNotice) {
+    #[cfg(target_os = "android")]
+    if let Err(e) = android::show_timer_notification(title, notice) {
+        log::warn!("Failed to show timer notification: {e}");
+    }
+    #[cfg(not(target_os = "android"))]
+    let _ = (title, notice);
+}
+/// Hide the ongoing timer notification and stop the foreground service.
+pub fn hide() {
+    #[cfg(target_os = "android")]
+    if let Err(e) = android::cancel_timer_notification() {
+        log::warn!("Failed to hide timer notification: {e}");
+    }
+}
+#[cfg(target_os = "android")]
+mod android {
+    use super::TimerNotice;
+    use jni::{objects::JObject, objects::JValue, JavaVM};
+    /// Call `MainActivity.showTimerNotification(title, whenMillis, countDown)`.
+    pub(super) fn show_timer_notification(title: &str, notice: TimerNotice) -> Result<(), String> {
+        let ctx = ndk_context::android_context();
+        // SAFETY: raw pointers come from the Android runtime; valid for process lifetime.
+        let vm = unsafe { JavaVM::from_raw(ctx.vm().cast()) }
+            .map_err(|e| format!("JavaVM::from_raw: {e}"))?;
+        let mut env = vm
+            .attach_current_thread()
+            .map_err(|e| format!("attach_current_thread: {e}"))?;
+        let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
+        let jtitle = env
+            .new_string(title)
+            .map_err(|e| format!("new_string title: {e}"))?;
+        let when_ms = i64::try_from(notice.when.saturating_mul(1_000)).unwrap_or(i64::MAX);
+        env.call_method(
+            &activity,
+            "showTimerNotification",
+            "(Ljava/lang/String;JZ)V",
+            &[
+                (&jtitle).into(),
+                JValue::Long(when_ms),
+                JValue::Bool(u8::from(notice.count_down)),
+            ],
+        )
+        .map_err(|e| format!("showTimerNotification: {e}"))?;
+        Ok(())
+    }
+    /// Call `MainActivity.cancelTimerNotification()`.
+    pub(super) fn cancel_timer_notification() -> Result<(), String> {
+        let ctx = ndk_context::android_context();
+        // SAFETY: raw pointers come from the Android runtime; valid for process lifetime.
+        let vm = unsafe { JavaVM::from_raw(ctx.vm().cast()) }
+            .map_err(|e| format!("JavaVM::from_raw: {e}"))?;
+        let mut env = vm
+            .attach_current_thread()
+            .map_err(|e| format!("attach_current_thread: {e}"))?;
+        let activity = unsafe { JObject::from_raw(ctx.context() as jni::sys::jobject) };
+        env.call_method(&activity, "cancelTimerNotification", "()V", &[])
+            .map_err(|e| format!("cancelTimerNotification: {e}"))?;
+        Ok(())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn active_session() -> WorkoutSession {
+        let mut s = WorkoutSession::new();
+        s.start_time = 1_000;
+        s
+    }
+    #[test]
+    fn rest_counts_down_to_its_end() {
+        let mut s = active_session();
+        s.rest_start_time = Some(2_000);
+        s.current_exercise_start = Some(1_500);
+        let notice = notice_for(&s, 90).unwrap();
+        assert_eq!(notice.kind, TimerKind::Rest);
+        assert_eq!(notice.when, 2_090);
+        assert!(notice.count_down);
+    }
+    #[test]
+    fn exercise_counts_up_from_its_start() {
+        let mut s = active_session();
+        s.current_exercise_start = Some(1_500);
+        let notice = notice_for(&s, 90).unwrap();
+        assert_eq!(notice.kind, TimerKind::Exercise);
+        assert_eq!(notice.when, 1_500);
+        assert!(!notice.count_down);
+    }
+    #[test]
+    fn session_timer_skips_paused_time() {
+        let mut s = active_session();
+        s.total_paused_duration = 60;
+        let notice = notice_for(&s, 0).unwrap();
+        assert_eq!(notice.kind, TimerKind::Session);
+        assert_eq!(notice.when, 1_060);
+    }
+    #[test]
+    fn hidden_when_paused_or_finished() {
+        let mut s = active_session();
+        s.paused_at = Some(1_200);
+        assert!(notice_for(&s, 30).is_none());
+        let mut done = active_session();
+        done.end_time = Some(2_000);
+        assert!(notice_for(&done, 30).is_none());
+    }
+}
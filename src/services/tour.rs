@@ -0,0 +1,136 @@
+/// Guided tour of the session view: contextual tips shown one at a time
+/// next to what they explain, the first times the session view opens.
+///
+/// Each tip is a [`Hint`] placed by a [`crate::components::active_session::TourTip`]
+/// next to its target; only mounted targets take part, so a tip waits until
+/// what it explains is on screen (e.g. an exercise in progress for
+/// [`Hint::CompleteCancel`]).  Dismissed hints are kept in the "seen hints"
+/// set of the settings, and can be reset from the More page.
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+/// Config key storing the hints already dismissed.
+const SEEN_HINTS_KEY: &str = "seen_hints";
+/// A tip of the session view tour, in the order shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    /// Tapping the timers configures the rest duration.
+    Timer,
+    /// The queue of exercises pending in the session.
+    PendingQueue,
+    /// The complete and cancel buttons of the exercise in progress.
+    CompleteCancel,
+}
+impl Hint {
+    /// Every hint, in tour order.
+    pub const ALL: [Self; 3] = [Self::Timer, Self::PendingQueue, Self::CompleteCancel];
+}
+/// The hints dismissed, from the `stored` config value; unknown ones, from a
+/// later version, are ignored.
+fn seen_of(stored: Option<String>) -> BTreeSet<Hint> {
+    let names: Vec<serde_json::Value> = stored
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    names
+        .into_iter()
+        .filter_map(|name| serde_json::from_value(name).ok())
+        .collect()
+}
+/// The hint to show: the first not dismissed of those whose target is
+/// `mounted`, while the session view is `in_view`.
+fn current(seen: &BTreeSet<Hint>, mounted: &BTreeSet<Hint>, in_view: bool) -> Option<Hint> {
+    if !in_view {
+        return None;
+    }
+    Hint::ALL
+        .into_iter()
+        .find(|h| !seen.contains(h) && mounted.contains(h))
+}
+/// State of the tour, see [`use_tour`].
+#[derive(Clone, Copy)]
+struct TourSignal {
+    /// Hints dismissed, persisted.
+    seen: Signal<BTreeSet<Hint>>,
+    /// Hints whose target is on screen.
+    mounted: Signal<BTreeSet<Hint>>,
+    /// Whether the session view is open.
+    in_view: Signal<bool>,
+}
+/// Provide the tour state.
+///
+/// Call once in the layout, above both the session header and the session
+/// view.
+pub fn use_tour() {
+    use_context_provider(|| TourSignal {
+        seen: Signal::new(seen_of(crate::utils::get_config(SEEN_HINTS_KEY))),
+        mounted: Signal::new(BTreeSet::new()),
+        in_view: Signal::new(false),
+    });
+}
+/// Run the tour while the calling component, the session view, is mounted.
+pub fn use_tour_view() {
+    let mut in_view = use_context::<TourSignal>().in_view;
+    use_effect(move || in_view.set(true));
+    use_drop(move || in_view.set(false));
+}
+/// Register the target of `hint` while the calling component is mounted, and
+/// return whether its tip is the one to show.
+pub fn use_hint(hint: Hint) -> Memo<bool> {
+    let tour = use_context::<TourSignal>();
+    let mut mounted = tour.mounted;
+    use_effect(move || {
+        mounted.write().insert(hint);
+    });
+    use_drop(move || {
+        mounted.write().remove(&hint);
+    });
+    use_memo(move || {
+        current(
+            &tour.seen.read(),
+            &tour.mounted.read(),
+            *tour.in_view.read(),
+        ) == Some(hint)
+    })
+}
+/// Replace the dismissed hints and persist them.
+fn save_seen(seen: BTreeSet<Hint>) {
+    let mut signal = consume_context::<TourSignal>().seen;
+    let value = if seen.is_empty() {
+        String::new()
+    } else {
+        serde_json::to_string(&seen).unwrap_or_default()
+    };
+    crate::utils::set_config(SEEN_HINTS_KEY, &value);
+    signal.set(seen);
+}
+/// Dismiss `hint`, moving on to the next tip.
+pub fn dismiss(hint: Hint) {
+    let mut seen = consume_context::<TourSignal>().seen.peek().clone();
+    seen.insert(hint);
+    save_seen(seen);
+}
+/// Dismiss every hint, ending the tour.
+pub fn skip_tour() {
+    save_seen(Hint::ALL.into_iter().collect());
+}
+/// Forget the dismissed hints, so the tour runs again.
+pub fn restart_tour() {
+    save_seen(BTreeSet::new());
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn tour_shows_the_first_unseen_hint_on_screen() {
+        let seen = seen_of(Some(r#"["timer","a_later_hint"]"#.into()));
+        assert_eq!(seen, BTreeSet::from([Hint::Timer]));
+        let mounted = BTreeSet::from([Hint::Timer, Hint::CompleteCancel]);
+        assert_eq!(current(&seen, &mounted, true), Some(Hint::CompleteCancel));
+        assert_eq!(current(&seen, &mounted, false), None);
+        assert_eq!(current(&BTreeSet::new(), &mounted, true), Some(Hint::Timer));
+        let all = Hint::ALL.into_iter().collect();
+        assert_eq!(current(&all, &mounted, true), None);
+        assert!(seen_of(None).is_empty());
+    }
+}
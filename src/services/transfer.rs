@@ -0,0 +1,422 @@
+/// Peer-to-peer "transfer to new device" over WebRTC, paired by QR codes.
+///
+/// No server is involved: the old device creates a WebRTC offer (host
+/// candidates only, i.e. the local network) and shows it together with a
+/// fresh random encryption key as a QR code.  The new device scans it,
+/// answers with its own QR code, and once the data channel opens the old
+/// device streams an encrypted [`TransferBundle`].
+///
+/// WebRTC is driven through `document::eval`, which runs inside the browser
+/// on the web and inside the system `WebView` on desktop / Android, so one
+/// code path serves every platform.
+use crate::models::{Exercise, WorkoutSession};
+use crate::services::crypto::{self, SecretKey, KEY_LEN};
+use dioxus::prelude::document;
+use serde::{Deserialize, Serialize};
+/// Prefix of a pairing offer code (old device → new device).
+const OFFER_PREFIX: &str = "logout-offer:";
+/// Prefix of a pairing answer code (new device → old device).
+const ANSWER_PREFIX: &str = "logout-answer:";
+/// Size of a data channel message, in bytes.
+const CHUNK_BYTES: usize = 16 * 1024;
+/// Amount of queued data below which the sender resumes sending.
+const BUFFERED_LOW_BYTES: usize = 256 * 1024;
+/// Amount of queued data above which the sender waits for it to drain.
+const BUFFERED_HIGH_BYTES: usize = 1024 * 1024;
+/// Time without progress after which a transfer is abandoned.
+const IDLE_TIMEOUT_MS: u32 = 30_000;
+/// Time the receiver waits for the sender to connect, long enough to scan
+/// the answer code.
+const CONNECT_TIMEOUT_MS: u32 = 120_000;
+/// Error reported by the JavaScript snippets when a timeout elapsed.
+const TIMEOUT_MARKER: &str = "__timeout__";
+/// Everything moved to the new device.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransferBundle {
+    /// All sessions (active and completed).
+    pub sessions: Vec<WorkoutSession>,
+    /// All user-defined exercises.
+    pub custom_exercises: Vec<Exercise>,
+}
+/// Errors raised during a transfer.
+#[derive(Debug, thiserror::Error)]
+pub enum TransferError {
+    /// The scanned / pasted code is not a valid pairing code.
+    #[error("invalid pairing code")]
+    InvalidCode,
+    /// WebRTC or camera failure reported by the `WebView`.
+    #[error("connection error: {0}")]
+    Connection(String),
+    /// Encryption failure or tampered data.
+    #[error(transparent)]
+    Crypto(#[from] crypto::CryptoError),
+    /// The decrypted bundle could not be parsed.
+    #[error("invalid data: {0}")]
+    Format(String),
+    /// The other device stopped responding.
+    #[error("the other device stopped responding")]
+    Timeout,
+}
+/// Decoded offer: the transfer key and the sender's SDP.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairingOffer {
+    /// Random one-time key used to encrypt the bundle.
+    pub key: SecretKey,
+    /// WebRTC session description of the sender.
+    pub sdp: String,
+}
+/// Encode an offer as a QR-friendly string.
+#[must_use]
+pub fn encode_offer(offer: &PairingOffer) -> String {
+    format!(
+        "{OFFER_PREFIX}{}.{}",
+        crypto::to_base64(offer.key.as_bytes()),
+        crypto::to_base64(offer.sdp.as_bytes())
+    )
+}
+/// Decode a string produced by [`encode_offer`].
+///
+/// # Errors
+///
+/// Returns [`TransferError::InvalidCode`] on malformed input.
+pub fn decode_offer(code: &str) -> Result<PairingOffer, TransferError> {
+    let rest = code
+        .trim()
+        .strip_prefix(OFFER_PREFIX)
+        .ok_or(TransferError::InvalidCode)?;
+    let (key_b64, sdp_b64) = rest.split_once('.').ok_or(TransferError::InvalidCode)?;
+    let key: [u8; KEY_LEN] = crypto::from_base64(key_b64)
+        .and_then(|k| k.try_into().ok())
+        .ok_or(TransferError::InvalidCode)?;
+    let sdp = crypto::from_base64(sdp_b64)
+        .and_then(|b| String::from_utf8(b).ok())
+        .ok_or(TransferError::InvalidCode)?;
+    Ok(PairingOffer {
+        key: SecretKey::from_bytes(key),
+        sdp,
+    })
+}
+/// Encode the receiver's answer SDP.
+#[must_use]
+pub fn encode_answer(sdp: &str) -> String {
+    format!("{ANSWER_PREFIX}{}", crypto::to_base64(sdp.as_bytes()))
+}
+/// Decode a string produced by [`encode_answer`].
+///
+/// # Errors
+///
+/// Returns [`TransferError::InvalidCode`] on malformed input.
+pub fn decode_answer(code: &str) -> Result<String, TransferError> {
+    code.trim()
+        .strip_prefix(ANSWER_PREFIX)
+        .and_then(crypto::from_base64)
+        .and_then(|b| String::from_utf8(b).ok())
+        .ok_or(TransferError::InvalidCode)
+}
+/// Generate a fresh random transfer key.
+///
+/// # Errors
+///
+/// Returns [`TransferError::Crypto`] if the random source is unavailable.
+pub fn new_transfer_key() -> Result<SecretKey, TransferError> {
+    let bytes: [u8; KEY_LEN] = crypto::random_bytes(KEY_LEN)?
+        .try_into()
+        .map_err(|_| crypto::CryptoError::Rng)?;
+    Ok(SecretKey::from_bytes(bytes))
+}
+/// Serialise and encrypt `bundle` into a base64 string.
+///
+/// # Errors
+///
+/// Fails on serialisation or encryption errors.
+pub fn seal_bundle(key: &SecretKey, bundle: &TransferBundle) -> Result<String, TransferError> {
+    let json = serde_json::to_vec(bundle).map_err(|e| TransferError::Format(e.to_string()))?;
+    Ok(crypto::to_base64(&crypto::encrypt(key, &json)?))
+}
+/// Decrypt and parse a string produced by [`seal_bundle`].
+///
+/// # Errors
+///
+/// Fails on a wrong key, tampered data or malformed JSON.
+pub fn open_bundle(key: &SecretKey, sealed: &str) -> Result<TransferBundle, TransferError> {
+    let bytes = crypto::from_base64(sealed).ok_or(TransferError::InvalidCode)?;
+    let json = crypto::decrypt(key, &bytes)?;
+    serde_json::from_slice(&json).map_err(|e| TransferError::Format(e.to_string()))
+}
+/// Render `data` as an SVG QR code, or `None` if it is too long to encode.
+#[must_use]
+pub fn qr_svg(data: &str) -> Option<String> {
+    let code = qrcode::QrCode::with_error_correction_level(data, qrcode::EcLevel::L).ok()?;
+    Some(
+        code.render::<qrcode::render::svg::Color<'_>>()
+            .min_dimensions(240, 240)
+            .quiet_zone(true)
+            .build(),
+    )
+}
+/// Shared JavaScript helpers installed once on `window.__logoutRtc`.
+///
+/// Only host candidates are gathered (no STUN/TURN) so traffic never leaves
+/// the local network.  Large payloads are split into [`CHUNK_BYTES`] chunks
+/// and terminated by an `"__end__"` marker.  `R.watchdog(fail, ms)` returns
+/// a function to call on every sign of progress, optionally with a new
+/// delay; `fail` runs if none comes for `ms`.
+const RTC_PRELUDE: &str = r"
+window.__logoutRtc = window.__logoutRtc || {};
+var R = window.__logoutRtc;
+R.gather = function(pc){ return new Promise(function(res){
+  if (pc.iceGatheringState === 'complete') { res(); return; }
+  pc.addEventListener('icegatheringstatechange', function(){ if (pc.iceGatheringState === 'complete') res(); });
+  setTimeout(res, 3000);
+}); };
+R.watchdog = function(fail, ms){
+  var timer = null;
+  var arm = function(next){ if (next) ms = next; clearTimeout(timer); timer = setTimeout(fail, ms); };
+  arm.stop = function(){ clearTimeout(timer); };
+  arm();
+  return arm;
+};
+";
+/// Sender side, step 1: create the offer and return its SDP.
+///
+/// # Errors
+///
+/// Returns [`TransferError::Connection`] if WebRTC is unavailable.
+pub async fn create_offer() -> Result<String, TransferError> {
+    let js = format!(
+        r"{RTC_PRELUDE}
+(async function(){{
+  try {{
+    if (R.pc) R.pc.close();
+    var pc = new RTCPeerConnection({{iceServers: []}});
+    R.pc = pc;
+    R.channel = pc.createDataChannel('logout-transfer', {{ordered: true}});
+    await pc.setLocalDescription(await pc.createOffer());
+    await R.gather(pc);
+    dioxus.send({{ok: pc.localDescription.sdp}});
+  }} catch (e) {{ dioxus.send({{err: String(e)}}); }}
+}})();"
+    );
+    eval_result(&js).await
+}
+/// Sender side, step 2: apply the receiver's answer, wait for the channel to
+/// open and stream `sealed` through it.
+///
+/// Sending pauses while more than [`BUFFERED_HIGH_BYTES`] are queued, until
+/// the channel drains below [`BUFFERED_LOW_BYTES`], so large histories do
+/// not overflow the channel buffer.
+///
+/// # Errors
+///
+/// Returns [`TransferError::Connection`] on WebRTC failures, including a
+/// channel closed before the receiver acknowledged the bundle, and
+/// [`TransferError::Timeout`] after [`IDLE_TIMEOUT_MS`] without progress.
+pub async fn send_bundle(answer_sdp: &str, sealed: &str) -> Result<(), TransferError> {
+    let answer_js = serde_json::to_string(answer_sdp).unwrap_or_default();
+    let sealed_js = serde_json::to_string(sealed).unwrap_or_default();
+    let js = format!(
+        r"{RTC_PRELUDE}
+(async function(){{
+  var done = false, pc = R.pc, progress = null;
+  var finish = function(result){{
+    if (done) return;
+    done = true;
+    if (progress) progress.stop();
+    if (pc) pc.close();
+    dioxus.send(result);
+  }};
+  try {{
+    var ch = R.channel;
+    if (!pc || !ch) throw new Error('no pending offer');
+    progress = R.watchdog(function(){{ finish({{err: '{TIMEOUT_MARKER}'}}); }}, {IDLE_TIMEOUT_MS});
+    ch.bufferedAmountLowThreshold = {BUFFERED_LOW_BYTES};
+    var drained = function(){{ return new Promise(function(res){{ ch.onbufferedamountlow = res; }}); }};
+    var data = {sealed_js};
+    var send = async function(){{
+      try {{
+        for (var i = 0; i < data.length && !done; i += {CHUNK_BYTES}) {{
+          if (ch.bufferedAmount > {BUFFERED_HIGH_BYTES}) await drained();
+          ch.send(data.slice(i, i + {CHUNK_BYTES}));
+          progress();
+        }}
+        if (!done) ch.send('__end__');
+      }} catch (e) {{ finish({{err: String(e)}}); }}
+    }};
+    ch.onmessage = function(e){{ if (e.data === '__ack__') finish({{ok: ''}}); }};
+    ch.onerror = function(e){{ finish({{err: String((e.error && e.error.message) || 'data channel error')}}); }};
+    ch.onclose = function(){{ finish({{err: 'data channel closed'}}); }};
+    if (ch.readyState === 'open') send(); else ch.onopen = send;
+    await pc.setRemoteDescription({{type: 'answer', sdp: {answer_js}}});
+  }} catch (e) {{ finish({{err: String(e)}}); }}
+}})();"
+    );
+    eval_result(&js).await.map(|_| ())
+}
+/// Receiver side, step 1: apply the sender's offer and return the answer SDP.
+///
+/// # Errors
+///
+/// Returns [`TransferError::Connection`] if WebRTC is unavailable.
+pub async fn accept_offer(offer_sdp: &str) -> Result<String, TransferError> {
+    let offer_js = serde_json::to_string(offer_sdp).unwrap_or_default();
+    let js = format!(
+        r"{RTC_PRELUDE}
+(async function(){{
+  try {{
+    if (R.pc) R.pc.close();
+    var pc = new RTCPeerConnection({{iceServers: []}});
+    R.pc = pc;
+    R.received = new Promise(function(res, rej){{
+      var ch = null, buf = [], done = false, progress = null;
+      var finish = function(err){{
+        if (done) return;
+        done = true;
+        if (progress) progress.stop();
+        if (err) {{ pc.close(); rej(new Error(err)); }} else {{ ch.send('__ack__'); res(buf.join('')); }}
+      }};
+      // Armed by `receive_bundle`, for the sender to connect.
+      R.armReceive = function(){{
+        if (!done && !progress) progress = R.watchdog(function(){{ finish('{TIMEOUT_MARKER}'); }}, {CONNECT_TIMEOUT_MS});
+      }};
+      pc.onconnectionstatechange = function(){{
+        if (pc.connectionState === 'failed') finish('connection failed');
+      }};
+      pc.ondatachannel = function(ev){{
+        ch = ev.channel;
+        if (progress) progress({IDLE_TIMEOUT_MS});
+        ch.onmessage = function(e){{
+          if (progress) progress({IDLE_TIMEOUT_MS});
+          if (e.data === '__end__') finish(null);
+          else buf.push(e.data);
+        }};
+        ch.onerror = function(e){{ finish(String((e.error && e.error.message) || 'data channel error')); }};
+        ch.onclose = function(){{ finish('data channel closed'); }};
+      }};
+    }});
+    await pc.setRemoteDescription({{type: 'offer', sdp: {offer_js}}});
+    await pc.setLocalDescription(await pc.createAnswer());
+    await R.gather(pc);
+    dioxus.send({{ok: pc.localDescription.sdp}});
+  }} catch (e) {{ dioxus.send({{err: String(e)}}); }}
+}})();"
+    );
+    eval_result(&js).await
+}
+/// Receiver side, step 2: wait until the whole sealed bundle has arrived.
+///
+/// # Errors
+///
+/// Returns [`TransferError::Connection`] if no offer was accepted first or
+/// the connection or channel failed, and [`TransferError::Timeout`] if the
+/// sender does not connect within [`CONNECT_TIMEOUT_MS`] of this call or
+/// then sends nothing for [`IDLE_TIMEOUT_MS`].
+pub async fn receive_bundle() -> Result<String, TransferError> {
+    let js = format!(
+        r"{RTC_PRELUDE}
+(async function(){{
+  try {{
+    if (!R.received) throw new Error('no accepted offer');
+    R.armReceive();
+    dioxus.send({{ok: await R.received}});
+  }} catch (e) {{ dioxus.send({{err: e.message || String(e)}}); }}
+}})();"
+    );
+    eval_result(&js).await
+}
+/// Scan a QR code with the camera using the `BarcodeDetector` API.
+///
+/// # Errors
+///
+/// Returns [`TransferError::Connection`] when the camera or the detector is
+/// unavailable; callers should fall back to pasting the code.
+pub async fn scan_qr_code(video_id: &str) -> Result<String, TransferError> {
+    let id_js = serde_json::to_string(video_id).unwrap_or_default();
+    let js = format!(
+        r"(async function(){{
+  try {{
+    if (!('BarcodeDetector' in window)) throw new Error('BarcodeDetector unsupported');
+    var video = document.getElementById({id_js});
+    var stream = await navigator.mediaDevices.getUserMedia({{video: {{facingMode: 'environment'}}}});
+    video.srcObject = stream; await video.play();
+    var detector = new BarcodeDetector({{formats: ['qr_code']}});
+    var tick = async function(){{
+      var codes = await detector.detect(video).catch(function(){{ return []; }});
+      if (codes.length) {{
+        stream.getTracks().forEach(function(t){{ t.stop(); }});
+        dioxus.send({{ok: codes[0].rawValue}});
+      }} else requestAnimationFrame(tick);
+    }};
+    tick();
+  }} catch (e) {{ dioxus.send({{err: String(e)}}); }}
+}})();"
+    );
+    eval_result(&js).await
+}
+/// Result envelope sent back by the JavaScript snippets.
+#[derive(Deserialize)]
+struct JsResult {
+    ok: Option<String>,
+    err: Option<String>,
+}
+async fn eval_result(js: &str) -> Result<String, TransferError> {
+    let mut eval = document::eval(js);
+    let result: JsResult = eval
+        .recv()
+        .await
+        .map_err(|e| TransferError::Connection(format!("{e:?}")))?;
+    match (result.ok, result.err) {
+        (Some(ok), _) => Ok(ok),
+        (None, Some(err)) if err == TIMEOUT_MARKER => Err(TransferError::Timeout),
+        (None, Some(err)) => Err(TransferError::Connection(err)),
+        (None, None) => Err(TransferError::Connection("empty response".into())),
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SDP: &str =
+        "v=0\r\no=- 1 2 IN IP4 127.0.0.1\r\na=candidate:1 1 udp 1 192.168.1.2 5000 typ host\r\n";
+    #[test]
+    fn offer_round_trip() {
+        let offer = PairingOffer {
+            key: new_transfer_key().unwrap(),
+            sdp: SDP.into(),
+        };
+        let code = encode_offer(&offer);
+        assert!(code.starts_with(OFFER_PREFIX));
+        assert_eq!(decode_offer(&code).unwrap(), offer);
+    }
+    #[test]
+    fn answer_round_trip() {
+        assert_eq!(decode_answer(&encode_answer(SDP)).unwrap(), SDP);
+    }
+    #[test]
+    fn offer_and_answer_codes_are_not_interchangeable() {
+        assert!(matches!(
+            decode_offer(&encode_answer(SDP)),
+            Err(TransferError::InvalidCode)
+        ));
+        assert!(decode_offer("logout-offer:short.xx").is_err());
+        assert!(decode_answer("garbage").is_err());
+    }
+    #[test]
+    fn bundle_round_trip_and_wrong_key() {
+        let key = new_transfer_key().unwrap();
+        let mut bundle = TransferBundle::default();
+        bundle.sessions.push(WorkoutSession::new());
+        let sealed = seal_bundle(&key, &bundle).unwrap();
+        assert_eq!(open_bundle(&key, &sealed).unwrap(), bundle);
+        let other = new_transfer_key().unwrap();
+        assert!(open_bundle(&other, &sealed).is_err());
+    }
+    #[test]
+    fn qr_svg_renders_offer() {
+        let offer = PairingOffer {
+            key: new_transfer_key().unwrap(),
+            sdp: SDP.into(),
+        };
+        let svg = qr_svg(&encode_offer(&offer)).unwrap();
+        assert!(svg.contains("<svg"));
+        assert!(qr_svg(&"x".repeat(10_000)).is_none());
+    }
+}
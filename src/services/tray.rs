@@ -0,0 +1,189 @@
+/// System tray icon of desktop builds, so the app can stay minimized while
+/// training at a desk.
+///
+/// It is shown while a session is running.  Its tooltip reads like the
+/// Android timer notification (see [`super::timer_service::notice_for`]):
+/// the rest countdown, or the elapsed time of the exercise or session,
+/// refreshed by the app clock.  Its menu completes the exercise in progress
+/// or finishes the session, see [`TrayAction`].
+///
+/// Only built with the `desktop-platform` feature; elsewhere
+/// [`use_session_tray`] does nothing.
+use super::timer_service::TimerNotice;
+use crate::models::format_time_i64;
+use dioxus::prelude::*;
+/// An action requested from the tray menu.
+#[cfg_attr(not(feature = "desktop-platform"), allow(dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayAction {
+    /// Complete the exercise in progress, as its complete button does.
+    Complete,
+    /// Finish the session.
+    Finish,
+}
+/// Tooltip of the tray icon for `notice`, titled `title`, at `now`.
+#[must_use]
+#[cfg_attr(not(feature = "desktop-platform"), allow(dead_code))]
+pub fn tooltip(title: &str, notice: TimerNotice, now: u64) -> String {
+    let seconds = if notice.count_down {
+        notice.when.cast_signed() - now.cast_signed()
+    } else {
+        now.saturating_sub(notice.when).cast_signed()
+    };
+    format!("{title} {}", format_time_i64(seconds))
+}
+/// Show the tray icon while `notice` is set, titled `title`, and call
+/// `on_action` with the actions picked from its menu.
+#[cfg(feature = "desktop-platform")]
+pub fn use_session_tray(
+    notice: Memo<Option<TimerNotice>>,
+    title: Memo<Option<String>>,
+    on_action: impl FnMut(TrayAction) + 'static,
+) {
+    let on_action = use_callback(on_action);
+    let clock = super::clock::use_clock();
+    use_effect(move || {
+        let now = clock();
+        match (notice(), title()) {
+            (Some(notice), Some(title)) => desktop::show(&tooltip(&title, notice, now), notice),
+            _ => desktop::hide(),
+        }
+    });
+    dioxus::desktop::use_tray_menu_event_handler(move |event| {
+        if let Some(action) = desktop::action_of(&event.id) {
+            on_action.call(action);
+        }
+    });
+    use_drop(desktop::hide);
+}
+#[cfg(not(feature = "desktop-platform"))]
+pub fn use_session_tray(
+    notice: Memo<Option<TimerNotice>>,
+    title: Memo<Option<String>>,
+    on_action: impl FnMut(TrayAction) + 'static,
+) {
+    let _ = (notice, title, on_action);
+}
+#[cfg(feature = "desktop-platform")]
+mod desktop {
+    use super::TrayAction;
+    use crate::services::timer_service::{TimerKind, TimerNotice};
+    use dioxus::desktop::trayicon::menu::{Menu, MenuId, MenuItem};
+    use dioxus::desktop::trayicon::{Icon, TrayIcon, TrayIconBuilder};
+    use dioxus_i18n::t;
+    use std::cell::RefCell;
+    /// Side of the square tray icon, in pixels.
+    const ICON_SIZE: u16 = 32;
+    /// RGBA colour of the tray icon disc.
+    const ICON_RGBA: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
+    /// The tray icon and its menu items.
+    struct Tray {
+        icon: TrayIcon,
+        complete: MenuItem,
+        finish: MenuItem,
+    }
+    thread_local! {
+        /// Built on first use, on the main thread running the event loop.
+        static TRAY: RefCell<Option<Tray>> = const { RefCell::new(None) };
+    }
+    /// Show the tray icon with `tooltip`, building it first if needed.
+    pub(super) fn show(tooltip: &str, notice: TimerNotice) {
+        TRAY.with(|tray| {
+            let mut tray = tray.borrow_mut();
+            if tray.is_none() {
+                match build() {
+                    Ok(built) => *tray = Some(built),
+                    Err(e) => {
+                        log::warn!("Failed to create the tray icon: {e}");
+                        return;
+                    }
+                }
+            }
+            let Some(tray) = tray.as_ref() else { return };
+            if let Err(e) = tray.icon.set_tooltip(Some(tooltip)) {
+                log::warn!("Failed to update the tray tooltip: {e}");
+            }
+            tray.complete
+                .set_enabled(notice.kind == TimerKind::Exercise);
+            let _ = tray.icon.set_visible(true);
+        });
+    }
+    /// Hide the tray icon, if built.
+    pub(super) fn hide() {
+        TRAY.with(|tray| {
+            if let Some(tray) = tray.borrow().as_ref() {
+                let _ = tray.icon.set_visible(false);
+            }
+        });
+    }
+    /// The action of the menu item `id`, if it is one of the tray's.
+    pub(super) fn action_of(id: &MenuId) -> Option<TrayAction> {
+        TRAY.with(|tray| {
+            let tray = tray.borrow();
+            let tray = tray.as_ref()?;
+            if id == tray.complete.id() {
+                Some(TrayAction::Complete)
+            } else if id == tray.finish.id() {
+                Some(TrayAction::Finish)
+            } else {
+                None
+            }
+        })
+    }
+    fn build() -> Result<Tray, String> {
+        let complete = MenuItem::new(t!("tray-complete"), false, None);
+        let finish = MenuItem::new(t!("tray-finish"), true, None);
+        let menu = Menu::new();
+        menu.append_items(&[&complete, &finish])
+            .map_err(|e| e.to_string())?;
+        let icon = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_icon(icon()?)
+            .with_tooltip("LogOut")
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Tray {
+            icon,
+            complete,
+            finish,
+        })
+    }
+    /// A plain disc, readable on both light and dark panels once the system
+    /// outlines it.
+    fn icon() -> Result<Icon, String> {
+        let radius = f32::from(ICON_SIZE) / 2.0;
+        let side = usize::from(ICON_SIZE);
+        let mut rgba = Vec::with_capacity(side * side * 4);
+        for y in 0..ICON_SIZE {
+            for x in 0..ICON_SIZE {
+                let dx = f32::from(x) + 0.5 - radius;
+                let dy = f32::from(y) + 0.5 - radius;
+                let inside = dx * dx + dy * dy <= radius * radius;
+                rgba.extend_from_slice(if inside { &ICON_RGBA } else { &[0; 4] });
+            }
+        }
+        let size = u32::from(ICON_SIZE);
+        Icon::from_rgba(rgba, size, size).map_err(|e| e.to_string())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::timer_service::TimerKind;
+    #[test]
+    fn tooltip_counts_down_the_rest_and_up_the_exercise() {
+        let rest = TimerNotice {
+            kind: TimerKind::Rest,
+            when: 1_060,
+            count_down: true,
+        };
+        assert_eq!(tooltip("Rest", rest, 1_018), "Rest 00:42");
+        assert!(tooltip("Rest", rest, 1_065).starts_with("Rest -"));
+        let exercise = TimerNotice {
+            kind: TimerKind::Exercise,
+            when: 1_000,
+            count_down: false,
+        };
+        assert_eq!(tooltip("Squat", exercise, 1_083), "Squat 01:23");
+    }
+}
@@ -1,47 +1,106 @@
-/// Wake Lock – prevent the device screen from sleeping while the app is open.
+/// Wake Lock – prevent the device screen from sleeping during a session.
 ///
 /// Uses the [Screen Wake Lock API](https://developer.mozilla.org/en-US/docs/Web/API/Screen_Wake_Lock_API)
 /// via `js_sys` reflection so that no additional `web-sys` feature flags are
 /// required.  The call is a progressive enhancement: if the API is unavailable
 /// the function silently does nothing.
 ///
-/// A new lock is requested whenever the page becomes visible again (e.g. after
-/// the user switches back from another app), which keeps the lock active
-/// throughout the session.
+/// The lock is only held while a session is active and the user has not
+/// turned "keep screen awake" off (see [`keep_awake_enabled`]).  Browsers
+/// drop the lock when the page is hidden, so a new one is requested whenever
+/// the page becomes visible again while a session is still running.
+/// Config key storing `"false"` when the user disabled keep-awake.
+const KEEP_AWAKE_KEY: &str = "keep_awake";
+/// Whether the screen should be kept awake during sessions (default `true`).
+#[must_use]
+pub fn keep_awake_enabled() -> bool {
+    crate::utils::get_config(KEEP_AWAKE_KEY).as_deref() != Some("false")
+}
+/// Persist the keep-awake preference (see [`keep_awake_enabled`]).
+pub fn set_keep_awake_enabled(enabled: bool) {
+    // The default is stored as an absent key.
+    crate::utils::set_config(KEEP_AWAKE_KEY, if enabled { "" } else { "false" });
+}
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+thread_local! {
+    /// `WakeLockSentinel` of the lock currently held, if any.
+    static SENTINEL: std::cell::RefCell<Option<wasm_bindgen::JsValue>> =
+        const { std::cell::RefCell::new(None) };
+    /// Whether the lock should be held (re-acquired on visibility change).
+    static WANTED: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+    /// Whether the `visibilitychange` listener has been installed.
+    static LISTENING: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+/// Acquire (`active`) or release the screen wake lock.
+///
+/// Call whenever a session starts or ends, or the preference changes; the
+/// caller is expected to pass `has_active_session && keep_awake_enabled()`.
 #[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
-pub fn enable_wake_lock() {
+pub fn set_session_wake_lock(active: bool) {
     use wasm_bindgen::prelude::*;
-    wasm_bindgen_futures::spawn_local(async {
-        if let Err(e) = request_wake_lock().await {
-            log::warn!("Wake Lock request failed: {:?}", e);
+    WANTED.with(|w| w.set(active));
+    if !active {
+        release_wake_lock();
+        return;
+    }
+    spawn_request();
+    if LISTENING.with(|l| l.replace(true)) {
+        return;
+    }
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let closure = Closure::<dyn FnMut()>::new(|| {
+        let visible = web_sys::window()
+            .and_then(|w| w.document())
+            .is_some_and(|d| d.visibility_state() == web_sys::VisibilityState::Visible);
+        if visible && WANTED.with(std::cell::Cell::get) {
+            spawn_request();
         }
     });
-    if let Some(window) = web_sys::window() {
-        let document = match window.document() {
-            Some(d) => d,
-            None => return,
-        };
-        let closure = Closure::<dyn FnMut()>::new(|| {
-            wasm_bindgen_futures::spawn_local(async {
-                let Some(window) = web_sys::window() else {
-                    return;
-                };
-                let Some(document) = window.document() else {
-                    return;
-                };
-                if document.visibility_state() == web_sys::VisibilityState::Visible {
-                    let _ = request_wake_lock().await;
+    let _ = document
+        .add_event_listener_with_callback("visibilitychange", closure.as_ref().unchecked_ref());
+    closure.forget();
+}
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+fn spawn_request() {
+    wasm_bindgen_futures::spawn_local(async {
+        match request_wake_lock().await {
+            // The session may have ended while the request was pending.
+            Ok(sentinel) if WANTED.with(std::cell::Cell::get) => {
+                release_wake_lock();
+                SENTINEL.with(|s| *s.borrow_mut() = sentinel);
+            }
+            Ok(sentinel) => {
+                if let Some(sentinel) = sentinel {
+                    release_sentinel(&sentinel);
                 }
-            });
-        });
-        let _ = document
-            .add_event_listener_with_callback("visibilitychange", closure.as_ref().unchecked_ref());
-        closure.forget();
+            }
+            Err(e) => log::warn!("Wake Lock request failed: {:?}", e),
+        }
+    });
+}
+/// Release the wake lock currently held, if any.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+fn release_wake_lock() {
+    if let Some(sentinel) = SENTINEL.with(|s| s.borrow_mut().take()) {
+        release_sentinel(&sentinel);
+    }
+}
+/// Calls `sentinel.release()` via JS reflection.
+#[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
+fn release_sentinel(sentinel: &wasm_bindgen::JsValue) {
+    use wasm_bindgen::JsCast;
+    if let Some(release) = js_sys::Reflect::get(sentinel, &"release".into())
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+    {
+        let _ = release.call0(sentinel);
     }
 }
 /// Calls `navigator.wakeLock.request("screen")` via JS reflection.
 #[cfg(all(target_arch = "wasm32", feature = "web-platform"))]
-async fn request_wake_lock() -> Result<(), String> {
+async fn request_wake_lock() -> Result<Option<wasm_bindgen::JsValue>, String> {
     use js_sys::{Array, Function, Reflect};
     use wasm_bindgen::JsCast;
     use wasm_bindgen::JsValue;
@@ -51,7 +110,7 @@ async fn request_wake_lock() -> Result<(), String> {
     let wake_lock =
         Reflect::get(&navigator, &JsValue::from_str("wakeLock")).map_err(|e| format!("{:?}", e))?;
     if wake_lock.is_undefined() || wake_lock.is_null() {
-        return Ok(());
+        return Ok(None);
     }
     let request_fn =
         Reflect::get(&wake_lock, &JsValue::from_str("request")).map_err(|e| format!("{:?}", e))?;
@@ -67,24 +126,31 @@ async fn request_wake_lock() -> Result<(), String> {
         .map_err(|_| "wakeLock.request did not return a Promise".to_string())?;
     JsFuture::from(promise)
         .await
-        .map(|_| ())
+        .map(Some)
         .map_err(|e| format!("{:?}", e))
 }
+/// Acquire (`active`) or release the session wake lock.
+///
+/// On Android this toggles the lock-screen mode of
+/// [`set_active_session_lock_screen`]; elsewhere it does nothing.
 #[cfg(not(all(target_arch = "wasm32", feature = "web-platform")))]
-pub fn enable_wake_lock() {
+pub fn set_session_wake_lock(active: bool) {
     #[cfg(target_os = "android")]
-    acquire_android_wake_lock();
+    set_active_session_lock_screen(active);
+    #[cfg(not(target_os = "android"))]
+    let _ = active;
 }
 
 /// Acquires an Android `PARTIAL_WAKE_LOCK` via JNI so that the CPU is not
 /// suspended while the app is downloading images in the background (even when
 /// the screen turns off).
 ///
-/// The lock is acquired with a one-hour timeout as a safety net; in normal
-/// usage it will be held until the process exits.  The `WAKE_LOCK` permission
-/// is already declared in `Dioxus.toml`.
+/// The lock is acquired at start-up with a one-hour timeout, which bounds it
+/// to the initial download.  It is unrelated to the session screen lock and
+/// the keep-awake preference.  The `WAKE_LOCK` permission is already declared
+/// in `Dioxus.toml`.
 #[cfg(target_os = "android")]
-fn acquire_android_wake_lock() {
+pub fn acquire_download_wake_lock() {
     use jni::{objects::JObject, JavaVM};
     use ndk_context::android_context;
 
@@ -432,7 +498,8 @@ pub fn is_shown_over_lock_screen() -> bool {
 mod tests {
     use super::*;
     #[test]
-    fn enable_wake_lock_noop_on_native() {
-        enable_wake_lock();
+    fn session_wake_lock_noop_on_native() {
+        set_session_wake_lock(true);
+        set_session_wake_lock(false);
     }
 }
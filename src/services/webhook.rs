@@ -0,0 +1,42 @@
+/// Webhook notified when a session finishes, for home-automation setups
+/// (Home Assistant, Node-RED, n8n…) to react to the end of a workout.
+///
+/// A [`WebhookPayload`] summarising the session is POSTed as JSON to the
+/// configured URL.  MQTT brokers are reached through such a tool, as most
+/// expose an HTTP bridge.  On the web the endpoint must allow cross-origin
+/// requests (CORS).
+pub use logout_core::webhook::WebhookPayload;
+/// Config key holding the webhook URL.
+pub const WEBHOOK_URL_KEY: &str = "webhook_url";
+/// Errors raised while calling the webhook.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// HTTP / transport failure.
+    #[error("network error: {0}")]
+    Network(String),
+    /// The endpoint answered with a non-success status.
+    #[error("HTTP {0}")]
+    Status(u16),
+}
+/// The configured webhook URL, if any.
+#[must_use]
+pub fn configured_url() -> Option<String> {
+    crate::utils::get_config(WEBHOOK_URL_KEY)
+}
+/// POST `payload` to `url`.
+///
+/// # Errors
+/// If the request fails or the endpoint does not answer with a 2xx status.
+pub async fn notify(url: &str, payload: &WebhookPayload) -> Result<(), WebhookError> {
+    let response = reqwest::Client::new()
+        .post(url)
+        .json(payload)
+        .send()
+        .await
+        .map_err(|e| WebhookError::Network(e.to_string()))?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(WebhookError::Status(response.status().as_u16()))
+    }
+}
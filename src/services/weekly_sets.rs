@@ -0,0 +1,126 @@
+/// Weekly hard-set counter behind the per-muscle targets of the home page.
+///
+/// Every completed working set (warm-ups excluded) logged since Monday counts
+/// one set for each primary muscle of its exercise.
+use crate::models::{Exercise, Muscle, WorkoutSession};
+use crate::services::{exercise_db, storage};
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+use time::Date;
+/// Number of most recent completed sessions scanned for this week's sets.
+const RECENT_SESSIONS: usize = 50;
+/// Hard sets per primary muscle among the sets of `sessions` completed on
+/// or after the local day `since`.
+#[must_use]
+pub fn hard_sets_since<E: AsRef<Exercise>>(
+    sessions: &[WorkoutSession],
+    db: &[E],
+    custom: &[E],
+    since: Date,
+) -> BTreeMap<Muscle, u32> {
+    let mut sets: BTreeMap<Muscle, u32> = BTreeMap::new();
+    for log in sessions.iter().flat_map(|s| &s.exercise_logs) {
+        let Some(end) = log.end_time else {
+            continue;
+        };
+        if !log.set_kind.counts_for_stats() || crate::datetime::to_local(end).date() < since {
+            continue;
+        }
+        let Some(exercise) = exercise_db::resolve_exercise(db, custom, &log.exercise_id) else {
+            continue;
+        };
+        for &muscle in &exercise.as_ref().primary_muscles {
+            *sets.entry(muscle).or_default() += 1;
+        }
+    }
+    sets
+}
+/// Reactive hard sets per muscle since Monday, from the recent completed
+/// sessions and those in memory (including the active one).
+pub fn use_weekly_hard_sets() -> Memo<BTreeMap<Muscle, u32>> {
+    let sessions = storage::use_sessions();
+    let all_exercises = exercise_db::use_exercises();
+    let custom_exercises = storage::use_custom_exercises();
+    let recent = use_resource(|| async {
+        storage::load_completed_sessions_page(RECENT_SESSIONS, 0, true)
+            .await
+            .unwrap_or_else(|e| {
+                log::error!("Failed to load recent sessions for weekly sets: {e}");
+                Vec::new()
+            })
+    });
+    use_memo(move || {
+        let in_memory = sessions.read();
+        let mut all: Vec<WorkoutSession> = in_memory.clone();
+        if let Some(recent) = recent.read().as_ref() {
+            all.extend(
+                recent
+                    .iter()
+                    .filter(|s| !in_memory.iter().any(|m| m.id == s.id))
+                    .cloned(),
+            );
+        }
+        hard_sets_since(
+            &all,
+            &all_exercises.read(),
+            &custom_exercises.read(),
+            crate::datetime::monday_of(crate::datetime::today()),
+        )
+    })
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, ExerciseLog, SetKind, Weight};
+    use time::Month;
+    fn exercise(id: &str, primary: Vec<Muscle>) -> Exercise {
+        Exercise {
+            id: id.into(),
+            name: id.into(),
+            name_lower: id.into(),
+            force: None,
+            level: None,
+            mechanic: None,
+            equipment: None,
+            primary_muscles: primary,
+            secondary_muscles: vec![Muscle::Triceps],
+            instructions: vec![],
+            category: Category::Strength,
+            images: vec![],
+            videos: vec![],
+            i18n: None,
+            rest_seconds: None,
+        }
+    }
+    fn set(id: &str, day: Date, set_kind: SetKind) -> ExerciseLog {
+        // Noon UTC, the same local day in most timezones.
+        let end = u64::try_from(day.midnight().assume_utc().unix_timestamp()).unwrap() + 43_200;
+        ExerciseLog {
+            exercise_id: id.into(),
+            exercise_name: id.into(),
+            start_time: end - 60,
+            end_time: Some(end),
+            weight_hg: Weight(600),
+            reps: Some(8),
+            set_kind,
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn counts_working_sets_of_primary_muscles_since_monday() {
+        let monday = Date::from_calendar_date(2025, Month::October, 13).unwrap();
+        let db = vec![exercise("bench", vec![Muscle::Chest, Muscle::Shoulders])];
+        let mut session = WorkoutSession::new();
+        session.exercise_logs = vec![
+            set("bench", monday, SetKind::Normal),
+            set("bench", monday.next_day().unwrap(), SetKind::Failure),
+            set("bench", monday, SetKind::WarmUp),
+            set("bench", monday.previous_day().unwrap(), SetKind::Normal),
+            set("unknown", monday, SetKind::Normal),
+        ];
+        let sets = hard_sets_since(&[session], &db, &[], monday);
+        assert_eq!(sets.get(&Muscle::Chest), Some(&2));
+        assert_eq!(sets.get(&Muscle::Shoulders), Some(&2));
+        assert_eq!(sets.get(&Muscle::Triceps), None);
+    }
+}
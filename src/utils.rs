@@ -7,12 +7,16 @@ pub(crate) const EXERCISE_IMAGES_BASE_URL: &str =
     "https://raw.githubusercontent.com/gfauredev/free-exercise-db/main/";
 /// localStorage / config-file key used to store a user-configured exercise database URL.
 pub(crate) const EXERCISE_DB_URL_STORAGE_KEY: &str = "exercise_db_url";
-/// Seconds in a minute.
-pub const SECONDS_IN_MINUTE: u64 = 60;
-/// Seconds in an hour.
-pub const SECONDS_IN_HOUR: u64 = 3600;
-/// Seconds in a day.
-pub const SECONDS_IN_DAY: u64 = 86400;
+/// Config key of the additional exercise database base URLs (JSON array),
+/// whose exercises are merged into those of the main database.
+pub(crate) const EXTRA_EXERCISE_DB_URLS_KEY: &str = "exercise_db_extra_urls";
+/// Config key storing the base URL of the exercise language pack.
+const LANGUAGE_PACK_URL_KEY: &str = "exercise_language_pack_url";
+/// Address of the public web app, which opens the links shared from native
+/// builds.
+pub(crate) const WEB_APP_URL: &str = "https://gfauredev.github.io/LogOut/";
+pub use crate::datetime::{SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE};
+pub use logout_core::query::get_query_param;
 
 /// Cross-platform async sleep used by debounce coroutines.
 ///
@@ -68,6 +72,38 @@ pub fn get_exercise_db_url() -> String {
 pub fn get_exercise_images_base_url() -> String {
     configured_exercise_db_url().unwrap_or_else(|| EXERCISE_IMAGES_BASE_URL.to_string())
 }
+/// Persist the exercise database base URL, removing the setting when `url`
+/// is empty or the default one.
+pub(crate) fn set_exercise_db_url(url: &str) {
+    let custom = if url == EXERCISE_DB_BASE_URL { "" } else { url };
+    set_config(EXERCISE_DB_URL_STORAGE_KEY, custom);
+}
+/// Returns the additional exercise database base URLs, in the order added.
+#[must_use]
+pub fn get_extra_exercise_db_urls() -> Vec<String> {
+    get_config(EXTRA_EXERCISE_DB_URLS_KEY)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+/// Persist the additional exercise database base URLs.
+pub(crate) fn set_extra_exercise_db_urls(urls: &[String]) {
+    let json = if urls.is_empty() {
+        String::new()
+    } else {
+        serde_json::to_string(urls).unwrap_or_default()
+    };
+    set_config(EXTRA_EXERCISE_DB_URLS_KEY, &json);
+}
+/// Returns the base URL of the exercise language pack, if one is set.
+#[must_use]
+pub fn get_language_pack_url() -> Option<String> {
+    get_config(LANGUAGE_PACK_URL_KEY).filter(|url| !url.is_empty())
+}
+/// Persist the base URL of the exercise language pack, removing the setting
+/// when `url` is empty.
+pub(crate) fn set_language_pack_url(url: &str) {
+    set_config(LANGUAGE_PACK_URL_KEY, url);
+}
 
 #[must_use]
 fn configured_exercise_db_url() -> Option<String> {
@@ -84,6 +120,43 @@ fn configured_exercise_db_url() -> Option<String> {
         native_storage::get_config_value(EXERCISE_DB_URL_STORAGE_KEY).filter(|url| !url.is_empty())
     }
 }
+/// Read a persisted setting.
+///
+/// On WASM the value lives in `localStorage`; on native in the `SQLite`
+/// `config` table.  Empty values are treated as absent.
+#[must_use]
+pub(crate) fn get_config(key: &str) -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(key).ok().flatten())
+            .filter(|v| !v.is_empty())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        crate::services::storage::native_storage::get_config_value(key).filter(|v| !v.is_empty())
+    }
+}
+/// Persist a setting (see [`get_config`]).  An empty `value` removes the key.
+pub(crate) fn set_config(key: &str, value: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) {
+            if value.is_empty() {
+                let _ = storage.remove_item(key);
+            } else {
+                let _ = storage.set_item(key, value);
+            }
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Err(e) = crate::services::storage::native_storage::set_config_value(key, value) {
+            log::warn!("Failed to persist setting {key}: {e}");
+        }
+    }
+}
 /// A pending exercise entry parsed from a deep-link session-creation URL.
 ///
 /// `weight_hg` is stored as hectograms (multiply kg × 10); `reps` is raw.
@@ -112,6 +185,9 @@ pub enum DeepLinkAction {
     CreateSession(Vec<SessionExerciseEntry>),
     /// Start a new active session with the given exercise IDs pre-queued.
     StartSession(Vec<String>),
+    /// Import a session shared by someone else, encoded by
+    /// [`crate::services::share_link::encode_session`].
+    ImportSession(String),
 }
 /// Parse a `logworkout://` URL into a [`DeepLinkAction`], returning `None` for
 /// unrecognised or malformed links.
@@ -125,6 +201,7 @@ pub enum DeepLinkAction {
 /// - `logworkout://exercise/add`
 /// - `logworkout://session/start[?exercises=<id>,<id>,…]`
 /// - `logworkout://session/create?exercises=<id>:<kg>:<reps>,…`
+/// - `logworkout://session/import?data=<code>`
 #[must_use]
 pub fn parse_deep_link(url: &str) -> Option<DeepLinkAction> {
     let rest = url.strip_prefix("logworkout://")?;
@@ -171,6 +248,9 @@ pub fn parse_web_deep_link_query(query: &str) -> Option<DeepLinkAction> {
     if let Some(exercises) = get_query_param(query, "dl_start") {
         return Some(DeepLinkAction::StartSession(parse_csv_ids(&exercises)));
     }
+    if let Some(code) = get_query_param(query, "dl_import") {
+        return Some(DeepLinkAction::ImportSession(code));
+    }
     None
 }
 /// Internal: convert a path + query string from a logworkout:// URL into an action.
@@ -202,6 +282,7 @@ fn parse_deep_link_path(path: &str, query: &str) -> Option<DeepLinkAction> {
                 &exercises_str,
             )))
         }
+        "session/import" => get_query_param(query, "data").map(DeepLinkAction::ImportSession),
         _ => None,
     }
 }
@@ -254,33 +335,6 @@ fn parse_csv_ids(s: &str) -> Vec<String> {
         .map(ToOwned::to_owned)
         .collect()
 }
-/// Look up a single parameter value from a URL query string.
-#[must_use]
-pub fn get_query_param(query: &str, name: &str) -> Option<String> {
-    query.split('&').find_map(|pair| {
-        let (k, v) = pair.split_once('=')?;
-        if k == name {
-            Some(percent_decode(v))
-        } else {
-            None
-        }
-    })
-}
-/// Percent-decodes a URL query-parameter value using the standardised
-/// `percent-encoding` crate.  `+` is treated as a space per the
-/// `application/x-www-form-urlencoded` convention.
-fn percent_decode(s: &str) -> String {
-    use std::borrow::Cow;
-    // Replace `+` with `%20` only when needed so we avoid an allocation in the common case.
-    let s = if s.contains('+') {
-        Cow::Owned(s.replace('+', "%20"))
-    } else {
-        Cow::Borrowed(s)
-    };
-    percent_encoding::percent_decode_str(&s)
-        .decode_utf8_lossy()
-        .into_owned()
-}
 /// Map a human-readable route name (as used in `?dl_navigate=…`) to the
 /// corresponding URL path.
 #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
@@ -294,153 +348,9 @@ fn route_name_to_path(name: &str) -> String {
         other => format!("/{other}"),
     }
 }
-/// Format a session timestamp as a human-readable relative date string.
-///
-/// Returns English strings; for localised output use [`session_days_ago`] with
-/// `t!()` in a component.
-#[must_use]
-pub fn format_session_date(timestamp: u64) -> String {
-    let days_ago = days_since(timestamp);
-    match days_ago {
-        0 => "Today".to_string(),
-        1 => "Yesterday".to_string(),
-        n => format!("{n} days ago"),
-    }
-}
-/// Return how many calendar days ago `timestamp` occurred (0 = today,
-/// 1 = yesterday, …).  Use this in Dioxus components together with `t!()` to
-/// produce a localised relative date string.
-#[must_use]
-pub fn session_days_ago(timestamp: u64) -> i64 {
-    days_since(timestamp)
-}
-/// Returns the local [`time::OffsetDateTime`] for a Unix-seconds timestamp,
-/// adjusted to the system's local timezone.  Used by [`is_same_weekday_as_today`]
-/// and [`format_short_date`].
-fn ts_to_local_datetime(timestamp_secs: u64) -> time::OffsetDateTime {
-    use time::OffsetDateTime;
-    #[cfg(not(target_arch = "wasm32"))]
-    let offset = OffsetDateTime::now_local()
-        .unwrap_or_else(|_| OffsetDateTime::now_utc())
-        .offset();
-    #[cfg(target_arch = "wasm32")]
-    let offset = {
-        let tz_offset_secs = -(js_sys::Date::new_0().get_timezone_offset() as i32) * 60;
-        time::UtcOffset::from_whole_seconds(tz_offset_secs).unwrap_or(time::UtcOffset::UTC)
-    };
-    OffsetDateTime::from_unix_timestamp(timestamp_secs.cast_signed())
-        .unwrap_or(OffsetDateTime::UNIX_EPOCH)
-        .to_offset(offset)
-}
-/// Returns `true` when `timestamp` falls on the same weekday as today in the
-/// local timezone (e.g. both are Monday), regardless of the calendar week.
-/// Used to suggest repeating a session performed on the same day of the week.
-#[must_use]
-pub fn is_same_weekday_as_today(timestamp: u64) -> bool {
-    use time::OffsetDateTime;
-    #[cfg(not(target_arch = "wasm32"))]
-    let today = OffsetDateTime::now_local()
-        .unwrap_or_else(|_| OffsetDateTime::now_utc())
-        .weekday();
-    #[cfg(target_arch = "wasm32")]
-    let today = {
-        let millis = js_sys::Date::now();
-        let tz_offset_secs = -(js_sys::Date::new_0().get_timezone_offset() as i32) * 60;
-        let offset =
-            time::UtcOffset::from_whole_seconds(tz_offset_secs).unwrap_or(time::UtcOffset::UTC);
-        OffsetDateTime::from_unix_timestamp_nanos((millis as i128) * 1_000_000)
-            .unwrap_or(OffsetDateTime::now_utc())
-            .to_offset(offset)
-            .weekday()
-    };
-    ts_to_local_datetime(timestamp).weekday() == today
-}
-/// Returns a short, locale-sensitive date string for `timestamp_secs` suitable
-/// for display on a compact button (e.g. "03/28" for English or "28/03" for
-/// French / Spanish).
-///
-/// The format is `MM/DD` when `lang` starts with `"en"`, and `DD/MM` for all
-/// other language tags, matching common European conventions.
-#[must_use]
-pub fn format_short_date(timestamp_secs: u64, lang: &str) -> String {
-    let dt = ts_to_local_datetime(timestamp_secs);
-    let day = dt.day();
-    let month = dt.month() as u8;
-    if lang.starts_with("en") {
-        format!("{month:02}/{day:02}")
-    } else {
-        format!("{day:02}/{month:02}")
-    }
-}
-/// Returns the number of elapsed calendar days between the local midnight of
-/// `timestamp`'s day and the local midnight of today, using system’s local TZ
-fn days_since(timestamp: u64) -> i64 {
-    use time::OffsetDateTime;
-    #[cfg(not(target_arch = "wasm32"))]
-    let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
-    #[cfg(target_arch = "wasm32")]
-    let now = {
-        let millis = js_sys::Date::now();
-        let tz_offset_secs = -(js_sys::Date::new_0().get_timezone_offset() as i32) * 60;
-        let offset =
-            time::UtcOffset::from_whole_seconds(tz_offset_secs).unwrap_or(time::UtcOffset::UTC);
-        OffsetDateTime::from_unix_timestamp_nanos((millis as i128) * 1_000_000)
-            .unwrap_or(OffsetDateTime::now_utc())
-            .to_offset(offset)
-    };
-    let offset = now.offset();
-    let ts_dt = OffsetDateTime::from_unix_timestamp(timestamp.cast_signed())
-        .unwrap_or(OffsetDateTime::UNIX_EPOCH)
-        .to_offset(offset);
-    let now_date = now.date();
-    let ts_date = ts_dt.date();
-    (now_date - ts_date).whole_days()
-}
 #[cfg(test)]
 mod tests {
     use super::*;
-    fn today_midnight_local_secs() -> u64 {
-        use time::OffsetDateTime;
-        let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
-        let midnight = now.replace_time(time::Time::MIDNIGHT);
-        midnight.unix_timestamp().max(0).cast_unsigned()
-    }
-    #[test]
-    fn format_session_date_today() {
-        let ts = today_midnight_local_secs() + SECONDS_IN_HOUR;
-        assert_eq!(format_session_date(ts), "Today");
-    }
-    #[test]
-    fn format_session_date_yesterday() {
-        let ts = today_midnight_local_secs() - 1;
-        assert_eq!(format_session_date(ts), "Yesterday");
-    }
-    #[test]
-    fn format_session_date_days_ago() {
-        let ts = today_midnight_local_secs() - SECONDS_IN_DAY * 3;
-        assert_eq!(format_session_date(ts), "3 days ago");
-    }
-    #[test]
-    fn format_session_date_beginning_of_today() {
-        let ts = today_midnight_local_secs();
-        assert_eq!(format_session_date(ts), "Today");
-    }
-    #[test]
-    fn format_session_date_end_of_yesterday() {
-        let ts = today_midnight_local_secs() - 1;
-        assert_eq!(format_session_date(ts), "Yesterday");
-    }
-    #[test]
-    fn format_session_date_two_days_ago() {
-        let ts = today_midnight_local_secs() - SECONDS_IN_DAY * 2;
-        assert_eq!(format_session_date(ts), "2 days ago");
-    }
-    #[test]
-    fn days_since_uses_local_midnight_boundary() {
-        let midnight = today_midnight_local_secs();
-        let days = super::days_since(midnight);
-        assert_eq!(days, 0, "local midnight should be day 0");
-    }
     #[test]
     fn get_exercise_db_url_returns_default_on_native() {
         #[cfg(not(target_arch = "wasm32"))]
@@ -602,32 +512,27 @@ mod tests {
         assert_eq!(super::parse_deep_link("https://example.com"), None);
     }
     #[test]
-    fn get_query_param_basic() {
+    fn manifest_shortcut_queries_parse() {
         assert_eq!(
-            super::get_query_param("foo=bar&baz=qux", "foo"),
-            Some("bar".to_string()),
+            super::parse_web_deep_link_query("dl_start="),
+            Some(super::DeepLinkAction::StartSession(vec![]))
         );
         assert_eq!(
-            super::get_query_param("foo=bar&baz=qux", "baz"),
-            Some("qux".to_string()),
+            super::parse_web_deep_link_query("dl_navigate=analytics"),
+            Some(super::DeepLinkAction::Navigate("/analytics".into()))
         );
-        assert_eq!(super::get_query_param("foo=bar&baz=qux", "missing"), None);
     }
     #[test]
-    fn percent_decode_handles_common_chars() {
+    fn shared_session_links_parse() {
         assert_eq!(
-            super::percent_decode("hello%20world"),
-            "hello world".to_string()
+            super::parse_web_deep_link_query("dl_import=eyJ2Ijox"),
+            Some(super::DeepLinkAction::ImportSession("eyJ2Ijox".into()))
         );
-        assert_eq!(super::percent_decode("a+b"), "a b".to_string());
         assert_eq!(
-            super::percent_decode("http%3A%2F%2Flocalhost%3A8080"),
-            "http://localhost:8080".to_string(),
+            super::parse_deep_link("logworkout://session/import?data=eyJ2Ijox"),
+            Some(super::DeepLinkAction::ImportSession("eyJ2Ijox".into()))
         );
-    }
-    #[test]
-    fn percent_decode_handles_multibyte_utf8() {
-        assert_eq!(super::percent_decode("%C3%A9"), "é".to_string());
+        assert_eq!(super::parse_deep_link("logworkout://session/import"), None);
     }
     #[test]
     fn parse_session_exercises_weight_rounding() {
@@ -698,56 +603,4 @@ mod tests {
     fn route_name_to_path_unknown_prefixes_slash() {
         assert_eq!(super::route_name_to_path("custom"), "/custom");
     }
-    #[test]
-    fn session_days_ago_today_is_zero() {
-        let midnight = today_midnight_local_secs();
-        assert_eq!(super::session_days_ago(midnight + SECONDS_IN_HOUR), 0);
-    }
-    #[test]
-    fn session_days_ago_yesterday_is_one() {
-        let midnight = today_midnight_local_secs();
-        assert_eq!(super::session_days_ago(midnight - 1), 1);
-    }
-    #[test]
-    fn session_days_ago_seven_days() {
-        let midnight = today_midnight_local_secs();
-        assert_eq!(super::session_days_ago(midnight - SECONDS_IN_DAY * 7), 7,);
-    }
-    #[test]
-    fn is_same_weekday_as_today_for_today() {
-        let midnight = today_midnight_local_secs();
-        // A timestamp from earlier today must share today's weekday.
-        assert!(super::is_same_weekday_as_today(midnight + SECONDS_IN_HOUR));
-    }
-    #[test]
-    fn is_same_weekday_as_today_for_yesterday() {
-        let midnight = today_midnight_local_secs();
-        // Yesterday has a different weekday (unless two days differ by 7, but
-        // yesterday is exactly 1 day ago so different weekday).
-        assert!(!super::is_same_weekday_as_today(midnight - 1));
-    }
-    #[test]
-    fn is_same_weekday_as_today_for_same_weekday_last_week() {
-        let midnight = today_midnight_local_secs();
-        // Exactly 7 days ago is the same weekday.
-        assert!(super::is_same_weekday_as_today(
-            midnight - SECONDS_IN_DAY * 7 + SECONDS_IN_HOUR
-        ));
-    }
-    #[test]
-    fn format_short_date_en() {
-        let midnight = today_midnight_local_secs();
-        let s = super::format_short_date(midnight + SECONDS_IN_HOUR, "en");
-        // Format should be MM/DD with two digits each.
-        assert_eq!(s.len(), 5, "en short date should be 5 chars: {s}");
-        assert_eq!(&s[2..3], "/");
-    }
-    #[test]
-    fn format_short_date_fr() {
-        let midnight = today_midnight_local_secs();
-        let s = super::format_short_date(midnight + SECONDS_IN_HOUR, "fr");
-        // Format should be DD/MM with two digits each.
-        assert_eq!(s.len(), 5, "fr short date should be 5 chars: {s}");
-        assert_eq!(&s[2..3], "/");
-    }
 }